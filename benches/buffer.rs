@@ -0,0 +1,84 @@
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use ratatui::{
+    buffer::{Buffer, Cell},
+    layout::Rect,
+    style::Style,
+};
+
+/// The size of a typical full screen terminal, and of a large one
+const AREAS: [Rect; 2] = [
+    Rect {
+        x: 0,
+        y: 0,
+        width: 80,
+        height: 24,
+    },
+    Rect {
+        x: 0,
+        y: 0,
+        width: 255,
+        height: 255,
+    },
+];
+
+/// Symbols made of a single code point, of multiple code points, and of multiple code points
+/// joined into a single grapheme
+const SYMBOLS: [(&str, &str); 4] = [
+    ("ascii", "a"),
+    ("wide", "あ"),
+    ("combining", "e\u{301}"),
+    ("zwj", "👩‍💻"),
+];
+
+fn empty(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("buffer/empty");
+    for area in AREAS {
+        group.bench_with_input(
+            BenchmarkId::from_parameter(format!("{}x{}", area.width, area.height)),
+            &area,
+            |bencher, &area| bencher.iter(|| Buffer::empty(black_box(area))),
+        );
+    }
+    group.finish();
+}
+
+fn fill(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("buffer/fill");
+    for (name, symbol) in SYMBOLS {
+        for area in AREAS {
+            let id = format!("{name}/{}x{}", area.width, area.height);
+            let line = symbol.repeat(usize::from(area.width));
+            group.bench_with_input(BenchmarkId::from_parameter(id), &line, |bencher, line| {
+                let mut buffer = Buffer::empty(area);
+                bencher.iter(|| {
+                    for y in area.top()..area.bottom() {
+                        buffer.set_string(0, y, black_box(line), Style::new());
+                    }
+                });
+            });
+        }
+    }
+    group.finish();
+}
+
+fn set_symbol(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("cell/set_symbol");
+    for (name, symbol) in SYMBOLS {
+        group.bench_with_input(
+            BenchmarkId::from_parameter(name),
+            symbol,
+            |bencher, symbol| {
+                let mut cell = Cell::default();
+                bencher.iter(|| {
+                    cell.set_symbol(black_box(symbol));
+                });
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, empty, fill, set_symbol);
+criterion_main!(benches);
@@ -52,7 +52,7 @@ fn paragraph(c: &mut Criterion) {
         // render the paragraph wrapped to 100 characters
         group.bench_with_input(
             BenchmarkId::new("render_wrap", line_count),
-            &Paragraph::new(lines).wrap(Wrap { trim: false }),
+            &Paragraph::new(lines).wrap(Wrap::new(false)),
             |bencher, paragraph| render(bencher, paragraph, WRAP_WIDTH),
         );
 
@@ -60,7 +60,7 @@ fn paragraph(c: &mut Criterion) {
         group.bench_with_input(
             BenchmarkId::new("render_wrap_scroll_full", line_count),
             &Paragraph::new(lines)
-                .wrap(Wrap { trim: false })
+                .wrap(Wrap::new(false))
                 .scroll((0, line_count)),
             |bencher, paragraph| render(bencher, paragraph, WRAP_WIDTH),
         );
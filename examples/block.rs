@@ -144,7 +144,7 @@ fn render_title(frame: &mut Frame, area: Rect) {
 
 fn placeholder_paragraph() -> Paragraph<'static> {
     let text = "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do eiusmod tempor incididunt ut labore et dolore magna aliqua.";
-    Paragraph::new(text.dark_gray()).wrap(Wrap { trim: true })
+    Paragraph::new(text.dark_gray()).wrap(Wrap::new(true))
 }
 
 fn render_borders(paragraph: &Paragraph, border: Borders, frame: &mut Frame, area: Rect) {
@@ -101,7 +101,8 @@ impl App {
                 Marker::Dot => Marker::Braille,
                 Marker::Braille => Marker::Block,
                 Marker::Block => Marker::HalfBlock,
-                Marker::HalfBlock => Marker::Bar,
+                Marker::HalfBlock => Marker::Shade,
+                Marker::Shade => Marker::Bar,
                 Marker::Bar => Marker::Dot,
             };
         }
@@ -143,6 +144,7 @@ impl App {
                 ctx.draw(&Map {
                     color: Color::Green,
                     resolution: MapResolution::High,
+                    ..Default::default()
                 });
                 ctx.print(self.x, -self.y, "You are here".yellow());
             })
@@ -157,6 +157,8 @@ impl App {
             | Constraint::Min(v)
             | Constraint::Max(v)
             | Constraint::Fill(v)
+            | Constraint::MinContent(v)
+            | Constraint::MaxContent(v)
             | Constraint::Percentage(v) => *v = v.saturating_add(1),
             Constraint::Ratio(_n, d) => *d = d.saturating_add(1),
         };
@@ -171,6 +173,8 @@ impl App {
             | Constraint::Min(v)
             | Constraint::Max(v)
             | Constraint::Fill(v)
+            | Constraint::MinContent(v)
+            | Constraint::MaxContent(v)
             | Constraint::Percentage(v) => *v = v.saturating_sub(1),
             Constraint::Ratio(_n, d) => *d = d.saturating_sub(1),
         };
@@ -248,8 +252,8 @@ impl From<Constraint> for ConstraintName {
             Length(_) => Self::Length,
             Percentage(_) => Self::Percentage,
             Ratio(_, _) => Self::Ratio,
-            Min(_) => Self::Min,
-            Max(_) => Self::Max,
+            Min(_) | MinContent(_) => Self::Min,
+            Max(_) | MaxContent(_) => Self::Max,
             Fill(_) => Self::Fill,
         }
     }
@@ -290,7 +294,7 @@ impl App {
         Paragraph::new(text)
             .fg(Self::TEXT_COLOR)
             .centered()
-            .wrap(Wrap { trim: false })
+            .wrap(Wrap::new(false))
     }
 
     fn swap_legend() -> impl Widget {
@@ -317,7 +321,7 @@ impl App {
             )
             .centered(),
         )
-        .wrap(Wrap { trim: false })
+        .wrap(Wrap::new(false))
     }
 
     /// A bar like `<----- 80 px (gap: 2 px) ----->`
@@ -521,6 +525,7 @@ impl SpacerBlock {
             vertical_right: " ",
             horizontal_top: " ",
             horizontal_bottom: " ",
+            ..symbols::border::PLAIN
         };
         Block::bordered()
             .border_set(corners_only)
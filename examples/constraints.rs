@@ -178,13 +178,13 @@ impl App {
         let block = Block::new()
             .title("Constraints ".bold())
             .title(" Use h l or ◄ ► to change tab and j k or ▲ ▼  to scroll");
-        Tabs::new(titles)
+        let tabs = Tabs::new(titles)
             .block(block)
             .highlight_style(Modifier::REVERSED)
             .select(self.selected_tab as usize)
             .padding("", "")
-            .divider(" ")
-            .render(area, buf);
+            .divider(" ");
+        Widget::render(tabs, area, buf);
     }
 
     fn render_axis(area: Rect, buf: &mut Buffer) {
@@ -395,8 +395,8 @@ impl Example {
             Constraint::Percentage(_) => PERCENTAGE_COLOR,
             Constraint::Ratio(_, _) => RATIO_COLOR,
             Constraint::Fill(_) => FILL_COLOR,
-            Constraint::Min(_) => MIN_COLOR,
-            Constraint::Max(_) => MAX_COLOR,
+            Constraint::Min(_) | Constraint::MinContent(_) => MIN_COLOR,
+            Constraint::Max(_) | Constraint::MaxContent(_) => MAX_COLOR,
         };
         let fg = Color::White;
         let title = format!("{constraint}");
@@ -166,10 +166,12 @@ fn run_app<B: Backend>(terminal: &mut Terminal<B>) -> io::Result<()> {
     let mut selected_button: usize = 0;
     let mut button_states = [State::Selected, State::Normal, State::Normal];
     loop {
-        terminal.draw(|frame| ui(frame, button_states))?;
+        // only repaint after an event changed something, at most 60 times per second
+        terminal.draw_throttled(60, |frame| ui(frame, button_states))?;
         if !event::poll(Duration::from_millis(100))? {
             continue;
         }
+        terminal.request_redraw();
         match event::read()? {
             Event::Key(key) => {
                 if key.kind != event::KeyEventKind::Press {
@@ -258,7 +258,7 @@ fn draw_text(f: &mut Frame, area: Rect) {
             .fg(Color::Magenta)
             .add_modifier(Modifier::BOLD),
     ));
-    let paragraph = Paragraph::new(text).block(block).wrap(Wrap { trim: true });
+    let paragraph = Paragraph::new(text).block(block).wrap(Wrap::new(true));
     f.render_widget(paragraph, area);
 }
 
@@ -299,6 +299,7 @@ fn draw_second_tab(f: &mut Frame, app: &mut App, area: Rect) {
             ctx.draw(&Map {
                 color: Color::White,
                 resolution: MapResolution::High,
+                ..Default::default()
             });
             ctx.layer();
             ctx.draw(&Rectangle {
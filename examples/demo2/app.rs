@@ -153,13 +153,13 @@ impl App {
 
         Span::styled("Ratatui", THEME.app_title).render(title, buf);
         let titles = Tab::iter().map(Tab::title);
-        Tabs::new(titles)
+        let tabs_widget = Tabs::new(titles)
             .style(THEME.tabs)
             .highlight_style(THEME.tabs_selected)
             .select(self.tab as usize)
             .divider("")
-            .padding("", "")
-            .render(tabs, buf);
+            .padding("", "");
+        Widget::render(tabs_widget, tabs, buf);
     }
 
     fn render_selected_tab(&self, area: Rect, buf: &mut Buffer) {
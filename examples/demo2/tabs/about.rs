@@ -92,7 +92,7 @@ fn render_crate_description(area: Rect, buf: &mut Buffer) {
                 .border_style(THEME.description_title)
                 .padding(Padding::new(0, 0, 0, 0)),
         )
-        .wrap(Wrap { trim: true })
+        .wrap(Wrap::new(true))
         .scroll((0, 0))
         .render(area, buf);
 }
@@ -142,7 +142,7 @@ fn render_recipe(area: Rect, buf: &mut Buffer) {
         .map(|(step, text)| Line::from(vec![step.white().bold(), text.gray()]))
         .collect_vec();
     Paragraph::new(lines)
-        .wrap(Wrap { trim: true })
+        .wrap(Wrap::new(true))
         .block(Block::new().padding(Padding::new(0, 1, 0, 0)))
         .render(area, buf);
 }
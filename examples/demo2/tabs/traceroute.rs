@@ -106,6 +106,7 @@ fn render_map(selected_row: usize, area: Rect, buf: &mut Buffer) {
     let map = Map {
         resolution: canvas::MapResolution::High,
         color: theme.color,
+        ..Default::default()
     };
     Canvas::default()
         .background_color(theme.background_color)
@@ -16,6 +16,7 @@ pub fn init() -> Result<Terminal<impl Backend>> {
     // using vhs in a 1280x640 sized window (github social preview size)
     let options = TerminalOptions {
         viewport: Viewport::Fixed(Rect::new(0, 0, 81, 18)),
+        ..Default::default()
     };
     let terminal = Terminal::with_options(CrosstermBackend::new(io::stdout()), options)?;
     enable_raw_mode().context("enable raw mode")?;
@@ -460,6 +460,7 @@ impl Example {
                 vertical_right: " ",
                 horizontal_top: " ",
                 horizontal_bottom: " ",
+                ..symbols::border::PLAIN
             };
             Block::bordered()
                 .border_set(corners_only)
@@ -511,8 +512,8 @@ impl Example {
 const fn color_for_constraint(constraint: Constraint) -> Color {
     use tailwind::*;
     match constraint {
-        Constraint::Min(_) => BLUE.c900,
-        Constraint::Max(_) => BLUE.c800,
+        Constraint::Min(_) | Constraint::MinContent(_) => BLUE.c900,
+        Constraint::Max(_) | Constraint::MaxContent(_) => BLUE.c800,
         Constraint::Length(_) => SLATE.c700,
         Constraint::Percentage(_) => SLATE.c800,
         Constraint::Ratio(_, _) => SLATE.c900,
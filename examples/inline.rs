@@ -88,6 +88,7 @@ fn main() -> Result<(), Box<dyn Error>> {
         backend,
         TerminalOptions {
             viewport: Viewport::Inline(8),
+            ..Default::default()
         },
     )?;
 
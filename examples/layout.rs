@@ -224,6 +224,8 @@ fn constraint_label(constraint: Constraint) -> String {
         | Constraint::Min(n)
         | Constraint::Max(n)
         | Constraint::Percentage(n)
-        | Constraint::Fill(n) => format!("{n}"),
+        | Constraint::Fill(n)
+        | Constraint::MinContent(n)
+        | Constraint::MaxContent(n) => format!("{n}"),
     }
 }
@@ -268,7 +268,7 @@ impl App<'_> {
         let info_paragraph = Paragraph::new(info)
             .block(inner_info_block)
             .fg(TEXT_COLOR)
-            .wrap(Wrap { trim: false });
+            .wrap(Wrap::new(false));
 
         // We can now render the item info
         info_paragraph.render(inner_info_area, buf);
@@ -49,12 +49,8 @@ struct App {
 
 impl App {
     fn chain_hook(&mut self) {
-        let original_hook = std::panic::take_hook();
-
-        std::panic::set_hook(Box::new(move |panic| {
-            reset_terminal().unwrap();
-            original_hook(panic);
-        }));
+        // restores the terminal before calling the original panic hook
+        ratatui::install_panic_hook();
 
         self.hook_enabled = true;
     }
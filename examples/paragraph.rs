@@ -143,21 +143,21 @@ fn ui(f: &mut Frame, app: &App) {
     let paragraph = Paragraph::new(text.clone())
         .style(Style::default().fg(Color::Gray))
         .block(create_block("Default alignment (Left), with wrap"))
-        .wrap(Wrap { trim: true });
+        .wrap(Wrap::new(true));
     f.render_widget(paragraph, layout[1]);
 
     let paragraph = Paragraph::new(text.clone())
         .style(Style::default().fg(Color::Gray))
         .block(create_block("Right alignment, with wrap"))
         .right_aligned()
-        .wrap(Wrap { trim: true });
+        .wrap(Wrap::new(true));
     f.render_widget(paragraph, layout[2]);
 
     let paragraph = Paragraph::new(text)
         .style(Style::default().fg(Color::Gray))
         .block(create_block("Center alignment, with wrap, with scroll"))
         .centered()
-        .wrap(Wrap { trim: true })
+        .wrap(Wrap::new(true))
         .scroll((app.scroll, 0));
     f.render_widget(paragraph, layout[3]);
 }
@@ -95,7 +95,7 @@ fn ui(f: &mut Frame, app: &App) {
     };
     let paragraph = Paragraph::new(text.slow_blink())
         .centered()
-        .wrap(Wrap { trim: true });
+        .wrap(Wrap::new(true));
     f.render_widget(paragraph, instructions);
 
     let block = Block::bordered().title("Content").on_blue();
@@ -73,6 +73,7 @@ fn init() -> io::Result<Terminal<impl Backend>> {
     enable_raw_mode()?;
     let options = TerminalOptions {
         viewport: Viewport::Inline(3),
+        ..Default::default()
     };
     Terminal::with_options(CrosstermBackend::new(stdout()), options)
 }
@@ -0,0 +1,525 @@
+//! Helpers to structure an application following the [Elm architecture].
+//!
+//! An application is a [`Model`], which holds the state of the application and defines:
+//!
+//! - a message type [`Model::Msg`], which describes everything that can happen to the model;
+//! - [`Model::update`], which changes the model in response to a message, and returns a [`Cmd`]
+//!   describing further work, such as sending other messages or quitting;
+//! - [`Model::view`], which renders the model to a [`Frame`];
+//! - [`Model::subscriptions`], which lists the [`Sub`]s that turn terminal events and ticks into
+//!   messages.
+//!
+//! A [`Program`] runs the model on a [`Terminal`]: it reads the events from an [`EventSource`],
+//! passes the resulting messages to the update function, carries out the returned commands and
+//! redraws the interface when the model changed. This keeps the update function easy to test, as
+//! it only maps a model and a message to a new model and a command.
+//!
+//! This is an opt-in, higher-level API. Applications are free to write their own loop using
+//! [`Terminal::draw`] instead.
+//!
+//! # Relation to the `runtime` module
+//!
+//! The `runtime` module (enabled by the `runtime-tokio` feature) provides another runner, for
+//! applications built on the Tokio runtime. Both follow the Elm architecture, but they do not
+//! share their loop because they wait differently: a [`Program`] blocks the current thread on an
+//! [`EventSource`] and runs the work of a [`Cmd`] synchronously, so it needs no async runtime,
+//! while the `runtime` runner awaits the event stream of the terminal and the messages sent by
+//! spawned tasks, with plain `update` and `view` functions instead of a [`Model`]. Use a
+//! [`Program`] for a synchronous application, and the `runtime` module when the application
+//! already uses Tokio for its own work, such as network requests.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use std::time::Duration;
+//!
+//! use ratatui::{
+//!     app::{Cmd, CrosstermEvents, Model, Program, Sub},
+//!     backend::event::{Event, KeyCode},
+//!     prelude::*,
+//!     widgets::Paragraph,
+//! };
+//!
+//! struct Counter {
+//!     count: i32,
+//!     seconds: u32,
+//! }
+//!
+//! enum Msg {
+//!     Increment,
+//!     Decrement,
+//!     Tick,
+//!     Quit,
+//! }
+//!
+//! impl Model for Counter {
+//!     type Msg = Msg;
+//!
+//!     fn update(&mut self, msg: Msg) -> Cmd<Msg> {
+//!         match msg {
+//!             Msg::Increment => self.count += 1,
+//!             Msg::Decrement => self.count -= 1,
+//!             Msg::Tick => self.seconds += 1,
+//!             Msg::Quit => return Cmd::quit(),
+//!         }
+//!         Cmd::none()
+//!     }
+//!
+//!     fn view(&self, frame: &mut Frame) {
+//!         let text = format!("Count: {} ({}s)", self.count, self.seconds);
+//!         frame.render_widget(Paragraph::new(text), frame.size());
+//!     }
+//!
+//!     fn subscriptions(&self) -> Vec<Sub<Msg>> {
+//!         vec![
+//!             Sub::events(|event| match event {
+//!                 Event::Key(key) => match key.code {
+//!                     KeyCode::Char('+') => Some(Msg::Increment),
+//!                     KeyCode::Char('-') => Some(Msg::Decrement),
+//!                     KeyCode::Char('q') => Some(Msg::Quit),
+//!                     _ => None,
+//!                 },
+//!                 _ => None,
+//!             }),
+//!             Sub::tick(Duration::from_secs(1), |_| Msg::Tick),
+//!         ]
+//!     }
+//! }
+//!
+//! let mut terminal = ratatui::init();
+//! let counter = Counter {
+//!     count: 0,
+//!     seconds: 0,
+//! };
+//! let result = Program::new(counter).run(&mut terminal, CrosstermEvents);
+//! ratatui::restore();
+//! println!("final count: {}", result?.count);
+//! # std::io::Result::Ok(())
+//! ```
+//!
+//! [Elm architecture]: https://guide.elm-lang.org/architecture/
+
+use std::{
+    collections::VecDeque,
+    io,
+    time::{Duration, Instant},
+};
+
+mod cmd;
+mod sub;
+
+use self::{cmd::Action, sub::SubKind};
+pub use self::{cmd::Cmd, sub::Sub};
+use crate::{
+    backend::event::{Event, Tick},
+    prelude::*,
+};
+
+/// The state of an application and the functions to update and render it
+///
+/// See the [module documentation](self) for more details.
+pub trait Model {
+    /// The messages that update the model
+    type Msg;
+
+    /// Returns the command to carry out when the program starts
+    ///
+    /// This is called once before the first frame is drawn, e.g. to load data. The default
+    /// implementation does nothing.
+    fn init(&mut self) -> Cmd<Self::Msg> {
+        Cmd::none()
+    }
+
+    /// Updates the model in response to a message, and returns the command to carry out next
+    fn update(&mut self, msg: Self::Msg) -> Cmd<Self::Msg>;
+
+    /// Renders the model to the frame
+    fn view(&self, frame: &mut Frame);
+
+    /// Returns the subscriptions of the model
+    ///
+    /// This is called after each update, so the subscriptions can depend on the state of the
+    /// model. The default implementation has no subscriptions.
+    fn subscriptions(&self) -> Vec<Sub<Self::Msg>> {
+        Vec::new()
+    }
+}
+
+/// A source of terminal events for a [`Program`]
+///
+/// This is implemented by [`CrosstermEvents`] when the `crossterm` feature is enabled, and by
+/// functions with the same signature as [`EventSource::next_event`], e.g. to replay events in
+/// tests.
+pub trait EventSource {
+    /// Waits for the next event for at most `timeout`, or indefinitely if `timeout` is `None`
+    ///
+    /// Returns `None` if no event happened before the timeout.
+    fn next_event(&mut self, timeout: Option<Duration>) -> io::Result<Option<Event>>;
+}
+
+impl<F> EventSource for F
+where
+    F: FnMut(Option<Duration>) -> io::Result<Option<Event>>,
+{
+    fn next_event(&mut self, timeout: Option<Duration>) -> io::Result<Option<Event>> {
+        self(timeout)
+    }
+}
+
+/// An [`EventSource`] reading the events of the terminal with Crossterm
+#[cfg(feature = "crossterm")]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct CrosstermEvents;
+
+#[cfg(feature = "crossterm")]
+impl EventSource for CrosstermEvents {
+    fn next_event(&mut self, timeout: Option<Duration>) -> io::Result<Option<Event>> {
+        if let Some(timeout) = timeout {
+            if !::crossterm::event::poll(timeout)? {
+                return Ok(None);
+            }
+        }
+        ::crossterm::event::read().map(|event| Some(event.into()))
+    }
+}
+
+/// Runs a [`Model`] on a [`Terminal`]
+///
+/// The program draws the model, then waits for an event or a tick of its subscriptions, passes
+/// the resulting messages to [`Model::update`] and carries out the returned commands, until a
+/// command quits. The interface is redrawn after the model was updated, at most
+/// [`Program::fps`] times per second. See the [module documentation](self) for an example.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Program<M> {
+    model: M,
+    fps: u32,
+}
+
+impl<M: Model> Program<M> {
+    /// Creates a new program for the model, which draws at most 60 frames per second
+    pub const fn new(model: M) -> Self {
+        Self { model, fps: 60 }
+    }
+
+    /// Sets the maximum number of frames drawn per second
+    ///
+    /// An `fps` of 0 redraws the interface after every update. See
+    /// [`Terminal::draw_throttled`] for more details.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn fps(mut self, fps: u32) -> Self {
+        self.fps = fps;
+        self
+    }
+
+    /// Runs the program until a command quits, and returns the final model
+    ///
+    /// The messages that are still queued when a command quits are discarded, see
+    /// [`Cmd::quit`].
+    ///
+    /// The terminal is not initialized or restored by the program, see [`crate::init`] and
+    /// [`crate::restore`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if drawing to the terminal or reading an event fails.
+    pub fn run<B, E>(self, terminal: &mut Terminal<B>, mut events: E) -> io::Result<M>
+    where
+        B: Backend,
+        E: EventSource,
+    {
+        let Self { mut model, fps } = self;
+        let mut queue = VecDeque::new();
+        // the timing of each subscribed interval
+        let mut ticks: Vec<Tick> = Vec::new();
+        if execute(model.init(), &mut queue) {
+            return Ok(model);
+        }
+        terminal.request_redraw();
+        loop {
+            while let Some(msg) = queue.pop_front() {
+                terminal.request_redraw();
+                if execute(model.update(msg), &mut queue) {
+                    return Ok(model);
+                }
+            }
+            terminal.draw_throttled(fps, |frame| model.view(frame))?;
+
+            let subscriptions = model.subscriptions();
+            update_ticks(&mut ticks, &subscriptions, Instant::now());
+            let now = Instant::now();
+            let next_frame = terminal
+                .needs_redraw()
+                .then(|| terminal.time_until_next_frame(fps));
+            let timeout = ticks
+                .iter()
+                .map(|tick| tick.deadline().saturating_duration_since(now))
+                .chain(next_frame)
+                .min();
+
+            if let Some(event) = events.next_event(timeout)? {
+                queue.extend(subscriptions.iter().filter_map(|sub| match &sub.kind {
+                    SubKind::Events(f) => f(&event),
+                    SubKind::Tick { .. } => None,
+                }));
+            }
+
+            poll_ticks(&mut ticks, &subscriptions, Instant::now(), &mut queue);
+        }
+    }
+}
+
+impl<M: Model + Default> Default for Program<M> {
+    /// Creates a new program for the default model, see [`Program::new`]
+    fn default() -> Self {
+        Self::new(M::default())
+    }
+}
+
+/// Queues the messages of the command, and returns true if the command quits
+fn execute<M>(cmd: Cmd<M>, queue: &mut VecDeque<M>) -> bool {
+    let mut quit = false;
+    for action in cmd.actions {
+        match action {
+            Action::Msg(msg) => queue.push_back(msg),
+            Action::Perform(f) => queue.push_back(f()),
+            Action::Quit => quit = true,
+        }
+    }
+    quit
+}
+
+/// Starts the timing of newly subscribed ticks, and stops the timing of unsubscribed ones
+fn update_ticks<M>(ticks: &mut Vec<Tick>, subscriptions: &[Sub<M>], now: Instant) {
+    let intervals: Vec<Duration> = subscriptions
+        .iter()
+        .filter_map(|sub| match sub.kind {
+            SubKind::Tick { interval, .. } => Some(interval),
+            SubKind::Events(_) => None,
+        })
+        .collect();
+    ticks.retain(|tick| intervals.contains(&tick.interval()));
+    for interval in intervals {
+        if !ticks.iter().any(|tick| tick.interval() == interval) {
+            ticks.push(Tick::starting_at(now, interval));
+        }
+    }
+}
+
+/// Queues the messages of the ticks that are due at `now`
+///
+/// The messages are created with the deadline of the tick rather than `now`, and the next deadline
+/// follows the previous one, so the ticks do not drift when they are handled late.
+fn poll_ticks<M>(
+    ticks: &mut [Tick],
+    subscriptions: &[Sub<M>],
+    now: Instant,
+    queue: &mut VecDeque<M>,
+) {
+    for tick in ticks {
+        if tick.poll(now).is_none() {
+            continue;
+        }
+        let interval = tick.interval();
+        // the deadline of the tick that is due, which is one interval before the next one
+        let deadline = tick.deadline().checked_sub(interval).unwrap_or(now);
+        queue.extend(subscriptions.iter().filter_map(|sub| match &sub.kind {
+            SubKind::Tick { interval: i, msg } if *i == interval => Some(msg(deadline)),
+            _ => None,
+        }));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::{
+        backend::{
+            event::{KeyCode, KeyEvent, KeyModifiers},
+            TestBackend,
+        },
+        widgets::Paragraph,
+    };
+
+    #[derive(Debug, Default)]
+    struct Counter {
+        count: i32,
+        ticks: u32,
+        tick_until: u32,
+        log: Vec<&'static str>,
+    }
+
+    #[derive(Debug)]
+    enum Msg {
+        Add(i32),
+        Tick,
+        Loaded,
+        Quit,
+    }
+
+    impl Model for Counter {
+        type Msg = Msg;
+
+        fn init(&mut self) -> Cmd<Msg> {
+            self.log.push("init");
+            Cmd::perform(|| Msg::Loaded)
+        }
+
+        fn update(&mut self, msg: Msg) -> Cmd<Msg> {
+            match msg {
+                Msg::Add(n) => self.count += n,
+                Msg::Loaded => self.log.push("loaded"),
+                Msg::Tick => {
+                    self.ticks += 1;
+                    if self.ticks == self.tick_until {
+                        return Cmd::quit();
+                    }
+                }
+                Msg::Quit => return Cmd::batch([Cmd::msg(Msg::Add(100)), Cmd::quit()]),
+            }
+            Cmd::none()
+        }
+
+        fn view(&self, frame: &mut Frame) {
+            frame.render_widget(Paragraph::new(self.count.to_string()), frame.size());
+        }
+
+        fn subscriptions(&self) -> Vec<Sub<Msg>> {
+            let mut subscriptions = vec![Sub::events(|event| match event {
+                Event::Key(key) => match key.code {
+                    KeyCode::Char('+') => Some(Msg::Add(1)),
+                    KeyCode::Char('-') => Some(Msg::Add(-1)),
+                    KeyCode::Char('q') => Some(Msg::Quit),
+                    _ => None,
+                },
+                _ => None,
+            })];
+            if self.tick_until > 0 {
+                subscriptions.push(Sub::tick(Duration::from_millis(1), |_| Msg::Tick));
+            }
+            subscriptions
+        }
+    }
+
+    /// An event source returning the given events, then waiting for the timeouts
+    fn replay<const N: usize>(
+        events: [char; N],
+    ) -> impl FnMut(Option<Duration>) -> io::Result<Option<Event>> {
+        let mut events = events.into_iter();
+        move |timeout| {
+            let Some(c) = events.next() else {
+                std::thread::sleep(timeout.expect("a timeout when no events are left"));
+                return Ok(None);
+            };
+            let key = KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE);
+            Ok(Some(Event::Key(key)))
+        }
+    }
+
+    #[test]
+    fn updates_model_with_events() {
+        let mut terminal = Terminal::new(TestBackend::new(3, 1)).unwrap();
+        let counter = Program::new(Counter::default())
+            .fps(0)
+            .run(&mut terminal, replay(['+', '+', 'x', '-', '+', 'q', '+']))
+            .unwrap();
+        // the message batched with the quit command and the events after it are discarded
+        assert_eq!(counter.count, 2);
+        assert_eq!(counter.log, ["init", "loaded"]);
+        terminal.backend().assert_buffer_lines(["2  "]);
+    }
+
+    #[test]
+    fn updates_model_with_ticks() {
+        let mut terminal = Terminal::new(TestBackend::new(3, 1)).unwrap();
+        let model = Counter {
+            tick_until: 3,
+            ..Counter::default()
+        };
+        let counter = Program::new(model).run(&mut terminal, replay([])).unwrap();
+        assert_eq!(counter.ticks, 3);
+    }
+
+    #[test]
+    fn quit_discards_batched_messages() {
+        let mut terminal = Terminal::new(TestBackend::new(3, 1)).unwrap();
+        let counter = Program::new(Counter::default())
+            .fps(0)
+            .run(&mut terminal, replay(['q']))
+            .unwrap();
+        assert_eq!(counter.count, 0);
+        terminal.backend().assert_buffer_lines(["0  "]);
+    }
+
+    #[test]
+    fn default_draws_at_60_fps() {
+        assert_eq!(Program::<Counter>::default().fps, 60);
+    }
+
+    #[test]
+    fn quits_from_init() {
+        struct Quit;
+
+        impl Model for Quit {
+            type Msg = ();
+
+            fn init(&mut self) -> Cmd<()> {
+                Cmd::quit()
+            }
+
+            fn update(&mut self, (): ()) -> Cmd<()> {
+                unreachable!("the program quits before any update");
+            }
+
+            fn view(&self, _: &mut Frame) {
+                unreachable!("the program quits before drawing");
+            }
+        }
+
+        let mut terminal = Terminal::new(TestBackend::new(3, 1)).unwrap();
+        Program::new(Quit).run(&mut terminal, replay([])).unwrap();
+    }
+
+    #[test]
+    fn update_ticks_tracks_subscribed_intervals() {
+        let start = Instant::now();
+        let now = start + Duration::from_millis(500);
+        let second = Duration::from_secs(1);
+        let mut ticks = vec![
+            Tick::starting_at(start, second),
+            Tick::starting_at(start, second * 2),
+        ];
+        let subscriptions = [
+            Sub::<()>::tick(second, |_| ()),
+            Sub::tick(second, |_| ()),
+            Sub::tick(second * 3, |_| ()),
+        ];
+        update_ticks(&mut ticks, &subscriptions, now);
+        assert_eq!(
+            ticks,
+            [
+                Tick::starting_at(start, second),
+                Tick::starting_at(now, second * 3)
+            ]
+        );
+    }
+
+    #[test]
+    fn poll_ticks_does_not_drift() {
+        let start = Instant::now();
+        let ms = Duration::from_millis(1);
+        let mut ticks = [Tick::starting_at(start, ms * 10)];
+        let subscriptions = [Sub::tick(ms * 10, move |time: Instant| time - start)];
+        let mut queue = VecDeque::new();
+
+        poll_ticks(&mut ticks, &subscriptions, start + ms * 9, &mut queue);
+        assert!(queue.is_empty());
+        // handled 3ms late, the tick keeps its deadline and the next one is not delayed
+        poll_ticks(&mut ticks, &subscriptions, start + ms * 13, &mut queue);
+        assert_eq!(queue, [ms * 10]);
+        assert_eq!(ticks[0].deadline(), start + ms * 20);
+        poll_ticks(&mut ticks, &subscriptions, start + ms * 20, &mut queue);
+        assert_eq!(queue, [ms * 10, ms * 20]);
+    }
+}
@@ -0,0 +1,155 @@
+use std::fmt;
+
+/// A command returned by [`Model::update`] to ask the [`Program`] for more work
+///
+/// Commands keep the update function free of side effects: instead of sending messages or
+/// performing I/O itself, the model returns a command describing what to do, which the program
+/// carries out after the update. A command can send a message, perform a function that produces a
+/// message, quit the program, or do several of these with [`Cmd::batch`].
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::app::Cmd;
+///
+/// enum Msg {
+///     Loaded(String),
+///     Saved,
+/// }
+///
+/// let cmd: Cmd<Msg> = Cmd::batch([
+///     Cmd::perform(|| Msg::Loaded("config".to_string())),
+///     Cmd::msg(Msg::Saved),
+/// ]);
+/// assert!(!cmd.is_none());
+/// ```
+///
+/// [`Model::update`]: super::Model::update
+/// [`Program`]: super::Program
+pub struct Cmd<M> {
+    pub(super) actions: Vec<Action<M>>,
+}
+
+/// A single action of a [`Cmd`]
+pub(super) enum Action<M> {
+    /// Send the message to the model
+    Msg(M),
+    /// Call the function and send the message it returns to the model
+    Perform(Box<dyn FnOnce() -> M>),
+    /// Stop the program
+    Quit,
+}
+
+impl<M> Cmd<M> {
+    /// A command that does nothing
+    pub const fn none() -> Self {
+        Self {
+            actions: Vec::new(),
+        }
+    }
+
+    /// A command that sends a message to the model
+    pub fn msg(msg: M) -> Self {
+        Self::from(Action::Msg(msg))
+    }
+
+    /// A command that calls a function and sends the message it returns to the model
+    ///
+    /// The function is called by the program after the update, e.g. to read a file or to query a
+    /// service, so that the update function does not have to perform the side effect itself.
+    pub fn perform<F>(f: F) -> Self
+    where
+        F: FnOnce() -> M + 'static,
+    {
+        Self::from(Action::Perform(Box::new(f)))
+    }
+
+    /// A command that stops the program
+    ///
+    /// The other actions of the command are still carried out, but the messages they produce are
+    /// discarded, as are the messages still queued from earlier commands and events: the program
+    /// returns the model once the command is done, without updating it again. To handle a message
+    /// before quitting, handle it in the update that returns this command instead.
+    pub fn quit() -> Self {
+        Self::from(Action::Quit)
+    }
+
+    /// A command that carries out the given commands in order
+    pub fn batch<I>(cmds: I) -> Self
+    where
+        I: IntoIterator<Item = Self>,
+    {
+        Self {
+            actions: cmds.into_iter().flat_map(|cmd| cmd.actions).collect(),
+        }
+    }
+
+    /// Returns true if the command does nothing
+    pub fn is_none(&self) -> bool {
+        self.actions.is_empty()
+    }
+
+    /// Returns true if the command stops the program
+    pub fn is_quit(&self) -> bool {
+        self.actions
+            .iter()
+            .any(|action| matches!(action, Action::Quit))
+    }
+}
+
+impl<M> From<Action<M>> for Cmd<M> {
+    fn from(action: Action<M>) -> Self {
+        Self {
+            actions: vec![action],
+        }
+    }
+}
+
+impl<M> Default for Cmd<M> {
+    fn default() -> Self {
+        Self::none()
+    }
+}
+
+impl<M: fmt::Debug> fmt::Debug for Cmd<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(&self.actions).finish()
+    }
+}
+
+impl<M: fmt::Debug> fmt::Debug for Action<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Msg(msg) => f.debug_tuple("Msg").field(msg).finish(),
+            Self::Perform(_) => f.write_str("Perform(..)"),
+            Self::Quit => f.write_str("Quit"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn none() {
+        let cmd = Cmd::<u8>::none();
+        assert!(cmd.is_none());
+        assert!(!cmd.is_quit());
+        assert_eq!(format!("{cmd:?}"), "[]");
+    }
+
+    #[test]
+    fn batch() {
+        let cmd = Cmd::batch([
+            Cmd::msg(1),
+            Cmd::none(),
+            Cmd::batch([Cmd::perform(|| 2), Cmd::quit()]),
+        ]);
+        assert!(!cmd.is_none());
+        assert!(cmd.is_quit());
+        assert_eq!(format!("{cmd:?}"), "[Msg(1), Perform(..), Quit]");
+    }
+}
@@ -0,0 +1,108 @@
+use std::{
+    fmt,
+    time::{Duration, Instant},
+};
+
+use crate::backend::event::Event;
+
+/// A subscription returned by [`Model::subscriptions`] to receive messages from outside the model
+///
+/// A subscription turns something that happens outside of the model into messages: the events of
+/// the terminal with [`Sub::events`], or the passing of time with [`Sub::tick`]. The program asks
+/// the model for its subscriptions after every update, so a model can subscribe to ticks only
+/// while an animation is running, for example.
+///
+/// # Example
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use ratatui::{
+///     app::Sub,
+///     backend::event::{Event, KeyCode},
+/// };
+///
+/// enum Msg {
+///     Key(KeyCode),
+///     Tick,
+/// }
+///
+/// let subscriptions: Vec<Sub<Msg>> = vec![
+///     Sub::events(|event| match event {
+///         Event::Key(key) => Some(Msg::Key(key.code)),
+///         _ => None,
+///     }),
+///     Sub::tick(Duration::from_millis(250), |_| Msg::Tick),
+/// ];
+/// ```
+///
+/// [`Model::subscriptions`]: super::Model::subscriptions
+pub struct Sub<M> {
+    pub(super) kind: SubKind<M>,
+}
+
+/// The source of the messages of a [`Sub`]
+pub(super) enum SubKind<M> {
+    /// Maps the events of the terminal to messages
+    Events(EventMapper<M>),
+    /// Sends a message each time the interval elapses
+    Tick {
+        interval: Duration,
+        msg: Box<dyn Fn(Instant) -> M>,
+    },
+}
+
+/// A function mapping an event to a message, if any
+type EventMapper<M> = Box<dyn Fn(&Event) -> Option<M>>;
+
+impl<M> Sub<M> {
+    /// Subscribes to the events of the terminal
+    ///
+    /// The function is called with each event and returns the message to send to the model, if
+    /// any.
+    pub fn events<F>(f: F) -> Self
+    where
+        F: Fn(&Event) -> Option<M> + 'static,
+    {
+        Self {
+            kind: SubKind::Events(Box::new(f)),
+        }
+    }
+
+    /// Subscribes to a tick that happens each time the interval elapses
+    ///
+    /// The function is called with the time of the tick and returns the message to send to the
+    /// model. The first tick happens one interval after the model first subscribes to it, and the
+    /// following ones are scheduled from it, so they do not drift when the program is busy (see
+    /// [`Tick`]). Tick subscriptions with the same interval share their timing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `interval` is zero.
+    ///
+    /// [`Tick`]: crate::backend::event::Tick
+    pub fn tick<F>(interval: Duration, f: F) -> Self
+    where
+        F: Fn(Instant) -> M + 'static,
+    {
+        assert!(
+            !interval.is_zero(),
+            "the interval of a tick must not be zero"
+        );
+        Self {
+            kind: SubKind::Tick {
+                interval,
+                msg: Box::new(f),
+            },
+        }
+    }
+}
+
+impl<M> fmt::Debug for Sub<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            SubKind::Events(_) => f.write_str("Sub::Events(..)"),
+            SubKind::Tick { interval, .. } => write!(f, "Sub::Tick({interval:?})"),
+        }
+    }
+}
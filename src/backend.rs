@@ -11,6 +11,10 @@
 //!
 //! Additionally, a [`TestBackend`] is provided for testing purposes.
 //!
+//! The [`event`] module provides a backend agnostic [`Event`](event::Event) type that the events
+//! of the supported backends can be converted into. With the `event-stream` feature enabled,
+//! [`EventStream`] reads these events asynchronously from the terminal.
+//!
 //! See the [Backend Comparison] section of the [Ratatui Website] for more details on the different
 //! backends.
 //!
@@ -104,6 +108,8 @@ use std::io;
 
 use strum::{Display, EnumString};
 
+// re-exported as the backends report and use the supported colors
+pub use crate::style::ColorSupport;
 use crate::{buffer::Cell, layout::Size, prelude::Rect};
 
 #[cfg(feature = "termion")]
@@ -121,9 +127,19 @@ mod termwiz;
 #[cfg(feature = "termwiz")]
 pub use self::termwiz::TermwizBackend;
 
+mod asciicast;
+pub use self::asciicast::AsciicastBackend;
+
+mod recording;
+pub use self::recording::{RecordedFrame, RecordingBackend};
+
 mod test;
 pub use self::test::TestBackend;
 
+pub mod event;
+#[cfg(feature = "event-stream")]
+pub use self::event::EventStream;
+
 /// Enum representing the different types of clearing operations that can be performed
 /// on the terminal screen.
 #[derive(Debug, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
@@ -153,6 +169,121 @@ pub struct WindowSize {
     pub pixels: Size,
 }
 
+/// A protocol used by terminals to display images.
+///
+/// Images can always be approximated with colored half block characters (see
+/// [`GraphicsProtocol::Halfblocks`]), but some terminals support protocols that display images at
+/// the full resolution of the screen. The protocol supported by the current terminal can be
+/// guessed from the environment with [`GraphicsProtocol::detect`].
+///
+/// See the [`Image`](crate::widgets::Image) widget for more details.
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum GraphicsProtocol {
+    /// Approximate images with colored upper and lower half block characters (`▀` and `▄`)
+    ///
+    /// This works on any terminal that supports true colors.
+    #[default]
+    Halfblocks,
+    /// The [Sixel](https://en.wikipedia.org/wiki/Sixel) graphics format
+    Sixel,
+    /// The [Kitty graphics protocol](https://sw.kovidgoyal.net/kitty/graphics-protocol/)
+    Kitty,
+    /// The [iTerm2 inline images protocol](https://iterm2.com/documentation-images.html)
+    Iterm2,
+}
+
+impl GraphicsProtocol {
+    /// Guesses the graphics protocol supported by the terminal from environment variables.
+    ///
+    /// This recognizes Kitty, iTerm2, `WezTerm` and terminals that advertise Sixel support in
+    /// `TERM`, and returns [`GraphicsProtocol::Halfblocks`] otherwise. Terminals can not be
+    /// reliably detected this way (e.g. over SSH or in a multiplexer), so applications should let
+    /// users override the result.
+    pub fn detect() -> Self {
+        Self::detect_with(|name| std::env::var(name).ok())
+    }
+
+    fn detect_with<F: Fn(&str) -> Option<String>>(var: F) -> Self {
+        let term = var("TERM").unwrap_or_default();
+        let term_program = var("TERM_PROGRAM").unwrap_or_default();
+        if var("KITTY_WINDOW_ID").is_some() || term.contains("kitty") {
+            return Self::Kitty;
+        }
+        if term_program == "iTerm.app" || term_program == "WezTerm" {
+            return Self::Iterm2;
+        }
+        if term.contains("sixel") || term == "mlterm" || term == "foot" {
+            return Self::Sixel;
+        }
+        Self::Halfblocks
+    }
+}
+
+/// The features supported by a backend and the terminal it is connected to.
+///
+/// Capabilities are returned by [`Backend::capabilities`] so that applications and widgets can
+/// degrade gracefully, e.g. by using indexed colors when true colors are not supported, or by
+/// rendering images with half blocks instead of a graphics protocol.
+///
+/// Most terminals can not be queried for their features, so the capabilities are a best guess
+/// based on what the backend library supports and on environment variables like `COLORTERM` and
+/// `TERM`. The default value assumes that none of the optional features are supported.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Capabilities {
+    /// 24-bit RGB colors ([`Color::Rgb`](crate::style::Color::Rgb)) are displayed accurately
+    pub true_color: bool,
+    /// The colors that can be displayed
+    pub color_support: ColorSupport,
+    /// Underlines can have a different color than the text
+    pub underline_color: bool,
+    /// Text can be displayed in italics
+    pub italic: bool,
+    /// Frames can be wrapped in synchronized updates to avoid tearing
+    pub synchronized_output: bool,
+    /// Mouse events can be captured
+    pub mouse: bool,
+    /// Pasted text can be reported as a single event (bracketed paste)
+    pub bracketed_paste: bool,
+    /// The title of the terminal window can be set
+    pub set_title: bool,
+    /// Text can be made clickable with hyperlinks (OSC 8)
+    pub hyperlinks: bool,
+    /// The best protocol available to display images
+    pub graphics: GraphicsProtocol,
+}
+
+impl Capabilities {
+    /// Returns true if the `COLORTERM` environment variable advertises true color support
+    pub fn true_color_from_env() -> bool {
+        std::env::var("COLORTERM").is_ok_and(|value| value == "truecolor" || value == "24bit")
+    }
+}
+
+/// Displays the escape sequence (OSC 8) that starts a hyperlink to the given URL, or that ends the
+/// current hyperlink if the URL is `None`
+#[cfg(any(feature = "crossterm", feature = "termion"))]
+struct Hyperlink<'a>(Option<&'a str>);
+
+#[cfg(any(feature = "crossterm", feature = "termion"))]
+impl std::fmt::Display for Hyperlink<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use std::fmt::Write;
+
+        f.write_str("\x1b]8;;")?;
+        // the targets of cells are sanitized, but cells can also be deserialized
+        for c in self
+            .0
+            .unwrap_or_default()
+            .chars()
+            .filter(|c| !c.is_control())
+        {
+            f.write_char(c)?;
+        }
+        f.write_str("\x1b\\")
+    }
+}
+
 /// The `Backend` trait provides an abstraction over different terminal libraries. It defines the
 /// methods required to draw content, manipulate the cursor, and clear the terminal screen.
 ///
@@ -298,6 +429,90 @@ pub trait Backend {
 
     /// Flush any buffered content to the terminal screen.
     fn flush(&mut self) -> io::Result<()>;
+
+    /// Write data to the terminal unchanged, at the current position of the cursor.
+    ///
+    /// This is used by [`Terminal`] to draw the [`Graphic`]s of a buffer, such as images displayed
+    /// with a terminal graphics protocol, which are escape sequences that cannot be stored in the
+    /// cells of the buffer.
+    ///
+    /// This method is optional and may not be implemented by all backends. The default
+    /// implementation does nothing.
+    ///
+    /// [`Terminal`]: crate::terminal::Terminal
+    /// [`Graphic`]: crate::buffer::Graphic
+    fn write_raw(&mut self, _data: &str) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Begin a synchronized update of the terminal screen.
+    ///
+    /// Terminals that support synchronized updates (DEC mode 2026) stop refreshing the screen
+    /// until [`end_synchronized_update`] is called, so that all the changes made in between are
+    /// displayed at once. This is called by [`Terminal::draw`] before drawing each frame when
+    /// [`TerminalOptions::synchronized_output`] is enabled.
+    ///
+    /// This method is optional and may not be implemented by all backends. The default
+    /// implementation does nothing.
+    ///
+    /// [`end_synchronized_update`]: Backend::end_synchronized_update
+    /// [`Terminal::draw`]: crate::terminal::Terminal::draw
+    /// [`TerminalOptions::synchronized_output`]: crate::terminal::TerminalOptions::synchronized_output
+    fn begin_synchronized_update(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// End a synchronized update of the terminal screen.
+    ///
+    /// See [`begin_synchronized_update`] for more details. The default implementation does
+    /// nothing.
+    ///
+    /// [`begin_synchronized_update`]: Backend::begin_synchronized_update
+    fn end_synchronized_update(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Restores the normal state of the terminal so that other programs can use it.
+    ///
+    /// This is called by [`Terminal::suspend`] before running another program, such as an editor
+    /// or a shell. Backends should disable raw mode, leave the alternate screen when
+    /// `alternate_screen` is true, and show the cursor. [`Backend::resume`] is called afterwards
+    /// to undo these changes.
+    ///
+    /// This method is optional and may not be implemented by all backends. The default
+    /// implementation does nothing.
+    ///
+    /// [`Terminal::suspend`]: crate::terminal::Terminal::suspend
+    fn suspend(&mut self, _alternate_screen: bool) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Restores the state of the terminal that was changed by [`Backend::suspend`].
+    ///
+    /// Backends should enable raw mode again if it was enabled before, and enter the alternate
+    /// screen when `alternate_screen` is true. The default implementation does nothing.
+    fn resume(&mut self, _alternate_screen: bool) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Get the features supported by the backend and the terminal as [`Capabilities`].
+    ///
+    /// This method is optional and may not be implemented by all backends. The default
+    /// implementation returns [`Capabilities::default()`], which assumes that none of the optional
+    /// features are supported.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, backend::TestBackend};
+    /// let backend = TestBackend::new(80, 25);
+    /// if !backend.capabilities().true_color {
+    ///     // use indexed colors instead
+    /// }
+    /// ```
+    fn capabilities(&self) -> Capabilities {
+        Capabilities::default()
+    }
 }
 
 #[cfg(test)]
@@ -306,6 +521,53 @@ mod tests {
 
     use super::*;
 
+    #[cfg(any(feature = "crossterm", feature = "termion"))]
+    #[test]
+    fn hyperlink_escape_sequence() {
+        assert_eq!(
+            Hyperlink(Some("https://ratatui.rs")).to_string(),
+            "\x1b]8;;https://ratatui.rs\x1b\\"
+        );
+        assert_eq!(
+            Hyperlink(Some("https://a\x1b\\\x07\u{9b}.rs")).to_string(),
+            "\x1b]8;;https://a\\.rs\x1b\\"
+        );
+        assert_eq!(Hyperlink(None).to_string(), "\x1b]8;;\x1b\\");
+    }
+
+    #[test]
+    fn graphics_protocol_detect() {
+        let detect = |vars: &[(&str, &str)]| {
+            GraphicsProtocol::detect_with(|name| {
+                vars.iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| (*value).to_string())
+            })
+        };
+        assert_eq!(detect(&[]), GraphicsProtocol::Halfblocks);
+        assert_eq!(detect(&[("TERM", "xterm-kitty")]), GraphicsProtocol::Kitty);
+        assert_eq!(detect(&[("KITTY_WINDOW_ID", "1")]), GraphicsProtocol::Kitty);
+        assert_eq!(
+            detect(&[("TERM_PROGRAM", "iTerm.app")]),
+            GraphicsProtocol::Iterm2
+        );
+        assert_eq!(detect(&[("TERM", "foot")]), GraphicsProtocol::Sixel);
+        assert_eq!(
+            detect(&[("TERM", "xterm-256color")]),
+            GraphicsProtocol::Halfblocks
+        );
+    }
+
+    #[test]
+    fn default_capabilities() {
+        let backend = TestBackend::new(10, 10);
+        assert_eq!(backend.capabilities(), Capabilities::default());
+        assert_eq!(
+            backend.capabilities().graphics,
+            GraphicsProtocol::Halfblocks
+        );
+    }
+
     #[test]
     fn clear_type_tostring() {
         assert_eq!(ClearType::All.to_string(), "All");
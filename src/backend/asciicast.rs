@@ -0,0 +1,370 @@
+//! This module provides the `AsciicastBackend`, which records the output of another [`Backend`] as
+//! an [asciicast v2] file that can be played with [asciinema].
+//!
+//! [asciicast v2]: https://docs.asciinema.org/manual/asciicast/v2/
+//! [asciinema]: https://asciinema.org
+
+use std::{
+    fmt::Write as _,
+    io::{self, Write},
+    time::{Instant, SystemTime, UNIX_EPOCH},
+};
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    backend::{Backend, Capabilities, ClearType, WindowSize},
+    buffer::{
+        ansi::{same_style, write_hyperlink, write_style},
+        Cell,
+    },
+    layout::Rect,
+};
+
+/// A [`Backend`] that records everything drawn to another backend as an [asciicast v2] file.
+///
+/// All the calls are forwarded to the wrapped backend, and are also converted to the escape
+/// sequences that produce the same output in a terminal. Each time the backend is flushed, which
+/// [`Terminal::draw`] does once per frame, these sequences are written to the writer as an output
+/// event with the time elapsed since the backend was created. A resize event is written when the
+/// size of the wrapped backend changes. The file can be played with `asciinema play` or embedded in
+/// a web page with the asciinema player, which makes it easy to produce demos of an application or
+/// to reproduce a bug.
+///
+/// Since the escape sequences are generated from the calls to the backend rather than copied from
+/// the output of the wrapped backend, this works with any backend, including the
+/// [`TestBackend`](crate::backend::TestBackend).
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::{fs::File, io::stdout};
+///
+/// use ratatui::{
+///     backend::{AsciicastBackend, CrosstermBackend},
+///     prelude::*,
+/// };
+///
+/// let backend = CrosstermBackend::new(stdout());
+/// let recording = File::create("demo.cast")?;
+/// let mut terminal = Terminal::new(AsciicastBackend::new(backend, recording)?)?;
+/// terminal.draw(|frame| frame.render_widget("Hello World!", frame.size()))?;
+/// # std::io::Result::Ok(())
+/// ```
+///
+/// [asciicast v2]: https://docs.asciinema.org/manual/asciicast/v2/
+/// [`Terminal::draw`]: crate::terminal::Terminal::draw
+#[derive(Debug)]
+pub struct AsciicastBackend<B: Backend, W: Write> {
+    backend: B,
+    writer: W,
+    start: Instant,
+    /// The escape sequences written since the last flush
+    output: String,
+    /// The size of the backend written in the last header or resize event
+    size: Rect,
+    /// The position of the cursor after the last cell drawn, if known
+    cursor: Option<(u16, u16)>,
+}
+
+impl<B: Backend, W: Write> AsciicastBackend<B, W> {
+    /// Creates a new `AsciicastBackend` that forwards the calls to `backend` and records them to
+    /// `writer`.
+    ///
+    /// The header of the asciicast file, which contains the size of the backend, is written
+    /// immediately.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the size of the backend cannot be queried or the header cannot be
+    /// written.
+    pub fn new(backend: B, mut writer: W) -> io::Result<Self> {
+        let size = backend.size()?;
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |duration| duration.as_secs());
+        writeln!(
+            writer,
+            "{{\"version\": 2, \"width\": {}, \"height\": {}, \"timestamp\": {timestamp}}}",
+            size.width, size.height
+        )?;
+        writer.flush()?;
+        Ok(Self {
+            backend,
+            writer,
+            start: Instant::now(),
+            output: String::new(),
+            size,
+            cursor: None,
+        })
+    }
+
+    /// Returns a reference to the wrapped backend.
+    pub const fn backend(&self) -> &B {
+        &self.backend
+    }
+
+    /// Returns a mutable reference to the wrapped backend.
+    ///
+    /// Note that the changes made directly to the wrapped backend are not recorded.
+    pub fn backend_mut(&mut self) -> &mut B {
+        &mut self.backend
+    }
+
+    /// Returns a reference to the writer that the recording is written to.
+    pub const fn writer(&self) -> &W {
+        &self.writer
+    }
+
+    /// Consumes the `AsciicastBackend` and returns the wrapped backend and the writer.
+    ///
+    /// The output that was not flushed yet is discarded.
+    pub fn into_inner(self) -> (B, W) {
+        (self.backend, self.writer)
+    }
+
+    /// Writes an event with the time elapsed since the creation of the backend
+    fn write_event(&mut self, code: &str, data: &str) -> io::Result<()> {
+        let elapsed = self.start.elapsed().as_secs_f64();
+        writeln!(
+            self.writer,
+            "[{elapsed:.6}, \"{code}\", \"{}\"]",
+            escape_json(data)
+        )
+    }
+}
+
+impl<B: Backend, W: Write> Backend for AsciicastBackend<B, W> {
+    fn draw<'a, I>(&mut self, content: I) -> io::Result<()>
+    where
+        I: Iterator<Item = (u16, u16, &'a Cell)>,
+    {
+        let content = content.collect::<Vec<_>>();
+        let default = Cell::default();
+        let mut last = &default;
+        for &(x, y, cell) in &content {
+            if self.cursor != Some((x, y)) {
+                let _ = write!(self.output, "\x1b[{};{}H", y + 1, x + 1);
+            }
+            if !same_style(cell, last) {
+                write_style(&mut self.output, cell);
+            }
+            if cell.hyperlink() != last.hyperlink() {
+                write_hyperlink(&mut self.output, cell.hyperlink());
+            }
+            self.output.push_str(cell.symbol());
+            let width = cell.symbol().width() as u16;
+            self.cursor = Some((x.saturating_add(width.max(1)), y));
+            last = cell;
+        }
+        if last.hyperlink().is_some() {
+            write_hyperlink(&mut self.output, None);
+        }
+        if !same_style(last, &default) {
+            write_style(&mut self.output, &default);
+        }
+        self.backend.draw(content.into_iter())
+    }
+
+    fn append_lines(&mut self, n: u16) -> io::Result<()> {
+        for _ in 0..n {
+            self.output.push_str("\r\n");
+        }
+        self.cursor = None;
+        self.backend.append_lines(n)
+    }
+
+    fn hide_cursor(&mut self) -> io::Result<()> {
+        self.output.push_str("\x1b[?25l");
+        self.backend.hide_cursor()
+    }
+
+    fn show_cursor(&mut self) -> io::Result<()> {
+        self.output.push_str("\x1b[?25h");
+        self.backend.show_cursor()
+    }
+
+    fn get_cursor(&mut self) -> io::Result<(u16, u16)> {
+        self.backend.get_cursor()
+    }
+
+    fn set_cursor(&mut self, x: u16, y: u16) -> io::Result<()> {
+        let _ = write!(self.output, "\x1b[{};{}H", y + 1, x + 1);
+        self.cursor = Some((x, y));
+        self.backend.set_cursor(x, y)
+    }
+
+    fn clear(&mut self) -> io::Result<()> {
+        self.output.push_str("\x1b[2J");
+        self.backend.clear()
+    }
+
+    fn clear_region(&mut self, clear_type: ClearType) -> io::Result<()> {
+        self.output.push_str(match clear_type {
+            ClearType::All => "\x1b[2J",
+            ClearType::AfterCursor => "\x1b[J",
+            ClearType::BeforeCursor => "\x1b[1J",
+            ClearType::CurrentLine => "\x1b[2K",
+            ClearType::UntilNewLine => "\x1b[K",
+        });
+        self.backend.clear_region(clear_type)
+    }
+
+    fn size(&self) -> io::Result<Rect> {
+        self.backend.size()
+    }
+
+    fn window_size(&mut self) -> io::Result<WindowSize> {
+        self.backend.window_size()
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.backend.flush()?;
+        let size = self.backend.size()?;
+        if size != self.size {
+            self.size = size;
+            self.write_event("r", &format!("{}x{}", size.width, size.height))?;
+        }
+        if !self.output.is_empty() {
+            let output = std::mem::take(&mut self.output);
+            self.write_event("o", &output)?;
+        }
+        self.writer.flush()
+    }
+
+    fn write_raw(&mut self, data: &str) -> io::Result<()> {
+        self.output.push_str(data);
+        self.backend.write_raw(data)
+    }
+
+    fn begin_synchronized_update(&mut self) -> io::Result<()> {
+        self.backend.begin_synchronized_update()
+    }
+
+    fn end_synchronized_update(&mut self) -> io::Result<()> {
+        self.backend.end_synchronized_update()
+    }
+
+    fn suspend(&mut self, alternate_screen: bool) -> io::Result<()> {
+        self.backend.suspend(alternate_screen)
+    }
+
+    fn resume(&mut self, alternate_screen: bool) -> io::Result<()> {
+        self.cursor = None;
+        self.backend.resume(alternate_screen)
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        self.backend.capabilities()
+    }
+}
+
+/// Escapes a string to be written in a JSON string literal
+fn escape_json(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(escaped, "\\u{:04x}", c as u32);
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::{
+        backend::TestBackend,
+        style::Stylize,
+        terminal::Terminal,
+        text::Line,
+        widgets::{Paragraph, Widget},
+    };
+
+    /// Returns the lines of the recording without the timestamp and the elapsed times
+    fn events(backend: &AsciicastBackend<TestBackend, Vec<u8>>) -> Vec<String> {
+        let recording = String::from_utf8(backend.writer().clone()).unwrap();
+        recording
+            .lines()
+            .map(|line| {
+                if let Some(header) = line.strip_prefix('{') {
+                    format!("{{{}", &header[..header.find(", \"timestamp\"").unwrap()])
+                } else {
+                    format!("[{}", &line[line.find(',').unwrap()..])
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn header() {
+        let backend = AsciicastBackend::new(TestBackend::new(4, 2), vec![]).unwrap();
+        assert_eq!(
+            events(&backend),
+            [r#"{"version": 2, "width": 4, "height": 2"#]
+        );
+        let recording = String::from_utf8(backend.writer().clone()).unwrap();
+        assert!(recording.ends_with("}\n"));
+    }
+
+    #[test]
+    fn records_frames() {
+        let backend = AsciicastBackend::new(TestBackend::new(4, 2), vec![]).unwrap();
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| {
+                Paragraph::new(Line::from(vec!["a".red(), "b".into()]))
+                    .render(f.size(), f.buffer_mut());
+            })
+            .unwrap();
+        terminal
+            .draw(|f| Paragraph::new("ac").render(f.size(), f.buffer_mut()))
+            .unwrap();
+        terminal
+            .backend()
+            .backend()
+            .assert_buffer_lines(["ac  ", "    "]);
+        assert_eq!(
+            events(terminal.backend()),
+            [
+                r#"{"version": 2, "width": 4, "height": 2"#,
+                r#"[, "o", "\u001b[1;1H\u001b[0;31ma\u001b[0mb\u001b[?25l"]"#,
+                r#"[, "o", "\u001b[1;1Hac\u001b[?25l"]"#,
+            ]
+        );
+    }
+
+    #[test]
+    fn records_resize() {
+        let mut backend = AsciicastBackend::new(TestBackend::new(4, 2), vec![]).unwrap();
+        backend.backend_mut().resize(3, 1);
+        backend.clear().unwrap();
+        backend.flush().unwrap();
+        assert_eq!(
+            events(&backend),
+            [
+                r#"{"version": 2, "width": 4, "height": 2"#,
+                r#"[, "r", "3x1"]"#,
+                r#"[, "o", "\u001b[2J"]"#,
+            ]
+        );
+    }
+
+    #[test]
+    fn escape_json_string() {
+        assert_eq!(
+            escape_json("a\"b\\c\n\r\t\x1b\u{7f}é"),
+            r#"a\"b\\c\n\r\t\u001b\u007fé"#
+        );
+    }
+}
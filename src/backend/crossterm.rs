@@ -8,16 +8,22 @@ use std::io::{self, Write};
 use crossterm::style::SetUnderlineColor;
 use crossterm::{
     cursor::{Hide, MoveTo, Show},
+    event::{DisableBracketedPaste, DisableFocusChange, EnableBracketedPaste, EnableFocusChange},
     execute, queue,
     style::{
         Attribute as CAttribute, Attributes as CAttributes, Color as CColor, Colors, ContentStyle,
         Print, SetAttribute, SetBackgroundColor, SetColors, SetForegroundColor,
     },
-    terminal::{self, Clear},
+    terminal::{
+        self, BeginSynchronizedUpdate, Clear, EndSynchronizedUpdate, EnterAlternateScreen,
+        LeaveAlternateScreen,
+    },
 };
 
 use crate::{
-    backend::{Backend, ClearType, WindowSize},
+    backend::{
+        Backend, Capabilities, ClearType, ColorSupport, GraphicsProtocol, Hyperlink, WindowSize,
+    },
     buffer::Cell,
     layout::Size,
     prelude::Rect,
@@ -82,6 +88,16 @@ use crate::{
 pub struct CrosstermBackend<W: Write> {
     /// The writer used to send commands to the terminal.
     writer: W,
+    /// Whether raw mode was enabled when the terminal was suspended
+    suspended_raw_mode: bool,
+    /// Whether bracketed paste was enabled with [`CrosstermBackend::enable_bracketed_paste`]
+    bracketed_paste: bool,
+    /// Whether focus change reporting was enabled with [`CrosstermBackend::enable_focus_change`]
+    focus_change: bool,
+    /// The colors that the output is adapted to, or `None` to detect legacy consoles
+    color_support: Option<ColorSupport>,
+    /// Whether box drawing symbols are replaced by ASCII, or `None` to detect legacy consoles
+    ascii_symbols: Option<bool>,
 }
 
 impl<W> CrosstermBackend<W>
@@ -98,7 +114,117 @@ where
     /// let backend = CrosstermBackend::new(stdout());
     /// ```
     pub const fn new(writer: W) -> Self {
-        Self { writer }
+        Self {
+            writer,
+            suspended_raw_mode: false,
+            bracketed_paste: false,
+            focus_change: false,
+            color_support: None,
+            ascii_symbols: None,
+        }
+    }
+
+    /// Sets the colors that the output is adapted to.
+    ///
+    /// The colors of the cells are converted with [`ColorSupport::adapt`] before they are sent to
+    /// the terminal, so e.g. RGB colors are displayed as the closest named color with
+    /// [`ColorSupport::Ansi16`]. By default, the colors are converted to the named colors on the
+    /// legacy Windows console, which does not support other colors, and sent unchanged otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use std::io::stdout;
+    /// # use ratatui::{backend::ColorSupport, prelude::*};
+    /// let backend = CrosstermBackend::new(stdout()).with_color_support(ColorSupport::Ansi256);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_color_support(mut self, color_support: ColorSupport) -> Self {
+        self.color_support = Some(color_support);
+        self
+    }
+
+    /// Sets whether box drawing symbols are replaced by ASCII characters.
+    ///
+    /// When enabled, the symbols used to draw lines and borders (e.g. `│`, `─` and `┌`) are
+    /// replaced by `|`, `-` and `+` before they are sent to the terminal, for terminals or fonts
+    /// that cannot display them. By default, they are replaced on the legacy Windows console and
+    /// sent unchanged otherwise.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_ascii_symbols(mut self, ascii_symbols: bool) -> Self {
+        self.ascii_symbols = Some(ascii_symbols);
+        self
+    }
+
+    /// Enables bracketed paste.
+    ///
+    /// When bracketed paste is enabled, the terminal reports pasted text as a single
+    /// [`Event::Paste`] instead of a sequence of key events, so applications can tell pasted input
+    /// apart from typed input (e.g. to avoid submitting a form on a pasted newline). It is disabled
+    /// while the terminal is suspended and enabled again when it resumes.
+    ///
+    /// Call [`CrosstermBackend::disable_bracketed_paste`] before the application exits, or use
+    /// [`restore`](crate::restore) which disables it.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use std::io::stdout;
+    /// # use ratatui::prelude::*;
+    /// let mut backend = CrosstermBackend::new(stdout());
+    /// backend.enable_bracketed_paste()?;
+    /// # std::io::Result::Ok(())
+    /// ```
+    ///
+    /// [`Event::Paste`]: crate::backend::event::Event::Paste
+    pub fn enable_bracketed_paste(&mut self) -> io::Result<()> {
+        execute!(self.writer, EnableBracketedPaste)?;
+        self.bracketed_paste = true;
+        Ok(())
+    }
+
+    /// Disables bracketed paste enabled with [`CrosstermBackend::enable_bracketed_paste`].
+    pub fn disable_bracketed_paste(&mut self) -> io::Result<()> {
+        execute!(self.writer, DisableBracketedPaste)?;
+        self.bracketed_paste = false;
+        Ok(())
+    }
+
+    /// Returns true if bracketed paste was enabled with
+    /// [`CrosstermBackend::enable_bracketed_paste`].
+    pub const fn is_bracketed_paste_enabled(&self) -> bool {
+        self.bracketed_paste
+    }
+
+    /// Enables focus change reporting.
+    ///
+    /// When focus change reporting is enabled, the terminal reports [`Event::FocusGained`] and
+    /// [`Event::FocusLost`] events when its window gains or loses focus, so applications can e.g.
+    /// pause animations or stop polling while they are not visible. It is disabled while the
+    /// terminal is suspended and enabled again when it resumes.
+    ///
+    /// Call [`CrosstermBackend::disable_focus_change`] before the application exits, or use
+    /// [`restore`](crate::restore) which disables it.
+    ///
+    /// [`Event::FocusGained`]: crate::backend::event::Event::FocusGained
+    /// [`Event::FocusLost`]: crate::backend::event::Event::FocusLost
+    pub fn enable_focus_change(&mut self) -> io::Result<()> {
+        execute!(self.writer, EnableFocusChange)?;
+        self.focus_change = true;
+        Ok(())
+    }
+
+    /// Disables focus change reporting enabled with [`CrosstermBackend::enable_focus_change`].
+    pub fn disable_focus_change(&mut self) -> io::Result<()> {
+        execute!(self.writer, DisableFocusChange)?;
+        self.focus_change = false;
+        Ok(())
+    }
+
+    /// Returns true if focus change reporting was enabled with
+    /// [`CrosstermBackend::enable_focus_change`].
+    pub const fn is_focus_change_enabled(&self) -> bool {
+        self.focus_change
     }
 
     /// Gets the writer.
@@ -146,11 +272,19 @@ where
     where
         I: Iterator<Item = (u16, u16, &'a Cell)>,
     {
+        let legacy = self.color_support.is_none() && legacy_console();
+        let color_support = self.color_support.unwrap_or(if legacy {
+            ColorSupport::Ansi16
+        } else {
+            ColorSupport::TrueColor
+        });
+        let ascii_symbols = self.ascii_symbols.unwrap_or_else(legacy_console);
         let mut fg = Color::Reset;
         let mut bg = Color::Reset;
         #[cfg(feature = "underline-color")]
         let mut underline_color = Color::Reset;
         let mut modifier = Modifier::empty();
+        let mut hyperlink = None;
         let mut last_pos: Option<(u16, u16)> = None;
         for (x, y, cell) in content {
             // Move the cursor if the previous location was not (x - 1, y)
@@ -166,22 +300,29 @@ where
                 diff.queue(&mut self.writer)?;
                 modifier = cell.modifier;
             }
-            if cell.fg != fg || cell.bg != bg {
-                queue!(
-                    self.writer,
-                    SetColors(Colors::new(cell.fg.into(), cell.bg.into()))
-                )?;
-                fg = cell.fg;
-                bg = cell.bg;
+            let colors = (color_support.adapt(cell.fg), color_support.adapt(cell.bg));
+            if colors != (fg, bg) {
+                (fg, bg) = colors;
+                queue!(self.writer, SetColors(Colors::new(fg.into(), bg.into())))?;
             }
             #[cfg(feature = "underline-color")]
-            if cell.underline_color != underline_color {
-                let color = CColor::from(cell.underline_color);
-                queue!(self.writer, SetUnderlineColor(color))?;
-                underline_color = cell.underline_color;
+            if color_support.adapt(cell.underline_color) != underline_color {
+                underline_color = color_support.adapt(cell.underline_color);
+                queue!(self.writer, SetUnderlineColor(underline_color.into()))?;
+            }
+            if cell.hyperlink() != hyperlink {
+                queue!(self.writer, Print(Hyperlink(cell.hyperlink())))?;
+                hyperlink = cell.hyperlink();
             }
 
-            queue!(self.writer, Print(cell.symbol()))?;
+            if ascii_symbols {
+                queue!(self.writer, Print(ascii_symbol(cell.symbol())))?;
+            } else {
+                queue!(self.writer, Print(cell.symbol()))?;
+            }
+        }
+        if hyperlink.is_some() {
+            queue!(self.writer, Print(Hyperlink(None)))?;
         }
 
         #[cfg(feature = "underline-color")]
@@ -266,6 +407,100 @@ where
     fn flush(&mut self) -> io::Result<()> {
         self.writer.flush()
     }
+
+    fn write_raw(&mut self, data: &str) -> io::Result<()> {
+        self.writer.write_all(data.as_bytes())
+    }
+
+    fn begin_synchronized_update(&mut self) -> io::Result<()> {
+        queue!(self.writer, BeginSynchronizedUpdate)
+    }
+
+    fn end_synchronized_update(&mut self) -> io::Result<()> {
+        queue!(self.writer, EndSynchronizedUpdate)
+    }
+
+    fn suspend(&mut self, alternate_screen: bool) -> io::Result<()> {
+        self.suspended_raw_mode = terminal::is_raw_mode_enabled()?;
+        if self.suspended_raw_mode {
+            terminal::disable_raw_mode()?;
+        }
+        if self.bracketed_paste {
+            queue!(self.writer, DisableBracketedPaste)?;
+        }
+        if self.focus_change {
+            queue!(self.writer, DisableFocusChange)?;
+        }
+        if alternate_screen {
+            queue!(self.writer, LeaveAlternateScreen)?;
+        }
+        execute!(self.writer, Show)
+    }
+
+    fn resume(&mut self, alternate_screen: bool) -> io::Result<()> {
+        if self.suspended_raw_mode {
+            terminal::enable_raw_mode()?;
+        }
+        if alternate_screen {
+            queue!(self.writer, EnterAlternateScreen)?;
+        }
+        if self.bracketed_paste {
+            queue!(self.writer, EnableBracketedPaste)?;
+        }
+        if self.focus_change {
+            queue!(self.writer, EnableFocusChange)?;
+        }
+        self.writer.flush()
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            true_color: Capabilities::true_color_from_env(),
+            color_support: self.color_support.unwrap_or_else(|| {
+                if legacy_console() {
+                    ColorSupport::Ansi16
+                } else {
+                    ColorSupport::detect()
+                }
+            }),
+            underline_color: cfg!(feature = "underline-color"),
+            italic: true,
+            synchronized_output: true,
+            mouse: true,
+            bracketed_paste: true,
+            set_title: true,
+            hyperlinks: true,
+            graphics: GraphicsProtocol::detect(),
+        }
+    }
+}
+
+/// Returns true if the terminal is the legacy Windows console, which does not support ANSI escape
+/// sequences nor colors other than the 16 named colors
+///
+/// Crossterm uses the Windows console API instead of escape sequences in this case.
+#[cfg(windows)]
+fn legacy_console() -> bool {
+    !crossterm::ansi_support::supports_ansi()
+}
+
+/// Returns true if the terminal is the legacy Windows console, which is never the case on other
+/// platforms
+#[cfg(not(windows))]
+const fn legacy_console() -> bool {
+    false
+}
+
+/// Replaces the box drawing symbols used for lines and borders by an ASCII character
+fn ascii_symbol(symbol: &str) -> &str {
+    match symbol {
+        "│" | "┃" | "║" | "╎" | "╏" | "┆" | "┇" | "┊" | "┋" => "|",
+        "─" | "━" | "═" | "╌" | "╍" | "┄" | "┅" | "┈" | "┉" => "-",
+        "┌" | "┐" | "└" | "┘" | "├" | "┤" | "┬" | "┴" | "┼" | "╭" | "╮" | "╯" | "╰" | "┏" | "┓"
+        | "┗" | "┛" | "┣" | "┫" | "┳" | "┻" | "╋" | "╔" | "╗" | "╚" | "╝" | "╠" | "╣" | "╦"
+        | "╩" | "╬" => "+",
+        _ => symbol,
+    }
 }
 
 impl From<Color> for CColor {
@@ -347,7 +582,8 @@ impl ModifierDiff {
         if removed.contains(Modifier::ITALIC) {
             queue!(w, SetAttribute(CAttribute::NoItalic))?;
         }
-        if removed.contains(Modifier::UNDERLINED) {
+        let underline = self.to.underline();
+        if underline.is_none() && self.from.underline().is_some() {
             queue!(w, SetAttribute(CAttribute::NoUnderline))?;
         }
         if removed.contains(Modifier::DIM) {
@@ -370,8 +606,18 @@ impl ModifierDiff {
         if added.contains(Modifier::ITALIC) {
             queue!(w, SetAttribute(CAttribute::Italic))?;
         }
-        if added.contains(Modifier::UNDERLINED) {
-            queue!(w, SetAttribute(CAttribute::Underlined))?;
+        if underline != self.from.underline() {
+            let attribute = match underline {
+                Some(Modifier::DOUBLE_UNDERLINED) => Some(CAttribute::DoubleUnderlined),
+                Some(Modifier::CURLY_UNDERLINED) => Some(CAttribute::Undercurled),
+                Some(Modifier::DOTTED_UNDERLINED) => Some(CAttribute::Underdotted),
+                Some(Modifier::DASHED_UNDERLINED) => Some(CAttribute::Underdashed),
+                Some(_) => Some(CAttribute::Underlined),
+                None => None,
+            };
+            if let Some(attribute) = attribute {
+                queue!(w, SetAttribute(attribute))?;
+            }
         }
         if added.contains(Modifier::DIM) {
             queue!(w, SetAttribute(CAttribute::Dim))?;
@@ -412,14 +658,21 @@ impl From<CAttributes> for Modifier {
         if value.has(CAttribute::Italic) {
             res |= Self::ITALIC;
         }
-        if value.has(CAttribute::Underlined)
-            || value.has(CAttribute::DoubleUnderlined)
-            || value.has(CAttribute::Undercurled)
-            || value.has(CAttribute::Underdotted)
-            || value.has(CAttribute::Underdashed)
-        {
+        if value.has(CAttribute::Underlined) {
             res |= Self::UNDERLINED;
         }
+        if value.has(CAttribute::DoubleUnderlined) {
+            res |= Self::DOUBLE_UNDERLINED;
+        }
+        if value.has(CAttribute::Undercurled) {
+            res |= Self::CURLY_UNDERLINED;
+        }
+        if value.has(CAttribute::Underdotted) {
+            res |= Self::DOTTED_UNDERLINED;
+        }
+        if value.has(CAttribute::Underdashed) {
+            res |= Self::DASHED_UNDERLINED;
+        }
         if value.has(CAttribute::SlowBlink) {
             res |= Self::SLOW_BLINK;
         }
@@ -454,7 +707,11 @@ impl From<ContentStyle> for Style {
             sub_modifier |= Modifier::CROSSED_OUT;
         }
         if value.attributes.has(CAttribute::NoUnderline) {
-            sub_modifier |= Modifier::UNDERLINED;
+            sub_modifier |= Modifier::UNDERLINED
+                | Modifier::DOUBLE_UNDERLINED
+                | Modifier::CURLY_UNDERLINED
+                | Modifier::DOTTED_UNDERLINED
+                | Modifier::DASHED_UNDERLINED;
         }
         if value.attributes.has(CAttribute::NoHidden) {
             sub_modifier |= Modifier::HIDDEN;
@@ -481,6 +738,65 @@ impl From<ContentStyle> for Style {
 mod tests {
     use super::*;
 
+    #[test]
+    fn draw_with_color_support() {
+        let mut backend =
+            CrosstermBackend::new(Vec::new()).with_color_support(ColorSupport::Ansi16);
+        let mut cell = Cell::default();
+        cell.set_symbol("a").set_fg(Color::Rgb(250, 0, 0));
+        backend.draw([(0, 0, &cell)].into_iter()).unwrap();
+        let output = String::from_utf8(backend.writer).unwrap();
+        assert!(output.contains("\x1b[38;5;9m"), "{output:?}");
+    }
+
+    #[test]
+    fn draw_with_ascii_symbols() {
+        let mut backend = CrosstermBackend::new(Vec::new()).with_ascii_symbols(true);
+        let mut cell = Cell::default();
+        cell.set_symbol("┌");
+        backend.draw([(0, 0, &cell)].into_iter()).unwrap();
+        let output = String::from_utf8(backend.writer).unwrap();
+        assert!(output.contains('+') && !output.contains('┌'), "{output:?}");
+    }
+
+    #[test]
+    fn ascii_symbols() {
+        assert_eq!(ascii_symbol("│"), "|");
+        assert_eq!(ascii_symbol("═"), "-");
+        assert_eq!(ascii_symbol("╭"), "+");
+        assert_eq!(ascii_symbol("a"), "a");
+    }
+
+    #[test]
+    fn bracketed_paste() {
+        let mut backend = CrosstermBackend::new(Vec::new());
+        backend.enable_bracketed_paste().unwrap();
+        assert!(backend.is_bracketed_paste_enabled());
+        backend.disable_bracketed_paste().unwrap();
+        assert!(!backend.is_bracketed_paste_enabled());
+        assert_eq!(backend.writer, b"\x1b[?2004h\x1b[?2004l");
+    }
+
+    #[test]
+    fn focus_change() {
+        let mut backend = CrosstermBackend::new(Vec::new());
+        backend.enable_focus_change().unwrap();
+        assert!(backend.is_focus_change_enabled());
+        backend.disable_focus_change().unwrap();
+        assert!(!backend.is_focus_change_enabled());
+        assert_eq!(backend.writer, b"\x1b[?1004h\x1b[?1004l");
+    }
+
+    #[test]
+    fn resume_enables_input_modes_again() {
+        let mut backend = CrosstermBackend::new(Vec::new());
+        backend.enable_bracketed_paste().unwrap();
+        backend.enable_focus_change().unwrap();
+        backend.writer.clear();
+        backend.resume(false).unwrap();
+        assert_eq!(backend.writer, b"\x1b[?2004h\x1b[?1004h");
+    }
+
     #[test]
     fn from_crossterm_color() {
         assert_eq!(Color::from(CColor::Reset), Color::Reset);
@@ -527,11 +843,19 @@ mod tests {
             assert_eq!(Modifier::from(CAttribute::Underlined), Modifier::UNDERLINED);
             assert_eq!(
                 Modifier::from(CAttribute::DoubleUnderlined),
-                Modifier::UNDERLINED
+                Modifier::DOUBLE_UNDERLINED
+            );
+            assert_eq!(
+                Modifier::from(CAttribute::Undercurled),
+                Modifier::CURLY_UNDERLINED
             );
             assert_eq!(
                 Modifier::from(CAttribute::Underdotted),
-                Modifier::UNDERLINED
+                Modifier::DOTTED_UNDERLINED
+            );
+            assert_eq!(
+                Modifier::from(CAttribute::Underdashed),
+                Modifier::DASHED_UNDERLINED
             );
             assert_eq!(Modifier::from(CAttribute::Dim), Modifier::DIM);
             assert_eq!(
@@ -576,7 +900,7 @@ mod tests {
                 Modifier::from(CAttributes::from(
                     [CAttribute::Dim, CAttribute::Underdotted].as_ref()
                 )),
-                Modifier::DIM | Modifier::UNDERLINED
+                Modifier::DIM | Modifier::DOTTED_UNDERLINED
             );
             assert_eq!(
                 Modifier::from(CAttributes::from(
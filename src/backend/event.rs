@@ -0,0 +1,829 @@
+//! Backend agnostic terminal events.
+//!
+//! Each backend library reports input using its own event types. This module provides a unified
+//! [`Event`] type that can be converted from the events of the supported backends, so that
+//! applications can handle keyboard, mouse, resize, paste and focus events without matching on the
+//! types of a specific backend library.
+//!
+//! The conversions are available when the corresponding backend feature is enabled:
+//! - [Crossterm]: `From<crossterm::event::Event> for Event`
+//! - [Termion]: `TryFrom<termion::event::Event> for Event` (unsupported events are rejected)
+//!
+//! The events of [Termwiz] are not supported: applications using the `TermwizBackend` have to read
+//! and handle the input events of Termwiz directly.
+//!
+//! With the `event-stream` feature, an [`EventStream`] that implements [`futures_core::Stream`] is
+//! also available to read events asynchronously from the terminal with Crossterm or Termion.
+//!
+//! A [`Tick`] produces periodic [`Event::Tick`] events, e.g. to drive animations, and can be merged
+//! into an [`EventStream`] with [`EventStream::with_tick`]. A tick does not depend on a backend, so
+//! applications using Termwiz can use [`Tick::poll`] or [`EventStream::tick`] alongside the events
+//! they read from Termwiz.
+//!
+//! Not every backend reports every kind of event. For example Termion does not report resize,
+//! paste or focus events, and does not report which mouse button was released.
+//!
+//! [Crossterm]: https://crates.io/crates/crossterm
+//! [Termion]: https://crates.io/crates/termion
+//! [Termwiz]: https://crates.io/crates/termwiz
+#[cfg(feature = "event-stream")]
+use std::{
+    io,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use bitflags::bitflags;
+
+mod tick;
+
+pub use self::tick::{Tick, TickEvent};
+
+/// A terminal event
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::backend::event::{Event, KeyCode, KeyEvent};
+///
+/// # fn handle(event: Event) -> bool {
+/// match event {
+///     Event::Key(KeyEvent {
+///         code: KeyCode::Char('q'),
+///         ..
+///     }) => return false,
+///     Event::Resize(width, height) => println!("resized to {width}x{height}"),
+///     _ => {}
+/// }
+/// # true
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Event {
+    /// A key was pressed, repeated or released
+    Key(KeyEvent),
+    /// A mouse button was pressed or released, the mouse moved or the wheel scrolled
+    Mouse(MouseEvent),
+    /// The terminal was resized to the given width and height
+    Resize(u16, u16),
+    /// Some text was pasted into the terminal
+    ///
+    /// This requires bracketed paste to be enabled, e.g. with
+    /// `CrosstermBackend::enable_bracketed_paste`. Otherwise the pasted text is reported as key
+    /// events.
+    Paste(String),
+    /// The terminal gained focus
+    ///
+    /// This requires focus change reporting to be enabled, e.g. with
+    /// `CrosstermBackend::enable_focus_change`.
+    FocusGained,
+    /// The terminal lost focus
+    ///
+    /// This requires focus change reporting to be enabled, e.g. with
+    /// `CrosstermBackend::enable_focus_change`.
+    FocusLost,
+    /// A [`Tick`] happened
+    ///
+    /// This is never reported by a backend, only by an [`EventStream`] with a tick, see
+    /// [`EventStream::with_tick`].
+    Tick(TickEvent),
+}
+
+/// A keyboard event
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KeyEvent {
+    /// The key
+    pub code: KeyCode,
+    /// The modifiers held while the key was pressed
+    pub modifiers: KeyModifiers,
+    /// Whether the key was pressed, repeated or released
+    pub kind: KeyEventKind,
+}
+
+impl KeyEvent {
+    /// Creates a new key press event
+    pub const fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
+        Self {
+            code,
+            modifiers,
+            kind: KeyEventKind::Press,
+        }
+    }
+}
+
+impl From<KeyCode> for KeyEvent {
+    fn from(code: KeyCode) -> Self {
+        Self::new(code, KeyModifiers::NONE)
+    }
+}
+
+/// A key on the keyboard
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum KeyCode {
+    /// Backspace key
+    Backspace,
+    /// Enter key
+    Enter,
+    /// Left arrow key
+    Left,
+    /// Right arrow key
+    Right,
+    /// Up arrow key
+    Up,
+    /// Down arrow key
+    Down,
+    /// Home key
+    Home,
+    /// End key
+    End,
+    /// Page up key
+    PageUp,
+    /// Page down key
+    PageDown,
+    /// Tab key
+    Tab,
+    /// Shift + Tab key
+    BackTab,
+    /// Delete key
+    Delete,
+    /// Insert key
+    Insert,
+    /// Function key (e.g. `F(1)` for F1)
+    F(u8),
+    /// A character
+    Char(char),
+    /// Escape key
+    Esc,
+    /// A key that has no equivalent in this enum (e.g. media keys or the null byte)
+    Null,
+}
+
+bitflags! {
+    /// The modifier keys held during a [`KeyEvent`] or a [`MouseEvent`]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+    pub struct KeyModifiers: u8 {
+        /// No modifier
+        const NONE    = 0b0000_0000;
+        /// Shift key
+        const SHIFT   = 0b0000_0001;
+        /// Control key
+        const CONTROL = 0b0000_0010;
+        /// Alt key
+        const ALT     = 0b0000_0100;
+        /// Super key (e.g. Windows or Command key)
+        const SUPER   = 0b0000_1000;
+    }
+}
+
+/// The kind of a [`KeyEvent`]
+///
+/// Only some backends and terminals report repeated and released keys.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum KeyEventKind {
+    /// The key was pressed
+    #[default]
+    Press,
+    /// The key is held down and repeated
+    Repeat,
+    /// The key was released
+    Release,
+}
+
+/// A mouse event
+///
+/// The column and row are zero-based and relative to the top left corner of the terminal.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MouseEvent {
+    /// What happened
+    pub kind: MouseEventKind,
+    /// The column of the mouse cursor
+    pub column: u16,
+    /// The row of the mouse cursor
+    pub row: u16,
+    /// The modifiers held during the event
+    pub modifiers: KeyModifiers,
+}
+
+/// The kind of a [`MouseEvent`]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MouseEventKind {
+    /// A button was pressed
+    Down(MouseButton),
+    /// A button was released
+    Up(MouseButton),
+    /// The mouse moved while a button was pressed
+    Drag(MouseButton),
+    /// The mouse moved while no button was pressed
+    Moved,
+    /// The wheel scrolled down
+    ScrollDown,
+    /// The wheel scrolled up
+    ScrollUp,
+    /// The wheel scrolled left
+    ScrollLeft,
+    /// The wheel scrolled right
+    ScrollRight,
+}
+
+/// A mouse button
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MouseButton {
+    /// The left mouse button
+    Left,
+    /// The right mouse button
+    Right,
+    /// The middle mouse button
+    Middle,
+}
+
+#[cfg(feature = "crossterm")]
+mod crossterm_conversions {
+    use ::crossterm::event as ct;
+
+    use super::*;
+
+    impl From<ct::Event> for Event {
+        fn from(event: ct::Event) -> Self {
+            match event {
+                ct::Event::Key(event) => Self::Key(event.into()),
+                ct::Event::Mouse(event) => Self::Mouse(event.into()),
+                ct::Event::Resize(width, height) => Self::Resize(width, height),
+                ct::Event::Paste(text) => Self::Paste(text),
+                ct::Event::FocusGained => Self::FocusGained,
+                ct::Event::FocusLost => Self::FocusLost,
+            }
+        }
+    }
+
+    impl From<ct::KeyEvent> for KeyEvent {
+        fn from(event: ct::KeyEvent) -> Self {
+            Self {
+                code: event.code.into(),
+                modifiers: event.modifiers.into(),
+                kind: match event.kind {
+                    ct::KeyEventKind::Press => KeyEventKind::Press,
+                    ct::KeyEventKind::Repeat => KeyEventKind::Repeat,
+                    ct::KeyEventKind::Release => KeyEventKind::Release,
+                },
+            }
+        }
+    }
+
+    impl From<ct::KeyCode> for KeyCode {
+        fn from(code: ct::KeyCode) -> Self {
+            match code {
+                ct::KeyCode::Backspace => Self::Backspace,
+                ct::KeyCode::Enter => Self::Enter,
+                ct::KeyCode::Left => Self::Left,
+                ct::KeyCode::Right => Self::Right,
+                ct::KeyCode::Up => Self::Up,
+                ct::KeyCode::Down => Self::Down,
+                ct::KeyCode::Home => Self::Home,
+                ct::KeyCode::End => Self::End,
+                ct::KeyCode::PageUp => Self::PageUp,
+                ct::KeyCode::PageDown => Self::PageDown,
+                ct::KeyCode::Tab => Self::Tab,
+                ct::KeyCode::BackTab => Self::BackTab,
+                ct::KeyCode::Delete => Self::Delete,
+                ct::KeyCode::Insert => Self::Insert,
+                ct::KeyCode::F(n) => Self::F(n),
+                ct::KeyCode::Char(c) => Self::Char(c),
+                ct::KeyCode::Esc => Self::Esc,
+                _ => Self::Null,
+            }
+        }
+    }
+
+    impl From<ct::KeyModifiers> for KeyModifiers {
+        fn from(modifiers: ct::KeyModifiers) -> Self {
+            let mut result = Self::NONE;
+            result.set(Self::SHIFT, modifiers.contains(ct::KeyModifiers::SHIFT));
+            result.set(Self::CONTROL, modifiers.contains(ct::KeyModifiers::CONTROL));
+            result.set(Self::ALT, modifiers.contains(ct::KeyModifiers::ALT));
+            result.set(Self::SUPER, modifiers.contains(ct::KeyModifiers::SUPER));
+            result
+        }
+    }
+
+    impl From<ct::MouseEvent> for MouseEvent {
+        fn from(event: ct::MouseEvent) -> Self {
+            Self {
+                kind: match event.kind {
+                    ct::MouseEventKind::Down(button) => MouseEventKind::Down(button.into()),
+                    ct::MouseEventKind::Up(button) => MouseEventKind::Up(button.into()),
+                    ct::MouseEventKind::Drag(button) => MouseEventKind::Drag(button.into()),
+                    ct::MouseEventKind::Moved => MouseEventKind::Moved,
+                    ct::MouseEventKind::ScrollDown => MouseEventKind::ScrollDown,
+                    ct::MouseEventKind::ScrollUp => MouseEventKind::ScrollUp,
+                    ct::MouseEventKind::ScrollLeft => MouseEventKind::ScrollLeft,
+                    ct::MouseEventKind::ScrollRight => MouseEventKind::ScrollRight,
+                },
+                column: event.column,
+                row: event.row,
+                modifiers: event.modifiers.into(),
+            }
+        }
+    }
+
+    impl From<ct::MouseButton> for MouseButton {
+        fn from(button: ct::MouseButton) -> Self {
+            match button {
+                ct::MouseButton::Left => Self::Left,
+                ct::MouseButton::Right => Self::Right,
+                ct::MouseButton::Middle => Self::Middle,
+            }
+        }
+    }
+}
+
+/// An error returned when a backend event has no equivalent [`Event`]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct UnsupportedEvent;
+
+impl std::fmt::Display for UnsupportedEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("unsupported terminal event")
+    }
+}
+
+impl std::error::Error for UnsupportedEvent {}
+
+#[cfg(feature = "termion")]
+mod termion_conversions {
+    use ::termion::event as tm;
+
+    use super::*;
+
+    impl TryFrom<tm::Event> for Event {
+        type Error = UnsupportedEvent;
+
+        fn try_from(event: tm::Event) -> Result<Self, Self::Error> {
+            match event {
+                tm::Event::Key(key) => Ok(Self::Key(key.try_into()?)),
+                tm::Event::Mouse(event) => Ok(Self::Mouse(event.into())),
+                tm::Event::Unsupported(_) => Err(UnsupportedEvent),
+            }
+        }
+    }
+
+    impl TryFrom<tm::Key> for KeyEvent {
+        type Error = UnsupportedEvent;
+
+        fn try_from(key: tm::Key) -> Result<Self, Self::Error> {
+            let (code, modifiers) = match key {
+                tm::Key::Backspace => (KeyCode::Backspace, KeyModifiers::NONE),
+                tm::Key::Left => (KeyCode::Left, KeyModifiers::NONE),
+                tm::Key::Right => (KeyCode::Right, KeyModifiers::NONE),
+                tm::Key::Up => (KeyCode::Up, KeyModifiers::NONE),
+                tm::Key::Down => (KeyCode::Down, KeyModifiers::NONE),
+                tm::Key::Home => (KeyCode::Home, KeyModifiers::NONE),
+                tm::Key::End => (KeyCode::End, KeyModifiers::NONE),
+                tm::Key::PageUp => (KeyCode::PageUp, KeyModifiers::NONE),
+                tm::Key::PageDown => (KeyCode::PageDown, KeyModifiers::NONE),
+                tm::Key::BackTab => (KeyCode::BackTab, KeyModifiers::SHIFT),
+                tm::Key::Delete => (KeyCode::Delete, KeyModifiers::NONE),
+                tm::Key::Insert => (KeyCode::Insert, KeyModifiers::NONE),
+                tm::Key::F(n) => (KeyCode::F(n), KeyModifiers::NONE),
+                tm::Key::Char('\n') => (KeyCode::Enter, KeyModifiers::NONE),
+                tm::Key::Char('\t') => (KeyCode::Tab, KeyModifiers::NONE),
+                tm::Key::Char(c) => (KeyCode::Char(c), KeyModifiers::NONE),
+                tm::Key::Alt(c) => (KeyCode::Char(c), KeyModifiers::ALT),
+                tm::Key::Ctrl(c) => (KeyCode::Char(c), KeyModifiers::CONTROL),
+                tm::Key::Null => (KeyCode::Null, KeyModifiers::NONE),
+                tm::Key::Esc => (KeyCode::Esc, KeyModifiers::NONE),
+                _ => return Err(UnsupportedEvent),
+            };
+            Ok(Self::new(code, modifiers))
+        }
+    }
+
+    impl From<tm::MouseEvent> for MouseEvent {
+        fn from(event: tm::MouseEvent) -> Self {
+            // termion does not report which button was released or dragged
+            let (kind, column, row) = match event {
+                tm::MouseEvent::Press(button, column, row) => {
+                    let kind = match button {
+                        tm::MouseButton::Left => MouseEventKind::Down(MouseButton::Left),
+                        tm::MouseButton::Right => MouseEventKind::Down(MouseButton::Right),
+                        tm::MouseButton::Middle => MouseEventKind::Down(MouseButton::Middle),
+                        tm::MouseButton::WheelUp => MouseEventKind::ScrollUp,
+                        tm::MouseButton::WheelDown => MouseEventKind::ScrollDown,
+                    };
+                    (kind, column, row)
+                }
+                tm::MouseEvent::Release(column, row) => {
+                    (MouseEventKind::Up(MouseButton::Left), column, row)
+                }
+                tm::MouseEvent::Hold(column, row) => {
+                    (MouseEventKind::Drag(MouseButton::Left), column, row)
+                }
+            };
+            // termion coordinates are one-based
+            Self {
+                kind,
+                column: column.saturating_sub(1),
+                row: row.saturating_sub(1),
+                modifiers: KeyModifiers::NONE,
+            }
+        }
+    }
+}
+
+/// An asynchronous stream of terminal [`Event`]s
+///
+/// The stream implements [`futures_core::Stream`] and can be used with any async runtime. It is
+/// created for a specific backend library with [`EventStream::crossterm`] or
+/// [`EventStream::termion`], depending on the enabled features. Events that can not be represented
+/// by [`Event`] are skipped. There is no stream for the events of Termwiz.
+///
+/// This requires the `event-stream` feature.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # #[cfg(feature = "crossterm")]
+/// # async fn run() -> std::io::Result<()> {
+/// use futures::StreamExt;
+/// use ratatui::backend::{event::Event, EventStream};
+///
+/// let mut events = EventStream::crossterm();
+/// while let Some(event) = events.next().await {
+///     if let Event::Key(key) = event? {
+///         println!("{key:?}");
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "event-stream")]
+pub struct EventStream {
+    inner: Pin<Box<dyn futures_core::Stream<Item = io::Result<Event>> + Send>>,
+}
+
+#[cfg(feature = "event-stream")]
+impl std::fmt::Debug for EventStream {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EventStream").finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "event-stream")]
+impl EventStream {
+    /// Creates a stream of the events read by Crossterm
+    ///
+    /// Only one Crossterm event stream should be read at a time.
+    #[cfg(feature = "crossterm")]
+    pub fn crossterm() -> Self {
+        Self {
+            inner: Box::pin(CrosstermEvents(::crossterm::event::EventStream::new())),
+        }
+    }
+
+    /// Creates a stream of the events read by Termion from stdin
+    ///
+    /// The events are read on a background thread, which exits once the stream is dropped and the
+    /// next event is read.
+    #[cfg(feature = "termion")]
+    pub fn termion() -> Self {
+        Self {
+            inner: Box::pin(TermionEvents::spawn()),
+        }
+    }
+
+    /// Creates a stream of the ticks of `tick`, as [`Event::Tick`] events
+    ///
+    /// This stream never ends. See [`EventStream::with_tick`] for how the ticks are timed. This
+    /// does not read the terminal, so it can be used with any backend, including Termwiz whose
+    /// events are not supported by [`EventStream`].
+    pub fn tick(tick: Tick) -> Self {
+        Self {
+            inner: Box::pin(TickEvents::spawn(tick)),
+        }
+    }
+
+    /// Merges the ticks of `tick` into the stream, as [`Event::Tick`] events
+    ///
+    /// The ticks are timed on a background thread, which exits after the stream is dropped and the
+    /// next tick happened, so they do not depend on a specific async runtime. If the stream is not
+    /// read for a while, the pending ticks are merged into a single event, and the skipped ticks
+    /// are counted in [`TickEvent::missed`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # #[cfg(feature = "crossterm")]
+    /// # async fn run() -> std::io::Result<()> {
+    /// use std::time::Duration;
+    ///
+    /// use futures::StreamExt;
+    /// use ratatui::backend::{
+    ///     event::{Event, Tick},
+    ///     EventStream,
+    /// };
+    ///
+    /// let tick = Tick::new(Duration::from_millis(250));
+    /// let mut events = EventStream::crossterm().with_tick(tick);
+    /// while let Some(event) = events.next().await {
+    ///     match event? {
+    ///         Event::Tick(tick) => println!("tick {}", tick.count),
+    ///         event => println!("{event:?}"),
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_tick(self, tick: Tick) -> Self {
+        Self {
+            inner: Box::pin(WithTick {
+                events: self.inner,
+                ticks: TickEvents::spawn(tick),
+            }),
+        }
+    }
+}
+
+#[cfg(feature = "event-stream")]
+impl futures_core::Stream for EventStream {
+    type Item = io::Result<Event>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.as_mut().poll_next(cx)
+    }
+}
+
+/// The ticks of a [`Tick`] timed on a background thread
+#[cfg(feature = "event-stream")]
+struct TickEvents {
+    shared: std::sync::Arc<std::sync::Mutex<PendingTick>>,
+}
+
+/// The tick that happened since the stream was last polled, and the waker of the stream
+#[cfg(feature = "event-stream")]
+#[derive(Default)]
+struct PendingTick {
+    event: Option<TickEvent>,
+    waker: Option<std::task::Waker>,
+}
+
+#[cfg(feature = "event-stream")]
+impl TickEvents {
+    fn spawn(mut tick: Tick) -> Self {
+        let shared = std::sync::Arc::new(std::sync::Mutex::new(PendingTick::default()));
+        // the thread only holds a weak reference, so that it sees when the stream is dropped
+        let thread_shared = std::sync::Arc::downgrade(&shared);
+        std::thread::spawn(move || loop {
+            let event = tick.wait();
+            let Some(shared) = thread_shared.upgrade() else {
+                break;
+            };
+            let mut pending = shared.lock().unwrap();
+            pending.event = Some(match pending.event {
+                Some(previous) => TickEvent {
+                    missed: previous
+                        .missed
+                        .saturating_add(event.missed)
+                        .saturating_add(1),
+                    ..event
+                },
+                None => event,
+            });
+            if let Some(waker) = pending.waker.take() {
+                waker.wake();
+            }
+        });
+        Self { shared }
+    }
+}
+
+#[cfg(feature = "event-stream")]
+impl futures_core::Stream for TickEvents {
+    type Item = io::Result<Event>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut pending = self.shared.lock().unwrap();
+        if let Some(event) = pending.event.take() {
+            return Poll::Ready(Some(Ok(Event::Tick(event))));
+        }
+        pending.waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+/// A stream of events merged with the ticks of a [`Tick`]
+#[cfg(feature = "event-stream")]
+struct WithTick {
+    events: Pin<Box<dyn futures_core::Stream<Item = io::Result<Event>> + Send>>,
+    ticks: TickEvents,
+}
+
+#[cfg(feature = "event-stream")]
+impl futures_core::Stream for WithTick {
+    type Item = io::Result<Event>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if let Poll::Ready(tick) = Pin::new(&mut self.ticks).poll_next(cx) {
+            return Poll::Ready(tick);
+        }
+        self.events.as_mut().poll_next(cx)
+    }
+}
+
+#[cfg(all(feature = "event-stream", feature = "crossterm"))]
+struct CrosstermEvents(::crossterm::event::EventStream);
+
+#[cfg(all(feature = "event-stream", feature = "crossterm"))]
+impl futures_core::Stream for CrosstermEvents {
+    type Item = io::Result<Event>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.0)
+            .poll_next(cx)
+            .map(|event| event.map(|event| event.map(Event::from)))
+    }
+}
+
+#[cfg(all(feature = "event-stream", feature = "termion"))]
+struct TermionEvents {
+    receiver: std::sync::mpsc::Receiver<io::Result<Event>>,
+    waker: std::sync::Arc<std::sync::Mutex<Option<std::task::Waker>>>,
+}
+
+#[cfg(all(feature = "event-stream", feature = "termion"))]
+impl TermionEvents {
+    fn spawn() -> Self {
+        use ::termion::input::TermRead;
+
+        let (sender, receiver) = std::sync::mpsc::channel();
+        let waker = std::sync::Arc::new(std::sync::Mutex::new(None::<std::task::Waker>));
+        let thread_waker = std::sync::Arc::clone(&waker);
+        std::thread::spawn(move || {
+            for event in io::stdin().events() {
+                let event = match event {
+                    Ok(event) => match Event::try_from(event) {
+                        Ok(event) => Ok(event),
+                        Err(UnsupportedEvent) => continue,
+                    },
+                    Err(err) => Err(err),
+                };
+                if sender.send(event).is_err() {
+                    break;
+                }
+                if let Some(waker) = thread_waker.lock().unwrap().take() {
+                    waker.wake();
+                }
+            }
+            // wake the stream so that it sees the disconnected channel
+            if let Some(waker) = thread_waker.lock().unwrap().take() {
+                waker.wake();
+            }
+        });
+        Self { receiver, waker }
+    }
+}
+
+#[cfg(all(feature = "event-stream", feature = "termion"))]
+impl futures_core::Stream for TermionEvents {
+    type Item = io::Result<Event>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        use std::sync::mpsc::TryRecvError;
+
+        // register the waker before checking the channel so that no event is missed
+        *self.waker.lock().unwrap() = Some(cx.waker().clone());
+        match self.receiver.try_recv() {
+            Ok(event) => Poll::Ready(Some(event)),
+            Err(TryRecvError::Empty) => Poll::Pending,
+            Err(TryRecvError::Disconnected) => Poll::Ready(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn key_event_from_key_code() {
+        assert_eq!(
+            KeyEvent::from(KeyCode::Enter),
+            KeyEvent {
+                code: KeyCode::Enter,
+                modifiers: KeyModifiers::NONE,
+                kind: KeyEventKind::Press,
+            }
+        );
+    }
+
+    #[cfg(feature = "crossterm")]
+    #[test]
+    fn from_crossterm() {
+        use ::crossterm::event as ct;
+
+        let event = ct::Event::Key(ct::KeyEvent::new(
+            ct::KeyCode::Char('a'),
+            ct::KeyModifiers::CONTROL | ct::KeyModifiers::SHIFT,
+        ));
+        assert_eq!(
+            Event::from(event),
+            Event::Key(KeyEvent::new(
+                KeyCode::Char('a'),
+                KeyModifiers::CONTROL | KeyModifiers::SHIFT
+            ))
+        );
+
+        let event = ct::Event::Mouse(ct::MouseEvent {
+            kind: ct::MouseEventKind::Down(ct::MouseButton::Right),
+            column: 3,
+            row: 4,
+            modifiers: ct::KeyModifiers::ALT,
+        });
+        assert_eq!(
+            Event::from(event),
+            Event::Mouse(MouseEvent {
+                kind: MouseEventKind::Down(MouseButton::Right),
+                column: 3,
+                row: 4,
+                modifiers: KeyModifiers::ALT,
+            })
+        );
+
+        assert_eq!(
+            Event::from(ct::Event::Resize(80, 24)),
+            Event::Resize(80, 24)
+        );
+        assert_eq!(
+            Event::from(ct::Event::Paste("text".into())),
+            Event::Paste("text".into())
+        );
+        assert_eq!(Event::from(ct::Event::FocusLost), Event::FocusLost);
+        assert_eq!(KeyCode::from(ct::KeyCode::CapsLock), KeyCode::Null);
+    }
+
+    #[cfg(feature = "termion")]
+    #[test]
+    fn from_termion() {
+        use ::termion::event as tm;
+
+        assert_eq!(
+            Event::try_from(tm::Event::Key(tm::Key::Ctrl('c'))),
+            Ok(Event::Key(KeyEvent::new(
+                KeyCode::Char('c'),
+                KeyModifiers::CONTROL
+            )))
+        );
+        assert_eq!(
+            Event::try_from(tm::Event::Key(tm::Key::Char('\n'))),
+            Ok(Event::Key(KeyCode::Enter.into()))
+        );
+        assert_eq!(
+            Event::try_from(tm::Event::Mouse(tm::MouseEvent::Press(
+                tm::MouseButton::WheelUp,
+                1,
+                1
+            ))),
+            Ok(Event::Mouse(MouseEvent {
+                kind: MouseEventKind::ScrollUp,
+                column: 0,
+                row: 0,
+                modifiers: KeyModifiers::NONE,
+            }))
+        );
+        assert_eq!(
+            Event::try_from(tm::Event::Unsupported(vec![0])),
+            Err(UnsupportedEvent)
+        );
+    }
+
+    #[cfg(feature = "event-stream")]
+    #[test]
+    fn event_stream_with_tick() {
+        use std::time::Duration;
+
+        use futures::{executor::block_on, stream, StreamExt};
+
+        let key = Event::Key(KeyCode::Enter.into());
+        let events = EventStream {
+            inner: Box::pin(stream::iter([Ok(key.clone())]).chain(stream::pending())),
+        };
+        let mut events = events.with_tick(Tick::new(Duration::from_millis(1)));
+        let mut seen = Vec::new();
+        while seen.len() < 3 {
+            seen.push(block_on(events.next()).unwrap().unwrap());
+        }
+        assert!(seen.contains(&key));
+        assert!(seen
+            .iter()
+            .any(|event| matches!(event, Event::Tick(TickEvent { count, .. }) if *count >= 1)));
+    }
+}
@@ -0,0 +1,215 @@
+use std::time::{Duration, Instant};
+
+/// A source of periodic [`TickEvent`]s, e.g. to drive animations or to poll data
+///
+/// A tick happens each time the interval elapses since the tick was created. The deadlines are
+/// computed from the start of the tick rather than from the time the previous tick was observed,
+/// so the ticks do not drift when they are handled late. If a tick is handled so late that whole
+/// intervals passed, the ticks of these intervals are skipped and reported as
+/// [`TickEvent::missed`] instead of firing in a burst.
+///
+/// The tick can be used on its own with [`Tick::wait`], or with [`Tick::timeout`] and
+/// [`Tick::poll`] alongside the events of a backend. With the `event-stream` feature, it can also
+/// be merged into an [`EventStream`] with [`EventStream::with_tick`], which then yields
+/// [`Event::Tick`] events.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::time::{Duration, Instant};
+///
+/// use ratatui::backend::event::Tick;
+///
+/// # fn handle_events(timeout: Duration) {}
+/// # fn animate(frame: u64) {}
+/// let mut tick = Tick::new(Duration::from_millis(100));
+/// loop {
+///     handle_events(tick.timeout());
+///     if let Some(event) = tick.poll(Instant::now()) {
+///         animate(event.count);
+///     }
+/// }
+/// ```
+///
+/// [`Event::Tick`]: super::Event::Tick
+/// [`EventStream`]: super::EventStream
+/// [`EventStream::with_tick`]: super::EventStream::with_tick
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Tick {
+    interval: Duration,
+    start: Instant,
+    next: Instant,
+    count: u64,
+}
+
+/// A tick of a [`Tick`] source
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TickEvent {
+    /// The number of intervals elapsed since the start of the tick, including the missed ones
+    pub count: u64,
+    /// The time of the deadline of this tick since the start of the tick
+    ///
+    /// This is a multiple of the interval, regardless of how late the tick was handled.
+    pub elapsed: Duration,
+    /// The number of ticks skipped since the previous tick because they were handled too late
+    pub missed: u64,
+}
+
+impl Tick {
+    /// Creates a new tick which happens each time `interval` elapses, starting now
+    ///
+    /// # Panics
+    ///
+    /// Panics if `interval` is zero.
+    pub fn new(interval: Duration) -> Self {
+        Self::starting_at(Instant::now(), interval)
+    }
+
+    /// Creates a new tick which happens each time `interval` elapses after `start`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `interval` is zero.
+    pub fn starting_at(start: Instant, interval: Duration) -> Self {
+        assert!(
+            !interval.is_zero(),
+            "the interval of a tick must not be zero"
+        );
+        Self {
+            interval,
+            start,
+            next: start + interval,
+            count: 0,
+        }
+    }
+
+    /// The interval between two ticks
+    pub const fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// The time of the next tick
+    pub const fn deadline(&self) -> Instant {
+        self.next
+    }
+
+    /// The time left until the next tick, or zero if it is due
+    ///
+    /// This is meant to be used as the timeout when waiting for the events of a backend, e.g.
+    /// with `crossterm::event::poll`.
+    pub fn timeout(&self) -> Duration {
+        self.next.saturating_duration_since(Instant::now())
+    }
+
+    /// Returns the tick if it is due at `now`, and schedules the next one
+    pub fn poll(&mut self, now: Instant) -> Option<TickEvent> {
+        if now < self.next {
+            return None;
+        }
+        let interval = self.interval.as_nanos();
+        let behind = now.duration_since(self.next).as_nanos() / interval;
+        let missed = u64::try_from(behind).unwrap_or(u64::MAX);
+        let deadline = self.next + nanos(behind * interval);
+        self.next = deadline + self.interval;
+        self.count = self.count.saturating_add(missed).saturating_add(1);
+        Some(TickEvent {
+            count: self.count,
+            elapsed: deadline.duration_since(self.start),
+            missed,
+        })
+    }
+
+    /// Blocks the current thread until the next tick, and returns it
+    pub fn wait(&mut self) -> TickEvent {
+        loop {
+            if let Some(event) = self.poll(Instant::now()) {
+                return event;
+            }
+            std::thread::sleep(self.timeout());
+        }
+    }
+
+    /// Restarts the tick from now, so that the next tick happens one interval from now
+    pub fn reset(&mut self) {
+        *self = Self::new(self.interval);
+    }
+}
+
+/// Converts a number of nanoseconds to a duration, saturating at the maximum of `u64`
+fn nanos(nanos: u128) -> Duration {
+    Duration::from_nanos(u64::try_from(nanos).unwrap_or(u64::MAX))
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    const MS: Duration = Duration::from_millis(1);
+
+    #[test]
+    fn poll_before_deadline() {
+        let start = Instant::now();
+        let mut tick = Tick::starting_at(start, MS * 10);
+        assert_eq!(tick.deadline(), start + MS * 10);
+        assert_eq!(tick.poll(start), None);
+        assert_eq!(tick.poll(start + MS * 9), None);
+    }
+
+    #[test]
+    fn poll_corrects_drift() {
+        let start = Instant::now();
+        let mut tick = Tick::starting_at(start, MS * 10);
+        assert_eq!(
+            tick.poll(start + MS * 13),
+            Some(TickEvent {
+                count: 1,
+                elapsed: MS * 10,
+                missed: 0,
+            })
+        );
+        // the next deadline is not moved by the 3ms delay
+        assert_eq!(tick.deadline(), start + MS * 20);
+        assert_eq!(tick.poll(start + MS * 19), None);
+        assert_eq!(
+            tick.poll(start + MS * 20),
+            Some(TickEvent {
+                count: 2,
+                elapsed: MS * 20,
+                missed: 0,
+            })
+        );
+    }
+
+    #[test]
+    fn poll_skips_missed_ticks() {
+        let start = Instant::now();
+        let mut tick = Tick::starting_at(start, MS * 10);
+        assert_eq!(
+            tick.poll(start + MS * 45),
+            Some(TickEvent {
+                count: 4,
+                elapsed: MS * 40,
+                missed: 3,
+            })
+        );
+        assert_eq!(tick.deadline(), start + MS * 50);
+        assert_eq!(tick.poll(start + MS * 45), None);
+    }
+
+    #[test]
+    fn wait() {
+        let mut tick = Tick::new(MS);
+        let event = tick.wait();
+        assert!(event.count >= 1);
+        assert_eq!(event.elapsed, MS * u32::try_from(event.count).unwrap());
+    }
+
+    #[test]
+    #[should_panic = "the interval of a tick must not be zero"]
+    fn zero_interval() {
+        let _ = Tick::new(Duration::ZERO);
+    }
+}
@@ -0,0 +1,263 @@
+//! This module provides the `RecordingBackend` implementation for the [`Backend`] trait.
+//! It is used in integration tests to verify the sequence of frames drawn to the terminal.
+
+use std::{
+    fmt::{self, Write},
+    io,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    backend::{test::buffer_view, Backend, Capabilities, ClearType, TestBackend, WindowSize},
+    buffer::{Buffer, Cell},
+    layout::Rect,
+    text::Line,
+};
+
+/// A [`Backend`] that renders to a [`TestBackend`] and records the content of the screen each time
+/// it is flushed.
+///
+/// [`Terminal::draw`] flushes the backend once per frame, so the recorded frames are the sequence
+/// of screens seen by the user. This makes it possible to test animations and interactions as a
+/// whole, rather than only the last screen. The frames can be compared to the expected lines with
+/// [`RecordingBackend::assert_frames`], or rendered as text with [`RecordingBackend::frames_view`]
+/// to be compared to a snapshot.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{backend::RecordingBackend, prelude::*, widgets::Paragraph};
+///
+/// let mut terminal = Terminal::new(RecordingBackend::new(5, 1))?;
+/// for text in ["one", "two"] {
+///     terminal.draw(|frame| frame.render_widget(Paragraph::new(text), frame.size()))?;
+/// }
+/// terminal.backend().assert_frames([["one  "], ["two  "]]);
+/// # std::io::Result::Ok(())
+/// ```
+///
+/// [`Terminal::draw`]: crate::terminal::Terminal::draw
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct RecordingBackend {
+    backend: TestBackend,
+    start: Instant,
+    frames: Vec<RecordedFrame>,
+}
+
+/// A frame recorded by a [`RecordingBackend`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct RecordedFrame {
+    /// The content of the screen when the frame was flushed
+    pub buffer: Buffer,
+    /// The time elapsed between the creation of the backend and the flush
+    pub elapsed: Duration,
+}
+
+impl RecordingBackend {
+    /// Creates a new `RecordingBackend` with the specified width and height.
+    pub fn new(width: u16, height: u16) -> Self {
+        Self {
+            backend: TestBackend::new(width, height),
+            start: Instant::now(),
+            frames: Vec::new(),
+        }
+    }
+
+    /// Returns a reference to the [`TestBackend`] that the frames are rendered to.
+    pub const fn backend(&self) -> &TestBackend {
+        &self.backend
+    }
+
+    /// Returns a mutable reference to the [`TestBackend`] that the frames are rendered to.
+    pub fn backend_mut(&mut self) -> &mut TestBackend {
+        &mut self.backend
+    }
+
+    /// Returns the frames recorded so far, from the oldest to the newest.
+    pub fn frames(&self) -> &[RecordedFrame] {
+        &self.frames
+    }
+
+    /// Returns the content of the screen of the frames recorded so far, from the oldest to the
+    /// newest.
+    pub fn buffers(&self) -> impl Iterator<Item = &Buffer> {
+        self.frames.iter().map(|frame| &frame.buffer)
+    }
+
+    /// Forgets the frames recorded so far, e.g. to ignore the frames drawn while setting up a test.
+    pub fn clear_frames(&mut self) {
+        self.frames.clear();
+    }
+
+    /// Returns a text rendering of the recorded frames.
+    ///
+    /// Each frame starts with a header containing its index, followed by its lines in quotes as in
+    /// the [`Display`] implementation of [`TestBackend`]. The timings are not included, so the
+    /// result can be compared to a snapshot.
+    ///
+    /// [`Display`]: std::fmt::Display
+    pub fn frames_view(&self) -> String {
+        frames_view(self.buffers())
+    }
+
+    /// Asserts that the recorded frames are equal to the expected lines, one list of lines per
+    /// frame.
+    ///
+    /// # Panics
+    ///
+    /// When the number of frames or the content of one of them are not equal, a panic occurs with
+    /// the expected and the recorded frames.
+    #[track_caller]
+    pub fn assert_frames<'line, Frames, Lines>(&self, expected: Frames)
+    where
+        Frames: IntoIterator<Item = Lines>,
+        Lines: IntoIterator,
+        Lines::Item: Into<Line<'line>>,
+    {
+        let expected: Vec<Buffer> = expected.into_iter().map(Buffer::with_lines).collect();
+        assert!(
+            self.buffers().eq(&expected),
+            "frames are not equal\nexpected:\n{}\nrecorded:\n{}",
+            frames_view(&expected),
+            self.frames_view()
+        );
+    }
+}
+
+/// Renders each buffer with a header containing its index.
+fn frames_view<'a>(buffers: impl IntoIterator<Item = &'a Buffer>) -> String {
+    let mut view = String::new();
+    for (index, buffer) in buffers.into_iter().enumerate() {
+        writeln!(view, "frame {index}:").unwrap();
+        view.push_str(&buffer_view(buffer));
+    }
+    view
+}
+
+impl fmt::Display for RecordingBackend {
+    /// Formats the recorded frames with [`RecordingBackend::frames_view`].
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.frames_view())
+    }
+}
+
+impl Backend for RecordingBackend {
+    fn draw<'a, I>(&mut self, content: I) -> io::Result<()>
+    where
+        I: Iterator<Item = (u16, u16, &'a Cell)>,
+    {
+        self.backend.draw(content)
+    }
+
+    fn append_lines(&mut self, n: u16) -> io::Result<()> {
+        self.backend.append_lines(n)
+    }
+
+    fn hide_cursor(&mut self) -> io::Result<()> {
+        self.backend.hide_cursor()
+    }
+
+    fn show_cursor(&mut self) -> io::Result<()> {
+        self.backend.show_cursor()
+    }
+
+    fn get_cursor(&mut self) -> io::Result<(u16, u16)> {
+        self.backend.get_cursor()
+    }
+
+    fn set_cursor(&mut self, x: u16, y: u16) -> io::Result<()> {
+        self.backend.set_cursor(x, y)
+    }
+
+    fn clear(&mut self) -> io::Result<()> {
+        self.backend.clear()
+    }
+
+    fn clear_region(&mut self, clear_type: ClearType) -> io::Result<()> {
+        self.backend.clear_region(clear_type)
+    }
+
+    fn size(&self) -> io::Result<Rect> {
+        self.backend.size()
+    }
+
+    fn window_size(&mut self) -> io::Result<WindowSize> {
+        self.backend.window_size()
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.backend.flush()?;
+        self.frames.push(RecordedFrame {
+            buffer: self.backend.buffer().clone(),
+            elapsed: self.start.elapsed(),
+        });
+        Ok(())
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        self.backend.capabilities()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        style::Style,
+        terminal::{Terminal, TerminalOptions, Viewport},
+        widgets::{Paragraph, Widget},
+    };
+
+    #[test]
+    fn records_each_flush() {
+        let mut backend = RecordingBackend::new(3, 1);
+        backend.flush().unwrap();
+        let mut cell = Cell::default();
+        cell.set_symbol("a");
+        backend.draw([(1, 0, &cell)].into_iter()).unwrap();
+        backend.flush().unwrap();
+
+        backend.assert_frames([["   "], [" a "]]);
+        assert!(backend.frames()[0].elapsed <= backend.frames()[1].elapsed);
+    }
+
+    #[test]
+    fn frames_view() {
+        let mut backend = RecordingBackend::new(2, 2);
+        backend.flush().unwrap();
+        backend.backend_mut().clear().unwrap();
+        backend.flush().unwrap();
+        assert_eq!(
+            backend.frames_view(),
+            "frame 0:\n\"  \"\n\"  \"\nframe 1:\n\"  \"\n\"  \"\n"
+        );
+        backend.clear_frames();
+        assert_eq!(backend.to_string(), "");
+    }
+
+    #[test]
+    fn records_terminal_draws() {
+        let options = TerminalOptions::default().viewport(Viewport::Inline(1));
+        let mut terminal = Terminal::with_options(RecordingBackend::new(4, 2), options).unwrap();
+        terminal
+            .draw(|f| f.render_widget(Paragraph::new("ab"), f.size()))
+            .unwrap();
+        terminal
+            .insert_before(1, |buf| buf.set_string(0, 0, "log", Style::new()))
+            .unwrap();
+        terminal
+            .draw(|f| Paragraph::new("cd").render(f.size(), f.buffer_mut()))
+            .unwrap();
+        terminal
+            .backend()
+            .assert_frames([["ab  ", "    "], ["log ", "    "], ["log ", "cd  "]]);
+    }
+
+    #[test]
+    #[should_panic = "frames are not equal"]
+    fn assert_frames_fails() {
+        let mut backend = RecordingBackend::new(2, 1);
+        backend.flush().unwrap();
+        backend.assert_frames([["a "]]);
+    }
+}
@@ -12,7 +12,9 @@ use std::{
 use termion::{color as tcolor, style as tstyle};
 
 use crate::{
-    backend::{Backend, ClearType, WindowSize},
+    backend::{
+        Backend, Capabilities, ClearType, ColorSupport, GraphicsProtocol, Hyperlink, WindowSize,
+    },
     buffer::Cell,
     prelude::Rect,
     style::{Color, Modifier, Style},
@@ -67,6 +69,7 @@ where
     W: Write,
 {
     writer: W,
+    color_support: Option<ColorSupport>,
 }
 
 impl<W> TermionBackend<W>
@@ -83,7 +86,30 @@ where
     /// let backend = TermionBackend::new(stdout());
     /// ```
     pub const fn new(writer: W) -> Self {
-        Self { writer }
+        Self {
+            writer,
+            color_support: None,
+        }
+    }
+
+    /// Sets the colors that the output is downsampled to.
+    ///
+    /// The colors of the cells are converted with [`Color::downsample`] before they are sent to
+    /// the terminal, so that styles written with RGB colors are displayed as the closest indexed
+    /// or named colors on terminals that do not support them. By default, the colors are sent
+    /// unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use std::io::stdout;
+    /// # use ratatui::{backend::ColorSupport, prelude::*};
+    /// let backend = TermionBackend::new(stdout()).with_color_support(ColorSupport::Ansi16);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_color_support(mut self, color_support: ColorSupport) -> Self {
+        self.color_support = Some(color_support);
+        self
     }
 
     /// Gets the writer.
@@ -175,6 +201,7 @@ where
         let mut fg = Color::Reset;
         let mut bg = Color::Reset;
         let mut modifier = Modifier::empty();
+        let mut hyperlink = None;
         let mut last_pos: Option<(u16, u16)> = None;
         for (x, y, cell) in content {
             // Move the cursor if the previous location was not (x - 1, y)
@@ -194,16 +221,26 @@ where
                 .unwrap();
                 modifier = cell.modifier;
             }
-            if cell.fg != fg {
-                write!(string, "{}", Fg(cell.fg)).unwrap();
-                fg = cell.fg;
+            let colors = self.color_support.map_or((cell.fg, cell.bg), |support| {
+                (cell.fg.downsample(support), cell.bg.downsample(support))
+            });
+            if colors.0 != fg {
+                write!(string, "{}", Fg(colors.0)).unwrap();
+                fg = colors.0;
             }
-            if cell.bg != bg {
-                write!(string, "{}", Bg(cell.bg)).unwrap();
-                bg = cell.bg;
+            if colors.1 != bg {
+                write!(string, "{}", Bg(colors.1)).unwrap();
+                bg = colors.1;
+            }
+            if cell.hyperlink() != hyperlink {
+                write!(string, "{}", Hyperlink(cell.hyperlink())).unwrap();
+                hyperlink = cell.hyperlink();
             }
             string.push_str(cell.symbol());
         }
+        if hyperlink.is_some() {
+            write!(string, "{}", Hyperlink(None)).unwrap();
+        }
         write!(
             self.writer,
             "{string}{}{}{}",
@@ -228,6 +265,56 @@ where
     fn flush(&mut self) -> io::Result<()> {
         self.writer.flush()
     }
+
+    fn write_raw(&mut self, data: &str) -> io::Result<()> {
+        self.writer.write_all(data.as_bytes())
+    }
+
+    fn begin_synchronized_update(&mut self) -> io::Result<()> {
+        write!(self.writer, "\x1b[?2026h")
+    }
+
+    fn end_synchronized_update(&mut self) -> io::Result<()> {
+        write!(self.writer, "\x1b[?2026l")
+    }
+
+    /// Leaves the alternate screen and shows the cursor.
+    ///
+    /// Raw mode is controlled by the [`RawTerminal`] wrapping the writer, so it is not disabled by
+    /// the backend. Call [`RawTerminal::suspend_raw_mode`] before suspending the terminal if
+    /// needed.
+    ///
+    /// [`RawTerminal`]: termion::raw::RawTerminal
+    /// [`RawTerminal::suspend_raw_mode`]: termion::raw::RawTerminal::suspend_raw_mode
+    fn suspend(&mut self, alternate_screen: bool) -> io::Result<()> {
+        if alternate_screen {
+            write!(self.writer, "{}", termion::screen::ToMainScreen)?;
+        }
+        write!(self.writer, "{}", termion::cursor::Show)?;
+        self.writer.flush()
+    }
+
+    fn resume(&mut self, alternate_screen: bool) -> io::Result<()> {
+        if alternate_screen {
+            write!(self.writer, "{}", termion::screen::ToAlternateScreen)?;
+        }
+        self.writer.flush()
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        Capabilities {
+            true_color: Capabilities::true_color_from_env(),
+            color_support: self.color_support.unwrap_or_else(ColorSupport::detect),
+            underline_color: false,
+            italic: true,
+            synchronized_output: true,
+            mouse: true,
+            bracketed_paste: false,
+            set_title: false,
+            hyperlinks: true,
+            graphics: GraphicsProtocol::detect(),
+        }
+    }
 }
 struct Fg(Color);
 
@@ -389,7 +476,8 @@ impl fmt::Display for ModifierDiff {
         if remove.contains(Modifier::ITALIC) {
             write!(f, "{}", termion::style::NoItalic)?;
         }
-        if remove.contains(Modifier::UNDERLINED) {
+        // termion only supports single underlines, other underline styles fall back to it
+        if self.to.underline().is_none() && self.from.underline().is_some() {
             write!(f, "{}", termion::style::NoUnderline)?;
         }
         if remove.contains(Modifier::DIM) {
@@ -418,7 +506,7 @@ impl fmt::Display for ModifierDiff {
         if add.contains(Modifier::ITALIC) {
             write!(f, "{}", termion::style::Italic)?;
         }
-        if add.contains(Modifier::UNDERLINED) {
+        if self.to.underline().is_some() && self.from.underline().is_none() {
             write!(f, "{}", termion::style::Underline)?;
         }
         if add.contains(Modifier::DIM) {
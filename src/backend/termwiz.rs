@@ -5,18 +5,19 @@
 //! [`TermwizBackend`]: crate::backend::TermionBackend
 //! [Termwiz]: https://crates.io/crates/termwiz
 
-use std::{error::Error, io};
+use std::{error::Error, io, sync::Arc};
 
 use termwiz::{
-    caps::Capabilities,
+    caps::{self, ColorLevel},
     cell::{AttributeChange, Blink, CellAttributes, Intensity, Underline},
     color::{AnsiColor, ColorAttribute, ColorSpec, LinearRgba, RgbColor, SrgbaTuple},
+    hyperlink::Hyperlink,
     surface::{Change, CursorVisibility, Position},
     terminal::{buffered::BufferedTerminal, ScreenSize, SystemTerminal, Terminal},
 };
 
 use crate::{
-    backend::{Backend, WindowSize},
+    backend::{Backend, Capabilities, ColorSupport, GraphicsProtocol, WindowSize},
     buffer::Cell,
     layout::Size,
     prelude::Rect,
@@ -86,7 +87,7 @@ impl TermwizBackend {
     /// ```
     pub fn new() -> Result<Self, Box<dyn Error>> {
         let mut buffered_terminal =
-            BufferedTerminal::new(SystemTerminal::new(Capabilities::new_from_env()?)?)?;
+            BufferedTerminal::new(SystemTerminal::new(caps::Capabilities::new_from_env()?)?)?;
         buffered_terminal.terminal().set_raw_mode()?;
         buffered_terminal.terminal().enter_alternate_screen()?;
         Ok(Self { buffered_terminal })
@@ -143,10 +144,13 @@ impl Backend for TermwizBackend {
 
             self.buffered_terminal
                 .add_change(Change::Attribute(AttributeChange::Underline(
-                    if cell.modifier.contains(Modifier::UNDERLINED) {
-                        Underline::Single
-                    } else {
-                        Underline::None
+                    match cell.modifier.underline() {
+                        None => Underline::None,
+                        Some(Modifier::DOUBLE_UNDERLINED) => Underline::Double,
+                        Some(Modifier::CURLY_UNDERLINED) => Underline::Curly,
+                        Some(Modifier::DOTTED_UNDERLINED) => Underline::Dotted,
+                        Some(Modifier::DASHED_UNDERLINED) => Underline::Dashed,
+                        Some(_) => Underline::Single,
                     },
                 )));
 
@@ -176,6 +180,11 @@ impl Backend for TermwizBackend {
                     },
                 )));
 
+            self.buffered_terminal
+                .add_change(Change::Attribute(AttributeChange::Hyperlink(
+                    cell.hyperlink().map(|url| Arc::new(Hyperlink::new(url))),
+                )));
+
             self.buffered_terminal.add_change(cell.symbol());
         }
         Ok(())
@@ -247,6 +256,54 @@ impl Backend for TermwizBackend {
             .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
         Ok(())
     }
+
+    fn suspend(&mut self, alternate_screen: bool) -> io::Result<()> {
+        self.show_cursor()?;
+        self.flush()?;
+        let terminal = self.buffered_terminal.terminal();
+        if alternate_screen {
+            terminal.exit_alternate_screen().map_err(io::Error::other)?;
+        }
+        terminal.set_cooked_mode().map_err(io::Error::other)?;
+        Ok(())
+    }
+
+    fn resume(&mut self, alternate_screen: bool) -> io::Result<()> {
+        let terminal = self.buffered_terminal.terminal();
+        terminal.set_raw_mode().map_err(io::Error::other)?;
+        if alternate_screen {
+            terminal
+                .enter_alternate_screen()
+                .map_err(io::Error::other)?;
+        }
+        Ok(())
+    }
+
+    fn capabilities(&self) -> Capabilities {
+        let Ok(caps) = caps::Capabilities::new_from_env() else {
+            return Capabilities::default();
+        };
+        let graphics = match GraphicsProtocol::detect() {
+            GraphicsProtocol::Halfblocks if caps.sixel() => GraphicsProtocol::Sixel,
+            graphics => graphics,
+        };
+        Capabilities {
+            true_color: caps.color_level() == ColorLevel::TrueColor,
+            color_support: match caps.color_level() {
+                ColorLevel::Sixteen => ColorSupport::Ansi16,
+                ColorLevel::TwoFiftySix => ColorSupport::Ansi256,
+                ColorLevel::TrueColor => ColorSupport::TrueColor,
+            },
+            underline_color: false,
+            italic: true,
+            synchronized_output: false,
+            mouse: caps.mouse_reporting(),
+            bracketed_paste: caps.bracketed_paste(),
+            set_title: true,
+            hyperlinks: true,
+            graphics,
+        }
+    }
 }
 
 impl From<CellAttributes> for Style {
@@ -294,7 +351,11 @@ impl From<Underline> for Modifier {
     fn from(value: Underline) -> Self {
         match value {
             Underline::None => Self::empty(),
-            _ => Self::UNDERLINED,
+            Underline::Single => Self::UNDERLINED,
+            Underline::Double => Self::DOUBLE_UNDERLINED,
+            Underline::Curly => Self::CURLY_UNDERLINED,
+            Underline::Dotted => Self::DOTTED_UNDERLINED,
+            Underline::Dashed => Self::DASHED_UNDERLINED,
         }
     }
 }
@@ -559,10 +620,19 @@ mod tests {
         fn from_underline() {
             assert_eq!(Modifier::from(Underline::None), Modifier::empty());
             assert_eq!(Modifier::from(Underline::Single), Modifier::UNDERLINED);
-            assert_eq!(Modifier::from(Underline::Double), Modifier::UNDERLINED);
-            assert_eq!(Modifier::from(Underline::Curly), Modifier::UNDERLINED);
-            assert_eq!(Modifier::from(Underline::Dashed), Modifier::UNDERLINED);
-            assert_eq!(Modifier::from(Underline::Dotted), Modifier::UNDERLINED);
+            assert_eq!(
+                Modifier::from(Underline::Double),
+                Modifier::DOUBLE_UNDERLINED
+            );
+            assert_eq!(Modifier::from(Underline::Curly), Modifier::CURLY_UNDERLINED);
+            assert_eq!(
+                Modifier::from(Underline::Dashed),
+                Modifier::DASHED_UNDERLINED
+            );
+            assert_eq!(
+                Modifier::from(Underline::Dotted),
+                Modifier::DOTTED_UNDERLINED
+            );
         }
 
         #[test]
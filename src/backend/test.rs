@@ -47,7 +47,7 @@ pub struct TestBackend {
 /// It iterates through the buffer content and appends each cell's symbol to the view string.
 /// If a cell is hidden by a multi-width symbol, it is added to the overwritten vector and
 /// displayed at the end of the line.
-fn buffer_view(buffer: &Buffer) -> String {
+pub(super) fn buffer_view(buffer: &Buffer) -> String {
     let mut view = String::with_capacity(buffer.content.len() + buffer.area.height as usize * 3);
     for cells in buffer.content.chunks(buffer.area.width as usize) {
         let mut overwritten = vec![];
@@ -96,25 +96,28 @@ impl TestBackend {
 
     /// Asserts that the `TestBackend`'s buffer is equal to the expected buffer.
     ///
-    /// This is a shortcut for `assert_eq!(self.buffer(), &expected)`.
+    /// This compares the style of the cells as well as their symbols, like
+    /// [`Buffer::assert_styled_eq`].
     ///
     /// # Panics
-    /// When they are not equal, a panic occurs with a detailed error message showing the
-    /// differences between the expected and actual buffers.
-    #[allow(deprecated)]
+    /// When they are not equal, a panic occurs with a [`BufferDiff`] showing the expected and
+    /// actual buffers side by side and listing the symbol, colors and modifiers of the cells that
+    /// differ.
+    ///
+    /// [`BufferDiff`]: crate::buffer::BufferDiff
     #[track_caller]
     pub fn assert_buffer(&self, expected: &Buffer) {
-        // TODO: use assert_eq!()
-        crate::assert_buffer_eq!(&self.buffer, expected);
+        self.buffer.assert_styled_eq(expected);
     }
 
     /// Asserts that the `TestBackend`'s buffer is equal to the expected lines.
     ///
-    /// This is a shortcut for `assert_eq!(self.buffer(), &Buffer::with_lines(expected))`.
+    /// This is a shortcut for `self.assert_buffer(&Buffer::with_lines(expected))`. The lines can be
+    /// styled to check the style of the cells, e.g. `["hello".red()]`.
     ///
     /// # Panics
-    /// When they are not equal, a panic occurs with a detailed error message showing the
-    /// differences between the expected and actual buffers.
+    /// When they are not equal, a panic occurs with a diff of the expected and actual buffers, see
+    /// [`TestBackend::assert_buffer`].
     #[track_caller]
     pub fn assert_buffer_lines<'line, Lines>(&self, expected: Lines)
     where
@@ -261,6 +264,7 @@ impl Backend for TestBackend {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::style::Stylize;
 
     #[test]
     fn new() {
@@ -320,6 +324,13 @@ mod tests {
         backend.assert_buffer_lines(["aaaaaaaaaa"; 2]);
     }
 
+    #[test]
+    #[should_panic = "(0, 0): fg Red != Reset"]
+    fn assert_buffer_panics_on_style() {
+        let backend = TestBackend::new(1, 1);
+        backend.assert_buffer_lines([" ".red()]);
+    }
+
     #[test]
     fn display() {
         let backend = TestBackend::new(10, 2);
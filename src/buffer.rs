@@ -1,10 +1,21 @@
 #![warn(missing_docs)]
 //! A module for the [`Buffer`] and [`Cell`] types.
 
+pub(crate) mod ansi;
 mod assert;
+mod blend;
 #[allow(clippy::module_inception)]
 mod buffer;
 mod cell;
+mod cell_run;
+mod diff;
+#[cfg(feature = "export")]
+mod export;
+mod graphic;
 
+pub use blend::BlendMode;
 pub use buffer::Buffer;
 pub use cell::Cell;
+pub use cell_run::CellRun;
+pub use diff::BufferDiff;
+pub use graphic::Graphic;
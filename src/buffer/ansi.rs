@@ -0,0 +1,225 @@
+use std::fmt::Write;
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    buffer::{Buffer, Cell},
+    style::{Color, Modifier},
+};
+
+const ESC: char = '\u{1b}';
+
+/// The SGR codes of the modifiers, in the order in which they are written
+const MODIFIER_CODES: [(Modifier, u8); 9] = [
+    (Modifier::BOLD, 1),
+    (Modifier::DIM, 2),
+    (Modifier::ITALIC, 3),
+    (Modifier::UNDERLINED, 4),
+    (Modifier::SLOW_BLINK, 5),
+    (Modifier::RAPID_BLINK, 6),
+    (Modifier::REVERSED, 7),
+    (Modifier::HIDDEN, 8),
+    (Modifier::CROSSED_OUT, 9),
+];
+
+impl Buffer {
+    /// Renders the content of the buffer as a string with ANSI escape sequences for the colors and
+    /// modifiers of the cells.
+    ///
+    /// Each row of the buffer is written on its own line, separated by `\n`. The style is written
+    /// only when it changes, and is reset at the end of each line. Hyperlinks are written as OSC 8
+    /// sequences. The symbols hidden by multi-width symbols are skipped, so the lines have the
+    /// expected width when printed.
+    ///
+    /// This is useful to save the rendered output to a file, e.g. as a golden file for snapshot
+    /// tests that can be viewed with `cat`, or to print it to a terminal outside of the
+    /// application. The result can be converted back to a [`Text`] with `Text::from_ansi` when the
+    /// `ansi` feature is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::prelude::*;
+    ///
+    /// let buffer = Buffer::with_lines([Line::from(vec!["error".red().bold(), ": oops".into()])]);
+    /// assert_eq!(buffer.to_ansi_string(), "\x1b[0;1;31merror\x1b[0m: oops");
+    /// ```
+    ///
+    /// [`Text`]: crate::text::Text
+    pub fn to_ansi_string(&self) -> String {
+        let mut ansi = String::with_capacity(self.content.len());
+        let default = Cell::default();
+        for (y, row) in self.rows().take(self.area.height as usize).enumerate() {
+            if y > 0 {
+                ansi.push('\n');
+            }
+            let mut last = &default;
+            let mut skip: usize = 0;
+            for cell in row {
+                if skip == 0 {
+                    if !same_style(cell, last) {
+                        write_style(&mut ansi, cell);
+                    }
+                    if cell.hyperlink() != last.hyperlink() {
+                        write_hyperlink(&mut ansi, cell.hyperlink());
+                    }
+                    ansi.push_str(cell.symbol());
+                    last = cell;
+                }
+                skip = std::cmp::max(skip, cell.symbol().width()).saturating_sub(1);
+            }
+            if last.hyperlink().is_some() {
+                write_hyperlink(&mut ansi, None);
+            }
+            if !same_style(last, &default) {
+                write_style(&mut ansi, &default);
+            }
+        }
+        ansi
+    }
+}
+
+/// Returns true if the cells have the same colors and modifiers
+pub(crate) fn same_style(cell: &Cell, other: &Cell) -> bool {
+    #[cfg(feature = "underline-color")]
+    if cell.underline_color != other.underline_color {
+        return false;
+    }
+    cell.fg == other.fg && cell.bg == other.bg && cell.modifier == other.modifier
+}
+
+/// Writes the SGR sequence that resets the style and sets the style of `cell`
+pub(crate) fn write_style(ansi: &mut String, cell: &Cell) {
+    let _ = write!(ansi, "{ESC}[0");
+    for (modifier, code) in MODIFIER_CODES {
+        if cell.modifier.contains(modifier) {
+            let _ = write!(ansi, ";{code}");
+        }
+    }
+    write_color(ansi, cell.fg, 30, 90, 38);
+    write_color(ansi, cell.bg, 40, 100, 48);
+    #[cfg(feature = "underline-color")]
+    if cell.underline_color != Color::Reset {
+        // there are no short codes for the named underline colors, so they are written with their
+        // index in the 256 colors palette, which is the same for the first 16 colors
+        let color = named_index(cell.underline_color).map_or(cell.underline_color, Color::Indexed);
+        write_color(ansi, color, 0, 0, 58);
+    }
+    ansi.push('m');
+}
+
+/// Writes the parameters of a color, using the codes starting at `normal` for the first 8 named
+/// colors, at `bright` for the other named colors and `extended` for indexed and RGB colors
+fn write_color(ansi: &mut String, color: Color, normal: u8, bright: u8, extended: u8) {
+    let _ = match (color, named_index(color)) {
+        (Color::Reset, _) => Ok(()),
+        (_, Some(index @ 0..=7)) => write!(ansi, ";{}", normal + index),
+        (_, Some(index)) => write!(ansi, ";{}", bright + index - 8),
+        (Color::Indexed(index), None) => write!(ansi, ";{extended};5;{index}"),
+        (Color::Rgb(r, g, b), None) => write!(ansi, ";{extended};2;{r};{g};{b}"),
+        _ => Ok(()),
+    };
+}
+
+/// Writes the OSC 8 sequence that starts a hyperlink, or ends it if `hyperlink` is `None`
+pub(crate) fn write_hyperlink(ansi: &mut String, hyperlink: Option<&str>) {
+    let _ = write!(ansi, "{ESC}]8;;{}{ESC}\\", hyperlink.unwrap_or_default());
+}
+
+/// The index of a named color in the 16 colors palette
+const fn named_index(color: Color) -> Option<u8> {
+    match color {
+        Color::Black => Some(0),
+        Color::Red => Some(1),
+        Color::Green => Some(2),
+        Color::Yellow => Some(3),
+        Color::Blue => Some(4),
+        Color::Magenta => Some(5),
+        Color::Cyan => Some(6),
+        Color::Gray => Some(7),
+        Color::DarkGray => Some(8),
+        Color::LightRed => Some(9),
+        Color::LightGreen => Some(10),
+        Color::LightYellow => Some(11),
+        Color::LightBlue => Some(12),
+        Color::LightMagenta => Some(13),
+        Color::LightCyan => Some(14),
+        Color::White => Some(15),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        layout::Rect,
+        style::{Style, Stylize},
+        text::{Line, Span},
+    };
+
+    #[test]
+    fn empty() {
+        assert_eq!(Buffer::empty(Rect::ZERO).to_ansi_string(), "");
+    }
+
+    #[test]
+    fn unstyled() {
+        let buffer = Buffer::with_lines(["ab", "cd"]);
+        assert_eq!(buffer.to_ansi_string(), "ab\ncd");
+    }
+
+    #[rstest]
+    #[case::named(Style::new().red().on_light_blue(), "\x1b[0;31;104m")]
+    #[case::indexed(Style::new().fg(Color::Indexed(42)), "\x1b[0;38;5;42m")]
+    #[case::rgb(Style::new().bg(Color::Rgb(1, 2, 3)), "\x1b[0;48;2;1;2;3m")]
+    #[case::modifiers(Style::new().bold().italic().crossed_out(), "\x1b[0;1;3;9m")]
+    fn styled(#[case] style: Style, #[case] sequence: &str) {
+        let buffer = Buffer::with_lines([Line::from(vec![Span::styled("a", style), "b".into()])]);
+        assert_eq!(buffer.to_ansi_string(), format!("{sequence}a\x1b[0mb"));
+    }
+
+    #[test]
+    fn style_reset_at_end_of_line() {
+        let buffer = Buffer::with_lines(["ab".green(), "cd".green()]);
+        assert_eq!(
+            buffer.to_ansi_string(),
+            "\x1b[0;32mab\x1b[0m\n\x1b[0;32mcd\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn multi_width() {
+        let buffer = Buffer::with_lines(["😃a"]);
+        assert_eq!(buffer.to_ansi_string(), "😃a");
+    }
+
+    #[test]
+    fn hyperlink() {
+        let buffer = Buffer::with_lines([Line::from(vec![
+            Span::raw("a").hyperlink("https://ratatui.rs"),
+            Span::raw("b"),
+        ])]);
+        assert_eq!(
+            buffer.to_ansi_string(),
+            "\x1b]8;;https://ratatui.rs\x1b\\a\x1b]8;;\x1b\\b"
+        );
+    }
+
+    #[cfg(feature = "ansi")]
+    #[test]
+    fn from_ansi_round_trip() {
+        let buffer = Buffer::with_lines([
+            Line::from(vec!["error".red().bold(), ": ".into(), "oops".on_blue()]),
+            Line::from("ok".fg(Color::Rgb(10, 20, 30))),
+        ]);
+        let ansi = buffer.to_ansi_string();
+        assert_eq!(
+            Buffer::with_lines(crate::text::Text::from_ansi(&ansi).lines),
+            buffer
+        );
+    }
+}
@@ -0,0 +1,135 @@
+use crate::{buffer::Cell, style::Color};
+
+/// How the cells of a buffer are combined with the cells below them by [`Buffer::merge_with`].
+///
+/// This makes it possible to composite buffers that were rendered separately, e.g. to draw a
+/// cached popup or a layer of widgets over the rest of the UI without rendering it again.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui::{buffer::BlendMode, prelude::*};
+///
+/// let mut background = Buffer::with_lines(["abc"]);
+/// let popup = Buffer::with_lines([" x "]);
+/// background.merge_with(&popup, BlendMode::Overlay);
+/// assert_eq!(background, Buffer::with_lines(["axc"]));
+/// ```
+///
+/// [`Buffer::merge_with`]: crate::buffer::Buffer::merge_with
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BlendMode {
+    /// The cells replace the cells below them.
+    #[default]
+    Replace,
+    /// The cells replace the cells below them, except for the empty cells (the cells that were
+    /// never drawn to or that were reset), which let the cells below show through.
+    SkipEmpty,
+    /// The cells below keep their symbol, foreground color and modifiers, and only take the
+    /// background color of the cells.
+    ///
+    /// This is useful to highlight or dim an area without changing its content.
+    KeepFg,
+    /// The cells below keep their background color, and take the symbol, foreground color and
+    /// modifiers of the cells.
+    ///
+    /// This is useful to draw text over a colored background.
+    KeepBg,
+    /// The cells are drawn over the cells below them, with their blank parts transparent.
+    ///
+    /// The symbol of the cell below is kept when the symbol of the cell is a space, the colors
+    /// below are kept when the colors of the cell are [`Color::Reset`], and the modifiers are
+    /// added to the modifiers below.
+    Overlay,
+}
+
+impl BlendMode {
+    /// Combines the cell `top` into the cell `bottom` below it
+    pub(crate) fn blend(self, bottom: &mut Cell, top: &Cell) {
+        match self {
+            Self::Replace => bottom.clone_from(top),
+            Self::SkipEmpty => {
+                if *top != Cell::default() {
+                    bottom.clone_from(top);
+                }
+            }
+            Self::KeepFg => {
+                bottom.bg = top.bg;
+            }
+            Self::KeepBg => {
+                let bg = bottom.bg;
+                bottom.clone_from(top);
+                bottom.bg = bg;
+            }
+            Self::Overlay => {
+                if top.symbol() != " " {
+                    bottom.set_symbol(top.symbol());
+                    bottom.set_hyperlink(top.hyperlink());
+                }
+                let overlay = |bottom: &mut Color, top: Color| {
+                    if top != Color::Reset {
+                        *bottom = top;
+                    }
+                };
+                overlay(&mut bottom.fg, top.fg);
+                overlay(&mut bottom.bg, top.bg);
+                #[cfg(feature = "underline-color")]
+                overlay(&mut bottom.underline_color, top.underline_color);
+                bottom.modifier.insert(top.modifier);
+                bottom.skip |= top.skip;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::style::{Modifier, Style};
+
+    fn cell(symbol: &str, style: Style) -> Cell {
+        let mut cell = Cell::default();
+        cell.set_symbol(symbol).set_style(style);
+        cell
+    }
+
+    #[rstest]
+    #[case::replace(BlendMode::Replace, cell("b", Style::new().fg(Color::Blue)))]
+    #[case::skip_empty(BlendMode::SkipEmpty, cell("b", Style::new().fg(Color::Blue)))]
+    #[case::keep_fg(
+        BlendMode::KeepFg,
+        cell("a", Style::new().fg(Color::Red).add_modifier(Modifier::BOLD))
+    )]
+    #[case::keep_bg(
+        BlendMode::KeepBg,
+        cell("b", Style::new().fg(Color::Blue).bg(Color::Green))
+    )]
+    #[case::overlay(
+        BlendMode::Overlay,
+        cell("b", Style::new().fg(Color::Blue).bg(Color::Green).add_modifier(Modifier::BOLD))
+    )]
+    fn blend(#[case] mode: BlendMode, #[case] expected: Cell) {
+        let mut bottom = cell(
+            "a",
+            Style::new()
+                .fg(Color::Red)
+                .bg(Color::Green)
+                .add_modifier(Modifier::BOLD),
+        );
+        mode.blend(&mut bottom, &cell("b", Style::new().fg(Color::Blue)));
+        assert_eq!(bottom, expected);
+    }
+
+    #[rstest]
+    #[case::replace(BlendMode::Replace, Cell::default())]
+    #[case::skip_empty(BlendMode::SkipEmpty, cell("a", Style::new().fg(Color::Red)))]
+    #[case::overlay(BlendMode::Overlay, cell("a", Style::new().fg(Color::Red)))]
+    fn blend_empty(#[case] mode: BlendMode, #[case] expected: Cell) {
+        let mut bottom = cell("a", Style::new().fg(Color::Red));
+        mode.blend(&mut bottom, &Cell::default());
+        assert_eq!(bottom, expected);
+    }
+}
@@ -1,9 +1,18 @@
-use std::fmt;
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+    ops::Range,
+    slice::{Chunks, ChunksMut},
+};
 
 use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthStr;
 
-use crate::{buffer::Cell, prelude::*};
+use crate::{
+    buffer::{BlendMode, BufferDiff, Cell, CellRun, Graphic},
+    layout::Position,
+    prelude::*,
+};
 
 /// A buffer that maps to the desired content of the terminal after the draw call
 ///
@@ -40,7 +49,7 @@ use crate::{buffer::Cell, prelude::*};
 /// buf.get_mut(5, 0).set_char('x');
 /// assert_eq!(buf.get(5, 0).symbol(), "x");
 /// ```
-#[derive(Default, Clone, Eq, PartialEq, Hash)]
+#[derive(Default, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Buffer {
     /// The area represented by this buffer
@@ -48,6 +57,35 @@ pub struct Buffer {
     /// The content of the buffer. The length of this Vec should always be equal to area.width *
     /// area.height
     pub content: Vec<Cell>,
+    /// The clip regions pushed with [`Buffer::push_clip`], each one being the intersection of the
+    /// previous one and the pushed area
+    #[cfg_attr(feature = "serde", serde(skip))]
+    clip_stack: Vec<Rect>,
+    /// The cell returned by [`Buffer::get_mut`] for positions outside of the clip region, so that
+    /// the changes to it are discarded
+    #[cfg_attr(feature = "serde", serde(skip))]
+    clipped_cell: Cell,
+    /// The graphics drawn over the buffer, see [`Buffer::set_graphic`]
+    #[cfg_attr(feature = "serde", serde(skip))]
+    graphics: Vec<Graphic>,
+}
+
+/// Buffers are equal if they have the same area, content and graphics, regardless of their clip
+/// regions
+impl PartialEq for Buffer {
+    fn eq(&self, other: &Self) -> bool {
+        self.area == other.area && self.content == other.content && self.graphics == other.graphics
+    }
+}
+
+impl Eq for Buffer {}
+
+impl Hash for Buffer {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.area.hash(state);
+        self.content.hash(state);
+        self.graphics.hash(state);
+    }
 }
 
 impl Buffer {
@@ -62,7 +100,13 @@ impl Buffer {
     pub fn filled(area: Rect, cell: &Cell) -> Self {
         let size = area.area() as usize;
         let content = vec![cell.clone(); size];
-        Self { area, content }
+        Self {
+            area,
+            content,
+            clip_stack: Vec::new(),
+            clipped_cell: Cell::default(),
+            graphics: Vec::new(),
+        }
     }
 
     /// Returns a Buffer containing the given lines
@@ -100,12 +144,84 @@ impl Buffer {
     }
 
     /// Returns a mutable reference to Cell at the given coordinates
+    ///
+    /// If the coordinates are outside of the clip region (see [`Buffer::push_clip`]), the changes
+    /// to the returned cell are discarded.
     #[track_caller]
     pub fn get_mut(&mut self, x: u16, y: u16) -> &mut Cell {
         let i = self.index_of(x, y);
+        if !self.clip_stack.is_empty() && !self.clip_area().contains(Position { x, y }) {
+            self.clipped_cell.reset();
+            return &mut self.clipped_cell;
+        }
         &mut self.content[i]
     }
 
+    /// Returns an iterator over the rows of the buffer, from top to bottom.
+    ///
+    /// Each row is a slice of `area.width` cells. This is faster than calling [`Buffer::get`] for
+    /// each cell when processing large parts of the buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ratatui::prelude::*;
+    /// let buffer = Buffer::with_lines(["ab", "cd"]);
+    /// let rows: Vec<String> = buffer
+    ///     .rows()
+    ///     .map(|row| row.iter().map(|cell| cell.symbol()).collect())
+    ///     .collect();
+    /// assert_eq!(rows, ["ab", "cd"]);
+    /// ```
+    pub fn rows(&self) -> Chunks<'_, Cell> {
+        // chunks panics on a size of 0, but an empty buffer has no content to split anyway
+        self.content.chunks(usize::from(self.area.width.max(1)))
+    }
+
+    /// Returns an iterator over mutable references to the rows of the buffer, from top to bottom.
+    ///
+    /// Each row is a slice of `area.width` cells. See [`Buffer::rows`] for more details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ratatui::prelude::*;
+    /// let mut buffer = Buffer::with_lines(["ab", "cd"]);
+    /// for row in buffer.rows_mut() {
+    ///     row.reverse();
+    /// }
+    /// assert_eq!(buffer, Buffer::with_lines(["ba", "dc"]));
+    /// ```
+    pub fn rows_mut(&mut self) -> ChunksMut<'_, Cell> {
+        self.content.chunks_mut(usize::from(self.area.width.max(1)))
+    }
+
+    /// Sets all the cells of the given area to a copy of `cell`.
+    ///
+    /// The parts of the area outside of the buffer are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ratatui::{buffer::Cell, prelude::*};
+    /// let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 2));
+    /// buffer.fill_rect(Rect::new(1, 1, 5, 5), Cell::default().set_symbol("x"));
+    /// assert_eq!(buffer, Buffer::with_lines(["    ", " xxx"]));
+    /// ```
+    pub fn fill_rect(&mut self, area: Rect, cell: &Cell) {
+        let area = self.clip_area().intersection(area);
+        if area.is_empty() {
+            return;
+        }
+        let start = usize::from(area.left() - self.area.left());
+        let end = usize::from(area.right() - self.area.left());
+        let rows =
+            usize::from(area.top() - self.area.top())..usize::from(area.bottom() - self.area.top());
+        for row in self.rows_mut().take(rows.end).skip(rows.start) {
+            row[start..end].fill(cell.clone());
+        }
+    }
+
     /// Returns the index in the `Vec<Cell>` for the given global (x, y) coordinates.
     ///
     /// Global coordinates are offset by the Buffer's area offset (`x`/`y`).
@@ -218,7 +334,10 @@ impl Buffer {
             });
         let style = style.into();
         for (symbol, width) in graphemes {
-            self.get_mut(x, y).set_symbol(symbol).set_style(style);
+            self.get_mut(x, y)
+                .set_symbol(symbol)
+                .set_style(style)
+                .set_hyperlink(None);
             let next_symbol = x + width;
             x += 1;
             // Reset following cells if multi-width (they would be hidden by the grapheme),
@@ -245,6 +364,7 @@ impl Buffer {
                 remaining_width as usize,
                 line.style.patch(span.style),
             );
+            self.set_hyperlink(x..pos.0, y, span.hyperlink.as_deref());
             let w = pos.0.saturating_sub(x);
             x = pos.0;
             remaining_width = remaining_width.saturating_sub(w);
@@ -252,9 +372,59 @@ impl Buffer {
         (x, y)
     }
 
+    /// Print a line, starting at the position (x, y), and truncate it with an ellipsis if it is
+    /// wider than `max_width` or than the remaining width of the buffer.
+    ///
+    /// The width is measured in columns, so wide graphemes (e.g. CJK characters or emoji) count as
+    /// two columns. A wide grapheme that would be split at the truncation boundary is omitted
+    /// rather than cut in half, in which case the ellipsis is printed directly after the previous
+    /// grapheme. The ellipsis is printed with the style of the line. If the ellipsis itself does
+    /// not fit, the line is truncated without it.
+    ///
+    /// Returns the position after the last printed cell, like [`Buffer::set_line`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::prelude::*;
+    ///
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 8, 2));
+    /// buf.set_line_truncated(0, 0, &Line::from("Hello, world!"), 8, "…");
+    /// buf.set_line_truncated(0, 1, &Line::from("日本語のテキスト"), 8, "…");
+    /// assert_eq!(buf, Buffer::with_lines(["Hello, …", "日本語… "]));
+    /// ```
+    pub fn set_line_truncated(
+        &mut self,
+        x: u16,
+        y: u16,
+        line: &Line<'_>,
+        max_width: u16,
+        ellipsis: &str,
+    ) -> (u16, u16) {
+        let available_width = self.area.right().saturating_sub(x).min(max_width);
+        if line.width() <= available_width as usize {
+            return self.set_line(x, y, line, available_width);
+        }
+        let ellipsis_width = ellipsis.width() as u16;
+        if ellipsis_width > available_width {
+            return self.set_line(x, y, line, available_width);
+        }
+        let (x, y) = self.set_line(x, y, line, available_width - ellipsis_width);
+        self.set_stringn(x, y, ellipsis, ellipsis_width as usize, line.style)
+    }
+
     /// Print a span, starting at the position (x, y)
     pub fn set_span(&mut self, x: u16, y: u16, span: &Span<'_>, max_width: u16) -> (u16, u16) {
-        self.set_stringn(x, y, &span.content, max_width as usize, span.style)
+        let pos = self.set_stringn(x, y, &span.content, max_width as usize, span.style);
+        self.set_hyperlink(x..pos.0, y, span.hyperlink.as_deref());
+        pos
+    }
+
+    /// Sets the hyperlink of the cells in the given range of columns of a row
+    fn set_hyperlink(&mut self, columns: Range<u16>, y: u16, hyperlink: Option<&str>) {
+        for x in columns {
+            self.get_mut(x, y).set_hyperlink(hyperlink);
+        }
     }
 
     /// Set the style of all cells in the given area.
@@ -263,7 +433,7 @@ impl Buffer {
     /// your own type that implements [`Into<Style>`]).
     pub fn set_style<S: Into<Style>>(&mut self, area: Rect, style: S) {
         let style = style.into();
-        let area = self.area.intersection(area);
+        let area = self.clip_area().intersection(area);
         for y in area.top()..area.bottom() {
             for x in area.left()..area.right() {
                 self.get_mut(x, y).set_style(style);
@@ -281,13 +451,98 @@ impl Buffer {
             self.content.resize(length, Cell::default());
         }
         self.area = area;
+        self.clip_stack.clear();
+        self.graphics.clear();
     }
 
-    /// Reset all cells in the buffer
+    /// Reset all cells in the buffer, and remove its clip regions and graphics
     pub fn reset(&mut self) {
         for cell in &mut self.content {
             cell.reset();
         }
+        self.clip_stack.clear();
+        self.graphics.clear();
+    }
+
+    /// Draws a graphic over the buffer.
+    ///
+    /// The cells of the area of the graphic are marked to be skipped (see [`Cell::skip`]), so the
+    /// terminal does not draw over the graphic. The graphic is ignored if its area is not fully
+    /// inside of the clip region (see [`Buffer::push_clip`]), as a terminal cannot clip it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::{buffer::Graphic, prelude::*};
+    ///
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 4, 2));
+    /// let graphic = Graphic::new(Rect::new(1, 0, 2, 2), "\x1b_Ga=p,i=1\x1b\\");
+    /// buf.set_graphic(graphic.clone());
+    /// assert_eq!(buf.graphics(), [graphic]);
+    /// assert!(buf.get(1, 1).skip);
+    /// ```
+    pub fn set_graphic(&mut self, graphic: Graphic) {
+        let area = graphic.area;
+        if area.is_empty() || self.clip_area().intersection(area) != area {
+            return;
+        }
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                self.get_mut(x, y).set_skip(true);
+            }
+        }
+        self.graphics.push(graphic);
+    }
+
+    /// Returns the graphics drawn over the buffer, see [`Buffer::set_graphic`]
+    pub fn graphics(&self) -> &[Graphic] {
+        &self.graphics
+    }
+
+    /// Restricts the following changes to the buffer to the given area.
+    ///
+    /// Until the matching call to [`Buffer::pop_clip`], the methods that write to the buffer
+    /// ([`Buffer::get_mut`], [`Buffer::set_string`], [`Buffer::set_line`], [`Buffer::set_style`],
+    /// etc.) leave the cells outside of the clip region unchanged. This makes it safe to render
+    /// widgets that draw outside of the area they are given, as they cannot overwrite their
+    /// neighbors. Clip regions can be nested, in which case the changes are restricted to the
+    /// intersection of the regions, see [`Buffer::clip_area`].
+    ///
+    /// Direct access to the cells with [`Buffer::content`] or [`Buffer::rows_mut`] is not clipped.
+    /// The clip regions are removed when the buffer is resized or reset.
+    ///
+    /// Prefer [`Frame::with_clip`] when rendering a frame, as it pops the region automatically.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::prelude::*;
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+    /// buf.push_clip(Rect::new(0, 0, 3, 1));
+    /// buf.set_string(0, 0, "abcdef", Style::new());
+    /// buf.pop_clip();
+    /// assert_eq!(buf, Buffer::with_lines(["abc   "]));
+    /// ```
+    ///
+    /// [`Frame::with_clip`]: crate::terminal::Frame::with_clip
+    pub fn push_clip(&mut self, area: Rect) {
+        let area = area.intersection(self.clip_area());
+        self.clip_stack.push(area);
+    }
+
+    /// Removes the clip region that was pushed last with [`Buffer::push_clip`], and returns it.
+    ///
+    /// Returns `None` if there is no clip region.
+    pub fn pop_clip(&mut self) -> Option<Rect> {
+        self.clip_stack.pop()
+    }
+
+    /// Returns the area that the changes to the buffer are currently restricted to.
+    ///
+    /// This is the intersection of the clip regions pushed with [`Buffer::push_clip`], or the area
+    /// of the buffer if there is none. Widgets can use it to skip drawing what would be clipped.
+    pub fn clip_area(&self) -> Rect {
+        self.clip_stack.last().copied().unwrap_or(self.area)
     }
 
     /// Merge an other buffer into this one
@@ -317,6 +572,34 @@ impl Buffer {
             self.content[k] = other.content[i].clone();
         }
         self.area = area;
+        self.graphics.extend(other.graphics.iter().cloned());
+    }
+
+    /// Draws an other buffer over this one, combining their cells with the given [`BlendMode`].
+    ///
+    /// Unlike [`Buffer::merge`], the area of this buffer does not change: only the cells where the
+    /// areas of the buffers intersect are combined. This makes it possible to composite buffers
+    /// that were rendered separately, such as a cached popup over the rest of the UI.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::{buffer::BlendMode, prelude::*};
+    ///
+    /// let mut buf = Buffer::with_lines(["abcd", "efgh"]);
+    /// let mut popup = Buffer::with_lines(["xy"]);
+    /// popup.area = Rect::new(1, 1, 2, 1);
+    /// buf.merge_with(&popup, BlendMode::Replace);
+    /// assert_eq!(buf, Buffer::with_lines(["abcd", "exyh"]));
+    /// ```
+    pub fn merge_with(&mut self, other: &Self, mode: BlendMode) {
+        let area = self.clip_area().intersection(other.area);
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                let top = other.get(x, y);
+                mode.blend(self.get_mut(x, y), top);
+            }
+        }
     }
 
     /// Builds a minimal sequence of coordinates and Cells necessary to update the UI from
@@ -370,6 +653,60 @@ impl Buffer {
         }
         updates
     }
+
+    /// Builds the same updates as [`Buffer::diff`], grouped into runs of adjacent cells.
+    ///
+    /// Updates that are next to each other on the same row are merged into a single [`CellRun`],
+    /// so a backend can move the cursor once per run instead of once per cell. A cell with a wide
+    /// symbol is adjacent to the cell that follows its trailing columns.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::prelude::*;
+    ///
+    /// let previous = Buffer::with_lines(["abcdef", "ghijkl"]);
+    /// let next = Buffer::with_lines(["aBCdeF", "ghijkl"]);
+    /// let runs = previous.diff_runs(&next);
+    /// assert_eq!(runs.len(), 2);
+    /// assert_eq!((runs[0].x, runs[0].y, runs[0].width()), (1, 0, 2));
+    /// assert_eq!((runs[1].x, runs[1].y, runs[1].width()), (5, 0, 1));
+    /// ```
+    pub fn diff_runs<'a>(&self, other: &'a Self) -> Vec<CellRun<'a>> {
+        let mut runs: Vec<CellRun<'a>> = vec![];
+        // the column after the last run, tracked here as CellRun::end measures the whole run
+        let mut end = 0;
+        for (x, y, cell) in self.diff(other) {
+            match runs.last_mut() {
+                Some(run) if run.y == y && end == x => run.cells.push(cell),
+                _ => runs.push(CellRun::new(x, y, vec![cell])),
+            }
+            end = x.saturating_add(cell.symbol().width().max(1) as u16);
+        }
+        runs
+    }
+
+    /// Asserts that the buffer is equal to the expected buffer, including the style of its cells.
+    ///
+    /// # Panics
+    ///
+    /// When the buffers are not equal, a panic occurs with a [`BufferDiff`] showing both buffers
+    /// side by side and listing the symbol, colors and modifiers of the cells that differ.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::prelude::*;
+    ///
+    /// let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 1));
+    /// buffer.set_string(0, 0, "hello", Style::new().red());
+    /// buffer.assert_styled_eq(&Buffer::with_lines(["hello".red()]));
+    /// ```
+    #[track_caller]
+    pub fn assert_styled_eq(&self, expected: &Self) {
+        let diff = BufferDiff::new(expected, self);
+        assert!(diff.is_empty(), "{diff}");
+    }
 }
 
 impl fmt::Debug for Buffer {
@@ -846,6 +1183,94 @@ mod tests {
         assert_eq!(diff, vec![(0, 0, &cell("4"))],);
     }
 
+    #[rstest]
+    #[case::fits("abc", 5, "abc  ")]
+    #[case::exact("abcde", 5, "abcde")]
+    #[case::truncated("abcdefg", 5, "abcd…")]
+    #[case::wide_at_boundary("日本語", 5, "日本…")]
+    #[case::wide_before_ellipsis("a日本語", 5, "a日… ")]
+    #[case::limited_by_max_width("abcdefg", 3, "ab…  ")]
+    fn set_line_truncated(#[case] content: &str, #[case] max_width: u16, #[case] expected: &str) {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 1));
+        buffer.set_line_truncated(0, 0, &Line::from(content), max_width, "…");
+        assert_eq!(buffer, Buffer::with_lines([expected]));
+    }
+
+    #[test]
+    fn set_line_truncated_styles_ellipsis_with_line_style() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 1));
+        let line = Line::from(vec!["ab".red(), "cde".blue()]).bold();
+        let pos = buffer.set_line_truncated(0, 0, &line, 4, "..");
+        assert_eq!(pos, (4, 0));
+        let mut expected = Buffer::with_lines(["ab.."]);
+        expected.set_style(Rect::new(0, 0, 2, 1), Style::new().red().bold());
+        expected.set_style(Rect::new(2, 0, 2, 1), Style::new().bold());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn set_line_truncated_without_room_for_ellipsis() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 1));
+        buffer.set_line_truncated(0, 0, &Line::from("abcdef"), 3, "....");
+        assert_eq!(buffer, Buffer::with_lines(["abc"]));
+    }
+
+    #[test]
+    fn set_line_hyperlinks() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 1));
+        let line = Line::from(vec![
+            Span::raw("ab"),
+            Span::raw("cd").hyperlink("https://ratatui.rs"),
+        ]);
+        buffer.set_line(0, 0, &line, 5);
+        let hyperlinks: Vec<_> = buffer.content.iter().map(Cell::hyperlink).collect();
+        let url = Some("https://ratatui.rs");
+        assert_eq!(hyperlinks, [None, None, url, url, None]);
+    }
+
+    #[test]
+    fn set_string_removes_hyperlinks() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 1));
+        let line = Line::from(Span::raw("abcde").hyperlink("https://ratatui.rs"));
+        buffer.set_line(0, 0, &line, 5);
+        buffer.set_string(1, 0, "xyz", Style::new());
+        buffer.set_line(3, 0, &Line::raw("w"), 1);
+        let hyperlinks: Vec<_> = buffer.content.iter().map(Cell::hyperlink).collect();
+        let url = Some("https://ratatui.rs");
+        assert_eq!(hyperlinks, [url, None, None, None, url]);
+    }
+
+    #[test]
+    fn diff_runs() {
+        let prev = Buffer::with_lines(["abcdef", "ghijkl"]);
+        let next = Buffer::with_lines(["aBCdeF", "GHijkl"]);
+        let runs = prev.diff_runs(&next);
+        assert_eq!(
+            runs,
+            vec![
+                CellRun::new(1, 0, vec![&cell("B"), &cell("C")]),
+                CellRun::new(5, 0, vec![&cell("F")]),
+                CellRun::new(0, 1, vec![&cell("G"), &cell("H")]),
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_runs_multi_width() {
+        let prev = Buffer::with_lines(["┌Title─┐"]);
+        let next = Buffer::with_lines(["┌称号─x┐"]);
+        let runs = prev.diff_runs(&next);
+        assert_eq!(
+            runs,
+            vec![CellRun::new(
+                1,
+                0,
+                vec![&cell("称"), &cell("号"), &cell("─"), &cell("x")]
+            )]
+        );
+        assert_eq!(runs[0].width(), 6);
+    }
+
     #[test]
     fn merge() {
         let mut one = Buffer::filled(
@@ -975,6 +1400,111 @@ mod tests {
         assert_eq!(skipped, vec![true, true, false, false, false, false]);
     }
 
+    #[test]
+    fn rows() {
+        let mut buf = Buffer::with_lines(["abc", "def"]);
+        let rows = buf.rows().map(<[Cell]>::len).collect_vec();
+        assert_eq!(rows, [3, 3]);
+        buf.rows_mut().nth(1).unwrap()[0].set_symbol("x");
+        assert_eq!(buf, Buffer::with_lines(["abc", "xef"]));
+    }
+
+    #[test]
+    fn rows_empty() {
+        let buf = Buffer::empty(Rect::new(0, 0, 0, 3));
+        assert_eq!(buf.rows().count(), 0);
+    }
+
+    #[rstest]
+    #[case::inside(Rect::new(2, 11, 2, 1), ["xxxx", "x..x", "xxxx"])]
+    #[case::overlapping(Rect::new(0, 9, 2, 2), [".xxx", "xxxx", "xxxx"])]
+    #[case::outside(Rect::new(5, 10, 2, 2), ["xxxx", "xxxx", "xxxx"])]
+    fn fill_rect(#[case] area: Rect, #[case] expected: [&str; 3]) {
+        let mut buf = Buffer::filled(Rect::new(1, 10, 4, 3), Cell::default().set_symbol("x"));
+        buf.fill_rect(area, Cell::default().set_symbol("."));
+        let mut expected = Buffer::with_lines(expected);
+        expected.area = buf.area;
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn merge_with() {
+        let mut buf = Buffer::with_lines(["abc", "def"]);
+        buf.set_style(buf.area, Style::new().red());
+        let mut other = Buffer::empty(Rect::new(1, 1, 3, 2));
+        other.set_string(2, 1, "x", Style::new().on_blue());
+        buf.merge_with(&other, BlendMode::SkipEmpty);
+
+        let mut expected = Buffer::with_lines(["abc", "dex"]);
+        expected.set_style(expected.area, Style::new().red());
+        expected.get_mut(2, 1).set_style(Style::reset().on_blue());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn clip() {
+        let mut buf = Buffer::with_lines(["xxxx", "xxxx", "xxxx"]);
+        buf.push_clip(Rect::new(1, 0, 3, 2));
+        assert_eq!(buf.clip_area(), Rect::new(1, 0, 3, 2));
+        buf.push_clip(Rect::new(0, 1, 3, 2));
+        assert_eq!(buf.clip_area(), Rect::new(1, 1, 2, 1));
+        buf.set_style(buf.area, Style::new().red());
+        buf.set_string(0, 1, "abcd", Style::new());
+        assert_eq!(buf.pop_clip(), Some(Rect::new(1, 1, 2, 1)));
+        buf.set_string(0, 0, "ef", Style::new());
+        assert_eq!(buf.pop_clip(), Some(Rect::new(1, 0, 3, 2)));
+        assert_eq!(buf.pop_clip(), None);
+        assert_eq!(buf.clip_area(), buf.area);
+
+        let mut expected = Buffer::with_lines(["xfxx", "xbcx", "xxxx"]);
+        expected.set_style(Rect::new(1, 1, 2, 1), Style::new().red());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn clip_is_ignored_by_equality() {
+        let mut buf = Buffer::with_lines(["xx"]);
+        buf.push_clip(Rect::new(0, 0, 1, 1));
+        assert_eq!(buf, Buffer::with_lines(["xx"]));
+    }
+
+    #[test]
+    fn clip_is_removed_by_resize_and_reset() {
+        let mut buf = Buffer::with_lines(["xx"]);
+        buf.push_clip(Rect::new(0, 0, 1, 1));
+        buf.resize(Rect::new(0, 0, 3, 1));
+        assert_eq!(buf.clip_area(), buf.area);
+        assert_eq!(buf.pop_clip(), None);
+
+        buf.push_clip(Rect::new(0, 0, 1, 1));
+        buf.reset();
+        buf.set_string(0, 0, "abc", Style::new());
+        assert_eq!(buf, Buffer::with_lines(["abc"]));
+    }
+
+    #[test]
+    fn clip_get_mut_and_fill_rect() {
+        let mut buf = Buffer::with_lines(["xxx"]);
+        buf.push_clip(Rect::new(1, 0, 1, 1));
+        buf.get_mut(0, 0).set_symbol("a");
+        buf.get_mut(1, 0).set_symbol("b");
+        buf.fill_rect(buf.area, Cell::default().set_style(Style::new().red()));
+        buf.pop_clip();
+        let mut expected = Buffer::with_lines(["x x"]);
+        expected.get_mut(1, 0).set_style(Style::new().red());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn clip_outside_of_buffer() {
+        let mut buf = Buffer::with_lines(["xx"]);
+        buf.push_clip(Rect::new(5, 5, 2, 2));
+        assert_eq!(buf.clip_area().area(), 0);
+        buf.set_string(0, 0, "ab", Style::new());
+        buf.pop_clip();
+        assert_eq!(buf, Buffer::with_lines(["xx"]));
+    }
+
     #[test]
     fn with_lines_accepts_into_lines() {
         use crate::style::Stylize;
@@ -983,4 +1513,17 @@ mod tests {
         buf.set_string(0, 1, "bar", Style::new().blue());
         assert_eq!(buf, Buffer::with_lines(["foo".red(), "bar".blue()]));
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serialize_then_deserialize() -> Result<(), serde_json::Error> {
+        let mut buffer = Buffer::with_lines([
+            Line::from(vec!["error".red().bold(), ": ".into(), "oops".on_blue()]),
+            Line::from(Span::raw("link").hyperlink("https://ratatui.rs")),
+        ]);
+        buffer.get_mut(1, 1).set_skip(true);
+        let json = serde_json::to_string(&buffer)?;
+        assert_eq!(serde_json::from_str::<Buffer>(&json)?, buffer);
+        Ok(())
+    }
 }
@@ -11,7 +11,8 @@ pub struct Cell {
     /// This accepts unicode grapheme clusters which might take up more than one cell.
     ///
     /// This is a [`CompactString`] which is a wrapper around [`String`] that uses a small inline
-    /// buffer for short strings.
+    /// buffer for short strings. Graphemes of up to 24 bytes (12 bytes on 32-bit targets), which
+    /// includes most graphemes made of multiple code points, are stored without heap allocation.
     ///
     /// See <https://github.com/ratatui-org/ratatui/pull/601> for more information.
     symbol: CompactString,
@@ -31,6 +32,9 @@ pub struct Cell {
 
     /// Whether the cell should be skipped when copying (diffing) the buffer to the screen.
     pub skip: bool,
+
+    /// The target of the hyperlink (OSC 8) that the cell belongs to, if any.
+    hyperlink: Option<CompactString>,
 }
 
 impl Cell {
@@ -53,6 +57,24 @@ impl Cell {
         self
     }
 
+    /// Gets the target of the hyperlink that the cell belongs to, if any.
+    #[must_use]
+    pub fn hyperlink(&self) -> Option<&str> {
+        self.hyperlink.as_deref()
+    }
+
+    /// Sets the target of the hyperlink that the cell belongs to.
+    ///
+    /// Backends that support hyperlinks (OSC 8) make the cells that share the same target
+    /// clickable. Passing `None` removes the hyperlink from the cell.
+    ///
+    /// Control characters are removed from the target, as they could end the escape sequence of
+    /// the hyperlink early and inject other escape sequences.
+    pub fn set_hyperlink(&mut self, hyperlink: Option<&str>) -> &mut Self {
+        self.hyperlink = hyperlink.map(|url| url.chars().filter(|c| !c.is_control()).collect());
+        self
+    }
+
     /// Sets the foreground color of the cell.
     pub fn set_fg(&mut self, color: Color) -> &mut Self {
         self.fg = color;
@@ -119,6 +141,7 @@ impl Cell {
         }
         self.modifier = Modifier::empty();
         self.skip = false;
+        self.hyperlink = None;
     }
 }
 
@@ -132,6 +155,7 @@ impl Default for Cell {
             underline_color: Color::Reset,
             modifier: Modifier::empty(),
             skip: false,
+            hyperlink: None,
         }
     }
 }
@@ -149,4 +173,32 @@ mod tests {
         cell.set_symbol("👨‍👩‍👧‍👦"); // Multiple code units combined with ZWJ
         assert_eq!(cell.symbol(), "👨‍👩‍👧‍👦");
     }
+
+    #[test]
+    fn symbol_is_inline() {
+        let mut cell = Cell::default();
+        for symbol in ["a", "あ", "e\u{301}", "👩‍💻", "🇫🇷"] {
+            cell.set_symbol(symbol);
+            assert!(!cell.symbol.is_heap_allocated(), "{symbol}");
+        }
+        cell.set_char('あ');
+        assert!(!cell.symbol.is_heap_allocated());
+    }
+
+    #[test]
+    fn hyperlink() {
+        let mut cell = Cell::default();
+        assert_eq!(cell.hyperlink(), None);
+        cell.set_hyperlink(Some("https://ratatui.rs"));
+        assert_eq!(cell.hyperlink(), Some("https://ratatui.rs"));
+        cell.reset();
+        assert_eq!(cell.hyperlink(), None);
+    }
+
+    #[test]
+    fn hyperlink_strips_control_characters() {
+        let mut cell = Cell::default();
+        cell.set_hyperlink(Some("https://a\x1b\\\x1b]0;title\x07.rs\u{9c}"));
+        assert_eq!(cell.hyperlink(), Some("https://a\\]0;title.rs"));
+    }
 }
@@ -0,0 +1,44 @@
+use unicode_width::UnicodeWidthStr;
+
+use crate::buffer::Cell;
+
+/// A run of changed cells that are next to each other on the same row of a [`Buffer`]
+///
+/// Runs are returned by [`Buffer::diff_runs`]. A backend can draw a run by moving the cursor once
+/// to the position of the run and then writing all of its cells, instead of moving the cursor for
+/// every cell.
+///
+/// Cells containing a wide symbol (e.g. CJK characters or emoji) occupy more than one column, so
+/// the number of cells in a run can be lower than its [width](CellRun::width).
+///
+/// [`Buffer`]: crate::buffer::Buffer
+/// [`Buffer::diff_runs`]: crate::buffer::Buffer::diff_runs
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct CellRun<'a> {
+    /// The column of the first cell of the run
+    pub x: u16,
+    /// The row of the run
+    pub y: u16,
+    /// The cells of the run, from left to right
+    pub cells: Vec<&'a Cell>,
+}
+
+impl<'a> CellRun<'a> {
+    /// Creates a new run starting at the given position
+    pub const fn new(x: u16, y: u16, cells: Vec<&'a Cell>) -> Self {
+        Self { x, y, cells }
+    }
+
+    /// Returns the number of columns covered by the run
+    pub fn width(&self) -> u16 {
+        self.cells
+            .iter()
+            .map(|cell| cell.symbol().width().max(1) as u16)
+            .sum()
+    }
+
+    /// Returns the column after the last cell of the run
+    pub fn end(&self) -> u16 {
+        self.x.saturating_add(self.width())
+    }
+}
@@ -0,0 +1,277 @@
+use std::fmt::{self, Write};
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    buffer::{Buffer, Cell},
+    layout::Position,
+};
+
+const EXPECTED_COLOR: &str = "\x1b[32m";
+const ACTUAL_COLOR: &str = "\x1b[31m";
+const RESET_COLOR: &str = "\x1b[0m";
+
+/// A comparison of an expected and an actual [`Buffer`], displayed as a diff of their cells.
+///
+/// Unlike a comparison of the text of the buffers, this takes the style of the cells into account.
+/// The [`Display`] implementation shows the content of both buffers side by side with the cells
+/// that differ highlighted (green in the expected buffer and red in the actual buffer), followed
+/// by the list of these cells with the symbol, colors and modifiers that differ.
+///
+/// This is used by [`Buffer::assert_styled_eq`] and [`TestBackend::assert_buffer`] to report the
+/// differences when the buffers are not equal.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui::{buffer::BufferDiff, prelude::*};
+///
+/// let expected = Buffer::with_lines(["ab".red()]);
+/// let actual = Buffer::with_lines(["ab".blue()]);
+/// let diff = BufferDiff::new(&expected, &actual);
+/// assert_eq!(diff.cells().count(), 2);
+/// println!("{diff}");
+/// ```
+///
+/// [`Display`]: std::fmt::Display
+/// [`TestBackend::assert_buffer`]: crate::backend::TestBackend::assert_buffer
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct BufferDiff<'a> {
+    expected: &'a Buffer,
+    actual: &'a Buffer,
+}
+
+impl<'a> BufferDiff<'a> {
+    /// Creates a new `BufferDiff` comparing the `actual` buffer to the `expected` one.
+    pub const fn new(expected: &'a Buffer, actual: &'a Buffer) -> Self {
+        Self { expected, actual }
+    }
+
+    /// Returns true if the buffers are equal.
+    pub fn is_empty(&self) -> bool {
+        self.expected == self.actual
+    }
+
+    /// Returns the position of the cells that differ, with the expected and the actual cell.
+    ///
+    /// When the areas of the buffers are not equal, the cells cannot be matched and nothing is
+    /// returned.
+    pub fn cells(&self) -> impl Iterator<Item = (Position, &'a Cell, &'a Cell)> {
+        let Self { expected, actual } = *self;
+        let same_area = expected.area == actual.area;
+        expected
+            .content
+            .iter()
+            .zip(&actual.content)
+            .enumerate()
+            .filter(move |(_, (expected, actual))| same_area && expected != actual)
+            .map(move |(i, (expected_cell, actual_cell))| {
+                let (x, y) = expected.pos_of(i);
+                (Position { x, y }, expected_cell, actual_cell)
+            })
+    }
+}
+
+impl fmt::Display for BufferDiff<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self { expected, actual } = *self;
+        let same_area = expected.area == actual.area;
+        if same_area {
+            let count = self.cells().count();
+            writeln!(f, "buffer contents not equal ({count} cells differ)")?;
+        } else {
+            writeln!(
+                f,
+                "buffer areas not equal\nexpected: {:?}\nactual:   {:?}",
+                expected.area, actual.area
+            )?;
+        }
+
+        let width = (expected.area.width as usize + 2).max("expected".len());
+        writeln!(f, "{:width$}   actual", "expected")?;
+        let height = expected.area.height.max(actual.area.height);
+        for y in 0..height {
+            let (expected_row, expected_width) = row_view(expected, actual, y, EXPECTED_COLOR);
+            let (actual_row, _) = row_view(actual, expected, y, ACTUAL_COLOR);
+            let padding = width.saturating_sub(expected_width);
+            writeln!(f, "{expected_row}{:padding$}   {actual_row}", "")?;
+        }
+
+        if same_area {
+            for (position, expected, actual) in self.cells() {
+                writeln!(
+                    f,
+                    "({}, {}): {}",
+                    position.x,
+                    position.y,
+                    cell_diff(expected, actual)
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Renders the row `y` of the buffer in quotes, with the cells that differ from the cells of the
+/// other buffer highlighted. Returns the row and its width, without the highlighting.
+fn row_view(buffer: &Buffer, other: &Buffer, y: u16, color: &str) -> (String, usize) {
+    let mut view = String::new();
+    let mut width = 2;
+    if y >= buffer.area.height {
+        return (view, 0);
+    }
+    let same_area = buffer.area == other.area;
+    let start = y as usize * buffer.area.width as usize;
+    let cells = &buffer.content[start..start + buffer.area.width as usize];
+    let mut skip: usize = 0;
+    view.push('"');
+    for (x, cell) in cells.iter().enumerate() {
+        if skip == 0 {
+            if same_area && *cell != other.content[start + x] {
+                let _ = write!(view, "{color}{}{RESET_COLOR}", cell.symbol());
+            } else {
+                view.push_str(cell.symbol());
+            }
+            width += cell.symbol().width();
+        }
+        skip = std::cmp::max(skip, cell.symbol().width()).saturating_sub(1);
+    }
+    view.push('"');
+    (view, width)
+}
+
+/// Describes the attributes that differ between the expected and the actual cell.
+fn cell_diff(expected: &Cell, actual: &Cell) -> String {
+    let mut diff = vec![];
+    if expected.symbol() != actual.symbol() {
+        diff.push(format!(
+            "symbol {:?} != {:?}",
+            expected.symbol(),
+            actual.symbol()
+        ));
+    }
+    if expected.fg != actual.fg {
+        diff.push(format!("fg {:?} != {:?}", expected.fg, actual.fg));
+    }
+    if expected.bg != actual.bg {
+        diff.push(format!("bg {:?} != {:?}", expected.bg, actual.bg));
+    }
+    #[cfg(feature = "underline-color")]
+    if expected.underline_color != actual.underline_color {
+        diff.push(format!(
+            "underline {:?} != {:?}",
+            expected.underline_color, actual.underline_color
+        ));
+    }
+    if expected.modifier != actual.modifier {
+        diff.push(format!(
+            "modifier {:?} != {:?}",
+            expected.modifier, actual.modifier
+        ));
+    }
+    if expected.skip != actual.skip {
+        diff.push(format!("skip {} != {}", expected.skip, actual.skip));
+    }
+    if expected.hyperlink() != actual.hyperlink() {
+        diff.push(format!(
+            "hyperlink {:?} != {:?}",
+            expected.hyperlink(),
+            actual.hyperlink()
+        ));
+    }
+    diff.join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::{
+        layout::Rect,
+        style::{Color, Modifier, Stylize},
+        text::Line,
+    };
+
+    #[test]
+    fn equal() {
+        let buffer = Buffer::with_lines(["ab".red()]);
+        let diff = BufferDiff::new(&buffer, &buffer);
+        assert!(diff.is_empty());
+        assert_eq!(diff.cells().count(), 0);
+    }
+
+    #[test]
+    fn cells() {
+        let expected = Buffer::with_lines(["abc"]);
+        let actual = Buffer::with_lines([Line::from(vec!["a".into(), "b".bold(), "d".into()])]);
+        let diff = BufferDiff::new(&expected, &actual);
+        assert!(!diff.is_empty());
+        assert_eq!(
+            diff.cells()
+                .map(|(position, expected, actual)| (position, expected.symbol(), actual.symbol()))
+                .collect::<Vec<_>>(),
+            [
+                (Position::new(1, 0), "b", "b"),
+                (Position::new(2, 0), "c", "d")
+            ]
+        );
+    }
+
+    #[test]
+    fn display() {
+        let expected = Buffer::with_lines(["ab", "cd"]);
+        let actual = Buffer::with_lines(["ab".into(), Line::from(vec!["c".red(), "e".bold()])]);
+        assert_eq!(
+            BufferDiff::new(&expected, &actual).to_string(),
+            [
+                "buffer contents not equal (2 cells differ)",
+                "expected   actual",
+                "\"ab\"       \"ab\"",
+                "\"\x1b[32mc\x1b[0m\x1b[32md\x1b[0m\"       \"\x1b[31mc\x1b[0m\x1b[31me\x1b[0m\"",
+                "(0, 1): fg Reset != Red",
+                "(1, 1): symbol \"d\" != \"e\", modifier NONE != BOLD",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn display_different_areas() {
+        let expected = Buffer::with_lines(["a"]);
+        let actual = Buffer::empty(Rect::new(0, 0, 2, 2));
+        assert_eq!(
+            BufferDiff::new(&expected, &actual).to_string(),
+            [
+                "buffer areas not equal",
+                "expected: Rect { x: 0, y: 0, width: 1, height: 1 }",
+                "actual:   Rect { x: 0, y: 0, width: 2, height: 2 }",
+                "expected   actual",
+                "\"a\"        \"  \"",
+                "           \"  \"",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn display_multi_width() {
+        let expected = Buffer::with_lines(["😃a"]);
+        let mut actual = expected.clone();
+        actual.get_mut(2, 0).set_bg(Color::Blue);
+        actual.get_mut(2, 0).modifier = Modifier::ITALIC;
+        assert_eq!(
+            BufferDiff::new(&expected, &actual).to_string(),
+            [
+                "buffer contents not equal (1 cells differ)",
+                "expected   actual",
+                "\"😃\x1b[32ma\x1b[0m\"      \"😃\x1b[31ma\x1b[0m\"",
+                "(2, 0): bg Reset != Blue, modifier NONE != ITALIC",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+}
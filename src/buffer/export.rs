@@ -0,0 +1,383 @@
+use std::fmt::Write;
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    buffer::{ansi::same_style, Buffer, Cell},
+    style::{Color, Modifier},
+};
+
+/// The foreground color of the cells with the [`Color::Reset`] foreground (gray)
+const DEFAULT_FG: u32 = 0xe5_e5e5;
+
+/// The background color of the cells with the [`Color::Reset`] background (black)
+const DEFAULT_BG: u32 = 0x00_0000;
+
+/// The width of a cell in an SVG document, in pixels
+const CELL_WIDTH: usize = 9;
+
+/// The height of a cell in an SVG document, in pixels
+const CELL_HEIGHT: usize = 18;
+
+/// The font size in an SVG document, in pixels. Monospace fonts are usually 0.6 times as wide as
+/// they are high, so the characters fit in [`CELL_WIDTH`].
+const FONT_SIZE: usize = 15;
+
+impl Buffer {
+    /// Renders the buffer as a standalone HTML document.
+    ///
+    /// The content is written in a `<pre>` element with a monospace font, with a `<span>` for each
+    /// run of cells that have the same style. The named and indexed colors are converted to the
+    /// colors of the xterm palette, and the reset colors are light gray on black. Hyperlinks are
+    /// written as `<a>` elements.
+    ///
+    /// This can be used to produce screenshots of an application for documentation or bug reports.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::prelude::*;
+    ///
+    /// let buffer = Buffer::with_lines(["error".red().bold()]);
+    /// let html = buffer.to_html();
+    /// assert!(html.contains(r#"<span style="color:#cd0000;font-weight:bold">error</span>"#));
+    /// ```
+    pub fn to_html(&self) -> String {
+        let mut html = String::new();
+        let _ = write!(
+            html,
+            concat!(
+                "<!DOCTYPE html>\n",
+                "<html>\n",
+                "<head>\n",
+                "<meta charset=\"utf-8\">\n",
+                "<style>\n",
+                "pre {{ background-color: #{:06x}; color: #{:06x}; font-family: monospace; ",
+                "line-height: 1.2; padding: 1em; display: inline-block; }}\n",
+                "a {{ color: inherit; }}\n",
+                "</style>\n",
+                "</head>\n",
+                "<body>\n",
+                "<pre>",
+            ),
+            DEFAULT_BG, DEFAULT_FG
+        );
+        for (y, row) in self.rows().take(self.area.height as usize).enumerate() {
+            if y > 0 {
+                html.push('\n');
+            }
+            for run in runs(row) {
+                let style = css_style(run.cell);
+                if let Some(hyperlink) = run.cell.hyperlink() {
+                    let _ = write!(html, "<a href=\"{}\">", escape(hyperlink));
+                }
+                if style.is_empty() {
+                    html.push_str(&escape(&run.text));
+                } else {
+                    let _ = write!(html, "<span style=\"{style}\">{}</span>", escape(&run.text));
+                }
+                if run.cell.hyperlink().is_some() {
+                    html.push_str("</a>");
+                }
+            }
+        }
+        html.push_str("</pre>\n</body>\n</html>\n");
+        html
+    }
+
+    /// Renders the buffer as a standalone SVG document.
+    ///
+    /// Each cell is drawn as a rectangle of 9 by 18 pixels, with the background colors drawn as
+    /// rectangles and each run of cells that have the same style drawn as a `<text>` element
+    /// stretched to the width of its cells, so that the columns stay aligned whatever the font.
+    /// The colors are converted as described in [`Buffer::to_html`].
+    ///
+    /// This can be used to produce screenshots of an application for documentation or bug reports,
+    /// e.g. in a README where HTML is not supported.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use ratatui::prelude::*;
+    ///
+    /// let buffer = Buffer::with_lines(["hello".on_blue()]);
+    /// let svg = buffer.to_svg();
+    /// assert!(svg.starts_with("<svg"));
+    /// assert!(svg.contains(r##"<rect x="0" y="0" width="45" height="18" fill="#0000ee"/>"##));
+    /// ```
+    pub fn to_svg(&self) -> String {
+        let width = self.area.width as usize * CELL_WIDTH;
+        let height = self.area.height as usize * CELL_HEIGHT;
+        let mut svg = String::new();
+        let _ = writeln!(
+            svg,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+             viewBox=\"0 0 {width} {height}\">"
+        );
+        let _ = writeln!(
+            svg,
+            "<style>text {{ font-family: monospace; font-size: {FONT_SIZE}px; white-space: pre; \
+             }}</style>"
+        );
+        let _ = writeln!(
+            svg,
+            "<rect width=\"100%\" height=\"100%\" fill=\"#{DEFAULT_BG:06x}\"/>"
+        );
+        for (y, row) in self.rows().take(self.area.height as usize).enumerate() {
+            let top = y * CELL_HEIGHT;
+            for run in runs(row) {
+                let (fg, bg) = colors(run.cell);
+                let left = run.x * CELL_WIDTH;
+                let width = run.width * CELL_WIDTH;
+                if bg != DEFAULT_BG {
+                    let _ = writeln!(
+                        svg,
+                        "<rect x=\"{left}\" y=\"{top}\" width=\"{width}\" \
+                         height=\"{CELL_HEIGHT}\" fill=\"#{bg:06x}\"/>"
+                    );
+                }
+                let decorated = run
+                    .cell
+                    .modifier
+                    .intersects(Modifier::UNDERLINED | Modifier::CROSSED_OUT | Modifier::REVERSED);
+                if run.text.trim().is_empty() && !decorated
+                    || run.cell.modifier.contains(Modifier::HIDDEN)
+                {
+                    continue;
+                }
+                let mut text = format!(
+                    "<text x=\"{left}\" y=\"{}\" textLength=\"{width}\" \
+                     lengthAdjust=\"spacingAndGlyphs\" fill=\"#{fg:06x}\"",
+                    top + CELL_HEIGHT * 3 / 4
+                );
+                let _ = write!(text, "{}", svg_attributes(run.cell.modifier));
+                let _ = write!(text, ">{}</text>", escape(&run.text));
+                if let Some(hyperlink) = run.cell.hyperlink() {
+                    text = format!("<a href=\"{}\">{text}</a>", escape(hyperlink));
+                }
+                svg.push_str(&text);
+                svg.push('\n');
+            }
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
+
+/// A run of consecutive cells of a row that have the same style
+struct Run<'a> {
+    /// The column of the first cell of the run
+    x: usize,
+    /// The number of columns of the run
+    width: usize,
+    /// The symbols of the cells, without the cells hidden by multi-width symbols
+    text: String,
+    /// The first cell of the run, which has the style of all the cells
+    cell: &'a Cell,
+}
+
+/// Splits a row into runs of cells that have the same style
+fn runs(row: &[Cell]) -> Vec<Run<'_>> {
+    let mut runs: Vec<Run> = vec![];
+    let mut skip: usize = 0;
+    for (x, cell) in row.iter().enumerate() {
+        if skip == 0 {
+            match runs.last_mut() {
+                Some(run)
+                    if same_style(run.cell, cell) && run.cell.hyperlink() == cell.hyperlink() =>
+                {
+                    run.text.push_str(cell.symbol());
+                }
+                _ => runs.push(Run {
+                    x,
+                    width: 0,
+                    text: cell.symbol().to_string(),
+                    cell,
+                }),
+            }
+        }
+        if let Some(run) = runs.last_mut() {
+            run.width += 1;
+        }
+        skip = std::cmp::max(skip, cell.symbol().width()).saturating_sub(1);
+    }
+    runs
+}
+
+/// Returns the foreground and background colors of a cell, swapped if the cell is reversed
+fn colors(cell: &Cell) -> (u32, u32) {
+    let fg = rgb(cell.fg).unwrap_or(DEFAULT_FG);
+    let bg = rgb(cell.bg).unwrap_or(DEFAULT_BG);
+    if cell.modifier.contains(Modifier::REVERSED) {
+        (bg, fg)
+    } else {
+        (fg, bg)
+    }
+}
+
+/// Converts a color to RGB, or returns `None` for [`Color::Reset`]
+fn rgb(color: Color) -> Option<u32> {
+    color
+        .to_rgb()
+        .map(|[r, g, b]| u32::from_be_bytes([0, r, g, b]))
+}
+
+/// Returns the CSS declarations of the style of a cell, without the reset colors
+fn css_style(cell: &Cell) -> String {
+    let mut declarations = vec![];
+    let reversed = cell.modifier.contains(Modifier::REVERSED);
+    let (fg, bg) = colors(cell);
+    if cell.fg != Color::Reset || reversed {
+        declarations.push(format!("color:#{fg:06x}"));
+    }
+    if cell.bg != Color::Reset || reversed {
+        declarations.push(format!("background-color:#{bg:06x}"));
+    }
+    if cell.modifier.contains(Modifier::BOLD) {
+        declarations.push("font-weight:bold".to_string());
+    }
+    if cell.modifier.contains(Modifier::DIM) {
+        declarations.push("opacity:0.5".to_string());
+    }
+    if cell.modifier.contains(Modifier::ITALIC) {
+        declarations.push("font-style:italic".to_string());
+    }
+    if let Some(decoration) = text_decoration(cell.modifier) {
+        declarations.push(format!("text-decoration:{decoration}"));
+    }
+    #[cfg(feature = "underline-color")]
+    if let Some(color) = rgb(cell.underline_color) {
+        declarations.push(format!("text-decoration-color:#{color:06x}"));
+    }
+    if cell.modifier.contains(Modifier::HIDDEN) {
+        declarations.push("visibility:hidden".to_string());
+    }
+    declarations.join(";")
+}
+
+/// Returns the SVG presentation attributes of the modifiers
+fn svg_attributes(modifier: Modifier) -> String {
+    let mut attributes = String::new();
+    if modifier.contains(Modifier::BOLD) {
+        attributes.push_str(" font-weight=\"bold\"");
+    }
+    if modifier.contains(Modifier::DIM) {
+        attributes.push_str(" opacity=\"0.5\"");
+    }
+    if modifier.contains(Modifier::ITALIC) {
+        attributes.push_str(" font-style=\"italic\"");
+    }
+    if let Some(decoration) = text_decoration(modifier) {
+        let _ = write!(attributes, " text-decoration=\"{decoration}\"");
+    }
+    attributes
+}
+
+/// Returns the value of the `text-decoration` property for the modifiers, if any
+const fn text_decoration(modifier: Modifier) -> Option<&'static str> {
+    match (
+        modifier.contains(Modifier::UNDERLINED),
+        modifier.contains(Modifier::CROSSED_OUT),
+    ) {
+        (true, true) => Some("underline line-through"),
+        (true, false) => Some("underline"),
+        (false, true) => Some("line-through"),
+        (false, false) => None,
+    }
+}
+
+/// Escapes the characters that have a special meaning in HTML and XML
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{
+        style::Stylize,
+        text::{Line, Span},
+    };
+
+    #[rstest]
+    #[case::reset(Color::Reset, None)]
+    #[case::named(Color::LightBlue, Some(0x5c_5cff))]
+    #[case::indexed_named(Color::Indexed(1), Some(0xcd_0000))]
+    #[case::indexed_cube(Color::Indexed(196), Some(0xff_0000))]
+    #[case::indexed_cube_mixed(Color::Indexed(110), Some(0x87_afd7))]
+    #[case::indexed_gray(Color::Indexed(244), Some(0x80_8080))]
+    #[case::rgb(Color::Rgb(1, 2, 3), Some(0x01_0203))]
+    fn to_rgb(#[case] color: Color, #[case] expected: Option<u32>) {
+        assert_eq!(rgb(color), expected);
+    }
+
+    #[test]
+    fn html() {
+        let buffer = Buffer::with_lines([
+            Line::from(vec!["a<b".red().bold(), " ".into(), "c".reversed()]),
+            Line::from(Span::raw("link").hyperlink("https://ratatui.rs")),
+        ]);
+        let html = buffer.to_html();
+        let content = &html[html.find("<pre>").unwrap()..html.find("</pre>").unwrap()];
+        assert_eq!(
+            content,
+            "<pre><span style=\"color:#cd0000;font-weight:bold\">a&lt;b</span> \
+             <span style=\"color:#000000;background-color:#e5e5e5\">c</span>\n\
+             <a href=\"https://ratatui.rs\">link</a> "
+        );
+        assert!(html.starts_with("<!DOCTYPE html>\n"));
+        assert!(html.ends_with("</html>\n"));
+    }
+
+    #[test]
+    fn html_multi_width() {
+        let buffer = Buffer::with_lines(["😃a"]);
+        assert!(buffer.to_html().contains("<pre>😃a</pre>"));
+    }
+
+    #[test]
+    fn svg() {
+        let buffer = Buffer::with_lines([Line::from(vec![
+            "ab".into(),
+            "  ".on_blue(),
+            "c".green().underlined(),
+        ])]);
+        assert_eq!(
+            buffer.to_svg(),
+            [
+                "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"45\" height=\"18\" \
+                 viewBox=\"0 0 45 18\">",
+                "<style>text { font-family: monospace; font-size: 15px; white-space: pre; \
+                 }</style>",
+                "<rect width=\"100%\" height=\"100%\" fill=\"#000000\"/>",
+                "<text x=\"0\" y=\"13\" textLength=\"18\" lengthAdjust=\"spacingAndGlyphs\" \
+                 fill=\"#e5e5e5\">ab</text>",
+                "<rect x=\"18\" y=\"0\" width=\"18\" height=\"18\" fill=\"#0000ee\"/>",
+                "<text x=\"36\" y=\"13\" textLength=\"9\" lengthAdjust=\"spacingAndGlyphs\" \
+                 fill=\"#00cd00\" text-decoration=\"underline\">c</text>",
+                "</svg>",
+                "",
+            ]
+            .join("\n")
+        );
+    }
+
+    #[test]
+    fn svg_multi_width() {
+        let buffer = Buffer::with_lines(["😃a"]);
+        assert!(buffer.to_svg().contains("textLength=\"27\""));
+    }
+}
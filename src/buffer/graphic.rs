@@ -0,0 +1,43 @@
+use crate::layout::Rect;
+
+/// An image drawn by the terminal over an area of a [`Buffer`]
+///
+/// Graphics are added with [`Buffer::set_graphic`] by widgets that display images with a terminal
+/// graphics protocol (see [`Image`]). They are not stored in the cells of the buffer: the
+/// [`Terminal`] writes the escape sequence of a graphic through [`Backend::write_raw`] once, when
+/// it first appears in a frame, and writes its cleanup sequence when it is no longer rendered.
+///
+/// [`Buffer`]: crate::buffer::Buffer
+/// [`Buffer::set_graphic`]: crate::buffer::Buffer::set_graphic
+/// [`Image`]: crate::widgets::Image
+/// [`Terminal`]: crate::terminal::Terminal
+/// [`Backend::write_raw`]: crate::backend::Backend::write_raw
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Graphic {
+    /// The area covered by the graphic. The escape sequence is written with the cursor at its top
+    /// left corner.
+    pub area: Rect,
+    /// The escape sequence that draws the graphic
+    pub sequence: String,
+    /// The escape sequence that removes the graphic from the screen, if the terminal does not
+    /// remove it when the cells of the area are redrawn
+    pub cleanup: Option<String>,
+}
+
+impl Graphic {
+    /// Creates a new graphic drawn over `area` by the given escape sequence
+    pub fn new<S: Into<String>>(area: Rect, sequence: S) -> Self {
+        Self {
+            area,
+            sequence: sequence.into(),
+            cleanup: None,
+        }
+    }
+
+    /// Sets the escape sequence that removes the graphic from the screen
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn cleanup<S: Into<String>>(mut self, cleanup: S) -> Self {
+        self.cleanup = Some(cleanup.into());
+        self
+    }
+}
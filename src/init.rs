@@ -0,0 +1,56 @@
+//! Convenience functions to set up the terminal for an application and to restore it afterwards.
+//!
+//! Most applications draw on the alternate screen with raw mode enabled, and need to undo these
+//! changes when they exit, including when they panic. Otherwise, the terminal is left in a state
+//! where the panic message is garbled and the shell is unusable until the `reset` command is run.
+//!
+//! Each backend has its own set of functions, available with the feature flag of the backend:
+//!
+//! - [`init`], [`restore`] and [`install_panic_hook`] for the [`CrosstermBackend`], the default
+//!   backend
+//! - [`init_termion`], [`restore_termion`] and [`install_termion_panic_hook`] for the
+//!   [`TermionBackend`]
+//! - [`init_termwiz`], [`restore_termwiz`] and [`install_termwiz_panic_hook`] for the
+//!   [`TermwizBackend`]
+//!
+//! Each function only restores the changes made by the matching `init` function.
+//!
+//! [`CrosstermBackend`]: crate::backend::CrosstermBackend
+//! [`TermionBackend`]: crate::backend::TermionBackend
+//! [`TermwizBackend`]: crate::backend::TermwizBackend
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! # #[cfg(feature = "crossterm")]
+//! # {
+//! use ratatui::widgets::Paragraph;
+//!
+//! let mut terminal = ratatui::init();
+//! terminal.draw(|frame| frame.render_widget(Paragraph::new("Hello World!"), frame.size()))?;
+//! ratatui::restore();
+//! # }
+//! # std::io::Result::Ok(())
+//! ```
+
+#[cfg(feature = "crossterm")]
+mod crossterm;
+#[cfg(feature = "termion")]
+mod termion;
+#[cfg(feature = "termwiz")]
+mod termwiz;
+
+#[cfg(feature = "crossterm")]
+pub use self::crossterm::{
+    init, install_panic_hook, restore, try_init, try_restore, DefaultTerminal,
+};
+#[cfg(feature = "termion")]
+pub use self::termion::{
+    init_termion, install_termion_panic_hook, restore_termion, try_init_termion,
+    try_restore_termion, TermionTerminal,
+};
+#[cfg(feature = "termwiz")]
+pub use self::termwiz::{
+    init_termwiz, install_termwiz_panic_hook, restore_termwiz, try_init_termwiz,
+    try_restore_termwiz, TermwizTerminal,
+};
@@ -0,0 +1,99 @@
+//! [`init`] and [`restore`] for the [`CrosstermBackend`], which also restore the terminal when the
+//! application panics.
+use std::{
+    io::{self, stdout, Stdout},
+    panic,
+};
+
+use crossterm::{
+    cursor::Show,
+    event::{DisableBracketedPaste, DisableFocusChange},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+
+use crate::{backend::CrosstermBackend, terminal::Terminal};
+
+/// The type of the [`Terminal`] returned by [`init`], which draws to the standard output using
+/// the [`CrosstermBackend`].
+pub type DefaultTerminal = Terminal<CrosstermBackend<Stdout>>;
+
+/// Initializes the terminal for an application and returns a [`DefaultTerminal`] to draw to it.
+///
+/// This installs a panic hook that restores the terminal (see [`install_panic_hook`]), enables
+/// raw mode and enters the alternate screen. Call [`restore`] before the application exits to
+/// undo these changes.
+///
+/// # Panics
+///
+/// Panics if the terminal cannot be initialized. Use [`try_init`] to handle the error instead.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// let mut terminal = ratatui::init();
+/// // optionally, report pasted text and focus changes as events
+/// terminal.backend_mut().enable_bracketed_paste()?;
+/// terminal.backend_mut().enable_focus_change()?;
+/// // run the application
+/// ratatui::restore();
+/// # std::io::Result::Ok(())
+/// ```
+pub fn init() -> DefaultTerminal {
+    try_init().expect("failed to initialize the terminal")
+}
+
+/// Initializes the terminal for an application and returns a [`DefaultTerminal`] to draw to it.
+///
+/// This is the same as [`init`], but returns an error instead of panicking if the terminal cannot
+/// be initialized.
+pub fn try_init() -> io::Result<DefaultTerminal> {
+    install_panic_hook();
+    enable_raw_mode()?;
+    execute!(stdout(), EnterAlternateScreen)?;
+    Terminal::new(CrosstermBackend::new(stdout()))
+}
+
+/// Restores the terminal to the state it was in before [`init`] was called.
+///
+/// This disables raw mode, leaves the alternate screen and shows the cursor. It also disables
+/// bracketed paste and focus change reporting, in case they were enabled with
+/// [`CrosstermBackend::enable_bracketed_paste`] and [`CrosstermBackend::enable_focus_change`].
+///
+/// Errors are printed to the standard error instead of being returned, as there is usually nothing
+/// else to do with them when the application exits. Use [`try_restore`] to handle the error
+/// instead.
+pub fn restore() {
+    if let Err(err) = try_restore() {
+        eprintln!("Failed to restore the terminal: {err}");
+    }
+}
+
+/// Restores the terminal to the state it was in before [`init`] was called.
+///
+/// This is the same as [`restore`], but returns the error if the terminal cannot be restored. The
+/// terminal is restored as much as possible even when one of the steps fails.
+pub fn try_restore() -> io::Result<()> {
+    let raw_mode = disable_raw_mode();
+    execute!(
+        stdout(),
+        DisableBracketedPaste,
+        DisableFocusChange,
+        LeaveAlternateScreen,
+        Show
+    )?;
+    raw_mode
+}
+
+/// Installs a panic hook that restores the terminal before the panic message is printed.
+///
+/// The hook calls [`restore`] and then the panic hook that was installed before, so the panic
+/// message is displayed in the normal screen as it would be without ratatui. This is called by
+/// [`init`], and is only needed when the terminal is initialized differently.
+pub fn install_panic_hook() {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        restore();
+        hook(info);
+    }));
+}
@@ -0,0 +1,110 @@
+//! [`init_termion`] and [`restore_termion`] for the [`TermionBackend`], which also restore the
+//! terminal when the application panics.
+//!
+//! Termion restores raw mode when the [`RawTerminal`] that enabled it is dropped, but the panic
+//! message is printed before the values of the application are dropped. The [`RawTerminal`] is
+//! kept in a static instead, so that the panic hook can restore the mode it saved.
+use std::{
+    io::{self, stdout, Stdout, Write},
+    panic,
+    sync::{Mutex, MutexGuard, PoisonError},
+};
+
+use termion::{
+    cursor::Show,
+    raw::{IntoRawMode, RawTerminal},
+    screen::{ToAlternateScreen, ToMainScreen},
+};
+
+use crate::{backend::TermionBackend, terminal::Terminal};
+
+/// The terminal that enabled raw mode, which holds the mode to restore
+static RAW_TERMINAL: Mutex<Option<RawTerminal<Stdout>>> = Mutex::new(None);
+
+/// The type of the [`Terminal`] returned by [`init_termion`], which draws to the standard output
+/// using the [`TermionBackend`].
+pub type TermionTerminal = Terminal<TermionBackend<Stdout>>;
+
+/// Initializes the terminal for an application and returns a [`TermionTerminal`] to draw to it.
+///
+/// This installs a panic hook that restores the terminal (see [`install_termion_panic_hook`]),
+/// enables raw mode and enters the alternate screen. Call [`restore_termion`] before the
+/// application exits to undo these changes.
+///
+/// # Panics
+///
+/// Panics if the terminal cannot be initialized. Use [`try_init_termion`] to handle the error
+/// instead.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// let mut terminal = ratatui::init_termion();
+/// // run the application
+/// ratatui::restore_termion();
+/// ```
+pub fn init_termion() -> TermionTerminal {
+    try_init_termion().expect("failed to initialize the terminal")
+}
+
+/// Initializes the terminal for an application and returns a [`TermionTerminal`] to draw to it.
+///
+/// This is the same as [`init_termion`], but returns an error instead of panicking if the
+/// terminal cannot be initialized.
+pub fn try_init_termion() -> io::Result<TermionTerminal> {
+    install_termion_panic_hook();
+    {
+        let mut raw_terminal = lock_raw_terminal();
+        // keep the terminal of a previous call, which saved the mode from before raw mode
+        if raw_terminal.is_none() {
+            *raw_terminal = Some(stdout().into_raw_mode()?);
+        }
+    }
+    write!(stdout(), "{ToAlternateScreen}")?;
+    stdout().flush()?;
+    Terminal::new(TermionBackend::new(stdout()))
+}
+
+/// Restores the terminal to the state it was in before [`init_termion`] was called.
+///
+/// This disables raw mode, leaves the alternate screen and shows the cursor.
+///
+/// Errors are printed to the standard error instead of being returned, as there is usually nothing
+/// else to do with them when the application exits. Use [`try_restore_termion`] to handle the
+/// error instead.
+pub fn restore_termion() {
+    if let Err(err) = try_restore_termion() {
+        eprintln!("Failed to restore the terminal: {err}");
+    }
+}
+
+/// Restores the terminal to the state it was in before [`init_termion`] was called.
+///
+/// This is the same as [`restore_termion`], but returns the error if the terminal cannot be
+/// restored. The terminal is restored as much as possible even when one of the steps fails.
+pub fn try_restore_termion() -> io::Result<()> {
+    let raw_mode = lock_raw_terminal()
+        .take()
+        .map_or(Ok(()), |raw_terminal| raw_terminal.suspend_raw_mode());
+    write!(stdout(), "{ToMainScreen}{Show}")?;
+    stdout().flush()?;
+    raw_mode
+}
+
+/// Installs a panic hook that restores the terminal before the panic message is printed.
+///
+/// The hook calls [`restore_termion`] and then the panic hook that was installed before, so the
+/// panic message is displayed in the normal screen as it would be without ratatui. This is called
+/// by [`init_termion`], and is only needed when the terminal is initialized differently.
+pub fn install_termion_panic_hook() {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        restore_termion();
+        hook(info);
+    }));
+}
+
+/// Locks the raw terminal, which stays usable even if a thread panicked while holding the lock
+fn lock_raw_terminal() -> MutexGuard<'static, Option<RawTerminal<Stdout>>> {
+    RAW_TERMINAL.lock().unwrap_or_else(PoisonError::into_inner)
+}
@@ -0,0 +1,122 @@
+//! [`init_termwiz`] and [`restore_termwiz`] for the [`TermwizBackend`], which also restore the
+//! terminal when the application panics.
+//!
+//! Termwiz restores the terminal when the [`SystemTerminal`] that changed it is dropped, but the
+//! panic message is printed before the values of the application are dropped. Raw mode and the
+//! alternate screen are enabled through a second [`SystemTerminal`] instead, which is kept in a
+//! static so that the panic hook can restore them.
+use std::{
+    error::Error,
+    panic,
+    sync::{Mutex, MutexGuard, PoisonError},
+};
+
+use termwiz::{
+    caps::Capabilities,
+    surface::{Change, CursorVisibility},
+    terminal::{buffered::BufferedTerminal, SystemTerminal, Terminal as _},
+};
+
+use crate::{backend::TermwizBackend, terminal::Terminal};
+
+/// The system terminal that enabled raw mode and entered the alternate screen
+static SYSTEM_TERMINAL: Mutex<Option<SystemTerminal>> = Mutex::new(None);
+
+/// The type of the [`Terminal`] returned by [`init_termwiz`], which draws using the
+/// [`TermwizBackend`].
+pub type TermwizTerminal = Terminal<TermwizBackend>;
+
+/// Initializes the terminal for an application and returns a [`TermwizTerminal`] to draw to it.
+///
+/// This installs a panic hook that restores the terminal (see [`install_termwiz_panic_hook`]),
+/// enables raw mode and enters the alternate screen. Call [`restore_termwiz`] before the
+/// application exits to undo these changes.
+///
+/// # Panics
+///
+/// Panics if the terminal cannot be initialized. Use [`try_init_termwiz`] to handle the error
+/// instead.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// let mut terminal = ratatui::init_termwiz();
+/// // run the application
+/// ratatui::restore_termwiz();
+/// ```
+pub fn init_termwiz() -> TermwizTerminal {
+    try_init_termwiz().expect("failed to initialize the terminal")
+}
+
+/// Initializes the terminal for an application and returns a [`TermwizTerminal`] to draw to it.
+///
+/// This is the same as [`init_termwiz`], but returns an error instead of panicking if the
+/// terminal cannot be initialized.
+pub fn try_init_termwiz() -> Result<TermwizTerminal, Box<dyn Error>> {
+    install_termwiz_panic_hook();
+    // the terminal of the backend is created first, so that it does not restore raw mode when it
+    // is dropped
+    let buffered_terminal =
+        BufferedTerminal::new(SystemTerminal::new(Capabilities::new_from_env()?)?)?;
+    {
+        let mut system_terminal = lock_system_terminal();
+        if system_terminal.is_none() {
+            *system_terminal = Some(SystemTerminal::new(Capabilities::new_from_env()?)?);
+        }
+        if let Some(system_terminal) = system_terminal.as_mut() {
+            system_terminal.set_raw_mode()?;
+            system_terminal.enter_alternate_screen()?;
+        }
+    }
+    let backend = TermwizBackend::with_buffered_terminal(buffered_terminal);
+    Ok(Terminal::new(backend)?)
+}
+
+/// Restores the terminal to the state it was in before [`init_termwiz`] was called.
+///
+/// This disables raw mode, leaves the alternate screen and shows the cursor.
+///
+/// Errors are printed to the standard error instead of being returned, as there is usually nothing
+/// else to do with them when the application exits. Use [`try_restore_termwiz`] to handle the
+/// error instead.
+pub fn restore_termwiz() {
+    if let Err(err) = try_restore_termwiz() {
+        eprintln!("Failed to restore the terminal: {err}");
+    }
+}
+
+/// Restores the terminal to the state it was in before [`init_termwiz`] was called.
+///
+/// This is the same as [`restore_termwiz`], but returns the error if the terminal cannot be
+/// restored. The terminal is restored as much as possible even when one of the steps fails.
+pub fn try_restore_termwiz() -> Result<(), Box<dyn Error>> {
+    let Some(mut system_terminal) = lock_system_terminal().take() else {
+        return Ok(());
+    };
+    let cooked_mode = system_terminal.set_cooked_mode();
+    system_terminal.exit_alternate_screen()?;
+    system_terminal.render(&[Change::CursorVisibility(CursorVisibility::Visible)])?;
+    system_terminal.flush()?;
+    Ok(cooked_mode?)
+}
+
+/// Installs a panic hook that restores the terminal before the panic message is printed.
+///
+/// The hook calls [`restore_termwiz`] and then the panic hook that was installed before, so the
+/// panic message is displayed in the normal screen as it would be without ratatui. This is called
+/// by [`init_termwiz`], and is only needed when the terminal is initialized differently.
+pub fn install_termwiz_panic_hook() {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        restore_termwiz();
+        hook(info);
+    }));
+}
+
+/// Locks the system terminal, which stays usable even if a thread panicked while holding the
+/// lock
+fn lock_system_terminal() -> MutexGuard<'static, Option<SystemTerminal>> {
+    SYSTEM_TERMINAL
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+}
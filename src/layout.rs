@@ -4,19 +4,28 @@ mod alignment;
 mod constraint;
 mod direction;
 mod flex;
+mod flow;
+mod grid;
 #[allow(clippy::module_inception)]
 mod layout;
 mod margin;
 mod position;
 mod rect;
+mod responsive;
 mod size;
+mod size_hint;
+pub mod solver;
 
 pub use alignment::Alignment;
 pub use constraint::Constraint;
 pub use direction::Direction;
 pub use flex::Flex;
-pub use layout::Layout;
+pub use flow::Flow;
+pub use grid::{Grid, GridAreas};
+pub use layout::{CacheStats, Layout};
 pub use margin::Margin;
 pub use position::Position;
 pub use rect::*;
+pub use responsive::{Breakpoint, Responsive};
 pub use size::Size;
+pub use size_hint::SizeHint;
@@ -6,6 +6,12 @@ pub enum Alignment {
     Left,
     Center,
     Right,
+    /// Stretches the lines of wrapped text to fill the available width by distributing the extra
+    /// space between the words
+    ///
+    /// The last line of each paragraph is aligned to the left, as are lines that are not wrapped
+    /// and widgets that do not wrap text.
+    Justify,
 }
 
 #[cfg(test)]
@@ -19,6 +25,7 @@ mod tests {
         assert_eq!(Alignment::Left.to_string(), "Left");
         assert_eq!(Alignment::Center.to_string(), "Center");
         assert_eq!(Alignment::Right.to_string(), "Right");
+        assert_eq!(Alignment::Justify.to_string(), "Justify");
     }
 
     #[test]
@@ -26,6 +33,7 @@ mod tests {
         assert_eq!("Left".parse::<Alignment>(), Ok(Alignment::Left));
         assert_eq!("Center".parse::<Alignment>(), Ok(Alignment::Center));
         assert_eq!("Right".parse::<Alignment>(), Ok(Alignment::Right));
+        assert_eq!("Justify".parse::<Alignment>(), Ok(Alignment::Justify));
         assert_eq!("".parse::<Alignment>(), Err(ParseError::VariantNotFound));
     }
 }
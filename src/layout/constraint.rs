@@ -3,6 +3,8 @@ use std::fmt;
 use itertools::Itertools;
 use strum::EnumIs;
 
+use super::{Direction, Size, SizeHint};
+
 /// A constraint that defines the size of a layout element.
 ///
 /// Constraints can be used to specify a fixed size, a percentage of the available space, a ratio of
@@ -22,6 +24,10 @@ use strum::EnumIs;
 /// 5. [`Constraint::Ratio`]
 /// 6. [`Constraint::Fill`]
 ///
+/// [`Constraint::MinContent`] and [`Constraint::MaxContent`] size an element to fit its content,
+/// as reported by a [`SizeHint`]. Their bounds have the priority of [`Constraint::Min`] and
+/// [`Constraint::Max`], and their size the priority of [`Constraint::Length`].
+///
 /// # Examples
 ///
 /// `Constraint` provides helper methods to create lists of constraints from various input formats.
@@ -188,6 +194,56 @@ pub enum Constraint {
     /// └───────────┘└───────────────────────┘└──────────┘
     /// ```
     Fill(u16),
+
+    /// Applies the minimum content size of the element
+    ///
+    /// The element is set to the specified size, and only grows when other constraints require
+    /// it. Unlike [`Constraint::Min`], the element does not grow to fill the excess space. This
+    /// is usually created from a widget with [`Constraint::min_content`].
+    ///
+    /// # Examples
+    ///
+    /// `[MinContent(20), Length(40)]`
+    ///
+    /// ```plain
+    /// ┌──────────────────┐┌──────────────────────────────────────┐
+    /// │       20 px      ││                 40 px                │
+    /// └──────────────────┘└──────────────────────────────────────┘
+    /// ```
+    ///
+    /// `[MinContent(20), Length(50)]` in a 50 px area
+    ///
+    /// ```plain
+    /// ┌──────────────────┐┌────────────────────────────┐
+    /// │       20 px      ││            30 px           │
+    /// └──────────────────┘└────────────────────────────┘
+    /// ```
+    MinContent(u16),
+
+    /// Applies the maximum content size of the element
+    ///
+    /// The element is set to the specified size, and only shrinks when there is not enough space
+    /// for the other constraints. This is usually created from a widget with
+    /// [`Constraint::max_content`].
+    ///
+    /// # Examples
+    ///
+    /// `[MaxContent(20), Fill(1)]`
+    ///
+    /// ```plain
+    /// ┌──────────────────┐┌────────────────────────────┐
+    /// │       20 px      ││            30 px           │
+    /// └──────────────────┘└────────────────────────────┘
+    /// ```
+    ///
+    /// `[MaxContent(20), Min(40)]`
+    ///
+    /// ```plain
+    /// ┌────────┐┌──────────────────────────────────────┐
+    /// │  10 px ││                 40 px                │
+    /// └────────┘└──────────────────────────────────────┘
+    /// ```
+    MaxContent(u16),
 }
 
 impl Constraint {
@@ -210,11 +266,61 @@ impl Constraint {
                 (percentage * length).min(length) as u16
             }
             Self::Length(l) | Self::Fill(l) => length.min(l),
-            Self::Max(m) => length.min(m),
-            Self::Min(m) => length.max(m),
+            Self::Max(m) | Self::MaxContent(m) => length.min(m),
+            Self::Min(m) | Self::MinContent(m) => length.max(m),
         }
     }
 
+    /// Creates a [`Constraint::MinContent`] from the size of a widget along the given direction
+    ///
+    /// The size is the [intrinsic size](SizeHint::intrinsic_size) of the widget in the `available`
+    /// size, usually the size of the area being split.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let area = Rect::new(0, 0, 40, 10);
+    /// let status = Paragraph::new("Connected").block(Block::bordered());
+    /// let [main, status_area] = Layout::vertical([
+    ///     Constraint::Fill(1),
+    ///     Constraint::min_content(&status, Direction::Vertical, area.as_size()),
+    /// ])
+    /// .areas(area);
+    /// assert_eq!(status_area.height, 3);
+    /// ```
+    pub fn min_content<W>(widget: &W, direction: Direction, available: Size) -> Self
+    where
+        W: SizeHint + ?Sized,
+    {
+        Self::MinContent(content_size(widget, direction, available))
+    }
+
+    /// Creates a [`Constraint::MaxContent`] from the size of a widget along the given direction
+    ///
+    /// The size is the [intrinsic size](SizeHint::intrinsic_size) of the widget in the `available`
+    /// size, usually the size of the area being split.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let area = Rect::new(0, 0, 40, 10);
+    /// let menu = Paragraph::new("Files\nSearch\nSettings");
+    /// let [menu_area, main] = Layout::horizontal([
+    ///     Constraint::max_content(&menu, Direction::Horizontal, area.as_size()),
+    ///     Constraint::Fill(1),
+    /// ])
+    /// .areas(area);
+    /// assert_eq!(menu_area.width, 8);
+    /// ```
+    pub fn max_content<W>(widget: &W, direction: Direction, available: Size) -> Self
+    where
+        W: SizeHint + ?Sized,
+    {
+        Self::MaxContent(content_size(widget, direction, available))
+    }
+
     /// Convert an iterator of lengths into a vector of constraints
     ///
     /// # Examples
@@ -324,6 +430,18 @@ impl Constraint {
     }
 }
 
+/// The size of a widget along a direction
+fn content_size<W>(widget: &W, direction: Direction, available: Size) -> u16
+where
+    W: SizeHint + ?Sized,
+{
+    let size = widget.intrinsic_size(available);
+    match direction {
+        Direction::Horizontal => size.width,
+        Direction::Vertical => size.height,
+    }
+}
+
 impl From<u16> for Constraint {
     /// Convert a `u16` into a [`Constraint::Length`]
     ///
@@ -371,6 +489,8 @@ impl fmt::Display for Constraint {
             Self::Fill(l) => write!(f, "Fill({l})"),
             Self::Max(m) => write!(f, "Max({m})"),
             Self::Min(m) => write!(f, "Min({m})"),
+            Self::MinContent(m) => write!(f, "MinContent({m})"),
+            Self::MaxContent(m) => write!(f, "MaxContent({m})"),
         }
     }
 }
@@ -391,6 +511,8 @@ mod tests {
         assert_eq!(Constraint::Length(10).to_string(), "Length(10)");
         assert_eq!(Constraint::Max(10).to_string(), "Max(10)");
         assert_eq!(Constraint::Min(10).to_string(), "Min(10)");
+        assert_eq!(Constraint::MinContent(10).to_string(), "MinContent(10)");
+        assert_eq!(Constraint::MaxContent(10).to_string(), "MaxContent(10)");
     }
 
     #[test]
@@ -0,0 +1,148 @@
+use super::Size;
+use crate::prelude::*;
+
+/// A flow layout places fixed-size items left to right, wrapping to a new line when the area is
+/// exhausted.
+///
+/// This is useful for content like tag clouds, button bars or card grids, where the number of items
+/// that fit on a line depends on the width of the area.
+///
+/// Each line is as tall as its tallest item. The space between the items of a line and between
+/// lines can be set with [`Flow::spacing`] and [`Flow::line_spacing`].
+///
+/// [`Flow::split`] returns one [`Rect`] per item, in the same order as the items. Items wider than
+/// the area are truncated to the width of the area, and items that do not fit in the height of the
+/// area are clipped, which results in empty rects for the items that are completely outside of the
+/// area.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     layout::{Flow, Size},
+///     prelude::*,
+/// };
+///
+/// let flow = Flow::new([Size::new(4, 1), Size::new(4, 1), Size::new(4, 1)]).spacing(1);
+/// let areas = flow.split(Rect::new(0, 0, 10, 3));
+/// assert_eq!(
+///     areas,
+///     [
+///         Rect::new(0, 0, 4, 1),
+///         Rect::new(5, 0, 4, 1),
+///         Rect::new(0, 1, 4, 1),
+///     ]
+/// );
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Flow {
+    items: Vec<Size>,
+    spacing: u16,
+    line_spacing: u16,
+}
+
+impl Flow {
+    /// Creates a new flow layout with the given item sizes
+    ///
+    /// `items` accepts any type that implements `IntoIterator<Item = Into<Size>>`, e.g. an array of
+    /// [`Size`] or of `(width, height)` tuples.
+    pub fn new<I>(items: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Size>,
+    {
+        Self {
+            items: items.into_iter().map(Into::into).collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the sizes of the items
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn items<I>(mut self, items: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Size>,
+    {
+        self.items = items.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the space between the items of a line
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the space between lines
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn line_spacing(mut self, line_spacing: u16) -> Self {
+        self.line_spacing = line_spacing;
+        self
+    }
+
+    /// Splits the given area into one rect per item
+    pub fn split(&self, area: Rect) -> Vec<Rect> {
+        let mut rects = Vec::with_capacity(self.items.len());
+        let mut x = area.x;
+        let mut y = area.y;
+        let mut line_height = 0;
+        for item in &self.items {
+            let width = item.width.min(area.width);
+            // wrap unless this is the first item of the line
+            if x > area.x && x.saturating_add(width) > area.right() {
+                x = area.x;
+                y = y
+                    .saturating_add(line_height)
+                    .saturating_add(self.line_spacing);
+                line_height = 0;
+            }
+            let rect = Rect::new(x, y, width, item.height);
+            rects.push(rect.intersection(area));
+            line_height = line_height.max(item.height);
+            x = x.saturating_add(width).saturating_add(self.spacing);
+        }
+        rects
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn wraps_items() {
+        let flow = Flow::new([(3, 1), (3, 2), (3, 1), (5, 1)])
+            .spacing(1)
+            .line_spacing(1);
+        assert_eq!(
+            flow.split(Rect::new(1, 1, 8, 10)),
+            [
+                Rect::new(1, 1, 3, 1),
+                Rect::new(5, 1, 3, 2),
+                Rect::new(1, 4, 3, 1),
+                Rect::new(1, 6, 5, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn truncates_wide_items() {
+        let flow = Flow::new([(2, 1), (20, 1)]);
+        assert_eq!(
+            flow.split(Rect::new(0, 0, 10, 2)),
+            [Rect::new(0, 0, 2, 1), Rect::new(0, 1, 10, 1)]
+        );
+    }
+
+    #[test]
+    fn clips_items_below_area() {
+        let flow = Flow::new([(5, 1), (5, 1), (5, 1)]);
+        let areas = flow.split(Rect::new(0, 0, 5, 2));
+        assert_eq!(areas[..2], [Rect::new(0, 0, 5, 1), Rect::new(0, 1, 5, 1)]);
+        assert!(areas[2].is_empty());
+    }
+}
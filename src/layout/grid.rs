@@ -0,0 +1,265 @@
+use std::ops::Index;
+
+use super::Flex;
+use crate::prelude::*;
+
+/// A grid layout splits an area into rows and columns in a single call.
+///
+/// Each row and each column is sized by its own [`Constraint`], and the space between rows and
+/// between columns can be set with [`Grid::row_spacing`] and [`Grid::column_spacing`] (or both at
+/// once with [`Grid::spacing`]). The rows and columns are computed with a [`Layout`] each, so the
+/// constraints behave the same way as in a [`Layout`] and the results are cached.
+///
+/// [`Grid::split`] returns the cells as [`GridAreas`], which can be indexed by `(row, column)`.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{layout::Grid, prelude::*};
+///
+/// let grid = Grid::new(
+///     [Constraint::Length(1), Constraint::Fill(1)],
+///     [Constraint::Percentage(50), Constraint::Percentage(50)],
+/// )
+/// .column_spacing(2);
+/// let areas = grid.split(Rect::new(0, 0, 22, 5));
+/// assert_eq!(areas[(0, 0)], Rect::new(0, 0, 10, 1));
+/// assert_eq!(areas[(1, 1)], Rect::new(12, 1, 10, 4));
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Grid {
+    rows: Vec<Constraint>,
+    columns: Vec<Constraint>,
+    row_spacing: u16,
+    column_spacing: u16,
+    flex: Flex,
+}
+
+impl Grid {
+    /// Creates a new grid with the given row and column constraints
+    ///
+    /// Both parameters accept any type that implements `IntoIterator<Item = Into<Constraint>>`,
+    /// like [`Layout::new`].
+    pub fn new<R, C>(rows: R, columns: C) -> Self
+    where
+        R: IntoIterator,
+        R::Item: Into<Constraint>,
+        C: IntoIterator,
+        C::Item: Into<Constraint>,
+    {
+        Self {
+            rows: rows.into_iter().map(Into::into).collect(),
+            columns: columns.into_iter().map(Into::into).collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the constraints of the rows
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn rows<I>(mut self, rows: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Constraint>,
+    {
+        self.rows = rows.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the constraints of the columns
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn columns<I>(mut self, columns: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Constraint>,
+    {
+        self.columns = columns.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the space between rows and between columns
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn spacing(mut self, spacing: u16) -> Self {
+        self.row_spacing = spacing;
+        self.column_spacing = spacing;
+        self
+    }
+
+    /// Sets the space between rows
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn row_spacing(mut self, spacing: u16) -> Self {
+        self.row_spacing = spacing;
+        self
+    }
+
+    /// Sets the space between columns
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn column_spacing(mut self, spacing: u16) -> Self {
+        self.column_spacing = spacing;
+        self
+    }
+
+    /// Sets how the excess space is distributed between rows and between columns
+    ///
+    /// See [`Layout::flex`] for more details.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn flex(mut self, flex: Flex) -> Self {
+        self.flex = flex;
+        self
+    }
+
+    /// Splits the given area into the cells of the grid
+    pub fn split(&self, area: Rect) -> GridAreas {
+        let rows = Layout::vertical(self.rows.iter().copied())
+            .spacing(self.row_spacing)
+            .flex(self.flex)
+            .split(area);
+        let columns = Layout::horizontal(self.columns.iter().copied())
+            .spacing(self.column_spacing)
+            .flex(self.flex)
+            .split(area);
+        let cells = rows
+            .iter()
+            .flat_map(|row| {
+                columns
+                    .iter()
+                    .map(|column| Rect::new(column.x, row.y, column.width, row.height))
+            })
+            .collect();
+        GridAreas {
+            cells,
+            columns: columns.len(),
+        }
+    }
+}
+
+/// The cells of a [`Grid`] after splitting an area, stored row by row
+///
+/// A cell is accessed by indexing with a `(row, column)` tuple.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct GridAreas {
+    cells: Vec<Rect>,
+    columns: usize,
+}
+
+impl GridAreas {
+    /// Returns the number of rows
+    pub fn row_count(&self) -> usize {
+        self.cells
+            .len()
+            .checked_div(self.columns)
+            .unwrap_or_default()
+    }
+
+    /// Returns the number of columns
+    pub const fn column_count(&self) -> usize {
+        self.columns
+    }
+
+    /// Returns the cell at the given row and column, or `None` if it is out of bounds
+    pub fn get(&self, row: usize, column: usize) -> Option<Rect> {
+        if column >= self.columns {
+            return None;
+        }
+        self.cells.get(row * self.columns + column).copied()
+    }
+
+    /// Returns the cells of the given row
+    ///
+    /// # Panics
+    ///
+    /// Panics if the row is out of bounds.
+    pub fn row(&self, row: usize) -> &[Rect] {
+        &self.cells[row * self.columns..(row + 1) * self.columns]
+    }
+
+    /// Returns an iterator over the rows of cells
+    pub fn rows(&self) -> impl Iterator<Item = &[Rect]> {
+        self.cells.chunks(self.columns.max(1))
+    }
+
+    /// Returns an iterator over all the cells, row by row
+    pub fn iter(&self) -> impl Iterator<Item = &Rect> {
+        self.cells.iter()
+    }
+}
+
+impl Index<(usize, usize)> for GridAreas {
+    type Output = Rect;
+
+    /// Returns the cell at the given `(row, column)`
+    ///
+    /// # Panics
+    ///
+    /// Panics if the row or the column is out of bounds.
+    fn index(&self, (row, column): (usize, usize)) -> &Self::Output {
+        assert!(
+            column < self.columns,
+            "column {column} out of bounds for a grid with {} columns",
+            self.columns
+        );
+        &self.cells[row * self.columns + column]
+    }
+}
+
+impl<'a> IntoIterator for &'a GridAreas {
+    type Item = &'a Rect;
+    type IntoIter = std::slice::Iter<'a, Rect>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.cells.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn split() {
+        let grid = Grid::new([1, 2], [3, 4, 5]).spacing(1);
+        let areas = grid.split(Rect::new(1, 1, 14, 4));
+        assert_eq!(areas.row_count(), 2);
+        assert_eq!(areas.column_count(), 3);
+        assert_eq!(
+            areas.row(0),
+            [
+                Rect::new(1, 1, 3, 1),
+                Rect::new(5, 1, 4, 1),
+                Rect::new(10, 1, 5, 1),
+            ]
+        );
+        assert_eq!(areas[(1, 2)], Rect::new(10, 3, 5, 2));
+        assert_eq!(areas.get(1, 0), Some(Rect::new(1, 3, 3, 2)));
+        assert_eq!(areas.get(0, 3), None);
+        assert_eq!(areas.get(2, 0), None);
+        assert_eq!(areas.iter().count(), 6);
+        assert_eq!(areas.rows().count(), 2);
+    }
+
+    #[test]
+    fn row_and_column_spacing() {
+        let grid = Grid::new([1, 1], [1, 1])
+            .row_spacing(2)
+            .column_spacing(1)
+            .flex(Flex::Start);
+        let areas = grid.split(Rect::new(0, 0, 10, 10));
+        assert_eq!(areas[(1, 1)], Rect::new(2, 3, 1, 1));
+    }
+
+    #[test]
+    fn empty() {
+        let areas = Grid::default().split(Rect::new(0, 0, 10, 10));
+        assert_eq!(areas.row_count(), 0);
+        assert_eq!(areas.column_count(), 0);
+        assert_eq!(areas.rows().count(), 0);
+    }
+
+    #[test]
+    #[should_panic = "column 2 out of bounds"]
+    fn index_out_of_bounds() {
+        let areas = Grid::new([1], [1, 1]).split(Rect::new(0, 0, 10, 10));
+        let _ = areas[(0, 2)];
+    }
+}
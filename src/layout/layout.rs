@@ -1,4 +1,11 @@
-use std::{cell::RefCell, collections::HashMap, iter, num::NonZeroUsize, rc::Rc, sync::OnceLock};
+use std::{
+    cell::{Cell, RefCell},
+    collections::HashMap,
+    iter,
+    num::NonZeroUsize,
+    rc::Rc,
+    sync::OnceLock,
+};
 
 use cassowary::{
     strength::REQUIRED,
@@ -38,6 +45,35 @@ const FLOAT_PRECISION_MULTIPLIER: f64 = 100.0;
 
 thread_local! {
     static LAYOUT_CACHE: OnceLock<RefCell<Cache>> = const { OnceLock::new() };
+    static LAYOUT_CACHE_STATS: Cell<CacheStats> = const { Cell::new(CacheStats::new()) };
+}
+
+/// Statistics about the thread-local layout cache, returned by [`Layout::cache_stats()`].
+///
+/// The hit and miss counters are incremented by every cached split (e.g. [`Layout::split()`]) and
+/// can be reset with [`Layout::reset_cache_stats()`]. Splits that bypass the cache (e.g.
+/// [`Layout::split_uncached()`]) are not counted.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct CacheStats {
+    /// The number of splits that were found in the cache
+    pub hits: u64,
+    /// The number of splits that had to be computed and were inserted into the cache
+    pub misses: u64,
+    /// The number of entries currently stored in the cache
+    pub len: usize,
+    /// The maximum number of entries the cache can store
+    pub capacity: usize,
+}
+
+impl CacheStats {
+    const fn new() -> Self {
+        Self {
+            hits: 0,
+            misses: 0,
+            len: 0,
+            capacity: 0,
+        }
+    }
 }
 
 /// A layout is a set of constraints that can be applied to a given area to split it into smaller
@@ -55,11 +91,15 @@ thread_local! {
 /// linear solver that can be used to solve linear equations and inequalities. In our case, we
 /// define a set of constraints that are applied to split the provided area into Rects aligned in a
 /// single direction, and the solver computes the values of the position and sizes that satisfy as
-/// many of the constraints in order of their priorities.
+/// many of the constraints in order of their priorities. Layouts that cannot be expressed as a
+/// split in a single direction can use the solver directly through the [`solver`](super::solver)
+/// module.
 ///
 /// When the layout is computed, the result is cached in a thread-local cache, so that subsequent
 /// calls with the same parameters are faster. The cache is a `LruCache`, and the size of the cache
-/// can be configured using [`Layout::init_cache()`].
+/// can be configured using [`Layout::init_cache()`] or [`Layout::resize_cache()`]. The cache can be
+/// inspected with [`Layout::cache_stats()`] and cleared with [`Layout::clear_cache()`], and
+/// [`Layout::split_uncached()`] bypasses it.
 ///
 /// # Constructors
 ///
@@ -227,6 +267,71 @@ impl Layout {
             .is_ok()
     }
 
+    /// Sets the maximum number of entries of the layout cache.
+    ///
+    /// Unlike [`Layout::init_cache()`], this can be called at any time. If the cache already
+    /// contains more entries than `cache_size`, the least recently used entries are removed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cache_size` is zero.
+    pub fn resize_cache(cache_size: usize) {
+        let cache_size = NonZeroUsize::new(cache_size).expect("cache size must not be zero");
+        LAYOUT_CACHE.with(|c| {
+            c.get_or_init(|| RefCell::new(LruCache::new(cache_size)))
+                .borrow_mut()
+                .resize(cache_size);
+        });
+    }
+
+    /// Removes all the entries from the layout cache of the current thread.
+    ///
+    /// This does not change the cache size or reset the [`CacheStats`].
+    pub fn clear_cache() {
+        LAYOUT_CACHE.with(|c| {
+            if let Some(cache) = c.get() {
+                cache.borrow_mut().clear();
+            }
+        });
+    }
+
+    /// Returns statistics about the layout cache of the current thread.
+    ///
+    /// This is useful to find out whether the cache is large enough for an application. A high
+    /// number of misses compared to hits with a full cache indicates that the cache is thrashing,
+    /// in which case the cache size can be increased with [`Layout::resize_cache()`], or layouts
+    /// that are computed only once can bypass the cache with [`Layout::split_uncached()`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::prelude::*;
+    /// Layout::reset_cache_stats();
+    /// let layout = Layout::vertical([Constraint::Length(1), Constraint::Min(0)]);
+    /// layout.split(Rect::new(0, 0, 10, 10));
+    /// layout.split(Rect::new(0, 0, 10, 10));
+    /// let stats = Layout::cache_stats();
+    /// assert_eq!((stats.hits, stats.misses), (1, 1));
+    /// ```
+    pub fn cache_stats() -> CacheStats {
+        let (len, capacity) = LAYOUT_CACHE.with(|c| {
+            c.get().map_or((0, Self::DEFAULT_CACHE_SIZE), |cache| {
+                let cache = cache.borrow();
+                (cache.len(), cache.cap().get())
+            })
+        });
+        CacheStats {
+            len,
+            capacity,
+            ..LAYOUT_CACHE_STATS.get()
+        }
+    }
+
+    /// Resets the hit and miss counters of the layout cache of the current thread.
+    pub fn reset_cache_stats() {
+        LAYOUT_CACHE_STATS.set(CacheStats::new());
+    }
+
     /// Set the direction of the layout.
     ///
     /// # Examples
@@ -572,19 +677,55 @@ impl Layout {
     /// ```
     pub fn split_with_spacers(&self, area: Rect) -> (Segments, Spacers) {
         LAYOUT_CACHE.with(|c| {
-            c.get_or_init(|| {
-                RefCell::new(LruCache::new(
-                    NonZeroUsize::new(Self::DEFAULT_CACHE_SIZE).unwrap(),
-                ))
-            })
-            .borrow_mut()
-            .get_or_insert((area, self.clone()), || {
-                self.try_split(area).expect("failed to split")
-            })
-            .clone()
+            let mut hit = true;
+            let result = c
+                .get_or_init(|| {
+                    RefCell::new(LruCache::new(
+                        NonZeroUsize::new(Self::DEFAULT_CACHE_SIZE).unwrap(),
+                    ))
+                })
+                .borrow_mut()
+                .get_or_insert((area, self.clone()), || {
+                    hit = false;
+                    self.split_with_spacers_uncached(area)
+                })
+                .clone();
+            let mut stats = LAYOUT_CACHE_STATS.get();
+            if hit {
+                stats.hits += 1;
+            } else {
+                stats.misses += 1;
+            }
+            LAYOUT_CACHE_STATS.set(stats);
+            result
         })
     }
 
+    /// Splits the given area like [`Layout::split()`] without using the layout cache.
+    ///
+    /// The result is always computed and is not stored in the cache. This is useful for layouts
+    /// that change on every frame (e.g. during an animation), which would otherwise evict entries
+    /// that are reused from the cache.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ratatui::prelude::*;
+    /// let layout = Layout::vertical([Constraint::Length(5), Constraint::Min(0)]);
+    /// let areas = layout.split_uncached(Rect::new(2, 2, 10, 10));
+    /// assert_eq!(areas[..], [Rect::new(2, 2, 10, 5), Rect::new(2, 7, 10, 5)]);
+    /// ```
+    pub fn split_uncached(&self, area: Rect) -> Rects {
+        self.split_with_spacers_uncached(area).0
+    }
+
+    /// Splits the given area like [`Layout::split_with_spacers()`] without using the layout cache.
+    ///
+    /// See [`Layout::split_uncached()`] for more details.
+    pub fn split_with_spacers_uncached(&self, area: Rect) -> (Segments, Spacers) {
+        self.try_split(area).expect("failed to split")
+    }
+
     fn try_split(&self, area: Rect) -> Result<(Segments, Spacers), AddConstraintError> {
         // To take advantage of all of cassowary features, we would want to store the `Solver` in
         // one of the fields of the Layout struct. And we would want to set it up such that we could
@@ -737,6 +878,14 @@ fn configure_constraints(
             Constraint::Length(length) => {
                 solver.add_constraint(element.has_int_size(length, LENGTH_SIZE_EQ))?;
             }
+            Constraint::MinContent(size) => {
+                solver.add_constraint(element.has_min_size(size, MIN_SIZE_GE))?;
+                solver.add_constraint(element.has_int_size(size, LENGTH_SIZE_EQ))?;
+            }
+            Constraint::MaxContent(size) => {
+                solver.add_constraint(element.has_max_size(size, MAX_SIZE_LE))?;
+                solver.add_constraint(element.has_int_size(size, LENGTH_SIZE_EQ))?;
+            }
             Constraint::Percentage(p) => {
                 let size = area.size() * f64::from(p) / 100.00;
                 solver.add_constraint(element.has_size(size, PERCENTAGE_SIZE_EQ))?;
@@ -1133,6 +1282,36 @@ mod tests {
         });
     }
 
+    #[test]
+    fn resize_and_clear_cache() {
+        Layout::resize_cache(2);
+        for width in 1..=3 {
+            Layout::vertical([Constraint::Min(0)]).split(Rect::new(0, 0, width, 1));
+        }
+        let stats = Layout::cache_stats();
+        assert_eq!((stats.len, stats.capacity), (2, 2));
+        Layout::clear_cache();
+        assert_eq!(Layout::cache_stats().len, 0);
+    }
+
+    #[test]
+    fn cache_stats() {
+        let layout = Layout::vertical([Constraint::Length(1), Constraint::Min(0)]);
+        let area = Rect::new(0, 0, 10, 10);
+        Layout::reset_cache_stats();
+        layout.split(area);
+        layout.split(area);
+        layout.split_uncached(area);
+        let stats = Layout::cache_stats();
+        assert_eq!((stats.hits, stats.misses, stats.len), (1, 1, 1));
+        assert_eq!(stats.capacity, Layout::DEFAULT_CACHE_SIZE);
+        Layout::reset_cache_stats();
+        assert_eq!(
+            (Layout::cache_stats().hits, Layout::cache_stats().misses),
+            (0, 0)
+        );
+    }
+
     #[test]
     fn default() {
         assert_eq!(
@@ -2164,6 +2343,25 @@ mod tests {
             assert_eq!(expected, r);
         }
 
+        #[rstest]
+        #[case::min_content(vec![20, 40], vec![MinContent(20), Length(40)])]
+        #[case::min_content_shares(vec![20, 80], vec![MinContent(20), Length(90)])]
+        #[case::min_content_fill(vec![20, 80], vec![MinContent(20), Fill(1)])]
+        #[case::min_content_overflow(vec![100, 0], vec![MinContent(120), Fill(1)])]
+        #[case::max_content(vec![20, 40], vec![MaxContent(20), Length(40)])]
+        #[case::max_content_fill(vec![20, 80], vec![MaxContent(20), Fill(1)])]
+        #[case::max_content_shrinks(vec![10, 90], vec![MaxContent(20), Min(90)])]
+        #[case::both(vec![10, 20, 70], vec![MaxContent(10), MinContent(20), Fill(1)])]
+        fn content(#[case] expected: Vec<u16>, #[case] constraints: Vec<Constraint>) {
+            let rect = Rect::new(0, 0, 100, 1);
+            let r = Layout::horizontal(constraints)
+                .split(rect)
+                .iter()
+                .map(|r| r.width)
+                .collect::<Vec<u16>>();
+            assert_eq!(expected, r);
+        }
+
         #[rstest]
         #[case::min_percentage(vec![80, 20], vec![Min(0), Percentage(20)])]
         #[case::max_percentage(vec![0, 100], vec![Max(0), Percentage(20)])]
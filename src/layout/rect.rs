@@ -249,6 +249,142 @@ impl Rect {
         Self::new(x, y, width, height)
     }
 
+    /// Returns a new `Rect` inside the current one, with a different margin on each side.
+    ///
+    /// This is the same as [`Rect::inner`], with separate margins for the left, right, top and
+    /// bottom sides. If the margins are larger than the `Rect`, the returned `Rect` will have no
+    /// width or height but keeps a position inside the current one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::prelude::*;
+    /// let rect = Rect::new(0, 0, 10, 5).inner_margin(1, 2, 0, 1);
+    /// assert_eq!(rect, Rect::new(1, 0, 7, 4));
+    /// ```
+    #[must_use = "method returns the modified value"]
+    pub fn inner_margin(self, left: u16, right: u16, top: u16, bottom: u16) -> Self {
+        let x = self.x.saturating_add(left).min(self.right());
+        let y = self.y.saturating_add(top).min(self.bottom());
+        Self {
+            x,
+            y,
+            width: self.right().saturating_sub(right).saturating_sub(x),
+            height: self.bottom().saturating_sub(bottom).saturating_sub(y),
+        }
+    }
+
+    /// Returns a `Rect` of the given size, aligned inside the `outer` `Rect`.
+    ///
+    /// The size is limited to the size of `outer`. `horizontal` places the `Rect` on the left, in
+    /// the center or on the right of `outer`. `vertical` places it on the top
+    /// ([`Alignment::Left`]), in the center ([`Alignment::Center`]) or at the bottom
+    /// ([`Alignment::Right`]) of `outer`. [`Alignment::Justify`] behaves like [`Alignment::Left`].
+    ///
+    /// When the `Rect` can not be exactly centered, the extra cell is left after it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{layout::Size, prelude::*};
+    /// let outer = Rect::new(0, 0, 20, 10);
+    /// let popup = Rect::align_within(outer, Alignment::Right, Alignment::Left, Size::new(5, 3));
+    /// assert_eq!(popup, Rect::new(15, 0, 5, 3));
+    /// ```
+    pub fn align_within(
+        outer: Self,
+        horizontal: Alignment,
+        vertical: Alignment,
+        size: Size,
+    ) -> Self {
+        /// Returns the offset of a length aligned inside an outer length
+        const fn offset(alignment: Alignment, outer: u16, length: u16) -> u16 {
+            match alignment {
+                Alignment::Left | Alignment::Justify => 0,
+                Alignment::Center => (outer - length) / 2,
+                Alignment::Right => outer - length,
+            }
+        }
+        let width = size.width.min(outer.width);
+        let height = size.height.min(outer.height);
+        Self {
+            x: outer.x + offset(horizontal, outer.width, width),
+            y: outer.y + offset(vertical, outer.height, height),
+            width,
+            height,
+        }
+    }
+
+    /// Returns a `Rect` of the given size, centered inside the current one.
+    ///
+    /// This is a shortcut for [`Rect::align_within`] with centered alignments, e.g. to place a
+    /// popup in the middle of the screen. The size is limited to the size of the current `Rect`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::prelude::*;
+    /// let popup = Rect::new(0, 0, 20, 10).centered(10, 4);
+    /// assert_eq!(popup, Rect::new(5, 3, 10, 4));
+    /// ```
+    #[must_use = "method returns the modified value"]
+    pub fn centered(self, width: u16, height: u16) -> Self {
+        Self::align_within(
+            self,
+            Alignment::Center,
+            Alignment::Center,
+            Size::new(width, height),
+        )
+    }
+
+    /// Splits the `Rect` into a left and a right part, `x` columns from its left side.
+    ///
+    /// `x` is limited to the width of the `Rect`, so the right part has no width when `x` is
+    /// larger than the `Rect`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::prelude::*;
+    /// let (sidebar, main) = Rect::new(0, 0, 20, 10).split_horizontally_at(5);
+    /// assert_eq!(sidebar, Rect::new(0, 0, 5, 10));
+    /// assert_eq!(main, Rect::new(5, 0, 15, 10));
+    /// ```
+    pub fn split_horizontally_at(self, x: u16) -> (Self, Self) {
+        let x = x.min(self.width);
+        let left = Self { width: x, ..self };
+        let right = Self {
+            x: self.x + x,
+            width: self.width - x,
+            ..self
+        };
+        (left, right)
+    }
+
+    /// Splits the `Rect` into a top and a bottom part, `y` rows from its top side.
+    ///
+    /// `y` is limited to the height of the `Rect`, so the bottom part has no height when `y` is
+    /// larger than the `Rect`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::prelude::*;
+    /// let (header, body) = Rect::new(0, 0, 20, 10).split_vertically_at(1);
+    /// assert_eq!(header, Rect::new(0, 0, 20, 1));
+    /// assert_eq!(body, Rect::new(0, 1, 20, 9));
+    /// ```
+    pub fn split_vertically_at(self, y: u16) -> (Self, Self) {
+        let y = y.min(self.height);
+        let top = Self { height: y, ..self };
+        let bottom = Self {
+            y: self.y + y,
+            height: self.height - y,
+            ..self
+        };
+        (top, bottom)
+    }
+
     /// An iterator over rows within the `Rect`.
     ///
     /// # Example
@@ -587,6 +723,67 @@ mod tests {
         assert_eq!(rect.clamp(other), expected);
     }
 
+    #[rstest]
+    #[case::none((0, 0, 0, 0), Rect::new(10, 20, 30, 40))]
+    #[case::sides((1, 2, 3, 4), Rect::new(11, 23, 27, 33))]
+    #[case::too_wide((20, 20, 0, 0), Rect::new(30, 20, 0, 40))]
+    #[case::too_tall((0, 0, 30, 15), Rect::new(10, 50, 30, 0))]
+    #[case::left_too_large((40, 0, 0, 0), Rect::new(40, 20, 0, 40))]
+    fn inner_margin(#[case] margins: (u16, u16, u16, u16), #[case] expected: Rect) {
+        let (left, right, top, bottom) = margins;
+        let rect = Rect::new(10, 20, 30, 40);
+        assert_eq!(rect.inner_margin(left, right, top, bottom), expected);
+    }
+
+    #[rstest]
+    #[case::top_left(Alignment::Left, Alignment::Left, Rect::new(10, 20, 4, 2))]
+    #[case::center(Alignment::Center, Alignment::Center, Rect::new(13, 24, 4, 2))]
+    #[case::bottom_right(Alignment::Right, Alignment::Right, Rect::new(16, 28, 4, 2))]
+    #[case::justify(Alignment::Justify, Alignment::Justify, Rect::new(10, 20, 4, 2))]
+    fn align_within(
+        #[case] horizontal: Alignment,
+        #[case] vertical: Alignment,
+        #[case] expected: Rect,
+    ) {
+        let outer = Rect::new(10, 20, 10, 10);
+        let rect = Rect::align_within(outer, horizontal, vertical, Size::new(4, 2));
+        assert_eq!(rect, expected);
+    }
+
+    #[test]
+    fn align_within_larger_than_outer() {
+        let outer = Rect::new(10, 20, 10, 10);
+        let rect = Rect::align_within(outer, Alignment::Center, Alignment::Right, Size::new(30, 5));
+        assert_eq!(rect, Rect::new(10, 25, 10, 5));
+    }
+
+    #[rstest]
+    #[case::even(Rect::new(0, 0, 10, 10), Rect::new(3, 4, 4, 2))]
+    #[case::odd(Rect::new(1, 1, 9, 9), Rect::new(3, 4, 4, 2))]
+    #[case::too_large(Rect::new(0, 0, 2, 1), Rect::new(0, 0, 2, 1))]
+    fn centered(#[case] rect: Rect, #[case] expected: Rect) {
+        assert_eq!(rect.centered(4, 2), expected);
+    }
+
+    #[rstest]
+    #[case::start(0, Rect::new(10, 20, 0, 5), Rect::new(10, 20, 30, 5))]
+    #[case::middle(10, Rect::new(10, 20, 10, 5), Rect::new(20, 20, 20, 5))]
+    #[case::end(30, Rect::new(10, 20, 30, 5), Rect::new(40, 20, 0, 5))]
+    #[case::beyond(50, Rect::new(10, 20, 30, 5), Rect::new(40, 20, 0, 5))]
+    fn split_horizontally_at(#[case] x: u16, #[case] left: Rect, #[case] right: Rect) {
+        let rect = Rect::new(10, 20, 30, 5);
+        assert_eq!(rect.split_horizontally_at(x), (left, right));
+    }
+
+    #[rstest]
+    #[case::start(0, Rect::new(10, 20, 5, 0), Rect::new(10, 20, 5, 30))]
+    #[case::middle(10, Rect::new(10, 20, 5, 10), Rect::new(10, 30, 5, 20))]
+    #[case::beyond(50, Rect::new(10, 20, 5, 30), Rect::new(10, 50, 5, 0))]
+    fn split_vertically_at(#[case] y: u16, #[case] top: Rect, #[case] bottom: Rect) {
+        let rect = Rect::new(10, 20, 5, 30);
+        assert_eq!(rect.split_vertically_at(y), (top, bottom));
+    }
+
     #[test]
     fn rows() {
         let area = Rect::new(0, 0, 3, 2);
@@ -0,0 +1,193 @@
+use std::rc::Rc;
+
+use super::Size;
+use crate::prelude::*;
+
+/// The minimum size of an area for a [`Responsive`] variant to be selected
+///
+/// A dimension of 0 matches any size, so [`Breakpoint::width`] only constrains the width and
+/// [`Breakpoint::height`] only constrains the height.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Breakpoint {
+    /// The minimum width in columns
+    pub min_width: u16,
+    /// The minimum height in rows
+    pub min_height: u16,
+}
+
+impl Breakpoint {
+    /// Creates a breakpoint matching areas of at least the given width and height
+    pub const fn new(min_width: u16, min_height: u16) -> Self {
+        Self {
+            min_width,
+            min_height,
+        }
+    }
+
+    /// Creates a breakpoint matching areas of at least the given width
+    pub const fn width(min_width: u16) -> Self {
+        Self::new(min_width, 0)
+    }
+
+    /// Creates a breakpoint matching areas of at least the given height
+    pub const fn height(min_height: u16) -> Self {
+        Self::new(0, min_height)
+    }
+
+    /// Returns true if the given size is at least as large as the breakpoint
+    pub const fn matches(self, size: Size) -> bool {
+        size.width >= self.min_width && size.height >= self.min_height
+    }
+}
+
+/// Selects between alternative [`Layout`]s based on the size of the area being split
+///
+/// A responsive layout has a default variant and any number of variants that are used when the
+/// area reaches a [`Breakpoint`]. Each variant has an id of any type, e.g. an enum or a string,
+/// which is returned along with the areas so that the widgets can be rendered into the areas of
+/// the chosen variant.
+///
+/// Breakpoints are checked in the reverse order that they were added, and the first one that
+/// matches is used, so they are usually added from the smallest to the largest. The default variant
+/// is used when no breakpoint matches.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     layout::{Breakpoint, Responsive},
+///     prelude::*,
+/// };
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Mode {
+///     Stacked,
+///     SideBySide,
+/// }
+///
+/// let layout = Responsive::new(Mode::Stacked, Layout::vertical([Constraint::Fill(1); 2]))
+///     .breakpoint(
+///         Breakpoint::width(80),
+///         Mode::SideBySide,
+///         Layout::horizontal([Constraint::Fill(1); 2]),
+///     );
+///
+/// let (mode, areas) = layout.split(Rect::new(0, 0, 60, 20));
+/// assert_eq!(mode, &Mode::Stacked);
+/// assert_eq!(
+///     areas[..],
+///     [Rect::new(0, 0, 60, 10), Rect::new(0, 10, 60, 10)]
+/// );
+///
+/// let (mode, areas) = layout.split(Rect::new(0, 0, 100, 20));
+/// assert_eq!(mode, &Mode::SideBySide);
+/// assert_eq!(
+///     areas[..],
+///     [Rect::new(0, 0, 50, 20), Rect::new(50, 0, 50, 20)]
+/// );
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Responsive<K> {
+    id: K,
+    layout: Layout,
+    breakpoints: Vec<(Breakpoint, K, Layout)>,
+}
+
+impl<K> Responsive<K> {
+    /// Creates a responsive layout with the variant that is used when no breakpoint matches
+    pub const fn new(id: K, layout: Layout) -> Self {
+        Self {
+            id,
+            layout,
+            breakpoints: Vec::new(),
+        }
+    }
+
+    /// Adds a variant that is used when the area matches the breakpoint
+    ///
+    /// Variants added later take precedence over the ones added before them.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn breakpoint(mut self, breakpoint: Breakpoint, id: K, layout: Layout) -> Self {
+        self.breakpoints.push((breakpoint, id, layout));
+        self
+    }
+
+    /// Returns the id and layout of the variant used for an area of the given size
+    pub fn select(&self, size: Size) -> (&K, &Layout) {
+        self.breakpoints
+            .iter()
+            .rev()
+            .find(|(breakpoint, _, _)| breakpoint.matches(size))
+            .map_or((&self.id, &self.layout), |(_, id, layout)| (id, layout))
+    }
+
+    /// Splits the area with the variant selected for its size
+    ///
+    /// Returns the id of the variant along with the areas, as returned by [`Layout::split`].
+    pub fn split(&self, area: Rect) -> (&K, Rc<[Rect]>) {
+        let (id, layout) = self.select(area.as_size());
+        (id, layout.split(area))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::zero(Breakpoint::default(), Size::new(0, 0), true)]
+    #[case::width(Breakpoint::width(80), Size::new(80, 0), true)]
+    #[case::narrow(Breakpoint::width(80), Size::new(79, 100), false)]
+    #[case::height(Breakpoint::height(24), Size::new(0, 24), true)]
+    #[case::short(Breakpoint::height(24), Size::new(100, 23), false)]
+    #[case::both(Breakpoint::new(80, 24), Size::new(80, 24), true)]
+    #[case::both_short(Breakpoint::new(80, 24), Size::new(80, 23), false)]
+    fn breakpoint_matches(
+        #[case] breakpoint: Breakpoint,
+        #[case] size: Size,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(breakpoint.matches(size), expected);
+    }
+
+    #[rstest]
+    #[case::default(Size::new(40, 10), "small")]
+    #[case::wide(Size::new(80, 10), "wide")]
+    #[case::large(Size::new(120, 40), "large")]
+    #[case::wide_and_short(Size::new(120, 39), "wide")]
+    fn select(#[case] size: Size, #[case] expected: &str) {
+        let layout = Responsive::new("small", Layout::vertical([Constraint::Fill(1)]))
+            .breakpoint(
+                Breakpoint::width(80),
+                "wide",
+                Layout::horizontal([Constraint::Fill(1)]),
+            )
+            .breakpoint(
+                Breakpoint::new(120, 40),
+                "large",
+                Layout::horizontal([Constraint::Fill(1); 3]),
+            );
+        let (id, _) = layout.select(size);
+        assert_eq!(*id, expected);
+    }
+
+    #[test]
+    fn split() {
+        let layout = Responsive::new(0, Layout::vertical([Constraint::Length(2); 2])).breakpoint(
+            Breakpoint::width(10),
+            1,
+            Layout::horizontal([Constraint::Length(5); 2]),
+        );
+
+        let (id, areas) = layout.split(Rect::new(1, 1, 9, 4));
+        assert_eq!(*id, 0);
+        assert_eq!(areas[..], [Rect::new(1, 1, 9, 2), Rect::new(1, 3, 9, 2)]);
+
+        let (id, areas) = layout.split(Rect::new(1, 1, 10, 4));
+        assert_eq!(*id, 1);
+        assert_eq!(areas[..], [Rect::new(1, 1, 5, 4), Rect::new(6, 1, 5, 4)]);
+    }
+}
@@ -0,0 +1,89 @@
+use crate::{
+    layout::Size,
+    text::{Line, Span, Text},
+};
+
+/// A widget that reports the size it would like to occupy
+///
+/// The size is used by [`Constraint::min_content`] and [`Constraint::max_content`] to create
+/// constraints that shrink-wrap a layout element around its content.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     layout::{Size, SizeHint},
+///     prelude::*,
+/// };
+///
+/// struct Logo;
+///
+/// impl SizeHint for Logo {
+///     fn intrinsic_size(&self, available: Size) -> Size {
+///         Size::new(20.min(available.width), 5.min(available.height))
+///     }
+/// }
+///
+/// let [logo, content] = Layout::horizontal([
+///     Constraint::max_content(&Logo, Direction::Horizontal, Size::new(80, 24)),
+///     Constraint::Fill(1),
+/// ])
+/// .areas(Rect::new(0, 0, 80, 24));
+/// assert_eq!(logo.width, 20);
+/// ```
+///
+/// [`Constraint::min_content`]: crate::layout::Constraint::min_content
+/// [`Constraint::max_content`]: crate::layout::Constraint::max_content
+pub trait SizeHint {
+    /// The size the widget would like to occupy when rendered in an area of at most the
+    /// `available` size
+    ///
+    /// Content that does not fit, e.g. text that is truncated when rendered, reports the
+    /// available size. Widgets that wrap their content use the available width to compute their
+    /// height.
+    fn intrinsic_size(&self, available: Size) -> Size;
+}
+
+impl SizeHint for Span<'_> {
+    fn intrinsic_size(&self, available: Size) -> Size {
+        let width = u16::try_from(self.width()).unwrap_or(u16::MAX);
+        Size::new(width.min(available.width), 1.min(available.height))
+    }
+}
+
+impl SizeHint for Line<'_> {
+    fn intrinsic_size(&self, available: Size) -> Size {
+        let width = u16::try_from(self.width()).unwrap_or(u16::MAX);
+        Size::new(width.min(available.width), 1.min(available.height))
+    }
+}
+
+impl SizeHint for Text<'_> {
+    fn intrinsic_size(&self, available: Size) -> Size {
+        let width = u16::try_from(self.width()).unwrap_or(u16::MAX);
+        let height = u16::try_from(self.height()).unwrap_or(u16::MAX);
+        Size::new(width.min(available.width), height.min(available.height))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn text() {
+        let available = Size::new(10, 10);
+        assert_eq!(Span::raw("abc").intrinsic_size(available), Size::new(3, 1));
+        assert_eq!(Line::raw("abc").intrinsic_size(available), Size::new(3, 1));
+        assert_eq!(
+            Text::raw("abc\nabcdef").intrinsic_size(available),
+            Size::new(6, 2)
+        );
+        assert_eq!(
+            Text::raw("abcdef\n\n\n").intrinsic_size(Size::new(4, 2)),
+            Size::new(4, 2)
+        );
+    }
+}
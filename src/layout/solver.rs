@@ -0,0 +1,569 @@
+//! A constraint solver to compute custom layouts.
+//!
+//! [`Layout`](super::Layout) splits an area along a single direction using the [`cassowary-rs`]
+//! solver. This module exposes the same solver to lay out areas with arbitrary linear relations
+//! between their edges and sizes, e.g. "the sidebar is 30% of the width left after a fixed menu"
+//! or "the footer is as tall as the header".
+//!
+//! A [`Solver`] creates [`Area`]s, whose left, top, right and bottom edges and size are unknown
+//! [`Expression`]s. Expressions are combined with `+`, `-`, `*` and `/`, and compared with
+//! [`Expression::equal_to`], [`Expression::at_most`] and [`Expression::at_least`] to create
+//! [`Relation`]s. A relation is required by default: adding it to the solver fails if it
+//! conflicts with the other required relations. A relation with a weaker [`Strength`] is
+//! satisfied as much as possible instead, and stronger relations win over weaker ones.
+//!
+//! # Example
+//!
+//! ```rust
+//! use ratatui::layout::{solver::*, Rect};
+//!
+//! let screen = Rect::new(0, 0, 100, 30);
+//! let mut solver = Solver::new();
+//! let menu = solver.area_in(screen);
+//! let sidebar = solver.area_in(screen);
+//! let main = solver.area_in(screen);
+//!
+//! for area in [menu, sidebar, main] {
+//!     solver.add(area.top().equal_to(screen.top()))?;
+//!     solver.add(area.bottom().equal_to(screen.bottom()))?;
+//! }
+//! solver.add(menu.left().equal_to(screen.left()))?;
+//! solver.add(menu.width().equal_to(20))?;
+//! solver.add(sidebar.left().equal_to(menu.right()))?;
+//! solver.add(
+//!     sidebar
+//!         .width()
+//!         .equal_to((screen.width - menu.width()) * 0.3),
+//! )?;
+//! solver.add(main.left().equal_to(sidebar.right()))?;
+//! solver.add(main.right().equal_to(screen.right()))?;
+//!
+//! assert_eq!(solver.rect(menu), Rect::new(0, 0, 20, 30));
+//! assert_eq!(solver.rect(sidebar), Rect::new(20, 0, 24, 30));
+//! assert_eq!(solver.rect(main), Rect::new(44, 0, 56, 30));
+//! # Ok::<(), SolverError>(())
+//! ```
+//!
+//! [`cassowary-rs`]: https://crates.io/crates/cassowary
+use std::{
+    fmt,
+    ops::{Add, Div, Mul, Neg, Sub},
+};
+
+use cassowary::{strength, AddConstraintError, RelationalOperator};
+
+use crate::layout::Rect;
+
+/// A solver that computes the values of [`Variable`]s from [`Relation`]s between them
+///
+/// See the [module documentation](self) for an example.
+pub struct Solver {
+    solver: cassowary::Solver,
+    /// The constraints added to the solver
+    constraints: Vec<cassowary::Constraint>,
+}
+
+impl fmt::Debug for Solver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Solver").finish_non_exhaustive()
+    }
+}
+
+impl Default for Solver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Solver {
+    /// Creates a new solver without relations
+    pub fn new() -> Self {
+        Self {
+            solver: cassowary::Solver::new(),
+            constraints: Vec::new(),
+        }
+    }
+
+    /// Creates a new area whose width and height are required to be positive
+    pub fn area(&mut self) -> Area {
+        let area = Area {
+            x: Variable::new(),
+            y: Variable::new(),
+            width: Variable::new(),
+            height: Variable::new(),
+        };
+        self.add_required(area.width().at_least(0));
+        self.add_required(area.height().at_least(0));
+        area
+    }
+
+    /// Creates a new area that is required to be inside of the given bounds
+    pub fn area_in(&mut self, bounds: Rect) -> Area {
+        let area = self.area();
+        self.add_required(area.left().at_least(bounds.left()));
+        self.add_required(area.top().at_least(bounds.top()));
+        self.add_required(area.right().at_most(bounds.right()));
+        self.add_required(area.bottom().at_most(bounds.bottom()));
+        area
+    }
+
+    /// Adds a relation to the solver
+    ///
+    /// # Errors
+    ///
+    /// Returns [`SolverError::Unsatisfiable`] if the relation is required and conflicts with the
+    /// required relations that were already added.
+    pub fn add(&mut self, relation: Relation) -> Result<(), SolverError> {
+        let Relation {
+            expression,
+            operator,
+            strength,
+        } = relation;
+        let constraint = cassowary::Constraint::new(expression.0, operator, strength.0);
+        match self.solver.add_constraint(constraint.clone()) {
+            Ok(()) => {
+                self.constraints.push(constraint);
+                Ok(())
+            }
+            Err(error) => {
+                // a constraint that failed to be added leaves the cassowary solver in an
+                // inconsistent state, so it is rebuilt from the constraints that were added
+                self.solver.reset();
+                self.solver
+                    .add_constraints(&self.constraints)
+                    .expect("constraints that were already added should be satisfiable");
+                Err(match error {
+                    AddConstraintError::UnsatisfiableConstraint => SolverError::Unsatisfiable,
+                    AddConstraintError::DuplicateConstraint
+                    | AddConstraintError::InternalSolverError(_) => SolverError::Internal,
+                })
+            }
+        }
+    }
+
+    /// Adds relations to the solver, stopping at the first error
+    ///
+    /// # Errors
+    ///
+    /// See [`Solver::add`].
+    pub fn add_all<I>(&mut self, relations: I) -> Result<(), SolverError>
+    where
+        I: IntoIterator<Item = Relation>,
+    {
+        relations
+            .into_iter()
+            .try_for_each(|relation| self.add(relation))
+    }
+
+    /// The value of a variable that satisfies the relations added to the solver
+    ///
+    /// Variables that are not part of any relation are 0.
+    pub fn value(&self, variable: Variable) -> f64 {
+        self.solver.get_value(variable.0)
+    }
+
+    /// The position and size of an area that satisfy the relations added to the solver
+    ///
+    /// The edges of the area are rounded to the nearest cell, so that adjacent areas do not
+    /// overlap or leave a gap between them.
+    pub fn rect(&self, area: Area) -> Rect {
+        let left = round(self.value(area.x));
+        let top = round(self.value(area.y));
+        let right = round(self.value(area.x) + self.value(area.width));
+        let bottom = round(self.value(area.y) + self.value(area.height));
+        Rect::new(
+            left,
+            top,
+            right.saturating_sub(left),
+            bottom.saturating_sub(top),
+        )
+    }
+
+    /// Adds a relation that cannot conflict with the existing ones because it involves new
+    /// variables
+    fn add_required(&mut self, relation: Relation) {
+        self.add(relation)
+            .expect("relation of new variables should be satisfiable");
+    }
+}
+
+/// Rounds a value to the nearest cell coordinate
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn round(value: f64) -> u16 {
+    value.round().clamp(0.0, f64::from(u16::MAX)) as u16
+}
+
+/// An error returned when a [`Relation`] cannot be added to a [`Solver`]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum SolverError {
+    /// The relation is required and conflicts with the required relations of the solver
+    Unsatisfiable,
+    /// The solver reached an invalid state
+    Internal,
+}
+
+impl fmt::Display for SolverError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unsatisfiable => f.write_str("the relation cannot be satisfied"),
+            Self::Internal => f.write_str("internal constraint solver error"),
+        }
+    }
+}
+
+impl std::error::Error for SolverError {}
+
+/// An unknown value computed by a [`Solver`]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Variable(cassowary::Variable);
+
+impl Default for Variable {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Variable {
+    /// Creates a new variable, distinct from all the other variables
+    pub fn new() -> Self {
+        Self(cassowary::Variable::new())
+    }
+}
+
+/// An area whose position and size are computed by a [`Solver`]
+///
+/// Areas are created with [`Solver::area`] or [`Solver::area_in`], and their computed position
+/// and size are returned by [`Solver::rect`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Area {
+    x: Variable,
+    y: Variable,
+    width: Variable,
+    height: Variable,
+}
+
+impl Area {
+    /// The x coordinate of the left edge of the area
+    pub fn left(self) -> Expression {
+        self.x.into()
+    }
+
+    /// The y coordinate of the top edge of the area
+    pub fn top(self) -> Expression {
+        self.y.into()
+    }
+
+    /// The x coordinate of the right edge of the area, which is outside of the area
+    pub fn right(self) -> Expression {
+        self.x + self.width
+    }
+
+    /// The y coordinate of the bottom edge of the area, which is outside of the area
+    pub fn bottom(self) -> Expression {
+        self.y + self.height
+    }
+
+    /// The width of the area
+    pub fn width(self) -> Expression {
+        self.width.into()
+    }
+
+    /// The height of the area
+    pub fn height(self) -> Expression {
+        self.height.into()
+    }
+}
+
+/// A linear combination of [`Variable`]s and a constant
+///
+/// Expressions are created from variables, numbers and the edges of an [`Area`], and are combined
+/// with `+`, `-`, and multiplied or divided by numbers.
+#[derive(Debug, Clone)]
+pub struct Expression(cassowary::Expression);
+
+impl Expression {
+    /// A relation requiring this expression to be equal to the other one
+    pub fn equal_to<T: Into<Self>>(self, other: T) -> Relation {
+        Relation::new(self, RelationalOperator::Equal, other.into())
+    }
+
+    /// A relation requiring this expression to be less than or equal to the other one
+    pub fn at_most<T: Into<Self>>(self, other: T) -> Relation {
+        Relation::new(self, RelationalOperator::LessOrEqual, other.into())
+    }
+
+    /// A relation requiring this expression to be greater than or equal to the other one
+    pub fn at_least<T: Into<Self>>(self, other: T) -> Relation {
+        Relation::new(self, RelationalOperator::GreaterOrEqual, other.into())
+    }
+}
+
+impl From<Variable> for Expression {
+    fn from(variable: Variable) -> Self {
+        Self(variable.0.into())
+    }
+}
+
+impl From<f64> for Expression {
+    fn from(value: f64) -> Self {
+        Self(value.into())
+    }
+}
+
+impl From<i32> for Expression {
+    fn from(value: i32) -> Self {
+        Self::from(f64::from(value))
+    }
+}
+
+impl From<u16> for Expression {
+    fn from(value: u16) -> Self {
+        Self::from(f64::from(value))
+    }
+}
+
+impl<T: Into<Self>> Add<T> for Expression {
+    type Output = Self;
+
+    fn add(self, rhs: T) -> Self {
+        Self(self.0 + rhs.into().0)
+    }
+}
+
+impl<T: Into<Self>> Sub<T> for Expression {
+    type Output = Self;
+
+    fn sub(self, rhs: T) -> Self {
+        Self(self.0 - rhs.into().0)
+    }
+}
+
+impl Mul<f64> for Expression {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self {
+        Self(self.0 * rhs)
+    }
+}
+
+impl Mul<Expression> for f64 {
+    type Output = Expression;
+
+    fn mul(self, rhs: Expression) -> Expression {
+        rhs * self
+    }
+}
+
+impl Div<f64> for Expression {
+    type Output = Self;
+
+    fn div(self, rhs: f64) -> Self {
+        Self(self.0 / rhs)
+    }
+}
+
+impl Neg for Expression {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+impl<T: Into<Expression>> Add<T> for Variable {
+    type Output = Expression;
+
+    fn add(self, rhs: T) -> Expression {
+        Expression::from(self) + rhs
+    }
+}
+
+impl<T: Into<Expression>> Sub<T> for Variable {
+    type Output = Expression;
+
+    fn sub(self, rhs: T) -> Expression {
+        Expression::from(self) - rhs
+    }
+}
+
+impl Mul<f64> for Variable {
+    type Output = Expression;
+
+    fn mul(self, rhs: f64) -> Expression {
+        Expression::from(self) * rhs
+    }
+}
+
+impl Div<f64> for Variable {
+    type Output = Expression;
+
+    fn div(self, rhs: f64) -> Expression {
+        Expression::from(self) / rhs
+    }
+}
+
+impl Sub<Expression> for u16 {
+    type Output = Expression;
+
+    fn sub(self, rhs: Expression) -> Expression {
+        Expression::from(self) - rhs
+    }
+}
+
+impl Add<Expression> for u16 {
+    type Output = Expression;
+
+    fn add(self, rhs: Expression) -> Expression {
+        Expression::from(self) + rhs
+    }
+}
+
+/// The strength of a [`Relation`]
+///
+/// A [`Solver`] satisfies all the [`REQUIRED`](Strength::REQUIRED) relations, and satisfies the
+/// other relations as much as possible, in the order of their strength.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Strength(f64);
+
+impl Strength {
+    /// The strength of relations that must be satisfied
+    pub const REQUIRED: Self = Self(strength::REQUIRED);
+    /// The strongest strength of relations that may not be satisfied
+    pub const STRONG: Self = Self(strength::STRONG);
+    /// A strength between [`STRONG`](Strength::STRONG) and [`WEAK`](Strength::WEAK)
+    pub const MEDIUM: Self = Self(strength::MEDIUM);
+    /// The weakest strength
+    pub const WEAK: Self = Self(strength::WEAK);
+
+    /// Creates a strength from the weights of its strong, medium and weak parts, each between 0
+    /// and 1000
+    ///
+    /// For example, `Strength::new(1.0, 0.0, 0.0)` is [`STRONG`](Strength::STRONG) and
+    /// `Strength::new(0.0, 2.0, 0.0)` is twice as strong as [`MEDIUM`](Strength::MEDIUM).
+    pub fn new(strong: f64, medium: f64, weak: f64) -> Self {
+        Self(strength::create(strong, medium, weak, 1.0))
+    }
+}
+
+/// A linear relation between two [`Expression`]s, which a [`Solver`] satisfies
+///
+/// Relations are created with [`Expression::equal_to`], [`Expression::at_most`] and
+/// [`Expression::at_least`], and are [required](Strength::REQUIRED) by default.
+#[derive(Debug, Clone)]
+#[must_use = "relations must be added to a solver to take effect"]
+pub struct Relation {
+    /// The left side minus the right side of the relation, compared to 0
+    expression: Expression,
+    operator: RelationalOperator,
+    strength: Strength,
+}
+
+impl Relation {
+    fn new(lhs: Expression, operator: RelationalOperator, rhs: Expression) -> Self {
+        Self {
+            expression: lhs - rhs,
+            operator,
+            strength: Strength::REQUIRED,
+        }
+    }
+
+    /// Sets the strength of the relation
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn strength(mut self, strength: Strength) -> Self {
+        self.strength = strength;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    #[test]
+    fn area_in_bounds() {
+        let bounds = Rect::new(5, 5, 10, 10);
+        let mut solver = Solver::new();
+        let area = solver.area_in(bounds);
+        solver.add(area.width().equal_to(100)).unwrap_err();
+        solver
+            .add(area.width().equal_to(100).strength(Strength::STRONG))
+            .unwrap();
+        solver.add(area.height().equal_to(3)).unwrap();
+        solver
+            .add(area.left().equal_to(0).strength(Strength::WEAK))
+            .unwrap();
+        solver.add(area.bottom().equal_to(bounds.bottom())).unwrap();
+        assert_eq!(solver.rect(area), Rect::new(5, 12, 10, 3));
+    }
+
+    #[test]
+    fn stronger_relations_win() {
+        let mut solver = Solver::new();
+        let variable = Variable::new();
+        solver
+            .add_all([
+                Expression::from(variable)
+                    .equal_to(10)
+                    .strength(Strength::WEAK),
+                Expression::from(variable)
+                    .equal_to(20)
+                    .strength(Strength::new(0.0, 2.0, 0.0)),
+                Expression::from(variable)
+                    .equal_to(30)
+                    .strength(Strength::MEDIUM),
+            ])
+            .unwrap();
+        assert!((solver.value(variable) - 20.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn rounds_adjacent_areas_without_gaps() {
+        let bounds = Rect::new(0, 0, 10, 1);
+        let mut solver = Solver::new();
+        let areas = [
+            solver.area_in(bounds),
+            solver.area_in(bounds),
+            solver.area_in(bounds),
+        ];
+        solver.add(areas[0].left().equal_to(0)).unwrap();
+        solver.add(areas[2].right().equal_to(10)).unwrap();
+        for pair in areas.windows(2) {
+            solver
+                .add(pair[0].right().equal_to(pair[1].left()))
+                .unwrap();
+            solver
+                .add(pair[0].width().equal_to(pair[1].width()))
+                .unwrap();
+        }
+        let rects = areas.map(|area| solver.rect(area));
+        assert_eq!(rects[0].right(), rects[1].left());
+        assert_eq!(rects[1].right(), rects[2].left());
+        assert_eq!(rects[2].right(), 10);
+        assert_eq!(
+            rects.map(|rect| rect.width).iter().sum::<u16>(),
+            bounds.width
+        );
+    }
+
+    #[test]
+    fn expressions() {
+        let mut solver = Solver::new();
+        let a = Variable::new();
+        let b = Variable::new();
+        solver.add((a + 2.0).equal_to(8)).unwrap();
+        solver.add((b - a).equal_to(-(a * 0.5) + 20.0)).unwrap();
+        solver.add((2.0 * (b / 4.0)).at_least(0)).unwrap();
+        assert!((solver.value(a) - 6.0).abs() < f64::EPSILON);
+        assert!((solver.value(b) - 23.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn error_display() {
+        assert_eq!(
+            SolverError::Unsatisfiable.to_string(),
+            "the relation cannot be satisfied"
+        );
+    }
+}
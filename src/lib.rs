@@ -342,15 +342,34 @@
     html_favicon_url = "https://raw.githubusercontent.com/ratatui-org/ratatui/main/assets/favicon.ico"
 )]
 
+pub mod app;
 pub mod backend;
 pub mod buffer;
+#[cfg(any(feature = "crossterm", feature = "termion", feature = "termwiz"))]
+mod init;
 pub mod layout;
+#[cfg(feature = "runtime-tokio")]
+pub mod runtime;
 pub mod style;
 pub mod symbols;
 pub mod terminal;
 pub mod text;
 pub mod widgets;
 
+#[cfg(feature = "crossterm")]
+pub use self::init::{init, install_panic_hook, restore, try_init, try_restore, DefaultTerminal};
+#[cfg(feature = "termion")]
+pub use self::init::{
+    init_termion, install_termion_panic_hook, restore_termion, try_init_termion,
+    try_restore_termion, TermionTerminal,
+};
+#[cfg(feature = "termwiz")]
+pub use self::init::{
+    init_termwiz, install_termwiz_panic_hook, restore_termwiz, try_init_termwiz,
+    try_restore_termwiz, TermwizTerminal,
+};
+#[cfg(feature = "runtime-tokio")]
+pub use self::runtime::run;
 #[doc(inline)]
 pub use self::terminal::{CompletedFrame, Frame, Terminal, TerminalOptions, Viewport};
 
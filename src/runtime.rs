@@ -0,0 +1,369 @@
+//! An application runner for the [Tokio] runtime, which draws the interface and passes the events
+//! of the terminal and the messages of the application to an update function.
+//!
+//! Most applications repeat the same scaffolding: initialize the terminal, read its events, update
+//! the state of the application, redraw the interface when something changed, and restore the
+//! terminal when the application exits or panics. [`run`] does all of this following the
+//! [Elm architecture]. The application is made of:
+//!
+//! - a state, which holds all the data of the application;
+//! - an `update` function, which changes the state in response to a [`Message`], i.e. an event of
+//!   the terminal or a message sent by the application, and returns whether to keep running;
+//! - a `view` function, which renders the state to a [`Frame`].
+//!
+//! The interface is redrawn after each message, at most [`Runtime::fps`] times per second. Long
+//! running work, such as network requests, can be spawned as tasks that send their results to the
+//! application with the sender returned by [`Runtime::sender`].
+//!
+//! This requires the `runtime-tokio` feature, and a Tokio runtime with the time driver enabled.
+//!
+//! The [`app`](crate::app) module provides a synchronous runner for the same architecture, which
+//! blocks the current thread while waiting for events and runs commands synchronously. This
+//! runner awaits the events of the terminal and the messages of spawned tasks instead, which
+//! suits applications that already use Tokio for their own work.
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use std::ops::ControlFlow;
+//!
+//! use ratatui::{
+//!     backend::event::{Event, KeyCode},
+//!     runtime::Message,
+//!     widgets::Paragraph,
+//! };
+//!
+//! # async fn run() -> std::io::Result<()> {
+//! let count = ratatui::run(
+//!     0,
+//!     |count: &mut i32, message: Message<()>| {
+//!         if let Message::Event(Event::Key(key)) = message {
+//!             match key.code {
+//!                 KeyCode::Char('+') => *count += 1,
+//!                 KeyCode::Char('-') => *count -= 1,
+//!                 KeyCode::Char('q') => return ControlFlow::Break(()),
+//!                 _ => {}
+//!             }
+//!         }
+//!         ControlFlow::Continue(())
+//!     },
+//!     |count, frame| frame.render_widget(Paragraph::new(format!("Count: {count}")), frame.size()),
+//! )
+//! .await?;
+//! println!("final count: {count}");
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! [Tokio]: https://tokio.rs
+//! [Elm architecture]: https://guide.elm-lang.org/architecture/
+
+use std::{future::poll_fn, io, ops::ControlFlow, pin::Pin};
+
+use futures_core::Stream;
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+
+use crate::{
+    backend::{event::Event, EventStream},
+    prelude::*,
+};
+
+/// Runs an application until its `update` function breaks, and returns its final state
+///
+/// This is a shortcut for [`Runtime::new`] followed by [`Runtime::run`]. See the [module
+/// documentation](self) for more details.
+///
+/// # Errors
+///
+/// Returns an error if the terminal cannot be initialized, drawn to or restored, or if reading
+/// its events fails.
+pub async fn run<S, M, U, V>(state: S, update: U, view: V) -> io::Result<S>
+where
+    U: FnMut(&mut S, Message<M>) -> ControlFlow<()>,
+    V: FnMut(&S, &mut Frame),
+{
+    Runtime::new().run(state, update, view).await
+}
+
+/// A message passed to the `update` function of an application
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum Message<M> {
+    /// An event read from the terminal
+    Event(Event),
+    /// A message sent by the application with the sender returned by [`Runtime::sender`]
+    App(M),
+}
+
+/// Runs an application, with options
+///
+/// See the [module documentation](crate::runtime) for more details.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::{ops::ControlFlow, time::Duration};
+///
+/// use ratatui::{
+///     runtime::{Message, Runtime},
+///     widgets::Paragraph,
+/// };
+///
+/// # async fn run() -> std::io::Result<()> {
+/// let runtime = Runtime::new().fps(30);
+/// let sender = runtime.sender();
+/// tokio::spawn(async move {
+///     loop {
+///         tokio::time::sleep(Duration::from_secs(1)).await;
+///         if sender.send(()).is_err() {
+///             break;
+///         }
+///     }
+/// });
+/// runtime
+///     .run(
+///         0,
+///         |seconds: &mut u32, message| match message {
+///             Message::App(()) => {
+///                 *seconds += 1;
+///                 ControlFlow::Continue(())
+///             }
+///             Message::Event(_) => ControlFlow::Break(()),
+///         },
+///         |seconds, frame| {
+///             frame.render_widget(Paragraph::new(format!("{seconds}s")), frame.size())
+///         },
+///     )
+///     .await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct Runtime<M> {
+    sender: UnboundedSender<M>,
+    receiver: UnboundedReceiver<M>,
+    fps: u32,
+}
+
+impl<M> Default for Runtime<M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<M> Runtime<M> {
+    /// Creates a new runtime, which draws at most 60 frames per second
+    pub fn new() -> Self {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        Self {
+            sender,
+            receiver,
+            fps: 60,
+        }
+    }
+
+    /// Sets the maximum number of frames drawn per second
+    ///
+    /// An `fps` of 0 redraws the interface after every message. See
+    /// [`Terminal::draw_throttled`] for more details.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn fps(mut self, fps: u32) -> Self {
+        self.fps = fps;
+        self
+    }
+
+    /// Returns a sender for the messages of the application
+    ///
+    /// The messages are passed to the `update` function as [`Message::App`]. The sender can be
+    /// cloned and moved to other tasks or threads.
+    pub fn sender(&self) -> UnboundedSender<M> {
+        self.sender.clone()
+    }
+
+    /// Runs an application on a terminal initialized with [`crate::init`], until its `update`
+    /// function breaks, and returns its final state
+    ///
+    /// The terminal is restored when the application exits, and when it panics.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the terminal cannot be initialized, drawn to or restored, or if reading
+    /// its events fails.
+    pub async fn run<S, U, V>(self, state: S, update: U, view: V) -> io::Result<S>
+    where
+        U: FnMut(&mut S, Message<M>) -> ControlFlow<()>,
+        V: FnMut(&S, &mut Frame),
+    {
+        let mut terminal = crate::try_init()?;
+        let result = self
+            .run_with(&mut terminal, EventStream::crossterm(), state, update, view)
+            .await;
+        let restored = crate::try_restore();
+        let state = result?;
+        restored?;
+        Ok(state)
+    }
+
+    /// Runs an application on the given terminal with the given events, until its `update`
+    /// function breaks or the events end, and returns its final state
+    ///
+    /// Unlike [`Runtime::run`], this does not initialize or restore the terminal, which makes it
+    /// possible to use other backends, e.g. the [`TestBackend`](crate::backend::TestBackend) in
+    /// tests.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if drawing to the terminal or reading an event fails.
+    pub async fn run_with<B, E, S, U, V>(
+        self,
+        terminal: &mut Terminal<B>,
+        mut events: E,
+        mut state: S,
+        mut update: U,
+        mut view: V,
+    ) -> io::Result<S>
+    where
+        B: Backend,
+        E: Stream<Item = io::Result<Event>> + Unpin,
+        U: FnMut(&mut S, Message<M>) -> ControlFlow<()>,
+        V: FnMut(&S, &mut Frame),
+    {
+        // the sender of the runtime is kept alive so that the receiver never closes
+        let Self {
+            sender: _sender,
+            mut receiver,
+            fps,
+        } = self;
+        terminal.request_redraw();
+        loop {
+            terminal.draw_throttled(fps, |frame| view(&state, frame))?;
+            let next_frame = terminal.time_until_next_frame(fps);
+            let next_event = poll_fn(|cx| Pin::new(&mut events).poll_next(cx));
+            let message = tokio::select! {
+                event = next_event => match event {
+                    Some(event) => Message::Event(event?),
+                    None => return Ok(state),
+                },
+                Some(message) = receiver.recv() => Message::App(message),
+                () = tokio::time::sleep(next_frame), if terminal.needs_redraw() => continue,
+            };
+            terminal.request_redraw();
+            if update(&mut state, message).is_break() {
+                return Ok(state);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::stream;
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::{
+        backend::{
+            event::{KeyCode, KeyEvent, KeyModifiers},
+            TestBackend,
+        },
+        widgets::Paragraph,
+    };
+
+    fn key(c: char) -> Event {
+        Event::Key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::NONE))
+    }
+
+    fn view<T: ToString>(count: &T, frame: &mut Frame) {
+        frame.render_widget(Paragraph::new(count.to_string()), frame.size());
+    }
+
+    #[tokio::test]
+    async fn updates_state_with_events() {
+        let mut terminal = Terminal::new(TestBackend::new(3, 1)).unwrap();
+        let events = stream::iter([key('+'), key('+'), key('q'), key('+')].map(Ok));
+        let count = Runtime::<()>::new()
+            .fps(0)
+            .run_with(
+                &mut terminal,
+                events,
+                0,
+                |count, message| match message {
+                    Message::Event(Event::Key(key)) if key.code == KeyCode::Char('q') => {
+                        ControlFlow::Break(())
+                    }
+                    _ => {
+                        *count += 1;
+                        ControlFlow::Continue(())
+                    }
+                },
+                view,
+            )
+            .await
+            .unwrap();
+        assert_eq!(count, 2);
+        terminal.backend().assert_buffer_lines(["2  "]);
+    }
+
+    #[tokio::test]
+    async fn updates_state_with_app_messages() {
+        let mut terminal = Terminal::new(TestBackend::new(3, 1)).unwrap();
+        let runtime = Runtime::new();
+        let sender = runtime.sender();
+        sender.send(1).unwrap();
+        sender.send(2).unwrap();
+        let total = runtime
+            .run_with(
+                &mut terminal,
+                stream::pending(),
+                0,
+                |total, message| {
+                    if let Message::App(n) = message {
+                        *total += n;
+                    }
+                    if *total >= 3 {
+                        ControlFlow::Break(())
+                    } else {
+                        ControlFlow::Continue(())
+                    }
+                },
+                view,
+            )
+            .await
+            .unwrap();
+        assert_eq!(total, 3);
+    }
+
+    #[tokio::test]
+    async fn stops_when_events_end() {
+        let mut terminal = Terminal::new(TestBackend::new(3, 1)).unwrap();
+        let count = Runtime::<()>::new()
+            .run_with(
+                &mut terminal,
+                stream::iter([Ok(key('a'))]),
+                0,
+                |count, _| {
+                    *count += 1;
+                    ControlFlow::Continue(())
+                },
+                view,
+            )
+            .await
+            .unwrap();
+        assert_eq!(count, 1);
+    }
+
+    #[tokio::test]
+    async fn returns_event_errors() {
+        let mut terminal = Terminal::new(TestBackend::new(3, 1)).unwrap();
+        let events = stream::iter([Err(io::Error::other("broken"))]);
+        let result = Runtime::<()>::new()
+            .run_with(
+                &mut terminal,
+                events,
+                0,
+                |_, _| ControlFlow::Continue(()),
+                view,
+            )
+            .await;
+        assert_eq!(result.unwrap_err().to_string(), "broken");
+    }
+}
@@ -73,11 +73,14 @@ use std::fmt;
 use bitflags::bitflags;
 
 mod color;
+mod gradient;
 mod stylize;
 
-pub use color::{Color, ParseColorError};
+pub use color::{Color, ColorSupport, ParseColorError};
+pub use gradient::Gradient;
 pub use stylize::{Styled, Stylize};
 pub mod palette;
+pub mod theme;
 
 bitflags! {
     /// Modifier changes the way a piece of text is displayed.
@@ -87,6 +90,16 @@ bitflags! {
     /// `From<Modifier> for Style` is implemented so you can use `Modifier` anywhere that accepts
     /// `Into<Style>`.
     ///
+    /// Besides [`UNDERLINED`](Modifier::UNDERLINED), the underline can be drawn with a different
+    /// style ([`DOUBLE_UNDERLINED`](Modifier::DOUBLE_UNDERLINED),
+    /// [`CURLY_UNDERLINED`](Modifier::CURLY_UNDERLINED),
+    /// [`DOTTED_UNDERLINED`](Modifier::DOTTED_UNDERLINED) or
+    /// [`DASHED_UNDERLINED`](Modifier::DASHED_UNDERLINED)). Each of these modifiers underlines the
+    /// text on its own. When several of them are set, the curly, dashed, dotted, double and single
+    /// styles take precedence in that order. Backends that don't support underline styles fall back
+    /// to a single underline. The color of the underline can be set with
+    /// [`Style::underline_color`].
+    ///
     /// ## Examples
     ///
     /// ```rust
@@ -106,6 +119,31 @@ bitflags! {
         const REVERSED          = 0b0000_0100_0000;
         const HIDDEN            = 0b0000_1000_0000;
         const CROSSED_OUT       = 0b0001_0000_0000;
+        const DOUBLE_UNDERLINED = 0b0010_0000_0000;
+        const CURLY_UNDERLINED  = 0b0100_0000_0000;
+        const DOTTED_UNDERLINED = 0b1000_0000_0000;
+        const DASHED_UNDERLINED = 0b1_0000_0000_0000;
+    }
+}
+
+impl Modifier {
+    /// Returns the underline modifier that is displayed, following the precedence of underline
+    /// styles, or `None` if the text is not underlined.
+    // only the backends display underlines
+    #[cfg_attr(
+        not(any(feature = "crossterm", feature = "termion", feature = "termwiz")),
+        allow(dead_code)
+    )]
+    pub(crate) fn underline(self) -> Option<Self> {
+        [
+            Self::CURLY_UNDERLINED,
+            Self::DASHED_UNDERLINED,
+            Self::DOTTED_UNDERLINED,
+            Self::DOUBLE_UNDERLINED,
+            Self::UNDERLINED,
+        ]
+        .into_iter()
+        .find(|underline| self.contains(*underline))
     }
 }
 
@@ -595,6 +633,10 @@ mod tests {
             Modifier::REVERSED,
             Modifier::HIDDEN,
             Modifier::CROSSED_OUT,
+            Modifier::DOUBLE_UNDERLINED,
+            Modifier::CURLY_UNDERLINED,
+            Modifier::DOTTED_UNDERLINED,
+            Modifier::DASHED_UNDERLINED,
         ];
 
         let mut buffer = Buffer::empty(Rect::new(0, 0, 1, 1));
@@ -620,11 +662,27 @@ mod tests {
     #[case(Modifier::HIDDEN, "HIDDEN")]
     #[case(Modifier::CROSSED_OUT, "CROSSED_OUT")]
     #[case(Modifier::BOLD | Modifier::DIM, "BOLD | DIM")]
-    #[case(Modifier::all(), "BOLD | DIM | ITALIC | UNDERLINED | SLOW_BLINK | RAPID_BLINK | REVERSED | HIDDEN | CROSSED_OUT")]
+    #[case(Modifier::DOUBLE_UNDERLINED, "DOUBLE_UNDERLINED")]
+    #[case(Modifier::CURLY_UNDERLINED, "CURLY_UNDERLINED")]
+    #[case(Modifier::DOTTED_UNDERLINED, "DOTTED_UNDERLINED")]
+    #[case(Modifier::DASHED_UNDERLINED, "DASHED_UNDERLINED")]
+    #[case(Modifier::all(), "BOLD | DIM | ITALIC | UNDERLINED | SLOW_BLINK | RAPID_BLINK | REVERSED | HIDDEN | CROSSED_OUT | DOUBLE_UNDERLINED | CURLY_UNDERLINED | DOTTED_UNDERLINED | DASHED_UNDERLINED")]
     fn modifier_debug(#[case] modifier: Modifier, #[case] expected: &str) {
         assert_eq!(format!("{modifier:?}"), expected);
     }
 
+    #[rstest]
+    #[case(Modifier::empty(), None)]
+    #[case(Modifier::BOLD, None)]
+    #[case(Modifier::UNDERLINED, Some(Modifier::UNDERLINED))]
+    #[case(Modifier::UNDERLINED | Modifier::DOUBLE_UNDERLINED, Some(Modifier::DOUBLE_UNDERLINED))]
+    #[case(Modifier::DOTTED_UNDERLINED | Modifier::DOUBLE_UNDERLINED, Some(Modifier::DOTTED_UNDERLINED))]
+    #[case(Modifier::DASHED_UNDERLINED | Modifier::DOTTED_UNDERLINED, Some(Modifier::DASHED_UNDERLINED))]
+    #[case(Modifier::all(), Some(Modifier::CURLY_UNDERLINED))]
+    fn modifier_underline(#[case] modifier: Modifier, #[case] expected: Option<Modifier>) {
+        assert_eq!(modifier.underline(), expected);
+    }
+
     #[test]
     fn style_can_be_const() {
         const RED: Color = Color::Red;
@@ -2,6 +2,8 @@
 
 use std::{fmt, str::FromStr};
 
+use strum::{Display, EnumString};
+
 /// ANSI Color
 ///
 /// All colors from the [ANSI color table] are supported (though some names are not exactly the
@@ -389,6 +391,308 @@ impl Color {
         // Delegate to the function for normalized HSL to RGB conversion
         normalized_hsl_to_rgb(h / 360.0, s / 100.0, l / 100.0)
     }
+
+    /// Converts a HSV representation to a `Color::Rgb` instance.
+    ///
+    /// The `from_hsv` function converts the Hue, Saturation and Value values to a corresponding
+    /// `Color` RGB equivalent.
+    ///
+    /// Hue values should be in the range [0, 360].
+    /// Saturation and V values should be in the range [0, 100].
+    /// Values that are not in the range are clamped to be within the range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::prelude::*;
+    ///
+    /// let color: Color = Color::from_hsv(0.0, 100.0, 100.0);
+    /// assert_eq!(color, Color::Rgb(255, 0, 0));
+    ///
+    /// let color: Color = Color::from_hsv(240.0, 0.0, 0.0);
+    /// assert_eq!(color, Color::Rgb(0, 0, 0));
+    /// ```
+    pub fn from_hsv(h: f64, s: f64, v: f64) -> Self {
+        let h = h.clamp(0.0, 360.0);
+        let s = s.clamp(0.0, 100.0) / 100.0;
+        let v = v.clamp(0.0, 100.0) / 100.0;
+
+        // Convert to HSL, which is what the RGB conversion is based on
+        let lightness = v * (1.0 - s / 2.0);
+        let saturation = if lightness <= 0.0 || lightness >= 1.0 {
+            0.0
+        } else {
+            (v - lightness) / lightness.min(1.0 - lightness)
+        };
+        normalized_hsl_to_rgb(h / 360.0, saturation, lightness)
+    }
+
+    /// Returns a lighter version of the color.
+    ///
+    /// The `amount` is added to the lightness of the color in the HSL color space, and should be
+    /// in the range [0, 100]. The lightness is clamped, so lightening by 100 always returns white.
+    ///
+    /// The named and indexed colors are converted to RGB first (see [`Color::downsample`] for the
+    /// reverse conversion). [`Color::Reset`] is returned unchanged, as its actual color depends on
+    /// the terminal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::prelude::*;
+    ///
+    /// let color = Color::from_hsl(210.0, 80.0, 40.0);
+    /// assert_eq!(color.lighten(20.0), Color::from_hsl(210.0, 80.0, 60.0));
+    /// assert_eq!(Color::Reset.lighten(20.0), Color::Reset);
+    /// ```
+    #[must_use]
+    pub fn lighten(self, amount: f64) -> Self {
+        self.with_lightness_offset(amount)
+    }
+
+    /// Returns a darker version of the color.
+    ///
+    /// The `amount` is subtracted from the lightness of the color in the HSL color space, and
+    /// should be in the range [0, 100]. The lightness is clamped, so darkening by 100 always
+    /// returns black.
+    ///
+    /// The named and indexed colors are converted to RGB first. [`Color::Reset`] is returned
+    /// unchanged, as its actual color depends on the terminal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::prelude::*;
+    ///
+    /// let color = Color::from_hsl(210.0, 80.0, 40.0);
+    /// assert_eq!(color.darken(20.0), Color::from_hsl(210.0, 80.0, 20.0));
+    /// assert_eq!(Color::White.darken(100.0), Color::Rgb(0, 0, 0));
+    /// ```
+    #[must_use]
+    pub fn darken(self, amount: f64) -> Self {
+        self.with_lightness_offset(-amount)
+    }
+
+    /// Returns the color that is displayed when drawing this color with the given opacity over
+    /// the `bg` color.
+    ///
+    /// The `alpha` should be in the range [0, 1], where 0 returns the background and 1 returns
+    /// this color. This is useful to compute e.g. the background of selected or hovered items from
+    /// an accent color and the background of the application. See [`Color::lerp`] for how the
+    /// colors are combined.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::prelude::*;
+    ///
+    /// let bg = Color::Rgb(0, 0, 0);
+    /// let accent = Color::Rgb(200, 100, 0);
+    /// assert_eq!(
+    ///     accent.with_alpha_blend_over(bg, 0.25),
+    ///     Color::Rgb(50, 25, 0)
+    /// );
+    /// ```
+    #[must_use]
+    pub fn with_alpha_blend_over(self, bg: Self, alpha: f64) -> Self {
+        Self::lerp(bg, self, alpha)
+    }
+
+    /// Linearly interpolates between two colors.
+    ///
+    /// Returns the RGB value of `a` when `t` is 0 and of `b` when `t` is 1, and a color in between
+    /// for the values in between. Values of `t` that are not in the range [0, 1] are clamped to be
+    /// within the range. This can be used to compute gradients at runtime.
+    ///
+    /// The colors are interpolated per RGB component, so the result is always a [`Color::Rgb`]:
+    /// the named and indexed colors are converted to RGB first, even when `t` is 0 or 1. As
+    /// [`Color::Reset`] cannot be interpolated, `a` is returned unchanged when `t` is less than 0.5
+    /// and `b` otherwise if either color is [`Color::Reset`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::prelude::*;
+    ///
+    /// let a = Color::Rgb(0, 0, 0);
+    /// let b = Color::Rgb(255, 100, 10);
+    /// assert_eq!(Color::lerp(a, b, 0.0), a);
+    /// assert_eq!(Color::lerp(a, b, 0.5), Color::Rgb(128, 50, 5));
+    /// assert_eq!(Color::lerp(a, b, 1.0), b);
+    /// // named colors come back as RGB
+    /// assert_eq!(Color::lerp(Color::Red, b, 0.0), Color::Rgb(205, 0, 0));
+    /// ```
+    pub fn lerp(a: Self, b: Self, t: f64) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let (Some(from), Some(to)) = (a.to_rgb(), b.to_rgb()) else {
+            return if t < 0.5 { a } else { b };
+        };
+        let [red, green, blue] = [0, 1, 2].map(|i| {
+            let (from, to) = (f64::from(from[i]), f64::from(to[i]));
+            (from + (to - from) * t).round() as u8
+        });
+        Self::Rgb(red, green, blue)
+    }
+
+    /// Returns the color with the given amount added to its HSL lightness, in the range [0, 100].
+    fn with_lightness_offset(self, amount: f64) -> Self {
+        let Some(rgb) = self.to_rgb() else {
+            return self;
+        };
+        let (hue, saturation, lightness) = rgb_to_normalized_hsl(rgb);
+        normalized_hsl_to_rgb(
+            hue,
+            saturation,
+            (lightness + amount / 100.0).clamp(0.0, 1.0),
+        )
+    }
+
+    /// Returns the RGB value that the color is usually displayed as, or `None` for
+    /// [`Color::Reset`], which depends on the terminal.
+    ///
+    /// The named colors and the first 16 indexed colors use the default palette of xterm, and the
+    /// other indexed colors use the standard 6x6x6 color cube and grayscale ramp.
+    pub(crate) const fn to_rgb(self) -> Option<[u8; 3]> {
+        match self {
+            Self::Reset => None,
+            Self::Rgb(r, g, b) => Some([r, g, b]),
+            Self::Indexed(index @ 0..=15) => Some(NAMED_COLORS[index as usize].1),
+            Self::Indexed(index @ 16..=231) => {
+                let index = index - 16;
+                Some([
+                    CUBE_LEVELS[(index / 36) as usize],
+                    CUBE_LEVELS[(index / 6 % 6) as usize],
+                    CUBE_LEVELS[(index % 6) as usize],
+                ])
+            }
+            Self::Indexed(index) => {
+                let level = (index - 232) * 10 + 8;
+                Some([level, level, level])
+            }
+            Self::Black => Some(NAMED_COLORS[0].1),
+            Self::Red => Some(NAMED_COLORS[1].1),
+            Self::Green => Some(NAMED_COLORS[2].1),
+            Self::Yellow => Some(NAMED_COLORS[3].1),
+            Self::Blue => Some(NAMED_COLORS[4].1),
+            Self::Magenta => Some(NAMED_COLORS[5].1),
+            Self::Cyan => Some(NAMED_COLORS[6].1),
+            Self::Gray => Some(NAMED_COLORS[7].1),
+            Self::DarkGray => Some(NAMED_COLORS[8].1),
+            Self::LightRed => Some(NAMED_COLORS[9].1),
+            Self::LightGreen => Some(NAMED_COLORS[10].1),
+            Self::LightYellow => Some(NAMED_COLORS[11].1),
+            Self::LightBlue => Some(NAMED_COLORS[12].1),
+            Self::LightMagenta => Some(NAMED_COLORS[13].1),
+            Self::LightCyan => Some(NAMED_COLORS[14].1),
+            Self::White => Some(NAMED_COLORS[15].1),
+        }
+    }
+
+    /// Converts the color to the closest color of the palette of a terminal that supports the
+    /// given colors.
+    ///
+    /// RGB colors are converted to the closest of the 256 indexed colors with
+    /// [`ColorSupport::Ansi256`], and RGB and indexed colors are converted to the closest of the 16
+    /// named colors with [`ColorSupport::Ansi16`]. Colors that are already in the palette, as well
+    /// as [`Color::Reset`], are returned unchanged.
+    ///
+    /// This makes it possible to author styles with RGB colors and still display sensible colors
+    /// on terminals that do not support them, where RGB colors are otherwise ignored or displayed
+    /// as unrelated colors. The backends can do this automatically, see e.g.
+    /// `CrosstermBackend::with_color_support`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ratatui::{prelude::*, style::ColorSupport};
+    ///
+    /// let orange = Color::Rgb(255, 135, 0);
+    /// assert_eq!(
+    ///     orange.downsample(ColorSupport::Ansi256),
+    ///     Color::Indexed(208)
+    /// );
+    /// assert_eq!(orange.downsample(ColorSupport::Ansi16), Color::Yellow);
+    /// assert_eq!(orange.downsample(ColorSupport::TrueColor), orange);
+    /// ```
+    #[must_use]
+    pub fn downsample(self, palette: ColorSupport) -> Self {
+        match palette {
+            ColorSupport::Ansi16 => self.nearest_named(),
+            ColorSupport::Ansi256 => self.nearest_indexed(),
+            ColorSupport::TrueColor => self,
+        }
+    }
+
+    /// Returns the named color closest to this color, or the color itself for [`Color::Reset`]
+    /// and the named colors.
+    fn nearest_named(self) -> Self {
+        let Some(rgb) = self.to_rgb() else {
+            return self;
+        };
+        NAMED_COLORS
+            .iter()
+            .min_by_key(|(_, named)| distance(rgb, *named))
+            .map_or(self, |(color, _)| *color)
+    }
+
+    /// Returns the indexed color closest to this RGB color, or the color itself for the other
+    /// colors.
+    ///
+    /// The closest color is searched in the color cube and the grayscale ramp only, as the first
+    /// 16 indexed colors are often customized by the terminal theme.
+    fn nearest_indexed(self) -> Self {
+        let Self::Rgb(r, g, b) = self else {
+            return self;
+        };
+        let cube_index = |value: u8| match value {
+            0..=47 => 0,
+            48..=114 => 1,
+            _ => (value - 35) / 40,
+        };
+        let cube = Self::Indexed(16 + 36 * cube_index(r) + 6 * cube_index(g) + cube_index(b));
+        let average = ((u16::from(r) + u16::from(g) + u16::from(b)) / 3) as u8;
+        let gray = Self::Indexed(232 + (average.saturating_sub(3) / 10).min(23));
+        [cube, gray]
+            .into_iter()
+            .min_by_key(|color| {
+                color
+                    .to_rgb()
+                    .map_or(u32::MAX, |rgb| distance([r, g, b], rgb))
+            })
+            .unwrap_or(self)
+    }
+}
+
+/// The named colors and their RGB values in the default palette of xterm, in the order of their
+/// ANSI index
+const NAMED_COLORS: [(Color, [u8; 3]); 16] = [
+    (Color::Black, [0x00, 0x00, 0x00]),
+    (Color::Red, [0xcd, 0x00, 0x00]),
+    (Color::Green, [0x00, 0xcd, 0x00]),
+    (Color::Yellow, [0xcd, 0xcd, 0x00]),
+    (Color::Blue, [0x00, 0x00, 0xee]),
+    (Color::Magenta, [0xcd, 0x00, 0xcd]),
+    (Color::Cyan, [0x00, 0xcd, 0xcd]),
+    (Color::Gray, [0xe5, 0xe5, 0xe5]),
+    (Color::DarkGray, [0x7f, 0x7f, 0x7f]),
+    (Color::LightRed, [0xff, 0x00, 0x00]),
+    (Color::LightGreen, [0x00, 0xff, 0x00]),
+    (Color::LightYellow, [0xff, 0xff, 0x00]),
+    (Color::LightBlue, [0x5c, 0x5c, 0xff]),
+    (Color::LightMagenta, [0xff, 0x00, 0xff]),
+    (Color::LightCyan, [0x00, 0xff, 0xff]),
+    (Color::White, [0xff, 0xff, 0xff]),
+];
+
+/// The levels of each component of the 6x6x6 color cube of the indexed colors
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// The squared euclidean distance between two RGB colors
+fn distance(a: [u8; 3], b: [u8; 3]) -> u32 {
+    a.iter()
+        .zip(b)
+        .map(|(a, b)| u32::from(a.abs_diff(b)).pow(2))
+        .sum()
 }
 
 /// Converts normalized HSL (Hue, Saturation, Lightness) values to RGB (Red, Green, Blue) color
@@ -445,6 +749,32 @@ fn normalized_hsl_to_rgb(hue: f64, saturation: f64, lightness: f64) -> Color {
     )
 }
 
+/// Converts an RGB color to normalized HSL (Hue, Saturation, Lightness) values in the range
+/// [0, 1]. This is the inverse of [`normalized_hsl_to_rgb`].
+fn rgb_to_normalized_hsl(rgb: [u8; 3]) -> (f64, f64, f64) {
+    let [red, green, blue] = rgb.map(|c| f64::from(c) / 255.0);
+    let max = rgb.iter().copied().max().unwrap_or_default();
+    let min = rgb.iter().copied().min().unwrap_or_default();
+    let (max_value, min_value) = (f64::from(max) / 255.0, f64::from(min) / 255.0);
+    let lightness = (max_value + min_value) / 2.0;
+
+    // Achromatic (grayscale) colors have no hue and no saturation
+    if max == min {
+        return (0.0, 0.0, lightness);
+    }
+
+    let delta = max_value - min_value;
+    let saturation = delta / (1.0 - (2.0 * lightness - 1.0).abs());
+    let hue = if max == rgb[0] {
+        ((green - blue) / delta).rem_euclid(6.0)
+    } else if max == rgb[1] {
+        (blue - red) / delta + 2.0
+    } else {
+        (red - green) / delta + 4.0
+    };
+    (hue / 6.0, saturation, lightness)
+}
+
 /// Helper function to calculate RGB component for a specific hue value.
 fn hue_to_rgb(p: f64, q: f64, t: f64) -> f64 {
     // Adjust the hue value to be within the valid range [0, 1]
@@ -468,15 +798,139 @@ fn hue_to_rgb(p: f64, q: f64, t: f64) -> f64 {
     }
 }
 
+/// The colors that a terminal can display.
+///
+/// Colors that the terminal cannot display are usually approximated by the terminal, and sometimes
+/// not at all (e.g. RGB colors are ignored by the legacy Windows console). [`ColorSupport::adapt`]
+/// converts a color to the closest color that can be displayed. The supported colors can be
+/// guessed from the environment with [`ColorSupport::detect`], and are reported by the backends in
+/// [`Capabilities::color_support`].
+///
+/// [`Capabilities::color_support`]: crate::backend::Capabilities::color_support
+///
+/// The variants are ordered by the number of colors, so they can be compared:
+///
+/// ```rust
+/// use ratatui::style::ColorSupport;
+///
+/// assert!(ColorSupport::TrueColor > ColorSupport::Ansi256);
+/// ```
+#[derive(
+    Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash,
+)]
+pub enum ColorSupport {
+    /// The 16 named colors, e.g. on the legacy Windows console
+    #[default]
+    Ansi16,
+    /// The 256 indexed colors
+    Ansi256,
+    /// 24-bit RGB colors
+    TrueColor,
+}
+
+impl ColorSupport {
+    /// Guesses the colors supported by the terminal from environment variables.
+    ///
+    /// This returns [`ColorSupport::TrueColor`] if `COLORTERM` is `truecolor` or `24bit` or the
+    /// terminal is Windows Terminal, [`ColorSupport::Ansi256`] if `TERM` ends with `256color`, and
+    /// [`ColorSupport::Ansi16`] otherwise.
+    pub fn detect() -> Self {
+        Self::detect_with(|name| std::env::var(name).ok())
+    }
+
+    /// Guesses the colors supported by the terminal from the environment variables returned by
+    /// `var`
+    fn detect_with<F: Fn(&str) -> Option<String>>(var: F) -> Self {
+        let colorterm = var("COLORTERM").unwrap_or_default();
+        if colorterm == "truecolor" || colorterm == "24bit" || var("WT_SESSION").is_some() {
+            Self::TrueColor
+        } else if var("TERM").is_some_and(|term| term.ends_with("256color")) {
+            Self::Ansi256
+        } else {
+            Self::Ansi16
+        }
+    }
+
+    /// Converts a color to the closest color that can be displayed.
+    ///
+    /// RGB colors are converted to indexed colors with [`ColorSupport::Ansi256`], and RGB and
+    /// indexed colors are converted to named colors with [`ColorSupport::Ansi16`]. The other colors
+    /// are returned unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::style::{Color, ColorSupport};
+    ///
+    /// assert_eq!(
+    ///     ColorSupport::Ansi16.adapt(Color::Rgb(250, 10, 10)),
+    ///     Color::LightRed
+    /// );
+    /// assert_eq!(
+    ///     ColorSupport::Ansi256.adapt(Color::Rgb(255, 0, 0)),
+    ///     Color::Indexed(196)
+    /// );
+    /// assert_eq!(
+    ///     ColorSupport::TrueColor.adapt(Color::Rgb(1, 2, 3)),
+    ///     Color::Rgb(1, 2, 3)
+    /// );
+    /// ```
+    ///
+    /// This is the same as [`Color::downsample`].
+    pub fn adapt(self, color: Color) -> Color {
+        color.downsample(self)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::error::Error;
 
+    use rstest::rstest;
     #[cfg(feature = "serde")]
     use serde::de::{Deserialize, IntoDeserializer};
 
     use super::*;
 
+    #[test]
+    fn to_rgb() {
+        assert_eq!(Color::Reset.to_rgb(), None);
+        assert_eq!(Color::LightBlue.to_rgb(), Some([0x5c, 0x5c, 0xff]));
+        assert_eq!(Color::Indexed(1).to_rgb(), Color::Red.to_rgb());
+        assert_eq!(Color::Indexed(110).to_rgb(), Some([0x87, 0xaf, 0xd7]));
+        assert_eq!(Color::Indexed(244).to_rgb(), Some([0x80, 0x80, 0x80]));
+        assert_eq!(Color::Rgb(1, 2, 3).to_rgb(), Some([1, 2, 3]));
+    }
+
+    #[rstest]
+    #[case::reset(Color::Reset, Color::Reset)]
+    #[case::named(Color::Cyan, Color::Cyan)]
+    #[case::indexed_named(Color::Indexed(9), Color::LightRed)]
+    #[case::indexed(Color::Indexed(196), Color::LightRed)]
+    #[case::rgb(Color::Rgb(200, 10, 10), Color::Red)]
+    #[case::rgb_gray(Color::Rgb(120, 120, 130), Color::DarkGray)]
+    fn downsample_ansi16(#[case] color: Color, #[case] expected: Color) {
+        assert_eq!(color.downsample(ColorSupport::Ansi16), expected);
+    }
+
+    #[rstest]
+    #[case::reset(Color::Reset, Color::Reset)]
+    #[case::named(Color::Red, Color::Red)]
+    #[case::indexed(Color::Indexed(3), Color::Indexed(3))]
+    #[case::rgb(Color::Rgb(255, 0, 0), Color::Indexed(196))]
+    #[case::rgb_cube(Color::Rgb(0x87, 0xaf, 0xd7), Color::Indexed(110))]
+    #[case::rgb_gray(Color::Rgb(128, 128, 128), Color::Indexed(244))]
+    #[case::rgb_near_gray(Color::Rgb(100, 101, 99), Color::Indexed(241))]
+    fn downsample_ansi256(#[case] color: Color, #[case] expected: Color) {
+        assert_eq!(color.downsample(ColorSupport::Ansi256), expected);
+    }
+
+    #[test]
+    fn downsample_true_color() {
+        let color = Color::Rgb(1, 2, 3);
+        assert_eq!(color.downsample(ColorSupport::TrueColor), color);
+    }
+
     #[test]
     fn test_hsl_to_rgb() {
         // Test with valid HSL values
@@ -504,6 +958,87 @@ mod tests {
         assert_eq!(color, Color::Rgb(0, 0, 0));
     }
 
+    #[test]
+    fn from_hsv() {
+        assert_eq!(Color::from_hsv(0.0, 100.0, 100.0), Color::Rgb(255, 0, 0));
+        assert_eq!(Color::from_hsv(120.0, 50.0, 50.0), Color::Rgb(64, 128, 64));
+        assert_eq!(
+            Color::from_hsv(240.0, 0.0, 100.0),
+            Color::Rgb(255, 255, 255)
+        );
+        assert_eq!(Color::from_hsv(-20.0, 120.0, -10.0), Color::Rgb(0, 0, 0));
+    }
+
+    #[rstest]
+    #[case::black(Color::Rgb(0, 0, 0), 50.0, Color::Rgb(128, 128, 128))]
+    #[case::named(Color::Black, 100.0, Color::Rgb(255, 255, 255))]
+    #[case::hue(Color::Rgb(204, 102, 0), 10.0, Color::Rgb(255, 128, 0))]
+    #[case::reset(Color::Reset, 50.0, Color::Reset)]
+    fn lighten(#[case] color: Color, #[case] amount: f64, #[case] expected: Color) {
+        assert_eq!(color.lighten(amount), expected);
+    }
+
+    #[rstest]
+    #[case::white(Color::Rgb(255, 255, 255), 50.0, Color::Rgb(128, 128, 128))]
+    #[case::indexed(Color::Indexed(196), 100.0, Color::Rgb(0, 0, 0))]
+    #[case::hue(
+        Color::from_hsl(300.0, 40.0, 70.0),
+        40.0,
+        Color::from_hsl(300.0, 40.0, 30.0)
+    )]
+    #[case::reset(Color::Reset, 50.0, Color::Reset)]
+    fn darken(#[case] color: Color, #[case] amount: f64, #[case] expected: Color) {
+        assert_eq!(color.darken(amount), expected);
+    }
+
+    #[test]
+    fn rgb_to_hsl_round_trip() {
+        for rgb in [
+            [0, 0, 0],
+            [255, 0, 0],
+            [12, 200, 99],
+            [250, 128, 114],
+            [17, 17, 240],
+        ] {
+            let (h, s, l) = rgb_to_normalized_hsl(rgb);
+            assert_eq!(
+                normalized_hsl_to_rgb(h, s, l),
+                Color::Rgb(rgb[0], rgb[1], rgb[2])
+            );
+        }
+    }
+
+    #[rstest]
+    #[case::start(0.0, Color::Rgb(10, 20, 30))]
+    #[case::middle(0.5, Color::Rgb(60, 70, 80))]
+    #[case::end(1.0, Color::Rgb(110, 120, 130))]
+    #[case::below(-1.0, Color::Rgb(10, 20, 30))]
+    #[case::above(2.0, Color::Rgb(110, 120, 130))]
+    fn lerp(#[case] t: f64, #[case] expected: Color) {
+        let a = Color::Rgb(10, 20, 30);
+        let b = Color::Rgb(110, 120, 130);
+        assert_eq!(Color::lerp(a, b, t), expected);
+    }
+
+    #[test]
+    fn lerp_named_and_reset() {
+        assert_eq!(
+            Color::lerp(Color::Black, Color::White, 0.5),
+            Color::Rgb(128, 128, 128)
+        );
+        assert_eq!(Color::lerp(Color::Reset, Color::Red, 0.4), Color::Reset);
+        assert_eq!(Color::lerp(Color::Reset, Color::Red, 0.5), Color::Red);
+    }
+
+    #[test]
+    fn with_alpha_blend_over() {
+        let bg = Color::Rgb(0, 0, 100);
+        let fg = Color::Rgb(200, 100, 0);
+        assert_eq!(fg.with_alpha_blend_over(bg, 0.0), bg);
+        assert_eq!(fg.with_alpha_blend_over(bg, 0.5), Color::Rgb(100, 50, 50));
+        assert_eq!(fg.with_alpha_blend_over(bg, 1.0), fg);
+    }
+
     #[test]
     fn from_u32() {
         assert_eq!(Color::from_u32(0x000000), Color::Rgb(0, 0, 0));
@@ -701,4 +1236,38 @@ mod tests {
         );
         Ok(())
     }
+
+    #[test]
+    fn color_support_detect() {
+        let detect = |vars: &[(&str, &str)]| {
+            ColorSupport::detect_with(|name| {
+                vars.iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| (*value).to_string())
+            })
+        };
+        assert_eq!(detect(&[]), ColorSupport::Ansi16);
+        assert_eq!(detect(&[("TERM", "xterm")]), ColorSupport::Ansi16);
+        assert_eq!(detect(&[("TERM", "xterm-256color")]), ColorSupport::Ansi256);
+        assert_eq!(
+            detect(&[("TERM", "xterm-256color"), ("COLORTERM", "truecolor")]),
+            ColorSupport::TrueColor
+        );
+        assert_eq!(detect(&[("WT_SESSION", "1")]), ColorSupport::TrueColor);
+    }
+
+    #[test]
+    fn color_support_adapt() {
+        assert_eq!(ColorSupport::Ansi16.adapt(Color::Reset), Color::Reset);
+        assert_eq!(ColorSupport::Ansi16.adapt(Color::Indexed(2)), Color::Green);
+        assert_eq!(
+            ColorSupport::Ansi256.adapt(Color::Indexed(2)),
+            Color::Indexed(2)
+        );
+        assert_eq!(ColorSupport::Ansi256.adapt(Color::Blue), Color::Blue);
+        assert_eq!(
+            ColorSupport::TrueColor.adapt(Color::Indexed(2)),
+            Color::Indexed(2)
+        );
+    }
 }
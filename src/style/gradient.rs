@@ -0,0 +1,181 @@
+use crate::{layout::Direction, style::Color};
+
+/// A gradient of colors that are interpolated across the width or the height of an area.
+///
+/// A gradient is made of two or more colors that are evenly spaced, and the colors in between are
+/// computed with [`Color::lerp`]. It can be applied to the foreground of text with
+/// [`Line::fg_gradient`] and to the background of a block with [`Block::bg_gradient`], which
+/// compute a color for each cell.
+///
+/// The interpolated colors are RGB colors, which are only displayed as intended by terminals that
+/// support true color. Other terminals display the closest color of their palette when the backend
+/// downsamples the colors, see e.g. `CrosstermBackend::with_color_support`, which makes the
+/// gradient look banded but keeps it readable.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{
+///     prelude::*,
+///     style::Gradient,
+///     widgets::{Block, Paragraph},
+/// };
+///
+/// let sunset = Gradient::new([Color::Rgb(255, 94, 77), Color::Rgb(80, 40, 140)]);
+/// let title = Line::from("Dashboard").fg_gradient(&sunset);
+/// let block = Block::bordered().bg_gradient(sunset.direction(Direction::Vertical));
+/// let paragraph = Paragraph::new(title).block(block);
+/// ```
+///
+/// [`Line::fg_gradient`]: crate::text::Line::fg_gradient
+/// [`Block::bg_gradient`]: crate::widgets::Block::bg_gradient
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Gradient {
+    /// The evenly spaced colors of the gradient
+    colors: Vec<Color>,
+    /// Whether the colors change across the width or the height of the area
+    direction: Direction,
+}
+
+impl Gradient {
+    /// Creates a horizontal gradient going through the given colors from left to right.
+    ///
+    /// A gradient without colors returns [`Color::Reset`] everywhere, and a gradient with a single
+    /// color returns that color everywhere.
+    pub fn new<I>(colors: I) -> Self
+    where
+        I: IntoIterator<Item = Color>,
+    {
+        Self {
+            colors: colors.into_iter().collect(),
+            direction: Direction::Horizontal,
+        }
+    }
+
+    /// Sets the direction of the gradient.
+    ///
+    /// A [`Direction::Horizontal`] gradient goes from left to right, and a
+    /// [`Direction::Vertical`] gradient goes from top to bottom. Text is always colored from left
+    /// to right, regardless of the direction.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Returns the colors of the gradient.
+    pub fn colors(&self) -> &[Color] {
+        &self.colors
+    }
+
+    /// Returns the color at the given position of the gradient.
+    ///
+    /// The position `t` goes from 0 for the first color to 1 for the last color, and is clamped to
+    /// be within this range.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{prelude::*, style::Gradient};
+    ///
+    /// let gradient = Gradient::new([
+    ///     Color::Rgb(0, 0, 0),
+    ///     Color::Rgb(200, 0, 0),
+    ///     Color::Rgb(200, 200, 0),
+    /// ]);
+    /// assert_eq!(gradient.color_at(0.25), Color::Rgb(100, 0, 0));
+    /// assert_eq!(gradient.color_at(0.75), Color::Rgb(200, 100, 0));
+    /// ```
+    pub fn color_at(&self, t: f64) -> Color {
+        match self.colors.as_slice() {
+            [] => Color::Reset,
+            [color] => *color,
+            colors => {
+                let segments = colors.len() - 1;
+                let position = t.clamp(0.0, 1.0) * segments as f64;
+                let index = (position.floor() as usize).min(segments - 1);
+                Color::lerp(colors[index], colors[index + 1], position - index as f64)
+            }
+        }
+    }
+
+    /// Returns the color of the cell at the given offset in a row or column of `length` cells,
+    /// where the first cell has the first color and the last cell has the last color.
+    pub(crate) fn color_at_cell(&self, offset: usize, length: usize) -> Color {
+        if length <= 1 {
+            return self.color_at(0.0);
+        }
+        self.color_at(offset as f64 / (length - 1) as f64)
+    }
+
+    /// Returns the color of the cell at the given position in an area of the given size, following
+    /// the direction of the gradient.
+    pub(crate) fn color_in_area(&self, (x, y): (u16, u16), (width, height): (u16, u16)) -> Color {
+        match self.direction {
+            Direction::Horizontal => self.color_at_cell(x.into(), width.into()),
+            Direction::Vertical => self.color_at_cell(y.into(), height.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn new() {
+        let gradient = Gradient::new([Color::Red, Color::Blue]);
+        assert_eq!(gradient.colors(), [Color::Red, Color::Blue]);
+        assert_eq!(gradient.direction, Direction::Horizontal);
+        assert_eq!(
+            gradient.direction(Direction::Vertical).direction,
+            Direction::Vertical
+        );
+    }
+
+    #[rstest]
+    #[case::start(0.0, Color::Rgb(0, 0, 0))]
+    #[case::first_segment(0.25, Color::Rgb(50, 0, 0))]
+    #[case::middle(0.5, Color::Rgb(100, 0, 0))]
+    #[case::second_segment(0.75, Color::Rgb(100, 100, 0))]
+    #[case::end(1.0, Color::Rgb(100, 200, 0))]
+    #[case::below(-1.0, Color::Rgb(0, 0, 0))]
+    #[case::above(2.0, Color::Rgb(100, 200, 0))]
+    fn color_at(#[case] t: f64, #[case] expected: Color) {
+        let gradient = Gradient::new([
+            Color::Rgb(0, 0, 0),
+            Color::Rgb(100, 0, 0),
+            Color::Rgb(100, 200, 0),
+        ]);
+        assert_eq!(gradient.color_at(t), expected);
+    }
+
+    #[test]
+    fn color_at_without_enough_colors() {
+        assert_eq!(Gradient::new([]).color_at(0.5), Color::Reset);
+        assert_eq!(Gradient::new([Color::Green]).color_at(0.5), Color::Green);
+    }
+
+    #[test]
+    fn color_at_cell() {
+        let gradient = Gradient::new([Color::Rgb(0, 0, 0), Color::Rgb(0, 0, 100)]);
+        assert_eq!(gradient.color_at_cell(0, 5), Color::Rgb(0, 0, 0));
+        assert_eq!(gradient.color_at_cell(1, 5), Color::Rgb(0, 0, 25));
+        assert_eq!(gradient.color_at_cell(4, 5), Color::Rgb(0, 0, 100));
+        assert_eq!(gradient.color_at_cell(0, 1), Color::Rgb(0, 0, 0));
+    }
+
+    #[test]
+    fn color_in_area() {
+        let gradient = Gradient::new([Color::Rgb(0, 0, 0), Color::Rgb(0, 0, 100)]);
+        assert_eq!(
+            gradient.color_in_area((2, 0), (3, 5)),
+            Color::Rgb(0, 0, 100)
+        );
+        let gradient = gradient.direction(Direction::Vertical);
+        assert_eq!(gradient.color_in_area((2, 0), (3, 5)), Color::Rgb(0, 0, 0));
+        assert_eq!(gradient.color_in_area((2, 2), (3, 5)), Color::Rgb(0, 0, 50));
+    }
+}
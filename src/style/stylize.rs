@@ -173,6 +173,10 @@ pub trait Stylize<'a, T>: Sized {
     modifier!(reversed);
     modifier!(hidden);
     modifier!(crossed_out);
+    modifier!(double_underlined);
+    modifier!(curly_underlined);
+    modifier!(dotted_underlined);
+    modifier!(dashed_underlined);
 }
 
 impl<'a, T, U> Stylize<'a, T> for U
@@ -0,0 +1,241 @@
+//! A module for defining themes, which map semantic roles to styles.
+//!
+//! Rather than choosing a [`Style`] for each part of the interface, an application can style its
+//! widgets with a [`Role`], such as [`Role::Primary`] or [`Role::Error`], which is converted to the
+//! style of that role in the active [`Theme`]. Switching between e.g. a dark and a light theme then
+//! only requires registering another theme with [`Theme::set_active`].
+//!
+//! `Role` implements `Into<Style>`, so it can be passed to every method accepting a style, such as
+//! [`Block::border_style`] or [`Span::styled`].
+//!
+//! # Example
+//!
+//! ```
+//! use ratatui::{
+//!     prelude::*,
+//!     style::theme::{Role, Theme},
+//!     widgets::*,
+//! };
+//!
+//! Theme::LIGHT.set_active();
+//! let block = Block::bordered()
+//!     .border_style(Role::Border)
+//!     .title(Span::styled("Errors", Role::Error));
+//! assert_eq!(Style::from(Role::Error), Theme::LIGHT.style(Role::Error));
+//! ```
+//!
+//! [`Block::border_style`]: crate::widgets::Block::border_style
+//! [`Span::styled`]: crate::text::Span::styled
+
+use std::cell::Cell;
+
+use strum::{Display, EnumCount, EnumIter, EnumString};
+
+use crate::style::{
+    palette::tailwind::{AMBER, BLUE, EMERALD, RED, SLATE, VIOLET, WHITE},
+    Modifier, Style,
+};
+
+thread_local! {
+    static ACTIVE_THEME: Cell<Theme> = const { Cell::new(Theme::DARK) };
+}
+
+/// A semantic role of a part of the interface, which a [`Theme`] maps to a [`Style`].
+///
+/// Converting a role into a [`Style`] returns the style of the role in the active theme (see
+/// [`Theme::set_active`]).
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Display, EnumString, EnumIter, EnumCount)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Role {
+    /// The main color of the application, e.g. for titles and selected tabs
+    Primary,
+    /// A less prominent alternative to the primary role
+    Secondary,
+    /// Elements that should stand out from the rest, e.g. keyboard shortcuts
+    Accent,
+    /// The background of the application and of its panels
+    Surface,
+    /// Regular text
+    Text,
+    /// Less important text, e.g. hints and placeholders
+    TextMuted,
+    /// The borders of blocks and separators
+    Border,
+    /// The selected item of lists and tables
+    Highlight,
+    /// Messages reporting success
+    Success,
+    /// Messages reporting warnings
+    Warning,
+    /// Messages reporting errors
+    Error,
+}
+
+/// A theme maps each [`Role`] to a [`Style`].
+///
+/// Two themes based on the [Tailwind palette](crate::style::palette::tailwind) are provided,
+/// [`Theme::DARK`] and [`Theme::LIGHT`]. Custom themes can be built from an existing theme or from
+/// [`Theme::new`], which uses the default style for every role, with [`Theme::with_style`].
+///
+/// The active theme is stored per thread and is used when converting a [`Role`] into a [`Style`].
+/// It is [`Theme::DARK`] until another theme is registered with [`Theme::set_active`]. Note that
+/// the role is converted when the style is passed to a widget, so widgets must be created again
+/// after changing the active theme, which is usually the case as they are created on each frame.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{
+///     prelude::*,
+///     style::theme::{Role, Theme},
+/// };
+///
+/// let theme = Theme::DARK.with_style(Role::Error, Style::new().red().bold());
+/// assert_eq!(theme.style(Role::Error), Style::new().red().bold());
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Theme {
+    /// The styles of the roles, indexed by the role
+    styles: [Style; Role::COUNT],
+}
+
+impl Theme {
+    /// A theme for terminals with a dark background.
+    pub const DARK: Self = Self::new()
+        .with_style(Role::Primary, Style::new().fg(BLUE.c400))
+        .with_style(Role::Secondary, Style::new().fg(SLATE.c300))
+        .with_style(Role::Accent, Style::new().fg(VIOLET.c400))
+        .with_style(Role::Surface, Style::new().fg(SLATE.c200).bg(SLATE.c900))
+        .with_style(Role::Text, Style::new().fg(SLATE.c100))
+        .with_style(Role::TextMuted, Style::new().fg(SLATE.c500))
+        .with_style(Role::Border, Style::new().fg(SLATE.c600))
+        .with_style(
+            Role::Highlight,
+            Style::new()
+                .fg(SLATE.c50)
+                .bg(BLUE.c700)
+                .add_modifier(Modifier::BOLD),
+        )
+        .with_style(Role::Success, Style::new().fg(EMERALD.c400))
+        .with_style(Role::Warning, Style::new().fg(AMBER.c400))
+        .with_style(Role::Error, Style::new().fg(RED.c400));
+
+    /// A theme for terminals with a light background.
+    pub const LIGHT: Self = Self::new()
+        .with_style(Role::Primary, Style::new().fg(BLUE.c600))
+        .with_style(Role::Secondary, Style::new().fg(SLATE.c600))
+        .with_style(Role::Accent, Style::new().fg(VIOLET.c600))
+        .with_style(Role::Surface, Style::new().fg(SLATE.c800).bg(SLATE.c50))
+        .with_style(Role::Text, Style::new().fg(SLATE.c900))
+        .with_style(Role::TextMuted, Style::new().fg(SLATE.c500))
+        .with_style(Role::Border, Style::new().fg(SLATE.c300))
+        .with_style(
+            Role::Highlight,
+            Style::new()
+                .fg(WHITE)
+                .bg(BLUE.c600)
+                .add_modifier(Modifier::BOLD),
+        )
+        .with_style(Role::Success, Style::new().fg(EMERALD.c600))
+        .with_style(Role::Warning, Style::new().fg(AMBER.c600))
+        .with_style(Role::Error, Style::new().fg(RED.c600));
+
+    /// Creates a theme that uses the default style for every role.
+    pub const fn new() -> Self {
+        Self {
+            styles: [Style::new(); Role::COUNT],
+        }
+    }
+
+    /// Returns the style of the given role.
+    pub const fn style(&self, role: Role) -> Style {
+        self.styles[role as usize]
+    }
+
+    /// Sets the style of the given role.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_style(mut self, role: Role, style: Style) -> Self {
+        self.styles[role as usize] = style;
+        self
+    }
+
+    /// Returns the active theme of the current thread.
+    pub fn active() -> Self {
+        ACTIVE_THEME.with(Cell::get)
+    }
+
+    /// Registers this theme as the active theme of the current thread, and returns the theme that
+    /// was active before.
+    #[allow(clippy::return_self_not_must_use)]
+    pub fn set_active(self) -> Self {
+        ACTIVE_THEME.with(|theme| theme.replace(self))
+    }
+}
+
+impl Default for Theme {
+    /// Returns [`Theme::DARK`].
+    fn default() -> Self {
+        Self::DARK
+    }
+}
+
+impl From<Role> for Style {
+    /// Returns the style of the role in the active theme.
+    fn from(role: Role) -> Self {
+        Theme::active().style(role)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use strum::IntoEnumIterator;
+
+    use super::*;
+    use crate::{
+        style::{Color, Stylize},
+        text::Span,
+    };
+
+    #[test]
+    fn new() {
+        let theme = Theme::new();
+        for role in Role::iter() {
+            assert_eq!(theme.style(role), Style::new());
+        }
+    }
+
+    #[test]
+    fn with_style() {
+        let theme = Theme::new().with_style(Role::Accent, Style::new().magenta());
+        assert_eq!(theme.style(Role::Accent), Style::new().magenta());
+        assert_eq!(theme.style(Role::Primary), Style::new());
+    }
+
+    #[test]
+    fn default_is_dark() {
+        assert_eq!(Theme::default(), Theme::DARK);
+    }
+
+    #[test]
+    fn active_theme() {
+        assert_eq!(Theme::active(), Theme::DARK);
+        assert_eq!(Style::from(Role::Error), Style::new().fg(RED.c400));
+
+        let theme = Theme::new().with_style(Role::Error, Style::new().red());
+        assert_eq!(theme.set_active(), Theme::DARK);
+        assert_eq!(Theme::active(), theme);
+        assert_eq!(
+            Span::styled("oops", Role::Error),
+            Span::styled("oops", Color::Red)
+        );
+        assert_eq!(Theme::DARK.set_active(), theme);
+    }
+
+    #[test]
+    fn role_from_str() {
+        assert_eq!("TextMuted".parse::<Role>(), Ok(Role::TextMuted));
+        assert_eq!(Role::TextMuted.to_string(), "TextMuted");
+    }
+}
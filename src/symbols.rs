@@ -60,6 +60,14 @@ pub mod half_block {
     pub const FULL: char = '█';
 }
 
+pub mod shade {
+    pub const EMPTY: char = ' ';
+    pub const LIGHT: char = '░';
+    pub const MEDIUM: char = '▒';
+    pub const DARK: char = '▓';
+    pub const FULL: char = '█';
+}
+
 pub mod bar {
     pub const FULL: &str = "█";
     pub const SEVEN_EIGHTHS: &str = "▇";
@@ -248,6 +256,16 @@ pub mod border {
         pub vertical_right: &'static str,
         pub horizontal_top: &'static str,
         pub horizontal_bottom: &'static str,
+        /// The joint where a vertical border meets the top border, e.g. `┬`
+        pub top_joint: &'static str,
+        /// The joint where a vertical border meets the bottom border, e.g. `┴`
+        pub bottom_joint: &'static str,
+        /// The joint where a horizontal border meets the left border, e.g. `├`
+        pub left_joint: &'static str,
+        /// The joint where a horizontal border meets the right border, e.g. `┤`
+        pub right_joint: &'static str,
+        /// The joint where a vertical border crosses a horizontal border, e.g. `┼`
+        pub cross: &'static str,
     }
 
     impl Default for Set {
@@ -272,6 +290,11 @@ pub mod border {
         vertical_right: line::NORMAL.vertical,
         horizontal_top: line::NORMAL.horizontal,
         horizontal_bottom: line::NORMAL.horizontal,
+        top_joint: line::NORMAL.horizontal_down,
+        bottom_joint: line::NORMAL.horizontal_up,
+        left_joint: line::NORMAL.vertical_right,
+        right_joint: line::NORMAL.vertical_left,
+        cross: line::NORMAL.cross,
     };
 
     /// Border Set with a single line width and rounded corners
@@ -290,6 +313,11 @@ pub mod border {
         vertical_right: line::ROUNDED.vertical,
         horizontal_top: line::ROUNDED.horizontal,
         horizontal_bottom: line::ROUNDED.horizontal,
+        top_joint: line::ROUNDED.horizontal_down,
+        bottom_joint: line::ROUNDED.horizontal_up,
+        left_joint: line::ROUNDED.vertical_right,
+        right_joint: line::ROUNDED.vertical_left,
+        cross: line::ROUNDED.cross,
     };
 
     /// Border Set with a double line width
@@ -308,6 +336,11 @@ pub mod border {
         vertical_right: line::DOUBLE.vertical,
         horizontal_top: line::DOUBLE.horizontal,
         horizontal_bottom: line::DOUBLE.horizontal,
+        top_joint: line::DOUBLE.horizontal_down,
+        bottom_joint: line::DOUBLE.horizontal_up,
+        left_joint: line::DOUBLE.vertical_right,
+        right_joint: line::DOUBLE.vertical_left,
+        cross: line::DOUBLE.cross,
     };
 
     /// Border Set with a thick line width
@@ -326,6 +359,11 @@ pub mod border {
         vertical_right: line::THICK.vertical,
         horizontal_top: line::THICK.horizontal,
         horizontal_bottom: line::THICK.horizontal,
+        top_joint: line::THICK.horizontal_down,
+        bottom_joint: line::THICK.horizontal_up,
+        left_joint: line::THICK.vertical_right,
+        right_joint: line::THICK.vertical_left,
+        cross: line::THICK.cross,
     };
 
     pub const QUADRANT_TOP_LEFT: &str = "▘";
@@ -361,6 +399,11 @@ pub mod border {
         vertical_right: QUADRANT_RIGHT_HALF,
         horizontal_top: QUADRANT_TOP_HALF,
         horizontal_bottom: QUADRANT_BOTTOM_HALF,
+        top_joint: QUADRANT_BLOCK,
+        bottom_joint: QUADRANT_BLOCK,
+        left_joint: QUADRANT_BLOCK,
+        right_joint: QUADRANT_BLOCK,
+        cross: QUADRANT_BLOCK,
     };
 
     /// Quadrant used for setting a border inside a block by one half cell "pixel".
@@ -380,6 +423,11 @@ pub mod border {
         vertical_right: QUADRANT_LEFT_HALF,
         horizontal_top: QUADRANT_BOTTOM_HALF,
         horizontal_bottom: QUADRANT_TOP_HALF,
+        top_joint: QUADRANT_BLOCK,
+        bottom_joint: QUADRANT_BLOCK,
+        left_joint: QUADRANT_BLOCK,
+        right_joint: QUADRANT_BLOCK,
+        cross: QUADRANT_BLOCK,
     };
 
     pub const ONE_EIGHTH_TOP_EIGHT: &str = "▔";
@@ -405,6 +453,11 @@ pub mod border {
         vertical_right: ONE_EIGHTH_RIGHT_EIGHT,
         horizontal_top: ONE_EIGHTH_BOTTOM_EIGHT,
         horizontal_bottom: ONE_EIGHTH_TOP_EIGHT,
+        top_joint: ONE_EIGHTH_BOTTOM_EIGHT,
+        bottom_joint: ONE_EIGHTH_TOP_EIGHT,
+        left_joint: ONE_EIGHTH_LEFT_EIGHT,
+        right_joint: ONE_EIGHTH_RIGHT_EIGHT,
+        cross: QUADRANT_BLOCK,
     };
 
     /// Tall border set based on McGugan box technique
@@ -425,6 +478,11 @@ pub mod border {
         vertical_right: ONE_EIGHTH_LEFT_EIGHT,
         horizontal_top: ONE_EIGHTH_TOP_EIGHT,
         horizontal_bottom: ONE_EIGHTH_BOTTOM_EIGHT,
+        top_joint: ONE_EIGHTH_TOP_EIGHT,
+        bottom_joint: ONE_EIGHTH_BOTTOM_EIGHT,
+        left_joint: ONE_EIGHTH_RIGHT_EIGHT,
+        right_joint: ONE_EIGHTH_LEFT_EIGHT,
+        cross: QUADRANT_BLOCK,
     };
 
     /// Wide proportional (visually equal width and height) border with using set of quadrants.
@@ -447,6 +505,11 @@ pub mod border {
         vertical_right: QUADRANT_BLOCK,
         horizontal_top: QUADRANT_BOTTOM_HALF,
         horizontal_bottom: QUADRANT_TOP_HALF,
+        top_joint: QUADRANT_BLOCK,
+        bottom_joint: QUADRANT_BLOCK,
+        left_joint: QUADRANT_BLOCK,
+        right_joint: QUADRANT_BLOCK,
+        cross: QUADRANT_BLOCK,
     };
 
     /// Tall proportional (visually equal width and height) border with using set of quadrants.
@@ -469,6 +532,11 @@ pub mod border {
         vertical_right: QUADRANT_BLOCK,
         horizontal_top: QUADRANT_TOP_HALF,
         horizontal_bottom: QUADRANT_BOTTOM_HALF,
+        top_joint: QUADRANT_BLOCK,
+        bottom_joint: QUADRANT_BLOCK,
+        left_joint: QUADRANT_BLOCK,
+        right_joint: QUADRANT_BLOCK,
+        cross: QUADRANT_BLOCK,
     };
 }
 
@@ -507,6 +575,13 @@ pub enum Marker {
     /// a grid that is double the resolution of the terminal. Because each terminal cell is
     /// generally about twice as tall as it is wide, this allows for a square grid of pixels.
     HalfBlock,
+    /// Use the unicode shade characters (`░`, `▒`, `▓` and `█`) to represent the density of the
+    /// points in each cell.
+    ///
+    /// Like [`Marker::Braille`], this is a 2x4 grid of dots per cell, but instead of drawing each
+    /// dot, a cell is shaded according to how many of its dots are on. This gives smooth,
+    /// anti-aliased looking lines and areas.
+    Shade,
 }
 
 pub mod scrollbar {
@@ -558,6 +633,107 @@ pub mod scrollbar {
     };
 }
 
+pub mod spinner {
+    //! Symbol sets of the [`Spinner`](crate::widgets::Spinner) widget.
+    //!
+    //! Each set is a sequence of symbols that are displayed one after the other in a loop.
+
+    /// A braille dot rotating around a square
+    /// ```text
+    /// ⠋⠙⠹⠸⠼⠴⠦⠧⠇⠏
+    /// ```
+    pub const DOTS: &[&str] = &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+    /// A rotating line
+    /// ```text
+    /// -\|/
+    /// ```
+    pub const LINE: &[&str] = &["-", "\\", "|", "/"];
+
+    /// A braille dot bouncing up and down
+    /// ```text
+    /// ⠁⠂⠄⡀⠄⠂
+    /// ```
+    pub const BOUNCE: &[&str] = &["⠁", "⠂", "⠄", "⡀", "⠄", "⠂"];
+}
+
+pub mod toggle {
+    //! Symbol sets of the [`Checkbox`](crate::widgets::Checkbox),
+    //! [`RadioGroup`](crate::widgets::RadioGroup) and [`Switch`](crate::widgets::Switch) widgets.
+    //!
+    //! Each set has a symbol for the on state (checked, selected or switched on) and one for the
+    //! off state.
+
+    /// Symbol set of a toggle
+    /// ```text
+    /// [x] [ ]
+    ///  ^   ^
+    ///  │   └ off
+    ///  └──── on
+    /// ```
+    #[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+    pub struct Set {
+        pub on: &'static str,
+        pub off: &'static str,
+    }
+
+    /// A checkbox made of brackets
+    /// ```text
+    /// [x] [ ]
+    /// ```
+    pub const CHECKBOX: Set = Set {
+        on: "[x]",
+        off: "[ ]",
+    };
+
+    /// A ballot box
+    /// ```text
+    /// ☑ ☐
+    /// ```
+    #[rustfmt::skip] // rustfmt squeezes the fields of short non-ASCII literals onto one line
+    pub const BALLOT: Set = Set {
+        on: "☑",
+        off: "☐",
+    };
+
+    /// A radio button made of parentheses
+    /// ```text
+    /// (•) ( )
+    /// ```
+    pub const RADIO: Set = Set {
+        on: "(•)",
+        off: "( )",
+    };
+
+    /// A filled or empty circle
+    /// ```text
+    /// ◉ ○
+    /// ```
+    #[rustfmt::skip]
+    pub const CIRCLE: Set = Set {
+        on: "◉",
+        off: "○",
+    };
+
+    /// A sliding switch
+    /// ```text
+    /// (─●) (●─)
+    /// ```
+    pub const SWITCH: Set = Set {
+        on: "(─●)",
+        off: "(●─)",
+    };
+
+    /// A switch labeled with its state
+    /// ```text
+    /// [ ON] [OFF]
+    /// ```
+    pub const LABELED: Set = Set {
+        on: "[ ON]",
+        off: "[OFF]",
+    };
+}
+
 #[cfg(test)]
 mod tests {
     use strum::ParseError;
@@ -32,10 +32,14 @@
 //! [`Buffer`]: crate::buffer::Buffer
 
 mod frame;
+mod hit_areas;
+mod metrics;
 #[allow(clippy::module_inception)]
 mod terminal;
 mod viewport;
 
 pub use frame::{CompletedFrame, Frame};
+pub use hit_areas::HitAreas;
+pub use metrics::{AllocationCounter, FrameMetrics, Metrics};
 pub use terminal::{Options as TerminalOptions, Terminal};
-pub use viewport::Viewport;
+pub use viewport::{Viewport, ViewportId};
@@ -1,4 +1,4 @@
-use crate::prelude::*;
+use crate::{prelude::*, terminal::HitAreas};
 
 /// A consistent view into the terminal state for rendering a single frame.
 ///
@@ -26,6 +26,9 @@ pub struct Frame<'a> {
 
     /// The frame count indicating the sequence number of this frame.
     pub(crate) count: usize,
+
+    /// The areas registered for hit-testing while drawing this frame
+    pub(crate) hit_areas: &'a mut HitAreas,
 }
 
 /// `CompletedFrame` represents the state of the terminal after all changes performed in the last
@@ -41,6 +44,35 @@ pub struct CompletedFrame<'a> {
     pub count: usize,
 }
 
+#[cfg(feature = "export")]
+impl CompletedFrame<'_> {
+    /// Renders the frame as a standalone HTML document.
+    ///
+    /// This is a shortcut for [`Buffer::to_html`] on the buffer of the frame. It can be used to
+    /// take a screenshot of the application after a call to [`Terminal::draw`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use ratatui::{backend::TestBackend, prelude::*, widgets::Paragraph};
+    /// # let mut terminal = Terminal::new(TestBackend::new(10, 1))?;
+    /// let frame =
+    ///     terminal.draw(|frame| frame.render_widget(Paragraph::new("Hello"), frame.size()))?;
+    /// std::fs::write("screenshot.html", frame.to_html())?;
+    /// # std::io::Result::Ok(())
+    /// ```
+    pub fn to_html(&self) -> String {
+        self.buffer.to_html()
+    }
+
+    /// Renders the frame as a standalone SVG document.
+    ///
+    /// This is a shortcut for [`Buffer::to_svg`] on the buffer of the frame.
+    pub fn to_svg(&self) -> String {
+        self.buffer.to_svg()
+    }
+}
+
 impl Frame<'_> {
     /// The size of the current frame
     ///
@@ -170,6 +202,65 @@ impl Frame<'_> {
         self.cursor_position = Some((x, y));
     }
 
+    /// Registers the area a widget was rendered into for mouse hit-testing.
+    ///
+    /// The identifier is chosen by the application and can be retrieved after the frame is drawn
+    /// with [`Terminal::hit_test`] when handling a mouse event. Areas registered later are
+    /// considered to be on top of areas registered earlier. See [`HitAreas`] for more details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{backend::TestBackend, prelude::*, widgets::*};
+    /// # let backend = TestBackend::new(10, 5);
+    /// # let mut terminal = Terminal::new(backend).unwrap();
+    /// terminal.draw(|frame| {
+    ///     let area = Rect::new(0, 0, 6, 1);
+    ///     frame.render_widget(Paragraph::new("Submit"), area);
+    ///     frame.register_hit_area("submit", area);
+    /// })?;
+    ///
+    /// // when a mouse event is received
+    /// assert_eq!(terminal.hit_test((2, 0)), Some("submit"));
+    /// # std::io::Result::Ok(())
+    /// ```
+    pub fn register_hit_area<S: Into<String>>(&mut self, id: S, area: Rect) {
+        self.hit_areas.register(id, area);
+    }
+
+    /// Calls `f` with the changes to the frame restricted to the given area.
+    ///
+    /// The widgets rendered by `f` cannot change the cells outside of `area`, even if they draw
+    /// outside of the area they are given. This makes it safe to compose widgets that do not
+    /// respect their area, such as third-party widgets. Calls to `with_clip` can be nested, in
+    /// which case the changes are restricted to the intersection of the areas. See
+    /// [`Buffer::push_clip`] for more details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{backend::TestBackend, prelude::*, widgets::*};
+    /// # let backend = TestBackend::new(10, 5);
+    /// # let mut terminal = Terminal::new(backend).unwrap();
+    /// # let mut frame = terminal.get_frame();
+    /// let area = Rect::new(0, 0, 5, 1);
+    /// frame.with_clip(area, |frame| {
+    ///     // the text is cut after 5 columns even though it is rendered in the whole frame
+    ///     frame.render_widget(Paragraph::new("Hello World"), frame.size());
+    /// });
+    /// ```
+    ///
+    /// [`Buffer::push_clip`]: crate::buffer::Buffer::push_clip
+    pub fn with_clip<F, R>(&mut self, area: Rect, f: F) -> R
+    where
+        F: FnOnce(&mut Self) -> R,
+    {
+        self.buffer.push_clip(area);
+        let result = f(self);
+        self.buffer.pop_clip();
+        result
+    }
+
     /// Gets the buffer that this `Frame` draws into as a mutable reference.
     pub fn buffer_mut(&mut self) -> &mut Buffer {
         self.buffer
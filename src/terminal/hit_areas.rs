@@ -0,0 +1,144 @@
+use crate::layout::{Position, Rect};
+
+/// A registry of the areas that widgets were rendered into, used for mouse hit-testing.
+///
+/// Areas are registered while drawing with [`Frame::register_hit_area`] and associated with an
+/// identifier chosen by the application. After the frame is drawn, the registry can be queried
+/// through [`Terminal::hit_areas`] or [`Terminal::hit_test`] to find out which widget is under a
+/// mouse event.
+///
+/// Areas registered later are considered to be on top of areas registered earlier, which matches
+/// the order in which widgets are drawn (e.g. a popup rendered last hides the widgets behind it).
+///
+/// The registry is cleared at the start of each [`Terminal::draw`] call, so it always reflects the
+/// last drawn frame. Registering areas is opt-in: if no area is registered, hit-testing always
+/// returns `None`.
+///
+/// [`Frame::register_hit_area`]: crate::terminal::Frame::register_hit_area
+/// [`Terminal::hit_areas`]: crate::terminal::Terminal::hit_areas
+/// [`Terminal::hit_test`]: crate::terminal::Terminal::hit_test
+/// [`Terminal::draw`]: crate::terminal::Terminal::draw
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{layout::Position, prelude::*, terminal::HitAreas};
+///
+/// let mut hit_areas = HitAreas::new();
+/// hit_areas.register("list", Rect::new(0, 0, 10, 10));
+/// hit_areas.register("popup", Rect::new(2, 2, 4, 4));
+///
+/// assert_eq!(hit_areas.hit(Position::new(1, 1)), Some("list"));
+/// assert_eq!(hit_areas.hit(Position::new(3, 3)), Some("popup"));
+/// assert_eq!(hit_areas.hit(Position::new(20, 20)), None);
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct HitAreas {
+    areas: Vec<(String, Rect)>,
+}
+
+impl HitAreas {
+    /// Creates an empty registry
+    pub const fn new() -> Self {
+        Self { areas: Vec::new() }
+    }
+
+    /// Registers an area with the given identifier
+    ///
+    /// The same identifier can be registered several times (e.g. for each row of a list). Empty
+    /// areas are ignored as they can never be hit.
+    pub fn register<S: Into<String>>(&mut self, id: S, area: Rect) {
+        if !area.is_empty() {
+            self.areas.push((id.into(), area));
+        }
+    }
+
+    /// Removes all the registered areas
+    pub fn clear(&mut self) {
+        self.areas.clear();
+    }
+
+    /// Returns the identifier of the top-most area containing the given position
+    pub fn hit<P: Into<Position>>(&self, position: P) -> Option<&str> {
+        self.hits(position).next()
+    }
+
+    /// Returns the identifiers of all the areas containing the given position, top-most first
+    pub fn hits<P: Into<Position>>(&self, position: P) -> impl Iterator<Item = &str> {
+        let position = position.into();
+        self.areas
+            .iter()
+            .rev()
+            .filter(move |(_, area)| area.contains(position))
+            .map(|(id, _)| id.as_str())
+    }
+
+    /// Returns the last area registered with the given identifier
+    pub fn area(&self, id: &str) -> Option<Rect> {
+        self.areas
+            .iter()
+            .rev()
+            .find(|(area_id, _)| area_id == id)
+            .map(|(_, area)| *area)
+    }
+
+    /// Returns an iterator over the registered identifiers and areas, in registration order
+    pub fn iter(&self) -> impl Iterator<Item = (&str, Rect)> {
+        self.areas.iter().map(|(id, area)| (id.as_str(), *area))
+    }
+
+    /// Returns the number of registered areas
+    pub fn len(&self) -> usize {
+        self.areas.len()
+    }
+
+    /// Returns true if no area is registered
+    pub fn is_empty(&self) -> bool {
+        self.areas.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn register_ignores_empty_areas() {
+        let mut hit_areas = HitAreas::new();
+        hit_areas.register("empty", Rect::new(1, 1, 0, 5));
+        assert!(hit_areas.is_empty());
+    }
+
+    #[test]
+    fn hit_returns_top_most_area() {
+        let mut hit_areas = HitAreas::new();
+        hit_areas.register("background", Rect::new(0, 0, 10, 10));
+        hit_areas.register("button", Rect::new(2, 2, 3, 1));
+        assert_eq!(hit_areas.hit((3, 2)), Some("button"));
+        assert_eq!(hit_areas.hit((5, 2)), Some("background"));
+        assert_eq!(hit_areas.hit((10, 2)), None);
+        assert_eq!(
+            hit_areas.hits((3, 2)).collect::<Vec<_>>(),
+            ["button", "background"]
+        );
+    }
+
+    #[test]
+    fn area() {
+        let mut hit_areas = HitAreas::new();
+        hit_areas.register("a", Rect::new(0, 0, 1, 1));
+        hit_areas.register("a", Rect::new(1, 1, 1, 1));
+        assert_eq!(hit_areas.area("a"), Some(Rect::new(1, 1, 1, 1)));
+        assert_eq!(hit_areas.area("b"), None);
+        assert_eq!(hit_areas.len(), 2);
+    }
+
+    #[test]
+    fn clear() {
+        let mut hit_areas = HitAreas::new();
+        hit_areas.register("a", Rect::new(0, 0, 1, 1));
+        hit_areas.clear();
+        assert_eq!(hit_areas.hit((0, 0)), None);
+        assert_eq!(hit_areas.iter().count(), 0);
+    }
+}
@@ -0,0 +1,163 @@
+use std::{
+    hash::{Hash, Hasher},
+    ptr,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
+
+/// Measurements of a single call to [`Terminal::draw`]
+///
+/// [`Terminal::draw`]: crate::Terminal::draw
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct FrameMetrics {
+    /// The time spent in the rendering closure, i.e. rendering the widgets to the buffer
+    pub render_time: Duration,
+    /// The time spent writing the frame to the backend, including computing the changes since
+    /// the previous frame, moving the cursor and flushing the backend
+    pub flush_time: Duration,
+    /// The number of cells that changed since the previous frame and were written to the backend
+    pub cells_updated: usize,
+    /// The number of allocations made during the draw call
+    ///
+    /// This is `None` unless an allocation counter was set with
+    /// [`TerminalOptions::allocation_counter`].
+    ///
+    /// [`TerminalOptions::allocation_counter`]: crate::TerminalOptions::allocation_counter
+    pub allocations: Option<usize>,
+}
+
+impl FrameMetrics {
+    /// The total time of the draw call, i.e. the render time plus the flush time
+    pub const fn total_time(&self) -> Duration {
+        self.render_time.saturating_add(self.flush_time)
+    }
+}
+
+/// Frame timing and update statistics recorded by a [`Terminal`]
+///
+/// Metrics are only recorded when enabled with [`TerminalOptions::metrics`], and are returned by
+/// [`Terminal::metrics`]. They can be reset with [`Terminal::reset_metrics`], e.g. to measure a
+/// single screen of an application.
+///
+/// [`Terminal`]: crate::Terminal
+/// [`Terminal::metrics`]: crate::Terminal::metrics
+/// [`Terminal::reset_metrics`]: crate::Terminal::reset_metrics
+/// [`TerminalOptions::metrics`]: crate::TerminalOptions::metrics
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Metrics {
+    /// The number of frames that were recorded
+    pub frames: usize,
+    /// The metrics of the last frame
+    pub last_frame: FrameMetrics,
+    /// The metrics of the frame with the longest total time
+    pub slowest_frame: FrameMetrics,
+    /// The sum of the metrics of all the frames
+    pub total: FrameMetrics,
+}
+
+impl Metrics {
+    /// The average metrics of the recorded frames
+    ///
+    /// Returns the default metrics if no frame was recorded.
+    pub fn average(&self) -> FrameMetrics {
+        if self.frames == 0 {
+            return FrameMetrics::default();
+        }
+        let frames = u32::try_from(self.frames).unwrap_or(u32::MAX);
+        FrameMetrics {
+            render_time: self.total.render_time / frames,
+            flush_time: self.total.flush_time / frames,
+            cells_updated: self.total.cells_updated / self.frames,
+            allocations: self.total.allocations.map(|count| count / self.frames),
+        }
+    }
+
+    /// Adds the metrics of a frame
+    pub(crate) fn record(&mut self, frame: FrameMetrics) {
+        if self.frames == 0 || frame.total_time() > self.slowest_frame.total_time() {
+            self.slowest_frame = frame;
+        }
+        self.frames = self.frames.saturating_add(1);
+        self.last_frame = frame;
+        self.total.render_time = self.total.render_time.saturating_add(frame.render_time);
+        self.total.flush_time = self.total.flush_time.saturating_add(frame.flush_time);
+        self.total.cells_updated = self.total.cells_updated.saturating_add(frame.cells_updated);
+        self.total.allocations = frame
+            .allocations
+            .map(|count| count.saturating_add(self.total.allocations.unwrap_or_default()));
+    }
+}
+
+/// A counter of the allocations made so far, incremented by the global allocator of the
+/// application
+///
+/// See [`TerminalOptions::allocation_counter`] for more details.
+///
+/// [`TerminalOptions::allocation_counter`]: crate::TerminalOptions::allocation_counter
+#[derive(Debug, Clone, Copy)]
+pub struct AllocationCounter(pub &'static AtomicUsize);
+
+impl AllocationCounter {
+    /// The current value of the counter
+    pub(crate) fn count(self) -> usize {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+// Counters are compared by address, as two counters are the same only if they are the same static
+impl PartialEq for AllocationCounter {
+    fn eq(&self, other: &Self) -> bool {
+        ptr::eq(self.0, other.0)
+    }
+}
+
+impl Eq for AllocationCounter {}
+
+impl Hash for AllocationCounter {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        ptr::hash(self.0, state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn frame(render_ms: u64, flush_ms: u64, cells_updated: usize) -> FrameMetrics {
+        FrameMetrics {
+            render_time: Duration::from_millis(render_ms),
+            flush_time: Duration::from_millis(flush_ms),
+            cells_updated,
+            allocations: Some(cells_updated * 2),
+        }
+    }
+
+    #[test]
+    fn record() {
+        let mut metrics = Metrics::default();
+        metrics.record(frame(4, 2, 10));
+        metrics.record(frame(8, 1, 30));
+        metrics.record(frame(2, 2, 0));
+
+        assert_eq!(metrics.frames, 3);
+        assert_eq!(metrics.last_frame, frame(2, 2, 0));
+        assert_eq!(metrics.slowest_frame, frame(8, 1, 30));
+        assert_eq!(metrics.total, frame(14, 5, 40));
+        assert_eq!(
+            metrics.average(),
+            FrameMetrics {
+                render_time: Duration::from_nanos(4_666_666),
+                flush_time: Duration::from_nanos(1_666_666),
+                cells_updated: 13,
+                allocations: Some(26),
+            }
+        );
+    }
+
+    #[test]
+    fn average_without_frames() {
+        assert_eq!(Metrics::default().average(), FrameMetrics::default());
+    }
+}
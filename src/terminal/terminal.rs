@@ -1,6 +1,18 @@
-use std::io;
+use std::{
+    io, mem,
+    sync::atomic::AtomicUsize,
+    time::{Duration, Instant},
+};
 
-use crate::{backend::ClearType, prelude::*};
+use crate::{
+    backend::ClearType,
+    buffer::{Cell, Graphic},
+    layout::Position,
+    prelude::*,
+    terminal::{AllocationCounter, FrameMetrics, HitAreas, Metrics, ViewportId},
+    text::measure,
+    widgets::{Paragraph, Wrap},
+};
 
 /// An interface to interact and draw [`Frame`]s on the user's terminal.
 ///
@@ -73,6 +85,34 @@ where
     last_known_cursor_pos: (u16, u16),
     /// Number of frames rendered up until current time.
     frame_count: usize,
+    /// Areas registered for hit-testing during the last draw call
+    hit_areas: HitAreas,
+    /// Whether frames are wrapped in synchronized updates
+    synchronized_output: bool,
+    /// The height up to which an inline viewport grows to fit its content
+    inline_max_height: Option<u16>,
+    /// The viewports added with [`Terminal::add_viewport`]
+    viewports: Vec<AdditionalViewport>,
+    /// The metrics of the draw calls, if enabled
+    metrics: Option<Metrics>,
+    /// The number of allocations made so far, used to count the allocations of each frame
+    allocation_counter: Option<AllocationCounter>,
+    /// Whether the next throttled draw call has to draw a frame
+    redraw_requested: bool,
+    /// When the last frame started rendering, used to limit the frame rate of throttled draw calls
+    last_draw: Option<Instant>,
+}
+
+/// A viewport added with [`Terminal::add_viewport`], which is drawn independently of the main
+/// viewport
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+struct AdditionalViewport {
+    /// The buffer the next draw call renders to
+    current: Buffer,
+    /// The buffer rendered by the last draw call, which the current buffer is compared to
+    previous: Buffer,
+    /// Areas registered for hit-testing during the last draw call of this viewport
+    hit_areas: HitAreas,
 }
 
 /// Options to pass to [`Terminal::with_options`]
@@ -80,6 +120,99 @@ where
 pub struct Options {
     /// Viewport used to draw to the terminal
     pub viewport: Viewport,
+    /// Whether each frame is written to the terminal as a synchronized update
+    ///
+    /// See [`Options::synchronized_output`] for more details.
+    pub synchronized_output: bool,
+    /// The height up to which an inline viewport grows to fit its content
+    ///
+    /// See [`Options::inline_max_height`] for more details.
+    pub inline_max_height: Option<u16>,
+    /// Whether the terminal records the metrics of each frame
+    ///
+    /// See [`Options::metrics`] for more details.
+    pub metrics: bool,
+    /// A counter of the allocations made so far
+    ///
+    /// See [`Options::allocation_counter`] for more details.
+    pub allocation_counter: Option<AllocationCounter>,
+}
+
+impl Options {
+    /// Sets the viewport used to draw to the terminal
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn viewport(mut self, viewport: Viewport) -> Self {
+        self.viewport = viewport;
+        self
+    }
+
+    /// Sets whether each frame is written to the terminal as a synchronized update
+    ///
+    /// When enabled, [`Terminal::draw`] wraps the changes of each frame in the begin and end
+    /// synchronized update sequences (DEC mode 2026) using
+    /// [`Backend::begin_synchronized_update`] and [`Backend::end_synchronized_update`]. Terminals
+    /// that support it display the whole frame at once, which avoids tearing when redrawing
+    /// quickly. Terminals that don't support it ignore the sequences. This is disabled by default.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn synchronized_output(mut self, synchronized_output: bool) -> Self {
+        self.synchronized_output = synchronized_output;
+        self
+    }
+
+    /// Sets the height up to which an inline viewport grows to fit its content
+    ///
+    /// By default, an inline viewport keeps the height given to [`Viewport::Inline`], and the
+    /// content that does not fit is truncated. When this is set, the area of the frame passed to
+    /// [`Terminal::draw`] is `max_height` rows high instead, and after each draw call the viewport
+    /// grows to the last row that was drawn to, scrolling the terminal if needed, like the
+    /// interfaces of tools such as `fzf`. The viewport never shrinks, and it is never higher than
+    /// the terminal. This has no effect on fullscreen and fixed viewports.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn inline_max_height(mut self, max_height: u16) -> Self {
+        self.inline_max_height = Some(max_height);
+        self
+    }
+
+    /// Sets whether the terminal records the metrics of each frame
+    ///
+    /// When enabled, each call to [`Terminal::draw`] measures the time spent rendering the widgets
+    /// and the time spent writing the frame to the backend, and counts the cells that were
+    /// updated. The metrics are returned by [`Terminal::metrics`]. This is disabled by default.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn metrics(mut self, metrics: bool) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    /// Sets a counter of the allocations made so far
+    ///
+    /// When metrics are enabled, the counter is read before and after each draw call to count the
+    /// allocations of the frame. Ratatui does not use unsafe code, so it cannot install a global
+    /// allocator itself. Instead, the application can use a counting allocator, e.g. one that
+    /// wraps [`std::alloc::System`] and increments a static counter on each allocation, and pass
+    /// the counter here.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::sync::atomic::AtomicUsize;
+    ///
+    /// use ratatui::{backend::TestBackend, prelude::*};
+    ///
+    /// // incremented by the global allocator of the application
+    /// static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+    ///
+    /// let options = TerminalOptions::default()
+    ///     .metrics(true)
+    ///     .allocation_counter(&ALLOCATIONS);
+    /// let terminal = Terminal::with_options(TestBackend::new(10, 10), options)?;
+    /// # std::io::Result::Ok(())
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn allocation_counter(mut self, counter: &'static AtomicUsize) -> Self {
+        self.allocation_counter = Some(AllocationCounter(counter));
+        self
+    }
 }
 
 impl<B> Drop for Terminal<B>
@@ -116,6 +249,7 @@ where
             backend,
             TerminalOptions {
                 viewport: Viewport::Fullscreen,
+                ..Default::default()
             },
         )
     }
@@ -129,7 +263,10 @@ where
     /// # use ratatui::{prelude::*, backend::TestBackend};
     /// let backend = CrosstermBackend::new(stdout());
     /// let viewport = Viewport::Fixed(Rect::new(0, 0, 10, 10));
-    /// let terminal = Terminal::with_options(backend, TerminalOptions { viewport })?;
+    /// let options = TerminalOptions::default()
+    ///     .viewport(viewport)
+    ///     .synchronized_output(true);
+    /// let terminal = Terminal::with_options(backend, options)?;
     /// # std::io::Result::Ok(())
     /// ```
     pub fn with_options(mut backend: B, options: TerminalOptions) -> io::Result<Self> {
@@ -152,6 +289,14 @@ where
             last_known_size: size,
             last_known_cursor_pos: cursor_pos,
             frame_count: 0,
+            hit_areas: HitAreas::new(),
+            synchronized_output: options.synchronized_output,
+            inline_max_height: options.inline_max_height,
+            viewports: Vec::new(),
+            metrics: options.metrics.then(Metrics::default),
+            allocation_counter: options.allocation_counter,
+            redraw_requested: true,
+            last_draw: None,
         })
     }
 
@@ -161,8 +306,9 @@ where
         Frame {
             cursor_position: None,
             viewport_area: self.viewport_area,
-            buffer: self.current_buffer_mut(),
+            buffer: &mut self.buffers[self.current],
             count,
+            hit_areas: &mut self.hit_areas,
         }
     }
 
@@ -184,13 +330,28 @@ where
     /// Obtains a difference between the previous and the current buffer and passes it to the
     /// current backend for drawing.
     pub fn flush(&mut self) -> io::Result<()> {
+        self.flush_updates().map(|_| ())
+    }
+
+    /// Flushes the changes of the current buffer and returns the number of updated cells
+    fn flush_updates(&mut self) -> io::Result<usize> {
         let previous_buffer = &self.buffers[1 - self.current];
         let current_buffer = &self.buffers[self.current];
+        remove_graphics(
+            &mut self.backend,
+            missing_graphics(previous_buffer, current_buffer),
+        )?;
         let updates = previous_buffer.diff(current_buffer);
         if let Some((col, row, _)) = updates.last() {
             self.last_known_cursor_pos = (*col, *row);
         }
-        self.backend.draw(updates.into_iter())
+        let count = updates.len();
+        self.backend.draw(updates.into_iter())?;
+        let added = missing_graphics(current_buffer, previous_buffer);
+        if let Some(position) = draw_graphics(&mut self.backend, added)? {
+            self.last_known_cursor_pos = position;
+        }
+        Ok(count)
     }
 
     /// Updates the Terminal so that internal buffers match the requested size.
@@ -201,6 +362,12 @@ where
         let next_area = match self.viewport {
             Viewport::Fullscreen => size,
             Viewport::Inline(height) => {
+                // keep the height the viewport grew to
+                let height = if self.inline_max_height.is_some() {
+                    height.max(self.viewport_area.height)
+                } else {
+                    height
+                };
                 let offset_in_previous_viewport = self
                     .last_known_cursor_pos
                     .1
@@ -209,6 +376,8 @@ where
             }
             Viewport::Fixed(area) => area,
         };
+        // resizing the buffers forgets their graphics
+        remove_graphics(&mut self.backend, self.buffers[1 - self.current].graphics())?;
         self.set_viewport_area(next_area);
         self.clear()?;
 
@@ -261,33 +430,63 @@ where
     where
         F: FnOnce(&mut Frame),
     {
+        let allocations = self.count_allocations();
+
         // Autoresize - otherwise we get glitches if shrinking or potential desync between widgets
         // and the terminal (if growing), which may OOB.
         self.autoresize()?;
 
+        self.hit_areas.clear();
+        // inline viewports that grow are drawn in a larger area to find out how high they need to
+        // be
+        let draw_area = self.draw_area();
+        self.buffers[self.current].resize(draw_area);
         let mut frame = self.get_frame();
+        frame.viewport_area = draw_area;
+        let start = Instant::now();
         f(&mut frame);
         // We can't change the cursor position right away because we have to flush the frame to
         // stdout first. But we also can't keep the frame around, since it holds a &mut to
         // Buffer. Thus, we're taking the important data out of the Frame and dropping it.
         let cursor_position = frame.cursor_position;
+        let rendered = Instant::now();
+
+        if draw_area != self.viewport_area {
+            self.grow_inline_viewport()?;
+        }
 
-        // Draw to stdout
-        self.flush()?;
+        let cells_updated = self.synchronized(|terminal| {
+            // Draw to stdout
+            let cells_updated = terminal.flush_updates()?;
 
-        match cursor_position {
-            None => self.hide_cursor()?,
-            Some((x, y)) => {
-                self.show_cursor()?;
-                self.set_cursor(x, y)?;
+            match cursor_position {
+                None => terminal.hide_cursor()?,
+                Some((x, y)) => {
+                    terminal.show_cursor()?;
+                    terminal.set_cursor(x, y)?;
+                }
             }
-        }
 
-        self.swap_buffers();
+            terminal.swap_buffers();
+            Ok(cells_updated)
+        })?;
 
         // Flush
         self.backend.flush()?;
 
+        if let Some(metrics) = &mut self.metrics {
+            let end = Instant::now();
+            metrics.record(FrameMetrics {
+                render_time: rendered - start,
+                flush_time: end - rendered,
+                cells_updated,
+                allocations: self
+                    .allocation_counter
+                    .zip(allocations)
+                    .map(|(counter, start)| counter.count().wrapping_sub(start)),
+            });
+        }
+
         let completed_frame = CompletedFrame {
             buffer: &self.buffers[1 - self.current],
             area: self.last_known_size,
@@ -296,10 +495,301 @@ where
 
         // increment frame count before returning from draw
         self.frame_count = self.frame_count.wrapping_add(1);
+        self.redraw_requested = false;
+        self.last_draw = Some(start);
 
         Ok(completed_frame)
     }
 
+    /// Draws a frame like [`Terminal::draw`], but only if a redraw was requested and the frame
+    /// rate allows it.
+    ///
+    /// A redraw is requested with [`Terminal::request_redraw`], e.g. when the application handles
+    /// an event or its state changes, and when the terminal is created, resized or cleared. The
+    /// frame is drawn only if at least `1 / fps` seconds elapsed since the last frame was drawn;
+    /// otherwise the request is kept for a later call. An `fps` of 0 does not limit the frame rate.
+    ///
+    /// Returns the completed frame, or `None` if no frame was drawn. This makes it possible to
+    /// call this method on every iteration of the event loop without repainting an unchanged
+    /// interface. Use [`Terminal::time_until_next_frame`] to find out how long to wait for events
+    /// before the next frame can be drawn.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use std::{io::stdout, time::Duration};
+    /// # use crossterm::event;
+    /// # use ratatui::{prelude::*, widgets::Paragraph};
+    /// let backend = CrosstermBackend::new(stdout());
+    /// let mut terminal = Terminal::new(backend)?;
+    /// let mut count = 0;
+    /// loop {
+    ///     terminal.draw_throttled(60, |frame| {
+    ///         frame.render_widget(Paragraph::new(format!("{count} events")), frame.size());
+    ///     })?;
+    ///     if event::poll(Duration::from_millis(250))? {
+    ///         event::read()?;
+    ///         count += 1;
+    ///         terminal.request_redraw();
+    ///     }
+    /// }
+    /// # std::io::Result::Ok(())
+    /// ```
+    pub fn draw_throttled<F>(&mut self, fps: u32, f: F) -> io::Result<Option<CompletedFrame>>
+    where
+        F: FnOnce(&mut Frame),
+    {
+        // resizing clears the terminal, which requests a redraw
+        self.autoresize()?;
+        if !self.redraw_requested || !self.time_until_next_frame(fps).is_zero() {
+            return Ok(None);
+        }
+        self.draw(f).map(Some)
+    }
+
+    /// Requests that the next call to [`Terminal::draw_throttled`] draws a frame
+    pub fn request_redraw(&mut self) {
+        self.redraw_requested = true;
+    }
+
+    /// Returns true if a redraw was requested since the last frame was drawn
+    ///
+    /// See [`Terminal::draw_throttled`] for more details.
+    pub const fn needs_redraw(&self) -> bool {
+        self.redraw_requested
+    }
+
+    /// Returns how long a throttled draw call at the given frame rate has to wait before drawing
+    /// the next frame
+    ///
+    /// This is zero if the frame can be drawn now. Event loops can use it as a timeout when
+    /// polling for events, so that they wake up in time for the next frame. See
+    /// [`Terminal::draw_throttled`] for more details.
+    pub fn time_until_next_frame(&self, fps: u32) -> Duration {
+        let interval = match fps {
+            0 => Duration::ZERO,
+            fps => Duration::from_secs(1) / fps,
+        };
+        self.last_draw.map_or(Duration::ZERO, |last_draw| {
+            interval.saturating_sub(last_draw.elapsed())
+        })
+    }
+
+    /// The number of allocations made so far, if metrics and an allocation counter are enabled
+    fn count_allocations(&self) -> Option<usize> {
+        self.allocation_counter
+            .filter(|_| self.metrics.is_some())
+            .map(AllocationCounter::count)
+    }
+
+    /// Returns the metrics recorded by the draw calls, or `None` if metrics are disabled
+    ///
+    /// Metrics are enabled with [`TerminalOptions::metrics`]. See [`Metrics`] for the recorded
+    /// values.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{backend::TestBackend, prelude::*, widgets::Paragraph};
+    ///
+    /// let options = TerminalOptions::default().metrics(true);
+    /// let mut terminal = Terminal::with_options(TestBackend::new(10, 1), options)?;
+    /// terminal.draw(|frame| frame.render_widget(Paragraph::new("Hello"), frame.size()))?;
+    ///
+    /// let metrics = terminal.metrics().expect("metrics are enabled");
+    /// assert_eq!(metrics.frames, 1);
+    /// assert_eq!(metrics.last_frame.cells_updated, 5);
+    /// # std::io::Result::Ok(())
+    /// ```
+    pub const fn metrics(&self) -> Option<&Metrics> {
+        self.metrics.as_ref()
+    }
+
+    /// Resets the recorded metrics, if metrics are enabled
+    pub fn reset_metrics(&mut self) {
+        if let Some(metrics) = &mut self.metrics {
+            *metrics = Metrics::default();
+        }
+    }
+
+    /// The area of the frame passed to the draw call
+    fn draw_area(&self) -> Rect {
+        match (&self.viewport, self.inline_max_height) {
+            (Viewport::Inline(_), Some(max_height)) => Rect {
+                height: max_height
+                    .min(self.last_known_size.height)
+                    .max(self.viewport_area.height),
+                ..self.viewport_area
+            },
+            _ => self.viewport_area,
+        }
+    }
+
+    /// Grows the inline viewport to fit the content drawn to the current buffer, and shrinks the
+    /// current buffer back to the area of the viewport
+    fn grow_inline_viewport(&mut self) -> io::Result<()> {
+        let content_height = self.buffers[self.current]
+            .rows()
+            .rposition(|row| row.iter().any(|cell| *cell != Cell::default()))
+            .map_or(0, |row| row as u16 + 1);
+        if content_height > self.viewport_area.height {
+            // make room below the viewport, which scrolls the terminal if it is at the bottom
+            let last_row = self.viewport_area.bottom().saturating_sub(1);
+            self.backend
+                .set_cursor(self.viewport_area.left(), last_row)?;
+            self.backend
+                .append_lines(content_height - self.viewport_area.height)?;
+            let y = self
+                .viewport_area
+                .y
+                .min(self.last_known_size.bottom().saturating_sub(content_height));
+            self.viewport_area = Rect {
+                y,
+                height: content_height,
+                ..self.viewport_area
+            };
+            // the viewport may have moved, so it is redrawn entirely
+            self.buffers[1 - self.current].resize(self.viewport_area);
+            self.clear()?;
+        }
+        let buffer = &mut self.buffers[self.current];
+        buffer.content.truncate(self.viewport_area.area() as usize);
+        buffer.area = self.viewport_area;
+        Ok(())
+    }
+
+    /// Returns the areas registered for hit-testing during the last draw call.
+    ///
+    /// See [`Frame::register_hit_area`] and [`HitAreas`] for more details.
+    pub const fn hit_areas(&self) -> &HitAreas {
+        &self.hit_areas
+    }
+
+    /// Returns the identifier of the top-most area registered during the last draw call that
+    /// contains the given position.
+    ///
+    /// This is a shortcut for `terminal.hit_areas().hit(position)`, which is typically called with
+    /// the coordinates of a mouse event. See [`Frame::register_hit_area`] for an example.
+    pub fn hit_test<P: Into<Position>>(&self, position: P) -> Option<&str> {
+        let position = position.into();
+        // the additional viewports are drawn over the main viewport
+        self.viewports
+            .iter()
+            .rev()
+            .find_map(|viewport| viewport.hit_areas.hit(position))
+            .or_else(|| self.hit_areas.hit(position))
+    }
+
+    /// Adds a viewport covering the given area of the terminal, which is drawn independently of
+    /// the main viewport with [`Terminal::draw_viewport`].
+    ///
+    /// Each viewport has its own buffers, so drawing one of them only writes the changes of that
+    /// viewport to the terminal. This makes it possible to split the terminal into parts that are
+    /// updated at different rates, such as a fixed status bar at the bottom of the terminal and a
+    /// main viewport showing a log. The viewports should not overlap the main viewport nor each
+    /// other. Like [`Viewport::Fixed`], they are not resized automatically.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{backend::TestBackend, prelude::*, widgets::*};
+    /// # let backend = TestBackend::new(20, 5);
+    /// let options = TerminalOptions::default().viewport(Viewport::Fixed(Rect::new(0, 0, 20, 4)));
+    /// let mut terminal = Terminal::with_options(backend, options)?;
+    /// let status_bar = terminal.add_viewport(Rect::new(0, 4, 20, 1));
+    ///
+    /// terminal.draw(|frame| frame.render_widget(Paragraph::new("main"), frame.size()))?;
+    /// terminal.draw_viewport(status_bar, |frame| {
+    ///     frame.render_widget(Paragraph::new("ready"), frame.size());
+    /// })?;
+    /// # std::io::Result::Ok(())
+    /// ```
+    pub fn add_viewport(&mut self, area: Rect) -> ViewportId {
+        self.viewports.push(AdditionalViewport {
+            current: Buffer::empty(area),
+            previous: Buffer::empty(area),
+            hit_areas: HitAreas::new(),
+        });
+        ViewportId(self.viewports.len() - 1)
+    }
+
+    /// Returns the area of a viewport added with [`Terminal::add_viewport`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the viewport was not added to this terminal.
+    pub fn viewport_area(&self, id: ViewportId) -> Rect {
+        self.viewports[id.0].current.area
+    }
+
+    /// Draws a viewport added with [`Terminal::add_viewport`].
+    ///
+    /// This works like [`Terminal::draw`], except that only the given viewport is drawn: the
+    /// frame covers the area of the viewport, and only the changes of that viewport are written
+    /// to the terminal. The cursor is shown at the position set with [`Frame::set_cursor`], if
+    /// any. Otherwise, the cursor is moved back to where it was before, and stays hidden or shown
+    /// as set by the last draw call of the main viewport.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the viewport was not added to this terminal.
+    pub fn draw_viewport<F>(&mut self, id: ViewportId, f: F) -> io::Result<()>
+    where
+        F: FnOnce(&mut Frame),
+    {
+        let viewport = &mut self.viewports[id.0];
+        viewport.hit_areas.clear();
+        let mut frame = Frame {
+            cursor_position: None,
+            viewport_area: viewport.current.area,
+            buffer: &mut viewport.current,
+            count: self.frame_count,
+            hit_areas: &mut viewport.hit_areas,
+        };
+        f(&mut frame);
+        let cursor_position = frame.cursor_position;
+
+        self.synchronized(|terminal| {
+            let viewport = &mut terminal.viewports[id.0];
+            let removed = missing_graphics(&viewport.previous, &viewport.current);
+            remove_graphics(&mut terminal.backend, removed)?;
+            let updates = viewport.previous.diff(&viewport.current);
+            terminal.backend.draw(updates.into_iter())?;
+            let added = missing_graphics(&viewport.current, &viewport.previous);
+            draw_graphics(&mut terminal.backend, added)?;
+            mem::swap(&mut viewport.current, &mut viewport.previous);
+            viewport.current.reset();
+
+            if let Some((x, y)) = cursor_position {
+                terminal.show_cursor()?;
+                terminal.set_cursor(x, y)
+            } else {
+                let (x, y) = terminal.last_known_cursor_pos;
+                terminal.set_cursor(x, y)
+            }
+        })?;
+        self.backend.flush()
+    }
+
+    /// Runs `f`, wrapped in a synchronized update if synchronized output is enabled
+    ///
+    /// The synchronized update is ended even if `f` fails, as the terminal would otherwise stop
+    /// refreshing the screen.
+    fn synchronized<T, F>(&mut self, f: F) -> io::Result<T>
+    where
+        F: FnOnce(&mut Self) -> io::Result<T>,
+    {
+        if !self.synchronized_output {
+            return f(self);
+        }
+        self.backend.begin_synchronized_update()?;
+        let result = f(self);
+        let end = self.backend.end_synchronized_update();
+        let value = result?;
+        end?;
+        Ok(value)
+    }
+
     /// Hides the cursor.
     pub fn hide_cursor(&mut self) -> io::Result<()> {
         self.backend.hide_cursor()?;
@@ -346,10 +836,65 @@ where
             }
         }
         // Reset the back buffer to make sure the next update will redraw everything.
+        remove_graphics(&mut self.backend, self.buffers[1 - self.current].graphics())?;
         self.buffers[1 - self.current].reset();
+        for viewport in &mut self.viewports {
+            remove_graphics(&mut self.backend, viewport.previous.graphics())?;
+            viewport.previous.reset();
+        }
+        self.redraw_requested = true;
         Ok(())
     }
 
+    /// Suspends the terminal while running `f`, e.g. to spawn an editor or a shell, and returns
+    /// the result of `f`.
+    ///
+    /// The terminal is restored to its normal state with [`Backend::suspend`] before calling `f`:
+    /// raw mode is disabled, the cursor is shown, and the alternate screen is left when the
+    /// viewport is fullscreen, as fullscreen applications are usually drawn on the alternate
+    /// screen. Afterwards, the state of the terminal is restored with [`Backend::resume`] and the
+    /// terminal is cleared, so the next call to [`Terminal::draw`] redraws everything. Inline
+    /// viewports are moved below the cursor, after the output of the program that was run.
+    ///
+    /// This can also be used to handle `Ctrl-Z` by stopping the process in `f`.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use std::{io::stdout, process::Command};
+    /// # use ratatui::prelude::*;
+    /// # let backend = CrosstermBackend::new(stdout());
+    /// # let mut terminal = Terminal::new(backend)?;
+    /// let status = terminal.suspend(|| Command::new("vim").arg("notes.txt").status())??;
+    /// # std::io::Result::Ok(())
+    /// ```
+    pub fn suspend<F, R>(&mut self, f: F) -> io::Result<R>
+    where
+        F: FnOnce() -> R,
+    {
+        let alternate_screen = matches!(self.viewport, Viewport::Fullscreen);
+        self.backend.suspend(alternate_screen)?;
+        self.backend.flush()?;
+
+        let result = f();
+
+        self.backend.resume(alternate_screen)?;
+        if self.hidden_cursor {
+            self.backend.hide_cursor()?;
+        }
+        match self.viewport {
+            Viewport::Inline(_) => {
+                // place the viewport at the cursor, as the output of `f` may have scrolled it away
+                self.last_known_cursor_pos = (0, self.viewport_area.top());
+                let size = self.size()?;
+                self.resize(size)?;
+            }
+            Viewport::Fullscreen | Viewport::Fixed(_) => self.clear()?,
+        }
+        self.backend.flush()?;
+        Ok(result)
+    }
+
     /// Clears the inactive buffer and swaps it with the current buffer
     pub fn swap_buffers(&mut self) {
         self.buffers[1 - self.current].reset();
@@ -460,6 +1005,138 @@ where
 
         Ok(())
     }
+
+    /// Insert some text before the current inline viewport. This has no effect when the viewport
+    /// is not inline.
+    ///
+    /// Unlike [`Terminal::insert_before`], the height does not need to be known in advance: the
+    /// lines of the text are wrapped to the width of the viewport, and as many lines as needed are
+    /// scrolled into the history of the terminal above the viewport. The style and alignment of
+    /// the text are kept. This makes it easy to print logs above an inline viewport showing some
+    /// progress.
+    ///
+    /// As with [`Terminal::insert_before`], call [`Terminal::draw`] afterwards to redraw the
+    /// viewport.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{backend::TestBackend, prelude::*};
+    /// # let backend = TestBackend::new(10, 10);
+    /// # let options = TerminalOptions::default().viewport(Viewport::Inline(2));
+    /// # let mut terminal = Terminal::with_options(backend, options)?;
+    /// terminal.insert_lines_before(Line::from(vec![
+    ///     Span::styled("[done]", Style::new().green()),
+    ///     Span::raw(" downloaded 3 files"),
+    /// ]))?;
+    /// # std::io::Result::Ok(())
+    /// ```
+    pub fn insert_lines_before<'a, T>(&mut self, text: T) -> io::Result<()>
+    where
+        T: Into<Text<'a>>,
+    {
+        if !matches!(self.viewport, Viewport::Inline(_)) {
+            return Ok(());
+        }
+        let Text {
+            lines,
+            style,
+            alignment,
+        } = text.into();
+        let width = self.viewport_area.width;
+        let wrap = Wrap::new(false);
+        // the lines are inserted in batches that are small enough for a buffer, whose number of
+        // cells is limited to `u16::MAX`
+        let max_height = (u16::MAX / width.max(1)).max(1);
+        let mut batch = Text {
+            lines: Vec::new(),
+            style,
+            alignment,
+        };
+        let mut batch_height = 0_u16;
+        for line in lines {
+            let line = Text {
+                lines: vec![line],
+                style,
+                alignment,
+            };
+            let height =
+                u16::try_from(measure::height(&line, width, Some(&wrap))).unwrap_or(u16::MAX);
+            if !batch.lines.is_empty() && batch_height.saturating_add(height) > max_height {
+                let full_batch = Text {
+                    lines: mem::take(&mut batch.lines),
+                    ..batch
+                };
+                self.insert_text_before(full_batch, batch_height, max_height)?;
+                batch_height = 0;
+            }
+            batch.lines.extend(line.lines);
+            batch_height = batch_height.saturating_add(height);
+        }
+        if !batch.lines.is_empty() {
+            self.insert_text_before(batch, batch_height, max_height)?;
+        }
+        Ok(())
+    }
+
+    /// Inserts `text`, which is `height` rows high once wrapped, before the viewport
+    ///
+    /// The text is inserted in chunks of at most `max_height` rows, so that a single line that
+    /// wraps to more rows than fit in a buffer is inserted as well.
+    fn insert_text_before(&mut self, text: Text, height: u16, max_height: u16) -> io::Result<()> {
+        let alignment = text.alignment.unwrap_or(Alignment::Left);
+        let paragraph = Paragraph::new(text)
+            .wrap(Wrap::new(false))
+            .alignment(alignment);
+        let mut scroll = 0;
+        while scroll < height {
+            let rows = (height - scroll).min(max_height);
+            let chunk = paragraph.clone().scroll((scroll, 0));
+            self.insert_before(rows, |buf| chunk.render(buf.area, buf))?;
+            scroll += rows;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the graphics of `buffer` that are not in `other`
+fn missing_graphics<'a>(
+    buffer: &'a Buffer,
+    other: &'a Buffer,
+) -> impl Iterator<Item = &'a Graphic> {
+    buffer
+        .graphics()
+        .iter()
+        .filter(|graphic| !other.graphics().contains(graphic))
+}
+
+/// Removes graphics from the screen by writing their cleanup sequences
+fn remove_graphics<'a, B: Backend>(
+    backend: &mut B,
+    graphics: impl IntoIterator<Item = &'a Graphic>,
+) -> io::Result<()> {
+    for cleanup in graphics
+        .into_iter()
+        .filter_map(|graphic| graphic.cleanup.as_ref())
+    {
+        backend.write_raw(cleanup)?;
+    }
+    Ok(())
+}
+
+/// Draws graphics by writing their escape sequences at the top left corner of their areas, and
+/// returns the top left corner of the last one, if any
+fn draw_graphics<'a, B: Backend>(
+    backend: &mut B,
+    graphics: impl IntoIterator<Item = &'a Graphic>,
+) -> io::Result<Option<(u16, u16)>> {
+    let mut position = None;
+    for graphic in graphics {
+        backend.set_cursor(graphic.area.x, graphic.area.y)?;
+        backend.write_raw(&graphic.sequence)?;
+        position = Some((graphic.area.x, graphic.area.y));
+    }
+    Ok(position)
 }
 
 fn compute_inline_size<B: Backend>(
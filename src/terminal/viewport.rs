@@ -28,6 +28,13 @@ pub enum Viewport {
     Fixed(Rect),
 }
 
+/// Identifies a viewport added to a [`Terminal`] with [`Terminal::add_viewport`].
+///
+/// [`Terminal`]: crate::terminal::Terminal
+/// [`Terminal::add_viewport`]: crate::terminal::Terminal::add_viewport
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ViewportId(pub(crate) usize);
+
 impl fmt::Display for Viewport {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -44,15 +44,25 @@
 //! ]);
 //! ```
 
+#[cfg(feature = "ansi")]
+mod ansi;
+
 mod grapheme;
 pub use grapheme::StyledGrapheme;
 
 mod line;
 pub use line::Line;
 
+#[cfg(feature = "markdown")]
+pub(crate) mod markdown;
+#[cfg(feature = "markdown")]
+pub use markdown::from_markdown;
+
 mod masked;
 pub use masked::Masked;
 
+pub mod measure;
+
 mod span;
 pub use span::Span;
 
@@ -0,0 +1,302 @@
+use crate::prelude::*;
+
+const ESC: char = '\u{1b}';
+const BEL: char = '\u{7}';
+
+impl<'a> Text<'a> {
+    /// Creates a [`Text`] from a string containing ANSI escape sequences.
+    ///
+    /// The SGR sequences (`ESC [ ... m`) that set the colors and modifiers of the text are
+    /// converted into the [`Style`] of the [`Span`]s. The style carries over to the following lines
+    /// until it is reset, as it does in a terminal. This supports the 16 named colors, the 256
+    /// indexed colors and RGB colors, so the colored output of tools like `git diff --color`, `ls
+    /// --color` or syntax highlighters can be displayed directly.
+    ///
+    /// Other escape sequences, such as cursor movements or hyperlinks, are removed from the text.
+    /// The content of the spans borrows from `s`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::prelude::*;
+    /// let text = Text::from_ansi("\x1b[1;31merror\x1b[0m: file not found");
+    /// assert_eq!(
+    ///     text,
+    ///     Text::from(Line::from(vec![
+    ///         Span::styled("error", Style::new().red().bold()),
+    ///         Span::raw(": file not found"),
+    ///     ]))
+    /// );
+    /// ```
+    pub fn from_ansi(s: &'a str) -> Self {
+        let mut style = Style::new();
+        let lines = s
+            .split('\n')
+            .map(|line| {
+                let line = line.strip_suffix('\r').unwrap_or(line);
+                Line::from(parse_line(line, &mut style))
+            })
+            .collect::<Vec<_>>();
+        Self::from(lines)
+    }
+}
+
+/// Splits a line into spans, applying the escape sequences to `style`
+fn parse_line<'a>(line: &'a str, style: &mut Style) -> Vec<Span<'a>> {
+    let mut spans = vec![];
+    let mut rest = line;
+    while let Some(start) = rest.find(ESC) {
+        if start > 0 {
+            spans.push(Span::styled(&rest[..start], *style));
+        }
+        let sequence = &rest[start + ESC.len_utf8()..];
+        let (parameters, end) = parse_escape_sequence(sequence);
+        if let Some(parameters) = parameters {
+            apply_sgr(parameters, style);
+        }
+        rest = &sequence[end..];
+    }
+    if !rest.is_empty() {
+        spans.push(Span::styled(rest, *style));
+    }
+    spans
+}
+
+/// Parses the escape sequence following an `ESC` character
+///
+/// Returns the parameters of the sequence if it is an SGR sequence, and the length of the
+/// sequence.
+fn parse_escape_sequence(sequence: &str) -> (Option<&str>, usize) {
+    let mut chars = sequence.char_indices();
+    match chars.next() {
+        // Control Sequence Introducer: parameters and intermediate bytes followed by a final byte
+        Some((_, '[')) => {
+            for (index, c) in chars {
+                if ('\u{40}'..='\u{7e}').contains(&c) {
+                    let parameters = (c == 'm').then(|| &sequence[1..index]);
+                    return (parameters, index + 1);
+                }
+            }
+            (None, sequence.len())
+        }
+        // Operating System Command (e.g. hyperlinks): terminated by BEL or `ESC \`
+        Some((_, ']')) => {
+            let end = sequence
+                .char_indices()
+                .find_map(|(index, c)| match c {
+                    BEL => Some(index + 1),
+                    ESC if sequence[index + 1..].starts_with('\\') => Some(index + 2),
+                    _ => None,
+                })
+                .unwrap_or(sequence.len());
+            (None, end)
+        }
+        // Character set designations have an intermediate byte
+        Some((_, '(' | ')' | '*' | '+')) => (
+            None,
+            chars.next().map_or(1, |(index, c)| index + c.len_utf8()),
+        ),
+        // Other escape sequences consist of a single character
+        Some((_, c)) => (None, c.len_utf8()),
+        None => (None, 0),
+    }
+}
+
+/// Applies the parameters of an SGR (Select Graphic Rendition) sequence to `style`
+///
+/// The parameters are separated by `;`, and can have sub-parameters separated by `:` (e.g. `4:3`
+/// for a curly underline or `38:2::255:0:0` for an RGB color). Invalid parameters are skipped.
+fn apply_sgr(parameters: &str, style: &mut Style) {
+    let mut parameters = parameters.split(';');
+    while let Some(parameter) = parameters.next() {
+        let mut values = parameter.split(':').map(parse_value);
+        let Some(Some(code)) = values.next() else {
+            continue;
+        };
+        let sub_values = parameter.contains(':').then(|| values.collect::<Vec<_>>());
+        match code {
+            0 => *style = Style::new(),
+            1 => style.add_modifier.insert(Modifier::BOLD),
+            2 => style.add_modifier.insert(Modifier::DIM),
+            3 => style.add_modifier.insert(Modifier::ITALIC),
+            4 => match sub_values.as_deref() {
+                Some([Some(0), ..]) => style.add_modifier.remove(UNDERLINES),
+                Some([Some(style_index @ 1..=5), ..]) => {
+                    style.add_modifier.remove(UNDERLINES);
+                    style.add_modifier.insert(underline(*style_index));
+                }
+                Some(_) => {}
+                None => style.add_modifier.insert(Modifier::UNDERLINED),
+            },
+            5 => style.add_modifier.insert(Modifier::SLOW_BLINK),
+            6 => style.add_modifier.insert(Modifier::RAPID_BLINK),
+            7 => style.add_modifier.insert(Modifier::REVERSED),
+            8 => style.add_modifier.insert(Modifier::HIDDEN),
+            9 => style.add_modifier.insert(Modifier::CROSSED_OUT),
+            22 => style.add_modifier.remove(Modifier::BOLD | Modifier::DIM),
+            23 => style.add_modifier.remove(Modifier::ITALIC),
+            24 => style.add_modifier.remove(UNDERLINES),
+            25 => style
+                .add_modifier
+                .remove(Modifier::SLOW_BLINK | Modifier::RAPID_BLINK),
+            27 => style.add_modifier.remove(Modifier::REVERSED),
+            28 => style.add_modifier.remove(Modifier::HIDDEN),
+            29 => style.add_modifier.remove(Modifier::CROSSED_OUT),
+            30..=37 => style.fg = Some(named_color(code - 30)),
+            38 => style.fg = extended_color(sub_values.as_deref(), &mut parameters),
+            39 => style.fg = None,
+            40..=47 => style.bg = Some(named_color(code - 40)),
+            48 => style.bg = extended_color(sub_values.as_deref(), &mut parameters),
+            49 => style.bg = None,
+            #[cfg(feature = "underline-color")]
+            58 => style.underline_color = extended_color(sub_values.as_deref(), &mut parameters),
+            #[cfg(feature = "underline-color")]
+            59 => style.underline_color = None,
+            90..=97 => style.fg = Some(named_color(code - 90 + 8)),
+            100..=107 => style.bg = Some(named_color(code - 100 + 8)),
+            _ => {}
+        }
+    }
+}
+
+/// The color of the 16 color palette with the given index
+const fn named_color(index: u8) -> Color {
+    match index {
+        0 => Color::Black,
+        1 => Color::Red,
+        2 => Color::Green,
+        3 => Color::Yellow,
+        4 => Color::Blue,
+        5 => Color::Magenta,
+        6 => Color::Cyan,
+        7 => Color::Gray,
+        8 => Color::DarkGray,
+        9 => Color::LightRed,
+        10 => Color::LightGreen,
+        11 => Color::LightYellow,
+        12 => Color::LightBlue,
+        13 => Color::LightMagenta,
+        14 => Color::LightCyan,
+        _ => Color::White,
+    }
+}
+
+/// The underline modifiers, which replace each other
+const UNDERLINES: Modifier = Modifier::UNDERLINED
+    .union(Modifier::DOUBLE_UNDERLINED)
+    .union(Modifier::CURLY_UNDERLINED)
+    .union(Modifier::DOTTED_UNDERLINED)
+    .union(Modifier::DASHED_UNDERLINED);
+
+/// Parses a parameter of an SGR sequence, where an empty parameter is the default value 0
+fn parse_value(value: &str) -> Option<u8> {
+    if value.is_empty() {
+        Some(0)
+    } else {
+        value.parse().ok()
+    }
+}
+
+/// The underline modifier of the style of the `4:n` sub-parameter
+const fn underline(style_index: u8) -> Modifier {
+    match style_index {
+        2 => Modifier::DOUBLE_UNDERLINED,
+        3 => Modifier::CURLY_UNDERLINED,
+        4 => Modifier::DOTTED_UNDERLINED,
+        5 => Modifier::DASHED_UNDERLINED,
+        _ => Modifier::UNDERLINED,
+    }
+}
+
+/// Parses the indexed or RGB color of the codes 38, 48 and 58
+///
+/// The color is given either by the sub-parameters of the code (`38:5:n`, `38:2:r:g:b` or
+/// `38:2:colorspace:r:g:b`) or by the following parameters (`38;5;n` or `38;2;r;g;b`), which are
+/// consumed from `parameters`.
+fn extended_color<'a>(
+    sub_values: Option<&[Option<u8>]>,
+    parameters: &mut impl Iterator<Item = &'a str>,
+) -> Option<Color> {
+    if let Some(sub_values) = sub_values {
+        return match *sub_values {
+            [Some(5), index] => index.map(Color::Indexed),
+            [Some(2), red, green, blue] | [Some(2), _, red, green, blue] => {
+                Some(Color::Rgb(red?, green?, blue?))
+            }
+            _ => None,
+        };
+    }
+    match parameters.next().and_then(parse_value)? {
+        5 => parameters.next().and_then(parse_value).map(Color::Indexed),
+        2 => {
+            // the three components are consumed even if one of them is invalid
+            let [red, green, blue] = [(); 3].map(|()| parameters.next().and_then(parse_value));
+            Some(Color::Rgb(red?, green?, blue?))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn plain_text() {
+        assert_eq!(Text::from_ansi("hello\nworld"), Text::from("hello\nworld"));
+    }
+
+    #[test]
+    fn styles_carry_over_lines() {
+        let text = Text::from_ansi("\x1b[32m+added\nline\x1b[0m\r\nplain");
+        assert_eq!(
+            text,
+            Text::from(vec![
+                Line::from(Span::styled("+added", Style::new().green())),
+                Line::from(Span::styled("line", Style::new().green())),
+                Line::from("plain"),
+            ])
+        );
+    }
+
+    #[rstest]
+    #[case::bold("1", Style::new().bold())]
+    #[case::reset_modifier("1;3;22", Style::new().italic())]
+    #[case::named("31;44", Style::new().red().on_blue())]
+    #[case::bright("91;107", Style::new().light_red().on_white())]
+    #[case::indexed("38;5;208", Style::new().fg(Color::Indexed(208)))]
+    #[case::rgb("48;2;10;20;30", Style::new().bg(Color::Rgb(10, 20, 30)))]
+    #[case::colon("38:5:208", Style::new().fg(Color::Indexed(208)))]
+    #[case::default_color("31;39", Style::new())]
+    #[case::reset("1;31;0;4", Style::new().underlined())]
+    #[case::empty("31;", Style::new())]
+    #[case::invalid("1;x;300;31", Style::new().bold().red())]
+    #[case::colon_rgb("38:2:10:20:30", Style::new().fg(Color::Rgb(10, 20, 30)))]
+    #[case::colon_rgb_colorspace("48:2::10:20:30", Style::new().bg(Color::Rgb(10, 20, 30)))]
+    #[case::curly_underline("4:3", Style::new().add_modifier(Modifier::CURLY_UNDERLINED))]
+    #[case::no_underline("4;4:0", Style::new())]
+    #[case::invalid_rgb("38;2;10;x;30;1", Style::new().bold())]
+    fn sgr(#[case] parameters: &str, #[case] expected: Style) {
+        let mut style = Style::new();
+        apply_sgr(parameters, &mut style);
+        assert_eq!(style, expected);
+    }
+
+    #[test]
+    fn removes_other_escape_sequences() {
+        let text = Text::from_ansi(
+            "\x1b[2K\x1b]8;;https://ratatui.rs\x1b\\link\x1b]8;;\x07 \x1b(Bdone\x1b[",
+        );
+        assert_eq!(
+            text,
+            Text::from(Line::from(vec![
+                Span::raw("link"),
+                Span::raw(" "),
+                Span::raw("done")
+            ]))
+        );
+    }
+}
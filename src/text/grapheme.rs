@@ -9,6 +9,8 @@ use crate::prelude::*;
 pub struct StyledGrapheme<'a> {
     pub symbol: &'a str,
     pub style: Style,
+    /// The target of the hyperlink of the [`Span`] the grapheme belongs to, if any
+    pub hyperlink: Option<&'a str>,
 }
 
 impl<'a> StyledGrapheme<'a> {
@@ -20,8 +22,18 @@ impl<'a> StyledGrapheme<'a> {
         Self {
             symbol,
             style: style.into(),
+            hyperlink: None,
         }
     }
+
+    /// Sets the target of the hyperlink of the grapheme.
+    ///
+    /// See [`Span::hyperlink`] for more details.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn hyperlink(mut self, url: &'a str) -> Self {
+        self.hyperlink = Some(url);
+        self
+    }
 }
 
 impl<'a> Styled for StyledGrapheme<'a> {
@@ -47,6 +59,13 @@ mod tests {
         let sg = StyledGrapheme::new("a", style);
         assert_eq!(sg.symbol, "a");
         assert_eq!(sg.style, style);
+        assert_eq!(sg.hyperlink, None);
+    }
+
+    #[test]
+    fn hyperlink() {
+        let sg = StyledGrapheme::new("a", Style::new()).hyperlink("https://ratatui.rs");
+        assert_eq!(sg.hyperlink, Some("https://ratatui.rs"));
     }
 
     #[test]
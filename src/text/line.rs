@@ -1,11 +1,12 @@
 #![deny(missing_docs)]
 #![warn(clippy::pedantic, clippy::nursery, clippy::arithmetic_side_effects)]
-use std::{borrow::Cow, fmt};
+use std::{borrow::Cow, fmt, ops::Range};
 
 use unicode_truncate::UnicodeTruncateStr;
+use unicode_width::UnicodeWidthStr;
 
 use super::StyledGrapheme;
-use crate::prelude::*;
+use crate::{prelude::*, style::Gradient};
 
 /// A line of text, consisting of one or more [`Span`]s.
 ///
@@ -140,9 +141,7 @@ use crate::prelude::*;
 /// # use ratatui::{prelude::*, widgets::*};
 /// # fn render(area: Rect, buf: &mut Buffer) {
 /// let line = Line::from("Hello world!").yellow().italic();
-/// Paragraph::new(line)
-///     .wrap(Wrap { trim: true })
-///     .render(area, buf);
+/// Paragraph::new(line).wrap(Wrap::new(true)).render(area, buf);
 /// # }
 /// ```
 ///
@@ -426,6 +425,122 @@ impl<'a> Line<'a> {
         self
     }
 
+    /// Patches the style of a range of the content of this Line, splitting spans as needed.
+    ///
+    /// `range` is a range of byte offsets in the content of the line, as returned by
+    /// [`str::find`] or [`str::match_indices`] on the string of the line (see
+    /// [`Line::to_string`](ToString::to_string)). The range is clamped to the content, and offsets
+    /// that fall inside a character are moved to the start of that character. The style of each
+    /// span in the range is patched with `style`, which is the building block for highlighting
+    /// search results or a selection.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::prelude::*;
+    /// let line = Line::from(vec!["Hello ".blue(), "world".green()]);
+    /// let start = line.to_string().find("lo w").unwrap();
+    /// let line = line.patch_style_range(start..start + 4, Modifier::REVERSED);
+    /// assert_eq!(
+    ///     line,
+    ///     Line::from(vec![
+    ///         "Hel".blue(),
+    ///         "lo ".blue().reversed(),
+    ///         "w".green().reversed(),
+    ///         "orld".green(),
+    ///     ])
+    /// );
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn patch_style_range<S: Into<Style>>(mut self, range: Range<usize>, style: S) -> Self {
+        self.patch_range(range, style.into());
+        self
+    }
+
+    /// Colors the foreground of this Line with a gradient, from its first to its last cell.
+    ///
+    /// Each grapheme is put in its own span, whose foreground is the color of the [`Gradient`] at
+    /// the position of the grapheme. The other properties of the style of the spans are kept. The
+    /// direction of the gradient is ignored, as text is always colored from left to right.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, style::Gradient};
+    /// let gradient = Gradient::new([Color::Rgb(0, 0, 0), Color::Rgb(200, 100, 0)]);
+    /// let line = Line::from("abc".bold()).fg_gradient(&gradient);
+    /// assert_eq!(
+    ///     line,
+    ///     Line::from(vec![
+    ///         "a".bold().fg(Color::Rgb(0, 0, 0)),
+    ///         "b".bold().fg(Color::Rgb(100, 50, 0)),
+    ///         "c".bold().fg(Color::Rgb(200, 100, 0)),
+    ///     ])
+    /// );
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn fg_gradient(mut self, gradient: &Gradient) -> Self {
+        let width = self.width();
+        let mut offset = 0;
+        let spans = self
+            .spans
+            .iter()
+            .flat_map(|span| span.styled_graphemes(Style::new()))
+            .map(|grapheme| {
+                let color = gradient.color_at_cell(offset, width);
+                offset = offset.saturating_add(grapheme.symbol.width());
+                let mut span = Span::styled(grapheme.symbol.to_string(), grapheme.style.fg(color));
+                span.hyperlink = grapheme.hyperlink.map(|url| Cow::Owned(url.to_string()));
+                span
+            })
+            .collect();
+        self.spans = spans;
+        self
+    }
+
+    /// Patches the style of a range of byte offsets of the content (see
+    /// [`Line::patch_style_range`])
+    pub(crate) fn patch_range(&mut self, range: Range<usize>, style: Style) {
+        if range.is_empty() {
+            return;
+        }
+        let mut spans = Vec::with_capacity(self.spans.len().saturating_add(2));
+        let mut offset = 0;
+        for span in self.spans.drain(..) {
+            let len = span.content.len();
+            let start = floor_char_boundary(&span.content, range.start.saturating_sub(offset));
+            let end = floor_char_boundary(&span.content, range.end.saturating_sub(offset));
+            offset = offset.saturating_add(len);
+            if start >= end {
+                spans.push(span);
+                continue;
+            }
+            let (rest, after) = split_content(span.content, end);
+            let (before, patched) = split_content(rest, start);
+            for (content, style) in [
+                (before, span.style),
+                (patched, span.style.patch(style)),
+                (after, span.style),
+            ] {
+                if !content.is_empty() {
+                    spans.push(Span {
+                        content,
+                        style,
+                        hyperlink: span.hyperlink.clone(),
+                    });
+                }
+            }
+        }
+        self.spans = spans;
+    }
+
     /// Resets the style of this Line.
     ///
     /// Equivalent to calling `patch_style(Style::reset())`.
@@ -571,7 +686,7 @@ impl WidgetRef for Line<'_> {
             let indent_width = match self.alignment {
                 Some(Alignment::Center) => (area_width.saturating_sub(line_width)) / 2,
                 Some(Alignment::Right) => area_width.saturating_sub(line_width),
-                Some(Alignment::Left) | None => 0,
+                Some(Alignment::Left | Alignment::Justify) | None => 0,
             };
             let indent_width = u16::try_from(indent_width).unwrap_or(u16::MAX);
             let area = area.indent_x(indent_width);
@@ -582,13 +697,35 @@ impl WidgetRef for Line<'_> {
             let skip_width = match self.alignment {
                 Some(Alignment::Center) => (line_width.saturating_sub(area_width)) / 2,
                 Some(Alignment::Right) => line_width.saturating_sub(area_width),
-                Some(Alignment::Left) | None => 0,
+                Some(Alignment::Left | Alignment::Justify) | None => 0,
             };
             render_spans(&self.spans, area, buf, skip_width);
         };
     }
 }
 
+/// The largest offset that is at most `index` and is on a character boundary of `s`
+fn floor_char_boundary(s: &str, index: usize) -> usize {
+    (0..=index.min(s.len()))
+        .rev()
+        .find(|&index| s.is_char_boundary(index))
+        .unwrap_or(0)
+}
+
+/// Splits the content of a span at `at`, keeping borrowed content borrowed
+fn split_content(content: Cow<'_, str>, at: usize) -> (Cow<'_, str>, Cow<'_, str>) {
+    match content {
+        Cow::Borrowed(s) => {
+            let (left, right) = s.split_at(at);
+            (Cow::Borrowed(left), Cow::Borrowed(right))
+        }
+        Cow::Owned(mut s) => {
+            let right = s.split_off(at);
+            (Cow::Owned(s), Cow::Owned(right))
+        }
+    }
+}
+
 /// Renders all the spans of the line that should be visible.
 fn render_spans(spans: &[Span], mut area: Rect, buf: &mut Buffer, span_skip_width: usize) {
     for (span, span_width, offset) in spans_after_width(spans, span_skip_width) {
@@ -782,6 +919,54 @@ mod tests {
         assert_eq!(raw_line, styled_line);
     }
 
+    #[rstest]
+    #[case::inside_span(2..4, vec!["ab".blue(), "cd".blue().bold(), "ef".blue(), "gh".red()])]
+    #[case::across_spans(4..7, vec!["abcd".blue(), "ef".blue().bold(), "g".red().bold(), "h".red()])]
+    #[case::whole_line(0..100, vec!["abcdef".blue().bold(), "gh".red().bold()])]
+    #[case::empty(3..3, vec!["abcdef".blue(), "gh".red()])]
+    #[case::past_end(10..12, vec!["abcdef".blue(), "gh".red()])]
+    fn patch_style_range(#[case] range: Range<usize>, #[case] expected: Vec<Span>) {
+        let line = Line::from(vec!["abcdef".blue(), "gh".red()]);
+        assert_eq!(
+            line.patch_style_range(range, Modifier::BOLD),
+            Line::from(expected)
+        );
+    }
+
+    #[test]
+    fn patch_style_range_keeps_character_boundaries() {
+        let line = Line::from(String::from("aé"));
+        // 2 is inside `é`, which starts at 1
+        let line = line.patch_style_range(2..3, Modifier::BOLD);
+        assert_eq!(
+            line,
+            Line::from(vec![Span::raw("a"), Span::raw("é").bold()])
+        );
+    }
+
+    #[test]
+    fn fg_gradient() {
+        let gradient = Gradient::new([Color::Rgb(0, 0, 0), Color::Rgb(0, 0, 90)]);
+        let line = Line::from(vec!["a".italic(), "文b".on_red()])
+            .red()
+            .fg_gradient(&gradient);
+        assert_eq!(
+            line,
+            Line::from(vec![
+                Span::raw("a").italic().fg(Color::Rgb(0, 0, 0)),
+                Span::raw("文").on_red().fg(Color::Rgb(0, 0, 30)),
+                Span::raw("b").on_red().fg(Color::Rgb(0, 0, 90)),
+            ])
+            .red()
+        );
+    }
+
+    #[test]
+    fn fg_gradient_empty() {
+        let gradient = Gradient::new([Color::Red, Color::Blue]);
+        assert_eq!(Line::default().fg_gradient(&gradient), Line::default());
+    }
+
     #[test]
     fn reset_style() {
         let line =
@@ -1016,6 +1201,15 @@ mod tests {
             assert_eq!(buf, expected);
         }
 
+        #[test]
+        fn render_justified() {
+            // a single line is the last line of its paragraph, which is aligned to the left
+            let line = Line::from("Hello world!").alignment(Alignment::Justify);
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 1));
+            line.render(Rect::new(0, 0, 15, 1), &mut buf);
+            assert_eq!(buf, Buffer::with_lines(["Hello world!   "]));
+        }
+
         #[test]
         fn render_right_aligned() {
             let line = hello_world().alignment(Alignment::Right);
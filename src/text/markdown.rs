@@ -0,0 +1,271 @@
+use crate::prelude::*;
+
+/// The style of level 1 headings
+const HEADING_1_STYLE: Style =
+    Style::new().add_modifier(Modifier::BOLD.union(Modifier::UNDERLINED));
+/// The style of the other headings
+const HEADING_STYLE: Style = Style::new().add_modifier(Modifier::BOLD);
+/// The style of code spans and code blocks
+const CODE_STYLE: Style = Style::new().fg(Color::Yellow);
+/// The style of the marker in front of block quotes
+const QUOTE_MARKER_STYLE: Style = Style::new().add_modifier(Modifier::DIM);
+
+/// Converts Markdown into styled [`Text`].
+///
+/// This supports a subset of [CommonMark] that is common in help screens and READMEs:
+/// - headings (`# Title`), in bold, and underlined for level 1 headings
+/// - bold (`**bold**` or `__bold__`) and italic (`*italic*` or `_italic_`) text
+/// - code spans (`` `code` ``) and fenced code blocks, in yellow
+/// - unordered lists (`-`, `*` or `+`), rendered with a `•` marker, and ordered lists (`1.`)
+/// - block quotes (`> quote`), rendered with a `│` marker
+/// - backslash escapes (`\*`)
+///
+/// Each line of the Markdown is converted into a [`Line`], so lines are not joined into
+/// paragraphs. Wrap the text in a [`Paragraph`] to fit it into an area. The markers of headings,
+/// emphasis and code are removed, and the content of the spans borrows from `markdown`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use ratatui::{prelude::*, text::from_markdown};
+/// let text = from_markdown("# Usage\nRun `app --help` for **more** options");
+/// assert_eq!(
+///     text,
+///     Text::from(vec![
+///         Line::from(Span::styled("Usage", Style::new().bold().underlined())),
+///         Line::from(vec![
+///             Span::raw("Run "),
+///             Span::styled("app --help", Style::new().yellow()),
+///             Span::raw(" for "),
+///             Span::styled("more", Style::new().bold()),
+///             Span::raw(" options"),
+///         ]),
+///     ])
+/// );
+/// ```
+///
+/// [CommonMark]: https://commonmark.org
+/// [`Paragraph`]: crate::widgets::Paragraph
+pub fn from_markdown(markdown: &str) -> Text<'_> {
+    let mut lines = vec![];
+    let mut fence = None;
+    for line in markdown.lines() {
+        let trimmed = line.trim_start();
+        if let Some(marker) = fence {
+            if trimmed.starts_with(marker) {
+                fence = None;
+            } else {
+                lines.push(Line::from(Span::styled(line, CODE_STYLE)));
+            }
+        } else if let Some(marker) = ["```", "~~~"]
+            .into_iter()
+            .find(|marker| trimmed.starts_with(marker))
+        {
+            fence = Some(marker);
+        } else {
+            lines.push(Line::from(parse_block(line)));
+        }
+    }
+    Text::from(lines)
+}
+
+/// Converts a line that is not part of a code block, according to the block it starts
+fn parse_block(line: &str) -> Vec<Span<'_>> {
+    let content = line.trim_start_matches(' ');
+    let indent = &line[..line.len() - content.len()];
+
+    if let Some((level, heading)) = heading(content).filter(|_| indent.len() < 4) {
+        let style = if level == 1 {
+            HEADING_1_STYLE
+        } else {
+            HEADING_STYLE
+        };
+        return parse_inline(heading, style);
+    }
+
+    if let Some(quote) = content.strip_prefix('>') {
+        let quote = quote.strip_prefix(' ').unwrap_or(quote);
+        let mut spans = vec![Span::styled("│ ", QUOTE_MARKER_STYLE)];
+        spans.extend(parse_block(quote));
+        return spans;
+    }
+
+    let mut spans = vec![];
+    if !indent.is_empty() {
+        spans.push(Span::raw(indent));
+    }
+    if let Some(item) = ["- ", "* ", "+ "]
+        .into_iter()
+        .find_map(|marker| content.strip_prefix(marker))
+    {
+        spans.push(Span::raw("• "));
+        spans.extend(parse_inline(item, Style::new()));
+    } else if let Some(marker_len) = ordered_list_marker(content) {
+        spans.push(Span::raw(&content[..marker_len]));
+        spans.extend(parse_inline(&content[marker_len..], Style::new()));
+    } else {
+        spans.extend(parse_inline(content, Style::new()));
+    }
+    spans
+}
+
+/// The level and the content of an ATX heading (`## Heading ##`)
+pub(crate) fn heading(line: &str) -> Option<(usize, &str)> {
+    let content = line.trim_start_matches('#');
+    let level = line.len() - content.len();
+    if !(1..=6).contains(&level) || !(content.is_empty() || content.starts_with(' ')) {
+        return None;
+    }
+    let content = content.trim();
+    // the closing sequence of `#` is only one if it is separated from the content by whitespace
+    let without_closing = content.trim_end_matches('#');
+    if without_closing.is_empty() || without_closing.ends_with(char::is_whitespace) {
+        Some((level, without_closing.trim_end()))
+    } else {
+        Some((level, content))
+    }
+}
+
+/// The length of the marker of an ordered list item (`1. ` or `1) `), if the line is one
+pub(crate) fn ordered_list_marker(line: &str) -> Option<usize> {
+    let digits = line.bytes().take_while(u8::is_ascii_digit).count();
+    let marker = line.get(digits..digits + 2)?;
+    ((1..=9).contains(&digits) && (marker == ". " || marker == ") ")).then_some(digits + 2)
+}
+
+/// Converts the emphasis, code spans and escapes of `text` into spans patched over `base`
+pub(crate) fn parse_inline(text: &str, base: Style) -> Vec<Span<'_>> {
+    let mut spans = vec![];
+    let (mut bold, mut italic) = (false, false);
+    let style = |bold: bool, italic: bool| {
+        let mut style = base;
+        if bold {
+            style = style.add_modifier(Modifier::BOLD);
+        }
+        if italic {
+            style = style.add_modifier(Modifier::ITALIC);
+        }
+        style
+    };
+    // the start of the text that is not yet part of a span
+    let mut start = 0;
+    let mut index = 0;
+    while let Some(c) = text[index..].chars().next() {
+        let rest = &text[index..];
+        match c {
+            '\\' if rest[1..].starts_with(|c: char| c.is_ascii_punctuation()) => {
+                push_span(&mut spans, &text[start..index], style(bold, italic));
+                // the escaped character starts the next span
+                start = index + 1;
+                index += 2;
+                continue;
+            }
+            '`' => {
+                if let Some(len) = rest[1..].find('`') {
+                    push_span(&mut spans, &text[start..index], style(bold, italic));
+                    let code = &rest[1..=len];
+                    push_span(&mut spans, code, style(bold, italic).patch(CODE_STYLE));
+                    index += len + 2;
+                    start = index;
+                    continue;
+                }
+            }
+            '*' | '_' => {
+                let double = if c == '*' { "**" } else { "__" };
+                let delimiter = if rest.starts_with(double) {
+                    double
+                } else {
+                    &rest[..1]
+                };
+                let after = &rest[delimiter.len()..];
+                let opening = if delimiter.len() == 2 { !bold } else { !italic };
+                // underscores inside words (e.g. `snake_case`) are not delimiters
+                let at_word_boundary = c == '*'
+                    || if opening {
+                        !text[..index].ends_with(|c: char| c.is_alphanumeric())
+                    } else {
+                        !after.starts_with(|c: char| c.is_alphanumeric())
+                    };
+                // an opening delimiter needs a matching closing delimiter
+                if at_word_boundary && (!opening || after.contains(delimiter)) {
+                    push_span(&mut spans, &text[start..index], style(bold, italic));
+                    if delimiter.len() == 2 {
+                        bold = !bold;
+                    } else {
+                        italic = !italic;
+                    }
+                    index += delimiter.len();
+                    start = index;
+                    continue;
+                }
+            }
+            _ => {}
+        }
+        index += c.len_utf8();
+    }
+    push_span(&mut spans, &text[start..], style(bold, italic));
+    spans
+}
+
+/// Adds a span with the given content, unless the content is empty
+fn push_span<'a>(spans: &mut Vec<Span<'a>>, content: &'a str, style: Style) {
+    if !content.is_empty() {
+        spans.push(Span::styled(content, style));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::h1("# Title", vec![Span::styled("Title", HEADING_1_STYLE)])]
+    #[case::h3("### Title ###", vec![Span::styled("Title", HEADING_STYLE)])]
+    #[case::trailing_hash("## Using C#", vec![Span::styled("Using C#", HEADING_STYLE)])]
+    #[case::only_closing("## ##", vec![])]
+    #[case::not_heading("#hashtag", vec![Span::raw("#hashtag")])]
+    #[case::bold("a **b** c", vec![
+        Span::raw("a "),
+        Span::styled("b", Style::new().bold()),
+        Span::raw(" c"),
+    ])]
+    #[case::italic("_a_ *b*", vec![
+        Span::styled("a", Style::new().italic()),
+        Span::raw(" "),
+        Span::styled("b", Style::new().italic()),
+    ])]
+    #[case::nested("**a *b***", vec![
+        Span::styled("a ", Style::new().bold()),
+        Span::styled("b", Style::new().bold().italic()),
+    ])]
+    #[case::snake_case("snake_case_name", vec![Span::raw("snake_case_name")])]
+    #[case::unmatched("2 * 3", vec![Span::raw("2 * 3")])]
+    #[case::code("`a *b*`", vec![Span::styled("a *b*", CODE_STYLE)])]
+    #[case::escape(r"\*a\*", vec![Span::raw("*a"), Span::raw("*")])]
+    #[case::unordered("  - item", vec![Span::raw("  "), Span::raw("• "), Span::raw("item")])]
+    #[case::ordered("10. item", vec![Span::raw("10. "), Span::raw("item")])]
+    #[case::quote("> **a**", vec![
+        Span::styled("│ ", QUOTE_MARKER_STYLE),
+        Span::styled("a", Style::new().bold()),
+    ])]
+    fn line(#[case] markdown: &str, #[case] expected: Vec<Span>) {
+        assert_eq!(from_markdown(markdown), Text::from(Line::from(expected)));
+    }
+
+    #[test]
+    fn code_block() {
+        let text = from_markdown("text\n```rust\nlet a = *b;\n```\n\nend");
+        assert_eq!(
+            text,
+            Text::from(vec![
+                Line::from("text"),
+                Line::from(Span::styled("let a = *b;", CODE_STYLE)),
+                Line::default(),
+                Line::from("end"),
+            ])
+        );
+    }
+}
@@ -0,0 +1,305 @@
+//! Measurement of text as it is laid out in the terminal.
+//!
+//! The functions in this module measure text the same way [`Paragraph`] renders it, so they can be
+//! used to size areas to fit some text, or to place the cursor inside text that is wrapped, e.g.
+//! in a text input.
+//!
+//! Widths are measured in columns of the terminal, one grapheme at a time, in the same way that
+//! the [`Buffer`] stores them. Byte offsets are offsets into the string that [`Text::to_string`]
+//! returns, where the lines are separated by a single `\n`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use ratatui::{layout::Position, prelude::*, text::measure, widgets::Wrap};
+//!
+//! let text = Text::from("Hello wonderful world");
+//! let wrap = Some(&Wrap::new(true));
+//! assert_eq!(measure::width("Hello"), 5);
+//! assert_eq!(measure::height(&text, 10, wrap), 3);
+//! // the `w` of `world` is at the start of the third line
+//! assert_eq!(
+//!     measure::cell_position(&text, 10, wrap, 16),
+//!     Some(Position::new(0, 2))
+//! );
+//! ```
+//!
+//! [`Paragraph`]: crate::widgets::Paragraph
+//! [`Buffer`]: crate::buffer::Buffer
+//! [`Text::to_string`]: ToString::to_string
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    layout::{Alignment, Position},
+    style::Style,
+    text::{Line, StyledGrapheme, Text},
+    widgets::{
+        reflow::{LineComposer, LineTruncator, WrappedLine},
+        Wrap,
+    },
+};
+
+/// Returns the width of `s` in columns of the terminal.
+///
+/// The width is the sum of the widths of the graphemes of `s`, which is how much space the string
+/// takes when it is written to a [`Buffer`]. Line breaks are ignored.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui::text::measure;
+///
+/// assert_eq!(measure::width("abc"), 3);
+/// assert_eq!(measure::width("コンピュータ"), 12);
+/// assert_eq!(measure::width("e\u{301}"), 1);
+/// ```
+///
+/// [`Buffer`]: crate::buffer::Buffer
+pub fn width(s: &str) -> usize {
+    s.graphemes(true)
+        .filter(|grapheme| *grapheme != "\n")
+        .map(UnicodeWidthStr::width)
+        .sum()
+}
+
+/// Returns the number of rows `text` takes when it is laid out in an area `width` columns wide.
+///
+/// When `wrap` is `None`, each line of the text takes a single row, and the lines that are too
+/// long are truncated. Otherwise, the lines are wrapped on word boundaries and indented as they are
+/// by a [`Paragraph`] with the same [`Wrap`].
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui::{layout::Position, prelude::*, text::measure, widgets::Wrap};
+///
+/// let text = Text::from("Hello world\nHi");
+/// assert_eq!(measure::height(&text, 20, Some(&Wrap::new(true))), 2);
+/// assert_eq!(measure::height(&text, 5, Some(&Wrap::new(true))), 3);
+/// assert_eq!(measure::height(&text, 5, None), 2);
+/// ```
+///
+/// [`Paragraph`]: crate::widgets::Paragraph
+pub fn height(text: &Text, width: u16, wrap: Option<&Wrap>) -> usize {
+    if width == 0 {
+        return 0;
+    }
+    text.iter()
+        .map(|line| {
+            let mut rows = 0;
+            layout_line(text, line, width, wrap, |_| rows += 1);
+            rows
+        })
+        .sum()
+}
+
+/// Returns the cell at which the character at `byte_offset` of `text` is displayed when the text
+/// is laid out in an area `width` columns wide.
+///
+/// The position is relative to the top left corner of the area: `x` is the column and `y` is the
+/// row. It takes into account the wrapping of the lines, as described in [`height`], and the
+/// alignment of the lines.
+///
+/// An offset at the end of a line (where `\n` is in the string returned by [`Text::to_string`]) or
+/// at the end of the text is positioned right after the last grapheme of the line, which is where
+/// a cursor appending to the line is displayed. This column can be equal to `width` when the line
+/// fills the area. An offset inside a grapheme is positioned at that grapheme, and an offset of
+/// whitespace that was trimmed by the wrapping is positioned at the following grapheme.
+///
+/// Returns `None` if `byte_offset` is past the end of the text or if `width` is 0.
+///
+/// # Examples
+///
+/// ```rust
+/// use ratatui::{layout::Position, prelude::*, text::measure, widgets::Wrap};
+///
+/// let text = Text::from("Hello world\nHi");
+/// let wrap = Some(&Wrap::new(true));
+/// assert_eq!(
+///     measure::cell_position(&text, 8, wrap, 4),
+///     Some(Position::new(4, 0))
+/// );
+/// assert_eq!(
+///     measure::cell_position(&text, 8, wrap, 8),
+///     Some(Position::new(2, 1))
+/// );
+/// assert_eq!(
+///     measure::cell_position(&text, 8, wrap, 14),
+///     Some(Position::new(2, 2))
+/// );
+/// assert_eq!(measure::cell_position(&text, 8, wrap, 15), None);
+/// ```
+///
+/// [`Text::to_string`]: ToString::to_string
+pub fn cell_position(
+    text: &Text,
+    width: u16,
+    wrap: Option<&Wrap>,
+    byte_offset: usize,
+) -> Option<Position> {
+    if width == 0 {
+        return None;
+    }
+    let mut row = 0;
+    let mut line_start = 0;
+    for line in text.iter() {
+        let line_len: usize = line.spans.iter().map(|span| span.content.len()).sum();
+        if byte_offset > line_start + line_len {
+            layout_line(text, line, width, wrap, |_| row += 1);
+            // the lines are separated by a line break
+            line_start += line_len + 1;
+            continue;
+        }
+        let offset = byte_offset - line_start;
+        // the position after the last grapheme, used when no grapheme is at or after the offset
+        let mut end = None;
+        let mut found = None;
+        layout_line(text, line, width, wrap, |wrapped| {
+            if found.is_some() {
+                return;
+            }
+            let mut x = line_offset(wrapped.width, width, wrapped.alignment);
+            for (grapheme, start) in wrapped.line.iter().zip(wrapped.offsets) {
+                let grapheme_width = grapheme.symbol.width() as u16;
+                let is_at_offset =
+                    start.is_some_and(|start| offset < start + grapheme.symbol.len());
+                if is_at_offset {
+                    found = Some((x, row));
+                    return;
+                }
+                x = x.saturating_add(grapheme_width);
+            }
+            end = Some((x, row));
+            row += 1;
+        });
+        let (x, y) = found.or(end).unwrap_or((0, row));
+        return Some(Position::new(x, u16::try_from(y).unwrap_or(u16::MAX)));
+    }
+    None
+}
+
+/// Returns the column at which a line `line_width` columns wide starts in an area `area_width`
+/// columns wide
+pub(crate) const fn line_offset(line_width: u16, area_width: u16, alignment: Alignment) -> u16 {
+    match alignment {
+        Alignment::Center => (area_width / 2).saturating_sub(line_width / 2),
+        Alignment::Right => area_width.saturating_sub(line_width),
+        // justified lines are stretched to the width of the area by the line composer
+        Alignment::Left | Alignment::Justify => 0,
+    }
+}
+
+/// Returns the number of rows composed by `composer`
+pub(crate) fn count_rows<'a>(mut composer: impl LineComposer<'a>) -> usize {
+    let mut rows = 0;
+    while composer.next_line().is_some() {
+        rows += 1;
+    }
+    rows
+}
+
+/// Lays out a single line of `text`, calling `f` with each of the rows it takes
+fn layout_line(
+    text: &Text,
+    line: &Line,
+    width: u16,
+    wrap: Option<&Wrap>,
+    mut f: impl FnMut(WrappedLine),
+) {
+    let alignment = line.alignment.or(text.alignment).unwrap_or(Alignment::Left);
+    let lines = std::iter::once((line.styled_graphemes(Style::new()), alignment));
+    if let Some(wrap) = wrap {
+        let mut composer = wrap.word_wrapper(lines, width, Style::new());
+        while let Some(wrapped) = composer.next_line() {
+            f(wrapped);
+        }
+    } else {
+        let mut composer = LineTruncator::new(lines, width);
+        while let Some(wrapped) = composer.next_line() {
+            f(wrapped);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{style::Stylize, text::Span};
+
+    #[rstest]
+    #[case::empty("", 0)]
+    #[case::ascii("hello", 5)]
+    #[case::wide("你好", 4)]
+    #[case::combining("e\u{301}e\u{301}", 2)]
+    #[case::line_break("a\nb", 2)]
+    fn width(#[case] s: &str, #[case] expected: usize) {
+        assert_eq!(super::width(s), expected);
+    }
+
+    #[rstest]
+    #[case::empty("", 10, None, 1)]
+    #[case::truncated("a long line\nb", 4, None, 2)]
+    #[case::wrapped("a long line\nb", 4, Some(&Wrap::new(true)), 4)]
+    #[case::zero_width("a long line", 0, Some(&Wrap::new(true)), 0)]
+    #[case::indented("a long line", 6, Some(&Wrap::new(true).initial_indent("- ")), 3)]
+    fn height(
+        #[case] text: &str,
+        #[case] width: u16,
+        #[case] wrap: Option<&Wrap>,
+        #[case] expected: usize,
+    ) {
+        assert_eq!(super::height(&Text::from(text), width, wrap), expected);
+    }
+
+    #[rstest]
+    #[case::start(0, Some((0, 0)))]
+    #[case::second_word(6, Some((0, 1)))]
+    #[case::inside_word(8, Some((2, 1)))]
+    #[case::trimmed_whitespace(5, Some((0, 1)))]
+    #[case::end_of_line(11, Some((5, 1)))]
+    #[case::empty_line(12, Some((0, 2)))]
+    #[case::wide_grapheme(16, Some((2, 3)))]
+    #[case::end_of_text(19, Some((4, 3)))]
+    #[case::past_end(20, None)]
+    fn cell_position(#[case] byte_offset: usize, #[case] expected: Option<(u16, u16)>) {
+        // "hello" and "world" are wrapped on different rows and the third line is empty
+        let text = Text::from("hello world\n\n你好");
+        assert_eq!(
+            super::cell_position(&text, 6, Some(&Wrap::new(true)), byte_offset),
+            expected.map(Position::from)
+        );
+    }
+
+    #[test]
+    fn cell_position_aligned() {
+        let text = Text::from(vec![
+            Line::from("ab").right_aligned(),
+            Line::from("cd").centered(),
+        ]);
+        assert_eq!(
+            super::cell_position(&text, 6, None, 1),
+            Some(Position::new(5, 0))
+        );
+        assert_eq!(
+            super::cell_position(&text, 6, None, 3),
+            Some(Position::new(2, 1))
+        );
+    }
+
+    #[test]
+    fn cell_position_styled_spans() {
+        let text = Text::from(Line::from(vec![
+            Span::styled("ab", Style::new().red()),
+            Span::raw("cd"),
+        ]));
+        assert_eq!(
+            super::cell_position(&text, 3, Some(&Wrap::new(false)), 3),
+            Some(Position::new(0, 1))
+        );
+    }
+}
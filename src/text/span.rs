@@ -95,6 +95,8 @@ pub struct Span<'a> {
     pub content: Cow<'a, str>,
     /// The style of the span.
     pub style: Style,
+    /// The target of the hyperlink (OSC 8) of the span, if any.
+    pub hyperlink: Option<Cow<'a, str>>,
 }
 
 impl<'a> Span<'a> {
@@ -114,6 +116,7 @@ impl<'a> Span<'a> {
         Self {
             content: content.into(),
             style: Style::default(),
+            hyperlink: None,
         }
     }
 
@@ -141,6 +144,7 @@ impl<'a> Span<'a> {
         Self {
             content: content.into(),
             style: style.into(),
+            hyperlink: None,
         }
     }
 
@@ -188,6 +192,41 @@ impl<'a> Span<'a> {
         self
     }
 
+    /// Sets the target of the hyperlink of the span.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// When rendered, the cells of the span are linked to the given URL. Backends that support
+    /// hyperlinks (OSC 8) make the text clickable in the terminal, others render the span as
+    /// regular text.
+    ///
+    /// Accepts any type that can be converted to [`Cow<str>`] (e.g. `&str`, `String`, `&String`,
+    /// etc.). Control characters are removed from the URL, as they could end the escape sequence
+    /// of the hyperlink early and inject other escape sequences.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::prelude::*;
+    /// let span = Span::raw("ratatui")
+    ///     .underlined()
+    ///     .hyperlink("https://ratatui.rs");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn hyperlink<T>(mut self, url: T) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        let url = url.into();
+        let url = if url.contains(char::is_control) {
+            Cow::Owned(url.chars().filter(|c| !c.is_control()).collect())
+        } else {
+            url
+        };
+        self.hyperlink = Some(url);
+        self
+    }
+
     /// Patches the style of the Span, adding modifiers from the given style.
     ///
     /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
@@ -269,11 +308,16 @@ impl<'a> Span<'a> {
         base_style: S,
     ) -> impl Iterator<Item = StyledGrapheme<'a>> {
         let style = base_style.into().patch(self.style);
+        let hyperlink = self.hyperlink.as_deref();
         self.content
             .as_ref()
             .graphemes(true)
             .filter(|g| *g != "\n")
-            .map(move |g| StyledGrapheme { symbol: g, style })
+            .map(move |g| StyledGrapheme {
+                symbol: g,
+                style,
+                hyperlink,
+            })
     }
 
     /// Converts this Span into a left-aligned [`Line`]
@@ -379,7 +423,8 @@ impl WidgetRef for Span<'_> {
             }
             buf.get_mut(current_x, y)
                 .set_symbol(g.symbol)
-                .set_style(g.style);
+                .set_style(g.style)
+                .set_hyperlink(g.hyperlink);
 
             // multi-width graphemes must clear the cells of characters that are hidden by the
             // grapheme, otherwise the hidden characters will be re-rendered if the grapheme is
@@ -565,6 +610,7 @@ mod tests {
         use rstest::rstest;
 
         use super::*;
+        use crate::buffer::Cell;
 
         #[test]
         fn render() {
@@ -579,6 +625,29 @@ mod tests {
             assert_eq!(buf, expected);
         }
 
+        #[test]
+        fn render_hyperlink() {
+            let span = Span::raw("abc").hyperlink("https://ratatui.rs");
+            let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+            span.render(buf.area, &mut buf);
+            let hyperlinks: Vec<_> = buf.content.iter().map(Cell::hyperlink).collect();
+            let link = Some("https://ratatui.rs");
+            assert_eq!(hyperlinks, [link, link, link, None, None]);
+        }
+
+        #[test]
+        fn hyperlink_strips_control_characters() {
+            let span = Span::raw("abc").hyperlink("https://ratatui.rs\x1b\\\x1b[2J\x07");
+            assert_eq!(span.hyperlink.as_deref(), Some("https://ratatui.rs\\[2J"));
+
+            // the cells are sanitized too when the field is set directly
+            let mut span = Span::raw("a");
+            span.hyperlink = Some("https://ratatui.rs\x1b".into());
+            let mut buf = Buffer::empty(Rect::new(0, 0, 1, 1));
+            span.render(buf.area, &mut buf);
+            assert_eq!(buf.get(0, 0).hyperlink(), Some("https://ratatui.rs"));
+        }
+
         #[rstest]
         fn render_out_of_bounds(mut small_buf: Buffer) {
             let out_of_bounds = Rect::new(20, 20, 10, 1);
@@ -1,5 +1,5 @@
 #![warn(missing_docs)]
-use std::{borrow::Cow, fmt};
+use std::{borrow::Cow, fmt, ops::Range};
 
 use itertools::{Itertools, Position};
 
@@ -158,7 +158,7 @@ use crate::prelude::*;
 /// # fn render(area: Rect, buf: &mut Buffer) {
 /// let text = Text::from("The first line\nThe second line");
 /// let paragraph = Paragraph::new(text)
-///     .wrap(Wrap { trim: true })
+///     .wrap(Wrap::new(true))
 ///     .scroll((1, 1))
 ///     .render(area, buf);
 /// # }
@@ -303,6 +303,52 @@ impl<'a> Text<'a> {
         self
     }
 
+    /// Patches the style of a range of the content of the Text, splitting spans as needed.
+    ///
+    /// `range` is a range of byte offsets in the content of the text, where the lines are
+    /// separated by a newline character as in the string of the text (see
+    /// [`Text::to_string`](ToString::to_string)). The range can span multiple lines. See
+    /// [`Line::patch_style_range`] for more details.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::prelude::*;
+    /// let text = Text::from("The first line\nThe second line");
+    /// let query = "line";
+    /// let matches = text
+    ///     .to_string()
+    ///     .match_indices(query)
+    ///     .map(|(start, _)| start)
+    ///     .collect::<Vec<_>>();
+    /// let text = matches.into_iter().fold(text, |text, start| {
+    ///     text.patch_style_range(start..start + query.len(), Modifier::REVERSED)
+    /// });
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn patch_style_range<S: Into<Style>>(mut self, range: Range<usize>, style: S) -> Self {
+        let style = style.into();
+        let mut offset = 0;
+        for line in &mut self.lines {
+            let len = line
+                .spans
+                .iter()
+                .map(|span| span.content.len())
+                .sum::<usize>();
+            let start = range.start.saturating_sub(offset);
+            let end = range.end.saturating_sub(offset).min(len);
+            line.patch_range(start..end, style);
+            // the newline separating the lines
+            offset += len + 1;
+        }
+        self
+    }
+
     /// Resets the style of the Text.
     ///
     /// Equivalent to calling [`patch_style(Style::reset())`](Text::patch_style).
@@ -693,6 +739,23 @@ mod tests {
         assert_eq!(text, expected_text);
     }
 
+    #[test]
+    fn patch_style_range() {
+        let text = Text::from(vec![
+            Line::from("first"),
+            Line::from(vec!["sec".blue(), "ond".into()]),
+        ]);
+        // `st\nse` spans the end of the first line and the start of the second one
+        let text = text.patch_style_range(3..8, Modifier::BOLD);
+        assert_eq!(
+            text,
+            Text::from(vec![
+                Line::from(vec!["fir".into(), "st".bold()]),
+                Line::from(vec!["se".blue().bold(), "c".blue(), "ond".into()]),
+            ])
+        );
+    }
+
     #[test]
     fn reset_style() {
         let style = Style::new().yellow().italic();
@@ -7,50 +7,125 @@
 //! The available widgets are:
 //! - [`Block`]: a basic widget that draws a block with optional borders, titles and styles.
 //! - [`BarChart`]: displays multiple datasets as bars with optional grouping.
-//! - [`calendar::Monthly`]: displays a single month.
+//! - [`Button`]: displays a button that can be activated with the keyboard or the mouse.
+//! - [`calendar::Monthly`]: displays a single month, and [`calendar::Quarterly`] and
+//!   [`calendar::Yearly`] display a grid of months.
+//! - [`Cached`]: reuses the previous rendering of a widget while its inputs are unchanged.
 //! - [`Canvas`]: draws arbitrary shapes using drawing characters.
 //! - [`Chart`]: displays multiple datasets as a lines or scatter graph.
+//! - [`Checkbox`]: displays a checkbox with a label.
 //! - [`Clear`]: clears the area it occupies. Useful to render over previously drawn widgets.
+//! - [`Collapsible`]: displays a section with a header that expands or collapses its content.
+//! - [`Dialog`]: displays a message with a row of buttons in a centered box, e.g. to confirm.
+//! - [`DiffView`]: displays the differences between two texts, unified or side by side.
+//! - [`Document`]: displays rich text like a help page, with links that can be followed.
+//! - [`FileExplorer`]: displays a navigable directory listing to choose a file.
+//! - [`form::Form`]: lays out labeled inputs, checkboxes and selects with focus traversal.
 //! - [`Gauge`]: displays progress percentage using block characters.
+//! - [`Graph`]: plots the latest values of a time series with Braille patterns.
+//! - [`HexView`]: displays bytes as offset, hexadecimal and ASCII columns with a cursor.
+//! - [`Image`]: displays an image with half blocks or a terminal graphics protocol.
+//! - [`Input`]: a single-line text input with cursor and selection handling.
+//! - [`KeyHints`]: displays a bar of key bindings with their descriptions.
 //! - [`LineGauge`]: display progress as a line.
 //! - [`List`]: displays a list of items and allows selection.
+//! - [`LogView`]: displays log records with level filtering, search and a follow mode.
 //! - [`Paragraph`]: displays a paragraph of optionally styled and wrapped text.
+//! - [`Popup`]: renders a widget in a centered box on top of other widgets.
+//! - [`ProgressGroup`]: displays a group of labeled progress bars.
+//! - [`RadioGroup`]: displays a group of options of which one can be selected.
+//! - [`ScrollView`]: displays a scrollable window over content larger than its area.
 //! - [`Scrollbar`]: displays a scrollbar.
+//! - [`Select`]: displays the chosen option and a popup list with type-ahead filtering.
 //! - [`Sparkline`]: display a single data set as a sparkline.
+//! - [`Spinner`]: displays an animated loading indicator.
+//! - [`Switch`]: displays an on/off switch with a label.
 //! - [`Table`]: displays multiple rows and columns in a grid and allows selection.
 //! - [`Tabs`]: displays a tab bar and allows selection.
+//! - [`TextArea`]: a multi-line text editor with soft wrapping.
+//! - [`Toasts`]: displays stacked notifications that expire automatically.
+//! - [`Tree`]: displays hierarchical data with expandable nodes and allows selection.
 //!
 //! [`Canvas`]: crate::widgets::canvas::Canvas
 mod barchart;
 pub mod block;
 mod borders;
+mod button;
+mod cached;
 #[cfg(feature = "widget-calendar")]
 pub mod calendar;
 pub mod canvas;
 mod chart;
 mod clear;
+mod collapsible;
+mod dialog;
+mod diff_view;
+mod document;
+#[cfg(feature = "widget-file-explorer")]
+mod file_explorer;
+pub mod form;
 mod gauge;
+mod graph;
+mod hex_view;
+mod image;
+pub mod input;
+mod key_hints;
 mod list;
+mod log_view;
 mod paragraph;
-mod reflow;
+mod popup;
+mod progress_group;
+pub(crate) mod reflow;
+mod scroll_view;
 mod scrollbar;
+mod select;
 mod sparkline;
+mod spinner;
 mod table;
 mod tabs;
-
+mod text_area;
+mod toasts;
+mod toggle;
+mod tree;
+
+#[cfg(feature = "widget-file-explorer")]
+pub use self::file_explorer::{FileEntry, FileExplorer, FileExplorerState, FileSort};
+#[cfg(feature = "log")]
+pub use self::log_view::LogViewLogger;
 pub use self::{
     barchart::{Bar, BarChart, BarGroup},
     block::{Block, BorderType, Padding},
     borders::*,
-    chart::{Axis, Chart, Dataset, GraphType, LegendPosition},
+    button::{Button, ButtonState},
+    cached::{Cached, CachedState},
+    chart::{Axis, Chart, ChartResolution, ChartState, Dataset, GraphType, LegendPosition, Scale},
     clear::Clear,
+    collapsible::{Collapsible, CollapsibleState},
+    dialog::{Dialog, DialogButton, DialogState},
+    diff_view::{Diff, DiffHunk, DiffLine, DiffLineKind, DiffMode, DiffView, DiffViewState},
+    document::{Document, DocumentSpan, DocumentState},
     gauge::{Gauge, LineGauge},
+    graph::{Graph, GraphState},
+    hex_view::{HexView, HexViewState},
+    image::Image,
+    input::{Input, InputState},
+    key_hints::{KeyHint, KeyHints},
     list::{List, ListDirection, ListItem, ListState},
-    paragraph::{Paragraph, Wrap},
+    log_view::{LogLevel, LogRecord, LogView, LogViewState},
+    paragraph::{Paragraph, ParagraphState, Wrap},
+    popup::Popup,
+    progress_group::{ProgressGroup, ProgressItem, ProgressState},
+    scroll_view::{ScrollView, ScrollViewState, ScrollbarVisibility},
     scrollbar::{ScrollDirection, Scrollbar, ScrollbarOrientation, ScrollbarState},
-    sparkline::{RenderDirection, Sparkline},
-    table::{Cell, HighlightSpacing, Row, Table, TableState},
-    tabs::Tabs,
+    select::{Select, SelectState},
+    sparkline::{RenderDirection, Sparkline, SparklineBar},
+    spinner::Spinner,
+    table::{Cell, HighlightSpacing, Row, SortDirection, Table, TableState},
+    tabs::{Tabs, TabsState},
+    text_area::{TextArea, TextAreaState},
+    toasts::{ToastLevel, ToastPosition, ToastState, ToastTtl, Toasts},
+    toggle::{Checkbox, CheckboxState, RadioGroup, RadioGroupState, Switch, SwitchState},
+    tree::{Tree, TreeItem, TreeState},
 };
 use crate::{buffer::Buffer, layout::Rect};
 
@@ -81,6 +81,9 @@ pub struct BarChart<'a> {
     /// Value necessary for a bar to reach the maximum height (if no value is specified,
     /// the maximum value in the data is taken as reference)
     max: Option<u64>,
+    /// Value necessary for a negative bar to reach the bottom (if no value is specified,
+    /// the minimum value in the data is taken as reference)
+    min: Option<i64>,
     /// direction of the bars
     direction: Direction,
 }
@@ -90,6 +93,7 @@ impl<'a> Default for BarChart<'a> {
         Self {
             block: None,
             max: None,
+            min: None,
             data: Vec::new(),
             bar_style: Style::default(),
             bar_width: 1,
@@ -169,6 +173,44 @@ impl<'a> BarChart<'a> {
         self
     }
 
+    /// Set the value necessary for a negative [`Bar`] to reach the bottom of the chart.
+    ///
+    /// If not set, the minimum value in the data is taken as reference.
+    ///
+    /// When the chart has negative values (see [`Bar::signed_value`]), a horizontal line is drawn
+    /// at zero. Positive bars grow up from this baseline and negative bars grow down from it. The
+    /// baseline is placed so that the space above and below it is proportional to the
+    /// [maximum](BarChart::max) and the minimum values. Setting a minimum of `0` hides the
+    /// negative bars.
+    ///
+    /// Negative bars are only drawn with [`Vertical`](crate::layout::Direction::Vertical) bars,
+    /// horizontal negative bars are empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let chart = BarChart::default()
+    ///     .data(BarGroup::default().bars(&[
+    ///         Bar::default().signed_value(3),
+    ///         Bar::default().signed_value(-2),
+    ///     ]))
+    ///     .min(-4);
+    /// // Renders in a 3x7 area
+    /// // █
+    /// // █
+    /// // 3
+    /// // ───
+    /// //   █
+    /// //   ▀
+    /// //
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn min(mut self, min: i64) -> Self {
+        self.min = Some(min);
+        self
+    }
+
     /// Set the default style of the bar.
     ///
     /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
@@ -284,6 +326,9 @@ impl<'a> BarChart<'a> {
     /// Set the direction of the bars.
     ///
     /// [`Vertical`](crate::layout::Direction::Vertical) bars are the default.
+    /// [`Horizontal`](crate::layout::Direction::Horizontal) bars grow from left to right, with the
+    /// bar labels on their left and the group labels below each group. Both directions use the
+    /// same grouping, gaps and styles.
     ///
     /// # Examples
     ///
@@ -318,8 +363,23 @@ impl BarChart<'_> {
     /// Returns the visible bars length in ticks. A cell contains 8 ticks.
     /// `available_space` used to calculate how many bars can fit in the space
     /// `bar_max_length` is the maximal length a bar can take.
+    /// Only the positive bars are measured, or only the negative bars if `negative` is true, the
+    /// other bars have no ticks.
     fn group_ticks(&self, available_space: u16, bar_max_length: u16) -> Vec<Vec<u64>> {
-        let max: u64 = self.maximum_data_value();
+        self.side_ticks(available_space, bar_max_length, false)
+    }
+
+    fn side_ticks(
+        &self,
+        available_space: u16,
+        bar_max_length: u16,
+        negative: bool,
+    ) -> Vec<Vec<u64>> {
+        let max: u64 = if negative {
+            self.minimum_data_value()
+        } else {
+            self.maximum_data_value()
+        };
         self.data
             .iter()
             .scan(available_space, |space, group| {
@@ -347,7 +407,13 @@ impl BarChart<'_> {
                         .bars
                         .iter()
                         .take(n as usize)
-                        .map(|bar| bar.value * u64::from(bar_max_length) * 8 / max)
+                        .map(|bar| {
+                            if bar.negative == negative {
+                                bar.value * u64::from(bar_max_length) * 8 / max
+                            } else {
+                                0
+                            }
+                        })
                         .collect()
                 })
             })
@@ -444,6 +510,13 @@ impl BarChart<'_> {
 
                 // label
                 if let Some(label) = &bar.label {
+                    let label_area = Rect {
+                        x: label_x,
+                        y: bar_value_area.top(),
+                        width: (label.width() as u16).min(label_size),
+                        height: 1,
+                    };
+                    buf.set_style(label_area, self.label_style);
                     buf.set_line(label_x, bar_value_area.top(), label, label_size);
                 }
 
@@ -480,9 +553,97 @@ impl BarChart<'_> {
             ..area
         };
 
+        if self.has_negative_bars() {
+            return self.render_vertical_with_baseline(area, buf, bars_area, label_info);
+        }
+
         let group_ticks = self.group_ticks(bars_area.width, bars_area.height);
         self.render_vertical_bars(bars_area, buf, &group_ticks);
-        self.render_labels_and_values(area, buf, label_info, &group_ticks);
+        self.render_labels_and_values(area, buf, label_info, &group_ticks, None);
+    }
+
+    /// Whether the chart has room for negative bars below a baseline
+    fn has_negative_bars(&self) -> bool {
+        match self.min {
+            Some(min) => min < 0,
+            None => self.data.iter().any(|group| group.max_negative().is_some()),
+        }
+    }
+
+    /// Renders vertical bars with positive bars above a baseline and negative bars below it
+    fn render_vertical_with_baseline(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        bars_area: Rect,
+        label_info: LabelInfo,
+    ) {
+        let available_height = bars_area.height.saturating_sub(1);
+        let positive = self.max.unwrap_or_else(|| {
+            self.data
+                .iter()
+                .filter_map(BarGroup::max)
+                .max()
+                .unwrap_or_default()
+        });
+        let negative = self.minimum_data_value();
+        let negative_height =
+            (u64::from(available_height) * negative / (positive + negative)) as u16;
+        let positive_area = Rect {
+            height: available_height - negative_height,
+            ..bars_area
+        };
+        let baseline = positive_area.bottom();
+        let negative_area = Rect {
+            y: baseline + 1,
+            height: negative_height,
+            ..bars_area
+        };
+
+        let positive_ticks = self.group_ticks(bars_area.width, positive_area.height);
+        let negative_ticks = self.side_ticks(bars_area.width, negative_area.height, true);
+        self.render_vertical_bars(positive_area, buf, &positive_ticks);
+        self.render_negative_bars(negative_area, buf, &negative_ticks);
+        for x in bars_area.left()..bars_area.right() {
+            buf.get_mut(x, baseline)
+                .set_symbol(symbols::line::HORIZONTAL);
+        }
+
+        let ticks: Vec<Vec<u64>> = positive_ticks
+            .iter()
+            .zip(&negative_ticks)
+            .map(|(positive, negative)| positive.iter().zip(negative).map(|(p, n)| p + n).collect())
+            .collect();
+        self.render_labels_and_values(area, buf, label_info, &ticks, Some(baseline));
+    }
+
+    /// Renders the negative bars, growing down from the top of the area
+    fn render_negative_bars(&self, area: Rect, buf: &mut Buffer, group_ticks: &[Vec<u64>]) {
+        let mut bar_x = area.left();
+        for (ticks_vec, group) in group_ticks.iter().zip(&self.data) {
+            for (ticks, bar) in ticks_vec.iter().zip(&group.bars) {
+                let mut ticks = *ticks;
+                let bar_style = self.bar_style.patch(bar.style);
+                for j in 0..area.height {
+                    // there are no block characters for the top eighths of a cell other than the
+                    // top eighth and the top half
+                    let symbol = match ticks {
+                        0 => self.bar_set.empty,
+                        1..=3 => symbols::border::ONE_EIGHTH_TOP_EIGHT,
+                        4..=7 => symbols::border::QUADRANT_TOP_HALF,
+                        _ => self.bar_set.full,
+                    };
+                    for x in 0..self.bar_width {
+                        buf.get_mut(bar_x + x, area.top() + j)
+                            .set_symbol(symbol)
+                            .set_style(bar_style);
+                    }
+                    ticks = ticks.saturating_sub(8);
+                }
+                bar_x += self.bar_gap + self.bar_width;
+            }
+            bar_x += self.group_gap;
+        }
     }
 
     fn render_vertical_bars(&self, area: Rect, buf: &mut Buffer, group_ticks: &[Vec<u64>]) {
@@ -520,6 +681,23 @@ impl BarChart<'_> {
         }
     }
 
+    /// get the magnitude of the minimum negative data value. the returned value is always greater
+    /// equal 1
+    fn minimum_data_value(&self) -> u64 {
+        self.min
+            .map_or_else(
+                || {
+                    self.data
+                        .iter()
+                        .filter_map(BarGroup::max_negative)
+                        .max()
+                        .unwrap_or_default()
+                },
+                |min| min.min(0).unsigned_abs(),
+            )
+            .max(1)
+    }
+
     /// get the maximum data value. the returned value is always greater equal 1
     fn maximum_data_value(&self) -> u64 {
         self.max
@@ -539,10 +717,17 @@ impl BarChart<'_> {
         buf: &mut Buffer,
         label_info: LabelInfo,
         group_ticks: &[Vec<u64>],
+        baseline: Option<u16>,
     ) {
         // print labels and values in one go
         let mut bar_x = area.left();
         let bar_y = area.bottom() - label_info.height - 1;
+        // with a baseline, values are printed next to it, on the side of the bar
+        let value_y = |bar: &Bar| match baseline {
+            Some(y) if bar.negative => y + 1,
+            Some(y) => y.saturating_sub(1).max(area.top()),
+            None => bar_y,
+        };
         for (group, ticks_vec) in self.data.iter().zip(group_ticks) {
             if group.bars.is_empty() {
                 continue;
@@ -566,7 +751,10 @@ impl BarChart<'_> {
                     bar.render_label(buf, self.bar_width, bar_x, bar_y + 1, self.label_style);
                 }
 
-                bar.render_value(buf, self.bar_width, bar_x, bar_y, self.value_style, *ticks);
+                let y = value_y(bar);
+                if y <= bar_y {
+                    bar.render_value(buf, self.bar_width, bar_x, y, self.value_style, *ticks);
+                }
 
                 bar_x += self.bar_gap + self.bar_width;
             }
@@ -1012,6 +1200,68 @@ mod tests {
         assert_eq!(buffer, expected);
     }
 
+    #[test]
+    fn test_horizontal_label_style() {
+        let chart = BarChart::default()
+            .direction(Direction::Horizontal)
+            .bar_gap(0)
+            .label_style(Style::new().bold())
+            .data(&[("Jan", 10), ("Feb", 20)]);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 2));
+        chart.render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["Jan 10█   ", "Feb 20████"]);
+        expected.set_style(Rect::new(0, 0, 3, 2), Style::new().bold());
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn negative_values() {
+        let chart = BarChart::default()
+            .data(BarGroup::default().bars(&[
+                Bar::default().signed_value(3).label("a".into()),
+                Bar::default().signed_value(-2).label("b".into()),
+            ]))
+            .bar_width(2)
+            .min(-4);
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 8));
+        chart.render(buffer.area, &mut buffer);
+        let expected = Buffer::with_lines([
+            "██   ",
+            "██   ",
+            "3█   ",
+            "─────",
+            "   -2",
+            "   ▀▀",
+            "     ",
+            "a  b ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn negative_values_without_min() {
+        let chart = BarChart::default().data(BarGroup::default().bars(&[
+            Bar::default().signed_value(-4),
+            Bar::default().signed_value(2),
+            Bar::default().value(1),
+        ]));
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 7));
+        chart.render(buffer.area, &mut buffer);
+        let expected = Buffer::with_lines([
+            "  █  ",
+            "  2 1",
+            "─────",
+            "█    ",
+            "█    ",
+            "█    ",
+            "█    ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
     #[test]
     fn test_group_label_style() {
         let chart: BarChart<'_> = BarChart::default()
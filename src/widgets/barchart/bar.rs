@@ -30,6 +30,8 @@ use crate::prelude::*;
 pub struct Bar<'a> {
     /// Value to display on the bar (computed when the data is passed to the widget)
     pub(super) value: u64,
+    /// Whether the value is negative, in which case `value` is its magnitude
+    pub(super) negative: bool,
     /// optional label to be printed under the bar
     pub(super) label: Option<Line<'a>>,
     /// style for the bar
@@ -52,6 +54,23 @@ impl<'a> Bar<'a> {
     #[must_use = "method moves the value of self and returns the modified value"]
     pub const fn value(mut self, value: u64) -> Self {
         self.value = value;
+        self.negative = false;
+        self
+    }
+
+    /// Set the value of this bar, which can be negative.
+    ///
+    /// Negative bars are drawn below the baseline of a vertical
+    /// [`BarChart`](crate::widgets::BarChart), see
+    /// [`BarChart::min`](crate::widgets::BarChart::min).
+    ///
+    /// # See also
+    ///
+    /// [`Bar::value`] to set a positive value.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn signed_value(mut self, value: i64) -> Self {
+        self.value = value.unsigned_abs();
+        self.negative = value < 0;
         self
     }
 
@@ -108,6 +127,15 @@ impl<'a> Bar<'a> {
         self
     }
 
+    /// The value as a string, with a minus sign if it is negative
+    fn value_string(&self) -> String {
+        if self.negative {
+            format!("-{}", self.value)
+        } else {
+            self.value.to_string()
+        }
+    }
+
     /// Render the value of the bar.
     ///
     /// [`text_value`](Bar::text_value) is used if set, otherwise the value is converted to string.
@@ -122,7 +150,7 @@ impl<'a> Bar<'a> {
         default_value_style: Style,
         bar_style: Style,
     ) {
-        let value = self.value.to_string();
+        let value = self.value_string();
         let text = self.text_value.as_ref().unwrap_or(&value);
 
         if !text.is_empty() {
@@ -157,7 +185,7 @@ impl<'a> Bar<'a> {
     ) {
         if self.value != 0 {
             const TICKS_PER_LINE: u64 = 8;
-            let value = self.value.to_string();
+            let value = self.value_string();
             let value_label = self.text_value.as_ref().unwrap_or(&value);
             let width = value_label.width() as u16;
             // if we have enough space or the ticks are greater equal than 1 cell (8)
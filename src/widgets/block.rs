@@ -5,10 +5,12 @@
 //! In its simplest form, a `Block` is a [border](Borders) around another widget. It can have a
 //! [title](Block::title) and [padding](Block::padding).
 
+use std::ops::Range;
+
 use itertools::Itertools;
 use strum::{Display, EnumString};
 
-use crate::{prelude::*, symbols::border, widgets::Borders};
+use crate::{prelude::*, style::Gradient, symbols::border, widgets::Borders};
 
 mod padding;
 pub mod title;
@@ -79,8 +81,12 @@ pub struct Block<'a> {
     border_set: border::Set,
     /// Widget style
     style: Style,
+    /// The gradient of the background of the block
+    bg_gradient: Option<Gradient>,
     /// Block padding
     padding: Padding,
+    /// Whether the borders are joined with the borders already in the buffer
+    merge_borders: bool,
 }
 
 /// The type of border of a [`Block`].
@@ -168,7 +174,9 @@ impl<'a> Block<'a> {
             border_style: Style::new(),
             border_set: BorderType::Plain.to_border_set(),
             style: Style::new(),
+            bg_gradient: None,
             padding: Padding::zero(),
+            merge_borders: false,
         }
     }
 
@@ -406,6 +414,26 @@ impl<'a> Block<'a> {
         self
     }
 
+    /// Fills the background of the block with a gradient.
+    ///
+    /// The background of each cell of the block, including its borders, is set to the color of
+    /// the [`Gradient`] at the position of the cell, across the width or the height of the block
+    /// depending on the direction of the gradient. This overrides the background of
+    /// [`Block::style`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, style::Gradient, widgets::*};
+    /// let gradient = Gradient::new([Color::Rgb(20, 20, 60), Color::Rgb(60, 20, 60)]);
+    /// Block::bordered().bg_gradient(gradient.direction(Direction::Vertical));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn bg_gradient(mut self, gradient: Gradient) -> Self {
+        self.bg_gradient = Some(gradient);
+        self
+    }
+
     /// Defines which borders to display.
     ///
     /// [`Borders`] can also be styled with [`Block::border_style`] and [`Block::border_type`].
@@ -495,7 +523,7 @@ impl<'a> Block<'a> {
     /// ```
     pub fn inner(&self, area: Rect) -> Rect {
         let mut inner = area;
-        if self.borders.intersects(Borders::LEFT) {
+        if self.borders.intersects(Borders::LEFT) || self.has_title_at_position(Position::Left) {
             inner.x = inner.x.saturating_add(1).min(inner.right());
             inner.width = inner.width.saturating_sub(1);
         }
@@ -503,7 +531,7 @@ impl<'a> Block<'a> {
             inner.y = inner.y.saturating_add(1).min(inner.bottom());
             inner.height = inner.height.saturating_sub(1);
         }
-        if self.borders.intersects(Borders::RIGHT) {
+        if self.borders.intersects(Borders::RIGHT) || self.has_title_at_position(Position::Right) {
             inner.width = inner.width.saturating_sub(1);
         }
         if self.borders.intersects(Borders::BOTTOM) || self.has_title_at_position(Position::Bottom)
@@ -561,6 +589,38 @@ impl<'a> Block<'a> {
         self.padding = padding;
         self
     }
+
+    /// Joins the borders of the block with the borders already rendered in the buffer.
+    ///
+    /// When enabled, a border symbol drawn over the border of another block is replaced by the
+    /// symbol that connects both, using the corners and the joints (`top_joint`, `left_joint`,
+    /// `cross`, ...) of the [`border_set`](Block::border_set). Blocks that share an edge then look
+    /// like a single frame. The existing symbols are recognized if they belong to the border set of
+    /// this block or to one of the line-based sets ([`border::PLAIN`], [`border::ROUNDED`],
+    /// [`border::DOUBLE`] and [`border::THICK`]). Other symbols, such as titles, are overwritten
+    /// as usual.
+    ///
+    /// # Example
+    ///
+    /// Two blocks sharing their vertical edge:
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # fn render(buf: &mut Buffer) {
+    /// let left = Rect::new(0, 0, 5, 3);
+    /// let right = Rect::new(4, 0, 5, 3);
+    /// Block::bordered().merge_borders(true).render(left, buf);
+    /// Block::bordered().merge_borders(true).render(right, buf);
+    /// // Renders
+    /// // ┌───┬───┐
+    /// // │   │   │
+    /// // └───┴───┘
+    /// # }
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn merge_borders(mut self, merge_borders: bool) -> Self {
+        self.merge_borders = merge_borders;
+        self
+    }
 }
 
 impl BorderType {
@@ -595,12 +655,26 @@ impl WidgetRef for Block<'_> {
             return;
         }
         buf.set_style(area, self.style);
+        self.render_bg_gradient(area, buf);
         self.render_borders(area, buf);
         self.render_titles(area, buf);
     }
 }
 
 impl Block<'_> {
+    fn render_bg_gradient(&self, area: Rect, buf: &mut Buffer) {
+        let Some(gradient) = &self.bg_gradient else {
+            return;
+        };
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                let position = (x - area.left(), y - area.top());
+                let color = gradient.color_in_area(position, (area.width, area.height));
+                buf.get_mut(x, y).set_bg(color);
+            }
+        }
+    }
+
     fn render_borders(&self, area: Rect, buf: &mut Buffer) {
         self.render_left_side(area, buf);
         self.render_top_side(area, buf);
@@ -616,6 +690,8 @@ impl Block<'_> {
     fn render_titles(&self, area: Rect, buf: &mut Buffer) {
         self.render_title_position(Position::Top, area, buf);
         self.render_title_position(Position::Bottom, area, buf);
+        self.render_vertical_titles(Position::Left, area, buf);
+        self.render_vertical_titles(Position::Right, area, buf);
     }
 
     fn render_title_position(&self, position: Position, area: Rect, buf: &mut Buffer) {
@@ -623,24 +699,24 @@ impl Block<'_> {
         self.render_right_titles(position, area, buf);
         self.render_center_titles(position, area, buf);
         self.render_left_titles(position, area, buf);
+        self.render_offset_titles(position, area, buf);
     }
 
+    // The sides leave out the corners, which are rendered separately, so that each cell of the
+    // border is rendered once and only joined with the borders of other blocks.
+
     fn render_left_side(&self, area: Rect, buf: &mut Buffer) {
         if self.borders.contains(Borders::LEFT) {
-            for y in area.top()..area.bottom() {
-                buf.get_mut(area.left(), y)
-                    .set_symbol(self.border_set.vertical_left)
-                    .set_style(self.border_style);
+            for y in self.vertical_side_range(area) {
+                self.set_border_symbol(buf, area.left(), y, self.border_set.vertical_left);
             }
         }
     }
 
     fn render_top_side(&self, area: Rect, buf: &mut Buffer) {
         if self.borders.contains(Borders::TOP) {
-            for x in area.left()..area.right() {
-                buf.get_mut(x, area.top())
-                    .set_symbol(self.border_set.horizontal_top)
-                    .set_style(self.border_style);
+            for x in self.horizontal_side_range(area) {
+                self.set_border_symbol(buf, x, area.top(), self.border_set.horizontal_top);
             }
         }
     }
@@ -648,10 +724,8 @@ impl Block<'_> {
     fn render_right_side(&self, area: Rect, buf: &mut Buffer) {
         if self.borders.contains(Borders::RIGHT) {
             let x = area.right() - 1;
-            for y in area.top()..area.bottom() {
-                buf.get_mut(x, y)
-                    .set_symbol(self.border_set.vertical_right)
-                    .set_style(self.border_style);
+            for y in self.vertical_side_range(area) {
+                self.set_border_symbol(buf, x, y, self.border_set.vertical_right);
             }
         }
     }
@@ -659,46 +733,72 @@ impl Block<'_> {
     fn render_bottom_side(&self, area: Rect, buf: &mut Buffer) {
         if self.borders.contains(Borders::BOTTOM) {
             let y = area.bottom() - 1;
-            for x in area.left()..area.right() {
-                buf.get_mut(x, y)
-                    .set_symbol(self.border_set.horizontal_bottom)
-                    .set_style(self.border_style);
+            for x in self.horizontal_side_range(area) {
+                self.set_border_symbol(buf, x, y, self.border_set.horizontal_bottom);
             }
         }
     }
 
+    /// The columns of the top and bottom sides, without the corners
+    fn horizontal_side_range(&self, area: Rect) -> Range<u16> {
+        let left = area.left() + u16::from(self.borders.contains(Borders::LEFT));
+        let right = area.right() - u16::from(self.borders.contains(Borders::RIGHT));
+        left..right
+    }
+
+    /// The rows of the left and right sides, without the corners
+    fn vertical_side_range(&self, area: Rect) -> Range<u16> {
+        let top = area.top() + u16::from(self.borders.contains(Borders::TOP));
+        let bottom = area.bottom() - u16::from(self.borders.contains(Borders::BOTTOM));
+        top..bottom
+    }
+
     fn render_bottom_right_corner(&self, buf: &mut Buffer, area: Rect) {
         if self.borders.contains(Borders::RIGHT | Borders::BOTTOM) {
-            buf.get_mut(area.right() - 1, area.bottom() - 1)
-                .set_symbol(self.border_set.bottom_right)
-                .set_style(self.border_style);
+            self.set_border_symbol(
+                buf,
+                area.right() - 1,
+                area.bottom() - 1,
+                self.border_set.bottom_right,
+            );
         }
     }
 
     fn render_top_right_corner(&self, buf: &mut Buffer, area: Rect) {
         if self.borders.contains(Borders::RIGHT | Borders::TOP) {
-            buf.get_mut(area.right() - 1, area.top())
-                .set_symbol(self.border_set.top_right)
-                .set_style(self.border_style);
+            self.set_border_symbol(buf, area.right() - 1, area.top(), self.border_set.top_right);
         }
     }
 
     fn render_bottom_left_corner(&self, buf: &mut Buffer, area: Rect) {
         if self.borders.contains(Borders::LEFT | Borders::BOTTOM) {
-            buf.get_mut(area.left(), area.bottom() - 1)
-                .set_symbol(self.border_set.bottom_left)
-                .set_style(self.border_style);
+            self.set_border_symbol(
+                buf,
+                area.left(),
+                area.bottom() - 1,
+                self.border_set.bottom_left,
+            );
         }
     }
 
     fn render_top_left_corner(&self, buf: &mut Buffer, area: Rect) {
         if self.borders.contains(Borders::LEFT | Borders::TOP) {
-            buf.get_mut(area.left(), area.top())
-                .set_symbol(self.border_set.top_left)
-                .set_style(self.border_style);
+            self.set_border_symbol(buf, area.left(), area.top(), self.border_set.top_left);
         }
     }
 
+    /// Sets the symbol and the style of a cell of the border, joining the symbol with the border
+    /// already in the cell if [`Block::merge_borders`] is enabled.
+    fn set_border_symbol(&self, buf: &mut Buffer, x: u16, y: u16, symbol: &'static str) {
+        let cell = buf.get_mut(x, y);
+        let symbol = if self.merge_borders {
+            merge_border_symbol(&self.border_set, cell.symbol(), symbol)
+        } else {
+            symbol
+        };
+        cell.set_symbol(symbol).set_style(self.border_style);
+    }
+
     /// Render titles aligned to the right of the block
     ///
     /// Currently (due to the way lines are truncated), the right side of the leftmost title will
@@ -797,6 +897,80 @@ impl Block<'_> {
         }
     }
 
+    /// Render titles at their offset from the left of the block
+    #[allow(clippy::similar_names)]
+    fn render_offset_titles(&self, position: Position, area: Rect, buf: &mut Buffer) {
+        let titles_area = self.titles_area(area, position);
+        for (title, offset) in self.offset_titles(position) {
+            let x = titles_area
+                .x
+                .saturating_add(offset)
+                .min(titles_area.right());
+            let title_area = Rect {
+                x,
+                width: (title.content.width() as u16).min(titles_area.right() - x),
+                ..titles_area
+            };
+            if title_area.is_empty() {
+                continue;
+            }
+            buf.set_style(title_area, self.titles_style);
+            title.content.render_ref(title_area, buf);
+        }
+    }
+
+    /// Render the titles of the left or right side of the block, from top to bottom
+    fn render_vertical_titles(&self, position: Position, area: Rect, buf: &mut Buffer) {
+        let titles_area = self.vertical_titles_area(area, position);
+        if titles_area.is_empty() {
+            return;
+        }
+        // NOTE: the order of the alignments defines the overlapping behavior, as for the titles
+        // on top and at the bottom of the block
+        for alignment in [Alignment::Right, Alignment::Center, Alignment::Left] {
+            let titles = self.filtered_titles(position, alignment).collect_vec();
+            let total_height = titles
+                .iter()
+                .map(|title| vertical_title_height(title) + 1) // space between titles
+                .sum::<u16>()
+                .saturating_sub(1); // no space for the last title
+            let free_height = titles_area.height.saturating_sub(total_height);
+            let mut y = titles_area.top()
+                + match alignment {
+                    Alignment::Center => free_height / 2,
+                    Alignment::Right => free_height,
+                    Alignment::Left | Alignment::Justify => 0,
+                };
+            for title in titles {
+                self.render_vertical_title(title, y, titles_area, buf);
+                y = y.saturating_add(vertical_title_height(title) + 1);
+            }
+        }
+        for (title, offset) in self.offset_titles(position) {
+            let y = titles_area.top().saturating_add(offset);
+            self.render_vertical_title(title, y, titles_area, buf);
+        }
+    }
+
+    /// Render a title with one grapheme per row, starting at `y` and truncated to `titles_area`
+    fn render_vertical_title(&self, title: &Title, y: u16, titles_area: Rect, buf: &mut Buffer) {
+        let rows = (y..titles_area.bottom()).zip(title.content.styled_graphemes(Style::new()));
+        for (y, grapheme) in rows {
+            buf.get_mut(titles_area.x, y)
+                .set_style(self.titles_style)
+                .set_symbol(grapheme.symbol)
+                .set_style(grapheme.style);
+        }
+    }
+
+    /// An iterator over the titles of the position that have an offset, and their offset
+    fn offset_titles(&self, position: Position) -> impl Iterator<Item = (&Title<'_>, u16)> {
+        self.titles.iter().filter_map(move |title| {
+            let offset = title.offset?;
+            (title.position.unwrap_or(self.titles_position) == position).then_some((title, offset))
+        })
+    }
+
     /// An iterator over the titles that match the position and alignment
     fn filtered_titles(
         &self,
@@ -804,8 +978,14 @@ impl Block<'_> {
         alignment: Alignment,
     ) -> impl DoubleEndedIterator<Item = &Title> {
         self.titles.iter().filter(move |title| {
+            // titles are not wrapped, so justified titles are aligned to the left
+            let title_alignment = match title.alignment.unwrap_or(self.titles_alignment) {
+                Alignment::Justify => Alignment::Left,
+                title_alignment => title_alignment,
+            };
             title.position.unwrap_or(self.titles_position) == position
-                && title.alignment.unwrap_or(self.titles_alignment) == alignment
+                && title_alignment == alignment
+                && title.offset.is_none()
         })
     }
 
@@ -817,7 +997,7 @@ impl Block<'_> {
         Rect {
             x: area.left() + left_border,
             y: match position {
-                Position::Top => area.top(),
+                Position::Top | Position::Left | Position::Right => area.top(),
                 Position::Bottom => area.bottom() - 1,
             },
             width: area
@@ -827,6 +1007,30 @@ impl Block<'_> {
             height: 1,
         }
     }
+
+    /// An area that is one column wide and spans the height of the block excluding the borders
+    /// and is positioned on the left or right side of the block.
+    fn vertical_titles_area(&self, area: Rect, position: Position) -> Rect {
+        let top_border = u16::from(self.borders.contains(Borders::TOP));
+        let bottom_border = u16::from(self.borders.contains(Borders::BOTTOM));
+        Rect {
+            x: match position {
+                Position::Right => area.right() - 1,
+                Position::Top | Position::Bottom | Position::Left => area.left(),
+            },
+            y: area.top() + top_border,
+            width: 1,
+            height: area
+                .height
+                .saturating_sub(top_border)
+                .saturating_sub(bottom_border),
+        }
+    }
+}
+
+/// The number of rows of a title rendered vertically, which is its number of graphemes
+fn vertical_title_height(title: &Title) -> u16 {
+    title.content.styled_graphemes(Style::new()).count() as u16
 }
 
 /// An extension trait for [`Block`] that provides some convenience methods.
@@ -858,6 +1062,64 @@ impl<'a> Styled for Block<'a> {
     }
 }
 
+/// The directions in which a border symbol connects to the neighboring cells
+mod connection {
+    pub const UP: u8 = 0b0001;
+    pub const DOWN: u8 = 0b0010;
+    pub const LEFT: u8 = 0b0100;
+    pub const RIGHT: u8 = 0b1000;
+}
+
+/// The symbols of a border set and the directions in which they connect
+const fn border_connections(set: &border::Set) -> [(&'static str, u8); 13] {
+    use connection::{DOWN, LEFT, RIGHT, UP};
+    [
+        (set.vertical_left, UP | DOWN),
+        (set.vertical_right, UP | DOWN),
+        (set.horizontal_top, LEFT | RIGHT),
+        (set.horizontal_bottom, LEFT | RIGHT),
+        (set.top_left, DOWN | RIGHT),
+        (set.top_right, DOWN | LEFT),
+        (set.bottom_left, UP | RIGHT),
+        (set.bottom_right, UP | LEFT),
+        (set.top_joint, LEFT | RIGHT | DOWN),
+        (set.bottom_joint, LEFT | RIGHT | UP),
+        (set.left_joint, UP | DOWN | RIGHT),
+        (set.right_joint, UP | DOWN | LEFT),
+        (set.cross, UP | DOWN | LEFT | RIGHT),
+    ]
+}
+
+/// Returns the symbol of `set` that joins the `existing` border symbol of a cell with the `new`
+/// symbol, e.g. `┬` when drawing `┌` over `┐`.
+///
+/// The `new` symbol is returned unchanged if the existing symbol is not a known border symbol.
+fn merge_border_symbol(set: &border::Set, existing: &str, new: &'static str) -> &'static str {
+    let connections_of = |symbol: &str| {
+        [
+            *set,
+            border::PLAIN,
+            border::ROUNDED,
+            border::DOUBLE,
+            border::THICK,
+        ]
+        .iter()
+        .flat_map(border_connections)
+        .find_map(|(candidate, connections)| (candidate == symbol).then_some(connections))
+    };
+    let (Some(existing), Some(added)) = (connections_of(existing), connections_of(new)) else {
+        return new;
+    };
+    let merged = existing | added;
+    if merged == added {
+        return new;
+    }
+    border_connections(set)
+        .into_iter()
+        .find_map(|(symbol, connections)| (connections == merged).then_some(symbol))
+        .unwrap_or(new)
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -932,6 +1194,15 @@ mod tests {
         assert_eq!(block.inner(area), expected);
     }
 
+    #[rstest]
+    #[case::left(Position::Left, Rect::new(1, 0, 2, 2))]
+    #[case::right(Position::Right, Rect::new(0, 0, 2, 2))]
+    fn inner_takes_into_account_vertical_title(#[case] position: Position, #[case] expected: Rect) {
+        let area = Rect::new(0, 0, 3, 2);
+        let block = Block::new().title(Title::from("Test").position(position));
+        assert_eq!(block.inner(area), expected);
+    }
+
     #[test]
     fn has_title_at_position_takes_into_account_all_positioning_declarations() {
         let block = Block::new();
@@ -997,7 +1268,9 @@ mod tests {
                 border_style: Style::new(),
                 border_set: BorderType::Plain.to_border_set(),
                 style: Style::new(),
+                bg_gradient: None,
                 padding: Padding::zero(),
+                merge_borders: false,
             }
         );
     }
@@ -1076,7 +1349,7 @@ mod tests {
     #[test]
     fn title() {
         use Alignment::*;
-        use Position::*;
+        use Position::{Bottom, Top};
         let mut buffer = Buffer::empty(Rect::new(0, 0, 11, 3));
         Block::bordered()
             .title(Title::from("A").position(Top).alignment(Left))
@@ -1121,6 +1394,7 @@ mod tests {
             (Alignment::Left, "test    "),
             (Alignment::Center, "  test  "),
             (Alignment::Right, "    test"),
+            (Alignment::Justify, "test    "),
         ];
         for (alignment, expected) in tests {
             let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 1));
@@ -1170,6 +1444,77 @@ mod tests {
         assert_eq!(buffer, Buffer::with_lines(["    ", "test"]));
     }
 
+    #[test]
+    fn title_offset() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 12, 3));
+        Block::bordered()
+            .title("left")
+            .title(Title::from("mid").offset(5))
+            .title(Title::from("bot").position(Position::Bottom).offset(7))
+            .render(buffer.area, &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "┌left─mid──┐",
+            "│          │",
+            "└───────bot┘",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn title_offset_truncates() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 1));
+        Block::new()
+            .title(Title::from("title").offset(5))
+            .title(Title::from("hidden").offset(20))
+            .render(buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["     tit"]));
+    }
+
+    #[test]
+    fn vertical_titles() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 8));
+        Block::bordered()
+            .title(Title::from("ab").position(Position::Left))
+            .title(
+                Title::from("cd")
+                    .position(Position::Left)
+                    .alignment(Alignment::Right),
+            )
+            .title(
+                Title::from("ef")
+                    .position(Position::Right)
+                    .alignment(Alignment::Center),
+            )
+            .title(Title::from("g").position(Position::Right).offset(0))
+            .render(buffer.area, &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "┌───┐",
+            "a   g",
+            "b   │",
+            "│   e",
+            "│   f",
+            "c   │",
+            "d   │",
+            "└───┘",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn vertical_title_style() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 1, 3));
+        Block::new()
+            .title_style(Style::new().on_blue())
+            .title(Title::from(Line::from(vec!["a".red(), "bcd".into()])).position(Position::Left))
+            .render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["a", "b", "c"]);
+        expected.set_style(Rect::new(0, 0, 1, 3), Style::new().on_blue());
+        expected.set_style(Rect::new(0, 0, 1, 1), Style::new().red());
+        assert_eq!(buffer, expected);
+    }
+
     #[test]
     fn title_content_style() {
         for alignment in [Alignment::Left, Alignment::Center, Alignment::Right] {
@@ -1243,6 +1588,47 @@ mod tests {
         assert_eq!("".parse::<BorderType>(), Err(ParseError::VariantNotFound));
     }
 
+    #[test]
+    fn render_bg_gradient() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 3, 2));
+        let gradient = Gradient::new([Color::Rgb(0, 0, 0), Color::Rgb(0, 100, 0)]);
+        Block::new()
+            .style(Style::new().red().on_blue())
+            .bg_gradient(gradient)
+            .render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["   ", "   "]);
+        expected.set_style(
+            Rect::new(0, 0, 1, 2),
+            Style::new().red().bg(Color::Rgb(0, 0, 0)),
+        );
+        expected.set_style(
+            Rect::new(1, 0, 1, 2),
+            Style::new().red().bg(Color::Rgb(0, 50, 0)),
+        );
+        expected.set_style(
+            Rect::new(2, 0, 1, 2),
+            Style::new().red().bg(Color::Rgb(0, 100, 0)),
+        );
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_vertical_bg_gradient() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 3));
+        let gradient = Gradient::new([Color::Rgb(0, 0, 0), Color::Rgb(0, 0, 100)]);
+        Block::bordered()
+            .bg_gradient(gradient.direction(Direction::Vertical))
+            .render(buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines(["┌──┐", "│  │", "└──┘"]);
+        expected.set_style(Rect::new(0, 0, 4, 1), Style::new().bg(Color::Rgb(0, 0, 0)));
+        expected.set_style(Rect::new(0, 1, 4, 1), Style::new().bg(Color::Rgb(0, 0, 50)));
+        expected.set_style(
+            Rect::new(0, 2, 4, 1),
+            Style::new().bg(Color::Rgb(0, 0, 100)),
+        );
+        assert_eq!(buffer, expected);
+    }
+
     #[test]
     fn render_plain_border() {
         let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
@@ -1333,6 +1719,77 @@ mod tests {
         assert_eq!(buffer, expected);
     }
 
+    #[test]
+    fn render_merged_borders() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 9, 5));
+        for (x, y) in [(0, 0), (4, 0), (0, 2), (4, 2)] {
+            Block::bordered()
+                .merge_borders(true)
+                .render(Rect::new(x, y, 5, 3), &mut buffer);
+        }
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "┌───┬───┐",
+            "│   │   │",
+            "├───┼───┤",
+            "│   │   │",
+            "└───┴───┘",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_merged_borders_with_other_border_type() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 7, 3));
+        Block::bordered()
+            .title("ab")
+            .render(Rect::new(0, 0, 4, 3), &mut buffer);
+        Block::bordered()
+            .border_type(BorderType::Double)
+            .merge_borders(true)
+            .render(Rect::new(3, 0, 4, 3), &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "┌ab╦══╗",
+            "│  ║  ║",
+            "└──╩══╝",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_borders_without_merging() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 7, 3));
+        Block::bordered().render(Rect::new(0, 0, 4, 3), &mut buffer);
+        Block::bordered().render(Rect::new(3, 0, 4, 3), &mut buffer);
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "┌──┌──┐",
+            "│  │  │",
+            "└──└──┘",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[rstest]
+    #[case::unknown("x", "│", "│")]
+    #[case::same("│", "│", "│")]
+    #[case::corners("┐", "┌", "┬")]
+    #[case::side_over_corner("┘", "│", "┤")]
+    #[case::cross("┴", "┬", "┼")]
+    #[case::other_set("═", "│", "┼")]
+    #[case::title("─", "a", "a")]
+    fn merge_border_symbol(
+        #[case] existing: &str,
+        #[case] new: &'static str,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(
+            super::merge_border_symbol(&border::PLAIN, existing, new),
+            expected
+        );
+    }
+
     #[test]
     fn render_custom_border_set() {
         let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 3));
@@ -1346,6 +1803,7 @@ mod tests {
                 vertical_right: "R",
                 horizontal_top: "T",
                 horizontal_bottom: "B",
+                ..border::PLAIN
             })
             .render(buffer.area, &mut buffer);
         #[rustfmt::skip]
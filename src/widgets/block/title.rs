@@ -60,11 +60,19 @@ pub struct Title<'a> {
     /// [`Block::title_position`](crate::widgets::Block::title_position) in the associated
     /// [`Block`](crate::widgets::Block).
     pub position: Option<Position>,
+
+    /// Title offset
+    ///
+    /// If set, the title starts this number of cells after the start of its side of the block (the
+    /// left of the top and bottom sides, the top of the left and right sides) instead of being
+    /// aligned. Offset titles are rendered over the aligned titles.
+    pub offset: Option<u16>,
 }
 
 /// Defines the [title](crate::widgets::block::Title) position.
 ///
-/// The title can be positioned on top or at the bottom of the block.
+/// The title can be positioned on top or at the bottom of the block, or on its left or right
+/// side, where it is rendered vertically with one grapheme per row.
 /// Defaults to [`Position::Top`].
 ///
 /// # Example
@@ -83,6 +91,16 @@ pub enum Position {
     Top,
     /// Position the title at the bottom of the block.
     Bottom,
+    /// Position the title on the left side of the block, from top to bottom.
+    ///
+    /// The [`Alignment::Left`] and [`Alignment::Right`] alignments put the title at the top and at
+    /// the bottom of the side.
+    Left,
+    /// Position the title on the right side of the block, from top to bottom.
+    ///
+    /// The [`Alignment::Left`] and [`Alignment::Right`] alignments put the title at the top and at
+    /// the bottom of the side.
+    Right,
 }
 
 impl<'a> Title<'a> {
@@ -109,6 +127,23 @@ impl<'a> Title<'a> {
         self.position = Some(position);
         self
     }
+
+    /// Set the title offset from the start of its side, which overrides the alignment.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::widgets::{block::*, *};
+    ///
+    /// // Renders
+    /// // ┌──Title───┐
+    /// Block::bordered().title(Title::from("Title").offset(2));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn offset(mut self, offset: u16) -> Self {
+        self.offset = Some(offset);
+        self
+    }
 }
 
 impl<'a, T> From<T> for Title<'a>
@@ -122,6 +157,7 @@ where
             content,
             alignment,
             position: None,
+            offset: None,
         }
     }
 }
@@ -137,12 +173,16 @@ mod tests {
     fn position_to_string() {
         assert_eq!(Position::Top.to_string(), "Top");
         assert_eq!(Position::Bottom.to_string(), "Bottom");
+        assert_eq!(Position::Left.to_string(), "Left");
+        assert_eq!(Position::Right.to_string(), "Right");
     }
 
     #[test]
     fn position_from_str() {
         assert_eq!("Top".parse::<Position>(), Ok(Position::Top));
         assert_eq!("Bottom".parse::<Position>(), Ok(Position::Bottom));
+        assert_eq!("Left".parse::<Position>(), Ok(Position::Left));
+        assert_eq!("Right".parse::<Position>(), Ok(Position::Right));
         assert_eq!("".parse::<Position>(), Err(ParseError::VariantNotFound));
     }
 
@@ -152,6 +192,13 @@ mod tests {
         assert_eq!(title.content, Line::from("Title"));
         assert_eq!(title.alignment, None);
         assert_eq!(title.position, None);
+        assert_eq!(title.offset, None);
+    }
+
+    #[test]
+    fn title_offset() {
+        let title = Title::from("Title").offset(3);
+        assert_eq!(title.offset, Some(3));
     }
 
     #[rstest]
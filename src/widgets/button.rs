@@ -0,0 +1,430 @@
+use crate::{
+    backend::event::{KeyCode, KeyEvent, KeyEventKind, MouseButton, MouseEvent, MouseEventKind},
+    layout::Position,
+    prelude::*,
+    widgets::{Block, StatefulWidgetRef},
+};
+
+/// State of the [`Button`] widget
+///
+/// The state holds whether the button is focused and pressed. The area of the button and whether
+/// it is disabled are recorded during each render, so that the state can handle mouse clicks and
+/// ignore the events of a disabled button.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{backend::event::KeyCode, widgets::*};
+///
+/// let mut state = ButtonState::default();
+/// assert!(!state.handle_key(KeyCode::Enter.into()));
+///
+/// state.set_focused(true);
+/// assert!(state.handle_key(KeyCode::Enter.into()));
+/// assert!(state.is_pressed());
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct ButtonState {
+    focused: bool,
+    press: Press,
+    disabled: bool,
+    area: Rect,
+}
+
+/// How a [`Button`] is pressed
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+enum Press {
+    #[default]
+    Released,
+    /// Pressed until it is released
+    Held,
+    /// Pressed with a key, and released after the next render
+    Key,
+}
+
+impl ButtonState {
+    /// Creates a new state, focused or not
+    pub const fn new(focused: bool) -> Self {
+        Self {
+            focused,
+            press: Press::Released,
+            disabled: false,
+            area: Rect::ZERO,
+        }
+    }
+
+    /// Whether the button is focused
+    pub const fn is_focused(&self) -> bool {
+        self.focused
+    }
+
+    /// Focuses the button or removes its focus
+    pub fn set_focused(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+
+    /// Whether the button is pressed
+    pub fn is_pressed(&self) -> bool {
+        self.press != Press::Released
+    }
+
+    /// Presses the button until [`ButtonState::release`] is called
+    pub fn press(&mut self) {
+        self.press = Press::Held;
+    }
+
+    /// Releases the button
+    pub fn release(&mut self) {
+        self.press = Press::Released;
+    }
+
+    /// Handles a key, returning whether it activated the button
+    ///
+    /// <kbd>Enter</kbd> and <kbd>Space</kbd> activate a focused button that is not disabled. The
+    /// button is displayed as pressed until the next render, or until the key is released for
+    /// backends that report key releases.
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        if !self.focused
+            || self.disabled
+            || !matches!(key.code, KeyCode::Enter | KeyCode::Char(' '))
+        {
+            return false;
+        }
+        if key.kind == KeyEventKind::Release {
+            self.release();
+            return false;
+        }
+        self.press = Press::Key;
+        true
+    }
+
+    /// Handles a mouse event, returning whether it activated the button
+    ///
+    /// Pressing the left button over the button presses it, and releasing it over the button
+    /// activates it. The area of the button is the one of the last render.
+    pub fn handle_mouse(&mut self, event: MouseEvent) -> bool {
+        if self.disabled {
+            return false;
+        }
+        let inside = self.area.contains(Position::new(event.column, event.row));
+        match event.kind {
+            MouseEventKind::Down(MouseButton::Left) if inside => {
+                self.press();
+                false
+            }
+            MouseEventKind::Up(MouseButton::Left) => {
+                let activated = self.is_pressed() && inside;
+                self.release();
+                activated
+            }
+            _ => false,
+        }
+    }
+}
+
+/// A widget that displays a button with a label and an optional key hint
+///
+/// The button is filled with a style that depends on its state: [`Button::style`] by default,
+/// patched with [`Button::focused_style`] when it is focused and [`Button::pressed_style`] when
+/// it is pressed. A disabled button is only patched with [`Button::disabled_style`]. The label is
+/// centered vertically and aligned with [`Button::alignment`], which centers it by default, and
+/// the key hint is displayed after the label.
+///
+/// Whether the button is focused and pressed is held by a [`ButtonState`], which can handle the
+/// key and mouse events that activate the button.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{prelude::*, widgets::*};
+///
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// let button = Button::new("Save")
+///     .key_hint("^S")
+///     .style(Style::new().on_blue());
+///
+/// // This should be stored outside of the function in your application state.
+/// let mut state = ButtonState::new(true);
+///
+/// frame.render_stateful_widget(button, area, &mut state);
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Button<'a> {
+    label: Line<'a>,
+    key_hint: Option<Span<'a>>,
+    block: Option<Block<'a>>,
+    alignment: Alignment,
+    style: Style,
+    focused_style: Style,
+    pressed_style: Style,
+    disabled: bool,
+    disabled_style: Style,
+    key_hint_style: Style,
+}
+
+impl Default for Button<'_> {
+    fn default() -> Self {
+        Self::new("")
+    }
+}
+
+impl<'a> Button<'a> {
+    /// Creates a new button with the given label
+    pub fn new<T: Into<Line<'a>>>(label: T) -> Self {
+        Self {
+            label: label.into(),
+            key_hint: None,
+            block: None,
+            alignment: Alignment::Center,
+            style: Style::new(),
+            focused_style: Style::new().add_modifier(Modifier::REVERSED),
+            pressed_style: Style::new().add_modifier(Modifier::BOLD),
+            disabled: false,
+            disabled_style: Style::new().add_modifier(Modifier::DIM),
+            key_hint_style: Style::new().add_modifier(Modifier::DIM),
+        }
+    }
+
+    /// Sets a key hint displayed after the label, e.g. the key that activates the button
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn key_hint<T: Into<Span<'a>>>(mut self, key_hint: T) -> Self {
+        self.key_hint = Some(key_hint.into());
+        self
+    }
+
+    /// Wraps the button in a [`Block`]
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the horizontal alignment of the label, which is centered by default
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Sets the style of the button
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of a focused button, which is reversed by default
+    ///
+    /// The style is patched on top of [`Button::style`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn focused_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.focused_style = style.into();
+        self
+    }
+
+    /// Sets the style of a pressed button, which is bold by default
+    ///
+    /// The style is patched on top of [`Button::style`], and of [`Button::focused_style`] when
+    /// the button is focused.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn pressed_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.pressed_style = style.into();
+        self
+    }
+
+    /// Sets whether the button is disabled, which renders it with [`Button::disabled_style`] and
+    /// makes its state ignore events
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Sets the style of a disabled button, which is dimmed by default
+    ///
+    /// The style is patched on top of [`Button::style`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn disabled_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.disabled_style = style.into();
+        self
+    }
+
+    /// Sets the style of the key hint, which is dimmed by default
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn key_hint_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.key_hint_style = style.into();
+        self
+    }
+
+    /// The style of the button in the given state
+    fn state_style(&self, state: &ButtonState) -> Style {
+        if self.disabled {
+            return self.style.patch(self.disabled_style);
+        }
+        let mut style = self.style;
+        if state.focused {
+            style = style.patch(self.focused_style);
+        }
+        if state.is_pressed() {
+            style = style.patch(self.pressed_style);
+        }
+        style
+    }
+}
+
+impl StatefulWidget for Button<'_> {
+    type State = ButtonState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        self.render_ref(area, buf, state);
+    }
+}
+
+// Note: remove this when StatefulWidgetRef is stabilized and replace with the blanket impl
+impl StatefulWidget for &Button<'_> {
+    type State = ButtonState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        self.render_ref(area, buf, state);
+    }
+}
+
+impl StatefulWidgetRef for Button<'_> {
+    type State = ButtonState;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let area = area.intersection(buf.area);
+        state.area = area;
+        state.disabled = self.disabled;
+        buf.set_style(area, self.state_style(state));
+        if state.press == Press::Key {
+            state.release();
+        }
+        self.block.render_ref(area, buf);
+        let inner = self.block.inner_if_some(area);
+        if inner.is_empty() {
+            return;
+        }
+
+        let mut line = self.label.clone();
+        if let Some(key_hint) = &self.key_hint {
+            line.spans.push(Span::raw(" "));
+            line.spans
+                .push(key_hint.clone().patch_style(self.key_hint_style));
+        }
+        let line = line.alignment(self.alignment);
+        let y = inner.y + (inner.height - 1) / 2;
+        line.render(Rect::new(inner.x, y, inner.width, 1), buf);
+    }
+}
+
+impl Styled for Button<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::backend::event::KeyModifiers;
+
+    fn mouse(kind: MouseEventKind, column: u16, row: u16) -> MouseEvent {
+        MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        }
+    }
+
+    #[test]
+    fn render() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 3));
+        let mut state = ButtonState::default();
+        Button::new("Save")
+            .key_hint("^S")
+            .key_hint_style(Style::new())
+            .render(buf.area, &mut buf, &mut state);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["          ", " Save ^S  ", "          "])
+        );
+        assert_eq!(state.area, buf.area);
+    }
+
+    #[rstest]
+    #[case::normal(false, false, false, Style::new())]
+    #[case::focused(true, false, false, Style::new().reversed())]
+    #[case::pressed(true, true, false, Style::new().reversed().bold())]
+    #[case::disabled(true, true, true, Style::new().dim())]
+    fn render_style(
+        #[case] focused: bool,
+        #[case] pressed: bool,
+        #[case] disabled: bool,
+        #[case] style: Style,
+    ) {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 1));
+        let mut state = ButtonState::new(focused);
+        if pressed {
+            state.press();
+        }
+        Button::new("Ok")
+            .disabled(disabled)
+            .render(buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines([" Ok "]);
+        expected.set_style(buf.area, style);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn key_press_is_released_after_render() {
+        let mut state = ButtonState::new(true);
+        assert!(state.handle_key(KeyCode::Char(' ').into()));
+        assert!(state.is_pressed());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 1));
+        Button::new("Ok").render(buf.area, &mut buf, &mut state);
+        assert_eq!(buf.get(0, 0).modifier, Modifier::REVERSED | Modifier::BOLD);
+        assert!(!state.is_pressed());
+
+        assert!(!state.handle_key(KeyCode::Tab.into()));
+        Button::new("Ok")
+            .disabled(true)
+            .render(buf.area, &mut buf, &mut state);
+        assert!(!state.handle_key(KeyCode::Enter.into()));
+    }
+
+    #[test]
+    fn handle_mouse() {
+        let mut state = ButtonState::default();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 3));
+        Button::new("Ok").render(Rect::new(2, 1, 4, 1), &mut buf, &mut state);
+
+        let left = MouseButton::Left;
+        assert!(!state.handle_mouse(mouse(MouseEventKind::Down(left), 0, 0)));
+        assert!(!state.is_pressed());
+        assert!(!state.handle_mouse(mouse(MouseEventKind::Down(left), 3, 1)));
+        assert!(state.is_pressed());
+        assert!(state.handle_mouse(mouse(MouseEventKind::Up(left), 5, 1)));
+        assert!(!state.is_pressed());
+
+        // releasing outside of the button cancels the click
+        state.handle_mouse(mouse(MouseEventKind::Down(left), 3, 1));
+        assert!(!state.handle_mouse(mouse(MouseEventKind::Up(left), 6, 1)));
+        assert!(!state.is_pressed());
+    }
+}
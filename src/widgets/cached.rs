@@ -0,0 +1,214 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+use crate::{buffer::BlendMode, prelude::*};
+
+/// A widget that reuses its previous rendering while its inputs are unchanged
+///
+/// `Cached` wraps another widget along with a key, which is a hash of everything the rendering of
+/// the widget depends on, e.g. the data it displays and its styles. The rendered cells are stored
+/// in a [`CachedState`]. On the next render, if the key and the area are the same, the stored cells
+/// are copied to the buffer instead of rendering the widget again. This saves the cost of
+/// rendering static panels, such as a help screen or a large table that rarely changes.
+///
+/// The widget is rendered over the cells that were in its area when it was last rendered, so the
+/// key should also cover anything rendered below the widget that shows through it.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     prelude::*,
+///     widgets::{Cached, CachedState, Paragraph},
+/// };
+///
+/// # fn ui(frame: &mut Frame, help: &str, state: &mut CachedState) {
+/// let paragraph = Paragraph::new(help);
+/// // the paragraph is only rendered again when the help text changes
+/// frame.render_stateful_widget(Cached::new(paragraph, help), frame.size(), state);
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Cached<W> {
+    widget: W,
+    key: u64,
+}
+
+/// State of a [`Cached`] widget
+///
+/// This holds the cells rendered by the widget and the key they were rendered with, and counts how
+/// often the cells were reused. Call [`CachedState::invalidate`] to render the widget again on the
+/// next render regardless of its key.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct CachedState {
+    key: Option<u64>,
+    buffer: Buffer,
+    hits: u64,
+    misses: u64,
+}
+
+impl<W> Cached<W> {
+    /// Creates a new cached widget with the key its rendering depends on
+    ///
+    /// `key` can be any type that implements [`Hash`], e.g. a tuple of the inputs of the widget or
+    /// a version number that is incremented when they change.
+    pub fn new<K: Hash>(widget: W, key: K) -> Self {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        Self {
+            widget,
+            key: hasher.finish(),
+        }
+    }
+}
+
+impl CachedState {
+    /// Creates a new empty state
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Discards the stored cells, so that the widget is rendered again on the next render
+    pub fn invalidate(&mut self) {
+        self.key = None;
+    }
+
+    /// The number of renders that reused the stored cells
+    pub const fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// The number of renders that rendered the widget
+    pub const fn misses(&self) -> u64 {
+        self.misses
+    }
+
+    /// Copies the stored cells to the buffer if they were rendered with the key in the same area,
+    /// and otherwise renders them with `render` and stores them
+    fn render<F>(&mut self, key: u64, area: Rect, buf: &mut Buffer, render: F)
+    where
+        F: FnOnce(Rect, &mut Buffer),
+    {
+        let area = area.intersection(buf.area);
+        if self.key == Some(key) && self.buffer.area == area {
+            self.hits = self.hits.saturating_add(1);
+        } else {
+            self.buffer.resize(area);
+            self.buffer.merge_with(buf, BlendMode::Replace);
+            render(area, &mut self.buffer);
+            self.key = Some(key);
+            self.misses = self.misses.saturating_add(1);
+        }
+        buf.merge_with(&self.buffer, BlendMode::Replace);
+    }
+}
+
+impl<W: Widget> StatefulWidget for Cached<W> {
+    type State = CachedState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let widget = self.widget;
+        state.render(self.key, area, buf, |area, buf| widget.render(area, buf));
+    }
+}
+
+// Note: remove this when StatefulWidgetRef is stabilized and replace with the blanket impl
+impl<W: WidgetRef> StatefulWidget for &Cached<W> {
+    type State = CachedState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        self.render_ref(area, buf, state);
+    }
+}
+
+impl<W: WidgetRef> StatefulWidgetRef for Cached<W> {
+    type State = CachedState;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        state.render(self.key, area, buf, |area, buf| {
+            self.widget.render_ref(area, buf);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    /// A widget that counts how often it is rendered
+    struct Counter<'a>(&'a Cell<usize>);
+
+    impl Widget for Counter<'_> {
+        fn render(self, area: Rect, buf: &mut Buffer) {
+            self.0.set(self.0.get() + 1);
+            buf.set_string(area.x, area.y, self.0.get().to_string(), Style::new());
+        }
+    }
+
+    #[test]
+    fn reuses_cells_while_key_is_unchanged() {
+        let renders = Cell::new(0);
+        let mut state = CachedState::new();
+        let area = Rect::new(0, 0, 3, 1);
+
+        let mut buf = Buffer::empty(area);
+        Cached::new(Counter(&renders), "a").render(area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["1  "]));
+
+        let mut buf = Buffer::empty(area);
+        Cached::new(Counter(&renders), "a").render(area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["1  "]));
+        assert_eq!(renders.get(), 1);
+        assert_eq!((state.hits(), state.misses()), (1, 1));
+
+        Cached::new(Counter(&renders), "b").render(area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["2  "]));
+        assert_eq!((state.hits(), state.misses()), (1, 2));
+    }
+
+    #[test]
+    fn renders_again_when_area_changes() {
+        let renders = Cell::new(0);
+        let mut state = CachedState::new();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 2));
+
+        Cached::new(Counter(&renders), 1).render(Rect::new(0, 0, 3, 1), &mut buf, &mut state);
+        Cached::new(Counter(&renders), 1).render(Rect::new(0, 1, 3, 1), &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["1  ", "2  "]));
+        assert_eq!(renders.get(), 2);
+    }
+
+    #[test]
+    fn invalidate() {
+        let renders = Cell::new(0);
+        let mut state = CachedState::new();
+        let area = Rect::new(0, 0, 3, 1);
+        let mut buf = Buffer::empty(area);
+
+        Cached::new(Counter(&renders), 1).render(area, &mut buf, &mut state);
+        state.invalidate();
+        Cached::new(Counter(&renders), 1).render(area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["2  "]));
+    }
+
+    #[test]
+    fn renders_over_existing_cells() {
+        let mut state = CachedState::new();
+        let mut buf = Buffer::with_lines(["xxxx"]);
+        let cached = Cached::new(Line::raw("ab"), "ab");
+
+        StatefulWidget::render(&cached, Rect::new(1, 0, 2, 1), &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["xabx"]));
+
+        let mut buf = Buffer::with_lines(["yyyy"]);
+        StatefulWidget::render(&cached, Rect::new(1, 0, 2, 1), &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["yaby"]));
+        assert_eq!(state.hits(), 1);
+    }
+}
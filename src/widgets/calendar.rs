@@ -7,10 +7,15 @@
 //! * `show_surrounding` is set, then days not in the `display_date` month will use that style.
 //! * a style is returned by the [`DateStyler`] for the day
 //!
-//! [`Monthly`] has several controls for what should be displayed
+//! [`Monthly`] has several controls for what should be displayed, as well as the first day of
+//! the week and the names of the months and weekdays, so that calendars can be localized.
+//!
+//! The [`Quarterly`] and [`Yearly`] widgets display a grid of months, which are formatted like the
+//! [`Monthly`] widget they are created from.
 use std::collections::HashMap;
 
-use time::{Date, Duration, OffsetDateTime};
+use time::{Date, Duration, Month, OffsetDateTime, Weekday};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 use crate::{prelude::*, widgets::Block};
 
@@ -22,8 +27,14 @@ pub struct Monthly<'a, DS: DateStyler> {
     show_surrounding: Option<Style>,
     show_weekday: Option<Style>,
     show_month: Option<Style>,
+    show_week_numbers: Option<Style>,
     default_style: Style,
     block: Option<Block<'a>>,
+    first_weekday: Weekday,
+    /// The names of the weekdays, from Monday to Sunday, or the English names if `None`
+    weekday_names: Option<[String; 7]>,
+    /// The names of the months, from January to December, or the English names if `None`
+    month_names: Option<[String; 12]>,
 }
 
 impl<'a, DS: DateStyler> Monthly<'a, DS> {
@@ -35,8 +46,12 @@ impl<'a, DS: DateStyler> Monthly<'a, DS> {
             show_surrounding: None,
             show_weekday: None,
             show_month: None,
+            show_week_numbers: None,
             default_style: Style::new(),
             block: None,
+            first_weekday: Weekday::Sunday,
+            weekday_names: None,
+            month_names: None,
         }
     }
 
@@ -72,6 +87,124 @@ impl<'a, DS: DateStyler> Monthly<'a, DS> {
         self
     }
 
+    /// Display a column containing the ISO week number of each week
+    ///
+    /// ISO weeks start on Monday, so when the weeks of the calendar start on another day, the
+    /// number is the ISO week of the Monday of each line.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn show_week_numbers<S: Into<Style>>(mut self, style: S) -> Self {
+        self.show_week_numbers = Some(style.into());
+        self
+    }
+
+    /// Set the first day of the weeks, Sunday by default
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{prelude::*, widgets::calendar::*};
+    /// use time::{Date, Month, Weekday};
+    ///
+    /// let date = Date::from_calendar_date(2024, Month::May, 1).unwrap();
+    /// let calendar = Monthly::new(date, CalendarEventStore::default()).first_weekday(Weekday::Monday);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn first_weekday(mut self, weekday: Weekday) -> Self {
+        self.first_weekday = weekday;
+        self
+    }
+
+    /// Set the function that provides the names of the weekdays in the weekdays header
+    ///
+    /// The function is called once for each weekday, and the names are truncated or padded to 2
+    /// columns. By default the names are the first two letters of the English names.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{prelude::*, widgets::calendar::*};
+    /// use time::{Date, Month, Weekday};
+    ///
+    /// let date = Date::from_calendar_date(2024, Month::May, 1).unwrap();
+    /// let calendar = Monthly::new(date, CalendarEventStore::default())
+    ///     .show_weekdays_header(Style::new())
+    ///     .weekday_names(|weekday| {
+    ///         match weekday {
+    ///             Weekday::Monday => "Lu",
+    ///             Weekday::Tuesday => "Ma",
+    ///             Weekday::Wednesday => "Me",
+    ///             Weekday::Thursday => "Je",
+    ///             Weekday::Friday => "Ve",
+    ///             Weekday::Saturday => "Sa",
+    ///             Weekday::Sunday => "Di",
+    ///         }
+    ///         .to_string()
+    ///     });
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn weekday_names<F, S>(mut self, weekday_name: F) -> Self
+    where
+        F: Fn(Weekday) -> S,
+        S: Into<String>,
+    {
+        let mut weekday = Weekday::Monday;
+        self.weekday_names = Some(std::array::from_fn(|_| {
+            let name = weekday_name(weekday).into();
+            weekday = weekday.next();
+            name
+        }));
+        self
+    }
+
+    /// Set the function that provides the names of the months in the month header
+    ///
+    /// The function is called once for each month. By default the names are the English names.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{prelude::*, widgets::calendar::*};
+    /// use time::{Date, Month};
+    ///
+    /// let date = Date::from_calendar_date(2024, Month::May, 1).unwrap();
+    /// let calendar = Monthly::new(date, CalendarEventStore::default())
+    ///     .show_month_header(Style::new())
+    ///     .month_names(|month| format!("{}月", u8::from(month)));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn month_names<F, S>(mut self, month_name: F) -> Self
+    where
+        F: Fn(Month) -> S,
+        S: Into<String>,
+    {
+        let mut month = Month::January;
+        self.month_names = Some(std::array::from_fn(|_| {
+            let name = month_name(month).into();
+            month = month.next();
+            name
+        }));
+        self
+    }
+
+    /// The name of the weekday in the weekdays header
+    fn weekday_name(&self, weekday: Weekday) -> String {
+        match &self.weekday_names {
+            Some(names) => names[usize::from(weekday.number_days_from_monday())].clone(),
+            None => weekday.to_string().chars().take(2).collect(),
+        }
+    }
+
+    /// The name of the month in the month header
+    fn month_name(&self, month: Month) -> String {
+        match &self.month_names {
+            Some(names) => names[usize::from(u8::from(month)) - 1].clone(),
+            None => month.to_string(),
+        }
+    }
+
     /// How to render otherwise unstyled dates
     ///
     /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
@@ -98,8 +231,8 @@ impl<'a, DS: DateStyler> Monthly<'a, DS> {
     }
 
     /// All logic to style a date goes here.
-    fn format_date(&self, date: Date) -> Span {
-        if date.month() == self.display_date.month() {
+    fn format_date(&self, date: Date, display_date: Date) -> Span {
+        if date.month() == display_date.month() {
             Span::styled(
                 format!("{:2?}", date.day()),
                 self.default_style.patch(self.events.get_style(date)),
@@ -127,25 +260,61 @@ impl<DS: DateStyler> Widget for Monthly<'_, DS> {
 
 impl<DS: DateStyler> WidgetRef for Monthly<'_, DS> {
     fn render_ref(&self, area: Rect, buf: &mut Buffer) {
-        self.block.render_ref(area, buf);
-        let inner = self.block.inner_if_some(area);
-        self.render_monthly(inner, buf);
+        self.render_month(self.display_date, area, buf);
     }
 }
 
 impl<DS: DateStyler> Monthly<'_, DS> {
-    fn render_monthly(&self, area: Rect, buf: &mut Buffer) {
+    /// Width of the week numbers column
+    const fn week_numbers_width(&self) -> u16 {
+        if self.show_week_numbers.is_some() {
+            3
+        } else {
+            0
+        }
+    }
+
+    /// The size of the calendar of a month with 6 weeks, including the block
+    fn size(&self) -> (u16, u16) {
+        let width = 21 + self.week_numbers_width();
+        let height =
+            u16::from(self.show_month.is_some()) + u16::from(self.show_weekday.is_some()) + 6;
+        let outer = Rect::new(0, 0, 100, 100);
+        let inner = self.block.inner_if_some(outer);
+        (
+            width + outer.width - inner.width,
+            height + outer.height - inner.height,
+        )
+    }
+
+    /// Renders the calendar of the month containing `display_date`
+    fn render_month(&self, display_date: Date, area: Rect, buf: &mut Buffer) {
+        self.block.render_ref(area, buf);
+        let inner = self.block.inner_if_some(area);
+        self.render_monthly(display_date, inner, buf);
+    }
+
+    fn render_monthly(&self, display_date: Date, area: Rect, buf: &mut Buffer) {
         let layout = Layout::vertical([
             Constraint::Length(self.show_month.is_some().into()),
             Constraint::Length(self.show_weekday.is_some().into()),
             Constraint::Fill(1),
         ]);
         let [month_header, days_header, days_area] = layout.areas(area);
+        let [week_numbers_area, days_area] = Layout::horizontal([
+            Constraint::Length(self.week_numbers_width()),
+            Constraint::Fill(1),
+        ])
+        .areas(days_area);
 
         // Draw the month name and year
         if let Some(style) = self.show_month {
             Line::styled(
-                format!("{} {}", self.display_date.month(), self.display_date.year()),
+                format!(
+                    "{} {}",
+                    self.month_name(display_date.month()),
+                    display_date.year()
+                ),
                 style,
             )
             .alignment(Alignment::Center)
@@ -154,17 +323,43 @@ impl<DS: DateStyler> Monthly<'_, DS> {
 
         // Draw days of week
         if let Some(style) = self.show_weekday {
-            Span::styled(" Su Mo Tu We Th Fr Sa", style).render(days_header, buf);
+            let days_header = Rect {
+                x: days_area.x,
+                width: days_area.width,
+                ..days_header
+            };
+            let mut weekday = self.first_weekday;
+            let mut names = String::with_capacity(21);
+            for _ in 0..7 {
+                names.push(' ');
+                names.push_str(&fit_width(&self.weekday_name(weekday), 2));
+                weekday = weekday.next();
+            }
+            Span::styled(names, style).render(days_header, buf);
         }
 
-        // Set the start of the calendar to the Sunday before the 1st (or the sunday of the first)
-        let first_of_month = self.display_date.replace_day(1).unwrap();
-        let offset = Duration::days(first_of_month.weekday().number_days_from_sunday().into());
+        // Set the start of the calendar to the first day of the week containing the 1st
+        let first_of_month = display_date.replace_day(1).unwrap();
+        let offset = (7 + first_of_month.weekday().number_days_from_sunday()
+            - self.first_weekday.number_days_from_sunday())
+            % 7;
+        let offset = Duration::days(offset.into());
         let mut curr_day = first_of_month - offset;
 
         let mut y = days_area.y;
         // go through all the weeks containing a day in the target month.
-        while curr_day.month() != self.display_date.month().next() {
+        while curr_day.month() != display_date.month().next() && y < days_area.bottom() {
+            if let Some(style) = self.show_week_numbers {
+                let days_to_monday = (7 - curr_day.weekday().number_days_from_monday()) % 7;
+                let monday = curr_day + Duration::days(days_to_monday.into());
+                buf.set_stringn(
+                    week_numbers_area.x,
+                    y,
+                    format!("{:>2}", monday.iso_week()),
+                    week_numbers_area.width as usize,
+                    style,
+                );
+            }
             let mut spans = Vec::with_capacity(14);
             for i in 0..7 {
                 // Draw the gutter. Do it here so we can avoid worrying about
@@ -174,13 +369,174 @@ impl<DS: DateStyler> Monthly<'_, DS> {
                 } else {
                     spans.push(Span::styled(" ", self.default_bg()));
                 }
-                spans.push(self.format_date(curr_day));
+                spans.push(self.format_date(curr_day, display_date));
                 curr_day += Duration::DAY;
             }
-            buf.set_line(days_area.x, y, &spans.into(), area.width);
+            buf.set_line(days_area.x, y, &spans.into(), days_area.width);
             y += 1;
         }
     }
+
+    /// Renders the calendars of `count` months starting at the month of `first_month` in a grid
+    /// with the given number of columns, separated by a column and a line
+    fn render_grid(
+        &self,
+        first_month: Date,
+        count: u8,
+        columns: u16,
+        area: Rect,
+        buf: &mut Buffer,
+    ) {
+        let (width, height) = self.size();
+        let columns = columns.max(1);
+        let mut month = first_month.replace_day(1).unwrap();
+        for i in 0..u16::from(count) {
+            let x = area.x.saturating_add((i % columns) * (width + 1));
+            let y = area.y.saturating_add((i / columns) * (height + 1));
+            let month_area = Rect::new(x, y, width, height).intersection(area);
+            if !month_area.is_empty() {
+                self.render_month(month, month_area, buf);
+            }
+            // the 1st of the next month
+            month = (month + Duration::days(31)).replace_day(1).unwrap();
+        }
+    }
+}
+
+/// Display the calendars of the three months of the quarter containing the display date of a
+/// [`Monthly`] calendar
+///
+/// The months are formatted like the [`Monthly`] calendar, including its block, and are laid out
+/// in a row by default. Each month takes 6 lines so that the months are aligned.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{prelude::*, widgets::calendar::*};
+/// use time::{Date, Month};
+///
+/// let date = Date::from_calendar_date(2024, Month::May, 1).unwrap();
+/// let monthly = Monthly::new(date, CalendarEventStore::default())
+///     .show_month_header(Style::new().bold())
+///     .show_weekdays_header(Style::new().dim());
+/// // April, May and June 2024
+/// let quarter = Quarterly::new(monthly);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Quarterly<'a, DS: DateStyler> {
+    monthly: Monthly<'a, DS>,
+    columns: u16,
+}
+
+impl<'a, DS: DateStyler> Quarterly<'a, DS> {
+    /// Construct a calendar for the quarter containing the display date of `monthly`
+    pub const fn new(monthly: Monthly<'a, DS>) -> Self {
+        Self {
+            monthly,
+            columns: 3,
+        }
+    }
+
+    /// Set the number of months displayed on each line, 3 by default
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn columns(mut self, columns: u16) -> Self {
+        self.columns = columns;
+        self
+    }
+}
+
+impl<DS: DateStyler> Widget for Quarterly<'_, DS> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_ref(area, buf);
+    }
+}
+
+impl<DS: DateStyler> WidgetRef for Quarterly<'_, DS> {
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        let date = self.monthly.display_date;
+        let first_month = (u8::from(date.month()) - 1) / 3 * 3 + 1;
+        let first_month =
+            Date::from_calendar_date(date.year(), Month::try_from(first_month).unwrap(), 1)
+                .unwrap();
+        self.monthly
+            .render_grid(first_month, 3, self.columns, area, buf);
+    }
+}
+
+/// Display the calendars of the twelve months of the year containing the display date of a
+/// [`Monthly`] calendar
+///
+/// The months are formatted like the [`Monthly`] calendar, including its block, and are laid out
+/// in 3 columns by default. Each month takes 6 lines so that the months are aligned.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{prelude::*, widgets::calendar::*};
+/// use time::{Date, Month};
+///
+/// let date = Date::from_calendar_date(2024, Month::January, 1).unwrap();
+/// let monthly = Monthly::new(date, CalendarEventStore::default())
+///     .show_month_header(Style::new().bold())
+///     .show_week_numbers(Style::new().dim());
+/// let year = Yearly::new(monthly).columns(4);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Yearly<'a, DS: DateStyler> {
+    monthly: Monthly<'a, DS>,
+    columns: u16,
+}
+
+impl<'a, DS: DateStyler> Yearly<'a, DS> {
+    /// Construct a calendar for the year containing the display date of `monthly`
+    pub const fn new(monthly: Monthly<'a, DS>) -> Self {
+        Self {
+            monthly,
+            columns: 3,
+        }
+    }
+
+    /// Set the number of months displayed on each line, 3 by default
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn columns(mut self, columns: u16) -> Self {
+        self.columns = columns;
+        self
+    }
+}
+
+impl<DS: DateStyler> Widget for Yearly<'_, DS> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_ref(area, buf);
+    }
+}
+
+impl<DS: DateStyler> WidgetRef for Yearly<'_, DS> {
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        let first_month = self
+            .monthly
+            .display_date
+            .replace_month(Month::January)
+            .and_then(|date| date.replace_day(1))
+            .unwrap();
+        self.monthly
+            .render_grid(first_month, 12, self.columns, area, buf);
+    }
+}
+
+/// Truncates or pads the string with spaces so that it is exactly `width` columns wide
+fn fit_width(s: &str, width: usize) -> String {
+    let mut result = String::with_capacity(width);
+    let mut result_width = 0;
+    for c in s.chars() {
+        let c_width = c.width().unwrap_or(0);
+        if result_width + c_width > width {
+            break;
+        }
+        result.push(c);
+        result_width += c_width;
+    }
+    result.push_str(&" ".repeat(width.saturating_sub(result.width())));
+    result
 }
 
 /// Provides a method for styling a given date. [Monthly] is generic on this trait, so any type
@@ -219,6 +575,48 @@ impl CalendarEventStore {
         let _ = self.0.insert(date, style.into());
     }
 
+    /// Add a range of dates, e.g. a selection or a trip
+    ///
+    /// The first and last dates of the range use `style`, and the dates in between use
+    /// `in_between_style`. The range is inclusive, and the dates can be given in any order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use ratatui::{prelude::*, widgets::calendar::*};
+    /// use time::{Date, Month};
+    ///
+    /// let start = Date::from_calendar_date(2024, Month::May, 6).unwrap();
+    /// let end = Date::from_calendar_date(2024, Month::May, 10).unwrap();
+    /// let mut events = CalendarEventStore::default();
+    /// events.add_range(
+    ///     start,
+    ///     end,
+    ///     Style::new().reversed(),
+    ///     Style::new().underlined(),
+    /// );
+    /// ```
+    pub fn add_range<S, T>(&mut self, start: Date, end: Date, style: S, in_between_style: T)
+    where
+        S: Into<Style>,
+        T: Into<Style>,
+    {
+        let (start, end) = if start <= end {
+            (start, end)
+        } else {
+            (end, start)
+        };
+        let in_between_style = in_between_style.into();
+        let mut date = start;
+        while let Some(next) = date.next_day().filter(|&next| next < end) {
+            self.add(next, in_between_style);
+            date = next;
+        }
+        let style = style.into();
+        self.add(start, style);
+        self.add(end, style);
+    }
+
     /// Helper for trait impls
     fn lookup_style(&self, date: Date) -> Style {
         self.0.get(&date).copied().unwrap_or_default()
@@ -274,6 +672,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn event_store_range() {
+        let date = |day| Date::from_calendar_date(2023, Month::January, day).unwrap();
+        let style = Style::new().reversed();
+        let in_between_style = Style::new().underlined();
+        let mut s = CalendarEventStore::default();
+        s.add_range(date(5), date(2), style, in_between_style);
+
+        assert_eq!(s.get_style(date(1)), Style::default());
+        assert_eq!(s.get_style(date(2)), style);
+        assert_eq!(s.get_style(date(3)), in_between_style);
+        assert_eq!(s.get_style(date(4)), in_between_style);
+        assert_eq!(s.get_style(date(5)), style);
+        assert_eq!(s.get_style(date(6)), Style::default());
+    }
+
     #[test]
     fn test_today() {
         CalendarEventStore::today(Style::default());
@@ -5,17 +5,28 @@
 //!
 //! The available shapes are:
 //!
+//! - [`Arc`]: An arc of a circle
 //! - [`Circle`]: A basic circle
+//! - [`Ellipse`]: An ellipse, optionally filled
 //! - [`Line`]: A line between two points
 //! - [`Map`]: A world map
+//! - [`Path`]: A path made of lines and Bezier curves
 //! - [`Points`]: A scatter of points
+//! - [`Polygon`]: A closed polygon, optionally filled
 //! - [`Rectangle`]: A basic rectangle
 //!
 //! You can also implement your own custom [`Shape`]s.
+//!
+//! The canvas can be framed by X and Y axes with labels, ticks and grid lines, see [`CanvasAxis`].
+mod arc;
+mod axis;
 mod circle;
+mod ellipse;
 mod line;
 mod map;
+mod path;
 mod points;
+mod polygon;
 mod rectangle;
 mod world;
 
@@ -24,10 +35,15 @@ use std::{fmt, iter::zip};
 use itertools::Itertools;
 
 pub use self::{
+    arc::Arc,
+    axis::CanvasAxis,
     circle::Circle,
+    ellipse::Ellipse,
     line::Line,
-    map::{Map, MapResolution},
+    map::{Coastlines, Map, MapBounds, MapResolution},
+    path::{Path, PathSegment},
     points::Points,
+    polygon::Polygon,
     rectangle::Rectangle,
 };
 use crate::{prelude::*, text::Line as TextLine, widgets::Block};
@@ -156,6 +172,76 @@ impl Grid for BrailleGrid {
     }
 }
 
+/// The `ShadeGrid` is a grid made up of cells each containing a shade character.
+///
+/// Like the `BrailleGrid`, each cell is a 2x4 grid of dots, which gives the same resolution. But
+/// instead of displaying the dots themselves, each cell displays a shade character ('░', '▒', '▓'
+/// or '█') depending on the number of dots that are on. This is useful to display the density of
+/// the points, and makes lines look anti-aliased.
+#[derive(Debug)]
+struct ShadeGrid {
+    /// Width of the grid in number of terminal columns
+    width: u16,
+    /// Height of the grid in number of terminal rows
+    height: u16,
+    /// The dots of each cell, one bit per dot
+    dots: Vec<u8>,
+    /// The color of each cell
+    colors: Vec<Color>,
+}
+
+impl ShadeGrid {
+    /// Create a new `ShadeGrid` with the given width and height measured in terminal columns and
+    /// rows respectively.
+    fn new(width: u16, height: u16) -> Self {
+        let length = usize::from(width * height);
+        Self {
+            width,
+            height,
+            dots: vec![0; length],
+            colors: vec![Color::Reset; length],
+        }
+    }
+
+    /// The shade character of a cell with the given dots
+    const fn shade(dots: u8) -> char {
+        match dots.count_ones() {
+            0 => symbols::shade::EMPTY,
+            1..=2 => symbols::shade::LIGHT,
+            3..=5 => symbols::shade::MEDIUM,
+            6..=7 => symbols::shade::DARK,
+            _ => symbols::shade::FULL,
+        }
+    }
+}
+
+impl Grid for ShadeGrid {
+    fn resolution(&self) -> (f64, f64) {
+        (f64::from(self.width) * 2.0, f64::from(self.height) * 4.0)
+    }
+
+    fn save(&self) -> Layer {
+        let string = self.dots.iter().map(|&dots| Self::shade(dots)).collect();
+        let colors = self.colors.iter().map(|c| (*c, Color::Reset)).collect();
+        Layer { string, colors }
+    }
+
+    fn reset(&mut self) {
+        self.dots.fill(0);
+        self.colors.fill(Color::Reset);
+    }
+
+    fn paint(&mut self, x: usize, y: usize, color: Color) {
+        let index = y / 4 * self.width as usize + x / 2;
+        if let Some(dots) = self.dots.get_mut(index) {
+            *dots |= 1 << (y % 4 * 2 + x % 2);
+        }
+        if let Some(c) = self.colors.get_mut(index) {
+            *c = color;
+        }
+    }
+}
+
 /// The `CharGrid` is a grid made up of cells each containing a single character.
 ///
 /// This makes it possible to draw shapes with a resolution of 1x1 dots per cell. This is useful
@@ -475,6 +561,7 @@ impl<'a> Context<'a> {
             symbols::Marker::Bar => Box::new(CharGrid::new(width, height, bar)),
             symbols::Marker::Braille => Box::new(BrailleGrid::new(width, height)),
             symbols::Marker::HalfBlock => Box::new(HalfBlockGrid::new(width, height)),
+            symbols::Marker::Shade => Box::new(ShadeGrid::new(width, height)),
         };
         Self {
             x_bounds,
@@ -574,6 +661,7 @@ impl<'a> Context<'a> {
 ///         ctx.draw(&Map {
 ///             resolution: MapResolution::High,
 ///             color: Color::White,
+///             ..Default::default()
 ///         });
 ///         ctx.layer();
 ///         ctx.draw(&Line {
@@ -605,6 +693,8 @@ where
     paint_func: Option<F>,
     background_color: Color,
     marker: symbols::Marker,
+    x_axis: Option<CanvasAxis<'a>>,
+    y_axis: Option<CanvasAxis<'a>>,
 }
 
 impl<'a, F> Default for Canvas<'a, F>
@@ -619,6 +709,8 @@ where
             paint_func: None,
             background_color: Color::Reset,
             marker: symbols::Marker::Braille,
+            x_axis: None,
+            y_axis: None,
         }
     }
 }
@@ -721,6 +813,89 @@ where
         self.marker = marker;
         self
     }
+
+    /// Draws an X axis below the canvas
+    ///
+    /// The axis takes two rows: one for the axis line and its ticks and one for the labels. The
+    /// labels are evenly spaced from the lower to the upper [x bounds](Canvas::x_bounds).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::widgets::canvas::*;
+    ///
+    /// Canvas::default()
+    ///     .x_bounds([0.0, 10.0])
+    ///     .x_axis(CanvasAxis::new(["0", "5", "10"]))
+    ///     .paint(|ctx| {});
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn x_axis(mut self, axis: CanvasAxis<'a>) -> Self {
+        self.x_axis = Some(axis);
+        self
+    }
+
+    /// Draws a Y axis on the left of the canvas
+    ///
+    /// The axis takes as many columns as the widest label, plus one for the axis line and its
+    /// ticks. The labels are evenly spaced from the lower to the upper
+    /// [y bounds](Canvas::y_bounds).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn y_axis(mut self, axis: CanvasAxis<'a>) -> Self {
+        self.y_axis = Some(axis);
+        self
+    }
+
+    /// Splits the canvas area into the graph area and the areas of the Y and X axes
+    fn layout(&self, area: Rect) -> (Rect, Option<Rect>, Option<Rect>) {
+        let y_axis_width = self
+            .y_axis
+            .as_ref()
+            .map_or(0, |axis| axis.labels_width().saturating_add(1))
+            .min(area.width);
+        let x_axis_height = if self.x_axis.is_some() { 2 } else { 0 }.min(area.height);
+        let graph = Rect {
+            x: area.x + y_axis_width,
+            y: area.y,
+            width: area.width - y_axis_width,
+            height: area.height - x_axis_height,
+        };
+        let y_axis = self
+            .y_axis
+            .as_ref()
+            .map(|_| Rect::new(area.x, graph.y, y_axis_width, graph.height));
+        let x_axis = self
+            .x_axis
+            .as_ref()
+            .map(|_| Rect::new(graph.x, graph.bottom(), graph.width, x_axis_height));
+        (graph, y_axis, x_axis)
+    }
+
+    /// Draws the axes and the grid lines around and in the graph area
+    fn render_axes(&self, area: Rect, buf: &mut Buffer) -> Rect {
+        let (graph, y_axis_area, x_axis_area) = self.layout(area);
+        if graph.is_empty() {
+            return graph;
+        }
+        if let Some(axis) = &self.x_axis {
+            axis.render_x_grid(graph, buf);
+        }
+        if let Some(axis) = &self.y_axis {
+            axis.render_y_grid(graph, buf);
+        }
+        if let (Some(axis), Some(axis_area)) = (&self.y_axis, y_axis_area) {
+            let corner = x_axis_area.is_some_and(|area| !area.is_empty());
+            axis.render_y_axis(graph, axis_area, corner, buf);
+        }
+        if let (Some(axis), Some(axis_area)) = (&self.x_axis, x_axis_area) {
+            axis.render_x_axis(graph, axis_area, buf);
+        }
+        graph
+    }
 }
 
 impl<F> Widget for Canvas<'_, F>
@@ -745,6 +920,10 @@ where
 
         buf.set_style(canvas_area, Style::default().bg(self.background_color));
 
+        let canvas_area = self.render_axes(canvas_area, buf);
+        if canvas_area.is_empty() {
+            return;
+        }
         let width = canvas_area.width as usize;
 
         let Some(ref painter) = self.paint_func else {
@@ -891,6 +1070,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_shade_marker() {
+        test_marker(
+            Marker::Shade,
+            indoc!(
+                "
+                ▒xxxx
+                ▒xxxx
+                ▒xxxx
+                ▒xxxx
+                ▒░░░░"
+            ),
+        );
+    }
+
     #[test]
     fn test_dot_marker() {
         test_marker(
@@ -905,4 +1099,68 @@ mod tests {
             ),
         );
     }
+
+    #[test]
+    fn render_axes() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 6));
+        Canvas::default()
+            .marker(Marker::Block)
+            .x_bounds([0.0, 10.0])
+            .y_bounds([0.0, 10.0])
+            .x_axis(CanvasAxis::new(["0", "5", "10"]))
+            .y_axis(CanvasAxis::new(["0", "10"]))
+            .paint(|ctx| ctx.print(10.0, 10.0, "x"))
+            .render(buf.area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::with_lines([
+                "10┤        x",
+                "  │         ",
+                "  │         ",
+                " 0┤         ",
+                "  └┬───┬───┬",
+                "   0   5  10",
+            ])
+        );
+    }
+
+    #[test]
+    fn render_axes_grid() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 5));
+        Canvas::default()
+            .x_bounds([0.0, 10.0])
+            .y_bounds([0.0, 10.0])
+            .x_axis(CanvasAxis::new(["a", "b"]).grid(Style::new()))
+            .y_axis(CanvasAxis::new(["c", "d", "e"]).grid(Style::new()))
+            .paint(|_| {})
+            .render(buf.area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["e┤┼────┼", "d┤┼────┼", "c┤┼────┼", " └┬────┬", "  a    b",])
+        );
+    }
+
+    #[test]
+    fn render_axes_styles() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 3));
+        Canvas::default()
+            .y_axis(CanvasAxis::new([Span::raw("1").bold()]).style(Color::Red))
+            .paint(|_| {})
+            .render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines([" │  ", " │  ", "1┤  "]);
+        expected.set_style(Rect::new(1, 0, 1, 3), Style::new().red());
+        expected.set_style(Rect::new(0, 2, 1, 1), Style::new().red().bold());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_axes_in_small_area() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 2, 1));
+        Canvas::default()
+            .x_axis(CanvasAxis::new(["0", "1"]))
+            .y_axis(CanvasAxis::new(["long label"]))
+            .paint(|_| {})
+            .render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["  "]));
+    }
 }
@@ -0,0 +1,82 @@
+use crate::{
+    style::Color,
+    widgets::canvas::{Line, Painter, Shape},
+};
+
+/// An arc of a circle with a given center and radius and with a given color
+///
+/// Angles are expressed in degrees, counterclockwise from the positive `x` axis. The arc is drawn
+/// from `start_angle` to `end_angle`, so swapping the angles draws the other part of the circle.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Arc {
+    /// `x` coordinate of the arc's center
+    pub x: f64,
+    /// `y` coordinate of the arc's center
+    pub y: f64,
+    /// Radius of the arc
+    pub radius: f64,
+    /// Angle where the arc starts, in degrees
+    pub start_angle: f64,
+    /// Angle where the arc ends, in degrees
+    pub end_angle: f64,
+    /// Color of the arc
+    pub color: Color,
+}
+
+impl Shape for Arc {
+    fn draw(&self, painter: &mut Painter) {
+        let sweep = (self.end_angle - self.start_angle).rem_euclid(360.0);
+        // one segment per degree
+        let segments = sweep.ceil().max(1.0) as u16;
+        let point = |segment: u16| {
+            let angle = f64::from(segment)
+                .mul_add(sweep / f64::from(segments), self.start_angle)
+                .to_radians();
+            (
+                self.radius.mul_add(angle.cos(), self.x),
+                self.radius.mul_add(angle.sin(), self.y),
+            )
+        };
+        for segment in 0..segments {
+            let (x1, y1) = point(segment);
+            let (x2, y2) = point(segment + 1);
+            Line::new(x1, y1, x2, y2, self.color).draw(painter);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{prelude::*, widgets::canvas::Canvas};
+
+    #[test]
+    fn draw_half_circle() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 5));
+        Canvas::default()
+            .marker(Marker::Braille)
+            .x_bounds([-10.0, 10.0])
+            .y_bounds([-10.0, 10.0])
+            .paint(|context| {
+                context.draw(&Arc {
+                    x: 0.0,
+                    y: 0.0,
+                    radius: 8.0,
+                    start_angle: 0.0,
+                    end_angle: 180.0,
+                    color: Color::Reset,
+                });
+            })
+            .render(buffer.area, &mut buffer);
+        assert_eq!(
+            buffer,
+            Buffer::with_lines([
+                "  ⣠⠤⠖⠦⢤⡀  ",
+                " ⡞⠁    ⠙⡆ ",
+                "⠘⠁      ⠙ ",
+                "          ",
+                "          ",
+            ])
+        );
+    }
+}
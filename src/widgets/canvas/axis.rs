@@ -0,0 +1,196 @@
+use crate::{prelude::*, symbols::line};
+
+/// An axis of a [`Canvas`], drawn along its left or bottom side
+///
+/// The labels are evenly spaced along the axis, from the lower bound of the canvas to its upper
+/// bound, and a tick mark is drawn on the axis line next to each label. Grid lines can be drawn
+/// across the canvas at the position of the ticks with [`CanvasAxis::grid`].
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     prelude::*,
+///     widgets::canvas::{Canvas, CanvasAxis},
+/// };
+///
+/// let canvas = Canvas::default()
+///     .x_bounds([0.0, 100.0])
+///     .y_bounds([-1.0, 1.0])
+///     .x_axis(CanvasAxis::new(["0", "50", "100"]))
+///     .y_axis(CanvasAxis::new(["-1", "0", "1"]).grid(Style::new().dark_gray()))
+///     .paint(|ctx| {});
+/// ```
+///
+/// [`Canvas`]: crate::widgets::canvas::Canvas
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct CanvasAxis<'a> {
+    labels: Vec<Span<'a>>,
+    style: Style,
+    grid_style: Option<Style>,
+}
+
+impl<'a> CanvasAxis<'a> {
+    /// Creates a new axis with the given labels
+    ///
+    /// An axis without labels is drawn as a line without ticks.
+    pub fn new<I>(labels: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Span<'a>>,
+    {
+        Self {
+            labels: labels.into_iter().map(Into::into).collect(),
+            style: Style::new(),
+            grid_style: None,
+        }
+    }
+
+    /// Sets the style of the axis line, ticks and labels
+    ///
+    /// The style of each label is patched on top of this style.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Draws grid lines across the canvas at the position of the ticks, with the given style
+    ///
+    /// The grid lines are drawn below the shapes of the canvas.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn grid<S: Into<Style>>(mut self, style: S) -> Self {
+        self.grid_style = Some(style.into());
+        self
+    }
+
+    /// The width of the widest label
+    pub(super) fn labels_width(&self) -> u16 {
+        self.labels
+            .iter()
+            .map(Span::width)
+            .max()
+            .unwrap_or_default() as u16
+    }
+
+    /// The offsets of the ticks from the start of an axis of the given length
+    fn ticks(&self, length: u16) -> impl Iterator<Item = u16> + '_ {
+        let count = self.labels.len();
+        let last = f64::from(length.saturating_sub(1));
+        (0..count).map(move |i| {
+            if count == 1 {
+                0
+            } else {
+                (i as f64 * last / (count - 1) as f64).round() as u16
+            }
+        })
+    }
+
+    /// Draws the vertical grid lines of an X axis in the graph area
+    pub(super) fn render_x_grid(&self, graph: Rect, buf: &mut Buffer) {
+        let Some(style) = self.grid_style else {
+            return;
+        };
+        for dx in self.ticks(graph.width) {
+            for y in graph.top()..graph.bottom() {
+                let cell = buf.get_mut(graph.x + dx, y);
+                let symbol = if cell.symbol() == line::HORIZONTAL {
+                    line::CROSS
+                } else {
+                    line::VERTICAL
+                };
+                cell.set_symbol(symbol).set_style(style);
+            }
+        }
+    }
+
+    /// Draws the horizontal grid lines of a Y axis in the graph area
+    pub(super) fn render_y_grid(&self, graph: Rect, buf: &mut Buffer) {
+        let Some(style) = self.grid_style else {
+            return;
+        };
+        for dy in self.ticks(graph.height) {
+            let y = graph.bottom() - 1 - dy;
+            for x in graph.left()..graph.right() {
+                let cell = buf.get_mut(x, y);
+                let symbol = if cell.symbol() == line::VERTICAL {
+                    line::CROSS
+                } else {
+                    line::HORIZONTAL
+                };
+                cell.set_symbol(symbol).set_style(style);
+            }
+        }
+    }
+
+    /// Draws an X axis below the graph area: the axis line on the first row of `area` and the
+    /// labels on the second row
+    pub(super) fn render_x_axis(&self, graph: Rect, area: Rect, buf: &mut Buffer) {
+        if area.is_empty() {
+            return;
+        }
+        let y = area.top();
+        for x in graph.left()..graph.right() {
+            buf.get_mut(x, y)
+                .set_symbol(line::HORIZONTAL)
+                .set_style(self.style);
+        }
+        let count = self.labels.len();
+        for (i, (dx, label)) in self.ticks(graph.width).zip(&self.labels).enumerate() {
+            let x = graph.x + dx;
+            buf.get_mut(x, y)
+                .set_symbol(line::HORIZONTAL_DOWN)
+                .set_style(self.style);
+            if area.height < 2 {
+                continue;
+            }
+            let width = label.width() as u16;
+            let label_x = if i == 0 {
+                x
+            } else if i == count - 1 {
+                (x + 1).saturating_sub(width)
+            } else {
+                x.saturating_sub(width / 2)
+            };
+            let label_x = label_x.clamp(area.left(), area.right());
+            let label = label.clone().patch_style(self.style);
+            buf.set_span(label_x, y + 1, &label, area.right() - label_x);
+        }
+    }
+
+    /// Draws a Y axis left of the graph area: the labels right aligned in `area` except for its
+    /// last column, which contains the axis line
+    ///
+    /// When `corner` is true, the corner joining the axis line to the X axis is drawn below it.
+    pub(super) fn render_y_axis(&self, graph: Rect, area: Rect, corner: bool, buf: &mut Buffer) {
+        if area.is_empty() {
+            return;
+        }
+        let x = area.right() - 1;
+        for y in graph.top()..graph.bottom() {
+            buf.get_mut(x, y)
+                .set_symbol(line::VERTICAL)
+                .set_style(self.style);
+        }
+        if corner {
+            buf.get_mut(x, graph.bottom())
+                .set_symbol(line::BOTTOM_LEFT)
+                .set_style(self.style);
+        }
+        for (dy, label) in self.ticks(graph.height).zip(&self.labels) {
+            let y = graph.bottom() - 1 - dy;
+            buf.get_mut(x, y)
+                .set_symbol(line::VERTICAL_LEFT)
+                .set_style(self.style);
+            let width = (label.width() as u16).min(area.width - 1);
+            let label = label.clone().patch_style(self.style);
+            buf.set_span(x - width, y, &label, width);
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use crate::{
+    style::Color,
+    widgets::canvas::{Painter, Polygon, Shape},
+};
+
+/// An ellipse with a given center and radii and with a given color
+///
+/// The ellipse is drawn as a polygon of 360 segments. When `filled` is `true`, the inside of the
+/// ellipse is painted as well.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Ellipse {
+    /// `x` coordinate of the ellipse's center
+    pub x: f64,
+    /// `y` coordinate of the ellipse's center
+    pub y: f64,
+    /// Horizontal radius of the ellipse
+    pub x_radius: f64,
+    /// Vertical radius of the ellipse
+    pub y_radius: f64,
+    /// Color of the ellipse
+    pub color: Color,
+    /// Whether the inside of the ellipse is painted
+    pub filled: bool,
+}
+
+impl Shape for Ellipse {
+    fn draw(&self, painter: &mut Painter) {
+        let coords: Vec<(f64, f64)> = (0..360)
+            .map(|angle| {
+                let radians = f64::from(angle).to_radians();
+                (
+                    self.x_radius.mul_add(radians.cos(), self.x),
+                    self.y_radius.mul_add(radians.sin(), self.y),
+                )
+            })
+            .collect();
+        Polygon {
+            coords: &coords,
+            color: self.color,
+            filled: self.filled,
+        }
+        .draw(painter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{prelude::*, widgets::canvas::Canvas};
+
+    #[test]
+    fn draw_filled_ellipse() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 13, 7));
+        Canvas::default()
+            .marker(Marker::Block)
+            .x_bounds([-6.0, 6.0])
+            .y_bounds([-3.0, 3.0])
+            .paint(|context| {
+                context.draw(&Ellipse {
+                    x: 0.0,
+                    y: 0.0,
+                    x_radius: 5.0,
+                    y_radius: 2.0,
+                    color: Color::Reset,
+                    filled: true,
+                });
+            })
+            .render(buffer.area, &mut buffer);
+        assert_eq!(
+            buffer,
+            Buffer::with_lines([
+                "             ",
+                " ██████████  ",
+                " ██████████  ",
+                " ███████████ ",
+                " ██████████  ",
+                "     █       ",
+                "             ",
+            ])
+        );
+    }
+}
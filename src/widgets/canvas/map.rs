@@ -1,16 +1,20 @@
+use std::hash::{Hash, Hasher};
+
 use strum::{Display, EnumString};
 
 use crate::{
     style::Color,
     widgets::canvas::{
         world::{WORLD_HIGH_RESOLUTION, WORLD_LOW_RESOLUTION},
-        Painter, Shape,
+        Line, Painter, Shape,
     },
 };
 
 /// Defines how many points are going to be used to draw a [`Map`].
 ///
-/// You generally want a [high](MapResolution::High) resolution map.
+/// You generally want a [high](MapResolution::High) resolution map. This is the most detailed
+/// dataset embedded in the crate: regional maps that need more detail can draw
+/// [coastlines](Coastlines) loaded from another dataset instead.
 #[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
 pub enum MapResolution {
     /// A lesser resolution for the [`Map`] [`Shape`].
@@ -35,9 +39,165 @@ impl MapResolution {
     }
 }
 
+/// A longitude and latitude bounding box, used to draw a region of a [`Map`]
+///
+/// The bounds can also be used as the bounds of the [`Canvas`], so that the region fills the
+/// canvas instead of being a small part of the whole world.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     prelude::*,
+///     widgets::canvas::{Canvas, Map, MapBounds, MapResolution},
+/// };
+///
+/// let europe = MapBounds::new([-25.0, 45.0], [34.0, 72.0]);
+/// let canvas = Canvas::default()
+///     .marker(Marker::Braille)
+///     .x_bounds(europe.longitude)
+///     .y_bounds(europe.latitude)
+///     .paint(|ctx| {
+///         ctx.draw(&Map {
+///             resolution: MapResolution::High,
+///             bounds: Some(europe),
+///             ..Default::default()
+///         });
+///     });
+/// ```
+///
+/// [`Canvas`]: crate::widgets::canvas::Canvas
+#[derive(Debug, Clone, Copy)]
+pub struct MapBounds {
+    /// The minimum and maximum longitude, in degrees
+    pub longitude: [f64; 2],
+    /// The minimum and maximum latitude, in degrees
+    pub latitude: [f64; 2],
+}
+
+impl MapBounds {
+    /// The bounds of the whole world
+    pub const WORLD: Self = Self::new([-180.0, 180.0], [-90.0, 90.0]);
+
+    /// Creates new bounds from the minimum and maximum longitude and latitude, in degrees
+    pub const fn new(longitude: [f64; 2], latitude: [f64; 2]) -> Self {
+        Self {
+            longitude,
+            latitude,
+        }
+    }
+
+    /// Whether the point at the given longitude and latitude is within the bounds
+    pub fn contains(&self, longitude: f64, latitude: f64) -> bool {
+        (self.longitude[0]..=self.longitude[1]).contains(&longitude)
+            && (self.latitude[0]..=self.latitude[1]).contains(&latitude)
+    }
+
+    fn bits(&self) -> [u64; 4] {
+        [
+            self.longitude[0].to_bits(),
+            self.longitude[1].to_bits(),
+            self.latitude[0].to_bits(),
+            self.latitude[1].to_bits(),
+        ]
+    }
+}
+
+impl Default for MapBounds {
+    fn default() -> Self {
+        Self::WORLD
+    }
+}
+
+// The bounds are compared bit by bit so that they can be used in the `Eq` and `Hash` map
+impl PartialEq for MapBounds {
+    fn eq(&self, other: &Self) -> bool {
+        self.bits() == other.bits()
+    }
+}
+
+impl Eq for MapBounds {}
+
+impl Hash for MapBounds {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.bits().hash(state);
+    }
+}
+
+/// Coastlines supplied by the user to draw a [`Map`] instead of the embedded datasets
+///
+/// The coastlines are polylines of `(longitude, latitude)` points in degrees, e.g. loaded from a
+/// higher resolution dataset such as [Natural Earth](https://www.naturalearthdata.com). Each
+/// polyline is drawn with lines between its consecutive points, so closed shapes should repeat
+/// their first point at the end.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::widgets::canvas::{Coastlines, Map};
+///
+/// let island = vec![(-6.2, 53.9), (-6.0, 53.2), (-6.8, 52.3), (-6.2, 53.9)];
+/// let map = Map {
+///     coastlines: Some(Coastlines::new([island])),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct Coastlines {
+    polylines: Vec<Vec<(f64, f64)>>,
+}
+
+impl Coastlines {
+    /// Creates coastlines from polylines of `(longitude, latitude)` points
+    pub fn new<I, P>(polylines: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: IntoIterator<Item = (f64, f64)>,
+    {
+        Self {
+            polylines: polylines
+                .into_iter()
+                .map(|polyline| polyline.into_iter().collect())
+                .collect(),
+        }
+    }
+
+    /// Returns the polylines of the coastlines
+    pub fn polylines(&self) -> &[Vec<(f64, f64)>] {
+        &self.polylines
+    }
+
+    fn bits(&self) -> impl Iterator<Item = impl Iterator<Item = (u64, u64)> + '_> + '_ {
+        self.polylines
+            .iter()
+            .map(|polyline| polyline.iter().map(|(x, y)| (x.to_bits(), y.to_bits())))
+    }
+}
+
+// The points are compared bit by bit so that the coastlines can be used in the `Eq` and `Hash`
+// map
+impl PartialEq for Coastlines {
+    fn eq(&self, other: &Self) -> bool {
+        self.polylines.len() == other.polylines.len()
+            && self.bits().zip(other.bits()).all(|(a, b)| a.eq(b))
+    }
+}
+
+impl Eq for Coastlines {}
+
+impl Hash for Coastlines {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        for polyline in self.bits() {
+            polyline.collect::<Vec<_>>().hash(state);
+        }
+    }
+}
+
 /// A world map
 ///
-/// A world map can be rendered with different [resolutions](MapResolution) and [colors](Color).
+/// A world map can be rendered with different [resolutions](MapResolution) and [colors](Color),
+/// or from [coastlines](Coastlines) supplied by the user. The map can be limited to a region with
+/// [`MapBounds`].
 #[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
 pub struct Map {
     /// The resolution of the map.
@@ -48,12 +208,37 @@ pub struct Map {
     ///
     /// This is the color of the points of the map.
     pub color: Color,
+    /// The region of the map to draw
+    ///
+    /// Only the points within these bounds are drawn, the whole world is drawn when `None`.
+    pub bounds: Option<MapBounds>,
+    /// Coastlines drawn instead of the embedded dataset of the [`resolution`](Map::resolution)
+    pub coastlines: Option<Coastlines>,
+}
+
+impl Map {
+    fn contains(&self, x: f64, y: f64) -> bool {
+        self.bounds.map_or(true, |bounds| bounds.contains(x, y))
+    }
 }
 
 impl Shape for Map {
     fn draw(&self, painter: &mut Painter) {
-        for (x, y) in self.resolution.data() {
-            if let Some((x, y)) = painter.get_point(*x, *y) {
+        if let Some(coastlines) = &self.coastlines {
+            for polyline in coastlines.polylines() {
+                for (&(x1, y1), &(x2, y2)) in polyline.iter().zip(polyline.iter().skip(1)) {
+                    if self.contains(x1, y1) && self.contains(x2, y2) {
+                        Line::new(x1, y1, x2, y2, self.color).draw(painter);
+                    }
+                }
+            }
+            return;
+        }
+        for &(x, y) in self.resolution.data() {
+            if !self.contains(x, y) {
+                continue;
+            }
+            if let Some((x, y)) = painter.get_point(x, y) {
                 painter.paint(x, y, self.color);
             }
         }
@@ -88,6 +273,80 @@ mod tests {
         let map = Map::default();
         assert_eq!(map.resolution, MapResolution::Low);
         assert_eq!(map.color, Color::Reset);
+        assert_eq!(map.bounds, None);
+        assert_eq!(map.coastlines, None);
+    }
+
+    #[test]
+    fn bounds_contains() {
+        let bounds = MapBounds::new([-10.0, 10.0], [40.0, 50.0]);
+        assert!(bounds.contains(0.0, 45.0));
+        assert!(bounds.contains(-10.0, 50.0));
+        assert!(!bounds.contains(11.0, 45.0));
+        assert!(!bounds.contains(0.0, 39.0));
+        assert!(MapBounds::default().contains(180.0, -90.0));
+    }
+
+    #[test]
+    fn draw_bounds() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 5));
+        let bounds = MapBounds::new([-180.0, 0.0], [-90.0, 90.0]);
+        let canvas = Canvas::default()
+            .marker(Marker::Dot)
+            .x_bounds([-180.0, 180.0])
+            .y_bounds([-90.0, 90.0])
+            .paint(|context| {
+                context.draw(&Map {
+                    bounds: Some(bounds),
+                    ..Default::default()
+                });
+            });
+        canvas.render(buffer.area, &mut buffer);
+        for y in 0..5 {
+            for x in 11..20 {
+                assert_eq!(
+                    buffer.get(x, y).symbol(),
+                    " ",
+                    "({x}, {y}) is out of bounds"
+                );
+            }
+        }
+        assert!(buffer.content().iter().any(|cell| cell.symbol() == "•"));
+    }
+
+    #[test]
+    fn draw_coastlines() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 5));
+        let square = vec![(1.0, 1.0), (8.0, 1.0), (8.0, 3.0), (1.0, 3.0), (1.0, 1.0)];
+        let canvas = Canvas::default()
+            .marker(Marker::Dot)
+            .x_bounds([0.0, 9.0])
+            .y_bounds([0.0, 4.0])
+            .paint(|context| {
+                context.draw(&Map {
+                    coastlines: Some(Coastlines::new([square.clone()])),
+                    ..Default::default()
+                });
+            });
+        canvas.render(buffer.area, &mut buffer);
+        let expected = Buffer::with_lines([
+            "          ",
+            " •••••••• ",
+            " •      • ",
+            " •••••••• ",
+            "          ",
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn coastlines_eq() {
+        let a = Coastlines::new([vec![(0.0, 1.0), (2.0, 3.0)]]);
+        let b = Coastlines::new([vec![(0.0, 1.0), (2.0, 3.0)]]);
+        let c = Coastlines::new([vec![(0.0, 1.0)], vec![(2.0, 3.0)]]);
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert_eq!(a.polylines(), [vec![(0.0, 1.0), (2.0, 3.0)]]);
     }
 
     #[test]
@@ -0,0 +1,307 @@
+use crate::{
+    style::Color,
+    widgets::canvas::{Line, Painter, Shape},
+};
+
+/// A segment of a [`Path`]
+///
+/// Each segment starts at the end of the previous one (the current point of the path).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PathSegment {
+    /// Moves the current point to `(x, y)` without drawing anything
+    MoveTo(f64, f64),
+    /// Draws a straight line to `(x, y)`
+    LineTo(f64, f64),
+    /// Draws a quadratic Bezier curve to `(x, y)` with the control point `(cx, cy)`
+    QuadTo {
+        /// `x` of the control point
+        cx: f64,
+        /// `y` of the control point
+        cy: f64,
+        /// `x` of the end point
+        x: f64,
+        /// `y` of the end point
+        y: f64,
+    },
+    /// Draws a cubic Bezier curve to `(x, y)` with the control points `(cx1, cy1)` and
+    /// `(cx2, cy2)`
+    CubicTo {
+        /// `x` of the first control point
+        cx1: f64,
+        /// `y` of the first control point
+        cy1: f64,
+        /// `x` of the second control point
+        cx2: f64,
+        /// `y` of the second control point
+        cy2: f64,
+        /// `x` of the end point
+        x: f64,
+        /// `y` of the end point
+        y: f64,
+    },
+    /// Draws a straight line back to the point of the last [`PathSegment::MoveTo`]
+    Close,
+}
+
+/// A path made of straight lines and Bezier curves with the given color
+///
+/// A path is built from a list of [`PathSegment`]s, usually with the [`Path::move_to`],
+/// [`Path::line_to`], [`Path::quad_to`], [`Path::cubic_to`] and [`Path::close`] methods. A path
+/// that does not start with [`PathSegment::MoveTo`] starts at `(0.0, 0.0)`.
+///
+/// Curves are approximated with straight lines short enough to look smooth at the resolution of
+/// the canvas, so they are best drawn with [`Marker::Braille`](crate::symbols::Marker::Braille).
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     prelude::*,
+///     widgets::canvas::{Canvas, Path},
+/// };
+///
+/// Canvas::default()
+///     .marker(symbols::Marker::Braille)
+///     .x_bounds([0.0, 10.0])
+///     .y_bounds([0.0, 10.0])
+///     .paint(|ctx| {
+///         ctx.draw(
+///             &Path::new(Color::Yellow)
+///                 .move_to(0.0, 0.0)
+///                 .quad_to(5.0, 10.0, 10.0, 0.0)
+///                 .close(),
+///         );
+///     });
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Path {
+    /// List of the segments of the path
+    pub segments: Vec<PathSegment>,
+    /// Color of the path
+    pub color: Color,
+}
+
+impl Path {
+    /// Create a new empty path with the given color
+    pub const fn new(color: Color) -> Self {
+        Self {
+            segments: Vec::new(),
+            color,
+        }
+    }
+
+    /// Moves the current point to `(x, y)` without drawing anything
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn move_to(mut self, x: f64, y: f64) -> Self {
+        self.segments.push(PathSegment::MoveTo(x, y));
+        self
+    }
+
+    /// Draws a straight line to `(x, y)`
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn line_to(mut self, x: f64, y: f64) -> Self {
+        self.segments.push(PathSegment::LineTo(x, y));
+        self
+    }
+
+    /// Draws a quadratic Bezier curve to `(x, y)` with the control point `(cx, cy)`
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn quad_to(mut self, cx: f64, cy: f64, x: f64, y: f64) -> Self {
+        self.segments.push(PathSegment::QuadTo { cx, cy, x, y });
+        self
+    }
+
+    /// Draws a cubic Bezier curve to `(x, y)` with the control points `(cx1, cy1)` and
+    /// `(cx2, cy2)`
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn cubic_to(mut self, cx1: f64, cy1: f64, cx2: f64, cy2: f64, x: f64, y: f64) -> Self {
+        self.segments.push(PathSegment::CubicTo {
+            cx1,
+            cy1,
+            cx2,
+            cy2,
+            x,
+            y,
+        });
+        self
+    }
+
+    /// Draws a straight line back to the start of the current sub-path
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn close(mut self) -> Self {
+        self.segments.push(PathSegment::Close);
+        self
+    }
+}
+
+impl Shape for Path {
+    fn draw(&self, painter: &mut Painter) {
+        let scale = dot_size(painter);
+        let mut start = (0.0, 0.0);
+        let mut current = (0.0, 0.0);
+        for segment in &self.segments {
+            match *segment {
+                PathSegment::MoveTo(x, y) => {
+                    start = (x, y);
+                    current = start;
+                }
+                PathSegment::LineTo(x, y) => {
+                    self.draw_line(painter, current, (x, y));
+                    current = (x, y);
+                }
+                PathSegment::QuadTo { cx, cy, x, y } => {
+                    let points = [current, (cx, cy), (x, y)];
+                    self.draw_curve(painter, &points, scale);
+                    current = (x, y);
+                }
+                PathSegment::CubicTo {
+                    cx1,
+                    cy1,
+                    cx2,
+                    cy2,
+                    x,
+                    y,
+                } => {
+                    let points = [current, (cx1, cy1), (cx2, cy2), (x, y)];
+                    self.draw_curve(painter, &points, scale);
+                    current = (x, y);
+                }
+                PathSegment::Close => {
+                    self.draw_line(painter, current, start);
+                    current = start;
+                }
+            }
+        }
+    }
+}
+
+impl Path {
+    fn draw_line(&self, painter: &mut Painter, (x1, y1): (f64, f64), (x2, y2): (f64, f64)) {
+        Line::new(x1, y1, x2, y2, self.color).draw(painter);
+    }
+
+    /// Draws a Bezier curve with the given start, control and end points as a list of lines
+    ///
+    /// The number of lines is the length of the control polygon in dots of the grid, which is an
+    /// upper bound of the length of the curve, so that each line covers at most one dot.
+    fn draw_curve(&self, painter: &mut Painter, points: &[(f64, f64)], scale: (f64, f64)) {
+        let length: f64 = points
+            .windows(2)
+            .map(|w| ((w[1].0 - w[0].0) / scale.0).hypot((w[1].1 - w[0].1) / scale.1))
+            .sum();
+        let steps = length.ceil().clamp(1.0, 4096.0) as u16;
+        let mut previous = points[0];
+        for step in 1..=steps {
+            let point = bezier(points, f64::from(step) / f64::from(steps));
+            self.draw_line(painter, previous, point);
+            previous = point;
+        }
+    }
+}
+
+/// Returns the size of a dot of the grid in the coordinate system of the canvas
+fn dot_size(painter: &Painter) -> (f64, f64) {
+    let [left, right] = painter.context.x_bounds;
+    let [bottom, top] = painter.context.y_bounds;
+    let (columns, rows) = painter.resolution;
+    let size = |length: f64, dots: f64| {
+        let size = length.abs() / (dots - 1.0);
+        if size.is_normal() {
+            size
+        } else {
+            f64::INFINITY
+        }
+    };
+    (size(right - left, columns), size(top - bottom, rows))
+}
+
+/// Evaluates the Bezier curve with the given points at `t` with De Casteljau's algorithm
+fn bezier(points: &[(f64, f64)], t: f64) -> (f64, f64) {
+    let mut points = points.to_vec();
+    for len in (1..points.len()).rev() {
+        for i in 0..len {
+            let (x1, y1) = points[i];
+            let (x2, y2) = points[i + 1];
+            points[i] = ((x2 - x1).mul_add(t, x1), (y2 - y1).mul_add(t, y1));
+        }
+    }
+    points[0]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{prelude::*, widgets::canvas::Canvas};
+
+    fn render(path: &Path) -> Buffer {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 5));
+        Canvas::default()
+            .marker(Marker::Braille)
+            .x_bounds([0.0, 10.0])
+            .y_bounds([0.0, 10.0])
+            .paint(|context| context.draw(path))
+            .render(buffer.area, &mut buffer);
+        buffer
+    }
+
+    #[test]
+    fn bezier_end_points() {
+        let points = [(0.0, 0.0), (5.0, 10.0), (10.0, 0.0)];
+        assert_eq!(bezier(&points, 0.0), (0.0, 0.0));
+        assert_eq!(bezier(&points, 0.5), (5.0, 5.0));
+        assert_eq!(bezier(&points, 1.0), (10.0, 0.0));
+    }
+
+    #[test]
+    fn draw_lines() {
+        let path = Path::new(Color::Reset)
+            .move_to(0.0, 0.0)
+            .line_to(10.0, 0.0)
+            .line_to(10.0, 10.0)
+            .close();
+        assert_eq!(
+            render(&path),
+            Buffer::with_lines([
+                "        ⡠⢺",
+                "      ⡠⠊ ⢸",
+                "    ⡠⠊   ⢸",
+                "  ⡠⠊     ⢸",
+                "⣠⣊⣀⣀⣀⣀⣀⣀⣀⣸",
+            ])
+        );
+    }
+
+    #[test]
+    fn draw_quadratic_curve() {
+        let path = Path::new(Color::Reset)
+            .move_to(0.0, 0.0)
+            .quad_to(5.0, 20.0, 10.0, 0.0);
+        assert_eq!(
+            render(&path),
+            Buffer::with_lines([
+                "  ⢠⠞⠉⠙⢦   ",
+                " ⢠⠏   ⠈⢧  ",
+                " ⡎     ⠈⡆ ",
+                "⡸       ⠸⡀",
+                "⡇        ⢇",
+            ])
+        );
+    }
+
+    #[test]
+    fn draw_cubic_curve() {
+        let path = Path::new(Color::Reset)
+            .move_to(0.0, 0.0)
+            .cubic_to(0.0, 10.0, 10.0, 0.0, 10.0, 10.0);
+        assert_eq!(
+            render(&path),
+            Buffer::with_lines([
+                "         ⡏",
+                "        ⣠⠃",
+                "⢀⡤⠖⠒⠒⠒⠒⠋⠁ ",
+                "⡎         ",
+                "⡇         ",
+            ])
+        );
+    }
+}
@@ -0,0 +1,135 @@
+use crate::{
+    style::Color,
+    widgets::canvas::{Line, Painter, Shape},
+};
+
+/// A closed polygon going through the given points with the given color
+///
+/// The last point is connected to the first one. When `filled` is `true`, the inside of the
+/// polygon is painted as well, following the even-odd rule, so self-intersecting polygons have
+/// holes where their edges overlap.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Polygon<'a> {
+    /// List of the vertices of the polygon
+    pub coords: &'a [(f64, f64)],
+    /// Color of the polygon
+    pub color: Color,
+    /// Whether the inside of the polygon is painted
+    pub filled: bool,
+}
+
+impl Shape for Polygon<'_> {
+    fn draw(&self, painter: &mut Painter) {
+        if self.filled {
+            self.fill(painter);
+        }
+        for (i, &(x1, y1)) in self.coords.iter().enumerate() {
+            let (x2, y2) = self.coords[(i + 1) % self.coords.len()];
+            Line::new(x1, y1, x2, y2, self.color).draw(painter);
+        }
+    }
+}
+
+impl Polygon<'_> {
+    /// Paints the points of the grid whose center is inside of the polygon
+    ///
+    /// Each row of the grid is scanned at its center and the segments between pairs of edge
+    /// crossings are painted.
+    fn fill(&self, painter: &mut Painter) {
+        let [left, right] = painter.context.x_bounds;
+        let [bottom, top] = painter.context.y_bounds;
+        let (columns, rows) = painter.resolution;
+        if columns < 2.0 || rows < 2.0 || right <= left || top <= bottom {
+            return;
+        }
+        let dx = (right - left) / (columns - 1.0);
+        let dy = (top - bottom) / (rows - 1.0);
+        let mut crossings = Vec::new();
+        for row in 0..rows as usize {
+            let y = (row as f64 + 0.5).mul_add(-dy, top);
+            crossings.clear();
+            for (i, &(x1, y1)) in self.coords.iter().enumerate() {
+                let (x2, y2) = self.coords[(i + 1) % self.coords.len()];
+                if (y1 <= y) != (y2 <= y) {
+                    crossings.push((y - y1).mul_add((x2 - x1) / (y2 - y1), x1));
+                }
+            }
+            crossings.sort_by(f64::total_cmp);
+            for span in crossings.chunks_exact(2) {
+                let first = ((span[0] - left) / dx - 0.5).ceil().max(0.0);
+                let last = ((span[1] - left) / dx - 0.5).floor().min(columns - 1.0);
+                if first > last {
+                    continue;
+                }
+                for column in first as usize..=last as usize {
+                    painter.paint(column, row, self.color);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{prelude::*, widgets::canvas::Canvas};
+
+    fn render(polygon: &Polygon) -> Buffer {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 5));
+        Canvas::default()
+            .marker(Marker::Block)
+            .x_bounds([0.0, 10.0])
+            .y_bounds([0.0, 5.0])
+            .paint(|context| context.draw(polygon))
+            .render(buffer.area, &mut buffer);
+        buffer
+    }
+
+    #[test]
+    fn draw_outline() {
+        let buffer = render(&Polygon {
+            coords: &[(0.0, 0.0), (9.0, 0.0), (0.0, 4.0)],
+            color: Color::Reset,
+            filled: false,
+        });
+        assert_eq!(
+            buffer,
+            Buffer::with_lines([
+                "██        ",
+                "█ ██      ",
+                "█   ██    ",
+                "█     ██  ",
+                "█████████ ",
+            ])
+        );
+    }
+
+    #[test]
+    fn draw_filled() {
+        let buffer = render(&Polygon {
+            coords: &[(0.0, 0.0), (9.0, 0.0), (0.0, 4.0)],
+            color: Color::Reset,
+            filled: true,
+        });
+        assert_eq!(
+            buffer,
+            Buffer::with_lines([
+                "██        ",
+                "████      ",
+                "██████    ",
+                "████████  ",
+                "█████████ ",
+            ])
+        );
+    }
+
+    #[test]
+    fn draw_empty() {
+        let buffer = render(&Polygon {
+            coords: &[],
+            color: Color::Reset,
+            filled: true,
+        });
+        assert_eq!(buffer, Buffer::empty(Rect::new(0, 0, 10, 5)));
+    }
+}
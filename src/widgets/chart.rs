@@ -1,14 +1,14 @@
-use std::cmp::max;
+use std::{borrow::Cow, cmp::max};
 
 use strum::{Display, EnumString};
 use unicode_width::UnicodeWidthStr;
 
 use crate::{
-    layout::Flex,
+    layout::{Flex, Position},
     prelude::*,
     widgets::{
-        canvas::{Canvas, Line as CanvasLine, Points},
-        Block,
+        canvas::{Canvas, Context, Line as CanvasLine, Points, Polygon},
+        Block, Clear, StatefulWidgetRef,
     },
 };
 
@@ -44,6 +44,10 @@ pub struct Axis<'a> {
     style: Style,
     /// The alignment of the labels of the Axis
     labels_alignment: Alignment,
+    /// The scale used to map the values to positions on the axis
+    scale: Scale,
+    /// Whether the bounds and labels are computed from the data at render time
+    auto_bounds: bool,
 }
 
 impl<'a> Axis<'a> {
@@ -66,6 +70,9 @@ impl<'a> Axis<'a> {
     ///
     /// In other words, sets the min and max value on this axis.
     ///
+    /// On a [logarithmic](Scale::Log10) scale, both bounds must be positive. Otherwise the values
+    /// can't be mapped to the axis, and the datasets and the crosshair are not drawn.
+    ///
     /// This is a fluent setter method which must be chained or used as it consumes self
     #[must_use = "method moves the value of self and returns the modified value"]
     pub const fn bounds(mut self, bounds: [f64; 2]) -> Self {
@@ -135,6 +142,244 @@ impl<'a> Axis<'a> {
         self.labels_alignment = alignment;
         self
     }
+
+    /// Sets the scale of the axis
+    ///
+    /// The scale determines how the values of the data points are mapped to positions on the axis.
+    /// See [`Scale`] for the available scales. The [bounds](Axis::bounds) are always given as
+    /// values, not positions, so a logarithmic axis from 1 to 1000 has the bounds `[1.0, 1000.0]`.
+    ///
+    /// The labels are still spread evenly along the axis, so they should follow the scale, e.g.
+    /// `1`, `10`, `100` and `1000` for the logarithmic axis above.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let axis = Axis::default()
+    ///     .scale(Scale::Log10)
+    ///     .bounds([1.0, 1000.0])
+    ///     .labels(vec!["1".into(), "10".into(), "100".into(), "1000".into()]);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn scale(mut self, scale: Scale) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Sets the labels of the axis to evenly spaced timestamps
+    ///
+    /// The bounds of the axis are interpreted as Unix timestamps in seconds, and `count` labels
+    /// (at least two) are generated between them and formatted in UTC. The format depends on the
+    /// time span of the axis: `YYYY-MM-DD` for spans of at least two days, `HH:MM` for spans of
+    /// at least two minutes and `HH:MM:SS` otherwise.
+    ///
+    /// The labels are computed from the current bounds, so this must be called after
+    /// [`Axis::bounds`].
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let axis = Axis::default()
+    ///     .bounds([1_700_000_000.0, 1_700_003_600.0])
+    ///     .time_labels(3);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn time_labels(mut self, count: usize) -> Self {
+        let [start, end] = self.bounds;
+        let span = end - start;
+        let steps = count.max(2) - 1;
+        let labels = (0..=steps)
+            .map(|i| {
+                let timestamp = span.mul_add(i as f64 / steps as f64, start);
+                Span::raw(format_timestamp(timestamp, span))
+            })
+            .collect();
+        self.labels = Some(labels);
+        self
+    }
+
+    /// Computes the bounds of the axis from the data when the chart is rendered
+    ///
+    /// The bounds are extended to include all the points of the datasets of the chart, rounded to
+    /// "nice" values, and labels are generated at evenly spaced ticks. On a linear scale the ticks
+    /// are 1, 2 or 5 times a power of ten apart, and on a [logarithmic](Scale::Log10) scale they
+    /// are powers of ten. Labels set with [`Axis::labels`] are kept instead of the generated ones.
+    ///
+    /// If the datasets have no points, the bounds set with [`Axis::bounds`] are used instead of the
+    /// data.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let data = [(0.0, 3.0), (1.0, 7.5), (2.0, 12.0)];
+    /// let chart = Chart::new(vec![Dataset::default().data(&data)])
+    ///     .x_axis(Axis::default().bounds_auto())
+    ///     .y_axis(Axis::default().bounds_auto());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn bounds_auto(mut self) -> Self {
+        self.auto_bounds = true;
+        self
+    }
+
+    /// Sets the bounds and labels of an axis with automatic bounds from the given values
+    fn resolve_auto_bounds(&mut self, values: impl Iterator<Item = f64>) {
+        if !self.auto_bounds {
+            return;
+        }
+        self.auto_bounds = false;
+        let (min, max) = values
+            .map(|value| self.scale.apply(value))
+            .filter(|value| value.is_finite())
+            .fold(None, |bounds, value| match bounds {
+                None => Some((value, value)),
+                Some((min, max)) => Some((f64::min(min, value), f64::max(max, value))),
+            })
+            .unwrap_or_else(|| {
+                (
+                    self.scale.apply(self.bounds[0]),
+                    self.scale.apply(self.bounds[1]),
+                )
+            });
+        if !min.is_finite() || !max.is_finite() {
+            return;
+        }
+        let ticks = match self.scale {
+            Scale::Linear => nice_ticks(min, max, AUTO_TICKS),
+            // one tick per power of ten, or fewer if the range spans many of them
+            Scale::Log10 => {
+                let (min, max) = (min.floor(), max.ceil().max(min.floor() + 1.0));
+                let step = ((max - min) / (AUTO_TICKS - 1) as f64).ceil();
+                let max = (((max - min) / step).ceil()).mul_add(step, min);
+                let ticks = ((max - min) / step).round() as usize + 1;
+                (0..ticks)
+                    .map(|i| (i as f64).mul_add(step, min))
+                    .map(|exponent| (10f64.powf(exponent), format!("{}", 10f64.powf(exponent))))
+                    .collect()
+            }
+        };
+        let (Some(first), Some(last)) = (ticks.first(), ticks.last()) else {
+            return;
+        };
+        self.bounds = [first.0, last.0];
+        if self.labels.is_none() {
+            self.labels = Some(ticks.into_iter().map(|(_, label)| label.into()).collect());
+        }
+    }
+
+    /// Returns the bounds of the axis mapped to its scale, or `None` if they can't be represented
+    /// (e.g. non-positive bounds on a logarithmic scale)
+    fn scaled_bounds(&self) -> Option<[f64; 2]> {
+        let bounds = self.bounds.map(|bound| self.scale.apply(bound));
+        bounds
+            .iter()
+            .all(|bound| bound.is_finite())
+            .then_some(bounds)
+    }
+}
+
+/// The scale of an [`Axis`], which maps the values of the data points to positions on the axis
+///
+/// See [`Axis::scale`]
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Scale {
+    /// Positions are proportional to the values. This is the default.
+    #[default]
+    Linear,
+    /// Positions are proportional to the base 10 logarithm of the values.
+    ///
+    /// Non-positive values can't be represented on this scale and the points that have such a
+    /// value are not drawn. Non-positive [bounds](Axis::bounds) are rejected the same way: the
+    /// datasets are not drawn at all.
+    Log10,
+}
+
+impl Scale {
+    /// Maps a value to its position on the scale
+    fn apply(self, value: f64) -> f64 {
+        match self {
+            Self::Linear => value,
+            Self::Log10 if value > 0.0 => value.log10(),
+            Self::Log10 => f64::NAN,
+        }
+    }
+
+    /// Maps a position on the scale back to a value
+    fn invert(self, position: f64) -> f64 {
+        match self {
+            Self::Linear => position,
+            Self::Log10 => 10f64.powf(position),
+        }
+    }
+}
+
+/// The approximate number of ticks generated for an axis with automatic bounds
+const AUTO_TICKS: usize = 5;
+
+/// Returns about `count` evenly spaced ticks covering `min` to `max` with their labels
+///
+/// The ticks are 1, 2 or 5 times a power of ten apart, and start and end on a multiple of that
+/// step.
+fn nice_ticks(min: f64, max: f64, count: usize) -> Vec<(f64, String)> {
+    let (min, max) = if min < max {
+        (min, max)
+    } else {
+        (min - 1.0, max + 1.0)
+    };
+    let rough_step = (max - min) / (count.max(2) - 1) as f64;
+    let magnitude = 10f64.powf(rough_step.log10().floor());
+    let step = [1.0, 2.0, 5.0, 10.0]
+        .into_iter()
+        .map(|factor| factor * magnitude)
+        .find(|&step| step >= rough_step)
+        .unwrap_or(rough_step);
+    let first = (min / step).floor();
+    let last = (max / step).ceil();
+    let decimals = (-step.log10().floor()).max(0.0) as usize;
+    (0..=(last - first) as usize)
+        .map(|i| {
+            // adding zero turns -0.0 into 0.0 so that it is not printed as "-0"
+            let tick = (first + i as f64).mul_add(step, 0.0);
+            (tick, format!("{tick:.decimals$}"))
+        })
+        .collect()
+}
+
+/// Formats a Unix timestamp in seconds for an axis label, with a precision suited to the time span
+/// of the axis
+fn format_timestamp(timestamp: f64, span: f64) -> String {
+    const MINUTE: i64 = 60;
+    const DAY: i64 = 24 * 60 * MINUTE;
+    let timestamp = timestamp.round() as i64;
+    let (days, seconds) = (timestamp.div_euclid(DAY), timestamp.rem_euclid(DAY));
+    let (hours, minutes, seconds) = (seconds / 3600, seconds / 60 % 60, seconds % 60);
+    if span.abs() >= (2 * DAY) as f64 {
+        // days to civil date, see http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days.rem_euclid(146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * month + 2) / 5 + 1;
+        let month = if month < 10 { month + 3 } else { month - 9 };
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+        format!("{year:04}-{month:02}-{day:02}")
+    } else if span.abs() >= (2 * MINUTE) as f64 {
+        format!("{hours:02}:{minutes:02}")
+    } else {
+        format!("{hours:02}:{minutes:02}:{seconds:02}")
+    }
 }
 
 /// Used to determine which style of graphing to use
@@ -148,6 +393,38 @@ pub enum GraphType {
     /// The order of the lines will be the same as the order of the points in the dataset, which
     /// allows this widget to draw lines both left-to-right and right-to-left
     Line,
+    /// Draw a line between each following point and fill the area under it.
+    ///
+    /// The area is filled down to the bottom of the y axis, or down to the previous dataset when
+    /// the chart is [stacked](Chart::stacked). The points should be sorted by their x coordinate.
+    Area,
+}
+
+/// The resolution used to plot the datasets of a [`Chart`]
+///
+/// See [`Chart::resolution`]
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ChartResolution {
+    /// Each dataset is plotted with its own [marker](Dataset::marker). This is the default.
+    #[default]
+    Marker,
+    /// All the datasets are plotted with [`Marker::Braille`](symbols::Marker::Braille), which has
+    /// a resolution of 2x4 dots per cell.
+    Braille,
+    /// All the datasets are plotted with [`Marker::Shade`](symbols::Marker::Shade), which has the
+    /// resolution of Braille but shades each cell according to the density of its dots.
+    Shade,
+}
+
+impl ChartResolution {
+    /// The marker used to plot a dataset with the given marker
+    const fn marker(self, marker: symbols::Marker) -> symbols::Marker {
+        match self {
+            Self::Marker => marker,
+            Self::Braille => symbols::Marker::Braille,
+            Self::Shade => symbols::Marker::Shade,
+        }
+    }
 }
 
 /// Allow users to specify the position of a legend in a [`Chart`]
@@ -268,6 +545,12 @@ impl LegendPosition {
     }
 }
 
+/// Computes the style of a data point, see [`Dataset::point_style`]
+type PointStyleFn = fn(&(f64, f64)) -> Style;
+
+/// Computes the symbol of a data point, see [`Dataset::point_symbol`]
+type PointSymbolFn<'a> = fn(&(f64, f64)) -> Option<&'a str>;
+
 /// A group of data points
 ///
 /// This is the main element composing a [`Chart`].
@@ -294,7 +577,7 @@ impl LegendPosition {
 ///     .graph_type(GraphType::Line)
 ///     .red();
 /// ```
-#[derive(Debug, Default, Clone, PartialEq)]
+#[derive(Debug, Default, Clone)]
 pub struct Dataset<'a> {
     /// Name of the dataset (used in the legend if shown)
     name: Option<Line<'a>>,
@@ -306,6 +589,10 @@ pub struct Dataset<'a> {
     graph_type: GraphType,
     /// Style used to plot this dataset
     style: Style,
+    /// Style of each point, patched on top of the dataset's style
+    point_style: Option<PointStyleFn>,
+    /// Symbol drawn in place of the marker for each point
+    point_symbol: Option<PointSymbolFn<'a>>,
 }
 
 impl<'a> Dataset<'a> {
@@ -346,8 +633,10 @@ impl<'a> Dataset<'a> {
 
     /// Sets the kind of character to use to display this dataset
     ///
-    /// You can use dots (`•`), blocks (`█`), bars (`▄`), braille (`⠓`, `⣇`, `⣿`) or half-blocks
-    /// (`█`, `▄`, and `▀`). See [`symbols::Marker`] for more details.
+    /// You can use dots (`•`), blocks (`█`), bars (`▄`), braille (`⠓`, `⣇`, `⣿`), half-blocks
+    /// (`█`, `▄`, and `▀`) or shades (`░`, `▒`, `▓`). See [`symbols::Marker`] for more details.
+    ///
+    /// The marker can be overridden for all the datasets with [`Chart::resolution`].
     ///
     /// Note [`Marker::Braille`](symbols::Marker::Braille) requires a font that supports Unicode
     /// Braille Patterns.
@@ -361,9 +650,10 @@ impl<'a> Dataset<'a> {
 
     /// Sets how the dataset should be drawn
     ///
-    /// [`Chart`] can draw either a [scatter](GraphType::Scatter) or [line](GraphType::Line) charts.
-    /// A scatter will draw only the points in the dataset while a line will also draw a line
-    /// between them. See [`GraphType`] for more details
+    /// [`Chart`] can draw [scatter](GraphType::Scatter), [line](GraphType::Line) or
+    /// [area](GraphType::Area) charts. A scatter will draw only the points in the dataset while a
+    /// line will also draw a line between them, and an area will also fill the area under the line.
+    /// See [`GraphType`] for more details
     ///
     /// This is a fluent setter method which must be chained or used as it consumes self
     #[must_use = "method moves the value of self and returns the modified value"]
@@ -396,6 +686,216 @@ impl<'a> Dataset<'a> {
         self.style = style.into();
         self
     }
+
+    /// Sets a function that computes the style of each point of this dataset
+    ///
+    /// The function is called with each data point, before stacking and scaling, and the returned
+    /// style is patched on top of the [style](Dataset::style) of the dataset. This can be used to
+    /// highlight some of the points of a single dataset, e.g. outliers or values above a
+    /// threshold. Like the dataset's style, only the foreground is used to draw the marker of a
+    /// point. The lines between points still use the dataset's style.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let dataset = Dataset::default()
+    ///     .data(&[(0.0, 1.0), (1.0, 12.0), (2.0, 3.0)])
+    ///     .graph_type(GraphType::Scatter)
+    ///     .green()
+    ///     .point_style(|&(_, y)| {
+    ///         if y > 10.0 {
+    ///             Style::new().red()
+    ///         } else {
+    ///             Style::new()
+    ///         }
+    ///     });
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn point_style(mut self, point_style: fn(&(f64, f64)) -> Style) -> Self {
+        self.point_style = Some(point_style);
+        self
+    }
+
+    /// Sets a function that returns the symbol drawn for each point of this dataset
+    ///
+    /// The function is called with each data point, before stacking and scaling. When it returns a
+    /// symbol, the symbol is printed on top of the chart at the position of the point, in the
+    /// style of the point (see [`Dataset::point_style`]), instead of the dataset's
+    /// [marker](Dataset::marker). When it returns `None`, the point is drawn with the marker.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let dataset = Dataset::default()
+    ///     .data(&[(0.0, 1.0), (1.0, 12.0), (2.0, 3.0)])
+    ///     .point_symbol(|&(_, y)| (y > 10.0).then_some("✖"));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn point_symbol(mut self, point_symbol: fn(&(f64, f64)) -> Option<&'a str>) -> Self {
+        self.point_symbol = Some(point_symbol);
+        self
+    }
+
+    /// Draws the points of this dataset, with their own style and symbol if any
+    ///
+    /// `data` are the points as drawn (after stacking), in the same order as the dataset's data.
+    fn draw_points(&self, ctx: &mut Context, data: &[(f64, f64)], x_axis: &Axis, y_axis: &Axis) {
+        for (point, &(x, y)) in self.data.iter().zip(data) {
+            let (x, y) = (x_axis.scale.apply(x), y_axis.scale.apply(y));
+            if !x.is_finite() || !y.is_finite() {
+                continue;
+            }
+            let style = self.point_style.map_or(self.style, |point_style| {
+                self.style.patch(point_style(point))
+            });
+            if let Some(symbol) = self
+                .point_symbol
+                .and_then(|point_symbol| point_symbol(point))
+            {
+                ctx.print(x, y, Span::styled(symbol.to_string(), style));
+            } else {
+                ctx.draw(&Points {
+                    coords: &[(x, y)],
+                    color: style.fg.unwrap_or(Color::Reset),
+                });
+            }
+        }
+    }
+}
+
+// Implemented manually because the point functions are compared by address, which the derived
+// implementation warns about
+impl PartialEq for Dataset<'_> {
+    #[allow(clippy::fn_to_numeric_cast_any)]
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && self.data == other.data
+            && self.marker == other.marker
+            && self.graph_type == other.graph_type
+            && self.style == other.style
+            && self.point_style.map(|f| f as usize) == other.point_style.map(|f| f as usize)
+            && self.point_symbol.map(|f| f as usize) == other.point_symbol.map(|f| f as usize)
+    }
+}
+
+/// State of a [`Chart`], which holds the position of an interactive crosshair
+///
+/// When the state has an [x value](ChartState::x) inside the bounds of the X axis, the chart draws
+/// a vertical crosshair line at this value and a floating readout with the value of each dataset
+/// at this position. The x value can be set directly with [`ChartState::select_x`], moved from
+/// point to point with [`ChartState::select_next_point`] and
+/// [`ChartState::select_previous_point`] (e.g. with the arrow keys), or set from a mouse position
+/// with [`ChartState::select_position`].
+///
+/// The state records the graph area and the X axis of the last render, so that mouse positions
+/// can be mapped to values.
+///
+/// # Example
+///
+/// ```rust
+/// # use ratatui::{prelude::*, widgets::*};
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// let data = [(0.0, 1.0), (1.0, 3.0), (2.0, 2.0)];
+/// let chart = Chart::new(vec![Dataset::default().name("data").data(&data)])
+///     .x_axis(Axis::default().bounds([0.0, 2.0]))
+///     .y_axis(Axis::default().bounds([0.0, 3.0]))
+///     .crosshair_style(Style::new().yellow());
+///
+/// // This should be stored outside of the function in your application state.
+/// let mut state = ChartState::default();
+/// state.select_next_point(&data);
+///
+/// frame.render_stateful_widget(chart, area, &mut state);
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ChartState {
+    x: Option<f64>,
+    /// The graph area during the last render
+    graph_area: Rect,
+    /// The bounds of the X axis during the last render
+    x_bounds: [f64; 2],
+    /// The scale of the X axis during the last render
+    x_scale: Scale,
+}
+
+impl ChartState {
+    /// Creates a new state without crosshair
+    pub const fn new() -> Self {
+        Self {
+            x: None,
+            graph_area: Rect::ZERO,
+            x_bounds: [0.0, 0.0],
+            x_scale: Scale::Linear,
+        }
+    }
+
+    /// The x value of the crosshair, if any
+    pub const fn x(&self) -> Option<f64> {
+        self.x
+    }
+
+    /// Sets the x value of the crosshair, or hides it with `None`
+    pub fn select_x(&mut self, x: Option<f64>) {
+        self.x = x;
+    }
+
+    /// Moves the crosshair to the first point of `data` after the current x value
+    ///
+    /// When the crosshair is hidden, it is moved to the first point. It does not move when there
+    /// is no point after it. The points are expected to be sorted by their x coordinate.
+    pub fn select_next_point(&mut self, data: &[(f64, f64)]) {
+        let next = match self.x {
+            Some(x) => data.iter().find(|&&(px, _)| px > x),
+            None => data.first(),
+        };
+        if let Some(&(x, _)) = next {
+            self.x = Some(x);
+        }
+    }
+
+    /// Moves the crosshair to the last point of `data` before the current x value
+    ///
+    /// When the crosshair is hidden, it is moved to the last point. It does not move when there is
+    /// no point before it. The points are expected to be sorted by their x coordinate.
+    pub fn select_previous_point(&mut self, data: &[(f64, f64)]) {
+        let previous = match self.x {
+            Some(x) => data.iter().rev().find(|&&(px, _)| px < x),
+            None => data.last(),
+        };
+        if let Some(&(x, _)) = previous {
+            self.x = Some(x);
+        }
+    }
+
+    /// Moves the crosshair to the x value at the given position during the last render, e.g. to
+    /// follow the mouse
+    ///
+    /// The crosshair is hidden when the position is outside of the graph area. Returns the new x
+    /// value.
+    pub fn select_position(&mut self, position: Position) -> Option<f64> {
+        self.x = self.graph_area.contains(position).then(|| {
+            let [left, right] = self.x_bounds.map(|bound| self.x_scale.apply(bound));
+            let column = f64::from(position.x - self.graph_area.x);
+            let last = f64::from(self.graph_area.width.saturating_sub(1)).max(1.0);
+            self.x_scale.invert(left + column * (right - left) / last)
+        });
+        // the bounds can't be mapped to a logarithmic scale if they are not positive
+        self.x = self.x.filter(|x| x.is_finite());
+        self.x
+    }
+
+    /// The area in which the data was plotted during the last render
+    pub const fn graph_area(&self) -> Rect {
+        self.graph_area
+    }
 }
 
 /// A container that holds all the infos about where to display each elements of the chart (axis,
@@ -496,6 +996,14 @@ pub struct Chart<'a> {
     /// The position determine where the length is shown or hide regardless of
     /// `hidden_legend_constraints`
     legend_position: Option<LegendPosition>,
+    /// Whether the datasets are stacked on top of each other
+    stacked: bool,
+    /// The resolution used to plot the datasets
+    resolution: ChartResolution,
+    /// The style of the crosshair line drawn at the x value of the [`ChartState`]
+    crosshair_style: Style,
+    /// The number of decimals of the values in the crosshair readout
+    readout_precision: Option<usize>,
 }
 
 impl<'a> Chart<'a> {
@@ -533,6 +1041,10 @@ impl<'a> Chart<'a> {
             datasets,
             hidden_legend_constraints: (Constraint::Ratio(1, 4), Constraint::Ratio(1, 4)),
             legend_position: Some(LegendPosition::default()),
+            stacked: false,
+            resolution: ChartResolution::Marker,
+            crosshair_style: Style::default(),
+            readout_precision: None,
         }
     }
 
@@ -688,6 +1200,212 @@ impl<'a> Chart<'a> {
         self
     }
 
+    /// Sets whether the datasets are stacked on top of each other
+    ///
+    /// When stacked, the y value of each point is added to the values of the previous datasets at
+    /// the same x coordinate, linearly interpolated between their points. Combined with
+    /// [`GraphType::Area`], this draws a stacked area chart, e.g. to show how a total is broken
+    /// down. The points of each dataset should be sorted by their x coordinate.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let user = [(0.0, 20.0), (1.0, 30.0), (2.0, 25.0)];
+    /// let system = [(0.0, 10.0), (1.0, 5.0), (2.0, 15.0)];
+    /// let chart = Chart::new(vec![
+    ///     Dataset::default()
+    ///         .name("user")
+    ///         .data(&user)
+    ///         .graph_type(GraphType::Area)
+    ///         .green(),
+    ///     Dataset::default()
+    ///         .name("system")
+    ///         .data(&system)
+    ///         .graph_type(GraphType::Area)
+    ///         .red(),
+    /// ])
+    /// .stacked(true);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn stacked(mut self, stacked: bool) -> Self {
+        self.stacked = stacked;
+        self
+    }
+
+    /// Sets the resolution used to plot the datasets
+    ///
+    /// By default each dataset is plotted with its own [marker](Dataset::marker), which often
+    /// draws one point per cell. With [`ChartResolution::Braille`] all the lines and points are
+    /// plotted with the sub-cell resolution of Braille patterns, as in a
+    /// [`Canvas`](crate::widgets::canvas::Canvas), and with [`ChartResolution::Shade`] the cells
+    /// are additionally shaded according to the density of the points, which makes the lines look
+    /// anti-aliased.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let data = [(0.0, 0.0), (1.0, 2.0), (2.0, 1.0)];
+    /// let chart = Chart::new(vec![Dataset::default()
+    ///     .data(&data)
+    ///     .graph_type(GraphType::Line)])
+    /// .resolution(ChartResolution::Shade);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn resolution(mut self, resolution: ChartResolution) -> Self {
+        self.resolution = resolution;
+        self
+    }
+
+    /// Sets the style of the crosshair line drawn at the x value of the [`ChartState`]
+    ///
+    /// The crosshair is only drawn on the empty cells of the graph, so that it does not hide the
+    /// data.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn crosshair_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.crosshair_style = style.into();
+        self
+    }
+
+    /// Sets the number of decimals of the values displayed in the crosshair readout
+    ///
+    /// The default is 2.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn readout_precision(mut self, precision: usize) -> Self {
+        self.readout_precision = Some(precision);
+        self
+    }
+
+    /// Draws the crosshair at the x value of the state and the readout of the datasets' values
+    fn render_crosshair(&self, graph_area: Rect, x: f64, buf: &mut Buffer) {
+        let Some([left, right]) = self.x_axis.scaled_bounds() else {
+            return;
+        };
+        let position = self.x_axis.scale.apply(x);
+        if graph_area.is_empty() || !(left.min(right)..=left.max(right)).contains(&position) {
+            return;
+        }
+        let last = f64::from(graph_area.width - 1);
+        let column = graph_area.x + ((position - left) * last / (right - left)) as u16;
+        for y in graph_area.top()..graph_area.bottom() {
+            let cell = buf.get_mut(column, y);
+            if cell.symbol() == " " {
+                cell.set_symbol(symbols::line::VERTICAL)
+                    .set_style(self.crosshair_style);
+            }
+        }
+
+        let precision = self.readout_precision.unwrap_or(2);
+        let mut lines = vec![Line::from(format!("x: {x:.precision$}"))];
+        for dataset in &self.datasets {
+            let (Some(&(first, _)), Some(&(last, _))) = (dataset.data.first(), dataset.data.last())
+            else {
+                continue;
+            };
+            if !(first..=last).contains(&x) {
+                continue;
+            }
+            let value = format!("{:.precision$}", interpolate(dataset.data, x));
+            let line = match &dataset.name {
+                Some(name) => {
+                    let mut line = name.clone();
+                    line.spans.push(Span::raw(": "));
+                    line.spans.push(Span::raw(value));
+                    line
+                }
+                None => Line::from(value),
+            };
+            lines.push(line.patch_style(dataset.style));
+        }
+
+        let width = lines.iter().map(Line::width).max().unwrap_or_default() as u16 + 2;
+        let height = lines.len() as u16 + 2;
+        let x = if column + 1 + width <= graph_area.right() {
+            column + 1
+        } else {
+            column.saturating_sub(width).max(graph_area.x)
+        };
+        let readout_area = Rect::new(x, graph_area.y, width, height).intersection(graph_area);
+        Clear.render(readout_area, buf);
+        buf.set_style(readout_area, self.style);
+        let block = Block::bordered();
+        let inner = block.inner(readout_area);
+        block.render(readout_area, buf);
+        for (y, line) in (inner.top()..inner.bottom()).zip(lines) {
+            line.render(Rect::new(inner.x, y, inner.width, 1), buf);
+        }
+    }
+
+    /// Returns the points of the dataset at the given index, stacked on top of the previous
+    /// datasets if the chart is stacked
+    fn stacked_data(&self, index: usize) -> Cow<'a, [(f64, f64)]> {
+        let data = self.datasets[index].data;
+        if !self.stacked {
+            return Cow::Borrowed(data);
+        }
+        let below = &self.datasets[..index];
+        data.iter()
+            .map(|&(x, y)| {
+                let offset: f64 = below.iter().map(|d| interpolate(d.data, x)).sum();
+                (x, y + offset)
+            })
+            .collect()
+    }
+
+    /// Returns a copy of the chart where the axes with automatic bounds have their bounds and
+    /// labels computed from the datasets
+    fn with_auto_bounds(&self) -> Self {
+        let mut chart = self.clone();
+        let data: Vec<_> = (0..self.datasets.len())
+            .map(|i| self.stacked_data(i))
+            .collect();
+        let points = || data.iter().flat_map(|data| data.iter());
+        chart.x_axis.resolve_auto_bounds(points().map(|&(x, _)| x));
+        chart.y_axis.resolve_auto_bounds(points().map(|&(_, y)| y));
+        chart
+    }
+
+    /// Maps the given points to the scales of the axes, leaving out the points that can't be
+    /// represented (e.g. non-positive values on a logarithmic scale)
+    fn scale_coords<'b>(&self, coords: &'b [(f64, f64)]) -> Cow<'b, [(f64, f64)]> {
+        if self.x_axis.scale == Scale::Linear && self.y_axis.scale == Scale::Linear {
+            return Cow::Borrowed(coords);
+        }
+        coords
+            .iter()
+            .map(|&(x, y)| (self.x_axis.scale.apply(x), self.y_axis.scale.apply(y)))
+            .filter(|(x, y)| x.is_finite() && y.is_finite())
+            .collect()
+    }
+
+    /// Returns the outline of the area under the given points of a dataset
+    ///
+    /// `data` are the points as drawn, `raw` the points of the dataset before stacking. The area
+    /// goes down to the previous datasets when stacked, and to the bottom of the y axis otherwise.
+    fn area_coords(&self, data: &[(f64, f64)], raw: &[(f64, f64)]) -> Vec<(f64, f64)> {
+        let bottom = self.y_axis.bounds[0];
+        let baseline = data.iter().zip(raw).rev().map(|(&(x, y), &(_, raw_y))| {
+            if self.stacked {
+                (x, y - raw_y)
+            } else {
+                (x, bottom)
+            }
+        });
+        data.iter().copied().chain(baseline).collect()
+    }
+
     /// Compute the internal layout of the chart given the area. If the area is too small some
     /// elements may be automatically hidden
     fn layout(&self, area: Rect) -> Option<ChartLayout> {
@@ -813,7 +1531,7 @@ impl<'a> Chart<'a> {
         {
             let first_label_width = first_x_label.content.width() as u16;
             let width_left_of_y_axis = match self.x_axis.labels_alignment {
-                Alignment::Left => {
+                Alignment::Left | Alignment::Justify => {
                     // The last character of the label should be below the Y-Axis when it exists,
                     // not on its left
                     let y_axis_offset = u16::from(has_y_axis);
@@ -853,7 +1571,7 @@ impl<'a> Chart<'a> {
         );
 
         let label_alignment = match self.x_axis.labels_alignment {
-            Alignment::Left => Alignment::Right,
+            Alignment::Left | Alignment::Justify => Alignment::Right,
             Alignment::Center => Alignment::Center,
             Alignment::Right => Alignment::Left,
         };
@@ -884,7 +1602,7 @@ impl<'a> Chart<'a> {
         graph_area: Rect,
     ) -> Rect {
         let (min_x, max_x) = match self.x_axis.labels_alignment {
-            Alignment::Left => (chart_area.left(), graph_area.left()),
+            Alignment::Left | Alignment::Justify => (chart_area.left(), graph_area.left()),
             Alignment::Center => (
                 chart_area.left(),
                 graph_area.left() + max_width_after_y_axis.min(label_width),
@@ -903,7 +1621,7 @@ impl<'a> Chart<'a> {
         let bounded_label_width = label_area.width.min(label_width);
 
         let x = match alignment {
-            Alignment::Left => label_area.left(),
+            Alignment::Left | Alignment::Justify => label_area.left(),
             Alignment::Center => label_area.left() + label_area.width / 2 - bounded_label_width / 2,
             Alignment::Right => label_area.right() - bounded_label_width,
         };
@@ -938,13 +1656,56 @@ impl<'a> Chart<'a> {
 
 impl Widget for Chart<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        self.render_ref(area, buf);
+        WidgetRef::render_ref(&self, area, buf);
+    }
+}
+
+impl StatefulWidget for Chart<'_> {
+    type State = ChartState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidgetRef::render_ref(&self, area, buf, state);
+    }
+}
+
+// Note: remove this when StatefulWidgetRef is stabilized and replace with the blanket impl
+impl StatefulWidget for &Chart<'_> {
+    type State = ChartState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidgetRef::render_ref(self, area, buf, state);
+    }
+}
+
+impl StatefulWidgetRef for Chart<'_> {
+    type State = ChartState;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        if self.x_axis.auto_bounds || self.y_axis.auto_bounds {
+            return StatefulWidgetRef::render_ref(&self.with_auto_bounds(), area, buf, state);
+        }
+        WidgetRef::render_ref(self, area, buf);
+
+        let chart_area = self.block.inner_if_some(area);
+        let graph_area = self
+            .layout(chart_area)
+            .map_or(Rect::ZERO, |layout| layout.graph_area);
+        state.graph_area = graph_area;
+        state.x_bounds = self.x_axis.bounds;
+        state.x_scale = self.x_axis.scale;
+        if let Some(x) = state.x {
+            self.render_crosshair(graph_area, x, buf);
+        }
     }
 }
 
 impl WidgetRef for Chart<'_> {
     #[allow(clippy::too_many_lines)]
     fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        if self.x_axis.auto_bounds || self.y_axis.auto_bounds {
+            return WidgetRef::render_ref(&self.with_auto_bounds(), area, buf);
+        }
+
         buf.set_style(area, self.style);
 
         self.block.render_ref(area, buf);
@@ -986,30 +1747,55 @@ impl WidgetRef for Chart<'_> {
             }
         }
 
-        for dataset in &self.datasets {
-            Canvas::default()
-                .background_color(self.style.bg.unwrap_or(Color::Reset))
-                .x_bounds(self.x_axis.bounds)
-                .y_bounds(self.y_axis.bounds)
-                .marker(dataset.marker)
-                .paint(|ctx| {
-                    ctx.draw(&Points {
-                        coords: dataset.data,
-                        color: dataset.style.fg.unwrap_or(Color::Reset),
-                    });
-                    if dataset.graph_type == GraphType::Line {
-                        for data in dataset.data.windows(2) {
-                            ctx.draw(&CanvasLine {
-                                x1: data[0].0,
-                                y1: data[0].1,
-                                x2: data[1].0,
-                                y2: data[1].1,
+        // the datasets can't be drawn if the bounds can't be mapped to the scales of the axes
+        let bounds = self.x_axis.scaled_bounds().zip(self.y_axis.scaled_bounds());
+        if let Some((x_bounds, y_bounds)) = bounds {
+            // stacked datasets are drawn from the top so that the line of each dataset is drawn
+            // over the bottom edge of the area of the dataset above it
+            let count = self.datasets.len();
+            for i in (0..count).map(|i| if self.stacked { count - 1 - i } else { i }) {
+                let dataset = &self.datasets[i];
+                let stacked = self.stacked_data(i);
+                let area = (dataset.graph_type == GraphType::Area).then(|| {
+                    self.scale_coords(&self.area_coords(&stacked, dataset.data))
+                        .into_owned()
+                });
+                let data = self.scale_coords(&stacked);
+                Canvas::default()
+                    .background_color(self.style.bg.unwrap_or(Color::Reset))
+                    .x_bounds(x_bounds)
+                    .y_bounds(y_bounds)
+                    .marker(self.resolution.marker(dataset.marker))
+                    .paint(|ctx| {
+                        if let Some(area) = &area {
+                            ctx.draw(&Polygon {
+                                coords: area,
                                 color: dataset.style.fg.unwrap_or(Color::Reset),
+                                filled: true,
                             });
                         }
-                    }
-                })
-                .render(graph_area, buf);
+                        if dataset.point_style.is_some() || dataset.point_symbol.is_some() {
+                            dataset.draw_points(ctx, &stacked, &self.x_axis, &self.y_axis);
+                        } else {
+                            ctx.draw(&Points {
+                                coords: &data,
+                                color: dataset.style.fg.unwrap_or(Color::Reset),
+                            });
+                        }
+                        if dataset.graph_type != GraphType::Scatter {
+                            for data in data.windows(2) {
+                                ctx.draw(&CanvasLine {
+                                    x1: data[0].0,
+                                    y1: data[0].1,
+                                    x2: data[1].0,
+                                    y2: data[1].1,
+                                    color: dataset.style.fg.unwrap_or(Color::Reset),
+                                });
+                            }
+                        }
+                    })
+                    .render(graph_area, buf);
+            }
         }
 
         if let Some((x, y)) = layout.title_x {
@@ -1109,6 +1895,22 @@ impl<'a> Styled for Chart<'a> {
     }
 }
 
+/// Returns the y value of the given points at `x`, linearly interpolated between the points
+///
+/// The points are expected to be sorted by their x coordinate. Outside of the range of the points
+/// the value is `0.0`.
+fn interpolate(data: &[(f64, f64)], x: f64) -> f64 {
+    let index = data.partition_point(|&(px, _)| px <= x);
+    let Some(&(x1, y1)) = index.checked_sub(1).and_then(|i| data.get(i)) else {
+        return 0.0;
+    };
+    match data.get(index) {
+        Some(&(x2, y2)) => (y2 - y1).mul_add((x - x1) / (x2 - x1), y1),
+        None if x > x1 => 0.0,
+        None => y1,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use rstest::rstest;
@@ -1193,22 +1995,413 @@ mod tests {
     fn graph_type_to_string() {
         assert_eq!(GraphType::Scatter.to_string(), "Scatter");
         assert_eq!(GraphType::Line.to_string(), "Line");
+        assert_eq!(GraphType::Area.to_string(), "Area");
     }
 
     #[test]
     fn graph_type_from_str() {
         assert_eq!("Scatter".parse::<GraphType>(), Ok(GraphType::Scatter));
         assert_eq!("Line".parse::<GraphType>(), Ok(GraphType::Line));
+        assert_eq!("Area".parse::<GraphType>(), Ok(GraphType::Area));
         assert_eq!("".parse::<GraphType>(), Err(ParseError::VariantNotFound));
     }
 
+    #[rstest]
+    #[case(-1.0, 0.0)]
+    #[case(0.0, 2.0)]
+    #[case(1.0, 3.0)]
+    #[case(2.0, 4.0)]
+    #[case(3.0, 3.0)]
+    #[case(4.0, 2.0)]
+    #[case(5.0, 0.0)]
+    fn interpolate(#[case] x: f64, #[case] expected: f64) {
+        let data = [(0.0, 2.0), (2.0, 4.0), (4.0, 2.0)];
+        assert!((super::interpolate(&data, x) - expected).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn render_area() {
+        let data = [(0.0, 0.0), (4.0, 4.0), (8.0, 0.0)];
+        let widget = Chart::new(vec![Dataset::default()
+            .data(&data)
+            .marker(symbols::Marker::Block)
+            .graph_type(GraphType::Area)])
+        .x_axis(Axis::default().bounds([0.0, 8.0]))
+        .y_axis(Axis::default().bounds([0.0, 4.0]));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 9, 5));
+        Widget::render(widget, buffer.area, &mut buffer);
+        assert_eq!(
+            buffer,
+            Buffer::with_lines([
+                "   ██    ",
+                "  ████   ",
+                " ██████  ",
+                "████████ ",
+                "█████████",
+            ])
+        );
+    }
+
+    #[test]
+    fn render_stacked_area() {
+        let bottom = [(0.0, 1.0), (8.0, 1.0)];
+        let top = [(0.0, 1.0), (8.0, 3.0)];
+        let widget = Chart::new(vec![
+            Dataset::default()
+                .data(&bottom)
+                .marker(symbols::Marker::Block)
+                .graph_type(GraphType::Area)
+                .red(),
+            Dataset::default()
+                .data(&top)
+                .marker(symbols::Marker::Block)
+                .graph_type(GraphType::Area)
+                .blue(),
+        ])
+        .x_axis(Axis::default().bounds([0.0, 8.0]))
+        .y_axis(Axis::default().bounds([0.0, 4.0]))
+        .stacked(true);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 9, 5));
+        Widget::render(widget, buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines([
+            "      ███",
+            "  ███████",
+            "█████████",
+            "█████████",
+            "█████████",
+        ]);
+        expected.set_style(Rect::new(6, 0, 3, 1), Color::Blue);
+        expected.set_style(Rect::new(2, 1, 7, 1), Color::Blue);
+        expected.set_style(Rect::new(0, 2, 9, 1), Color::Blue);
+        expected.set_style(Rect::new(0, 3, 9, 2), Color::Red);
+        assert_eq!(buffer, expected);
+    }
+
+    #[rstest]
+    #[case(0.0, 60.0, "00:00:00")]
+    #[case(3_723.0, 60.0, "01:02:03")]
+    #[case(3_723.0, 3_600.0, "01:02")]
+    #[case(0.0, 172_800.0, "1970-01-01")]
+    #[case(951_782_400.0, 172_800.0, "2000-02-29")]
+    #[case(1_700_000_000.0, 172_800.0, "2023-11-14")]
+    #[case(-86_400.0, 172_800.0, "1969-12-31")]
+    fn format_timestamp(#[case] timestamp: f64, #[case] span: f64, #[case] expected: &str) {
+        assert_eq!(super::format_timestamp(timestamp, span), expected);
+    }
+
+    #[test]
+    fn time_labels() {
+        let axis = Axis::default().bounds([0.0, 7_200.0]).time_labels(3);
+        assert_eq!(
+            axis.labels,
+            Some(vec!["00:00".into(), "01:00".into(), "02:00".into()])
+        );
+    }
+
+    #[rstest]
+    #[case(0.0, 10.0, &["0", "5", "10"])]
+    #[case(0.0, 4.0, &["0", "1", "2", "3", "4"])]
+    #[case(-3.0, 7.5, &["-5", "0", "5", "10"])]
+    #[case(0.12, 0.87, &["0.0", "0.2", "0.4", "0.6", "0.8", "1.0"])]
+    #[case(5.0, 5.0, &["4.0", "4.5", "5.0", "5.5", "6.0"])]
+    fn nice_ticks(#[case] min: f64, #[case] max: f64, #[case] expected: &[&str]) {
+        let labels: Vec<String> = super::nice_ticks(min, max, AUTO_TICKS)
+            .into_iter()
+            .map(|(_, label)| label)
+            .collect();
+        assert_eq!(labels, expected);
+    }
+
+    #[test]
+    fn bounds_auto() {
+        let data = [(1.0, 3.0), (2.0, 7.5), (3.0, 12.0)];
+        let chart = Chart::new(vec![Dataset::default().data(&data)])
+            .x_axis(Axis::default().bounds_auto())
+            .y_axis(
+                Axis::default()
+                    .bounds_auto()
+                    .labels(vec!["low".into(), "high".into()]),
+            )
+            .with_auto_bounds();
+        assert_eq!(
+            chart.x_axis,
+            Axis::default().bounds([1.0, 3.0]).labels(vec![
+                "1.0".into(),
+                "1.5".into(),
+                "2.0".into(),
+                "2.5".into(),
+                "3.0".into()
+            ])
+        );
+        assert_eq!(
+            chart.y_axis,
+            Axis::default()
+                .bounds([0.0, 15.0])
+                .labels(vec!["low".into(), "high".into()])
+        );
+    }
+
+    #[test]
+    fn bounds_auto_log_scale() {
+        let data = [(0.0, 0.0), (1.0, 3.0), (2.0, 420.0)];
+        let chart = Chart::new(vec![Dataset::default().data(&data)])
+            .y_axis(Axis::default().scale(Scale::Log10).bounds_auto())
+            .with_auto_bounds();
+        assert_eq!(
+            chart.y_axis,
+            Axis::default()
+                .scale(Scale::Log10)
+                .bounds([1.0, 1000.0])
+                .labels(vec!["1".into(), "10".into(), "100".into(), "1000".into()])
+        );
+    }
+
+    #[test]
+    fn bounds_auto_without_data() {
+        let chart = Chart::new(vec![])
+            .x_axis(Axis::default().bounds([2.0, 4.0]).bounds_auto())
+            .with_auto_bounds();
+        assert_eq!(
+            chart.x_axis,
+            Axis::default().bounds([2.0, 4.0]).labels(vec![
+                "2.0".into(),
+                "2.5".into(),
+                "3.0".into(),
+                "3.5".into(),
+                "4.0".into()
+            ])
+        );
+    }
+
+    #[test]
+    fn scale_to_string() {
+        assert_eq!(Scale::Linear.to_string(), "Linear");
+        assert_eq!(Scale::Log10.to_string(), "Log10");
+    }
+
+    #[test]
+    fn render_log_scale() {
+        let data = [
+            (0.0, 1.0),
+            (1.0, 10.0),
+            (2.0, 100.0),
+            (3.0, 1000.0),
+            (4.0, 0.0),
+        ];
+        let widget = Chart::new(vec![Dataset::default()
+            .data(&data)
+            .marker(symbols::Marker::Block)])
+        .x_axis(Axis::default().bounds([0.0, 3.0]))
+        .y_axis(Axis::default().bounds([1.0, 1000.0]).scale(Scale::Log10));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 4));
+        Widget::render(widget, buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["   █", "  █ ", " █  ", "█   "]));
+    }
+
+    #[test]
+    fn render_log_scale_non_positive_bounds() {
+        let data = [(0.0, 1.0), (1.0, 10.0), (2.0, 100.0), (3.0, 1000.0)];
+        let widget = Chart::new(vec![Dataset::default()
+            .data(&data)
+            .marker(symbols::Marker::Block)])
+        .x_axis(Axis::default().bounds([0.0, 3.0]))
+        .y_axis(Axis::default().bounds([0.0, 1000.0]).scale(Scale::Log10));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 4));
+        Widget::render(widget, buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(["    "; 4]));
+    }
+
+    #[test]
+    fn render_point_style() {
+        let data = [(0.0, 0.0), (1.0, 3.0), (2.0, 1.0), (3.0, 3.0)];
+        let widget = Chart::new(vec![Dataset::default()
+            .data(&data)
+            .marker(symbols::Marker::Block)
+            .graph_type(GraphType::Scatter)
+            .green()
+            .point_style(|&(_, y)| {
+                if y > 2.0 {
+                    Style::new().red()
+                } else {
+                    Style::new()
+                }
+            })])
+        .x_axis(Axis::default().bounds([0.0, 3.0]))
+        .y_axis(Axis::default().bounds([0.0, 3.0]));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 4));
+        Widget::render(widget, buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines([" █ █", "    ", "  █ ", "█   "]);
+        expected.set_style(Rect::new(1, 0, 1, 1), Color::Red);
+        expected.set_style(Rect::new(3, 0, 1, 1), Color::Red);
+        expected.set_style(Rect::new(2, 2, 1, 1), Color::Green);
+        expected.set_style(Rect::new(0, 3, 1, 1), Color::Green);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_point_symbol() {
+        let data = [(0.0, 0.0), (1.0, 3.0), (2.0, 1.0), (3.0, 3.0)];
+        let widget = Chart::new(vec![Dataset::default()
+            .data(&data)
+            .marker(symbols::Marker::Block)
+            .graph_type(GraphType::Scatter)
+            .point_style(|&(x, _)| {
+                if x > 2.0 {
+                    Style::new().yellow()
+                } else {
+                    Style::new()
+                }
+            })
+            .point_symbol(|&(_, y)| (y > 2.0).then_some("x"))])
+        .x_axis(Axis::default().bounds([0.0, 3.0]))
+        .y_axis(Axis::default().bounds([0.0, 3.0]));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 4));
+        Widget::render(widget, buffer.area, &mut buffer);
+        let mut expected = Buffer::with_lines([" x x", "    ", "  █ ", "█   "]);
+        expected.set_style(Rect::new(3, 0, 1, 1), Color::Yellow);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn dataset_eq_compares_point_functions() {
+        fn red(_: &(f64, f64)) -> Style {
+            Style::new().red()
+        }
+        fn blue(_: &(f64, f64)) -> Style {
+            Style::new().blue()
+        }
+        assert_eq!(
+            Dataset::default().point_style(red),
+            Dataset::default().point_style(red)
+        );
+        assert_ne!(
+            Dataset::default().point_style(red),
+            Dataset::default().point_style(blue)
+        );
+        assert_ne!(Dataset::default().point_style(red), Dataset::default());
+    }
+
+    #[test]
+    fn chart_resolution_to_string() {
+        assert_eq!(ChartResolution::Marker.to_string(), "Marker");
+        assert_eq!(ChartResolution::Braille.to_string(), "Braille");
+        assert_eq!(ChartResolution::Shade.to_string(), "Shade");
+    }
+
+    #[rstest]
+    #[case::marker(ChartResolution::Marker, ["    •", "   • ", "  •  ", " •   ", "•    "])]
+    #[case::braille(ChartResolution::Braille, ["    ⡜", "   ⡜ ", "  ⡜  ", " ⡜   ", "⡜    "])]
+    #[case::shade(ChartResolution::Shade, ["    ▒", "   ▒ ", "  ▒  ", " ▒   ", "▒    "])]
+    fn render_resolution(#[case] resolution: ChartResolution, #[case] expected: [&str; 5]) {
+        let data = [(0.0, 0.0), (4.0, 4.0)];
+        let widget = Chart::new(vec![Dataset::default()
+            .data(&data)
+            .graph_type(GraphType::Line)])
+        .x_axis(Axis::default().bounds([0.0, 4.0]))
+        .y_axis(Axis::default().bounds([0.0, 4.0]))
+        .resolution(resolution);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 5));
+        Widget::render(widget, buffer.area, &mut buffer);
+        assert_eq!(buffer, Buffer::with_lines(expected));
+    }
+
+    #[test]
+    fn chart_state_select_points() {
+        let data = [(0.0, 1.0), (1.0, 2.0), (3.0, 1.0)];
+        let mut state = ChartState::new();
+        state.select_previous_point(&data);
+        assert_eq!(state.x(), Some(3.0));
+        state.select_previous_point(&data);
+        assert_eq!(state.x(), Some(1.0));
+        state.select_x(Some(1.5));
+        state.select_next_point(&data);
+        assert_eq!(state.x(), Some(3.0));
+        state.select_next_point(&data);
+        assert_eq!(state.x(), Some(3.0));
+        state.select_x(None);
+        state.select_next_point(&data);
+        assert_eq!(state.x(), Some(0.0));
+    }
+
+    #[rstest]
+    #[case::first_column(Position::new(0, 0), Some(0.0))]
+    #[case::last_column(Position::new(10, 4), Some(5.0))]
+    #[case::middle(Position::new(4, 2), Some(2.0))]
+    #[case::outside(Position::new(11, 0), None)]
+    fn chart_state_select_position(#[case] position: Position, #[case] expected: Option<f64>) {
+        let chart = Chart::new(vec![])
+            .x_axis(Axis::default().bounds([0.0, 5.0]))
+            .y_axis(Axis::default().bounds([0.0, 5.0]));
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 11, 5));
+        let mut state = ChartState::default();
+        StatefulWidget::render(chart, buffer.area, &mut buffer, &mut state);
+        assert_eq!(state.graph_area(), buffer.area);
+        assert_eq!(state.select_position(position), expected);
+        assert_eq!(state.x(), expected);
+    }
+
+    #[test]
+    fn render_crosshair() {
+        let data = [(0.0, 0.0), (6.0, 4.0), (12.0, 0.0)];
+        let chart = Chart::new(vec![Dataset::default()
+            .name("a")
+            .data(&data)
+            .marker(symbols::Marker::Block)
+            .graph_type(GraphType::Scatter)])
+        .x_axis(Axis::default().bounds([0.0, 12.0]))
+        .y_axis(Axis::default().bounds([0.0, 4.0]))
+        .legend_position(None)
+        .readout_precision(1);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 13, 5));
+        let mut state = ChartState::new();
+        state.select_x(Some(3.0));
+        StatefulWidget::render(chart, buffer.area, &mut buffer, &mut state);
+        assert_eq!(
+            buffer,
+            Buffer::with_lines([
+                "   │┌──────┐ ",
+                "   ││x: 3.0│ ",
+                "   ││a: 2.0│ ",
+                "   │└──────┘ ",
+                "█  │        █",
+            ])
+        );
+    }
+
+    #[test]
+    fn render_crosshair_readout_on_the_left() {
+        let data = [(0.0, 1.0), (8.0, 4.0)];
+        let chart = Chart::new(vec![Dataset::default()
+            .data(&data)
+            .marker(symbols::Marker::Block)
+            .graph_type(GraphType::Scatter)
+            .red()])
+        .x_axis(Axis::default().bounds([0.0, 8.0]))
+        .y_axis(Axis::default().bounds([0.0, 4.0]))
+        .readout_precision(0);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 9, 5));
+        let mut state = ChartState::new();
+        state.select_x(Some(6.0));
+        StatefulWidget::render(chart, buffer.area, &mut buffer, &mut state);
+        let mut expected = Buffer::with_lines([
+            "┌────┐│ █",
+            "│x: 6││  ",
+            "│3   ││  ",
+            "└────┘│  ",
+            "      │  ",
+        ]);
+        expected.set_style(Rect::new(1, 2, 4, 1), Color::Red);
+        expected.set_style(Rect::new(8, 0, 1, 1), Color::Red);
+        assert_eq!(buffer, expected);
+    }
+
     #[test]
     fn it_does_not_panic_if_title_is_wider_than_buffer() {
         let widget = Chart::default()
             .y_axis(Axis::default().title("xxxxxxxxxxxxxxxx"))
             .x_axis(Axis::default().title("xxxxxxxxxxxxxxxx"));
         let mut buffer = Buffer::empty(Rect::new(0, 0, 8, 4));
-        widget.render(buffer.area, &mut buffer);
+        Widget::render(widget, buffer.area, &mut buffer);
         assert_eq!(buffer, Buffer::with_lines(vec![" ".repeat(8); 4]));
     }
 
@@ -1243,7 +2436,7 @@ mod tests {
         let widget = Chart::new(vec![long_dataset_name, short_dataset])
             .hidden_legend_constraints((100.into(), 100.into()));
         let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 5));
-        widget.render(buffer.area, &mut buffer);
+        Widget::render(widget, buffer.area, &mut buffer);
         let expected = Buffer::with_lines([
             "    ┌──────────────┐",
             "    │Very long name│",
@@ -1260,7 +2453,7 @@ mod tests {
             .legend_position(Some(LegendPosition::TopLeft));
         let area = Rect::new(0, 0, 30, 20);
         let mut buffer = Buffer::empty(area);
-        chart.render(buffer.area, &mut buffer);
+        Widget::render(chart, buffer.area, &mut buffer);
         let expected = Buffer::with_lines([
             "┌───┐                         ",
             "│Ds1│                         ",
@@ -1292,7 +2485,7 @@ mod tests {
             .y_axis(Axis::default().title("The title overlap a legend."));
         let area = Rect::new(0, 0, 30, 20);
         let mut buffer = Buffer::empty(area);
-        chart.render(buffer.area, &mut buffer);
+        Widget::render(chart, buffer.area, &mut buffer);
         let expected = Buffer::with_lines([
             "The title overlap a legend.   ",
             "                         ┌───┐",
@@ -1324,7 +2517,7 @@ mod tests {
             .y_axis(Axis::default().title("The title overlap a legend."));
         let area = Rect::new(0, 0, 10, 10);
         let mut buffer = Buffer::empty(area);
-        chart.render(buffer.area, &mut buffer);
+        Widget::render(chart, buffer.area, &mut buffer);
         let expected = Buffer::with_lines([
             "          ",
             "          ",
@@ -1359,7 +2552,7 @@ mod tests {
         ] {
             let chart = chart.clone().legend_position(Some(position));
             buffer.reset();
-            chart.render(buffer.area, &mut buffer);
+            Widget::render(chart, buffer.area, &mut buffer);
             #[rustfmt::skip]
             let expected = Buffer::with_lines([
                 "┌────┐",
@@ -1456,7 +2649,7 @@ mod tests {
         let chart = Chart::new(vec![Dataset::default().name(name)])
             .legend_position(legend_position)
             .hidden_legend_constraints((Constraint::Percentage(100), Constraint::Percentage(100)));
-        chart.render(buffer.area, &mut buffer);
+        Widget::render(chart, buffer.area, &mut buffer);
         assert_eq!(buffer, Buffer::with_lines(expected));
     }
 }
@@ -0,0 +1,396 @@
+use crate::{
+    layout::Position,
+    prelude::*,
+    widgets::{Block, StatefulWidgetRef},
+};
+
+/// State of the [`Collapsible`] widget
+///
+/// The state tracks whether the section is expanded, and the area of the header during the last
+/// render so that a mouse click on the header can toggle the section with
+/// [`CollapsibleState::handle_click`].
+///
+/// # Example
+///
+/// ```rust
+/// # use ratatui::{prelude::*, widgets::*};
+/// let mut state = CollapsibleState::default();
+/// assert!(!state.is_expanded());
+/// state.toggle();
+/// assert!(state.is_expanded());
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct CollapsibleState {
+    expanded: bool,
+    /// The area of the header during the last render, used for mouse clicks
+    header_area: Rect,
+}
+
+impl CollapsibleState {
+    /// Creates a new state that is expanded or collapsed
+    pub const fn new(expanded: bool) -> Self {
+        Self {
+            expanded,
+            header_area: Rect::ZERO,
+        }
+    }
+
+    /// Whether the section is expanded
+    pub const fn is_expanded(&self) -> bool {
+        self.expanded
+    }
+
+    /// Expands or collapses the section
+    pub fn set_expanded(&mut self, expanded: bool) {
+        self.expanded = expanded;
+    }
+
+    /// Expands the section
+    pub fn expand(&mut self) {
+        self.expanded = true;
+    }
+
+    /// Collapses the section
+    pub fn collapse(&mut self) {
+        self.expanded = false;
+    }
+
+    /// Expands the section if it is collapsed and collapses it otherwise
+    pub fn toggle(&mut self) {
+        self.expanded = !self.expanded;
+    }
+
+    /// The area of the header during the last render
+    ///
+    /// This is [`Rect::ZERO`] before the first render.
+    pub const fn header_area(&self) -> Rect {
+        self.header_area
+    }
+
+    /// Toggles the section if the position, e.g. of a mouse click, is on the header
+    ///
+    /// Returns whether the section was toggled.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{layout::Position, prelude::*, widgets::*};
+    /// let mut buf = Buffer::empty(Rect::new(0, 0, 10, 3));
+    /// let mut state = CollapsibleState::default();
+    /// Collapsible::new("Details", "content").render(buf.area, &mut buf, &mut state);
+    ///
+    /// assert!(state.handle_click(Position::new(3, 0)));
+    /// assert!(state.is_expanded());
+    /// assert!(!state.handle_click(Position::new(3, 2)));
+    /// ```
+    pub fn handle_click(&mut self, position: Position) -> bool {
+        let on_header = self.header_area.contains(position);
+        if on_header {
+            self.toggle();
+        }
+        on_header
+    }
+}
+
+/// A section with a header that can be expanded to show its content or collapsed to hide it
+///
+/// The header is a single line with the title of the section, preceded by an indicator of whether
+/// the section is expanded (`▾`) or collapsed (`▸`). When the [`CollapsibleState`] is expanded,
+/// the content is rendered below the header, optionally surrounded by a [`Block`]. When it is
+/// collapsed, only the header is rendered.
+///
+/// The section should be given the height returned by [`Collapsible::height`], so that a collapsed
+/// section only takes one line in the layout. The section can be toggled with
+/// [`CollapsibleState::toggle`], e.g. on a key press, or with [`CollapsibleState::handle_click`]
+/// on a mouse click.
+///
+/// Any widget that implements [`WidgetRef`] can be used as content.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{prelude::*, widgets::*};
+///
+/// # fn ui(frame: &mut Frame, state: &mut CollapsibleState) {
+/// let details = Paragraph::new("Line 1\nLine 2");
+/// let section = Collapsible::new("Details", details)
+///     .block(Block::bordered())
+///     .header_style(Style::new().bold());
+/// let [section_area, _rest] = Layout::vertical([
+///     Constraint::Length(section.height(state, 2)),
+///     Constraint::Fill(1),
+/// ])
+/// .areas(frame.size());
+/// frame.render_stateful_widget(section, section_area, state);
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Collapsible<'a, W> {
+    title: Line<'a>,
+    content: W,
+    block: Option<Block<'a>>,
+    style: Style,
+    header_style: Style,
+    expanded_symbol: &'a str,
+    collapsed_symbol: &'a str,
+}
+
+impl<'a, W> Collapsible<'a, W> {
+    /// Creates a new section with the given title and content
+    ///
+    /// `title` accepts any type that is convertible to [`Line`] (e.g. `&str`, `String`, [`Span`]
+    /// or [`Line`]).
+    pub fn new<T: Into<Line<'a>>>(title: T, content: W) -> Self {
+        Self {
+            title: title.into(),
+            content,
+            block: None,
+            style: Style::new(),
+            header_style: Style::new(),
+            expanded_symbol: "▾ ",
+            collapsed_symbol: "▸ ",
+        }
+    }
+
+    /// Surrounds the content with a [`Block`]
+    ///
+    /// The block is only rendered when the section is expanded.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the style of the whole section
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the header
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn header_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.header_style = style.into();
+        self
+    }
+
+    /// Sets the symbol displayed before the title when the section is expanded
+    ///
+    /// Defaults to `"▾ "`.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn expanded_symbol(mut self, symbol: &'a str) -> Self {
+        self.expanded_symbol = symbol;
+        self
+    }
+
+    /// Sets the symbol displayed before the title when the section is collapsed
+    ///
+    /// Defaults to `"▸ "`.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn collapsed_symbol(mut self, symbol: &'a str) -> Self {
+        self.collapsed_symbol = symbol;
+        self
+    }
+
+    /// Returns the height of the section for content of the given height
+    ///
+    /// This is one line for the header when the section is collapsed. When it is expanded, the
+    /// height of the content and of the borders and padding of the block is added.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let section = Collapsible::new("Details", "content").block(Block::bordered());
+    /// assert_eq!(section.height(&CollapsibleState::new(false), 1), 1);
+    /// assert_eq!(section.height(&CollapsibleState::new(true), 1), 4);
+    /// ```
+    pub fn height(&self, state: &CollapsibleState, content_height: u16) -> u16 {
+        if !state.expanded {
+            return 1;
+        }
+        let block_height = self.block.as_ref().map_or(0, |block| {
+            let area = Rect {
+                height: u16::MAX,
+                ..Rect::ZERO
+            };
+            area.height - block.inner(area).height
+        });
+        content_height
+            .saturating_add(block_height)
+            .saturating_add(1)
+    }
+}
+
+impl<W: WidgetRef> StatefulWidget for Collapsible<'_, W> {
+    type State = CollapsibleState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        self.render_ref(area, buf, state);
+    }
+}
+
+impl<W: WidgetRef> StatefulWidget for &Collapsible<'_, W> {
+    type State = CollapsibleState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        self.render_ref(area, buf, state);
+    }
+}
+
+impl<W: WidgetRef> StatefulWidgetRef for Collapsible<'_, W> {
+    type State = CollapsibleState;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let area = area.intersection(buf.area);
+        state.header_area = Rect { height: 1, ..area }.intersection(area);
+        if area.is_empty() {
+            return;
+        }
+        buf.set_style(area, self.style);
+
+        let [header_area, content_area] =
+            Layout::vertical([Constraint::Length(1), Constraint::Fill(1)]).areas(area);
+        buf.set_style(header_area, self.header_style);
+        let symbol = if state.expanded {
+            self.expanded_symbol
+        } else {
+            self.collapsed_symbol
+        };
+        let (x, _) = buf.set_stringn(
+            header_area.x,
+            header_area.y,
+            symbol,
+            header_area.width as usize,
+            Style::new(),
+        );
+        let title_area = Rect {
+            x,
+            width: header_area.right() - x,
+            ..header_area
+        };
+        self.title.render_ref(title_area, buf);
+
+        if state.expanded {
+            self.block.render_ref(content_area, buf);
+            let inner = self.block.inner_if_some(content_area);
+            self.content.render_ref(inner, buf);
+        }
+    }
+}
+
+impl<W> Styled for Collapsible<'_, W> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn state() {
+        let mut state = CollapsibleState::new(true);
+        assert!(state.is_expanded());
+        state.collapse();
+        assert!(!state.is_expanded());
+        state.expand();
+        assert!(state.is_expanded());
+        state.set_expanded(false);
+        assert!(!state.is_expanded());
+        state.toggle();
+        assert!(state.is_expanded());
+    }
+
+    #[rstest]
+    #[case::collapsed(false, None, 1)]
+    #[case::expanded(true, None, 4)]
+    #[case::expanded_with_block(true, Some(Block::bordered()), 6)]
+    #[case::collapsed_with_block(false, Some(Block::bordered()), 1)]
+    fn height(#[case] expanded: bool, #[case] block: Option<Block>, #[case] expected: u16) {
+        let mut section = Collapsible::new("title", "");
+        if let Some(block) = block {
+            section = section.block(block);
+        }
+        assert_eq!(
+            section.height(&CollapsibleState::new(expanded), 3),
+            expected
+        );
+    }
+
+    #[test]
+    fn render_collapsed() {
+        let mut buf = Buffer::with_lines(["xxxxxxxxx", "xxxxxxxxx", "xxxxxxxxx"]);
+        let mut state = CollapsibleState::new(false);
+        Collapsible::new("Details", "content")
+            .block(Block::bordered())
+            .render(buf.area, &mut buf, &mut state);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["▸ Details", "xxxxxxxxx", "xxxxxxxxx"])
+        );
+        assert_eq!(state.header_area(), Rect::new(0, 0, 9, 1));
+    }
+
+    #[test]
+    fn render_expanded() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 9, 4));
+        let mut state = CollapsibleState::new(true);
+        Collapsible::new("Details", "content")
+            .block(Block::bordered())
+            .header_style(Style::new().bold())
+            .render(buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(["▾ Details", "┌───────┐", "│content│", "└───────┘"]);
+        expected.set_style(Rect::new(0, 0, 9, 1), Style::new().bold());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_custom_symbols() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 7, 2));
+        let mut state = CollapsibleState::new(true);
+        Collapsible::new("Title", "content")
+            .expanded_symbol("- ")
+            .collapsed_symbol("+ ")
+            .render(buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["- Title", "content"]));
+
+        state.collapse();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 7, 1));
+        Collapsible::new("Title", "content")
+            .expanded_symbol("- ")
+            .collapsed_symbol("+ ")
+            .render(buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["+ Title"]));
+    }
+
+    #[test]
+    fn handle_click() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 5));
+        let mut state = CollapsibleState::default();
+        Collapsible::new("Details", "content").render(Rect::new(0, 2, 10, 3), &mut buf, &mut state);
+        assert!(!state.handle_click(Position::new(0, 0)));
+        assert!(!state.is_expanded());
+        assert!(state.handle_click(Position::new(9, 2)));
+        assert!(state.is_expanded());
+        assert!(state.handle_click(Position::new(0, 2)));
+        assert!(!state.is_expanded());
+    }
+}
@@ -0,0 +1,462 @@
+use crate::{
+    layout::Flex,
+    prelude::*,
+    widgets::{Block, Clear, Padding, Paragraph, StatefulWidgetRef, Wrap},
+};
+
+/// The keyboard hints rendered at the bottom of a [`Dialog`] by default
+const DEFAULT_KEY_HINTS: &str = "←/→ move · Enter select · Esc cancel";
+
+/// A button of a [`Dialog`]
+///
+/// # Example
+///
+/// ```rust
+/// # use ratatui::widgets::*;
+/// assert_eq!(DialogButton::Ok.label(), "Ok".into());
+/// assert_eq!(
+///     DialogButton::from("Retry"),
+///     DialogButton::Custom("Retry".into())
+/// );
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum DialogButton<'a> {
+    /// A button labeled "Ok"
+    Ok,
+    /// A button labeled "Cancel"
+    Cancel,
+    /// A button with a custom label
+    Custom(Line<'a>),
+}
+
+impl<'a> DialogButton<'a> {
+    /// The label of the button
+    pub fn label(&self) -> Line<'a> {
+        match self {
+            Self::Ok => Line::from("Ok"),
+            Self::Cancel => Line::from("Cancel"),
+            Self::Custom(label) => label.clone(),
+        }
+    }
+}
+
+impl<'a, T> From<T> for DialogButton<'a>
+where
+    T: Into<Line<'a>>,
+{
+    fn from(label: T) -> Self {
+        Self::Custom(label.into())
+    }
+}
+
+/// State of the [`Dialog`] widget
+///
+/// The state holds the index of the focused button. The index is clamped to the last button when
+/// the dialog is rendered.
+///
+/// # Example
+///
+/// ```rust
+/// # use ratatui::widgets::*;
+/// let mut state = DialogState::default();
+/// state.focus_next();
+/// assert_eq!(state.focused(), 1);
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct DialogState {
+    focused: usize,
+}
+
+impl DialogState {
+    /// Creates a new state with the given button focused
+    pub const fn new(focused: usize) -> Self {
+        Self { focused }
+    }
+
+    /// The index of the focused button
+    pub const fn focused(&self) -> usize {
+        self.focused
+    }
+
+    /// Focuses the button at the given index
+    pub fn focus(&mut self, index: usize) {
+        self.focused = index;
+    }
+
+    /// Focuses the next button
+    ///
+    /// The index is clamped to the last button on the next render.
+    pub fn focus_next(&mut self) {
+        self.focused = self.focused.saturating_add(1);
+    }
+
+    /// Focuses the previous button
+    pub fn focus_previous(&mut self) {
+        self.focused = self.focused.saturating_sub(1);
+    }
+}
+
+/// A modal dialog that displays a message and a row of buttons, e.g. to ask for a confirmation.
+///
+/// A `Dialog` is rendered in a centered box on top of other widgets: it clears the area it
+/// occupies (see [`Clear`]) and draws a [`Block`] around its content. The message is wrapped to
+/// the width of the dialog, and the height of the dialog fits the message, the buttons and the
+/// keyboard hints.
+///
+/// The buttons default to [`DialogButton::Ok`] and [`DialogButton::Cancel`], and the focused
+/// button is tracked by a [`DialogState`]. The dialog does not handle input itself: the
+/// application moves the focus and acts on the focused button, and the keyboard hints rendered
+/// below the buttons describe these key bindings.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{prelude::*, widgets::*};
+///
+/// # fn ui(frame: &mut Frame) {
+/// let dialog = Dialog::new("Discard the unsaved changes?")
+///     .block(Block::bordered().title("Quit"))
+///     .buttons([DialogButton::from("Discard"), DialogButton::Cancel]);
+///
+/// // This should be stored outside of the function in your application state.
+/// let mut state = DialogState::default();
+///
+/// frame.render_stateful_widget(dialog, frame.size(), &mut state);
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Dialog<'a> {
+    message: Paragraph<'a>,
+    buttons: Vec<DialogButton<'a>>,
+    block: Block<'a>,
+    style: Style,
+    width: Constraint,
+    button_style: Style,
+    focused_button_style: Style,
+    key_hints: Line<'a>,
+}
+
+impl<'a> Dialog<'a> {
+    /// Creates a new dialog with the given message
+    ///
+    /// The dialog has a bordered block, "Ok" and "Cancel" buttons and takes 50% of the width of
+    /// the area by default.
+    pub fn new<T>(message: T) -> Self
+    where
+        T: Into<Text<'a>>,
+    {
+        Self {
+            message: Paragraph::new(message).wrap(Wrap::new(true)),
+            buttons: vec![DialogButton::Ok, DialogButton::Cancel],
+            block: Block::bordered().padding(Padding::horizontal(1)),
+            style: Style::new(),
+            width: Constraint::Percentage(50),
+            button_style: Style::new(),
+            focused_button_style: Style::new().reversed(),
+            key_hints: Line::from(DEFAULT_KEY_HINTS).dim(),
+        }
+    }
+
+    /// Sets the buttons of the dialog
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::widgets::*;
+    /// let dialog = Dialog::new("Overwrite the file?").buttons(["Yes", "No"]);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn buttons<I>(mut self, buttons: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<DialogButton<'a>>,
+    {
+        self.buttons = buttons.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the block drawn around the dialog
+    ///
+    /// The default block is bordered with a horizontal padding of 1.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = block;
+        self
+    }
+
+    /// Sets the style of the dialog area
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the width of the dialog
+    ///
+    /// The height of the dialog is computed from its content.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn width(mut self, width: Constraint) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the style of the buttons that are not focused
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn button_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.button_style = style.into();
+        self
+    }
+
+    /// Sets the style of the focused button
+    ///
+    /// The focused button is reversed by default.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn focused_button_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.focused_button_style = style.into();
+        self
+    }
+
+    /// Sets the keyboard hints rendered below the buttons
+    ///
+    /// The default hints describe the usual key bindings of a dialog and are dimmed. An empty
+    /// line hides the hints.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::widgets::*;
+    /// let dialog = Dialog::new("Delete 3 files?").key_hints("y: yes · n: no");
+    /// let dialog = Dialog::new("Delete 3 files?").key_hints("");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn key_hints<T>(mut self, hints: T) -> Self
+    where
+        T: Into<Line<'a>>,
+    {
+        self.key_hints = hints.into();
+        self
+    }
+
+    /// Returns the button at the given index, e.g. the focused button of a [`DialogState`]
+    pub fn button(&self, index: usize) -> Option<&DialogButton<'a>> {
+        self.buttons.get(index)
+    }
+
+    /// Returns the area occupied by the dialog when rendered into the given area
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let dialog = Dialog::new("Hello").width(Constraint::Length(20));
+    /// assert_eq!(
+    ///     dialog.area(Rect::new(0, 0, 40, 20)),
+    ///     Rect::new(10, 7, 20, 6)
+    /// );
+    /// ```
+    pub fn area(&self, area: Rect) -> Rect {
+        let [horizontal] = Layout::horizontal([self.width])
+            .flex(Flex::Center)
+            .areas(area);
+        let inner = self.block.inner(horizontal);
+        let borders_height = horizontal.height - inner.height;
+        let message_height = self.message.line_count(inner.width) as u16;
+        let height = borders_height
+            .saturating_add(message_height)
+            .saturating_add(self.footer_height());
+        let [area] = Layout::vertical([Constraint::Length(height)])
+            .flex(Flex::Center)
+            .areas(horizontal);
+        area
+    }
+
+    /// The height of the blank line, the buttons and the keyboard hints below the message
+    fn footer_height(&self) -> u16 {
+        if self.key_hints.width() > 0 {
+            3
+        } else {
+            2
+        }
+    }
+
+    fn render_buttons(&self, area: Rect, buf: &mut Buffer, focused: usize) {
+        const SPACING: u16 = 2;
+        let labels: Vec<Line> = self.buttons.iter().map(DialogButton::label).collect();
+        let total_width = labels
+            .iter()
+            .map(|label| label.width() as u16 + 2)
+            .sum::<u16>()
+            + SPACING * (labels.len().saturating_sub(1) as u16);
+        let mut x = area.x + area.width.saturating_sub(total_width) / 2;
+        for (i, label) in labels.iter().enumerate() {
+            let width = (label.width() as u16 + 2).min(area.right().saturating_sub(x));
+            if width == 0 {
+                break;
+            }
+            let button_area = Rect::new(x, area.y, width, 1);
+            let style = if i == focused {
+                self.focused_button_style
+            } else {
+                self.button_style
+            };
+            buf.set_style(button_area, style);
+            buf.set_line(x + 1, area.y, label, width.saturating_sub(1));
+            x = button_area.right().saturating_add(SPACING);
+        }
+    }
+}
+
+impl Widget for Dialog<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        WidgetRef::render_ref(&self, area, buf);
+    }
+}
+
+impl WidgetRef for Dialog<'_> {
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        let mut state = DialogState::default();
+        StatefulWidgetRef::render_ref(self, area, buf, &mut state);
+    }
+}
+
+impl StatefulWidget for Dialog<'_> {
+    type State = DialogState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidgetRef::render_ref(&self, area, buf, state);
+    }
+}
+
+// Note: remove this when StatefulWidgetRef is stabilized and replace with the blanket impl
+impl StatefulWidget for &Dialog<'_> {
+    type State = DialogState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidgetRef::render_ref(self, area, buf, state);
+    }
+}
+
+impl StatefulWidgetRef for Dialog<'_> {
+    type State = DialogState;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        state.focused = state.focused.min(self.buttons.len().saturating_sub(1));
+        let area = self.area(area);
+        Clear.render(area, buf);
+        buf.set_style(area, self.style);
+        self.block.render_ref(area, buf);
+        let inner = self.block.inner(area);
+        if inner.is_empty() {
+            return;
+        }
+
+        let hints_height = self.footer_height() - 2;
+        let [message_area, _, buttons_area, hints_area] = Layout::vertical([
+            Constraint::Min(0),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(hints_height),
+        ])
+        .areas(inner);
+        WidgetRef::render_ref(&self.message, message_area, buf);
+        self.render_buttons(buttons_area, buf, state.focused);
+        self.key_hints.clone().centered().render(hints_area, buf);
+    }
+}
+
+impl Styled for Dialog<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::ok(DialogButton::Ok, "Ok")]
+    #[case::cancel(DialogButton::Cancel, "Cancel")]
+    #[case::custom(DialogButton::from("Retry"), "Retry")]
+    fn button_label(#[case] button: DialogButton, #[case] expected: &str) {
+        assert_eq!(button.label(), Line::from(expected));
+    }
+
+    #[test]
+    fn state_focus() {
+        let mut state = DialogState::new(1);
+        state.focus_previous();
+        state.focus_previous();
+        assert_eq!(state.focused(), 0);
+        state.focus_next();
+        assert_eq!(state.focused(), 1);
+        state.focus(3);
+        assert_eq!(state.focused(), 3);
+    }
+
+    #[rstest]
+    #[case::single_line(Constraint::Length(20), Rect::new(10, 7, 20, 6))]
+    #[case::wrapped(Constraint::Length(6), Rect::new(17, 5, 6, 11))]
+    fn area(#[case] width: Constraint, #[case] expected: Rect) {
+        let dialog = Dialog::new("Hello world").width(width);
+        assert_eq!(dialog.area(Rect::new(0, 0, 40, 20)), expected);
+    }
+
+    #[test]
+    fn area_without_key_hints() {
+        let dialog = Dialog::new("Hello")
+            .width(Constraint::Length(20))
+            .key_hints("");
+        assert_eq!(
+            dialog.area(Rect::new(0, 0, 40, 20)),
+            Rect::new(10, 8, 20, 5)
+        );
+    }
+
+    #[test]
+    fn render() {
+        let mut buf = Buffer::with_lines(vec!["x".repeat(24); 8]);
+        let dialog = Dialog::new("Save changes?")
+            .width(Constraint::Length(22))
+            .key_hints("Enter: select");
+        let mut state = DialogState::new(1);
+        StatefulWidget::render(&dialog, buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines([
+            "xxxxxxxxxxxxxxxxxxxxxxxx",
+            "x┌────────────────────┐x",
+            "x│ Save changes?      │x",
+            "x│                    │x",
+            "x│    Ok    Cancel    │x",
+            "x│   Enter: select    │x",
+            "x└────────────────────┘x",
+            "xxxxxxxxxxxxxxxxxxxxxxxx",
+        ]);
+        expected.set_style(Rect::new(11, 4, 8, 1), Style::new().reversed());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_clamps_focused_button() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 20, 8));
+        let mut state = DialogState::new(5);
+        StatefulWidget::render(
+            Dialog::new("Continue?").buttons(["Yes", "No", "Later"]),
+            buf.area,
+            &mut buf,
+            &mut state,
+        );
+        assert_eq!(state.focused(), 2);
+    }
+}
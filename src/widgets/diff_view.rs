@@ -0,0 +1,1085 @@
+use std::{borrow::Cow, ops::Range};
+
+use strum::{Display, EnumString};
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::{
+    prelude::*,
+    widgets::{Block, StatefulWidgetRef},
+};
+
+/// The number of unchanged lines kept around the changes by [`Diff::from_texts`]
+const DEFAULT_CONTEXT: usize = 3;
+
+/// The kind of a [`DiffLine`]
+#[derive(Debug, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum DiffLineKind {
+    /// A line present in both texts
+    Context,
+    /// A line only present in the new text
+    Added,
+    /// A line only present in the old text
+    Removed,
+}
+
+impl DiffLineKind {
+    /// The sign prefixing the lines of this kind in a unified diff
+    const fn sign(self) -> &'static str {
+        match self {
+            Self::Context => " ",
+            Self::Added => "+",
+            Self::Removed => "-",
+        }
+    }
+}
+
+/// A line of a [`DiffHunk`]
+///
+/// Changed lines hold the byte ranges of their content that differ from the line they replace,
+/// which are highlighted by the [`DiffView`]. The ranges are computed by [`DiffHunk::new`] for
+/// each removed line followed by an added line.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct DiffLine<'a> {
+    kind: DiffLineKind,
+    content: Cow<'a, str>,
+    changes: Vec<Range<usize>>,
+}
+
+impl<'a> DiffLine<'a> {
+    /// Creates a new line of the given kind
+    ///
+    /// The content should not contain the sign of the line nor the line ending.
+    pub fn new<T>(kind: DiffLineKind, content: T) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        Self {
+            kind,
+            content: content.into(),
+            changes: Vec::new(),
+        }
+    }
+
+    /// Creates a new line present in both texts
+    pub fn context<T>(content: T) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        Self::new(DiffLineKind::Context, content)
+    }
+
+    /// Creates a new line only present in the new text
+    pub fn added<T>(content: T) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        Self::new(DiffLineKind::Added, content)
+    }
+
+    /// Creates a new line only present in the old text
+    pub fn removed<T>(content: T) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        Self::new(DiffLineKind::Removed, content)
+    }
+
+    /// The kind of the line
+    pub const fn kind(&self) -> DiffLineKind {
+        self.kind
+    }
+
+    /// The content of the line
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    /// The byte ranges of the content that differ from the paired line of the other text
+    pub fn changes(&self) -> &[Range<usize>] {
+        &self.changes
+    }
+}
+
+/// A group of changed lines and their surrounding context in a [`Diff`]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct DiffHunk<'a> {
+    old_start: usize,
+    new_start: usize,
+    lines: Vec<DiffLine<'a>>,
+}
+
+impl<'a> DiffHunk<'a> {
+    /// Creates a new hunk starting at the given 1-based line numbers of the old and new texts
+    ///
+    /// The changes within lines are computed for each run of removed lines followed by a run of
+    /// added lines, by pairing the lines of both runs in order.
+    pub fn new<I>(old_start: usize, new_start: usize, lines: I) -> Self
+    where
+        I: IntoIterator<Item = DiffLine<'a>>,
+    {
+        let mut lines: Vec<DiffLine<'a>> = lines.into_iter().collect();
+        highlight_changes(&mut lines);
+        Self {
+            old_start,
+            new_start,
+            lines,
+        }
+    }
+
+    /// The 1-based line number of the first line of the hunk in the old text
+    pub const fn old_start(&self) -> usize {
+        self.old_start
+    }
+
+    /// The 1-based line number of the first line of the hunk in the new text
+    pub const fn new_start(&self) -> usize {
+        self.new_start
+    }
+
+    /// The lines of the hunk
+    pub fn lines(&self) -> &[DiffLine<'a>] {
+        &self.lines
+    }
+
+    /// The number of lines of the hunk in the old text
+    pub fn old_count(&self) -> usize {
+        self.count(DiffLineKind::Added)
+    }
+
+    /// The number of lines of the hunk in the new text
+    pub fn new_count(&self) -> usize {
+        self.count(DiffLineKind::Removed)
+    }
+
+    /// The header of the hunk in the unified diff format, e.g. `@@ -1,3 +1,4 @@`
+    pub fn header(&self) -> String {
+        format!(
+            "@@ -{},{} +{},{} @@",
+            self.old_start,
+            self.old_count(),
+            self.new_start,
+            self.new_count()
+        )
+    }
+
+    /// The number of lines that are not of the given kind
+    fn count(&self, excluded: DiffLineKind) -> usize {
+        self.lines
+            .iter()
+            .filter(|line| line.kind != excluded)
+            .count()
+    }
+}
+
+/// The differences between two texts, as a list of [`DiffHunk`]s
+///
+/// A diff is either computed from two texts with [`Diff::from_texts`], or built from hunks that
+/// were computed elsewhere (e.g. parsed from the output of `git diff`) with [`Diff::new`].
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::widgets::{Diff, DiffLineKind};
+///
+/// let diff = Diff::from_texts("a\nb\nc\n", "a\nB\nc\n");
+/// let lines = diff.hunks()[0].lines();
+/// assert_eq!(lines[1].kind(), DiffLineKind::Removed);
+/// assert_eq!(lines[2].kind(), DiffLineKind::Added);
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Diff<'a> {
+    hunks: Vec<DiffHunk<'a>>,
+}
+
+impl<'a> Diff<'a> {
+    /// Creates a new diff from the given hunks
+    pub fn new<I>(hunks: I) -> Self
+    where
+        I: IntoIterator<Item = DiffHunk<'a>>,
+    {
+        Self {
+            hunks: hunks.into_iter().collect(),
+        }
+    }
+
+    /// Computes the differences between the lines of two texts, keeping 3 unchanged lines around
+    /// each change
+    ///
+    /// The texts are compared line by line with the Myers algorithm, the same as `git diff`.
+    pub fn from_texts(old: &'a str, new: &'a str) -> Self {
+        Self::from_texts_with_context(old, new, DEFAULT_CONTEXT)
+    }
+
+    /// Computes the differences between the lines of two texts, keeping the given number of
+    /// unchanged lines around each change
+    ///
+    /// Changes separated by at most twice the number of context lines are grouped in the same
+    /// hunk.
+    pub fn from_texts_with_context(old: &'a str, new: &'a str, context: usize) -> Self {
+        let old: Vec<&str> = old.lines().collect();
+        let new: Vec<&str> = new.lines().collect();
+        let script = edits(&old, &new);
+        Self::new(hunks(&old, &new, &script, context))
+    }
+
+    /// The hunks of the diff
+    pub fn hunks(&self) -> &[DiffHunk<'a>] {
+        &self.hunks
+    }
+
+    /// Whether the texts are identical
+    pub fn is_empty(&self) -> bool {
+        self.hunks.is_empty()
+    }
+}
+
+/// An operation of an edit script
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+enum Edit {
+    Equal,
+    Delete,
+    Insert,
+}
+
+/// Computes the shortest edit script transforming `old` into `new` with the Myers algorithm
+///
+/// Only the diagonals reached at each step are kept for the backtracking, so the memory used is
+/// quadratic in the number of edits rather than in the length of the sequences.
+#[allow(
+    clippy::cast_possible_wrap,
+    clippy::cast_sign_loss,
+    clippy::many_single_char_names
+)]
+fn edits<T: PartialEq>(old: &[T], new: &[T]) -> Vec<Edit> {
+    let (n, m) = (old.len() as isize, new.len() as isize);
+    let max = n + m;
+    // the furthest x reached on each diagonal k = x - y, indexed by k + max
+    let mut v = vec![0_isize; 2 * max as usize + 2];
+    let mut trace = Vec::new();
+    'search: for d in 0..=max {
+        trace.push(v[(max - d) as usize..=(max + d) as usize].to_vec());
+        for k in (-d..=d).step_by(2) {
+            let i = (k + max) as usize;
+            let mut x = if k == -d || (k != d && v[i - 1] < v[i + 1]) {
+                v[i + 1]
+            } else {
+                v[i - 1] + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && old[x as usize] == new[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            v[i] = x;
+            if x >= n && y >= m {
+                break 'search;
+            }
+        }
+    }
+
+    let mut script = Vec::with_capacity(max as usize);
+    let (mut x, mut y) = (n, m);
+    for (d, v) in trace.iter().enumerate().skip(1).rev() {
+        let d = d as isize;
+        let k = x - y;
+        // the diagonals of the previous step are stored at k + d
+        let prev_k = if k == -d || (k != d && v[(k - 1 + d) as usize] < v[(k + 1 + d) as usize]) {
+            k + 1
+        } else {
+            k - 1
+        };
+        let prev_x = v[(prev_k + d) as usize];
+        let prev_y = prev_x - prev_k;
+        while x > prev_x && y > prev_y {
+            script.push(Edit::Equal);
+            x -= 1;
+            y -= 1;
+        }
+        script.push(if x == prev_x {
+            Edit::Insert
+        } else {
+            Edit::Delete
+        });
+        x = prev_x;
+        y = prev_y;
+    }
+    script.extend(std::iter::repeat(Edit::Equal).take(x as usize));
+    script.reverse();
+    script
+}
+
+/// Groups the changes of an edit script into hunks with the given number of context lines
+fn hunks<'a>(
+    old: &[&'a str],
+    new: &[&'a str],
+    script: &[Edit],
+    context: usize,
+) -> Vec<DiffHunk<'a>> {
+    // the indices of the old and new lines at each edit
+    let positions: Vec<(usize, usize)> = script
+        .iter()
+        .scan((0, 0), |(old, new), edit| {
+            let position = (*old, *new);
+            match edit {
+                Edit::Equal => (*old, *new) = (*old + 1, *new + 1),
+                Edit::Delete => *old += 1,
+                Edit::Insert => *new += 1,
+            }
+            Some(position)
+        })
+        .collect();
+    let changes: Vec<usize> = script
+        .iter()
+        .enumerate()
+        .filter(|(_, edit)| **edit != Edit::Equal)
+        .map(|(i, _)| i)
+        .collect();
+
+    let mut hunks = Vec::new();
+    let mut i = 0;
+    while i < changes.len() {
+        let start = changes[i].saturating_sub(context);
+        while i + 1 < changes.len() && changes[i + 1] - changes[i] <= 2 * context + 1 {
+            i += 1;
+        }
+        let end = (changes[i] + context + 1).min(script.len());
+        i += 1;
+        let lines = script[start..end]
+            .iter()
+            .zip(&positions[start..end])
+            .map(|(edit, &(o, n))| match edit {
+                Edit::Equal => DiffLine::context(old[o]),
+                Edit::Delete => DiffLine::removed(old[o]),
+                Edit::Insert => DiffLine::added(new[n]),
+            });
+        let (old_start, new_start) = positions[start];
+        hunks.push(DiffHunk::new(old_start + 1, new_start + 1, lines));
+    }
+    hunks
+}
+
+/// Computes the changes within each removed line followed by an added line
+fn highlight_changes(lines: &mut [DiffLine]) {
+    let mut start = 0;
+    while start < lines.len() {
+        let is_kind = |kind| move |line: &&DiffLine| line.kind == kind;
+        let removed = lines[start..]
+            .iter()
+            .take_while(is_kind(DiffLineKind::Removed))
+            .count();
+        if removed == 0 {
+            start += 1;
+            continue;
+        }
+        let added = lines[start + removed..]
+            .iter()
+            .take_while(is_kind(DiffLineKind::Added))
+            .count();
+        let (removed_lines, added_lines) = lines[start..].split_at_mut(removed);
+        for (old, new) in removed_lines.iter_mut().zip(added_lines.iter_mut()) {
+            (old.changes, new.changes) = line_changes(&old.content, &new.content);
+        }
+        start += removed + added;
+    }
+}
+
+/// Computes the byte ranges of the graphemes that differ between two lines
+fn line_changes(old: &str, new: &str) -> (Vec<Range<usize>>, Vec<Range<usize>>) {
+    let old_graphemes: Vec<(usize, &str)> = old.grapheme_indices(true).collect();
+    let new_graphemes: Vec<(usize, &str)> = new.grapheme_indices(true).collect();
+    let script = edits(
+        &old_graphemes.iter().map(|(_, g)| *g).collect::<Vec<_>>(),
+        &new_graphemes.iter().map(|(_, g)| *g).collect::<Vec<_>>(),
+    );
+    let (mut old_changes, mut new_changes) = (Vec::new(), Vec::new());
+    let (mut old_index, mut new_index) = (0, 0);
+    for edit in script {
+        match edit {
+            Edit::Equal => {
+                old_index += 1;
+                new_index += 1;
+            }
+            Edit::Delete => {
+                let (start, grapheme) = old_graphemes[old_index];
+                push_range(&mut old_changes, start..start + grapheme.len());
+                old_index += 1;
+            }
+            Edit::Insert => {
+                let (start, grapheme) = new_graphemes[new_index];
+                push_range(&mut new_changes, start..start + grapheme.len());
+                new_index += 1;
+            }
+        }
+    }
+    (old_changes, new_changes)
+}
+
+/// Pushes a range, merging it with the last range if they are contiguous
+fn push_range(ranges: &mut Vec<Range<usize>>, range: Range<usize>) {
+    match ranges.last_mut() {
+        Some(last) if last.end == range.start => last.end = range.end,
+        _ => ranges.push(range),
+    }
+}
+
+/// The layout of a [`DiffView`]
+///
+/// See [`DiffView::mode`]
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum DiffMode {
+    /// The lines of both texts are interleaved in a single column, like `git diff`. This is the
+    /// default.
+    #[default]
+    Unified,
+    /// The old text is displayed on the left and the new text on the right, with the changed
+    /// lines aligned.
+    SideBySide,
+}
+
+/// State of the [`DiffView`] widget
+///
+/// The state holds the first visible row. The number of rows and the rows of the hunk headers are
+/// recorded on render, so that the offset can be clamped and moved from hunk to hunk.
+///
+/// # Example
+///
+/// ```rust
+/// # use ratatui::{prelude::*, widgets::*};
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// // This should be stored outside of the function in your application state.
+/// let diff = Diff::from_texts("a\nb\n", "a\nc\n");
+/// let mut state = DiffViewState::default();
+///
+/// frame.render_stateful_widget(DiffView::new(&diff), area, &mut state);
+/// state.next_hunk();
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct DiffViewState {
+    offset: usize,
+    /// The rows of the hunk headers during the last render
+    hunk_rows: Vec<usize>,
+}
+
+impl DiffViewState {
+    /// Creates a new state scrolled to the top
+    pub const fn new() -> Self {
+        Self {
+            offset: 0,
+            hunk_rows: Vec::new(),
+        }
+    }
+
+    /// The index of the first visible row
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Scrolls up by the given number of rows
+    pub fn scroll_up(&mut self, rows: usize) {
+        self.offset = self.offset.saturating_sub(rows);
+    }
+
+    /// Scrolls down by the given number of rows
+    ///
+    /// The offset is clamped to the last row on the next render.
+    pub fn scroll_down(&mut self, rows: usize) {
+        self.offset = self.offset.saturating_add(rows);
+    }
+
+    /// Scrolls to the first row
+    pub fn scroll_to_top(&mut self) {
+        self.offset = 0;
+    }
+
+    /// Scrolls to the last row
+    ///
+    /// The offset is clamped to the last row on the next render.
+    pub fn scroll_to_bottom(&mut self) {
+        self.offset = usize::MAX;
+    }
+
+    /// Scrolls to the header of the next hunk
+    ///
+    /// This does nothing before the first render or when the last hunk is already at the top.
+    pub fn next_hunk(&mut self) {
+        if let Some(&row) = self.hunk_rows.iter().find(|&&row| row > self.offset) {
+            self.offset = row;
+        }
+    }
+
+    /// Scrolls to the header of the previous hunk
+    ///
+    /// This does nothing before the first render or when the first hunk is already visible at the
+    /// top.
+    pub fn previous_hunk(&mut self) {
+        if let Some(&row) = self.hunk_rows.iter().rev().find(|&&row| row < self.offset) {
+            self.offset = row;
+        }
+    }
+}
+
+/// A line of one of the texts with its 1-based line number
+type NumberedLine<'d, 'a> = (usize, &'d DiffLine<'a>);
+
+/// A row of a [`DiffView`]
+enum Row<'d, 'a> {
+    Header(&'d DiffHunk<'a>),
+    Unified {
+        old: Option<usize>,
+        new: Option<usize>,
+        line: &'d DiffLine<'a>,
+    },
+    SideBySide {
+        old: Option<NumberedLine<'d, 'a>>,
+        new: Option<NumberedLine<'d, 'a>>,
+    },
+}
+
+/// A widget that displays a [`Diff`] in a unified or side-by-side layout
+///
+/// Each hunk starts with a header row in the unified diff format (`@@ -1,3 +1,4 @@`), followed by
+/// its lines prefixed with their line numbers in the old and new texts and with their sign (`+`,
+/// `-` or a space). The added and removed lines are styled with [`DiffView::added_style`] and
+/// [`DiffView::removed_style`], and the parts that changed within a line replaced by another are
+/// highlighted with [`DiffView::added_highlight_style`] and [`DiffView::removed_highlight_style`].
+///
+/// The view scrolls with a [`DiffViewState`], which can also jump from hunk to hunk.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{prelude::*, widgets::*};
+///
+/// # fn ui(frame: &mut Frame, state: &mut DiffViewState) {
+/// # let area = Rect::default();
+/// let diff = Diff::from_texts("fn main() {}\n", "fn main() {\n    run();\n}\n");
+/// let diff_view = DiffView::new(&diff)
+///     .block(Block::bordered().title("src/main.rs"))
+///     .mode(DiffMode::SideBySide)
+///     .added_highlight_style(Style::new().on_green());
+/// frame.render_stateful_widget(diff_view, area, state);
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct DiffView<'a> {
+    diff: &'a Diff<'a>,
+    block: Option<Block<'a>>,
+    style: Style,
+    mode: DiffMode,
+    show_line_numbers: bool,
+    line_number_style: Style,
+    hunk_header_style: Style,
+    added_style: Style,
+    removed_style: Style,
+    added_highlight_style: Style,
+    removed_highlight_style: Style,
+}
+
+impl<'a> DiffView<'a> {
+    /// Creates a new view of the given diff
+    pub const fn new(diff: &'a Diff<'a>) -> Self {
+        Self {
+            diff,
+            block: None,
+            style: Style::new(),
+            mode: DiffMode::Unified,
+            show_line_numbers: true,
+            line_number_style: Style::new().add_modifier(Modifier::DIM),
+            hunk_header_style: Style::new().fg(Color::Cyan),
+            added_style: Style::new().fg(Color::Green),
+            removed_style: Style::new().fg(Color::Red),
+            added_highlight_style: Style::new().add_modifier(Modifier::REVERSED),
+            removed_highlight_style: Style::new().add_modifier(Modifier::REVERSED),
+        }
+    }
+
+    /// Wraps the view in a [`Block`]
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the style of the view
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the layout of the view, which is [`DiffMode::Unified`] by default
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn mode(mut self, mode: DiffMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets whether the line numbers are displayed, which is the default
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn show_line_numbers(mut self, show: bool) -> Self {
+        self.show_line_numbers = show;
+        self
+    }
+
+    /// Sets the style of the line numbers, which are dimmed by default
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn line_number_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.line_number_style = style.into();
+        self
+    }
+
+    /// Sets the style of the hunk headers, which are cyan by default
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn hunk_header_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.hunk_header_style = style.into();
+        self
+    }
+
+    /// Sets the style of the added lines, which are green by default
+    ///
+    /// The style is applied to the whole row, so a background color highlights the full width of
+    /// the view.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn added_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.added_style = style.into();
+        self
+    }
+
+    /// Sets the style of the removed lines, which are red by default
+    ///
+    /// The style is applied to the whole row, so a background color highlights the full width of
+    /// the view.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn removed_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.removed_style = style.into();
+        self
+    }
+
+    /// Sets the style of the changes within the added lines, which are reversed by default
+    ///
+    /// The style is patched on top of [`DiffView::added_style`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn added_highlight_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.added_highlight_style = style.into();
+        self
+    }
+
+    /// Sets the style of the changes within the removed lines, which are reversed by default
+    ///
+    /// The style is patched on top of [`DiffView::removed_style`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn removed_highlight_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.removed_highlight_style = style.into();
+        self
+    }
+
+    /// The rows of the view in its current mode
+    fn rows(&self) -> Vec<Row<'_, 'a>> {
+        let mut rows = Vec::new();
+        for hunk in &self.diff.hunks {
+            rows.push(Row::Header(hunk));
+            let (mut old, mut new) = (hunk.old_start, hunk.new_start);
+            let (mut removed, mut added) = (Vec::new(), Vec::new());
+            for line in &hunk.lines {
+                let (old_number, new_number) = match line.kind {
+                    DiffLineKind::Context => (Some(old), Some(new)),
+                    DiffLineKind::Added => (None, Some(new)),
+                    DiffLineKind::Removed => (Some(old), None),
+                };
+                old += usize::from(old_number.is_some());
+                new += usize::from(new_number.is_some());
+                match (self.mode, line.kind) {
+                    (DiffMode::Unified, _) => rows.push(Row::Unified {
+                        old: old_number,
+                        new: new_number,
+                        line,
+                    }),
+                    (DiffMode::SideBySide, DiffLineKind::Context) => {
+                        align(&mut rows, &mut removed, &mut added);
+                        rows.push(Row::SideBySide {
+                            old: old_number.map(|number| (number, line)),
+                            new: new_number.map(|number| (number, line)),
+                        });
+                    }
+                    (DiffMode::SideBySide, DiffLineKind::Removed) => {
+                        if !added.is_empty() {
+                            align(&mut rows, &mut removed, &mut added);
+                        }
+                        removed.extend(old_number.map(|number| (number, line)));
+                    }
+                    (DiffMode::SideBySide, DiffLineKind::Added) => {
+                        added.extend(new_number.map(|number| (number, line)));
+                    }
+                }
+            }
+            align(&mut rows, &mut removed, &mut added);
+        }
+        rows
+    }
+
+    /// The width of the largest line number
+    fn line_number_width(&self) -> usize {
+        let last = self
+            .diff
+            .hunks
+            .iter()
+            .map(|hunk| (hunk.old_start + hunk.old_count()).max(hunk.new_start + hunk.new_count()))
+            .max()
+            .unwrap_or_default();
+        last.to_string().len()
+    }
+
+    fn render_header(&self, hunk: &DiffHunk, area: Rect, buf: &mut Buffer) {
+        buf.set_style(area, self.hunk_header_style);
+        buf.set_stringn(
+            area.x,
+            area.y,
+            hunk.header(),
+            area.width as usize,
+            self.hunk_header_style,
+        );
+    }
+
+    /// Renders the line numbers followed by the line, returning nothing for missing lines
+    fn render_line(
+        &self,
+        numbers: &[Option<usize>],
+        line: Option<&DiffLine>,
+        number_width: usize,
+        area: Rect,
+        buf: &mut Buffer,
+    ) {
+        let mut area = area;
+        if self.show_line_numbers {
+            let gutter: String = numbers
+                .iter()
+                .map(|number| {
+                    number.map_or_else(
+                        || format!("{:number_width$} ", ""),
+                        |number| format!("{number:>number_width$} "),
+                    )
+                })
+                .collect();
+            let (x, _) = buf.set_stringn(
+                area.x,
+                area.y,
+                gutter,
+                area.width as usize,
+                self.line_number_style,
+            );
+            area.width -= x - area.x;
+            area.x = x;
+        }
+        let Some(line) = line else {
+            return;
+        };
+        let (style, highlight_style) = match line.kind {
+            DiffLineKind::Context => (Style::new(), Style::new()),
+            DiffLineKind::Added => (self.added_style, self.added_highlight_style),
+            DiffLineKind::Removed => (self.removed_style, self.removed_highlight_style),
+        };
+        let content = line.content();
+        let mut spans = vec![Span::raw(line.kind.sign())];
+        let mut position = 0;
+        for change in &line.changes {
+            if change.start > position {
+                spans.push(Span::raw(&content[position..change.start]));
+            }
+            spans.push(Span::styled(&content[change.clone()], highlight_style));
+            position = change.end;
+        }
+        if position < content.len() {
+            spans.push(Span::raw(&content[position..]));
+        }
+        buf.set_style(area, style);
+        buf.set_line(area.x, area.y, &Line::from(spans).style(style), area.width);
+    }
+}
+
+/// Pushes the pending removed and added lines side by side
+fn align<'d, 'a>(
+    rows: &mut Vec<Row<'d, 'a>>,
+    removed: &mut Vec<NumberedLine<'d, 'a>>,
+    added: &mut Vec<NumberedLine<'d, 'a>>,
+) {
+    for i in 0..removed.len().max(added.len()) {
+        rows.push(Row::SideBySide {
+            old: removed.get(i).copied(),
+            new: added.get(i).copied(),
+        });
+    }
+    removed.clear();
+    added.clear();
+}
+
+impl StatefulWidget for DiffView<'_> {
+    type State = DiffViewState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        self.render_ref(area, buf, state);
+    }
+}
+
+// Note: remove this when StatefulWidgetRef is stabilized and replace with the blanket impl
+impl StatefulWidget for &DiffView<'_> {
+    type State = DiffViewState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        self.render_ref(area, buf, state);
+    }
+}
+
+impl StatefulWidgetRef for DiffView<'_> {
+    type State = DiffViewState;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        self.block.render_ref(area, buf);
+        let area = self.block.inner_if_some(area).intersection(buf.area);
+
+        let rows = self.rows();
+        state.hunk_rows = rows
+            .iter()
+            .enumerate()
+            .filter(|(_, row)| matches!(row, Row::Header(_)))
+            .map(|(i, _)| i)
+            .collect();
+        state.offset = state
+            .offset
+            .min(rows.len().saturating_sub(usize::from(area.height)));
+        if area.is_empty() {
+            return;
+        }
+
+        let number_width = self.line_number_width();
+        let left_width = area.width.saturating_sub(1) / 2;
+        for (y, row) in (area.top()..area.bottom()).zip(rows.iter().skip(state.offset)) {
+            let row_area = Rect::new(area.x, y, area.width, 1);
+            match *row {
+                Row::Header(hunk) => self.render_header(hunk, row_area, buf),
+                Row::Unified { old, new, line } => {
+                    self.render_line(&[old, new], Some(line), number_width, row_area, buf);
+                }
+                Row::SideBySide { old, new } => {
+                    let (left, right) = (
+                        Rect::new(area.x, y, left_width, 1),
+                        Rect::new(area.x + left_width + 1, y, area.width - left_width - 1, 1),
+                    );
+                    if area.width > left_width {
+                        buf.get_mut(area.x + left_width, y)
+                            .set_symbol(symbols::line::VERTICAL)
+                            .set_style(self.line_number_style);
+                    }
+                    for (side, line) in [(left, old), (right, new)] {
+                        let number = line.map(|(number, _)| number);
+                        let line = line.map(|(_, line)| line);
+                        self.render_line(&[number], line, number_width, side, buf);
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Styled for DiffView<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    const OLD: &str = "one\ntwo\nthree\nfour\nfive\nsix\nseven\neight\nnine\nten\n";
+    const NEW: &str = "one\ntwo\nthree\nfour\nfive\nsix\nseven\neight\nnine\nTEN\neleven\n";
+
+    fn render(view: &DiffView, state: &mut DiffViewState, width: u16, height: u16) -> Buffer {
+        let mut buf = Buffer::empty(Rect::new(0, 0, width, height));
+        view.render(buf.area, &mut buf, state);
+        buf
+    }
+
+    /// A view without the default styles, to test the layout of the rows
+    fn plain_view<'a>(diff: &'a Diff<'a>) -> DiffView<'a> {
+        DiffView::new(diff)
+            .line_number_style(Style::new())
+            .hunk_header_style(Style::new())
+            .added_style(Style::new())
+            .removed_style(Style::new())
+            .added_highlight_style(Style::new())
+            .removed_highlight_style(Style::new())
+    }
+
+    #[rstest]
+    #[case::equal("abc", "abc", "===")]
+    #[case::empty("", "", "")]
+    #[case::insert_all("", "ab", "++")]
+    #[case::delete_all("ab", "", "--")]
+    #[case::replace("abc", "axc", "=-+=")]
+    #[case::insert_middle("ac", "abc", "=+=")]
+    #[case::myers_example("abcabba", "cbabac", "--=+==-=+")]
+    fn edit_script(#[case] old: &str, #[case] new: &str, #[case] expected: &str) {
+        let old: Vec<char> = old.chars().collect();
+        let new: Vec<char> = new.chars().collect();
+        let script: String = edits(&old, &new)
+            .into_iter()
+            .map(|edit| match edit {
+                Edit::Equal => '=',
+                Edit::Delete => '-',
+                Edit::Insert => '+',
+            })
+            .collect();
+        assert_eq!(script, expected);
+    }
+
+    #[test]
+    fn from_texts() {
+        let diff = Diff::from_texts(OLD, NEW);
+        assert_eq!(
+            diff,
+            Diff::new([DiffHunk::new(
+                7,
+                7,
+                [
+                    DiffLine::context("seven"),
+                    DiffLine::context("eight"),
+                    DiffLine::context("nine"),
+                    DiffLine::removed("ten"),
+                    DiffLine::added("TEN"),
+                    DiffLine::added("eleven"),
+                ]
+            )])
+        );
+        assert_eq!(diff.hunks()[0].header(), "@@ -7,4 +7,5 @@");
+    }
+
+    #[test]
+    fn from_texts_splits_hunks() {
+        let diff = Diff::from_texts_with_context("a\nb\nc\nd\ne\n", "A\nb\nc\nd\nE\n", 1);
+        let headers: Vec<String> = diff.hunks().iter().map(DiffHunk::header).collect();
+        assert_eq!(headers, ["@@ -1,2 +1,2 @@", "@@ -4,2 +4,2 @@"]);
+
+        let diff = Diff::from_texts_with_context("a\nb\nc\nd\ne\n", "A\nb\nc\nd\nE\n", 2);
+        let headers: Vec<String> = diff.hunks().iter().map(DiffHunk::header).collect();
+        assert_eq!(headers, ["@@ -1,5 +1,5 @@"]);
+    }
+
+    #[test]
+    fn from_identical_texts() {
+        assert!(Diff::from_texts(OLD, OLD).is_empty());
+    }
+
+    #[test]
+    #[allow(clippy::single_range_in_vec_init)]
+    fn line_changes_are_highlighted() {
+        let hunk = DiffHunk::new(
+            1,
+            1,
+            [
+                DiffLine::removed("let x = 1;"),
+                DiffLine::added("let y = 10;"),
+                DiffLine::added("extra"),
+            ],
+        );
+        assert_eq!(hunk.lines()[0].changes(), [4..5]);
+        assert_eq!(hunk.lines()[1].changes(), [4..5, 9..10]);
+        assert_eq!(hunk.lines()[2].changes(), []);
+    }
+
+    #[test]
+    fn render_unified() {
+        let diff = Diff::from_texts_with_context(OLD, NEW, 1);
+        let view = plain_view(&diff);
+        let buf = render(&view, &mut DiffViewState::new(), 20, 5);
+        assert_eq!(
+            buf,
+            Buffer::with_lines([
+                "@@ -9,2 +9,3 @@     ",
+                " 9  9  nine         ",
+                "10    -ten          ",
+                "   10 +TEN          ",
+                "   11 +eleven       ",
+            ])
+        );
+    }
+
+    #[test]
+    fn render_side_by_side() {
+        let diff = Diff::from_texts_with_context(OLD, NEW, 1);
+        let view = plain_view(&diff).mode(DiffMode::SideBySide);
+        let buf = render(&view, &mut DiffViewState::new(), 25, 4);
+        assert_eq!(
+            buf,
+            Buffer::with_lines([
+                "@@ -9,2 +9,3 @@          ",
+                " 9  nine    │ 9  nine    ",
+                "10 -ten     │10 +TEN     ",
+                "            │11 +eleven  ",
+            ])
+        );
+    }
+
+    #[test]
+    fn render_without_line_numbers() {
+        let diff = Diff::from_texts_with_context("a\n", "b\n", 0);
+        let view = plain_view(&diff).show_line_numbers(false);
+        let buf = render(&view, &mut DiffViewState::new(), 16, 3);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["@@ -1,1 +1,1 @@ ", "-a              ", "+b              "])
+        );
+    }
+
+    #[test]
+    fn render_styles() {
+        let diff = Diff::from_texts_with_context("ab\n", "ac\n", 0);
+        let view = DiffView::new(&diff).show_line_numbers(false);
+        let buf = render(&view, &mut DiffViewState::new(), 4, 3);
+        let mut expected = Buffer::with_lines(["@@ -", "-ab ", "+ac "]);
+        expected.set_style(Rect::new(0, 0, 4, 1), Style::new().cyan());
+        expected.set_style(Rect::new(0, 1, 4, 1), Style::new().red());
+        expected.set_style(Rect::new(2, 1, 1, 1), Style::new().red().reversed());
+        expected.set_style(Rect::new(0, 2, 4, 1), Style::new().green());
+        expected.set_style(Rect::new(2, 2, 1, 1), Style::new().green().reversed());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn state_scrolls() {
+        let diff = Diff::from_texts_with_context("a\nb\nc\nd\ne\n", "A\nb\nc\nd\nE\n", 1);
+        let view = plain_view(&diff);
+        let mut state = DiffViewState::new();
+        state.scroll_to_bottom();
+        render(&view, &mut state, 20, 3);
+        assert_eq!(state.offset(), 5);
+        state.scroll_up(2);
+        assert_eq!(state.offset(), 3);
+        state.scroll_to_top();
+        assert_eq!(state.offset(), 0);
+
+        state.next_hunk();
+        assert_eq!(state.offset(), 4);
+        state.next_hunk();
+        assert_eq!(state.offset(), 4);
+        state.scroll_down(1);
+        state.previous_hunk();
+        assert_eq!(state.offset(), 4);
+        state.previous_hunk();
+        assert_eq!(state.offset(), 0);
+    }
+}
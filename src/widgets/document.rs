@@ -0,0 +1,1013 @@
+use std::borrow::Cow;
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    prelude::*,
+    widgets::{Block, StatefulWidgetRef},
+};
+
+/// The marker of the items of unordered lists
+const BULLET: &str = "•";
+
+/// A piece of styled text in a [`Document`], which can be a link
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{prelude::*, widgets::DocumentSpan};
+///
+/// let spans = [
+///     DocumentSpan::raw("See the "),
+///     DocumentSpan::link("key bindings", "help:keys"),
+///     DocumentSpan::styled(" now", Style::new().bold()),
+/// ];
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct DocumentSpan<'a> {
+    content: Cow<'a, str>,
+    style: Style,
+    link: Option<Cow<'a, str>>,
+}
+
+impl<'a> DocumentSpan<'a> {
+    /// Creates a span with the default style
+    pub fn raw<T>(content: T) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        Self::styled(content, Style::new())
+    }
+
+    /// Creates a span with the given style
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    pub fn styled<T, S>(content: T, style: S) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+        S: Into<Style>,
+    {
+        Self {
+            content: content.into(),
+            style: style.into(),
+            link: None,
+        }
+    }
+
+    /// Creates a link to the given target
+    ///
+    /// The target is not interpreted by the document: it is returned by
+    /// [`Document::link_target`] so that the application can follow the link, e.g. by opening
+    /// another help page.
+    pub fn link<T, U>(content: T, target: U) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+        U: Into<Cow<'a, str>>,
+    {
+        Self {
+            content: content.into(),
+            style: Style::new(),
+            link: Some(target.into()),
+        }
+    }
+
+    /// The content of the span
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    /// The target of the span if it is a link
+    pub fn target(&self) -> Option<&str> {
+        self.link.as_deref()
+    }
+}
+
+impl<'a> From<&'a str> for DocumentSpan<'a> {
+    fn from(content: &'a str) -> Self {
+        Self::raw(content)
+    }
+}
+
+impl From<String> for DocumentSpan<'_> {
+    fn from(content: String) -> Self {
+        Self::raw(content)
+    }
+}
+
+impl<'a> From<Span<'a>> for DocumentSpan<'a> {
+    fn from(span: Span<'a>) -> Self {
+        Self::styled(span.content, span.style)
+    }
+}
+
+/// A block of a [`Document`]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+enum Element<'a> {
+    Heading(Vec<DocumentSpan<'a>>),
+    Paragraph(Vec<DocumentSpan<'a>>),
+    ListItem {
+        marker: Cow<'a, str>,
+        spans: Vec<DocumentSpan<'a>>,
+    },
+    Code(Vec<Cow<'a, str>>),
+}
+
+impl<'a> Element<'a> {
+    fn spans(&self) -> &[DocumentSpan<'a>] {
+        match self {
+            Self::Heading(spans) | Self::Paragraph(spans) | Self::ListItem { spans, .. } => spans,
+            Self::Code(_) => &[],
+        }
+    }
+}
+
+/// State of the [`Document`] widget
+///
+/// The state holds the first visible row and the focused link. The number of links is recorded on
+/// render, so that the focus can cycle through them, and the document scrolls to the focused link
+/// on the next render after the focus moves.
+///
+/// # Example
+///
+/// ```rust
+/// # use ratatui::{prelude::*, widgets::*};
+/// # enum KeyCode { Tab, BackTab, Enter }
+/// # fn open_page(target: &str) {}
+/// fn handle_key(key: KeyCode, document: &Document, state: &mut DocumentState) {
+///     match key {
+///         KeyCode::Tab => state.focus_next_link(),
+///         KeyCode::BackTab => state.focus_previous_link(),
+///         KeyCode::Enter => {
+///             if let Some(target) = state.focused_link().and_then(|i| document.link_target(i)) {
+///                 open_page(target);
+///             }
+///         }
+///     }
+/// }
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct DocumentState {
+    offset: usize,
+    focused_link: Option<usize>,
+    /// The number of links during the last render
+    link_count: usize,
+    /// Whether the focused link should be scrolled into view on the next render
+    scroll_to_focus: bool,
+}
+
+impl DocumentState {
+    /// Creates a new state scrolled to the top, without focused link
+    pub const fn new() -> Self {
+        Self {
+            offset: 0,
+            focused_link: None,
+            link_count: 0,
+            scroll_to_focus: false,
+        }
+    }
+
+    /// The index of the first visible row
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Scrolls up by the given number of rows
+    pub fn scroll_up(&mut self, rows: usize) {
+        self.offset = self.offset.saturating_sub(rows);
+    }
+
+    /// Scrolls down by the given number of rows
+    ///
+    /// The offset is clamped to the last row on the next render.
+    pub fn scroll_down(&mut self, rows: usize) {
+        self.offset = self.offset.saturating_add(rows);
+    }
+
+    /// Scrolls to the first row
+    pub fn scroll_to_top(&mut self) {
+        self.offset = 0;
+    }
+
+    /// Scrolls to the last row
+    ///
+    /// The offset is clamped to the last row on the next render.
+    pub fn scroll_to_bottom(&mut self) {
+        self.offset = usize::MAX;
+    }
+
+    /// The index of the focused link, in the order of the document
+    ///
+    /// Use [`Document::link_target`] to get the target of the link.
+    pub const fn focused_link(&self) -> Option<usize> {
+        self.focused_link
+    }
+
+    /// Focuses the link at the given index, or removes the focus with `None`
+    ///
+    /// The document scrolls to the link on the next render.
+    pub fn focus_link(&mut self, index: Option<usize>) {
+        self.focused_link = index;
+        self.scroll_to_focus = true;
+    }
+
+    /// Focuses the next link, or the first link after the last one
+    ///
+    /// This does nothing before the first render or when the document has no links.
+    pub fn focus_next_link(&mut self) {
+        if self.link_count == 0 {
+            return;
+        }
+        let next = self
+            .focused_link
+            .map_or(0, |link| (link + 1) % self.link_count);
+        self.focus_link(Some(next));
+    }
+
+    /// Focuses the previous link, or the last link before the first one
+    ///
+    /// This does nothing before the first render or when the document has no links.
+    pub fn focus_previous_link(&mut self) {
+        if self.link_count == 0 {
+            return;
+        }
+        let previous = self
+            .focused_link
+            .and_then(|link| link.checked_sub(1))
+            .unwrap_or(self.link_count - 1);
+        self.focus_link(Some(previous));
+    }
+}
+
+/// A widget that displays structured rich text, like a help page, with links that can be
+/// focused and followed
+///
+/// A document is a sequence of headings, paragraphs, list items and code blocks, which are built
+/// with the methods of the widget, or parsed from Markdown with `Document::from_markdown` when the
+/// `markdown` feature is enabled. Headings, paragraphs and list items are wrapped to the width of
+/// the area, with a hanging indent for list items, while code blocks are not wrapped.
+///
+/// The text of the blocks is made of [`DocumentSpan`]s, some of which can be links. The
+/// [`DocumentState`] holds the scroll position and the focused link, which can move from link to
+/// link, e.g. with <kbd>Tab</kbd>, and be followed with [`Document::link_target`], e.g. with
+/// <kbd>Enter</kbd>.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{prelude::*, widgets::*};
+///
+/// # fn ui(frame: &mut Frame, state: &mut DocumentState) {
+/// # let area = Rect::default();
+/// let document = Document::new()
+///     .block(Block::bordered().title("Help"))
+///     .heading(["Navigation"])
+///     .paragraph([
+///         DocumentSpan::raw("Use the arrow keys to move, or see "),
+///         DocumentSpan::link("all the key bindings", "keys"),
+///         DocumentSpan::raw("."),
+///     ])
+///     .list_item(["q: quit"])
+///     .list_item(["?: toggle this help"])
+///     .code_block("app --config ~/.config/app.toml");
+/// frame.render_stateful_widget(&document, area, state);
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Document<'a> {
+    elements: Vec<Element<'a>>,
+    block: Option<Block<'a>>,
+    style: Style,
+    heading_style: Style,
+    code_style: Style,
+    link_style: Style,
+    focused_link_style: Style,
+}
+
+impl Default for Document<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Document<'a> {
+    /// Creates an empty document
+    pub const fn new() -> Self {
+        Self {
+            elements: Vec::new(),
+            block: None,
+            style: Style::new(),
+            heading_style: Style::new().add_modifier(Modifier::BOLD),
+            code_style: Style::new().fg(Color::Yellow),
+            link_style: Style::new()
+                .fg(Color::Blue)
+                .add_modifier(Modifier::UNDERLINED),
+            focused_link_style: Style::new().add_modifier(Modifier::REVERSED),
+        }
+    }
+
+    /// Adds a heading, styled with [`Document::heading_style`]
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn heading<I>(mut self, spans: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<DocumentSpan<'a>>,
+    {
+        self.elements.push(Element::Heading(
+            spans.into_iter().map(Into::into).collect(),
+        ));
+        self
+    }
+
+    /// Adds a paragraph
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn paragraph<I>(mut self, spans: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<DocumentSpan<'a>>,
+    {
+        self.elements.push(Element::Paragraph(
+            spans.into_iter().map(Into::into).collect(),
+        ));
+        self
+    }
+
+    /// Adds an item of an unordered list, marked with a bullet
+    ///
+    /// Consecutive list items are not separated by blank lines.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn list_item<I>(self, spans: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<DocumentSpan<'a>>,
+    {
+        self.push_list_item(BULLET.into(), spans)
+    }
+
+    /// Adds an item of an ordered list, marked with the given number
+    ///
+    /// Consecutive list items are not separated by blank lines.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn ordered_list_item<I>(self, number: usize, spans: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<DocumentSpan<'a>>,
+    {
+        self.push_list_item(format!("{number}.").into(), spans)
+    }
+
+    /// Adds a block of code, styled with [`Document::code_style`]
+    ///
+    /// The lines of the code are not wrapped.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn code_block<T>(mut self, code: T) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        let lines = match code.into() {
+            Cow::Borrowed(code) => code.lines().map(Cow::Borrowed).collect(),
+            Cow::Owned(code) => code.lines().map(|line| Cow::Owned(line.into())).collect(),
+        };
+        self.elements.push(Element::Code(lines));
+        self
+    }
+
+    /// Wraps the document in a [`Block`]
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the style of the document
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the headings, which are bold by default
+    ///
+    /// The style of each span of the headings is patched on top of this style.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn heading_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.heading_style = style.into();
+        self
+    }
+
+    /// Sets the style of the code blocks, which are yellow by default
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn code_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.code_style = style.into();
+        self
+    }
+
+    /// Sets the style of the links, which are blue and underlined by default
+    ///
+    /// The style is patched on top of the style of the link span.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn link_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.link_style = style.into();
+        self
+    }
+
+    /// Sets the style of the focused link, which is reversed by default
+    ///
+    /// The style is patched on top of [`Document::link_style`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn focused_link_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.focused_link_style = style.into();
+        self
+    }
+
+    /// The number of links of the document
+    pub fn link_count(&self) -> usize {
+        self.links().count()
+    }
+
+    /// The target of the link at the given index, in the order of the document
+    pub fn link_target(&self, index: usize) -> Option<&str> {
+        self.links().nth(index)
+    }
+
+    fn links(&self) -> impl Iterator<Item = &str> {
+        self.elements
+            .iter()
+            .flat_map(Element::spans)
+            .filter_map(DocumentSpan::target)
+    }
+
+    fn push_list_item<I>(mut self, marker: Cow<'a, str>, spans: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<DocumentSpan<'a>>,
+    {
+        self.elements.push(Element::ListItem {
+            marker,
+            spans: spans.into_iter().map(Into::into).collect(),
+        });
+        self
+    }
+
+    /// Lays out the blocks of the document into rows of the given width
+    fn layout(&self, width: u16) -> Layout<'_> {
+        let mut layout = Layout {
+            width: usize::from(width),
+            rows: Vec::new(),
+            link_rows: Vec::new(),
+            link_count: 0,
+        };
+        let mut previous: Option<&Element> = None;
+        for element in &self.elements {
+            let continues_list = matches!(
+                (previous, element),
+                (Some(Element::ListItem { .. }), Element::ListItem { .. })
+            );
+            if previous.is_some() && !continues_list {
+                layout.rows.push(Row::default());
+            }
+            match element {
+                Element::Heading(spans) => layout.wrap(None, spans, self.heading_style),
+                Element::Paragraph(spans) => layout.wrap(None, spans, Style::new()),
+                Element::ListItem { marker, spans } => {
+                    layout.wrap(Some(marker), spans, Style::new());
+                }
+                Element::Code(lines) => {
+                    layout.rows.extend(lines.iter().map(|line| Row {
+                        fragments: vec![Fragment {
+                            content: line,
+                            style: self.code_style,
+                            link: None,
+                        }],
+                        ..Row::default()
+                    }));
+                }
+            }
+            previous = Some(element);
+        }
+        layout
+    }
+}
+
+/// A piece of text on a row, with the index of its link if it is part of one
+#[derive(Debug, Clone, Copy)]
+struct Fragment<'d> {
+    content: &'d str,
+    style: Style,
+    link: Option<usize>,
+}
+
+/// A row of a laid out document
+#[derive(Debug, Default)]
+struct Row<'d> {
+    /// The marker of a list item, displayed in the indentation of its first row
+    marker: Option<&'d str>,
+    indent: u16,
+    /// The width of the fragments
+    width: usize,
+    fragments: Vec<Fragment<'d>>,
+}
+
+/// The rows of a document laid out for a given width
+struct Layout<'d> {
+    width: usize,
+    rows: Vec<Row<'d>>,
+    /// The first row of each link
+    link_rows: Vec<usize>,
+    link_count: usize,
+}
+
+impl<'d> Layout<'d> {
+    /// Wraps spans into rows at word boundaries, indenting them after the marker if any
+    fn wrap(&mut self, marker: Option<&'d str>, spans: &'d [DocumentSpan], base: Style) {
+        let indent = marker.map_or(0, |marker| marker.width() + 1);
+        let available = self.width.saturating_sub(indent).max(1);
+        let mut row = Row {
+            marker,
+            indent: u16::try_from(indent).unwrap_or(u16::MAX),
+            ..Row::default()
+        };
+        // the fragments of the current word, which can span several spans, and the space before it
+        let mut word = Vec::new();
+        let mut space = None;
+        for span in spans {
+            let link = span.link.is_some().then(|| {
+                self.link_count += 1;
+                self.link_count - 1
+            });
+            let style = base.patch(span.style);
+            for (is_space, content) in split_words(&span.content) {
+                if is_space {
+                    self.place_word(&mut row, std::mem::take(&mut word), space, available);
+                    space = Some(Fragment {
+                        content: " ",
+                        style,
+                        link,
+                    });
+                } else {
+                    word.push(Fragment {
+                        content,
+                        style,
+                        link,
+                    });
+                }
+            }
+        }
+        self.place_word(&mut row, word, space, available);
+        self.rows.push(row);
+    }
+
+    /// Places a word on the current row, or on a new row if it does not fit, breaking it at
+    /// grapheme boundaries if it is wider than the available width
+    fn place_word(
+        &mut self,
+        row: &mut Row<'d>,
+        word: Vec<Fragment<'d>>,
+        space: Option<Fragment<'d>>,
+        available: usize,
+    ) {
+        if word.is_empty() {
+            return;
+        }
+        let word_width: usize = word.iter().map(|fragment| fragment.content.width()).sum();
+        let space_width = usize::from(space.is_some());
+        if row.width > 0 && row.width + space_width + word_width > available {
+            self.break_row(row);
+        }
+        if let Some(space) = space.filter(|_| row.width > 0) {
+            self.push(row, space);
+        }
+        if word_width <= available {
+            for fragment in word {
+                self.push(row, fragment);
+            }
+            return;
+        }
+        for fragment in word {
+            for grapheme in fragment.content.graphemes(true) {
+                if row.width > 0 && row.width + grapheme.width() > available {
+                    self.break_row(row);
+                }
+                self.push(
+                    row,
+                    Fragment {
+                        content: grapheme,
+                        ..fragment
+                    },
+                );
+            }
+        }
+    }
+
+    fn push(&mut self, row: &mut Row<'d>, fragment: Fragment<'d>) {
+        if let Some(link) = fragment.link {
+            if self.link_rows.len() <= link {
+                self.link_rows.resize(link + 1, self.rows.len());
+            }
+        }
+        row.width += fragment.content.width();
+        row.fragments.push(fragment);
+    }
+
+    fn break_row(&mut self, row: &mut Row<'d>) {
+        let next = Row {
+            indent: row.indent,
+            ..Row::default()
+        };
+        self.rows.push(std::mem::replace(row, next));
+    }
+}
+
+/// Splits a text into alternating runs of whitespace and of other characters
+fn split_words(text: &str) -> impl Iterator<Item = (bool, &str)> {
+    let mut rest = text;
+    std::iter::from_fn(move || {
+        let is_space = rest.chars().next()?.is_whitespace();
+        let end = rest
+            .find(|c: char| c.is_whitespace() != is_space)
+            .unwrap_or(rest.len());
+        let (run, tail) = rest.split_at(end);
+        rest = tail;
+        Some((is_space, run))
+    })
+}
+
+impl StatefulWidget for Document<'_> {
+    type State = DocumentState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        self.render_ref(area, buf, state);
+    }
+}
+
+// Note: remove this when StatefulWidgetRef is stabilized and replace with the blanket impl
+impl StatefulWidget for &Document<'_> {
+    type State = DocumentState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        self.render_ref(area, buf, state);
+    }
+}
+
+impl StatefulWidgetRef for Document<'_> {
+    type State = DocumentState;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        self.block.render_ref(area, buf);
+        let area = self.block.inner_if_some(area).intersection(buf.area);
+
+        let layout = self.layout(area.width);
+        state.link_count = layout.link_count;
+        state.focused_link = state.focused_link.filter(|&link| link < layout.link_count);
+        if area.is_empty() {
+            return;
+        }
+
+        let height = usize::from(area.height);
+        if state.scroll_to_focus {
+            let focused_row = state
+                .focused_link
+                .and_then(|link| layout.link_rows.get(link));
+            if let Some(&row) = focused_row {
+                state.offset = state.offset.clamp((row + 1).saturating_sub(height), row);
+            }
+            state.scroll_to_focus = false;
+        }
+        state.offset = state.offset.min(layout.rows.len().saturating_sub(height));
+
+        for (y, row) in (area.top()..area.bottom()).zip(layout.rows.iter().skip(state.offset)) {
+            if let Some(marker) = row.marker {
+                buf.set_stringn(area.x, y, marker, area.width as usize, Style::new());
+            }
+            let mut x = area.x + row.indent.min(area.width);
+            for fragment in &row.fragments {
+                let mut style = fragment.style;
+                if fragment.link.is_some() {
+                    style = style.patch(self.link_style);
+                    if fragment.link == state.focused_link {
+                        style = style.patch(self.focused_link_style);
+                    }
+                }
+                let width = usize::from(area.right() - x);
+                (x, _) = buf.set_stringn(x, y, fragment.content, width, style);
+            }
+        }
+    }
+}
+
+impl Styled for Document<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(feature = "markdown")]
+mod markdown {
+    use super::*;
+    use crate::text::markdown::{heading, ordered_list_marker, parse_inline};
+
+    impl<'a> Document<'a> {
+        /// Creates a document from Markdown
+        ///
+        /// This supports the same subset of Markdown as [`from_markdown`], and inline links
+        /// (`[text](target)`). Consecutive lines are joined into paragraphs, which end at blank
+        /// lines, and lines following a list item continue it.
+        ///
+        /// # Example
+        ///
+        /// ```rust
+        /// use ratatui::widgets::Document;
+        ///
+        /// let document = Document::from_markdown(
+        ///     "# Help\n\nPress `q` to quit, or see the [key bindings](keys).\n\n- one\n- two",
+        /// );
+        /// assert_eq!(document.link_target(0), Some("keys"));
+        /// ```
+        ///
+        /// [`from_markdown`]: crate::text::from_markdown
+        pub fn from_markdown(markdown: &'a str) -> Self {
+            let mut document = Self::new();
+            let mut fence = None;
+            // whether the next line continues the last paragraph or list item
+            let mut open = false;
+            for line in markdown.lines() {
+                let trimmed = line.trim_start();
+                if let Some(marker) = fence {
+                    if trimmed.starts_with(marker) {
+                        fence = None;
+                        continue;
+                    }
+                    if let Some(Element::Code(lines)) = document.elements.last_mut() {
+                        lines.push(Cow::Borrowed(line));
+                    }
+                    continue;
+                }
+                if let Some(marker) = ["```", "~~~"]
+                    .into_iter()
+                    .find(|marker| trimmed.starts_with(marker))
+                {
+                    fence = Some(marker);
+                    document.elements.push(Element::Code(Vec::new()));
+                    open = false;
+                } else if trimmed.is_empty() {
+                    open = false;
+                } else if let Some((_, content)) = heading(trimmed) {
+                    document
+                        .elements
+                        .push(Element::Heading(parse_spans(content)));
+                    open = false;
+                } else if let Some(item) = ["- ", "* ", "+ "]
+                    .into_iter()
+                    .find_map(|marker| trimmed.strip_prefix(marker))
+                {
+                    document = document.push_list_item(BULLET.into(), parse_spans(item));
+                    open = true;
+                } else if let Some(marker_len) = ordered_list_marker(trimmed) {
+                    let marker = trimmed[..marker_len].trim_end();
+                    let item = parse_spans(&trimmed[marker_len..]);
+                    document = document.push_list_item(marker.into(), item);
+                    open = true;
+                } else {
+                    let spans = parse_spans(trimmed);
+                    match document.elements.last_mut() {
+                        Some(Element::Paragraph(last) | Element::ListItem { spans: last, .. })
+                            if open =>
+                        {
+                            last.push(DocumentSpan::raw(" "));
+                            last.extend(spans);
+                        }
+                        _ => {
+                            document.elements.push(Element::Paragraph(spans));
+                            open = true;
+                        }
+                    }
+                }
+            }
+            document
+        }
+    }
+
+    /// Converts the links, emphasis and code spans of a line into spans
+    fn parse_spans(text: &str) -> Vec<DocumentSpan<'_>> {
+        let mut spans = Vec::new();
+        let mut rest = text;
+        while let Some((before, label, target, after)) = find_link(rest) {
+            spans.extend(
+                parse_inline(before, Style::new())
+                    .into_iter()
+                    .map(Into::into),
+            );
+            spans.push(DocumentSpan::link(label, target));
+            rest = after;
+        }
+        spans.extend(parse_inline(rest, Style::new()).into_iter().map(Into::into));
+        spans
+    }
+
+    /// Finds the first inline link (`[label](target)`) of a text, returning the text before it,
+    /// its label, its target and the text after it
+    fn find_link(text: &str) -> Option<(&str, &str, &str, &str)> {
+        let mut start = 0;
+        while let Some(separator) = text[start..].find("](") {
+            let separator = start + separator;
+            let open = text[..separator].rfind('[');
+            let close = text[separator + 2..].find(')');
+            if let (Some(open), Some(close)) = (open, close) {
+                let close = separator + 2 + close;
+                return Some((
+                    &text[..open],
+                    &text[open + 1..separator],
+                    &text[separator + 2..close],
+                    &text[close + 1..],
+                ));
+            }
+            start = separator + 2;
+        }
+        None
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use pretty_assertions::assert_eq;
+
+        use super::*;
+
+        #[test]
+        fn from_markdown() {
+            let markdown = "# Title\n\nSee [docs](https://x) and **more**\ntext.\n\n- one\n  \
+                            continued\n2. two\n\n```\ncode\n```";
+            assert_eq!(
+                Document::from_markdown(markdown),
+                Document::new()
+                    .heading(["Title"])
+                    .paragraph([
+                        DocumentSpan::raw("See "),
+                        DocumentSpan::link("docs", "https://x"),
+                        DocumentSpan::raw(" and "),
+                        DocumentSpan::styled("more", Style::new().bold()),
+                        DocumentSpan::raw(" "),
+                        DocumentSpan::raw("text."),
+                    ])
+                    .list_item(["one", " ", "continued"])
+                    .ordered_list_item(2, ["two"])
+                    .code_block("code")
+            );
+        }
+
+        #[test]
+        fn find_links() {
+            assert_eq!(find_link("no link"), None);
+            assert_eq!(find_link("a [b](c) d"), Some(("a ", "b", "c", " d")));
+            assert_eq!(find_link("[a [b](c)"), Some(("[a ", "b", "c", "")));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn render(document: &Document, state: &mut DocumentState, width: u16, height: u16) -> Buffer {
+        let mut buf = Buffer::empty(Rect::new(0, 0, width, height));
+        document.render(buf.area, &mut buf, state);
+        buf
+    }
+
+    /// A document without the default styles, to test the layout of the rows
+    fn plain_document<'a>() -> Document<'a> {
+        Document::new()
+            .heading_style(Style::new())
+            .code_style(Style::new())
+            .link_style(Style::new())
+            .focused_link_style(Style::new())
+    }
+
+    #[test]
+    fn render_blocks() {
+        let document = plain_document()
+            .heading(["Help"])
+            .paragraph([
+                DocumentSpan::raw("Press "),
+                DocumentSpan::link("q", "quit"),
+                DocumentSpan::raw(" to quit the app"),
+            ])
+            .list_item(["first item wraps"])
+            .list_item(["two"])
+            .code_block("let a = 1; // not wrapped");
+        let buf = render(&document, &mut DocumentState::new(), 12, 10);
+        assert_eq!(
+            buf,
+            Buffer::with_lines([
+                "Help        ",
+                "            ",
+                "Press q to  ",
+                "quit the app",
+                "            ",
+                "• first item",
+                "  wraps     ",
+                "• two       ",
+                "            ",
+                "let a = 1; /",
+            ])
+        );
+    }
+
+    #[test]
+    fn render_long_word() {
+        let document = plain_document().ordered_list_item(1, ["abcdefgh"]);
+        let buf = render(&document, &mut DocumentState::new(), 6, 3);
+        assert_eq!(buf, Buffer::with_lines(["1. abc", "   def", "   gh "]));
+    }
+
+    #[test]
+    fn render_styles() {
+        let document = Document::new()
+            .heading([DocumentSpan::styled("H", Color::Red)])
+            .paragraph([
+                DocumentSpan::raw("a "),
+                DocumentSpan::link("b", "x"),
+                DocumentSpan::raw(" c "),
+                DocumentSpan::link("d", "y"),
+            ]);
+        let mut state = DocumentState::new();
+        render(&document, &mut state, 8, 3);
+        state.focus_next_link();
+        state.focus_next_link();
+        let buf = render(&document, &mut state, 8, 3);
+        let mut expected = Buffer::with_lines(["H       ", "        ", "a b c d "]);
+        let link_style = Style::new().blue().underlined();
+        expected.set_style(Rect::new(0, 0, 1, 1), Style::new().red().bold());
+        expected.set_style(Rect::new(2, 2, 1, 1), link_style);
+        expected.set_style(Rect::new(6, 2, 1, 1), link_style.reversed());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn links() {
+        let document = Document::new()
+            .paragraph([DocumentSpan::link("a", "x"), DocumentSpan::raw(" b")])
+            .list_item([DocumentSpan::link("c", "y")]);
+        assert_eq!(document.link_count(), 2);
+        assert_eq!(document.link_target(1), Some("y"));
+        assert_eq!(document.link_target(2), None);
+    }
+
+    #[test]
+    fn state_focuses_links() {
+        let document = Document::new().paragraph([
+            DocumentSpan::link("a", "x"),
+            DocumentSpan::raw(" "),
+            DocumentSpan::link("b", "y"),
+        ]);
+        let mut state = DocumentState::new();
+        state.focus_next_link();
+        assert_eq!(state.focused_link(), None);
+        render(&document, &mut state, 10, 1);
+        state.focus_next_link();
+        assert_eq!(state.focused_link(), Some(0));
+        state.focus_next_link();
+        assert_eq!(state.focused_link(), Some(1));
+        state.focus_next_link();
+        assert_eq!(state.focused_link(), Some(0));
+        state.focus_previous_link();
+        assert_eq!(state.focused_link(), Some(1));
+        state.focus_link(Some(5));
+        render(&document, &mut state, 10, 1);
+        assert_eq!(state.focused_link(), None);
+    }
+
+    #[test]
+    fn state_scrolls_to_focused_link() {
+        let document = plain_document()
+            .paragraph(["one two three four five"])
+            .paragraph([DocumentSpan::link("link", "x")]);
+        let mut state = DocumentState::new();
+        render(&document, &mut state, 5, 2);
+        state.focus_next_link();
+        let buf = render(&document, &mut state, 5, 2);
+        assert_eq!(state.offset(), 5);
+        assert_eq!(buf, Buffer::with_lines(["     ", "link "]));
+
+        // scrolling away from the focused link is not undone
+        state.scroll_up(2);
+        render(&document, &mut state, 5, 2);
+        assert_eq!(state.offset(), 3);
+
+        state.scroll_to_bottom();
+        render(&document, &mut state, 5, 2);
+        assert_eq!(state.offset(), 5);
+    }
+}
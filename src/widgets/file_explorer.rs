@@ -0,0 +1,652 @@
+use std::{
+    cmp::Ordering,
+    fs, io,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+use strum::{Display, EnumString};
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    prelude::*,
+    widgets::{Block, StatefulWidgetRef},
+};
+
+/// An entry of a directory listed by a [`FileExplorerState`]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct FileEntry {
+    path: PathBuf,
+    name: String,
+    is_dir: bool,
+    size: u64,
+    modified: Option<SystemTime>,
+}
+
+impl FileEntry {
+    /// Reads the metadata of the entry at the given path, following symbolic links
+    ///
+    /// The metadata of broken symbolic links is the metadata of the link itself.
+    fn new(path: PathBuf) -> Self {
+        let metadata = fs::metadata(&path)
+            .or_else(|_| fs::symlink_metadata(&path))
+            .ok();
+        let name = path
+            .file_name()
+            .map_or_else(String::new, |name| name.to_string_lossy().into_owned());
+        Self {
+            name,
+            is_dir: metadata.as_ref().is_some_and(fs::Metadata::is_dir),
+            size: metadata.as_ref().map_or(0, fs::Metadata::len),
+            modified: metadata.and_then(|metadata| metadata.modified().ok()),
+            path,
+        }
+    }
+
+    /// The path of the entry
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The file name of the entry
+    ///
+    /// Names that are not valid Unicode are converted lossily.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Whether the entry is a directory, or a symbolic link to a directory
+    pub const fn is_dir(&self) -> bool {
+        self.is_dir
+    }
+
+    /// The size of the entry in bytes
+    pub const fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// The last modification time of the entry, if the platform provides it
+    pub const fn modified(&self) -> Option<SystemTime> {
+        self.modified
+    }
+
+    /// Whether the entry is hidden, i.e. its name starts with a dot
+    pub fn is_hidden(&self) -> bool {
+        self.name.starts_with('.')
+    }
+}
+
+/// The order of the entries of a [`FileExplorerState`]
+///
+/// Directories are always listed before files, and entries that compare equal are sorted by
+/// name.
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum FileSort {
+    /// Sorts the entries by name, ignoring case. This is the default.
+    #[default]
+    Name,
+    /// Sorts the entries by size, largest first
+    Size,
+    /// Sorts the entries by modification time, most recent first
+    Modified,
+}
+
+/// State of the [`FileExplorer`] widget
+///
+/// The state holds the listing of the current directory and the selected entry. It reads the
+/// file system when it is created and when it changes directory, not when it is rendered, so call
+/// [`FileExplorerState::refresh`] to pick up changes made to the directory.
+///
+/// Opening the selected entry with [`FileExplorerState::open_selected`] enters directories and
+/// returns the path of files, so the application decides what to do with the chosen file.
+///
+/// # Example
+///
+/// ```rust
+/// use std::{io, path::PathBuf};
+///
+/// use ratatui::widgets::FileExplorerState;
+///
+/// # enum KeyCode { Up, Down, Left, Enter, Char(char) }
+/// fn handle_key(key: KeyCode, state: &mut FileExplorerState) -> io::Result<Option<PathBuf>> {
+///     match key {
+///         KeyCode::Up => state.select_previous(),
+///         KeyCode::Down => state.select_next(),
+///         KeyCode::Left => state.go_to_parent()?,
+///         KeyCode::Char('.') => state.toggle_hidden(),
+///         KeyCode::Enter => return state.open_selected(),
+///         KeyCode::Char(_) => {}
+///     }
+///     Ok(None)
+/// }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct FileExplorerState {
+    current_dir: PathBuf,
+    /// All the entries of the current directory, including the hidden ones, in order
+    entries: Vec<FileEntry>,
+    show_hidden: bool,
+    sort: FileSort,
+    /// The index of the selected entry among the visible entries
+    selected: usize,
+    offset: usize,
+}
+
+impl FileExplorerState {
+    /// Creates a new state listing the given directory, without hidden entries
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory cannot be read.
+    pub fn new<P: Into<PathBuf>>(path: P) -> io::Result<Self> {
+        let mut state = Self {
+            current_dir: PathBuf::new(),
+            entries: Vec::new(),
+            show_hidden: false,
+            sort: FileSort::default(),
+            selected: 0,
+            offset: 0,
+        };
+        state.set_current_dir(path)?;
+        Ok(state)
+    }
+
+    /// The directory being listed
+    pub fn current_dir(&self) -> &Path {
+        &self.current_dir
+    }
+
+    /// The visible entries of the current directory, in order
+    pub fn entries(&self) -> impl Iterator<Item = &FileEntry> {
+        let show_hidden = self.show_hidden;
+        self.entries
+            .iter()
+            .filter(move |entry| show_hidden || !entry.is_hidden())
+    }
+
+    /// The selected entry, or `None` if the directory has no visible entries
+    pub fn selected(&self) -> Option<&FileEntry> {
+        self.entries().nth(self.selected)
+    }
+
+    /// Selects the next entry, staying on the last one
+    pub fn select_next(&mut self) {
+        self.selected = (self.selected + 1).min(self.len().saturating_sub(1));
+    }
+
+    /// Selects the previous entry, staying on the first one
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// Selects the first entry
+    pub fn select_first(&mut self) {
+        self.selected = 0;
+    }
+
+    /// Selects the last entry
+    pub fn select_last(&mut self) {
+        self.selected = self.len().saturating_sub(1);
+    }
+
+    /// Whether hidden entries are listed
+    pub const fn show_hidden(&self) -> bool {
+        self.show_hidden
+    }
+
+    /// Sets whether hidden entries are listed, keeping the selected entry selected if it is still
+    /// listed
+    pub fn set_show_hidden(&mut self, show_hidden: bool) {
+        let selected = self.selected_path();
+        self.show_hidden = show_hidden;
+        self.select_path(selected.as_deref());
+    }
+
+    /// Toggles whether hidden entries are listed
+    pub fn toggle_hidden(&mut self) {
+        self.set_show_hidden(!self.show_hidden);
+    }
+
+    /// The order of the entries
+    pub const fn sort(&self) -> FileSort {
+        self.sort
+    }
+
+    /// Sets the order of the entries, keeping the selected entry selected
+    pub fn set_sort(&mut self, sort: FileSort) {
+        let selected = self.selected_path();
+        self.sort = sort;
+        self.sort_entries();
+        self.select_path(selected.as_deref());
+    }
+
+    /// Lists the given directory and selects its first entry
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory cannot be read, in which case the state is unchanged.
+    pub fn set_current_dir<P: Into<PathBuf>>(&mut self, path: P) -> io::Result<()> {
+        let path = path.into();
+        self.entries = read_entries(&path)?;
+        self.current_dir = path;
+        self.sort_entries();
+        self.selected = 0;
+        self.offset = 0;
+        Ok(())
+    }
+
+    /// Lists the current directory again, keeping the selected entry selected if it still exists
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory cannot be read, in which case the state is unchanged.
+    pub fn refresh(&mut self) -> io::Result<()> {
+        let selected = self.selected_path();
+        self.entries = read_entries(&self.current_dir)?;
+        self.sort_entries();
+        self.select_path(selected.as_deref());
+        Ok(())
+    }
+
+    /// Opens the selected entry
+    ///
+    /// Directories are entered, and `None` is returned. Files are not opened: their path is
+    /// returned for the application to use.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the selected directory cannot be read, in which case the state is
+    /// unchanged.
+    pub fn open_selected(&mut self) -> io::Result<Option<PathBuf>> {
+        let Some(entry) = self.selected() else {
+            return Ok(None);
+        };
+        if !entry.is_dir {
+            return Ok(Some(entry.path.clone()));
+        }
+        let path = entry.path.clone();
+        self.set_current_dir(path)?;
+        Ok(None)
+    }
+
+    /// Lists the parent of the current directory and selects the directory that was listed
+    ///
+    /// This does nothing if the current directory has no parent.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the parent directory cannot be read, in which case the state is
+    /// unchanged.
+    pub fn go_to_parent(&mut self) -> io::Result<()> {
+        let Some(parent) = self.current_dir.parent() else {
+            return Ok(());
+        };
+        let child = self.current_dir.clone();
+        // an empty parent means the current directory is relative, e.g. `src`
+        let parent = if parent.as_os_str().is_empty() {
+            PathBuf::from(".")
+        } else {
+            parent.to_path_buf()
+        };
+        self.set_current_dir(parent)?;
+        let selected = self
+            .entries
+            .iter()
+            .find(|entry| entry.path.file_name() == child.file_name())
+            .map(|entry| entry.path.clone());
+        self.select_path(selected.as_deref());
+        Ok(())
+    }
+
+    /// The number of visible entries
+    fn len(&self) -> usize {
+        self.entries().count()
+    }
+
+    fn selected_path(&self) -> Option<PathBuf> {
+        self.selected().map(|entry| entry.path.clone())
+    }
+
+    /// Selects the visible entry with the given path, or the closest entry to the current
+    /// selection if there is none
+    fn select_path(&mut self, path: Option<&Path>) {
+        let index = path.and_then(|path| self.entries().position(|entry| entry.path == path));
+        self.selected = index.unwrap_or_else(|| self.selected.min(self.len().saturating_sub(1)));
+    }
+
+    fn sort_entries(&mut self) {
+        let sort = self.sort;
+        self.entries.sort_by(|a, b| {
+            b.is_dir
+                .cmp(&a.is_dir)
+                .then_with(|| match sort {
+                    FileSort::Name => Ordering::Equal,
+                    FileSort::Size => b.size.cmp(&a.size),
+                    FileSort::Modified => b.modified.cmp(&a.modified),
+                })
+                .then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase()))
+                .then_with(|| a.name.cmp(&b.name))
+        });
+    }
+}
+
+/// Reads the entries of a directory, skipping the entries that cannot be read
+fn read_entries(path: &Path) -> io::Result<Vec<FileEntry>> {
+    Ok(fs::read_dir(path)?
+        .filter_map(Result::ok)
+        .map(|entry| FileEntry::new(entry.path()))
+        .collect())
+}
+
+/// Formats a size in bytes with a binary unit, e.g. `1.5K`
+#[allow(clippy::cast_precision_loss)]
+fn format_size(size: u64) -> String {
+    const UNITS: [&str; 5] = ["K", "M", "G", "T", "P"];
+    if size < 1024 {
+        return format!("{size}B");
+    }
+    let mut value = size as f64 / 1024.0;
+    let mut unit = UNITS[0];
+    for next in &UNITS[1..] {
+        if value < 1024.0 {
+            break;
+        }
+        value /= 1024.0;
+        unit = next;
+    }
+    format!("{value:.1}{unit}")
+}
+
+/// A widget that displays the listing of a directory of a [`FileExplorerState`]
+///
+/// Each row displays the name of an entry, with a trailing `/` for directories, and the size of
+/// files aligned to the right. Directories are styled with [`FileExplorer::directory_style`] and
+/// the selected entry with [`FileExplorer::highlight_style`]. The listing scrolls to keep the
+/// selected entry visible.
+///
+/// The widget only displays the state: see [`FileExplorerState`] to navigate the file system.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{prelude::*, widgets::*};
+///
+/// # fn ui(frame: &mut Frame, state: &mut FileExplorerState) {
+/// # let area = Rect::default();
+/// let title = state.current_dir().display().to_string();
+/// let explorer = FileExplorer::new()
+///     .block(Block::bordered().title(title))
+///     .highlight_style(Style::new().on_dark_gray());
+/// frame.render_stateful_widget(explorer, area, state);
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct FileExplorer<'a> {
+    block: Option<Block<'a>>,
+    style: Style,
+    highlight_style: Style,
+    directory_style: Style,
+    show_size: bool,
+}
+
+impl Default for FileExplorer<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> FileExplorer<'a> {
+    /// Creates a new file explorer widget
+    pub const fn new() -> Self {
+        Self {
+            block: None,
+            style: Style::new(),
+            highlight_style: Style::new().add_modifier(Modifier::REVERSED),
+            directory_style: Style::new().fg(Color::Blue).add_modifier(Modifier::BOLD),
+            show_size: true,
+        }
+    }
+
+    /// Wraps the listing in a [`Block`]
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the style of the widget
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the row of the selected entry, which is reversed by default
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.highlight_style = style.into();
+        self
+    }
+
+    /// Sets the style of the names of directories, which are blue and bold by default
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn directory_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.directory_style = style.into();
+        self
+    }
+
+    /// Sets whether the sizes of files are displayed, which is the default
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn show_size(mut self, show: bool) -> Self {
+        self.show_size = show;
+        self
+    }
+}
+
+impl StatefulWidget for FileExplorer<'_> {
+    type State = FileExplorerState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        self.render_ref(area, buf, state);
+    }
+}
+
+// Note: remove this when StatefulWidgetRef is stabilized and replace with the blanket impl
+impl StatefulWidget for &FileExplorer<'_> {
+    type State = FileExplorerState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        self.render_ref(area, buf, state);
+    }
+}
+
+impl StatefulWidgetRef for FileExplorer<'_> {
+    type State = FileExplorerState;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        self.block.render_ref(area, buf);
+        let area = self.block.inner_if_some(area).intersection(buf.area);
+        let len = state.len();
+        state.selected = state.selected.min(len.saturating_sub(1));
+        if area.is_empty() {
+            return;
+        }
+
+        let height = usize::from(area.height);
+        state.offset = state
+            .offset
+            .clamp((state.selected + 1).saturating_sub(height), state.selected)
+            .min(len.saturating_sub(height));
+        let rows = (area.top()..area.bottom()).zip(state.entries().enumerate().skip(state.offset));
+        for (y, (index, entry)) in rows {
+            let size = if self.show_size && !entry.is_dir {
+                format_size(entry.size)
+            } else {
+                String::new()
+            };
+            let size_width = size.width() as u16;
+            if size_width < area.width {
+                let x = area.right() - size_width;
+                buf.set_string(x, y, &size, Style::new());
+            }
+            let (name, style) = if entry.is_dir {
+                (format!("{}/", entry.name), self.directory_style)
+            } else {
+                (entry.name.clone(), Style::new())
+            };
+            // keep a space between the name and the size
+            let name_width = if size.is_empty() {
+                area.width
+            } else {
+                area.width.saturating_sub(size_width + 1)
+            };
+            buf.set_stringn(area.x, y, name, usize::from(name_width), style);
+            if index == state.selected {
+                buf.set_style(Rect::new(area.x, y, area.width, 1), self.highlight_style);
+            }
+        }
+    }
+}
+
+impl Styled for FileExplorer<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::process;
+
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    /// A temporary directory with a subdirectory, two files and a hidden file, removed on drop
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> Self {
+            let path = std::env::temp_dir().join(format!("ratatui-{}-{name}", process::id()));
+            fs::create_dir_all(path.join("b_dir")).unwrap();
+            fs::write(path.join("b_dir").join("inner"), "").unwrap();
+            fs::write(path.join("a.txt"), "hello").unwrap();
+            fs::write(path.join("C.md"), vec![b'c'; 2000]).unwrap();
+            fs::write(path.join(".hidden"), "").unwrap();
+            Self(path)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    fn names(state: &FileExplorerState) -> Vec<&str> {
+        state.entries().map(FileEntry::name).collect()
+    }
+
+    #[rstest]
+    #[case(0, "0B")]
+    #[case(1023, "1023B")]
+    #[case(1024, "1.0K")]
+    #[case(1536, "1.5K")]
+    #[case(1024 * 1024, "1.0M")]
+    #[case(5 * 1024 * 1024 * 1024, "5.0G")]
+    fn formats_size(#[case] size: u64, #[case] expected: &str) {
+        assert_eq!(format_size(size), expected);
+    }
+
+    #[test]
+    fn lists_and_sorts_entries() {
+        let dir = TempDir::new("sort");
+        let mut state = FileExplorerState::new(&dir.0).unwrap();
+        assert_eq!(state.current_dir(), dir.0);
+        assert_eq!(names(&state), ["b_dir", "a.txt", "C.md"]);
+
+        state.toggle_hidden();
+        assert_eq!(names(&state), ["b_dir", ".hidden", "a.txt", "C.md"]);
+
+        state.set_sort(FileSort::Size);
+        assert_eq!(names(&state), ["b_dir", "C.md", "a.txt", ".hidden"]);
+    }
+
+    #[test]
+    fn keeps_selection() {
+        let dir = TempDir::new("selection");
+        let mut state = FileExplorerState::new(&dir.0).unwrap();
+        state.select_last();
+        assert_eq!(state.selected().map(FileEntry::name), Some("C.md"));
+        state.select_next();
+        assert_eq!(state.selected().map(FileEntry::name), Some("C.md"));
+
+        state.set_sort(FileSort::Size);
+        assert_eq!(state.selected().map(FileEntry::name), Some("C.md"));
+        state.toggle_hidden();
+        assert_eq!(state.selected().map(FileEntry::name), Some("C.md"));
+
+        fs::remove_file(dir.0.join("C.md")).unwrap();
+        state.refresh().unwrap();
+        assert_eq!(state.selected().map(FileEntry::name), Some("a.txt"));
+    }
+
+    #[test]
+    fn navigates_directories() {
+        let dir = TempDir::new("navigation");
+        let mut state = FileExplorerState::new(&dir.0).unwrap();
+        assert_eq!(state.open_selected().unwrap(), None);
+        assert_eq!(state.current_dir(), dir.0.join("b_dir"));
+        assert_eq!(names(&state), ["inner"]);
+        assert_eq!(
+            state.open_selected().unwrap(),
+            Some(dir.0.join("b_dir").join("inner"))
+        );
+
+        state.go_to_parent().unwrap();
+        assert_eq!(state.current_dir(), dir.0);
+        assert_eq!(state.selected().map(FileEntry::name), Some("b_dir"));
+
+        state.select_next();
+        assert_eq!(state.open_selected().unwrap(), Some(dir.0.join("a.txt")));
+        assert!(state.set_current_dir(dir.0.join("missing")).is_err());
+        assert_eq!(state.current_dir(), dir.0);
+    }
+
+    #[test]
+    fn render() {
+        let dir = TempDir::new("render");
+        let mut state = FileExplorerState::new(&dir.0).unwrap();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 3));
+        FileExplorer::new().render(buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(["b_dir/      ", "a.txt     5B", "C.md    2.0K"]);
+        expected.set_style(Rect::new(0, 0, 6, 1), Style::new().blue().bold());
+        expected.set_style(Rect::new(0, 0, 12, 1), Style::new().reversed());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_scrolls_to_selected_entry() {
+        let dir = TempDir::new("scroll");
+        let mut state = FileExplorerState::new(&dir.0).unwrap();
+        state.select_last();
+        let explorer = FileExplorer::new()
+            .show_size(false)
+            .highlight_style(Style::new())
+            .directory_style(Style::new());
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 2));
+        explorer.render(buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["a.txt   ", "C.md    "]));
+    }
+}
@@ -0,0 +1,741 @@
+//! A [`Form`] of labeled fields, its [`FormState`] and the [`FocusState`] that moves between the
+//! fields.
+//!
+//! A form lays out its [`Field`]s vertically, with their labels in a column on the left and their
+//! values on the right. The available fields are:
+//!
+//! - [`Field::input`]: a single-line text input, edited like an [`Input`]
+//! - [`Field::checkbox`]: a checkbox, toggled with <kbd>Space</kbd>
+//! - [`Field::select`]: a choice between options, cycled with <kbd>←</kbd> and <kbd>→</kbd>
+//!
+//! The values of the fields are held by the [`FormState`], which also handles the keys of the
+//! focused field and moves the focus with <kbd>Tab</kbd> and <kbd>Shift+Tab</kbd>. Validation is
+//! left to the application, which can display a message below each field with [`Field::error`].
+//!
+//! [`Input`]: crate::widgets::Input
+mod focus;
+
+pub use self::focus::FocusState;
+use crate::{
+    backend::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    layout::Position,
+    prelude::*,
+    symbols::toggle,
+    widgets::{
+        Block, Checkbox, CheckboxState, Input, InputState, Select, SelectState, StatefulWidgetRef,
+    },
+};
+
+/// The kind of a [`Field`], with its settings
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+enum FieldKind<'a> {
+    Input {
+        placeholder: Option<Span<'a>>,
+        mask: Option<char>,
+    },
+    Checkbox,
+    Select(Vec<Line<'a>>),
+}
+
+/// A labeled field of a [`Form`]
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::widgets::form::Field;
+///
+/// let fields = [
+///     Field::input("Name").placeholder("Jane Doe"),
+///     Field::input("Password").mask('*').error("too short"),
+///     Field::checkbox("Remember me"),
+///     Field::select("Theme", ["Light", "Dark"]),
+/// ];
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Field<'a> {
+    label: Line<'a>,
+    kind: FieldKind<'a>,
+    error: Option<Line<'a>>,
+}
+
+impl<'a> Field<'a> {
+    /// Creates a text input field, whose value is a [`FieldValue::Input`]
+    pub fn input<T: Into<Line<'a>>>(label: T) -> Self {
+        Self::new(
+            label,
+            FieldKind::Input {
+                placeholder: None,
+                mask: None,
+            },
+        )
+    }
+
+    /// Creates a checkbox field, whose value is a [`FieldValue::Checkbox`]
+    pub fn checkbox<T: Into<Line<'a>>>(label: T) -> Self {
+        Self::new(label, FieldKind::Checkbox)
+    }
+
+    /// Creates a field to choose one of the given options, whose value is a
+    /// [`FieldValue::Select`]
+    pub fn select<T, I>(label: T, options: I) -> Self
+    where
+        T: Into<Line<'a>>,
+        I: IntoIterator,
+        I::Item: Into<Line<'a>>,
+    {
+        let options = options.into_iter().map(Into::into).collect();
+        Self::new(label, FieldKind::Select(options))
+    }
+
+    /// Sets the text displayed when the value of an input field is empty
+    ///
+    /// This has no effect on other fields.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn placeholder<T: Into<Span<'a>>>(mut self, placeholder: T) -> Self {
+        if let FieldKind::Input {
+            placeholder: field_placeholder,
+            ..
+        } = &mut self.kind
+        {
+            *field_placeholder = Some(placeholder.into());
+        }
+        self
+    }
+
+    /// Masks the value of an input field with the given character, e.g. for passwords
+    ///
+    /// This has no effect on other fields.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn mask(mut self, mask: char) -> Self {
+        if let FieldKind::Input {
+            mask: field_mask, ..
+        } = &mut self.kind
+        {
+            *field_mask = Some(mask);
+        }
+        self
+    }
+
+    /// Sets a validation message displayed below the value of the field
+    ///
+    /// The message takes a row of the form, and is styled with [`Form::error_style`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn error<T: Into<Line<'a>>>(mut self, error: T) -> Self {
+        self.error = Some(error.into());
+        self
+    }
+
+    fn new<T: Into<Line<'a>>>(label: T, kind: FieldKind<'a>) -> Self {
+        Self {
+            label: label.into(),
+            kind,
+            error: None,
+        }
+    }
+
+    /// The default value of the field
+    fn default_value(&self) -> FieldValue {
+        match self.kind {
+            FieldKind::Input { .. } => FieldValue::Input(InputState::default()),
+            FieldKind::Checkbox => FieldValue::Checkbox(false),
+            FieldKind::Select(_) => FieldValue::Select(0),
+        }
+    }
+
+    /// Whether the value has the type of the values of the field
+    const fn accepts(&self, value: &FieldValue) -> bool {
+        matches!(
+            (&self.kind, value),
+            (FieldKind::Input { .. }, FieldValue::Input(_))
+                | (FieldKind::Checkbox, FieldValue::Checkbox(_))
+                | (FieldKind::Select(_), FieldValue::Select(_))
+        )
+    }
+}
+
+/// The value of a [`Field`], held by a [`FormState`]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum FieldValue {
+    /// The text of an input field, with its cursor and selection
+    Input(InputState),
+    /// Whether a checkbox field is checked
+    Checkbox(bool),
+    /// The index of the chosen option of a select field
+    Select(usize),
+}
+
+impl FieldValue {
+    /// Creates the value of an input field with the given text
+    pub fn input<T: Into<String>>(value: T) -> Self {
+        Self::Input(InputState::new(value))
+    }
+}
+
+/// State of the [`Form`] widget
+///
+/// The state holds the values of the fields and the [`FocusState`] of the form. The values are
+/// matched with the fields by index when the form is rendered: missing values, and values that do
+/// not match the type of their field, are replaced with the default value of the field (an empty
+/// text, an unchecked checkbox or the first option).
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     backend::event::KeyEvent,
+///     widgets::form::{FieldValue, FormState},
+/// };
+///
+/// fn handle_key(key: KeyEvent, state: &mut FormState) {
+///     if !state.handle_key(key) {
+///         // the key was not used by the form, e.g. Enter to submit it
+///     }
+/// }
+///
+/// let mut state = FormState::new([FieldValue::input("Jane"), FieldValue::Checkbox(true)]);
+/// assert_eq!(state.text(0), Some("Jane"));
+/// assert_eq!(state.checked(1), Some(true));
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct FormState {
+    focus: FocusState,
+    values: Vec<FieldValue>,
+    /// The number of options of each field during the last render, 0 for fields that are not
+    /// select fields
+    option_counts: Vec<usize>,
+}
+
+impl FormState {
+    /// Creates a new state with the given values, focusing the first field
+    pub fn new<I>(values: I) -> Self
+    where
+        I: IntoIterator<Item = FieldValue>,
+    {
+        let values: Vec<FieldValue> = values.into_iter().collect();
+        let mut focus = FocusState::new(values.len());
+        focus.focus(Some(0));
+        Self {
+            focus,
+            values,
+            option_counts: Vec::new(),
+        }
+    }
+
+    /// The focus of the form
+    pub const fn focus(&self) -> &FocusState {
+        &self.focus
+    }
+
+    /// The focus of the form, e.g. to set a custom traversal order
+    ///
+    /// The number of widgets of the focus state is set to the number of fields when the form is
+    /// rendered.
+    pub fn focus_mut(&mut self) -> &mut FocusState {
+        &mut self.focus
+    }
+
+    /// The value of the field at the given index
+    pub fn value(&self, index: usize) -> Option<&FieldValue> {
+        self.values.get(index)
+    }
+
+    /// The value of the field at the given index, e.g. to edit it
+    pub fn value_mut(&mut self, index: usize) -> Option<&mut FieldValue> {
+        self.values.get_mut(index)
+    }
+
+    /// The text of the input field at the given index
+    pub fn text(&self, index: usize) -> Option<&str> {
+        match self.values.get(index) {
+            Some(FieldValue::Input(input)) => Some(input.value()),
+            _ => None,
+        }
+    }
+
+    /// Whether the checkbox field at the given index is checked
+    pub fn checked(&self, index: usize) -> Option<bool> {
+        match self.values.get(index) {
+            Some(FieldValue::Checkbox(checked)) => Some(*checked),
+            _ => None,
+        }
+    }
+
+    /// The index of the chosen option of the select field at the given index
+    pub fn selected_option(&self, index: usize) -> Option<usize> {
+        match self.values.get(index) {
+            Some(FieldValue::Select(selected)) => Some(*selected),
+            _ => None,
+        }
+    }
+
+    /// The position of the cursor of the focused input field during the last render
+    ///
+    /// Pass it to [`Frame::set_cursor`] to display the terminal cursor in the field.
+    ///
+    /// [`Frame::set_cursor`]: crate::terminal::Frame::set_cursor
+    pub fn cursor_position(&self) -> Option<Position> {
+        match self.values.get(self.focus.focused()?) {
+            Some(FieldValue::Input(input)) => input.cursor_position(),
+            _ => None,
+        }
+    }
+
+    /// Handles a key, returning whether it was used by the form
+    ///
+    /// <kbd>Tab</kbd> and <kbd>Shift+Tab</kbd> move the focus, and the other keys are handled by
+    /// the focused field:
+    /// - input fields insert characters, delete them with <kbd>Backspace</kbd> and
+    ///   <kbd>Delete</kbd>, and move the cursor with <kbd>←</kbd>, <kbd>→</kbd>, <kbd>Home</kbd>
+    ///   and <kbd>End</kbd>
+    /// - checkbox fields are toggled with <kbd>Space</kbd>
+    /// - select fields choose the previous or next option with <kbd>←</kbd> and <kbd>→</kbd>
+    ///
+    /// The number of options of select fields is known after the first render.
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        if self.focus.handle_key(key) {
+            return true;
+        }
+        if key.kind == KeyEventKind::Release {
+            return false;
+        }
+        let Some(index) = self.focus.focused() else {
+            return false;
+        };
+        let options = self.option_counts.get(index).copied().unwrap_or_default();
+        match self.values.get_mut(index) {
+            Some(FieldValue::Input(input)) => handle_input_key(input, key),
+            Some(FieldValue::Checkbox(checked)) if key.code == KeyCode::Char(' ') => {
+                *checked = !*checked;
+                true
+            }
+            Some(FieldValue::Select(selected)) if options > 0 => match key.code {
+                KeyCode::Left => {
+                    *selected = (*selected + options - 1) % options;
+                    true
+                }
+                KeyCode::Right => {
+                    *selected = (*selected + 1) % options;
+                    true
+                }
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// Matches the values with the fields
+    fn sync(&mut self, fields: &[Field]) {
+        self.values.truncate(fields.len());
+        for (index, field) in fields.iter().enumerate() {
+            match self.values.get_mut(index) {
+                Some(value) if field.accepts(value) => {}
+                Some(value) => *value = field.default_value(),
+                None => self.values.push(field.default_value()),
+            }
+        }
+        self.option_counts = fields
+            .iter()
+            .map(|field| match &field.kind {
+                FieldKind::Select(options) => options.len(),
+                _ => 0,
+            })
+            .collect();
+        for (value, &options) in self.values.iter_mut().zip(&self.option_counts) {
+            if let FieldValue::Select(selected) = value {
+                *selected = (*selected).min(options.saturating_sub(1));
+            }
+        }
+        self.focus.set_len(fields.len());
+    }
+}
+
+/// Edits an input with a key, returning whether the key was used
+fn handle_input_key(input: &mut InputState, key: KeyEvent) -> bool {
+    let shortcut = key
+        .modifiers
+        .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT);
+    match key.code {
+        KeyCode::Char(c) if !shortcut => input.insert_char(c),
+        KeyCode::Backspace => input.delete_backward(),
+        KeyCode::Delete => input.delete_forward(),
+        KeyCode::Left => input.move_left(),
+        KeyCode::Right => input.move_right(),
+        KeyCode::Home => input.move_home(),
+        KeyCode::End => input.move_end(),
+        _ => return false,
+    }
+    true
+}
+
+/// A widget that lays out labeled [`Field`]s vertically
+///
+/// The labels are displayed in a column as wide as the widest label, followed by the values of
+/// the fields: input fields are rendered like an [`Input`], checkboxes like a [`Checkbox`] with
+/// [`Form::checkbox_symbols`] and select fields like a closed [`Select`]. The label of the focused
+/// field is styled with [`Form::focused_label_style`], and the validation message of a field is
+/// displayed below its value.
+///
+/// The values of the fields are held by a [`FormState`]. Fields that do not fit in the area are
+/// not rendered.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     prelude::*,
+///     widgets::{form::*, Block},
+/// };
+///
+/// # fn ui(frame: &mut Frame, state: &mut FormState) {
+/// # let area = Rect::default();
+/// let name_error = state
+///     .text(0)
+///     .filter(|name| name.is_empty())
+///     .map(|_| "required");
+/// let mut name = Field::input("Name").placeholder("Jane Doe");
+/// if let Some(error) = name_error {
+///     name = name.error(error);
+/// }
+/// let form = Form::new([
+///     name,
+///     Field::checkbox("Subscribe"),
+///     Field::select("Plan", ["Free", "Pro"]),
+/// ])
+/// .block(Block::bordered().title("Sign up"));
+/// frame.render_stateful_widget(form, area, state);
+/// if let Some(position) = state.cursor_position() {
+///     frame.set_cursor(position.x, position.y);
+/// }
+/// # }
+/// ```
+///
+/// [`Input`]: crate::widgets::Input
+/// [`Checkbox`]: crate::widgets::Checkbox
+/// [`Select`]: crate::widgets::Select
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Form<'a> {
+    fields: Vec<Field<'a>>,
+    block: Option<Block<'a>>,
+    style: Style,
+    label_style: Style,
+    focused_label_style: Style,
+    input_style: Style,
+    error_style: Style,
+    checkbox_symbols: toggle::Set,
+    spacing: u16,
+}
+
+impl Default for Form<'_> {
+    fn default() -> Self {
+        Self::new([])
+    }
+}
+
+impl<'a> Form<'a> {
+    /// Creates a new form with the given fields
+    pub fn new<I>(fields: I) -> Self
+    where
+        I: IntoIterator<Item = Field<'a>>,
+    {
+        Self {
+            fields: fields.into_iter().collect(),
+            block: None,
+            style: Style::new(),
+            label_style: Style::new(),
+            focused_label_style: Style::new().add_modifier(Modifier::BOLD),
+            input_style: Style::new().add_modifier(Modifier::UNDERLINED),
+            error_style: Style::new().fg(Color::Red),
+            checkbox_symbols: toggle::CHECKBOX,
+            spacing: 0,
+        }
+    }
+
+    /// Wraps the form in a [`Block`]
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the style of the form
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the labels
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn label_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.label_style = style.into();
+        self
+    }
+
+    /// Sets the style of the label of the focused field, which is bold by default
+    ///
+    /// The style is patched on top of [`Form::label_style`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn focused_label_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.focused_label_style = style.into();
+        self
+    }
+
+    /// Sets the style of the values of the input fields, which are underlined by default to show
+    /// their width
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn input_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.input_style = style.into();
+        self
+    }
+
+    /// Sets the style of the validation messages, which are red by default
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn error_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.error_style = style.into();
+        self
+    }
+
+    /// Sets the symbols of the checkbox fields, which default to [`toggle::CHECKBOX`]
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn checkbox_symbols(mut self, symbols: toggle::Set) -> Self {
+        self.checkbox_symbols = symbols;
+        self
+    }
+
+    /// Sets the number of empty rows between the fields, which defaults to 0
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    fn render_value(&self, field: &Field, value: &mut FieldValue, area: Rect, buf: &mut Buffer) {
+        match (&field.kind, value) {
+            (FieldKind::Input { placeholder, mask }, FieldValue::Input(input)) => {
+                let mut widget = Input::new().style(self.input_style);
+                if let Some(placeholder) = placeholder {
+                    widget = widget.placeholder(placeholder.clone());
+                }
+                if let Some(mask) = *mask {
+                    widget = widget.mask(mask);
+                }
+                widget.render(area, buf, input);
+            }
+            (FieldKind::Checkbox, FieldValue::Checkbox(checked)) => {
+                let mut state = CheckboxState::new(*checked);
+                Checkbox::new("")
+                    .symbols(self.checkbox_symbols.clone())
+                    .render(area, buf, &mut state);
+            }
+            (FieldKind::Select(options), FieldValue::Select(selected)) => {
+                let mut state = SelectState::new(Some(*selected));
+                Select::new(options.iter().cloned()).render(area, buf, &mut state);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl StatefulWidget for Form<'_> {
+    type State = FormState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        self.render_ref(area, buf, state);
+    }
+}
+
+// Note: remove this when StatefulWidgetRef is stabilized and replace with the blanket impl
+impl StatefulWidget for &Form<'_> {
+    type State = FormState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        self.render_ref(area, buf, state);
+    }
+}
+
+impl StatefulWidgetRef for Form<'_> {
+    type State = FormState;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        self.block.render_ref(area, buf);
+        let area = self.block.inner_if_some(area).intersection(buf.area);
+        state.sync(&self.fields);
+        if area.is_empty() {
+            return;
+        }
+
+        let label_width = self
+            .fields
+            .iter()
+            .map(|field| field.label.width())
+            .max()
+            .unwrap_or_default();
+        // keep a space between the labels and the values
+        let label_width = u16::try_from(label_width + 1)
+            .unwrap_or(u16::MAX)
+            .min(area.width);
+        let value_x = area.x + label_width;
+        let value_width = area.width - label_width;
+
+        let mut y = area.top();
+        for (index, (field, value)) in self.fields.iter().zip(&mut state.values).enumerate() {
+            if y >= area.bottom() {
+                break;
+            }
+            let label_style = if state.focus.is_focused(index) {
+                self.label_style.patch(self.focused_label_style)
+            } else {
+                self.label_style
+            };
+            buf.set_style(Rect::new(area.x, y, label_width, 1), label_style);
+            buf.set_line(area.x, y, &field.label, label_width);
+            let value_area = Rect::new(value_x, y, value_width, 1);
+            self.render_value(field, value, value_area, buf);
+            y += 1;
+
+            if let Some(error) = field.error.as_ref().filter(|_| y < area.bottom()) {
+                let error_area = Rect::new(value_x, y, value_width, 1);
+                buf.set_style(error_area, self.error_style);
+                buf.set_line(value_x, y, error, value_width);
+                y += 1;
+            }
+            y = y.saturating_add(self.spacing);
+        }
+    }
+}
+
+impl Styled for Form<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn fields<'a>() -> Vec<Field<'a>> {
+        vec![
+            Field::input("Name").error("required"),
+            Field::checkbox("Subscribe"),
+            Field::select("Plan", ["Free", "Pro"]),
+        ]
+    }
+
+    /// A form without the default styles, to test the layout of the fields
+    fn plain_form(fields: Vec<Field<'_>>) -> Form<'_> {
+        Form::new(fields)
+            .focused_label_style(Style::new())
+            .input_style(Style::new())
+            .error_style(Style::new())
+    }
+
+    fn render(form: &Form, state: &mut FormState, width: u16, height: u16) -> Buffer {
+        let mut buf = Buffer::empty(Rect::new(0, 0, width, height));
+        form.render(buf.area, &mut buf, state);
+        buf
+    }
+
+    #[test]
+    fn render_fields() {
+        let mut state = FormState::new([FieldValue::input("Al"), FieldValue::Checkbox(true)]);
+        let buf = render(&plain_form(fields()), &mut state, 20, 4);
+        assert_eq!(
+            buf,
+            Buffer::with_lines([
+                "Name      Al        ",
+                "          required  ",
+                "Subscribe [x]       ",
+                "Plan      Free     ▾",
+            ])
+        );
+        assert_eq!(state.selected_option(2), Some(0));
+        assert_eq!(state.cursor_position(), Some(Position::new(12, 0)));
+    }
+
+    #[test]
+    fn render_checkbox_symbols() {
+        let form = plain_form(vec![Field::checkbox("a")]).checkbox_symbols(toggle::BALLOT);
+        let mut state = FormState::new([FieldValue::Checkbox(true)]);
+        let buf = render(&form, &mut state, 4, 1);
+        assert_eq!(buf, Buffer::with_lines(["a ☑ "]));
+    }
+
+    #[test]
+    fn render_spacing_and_overflow() {
+        let form = plain_form(vec![Field::checkbox("a"), Field::checkbox("b")]).spacing(1);
+        let buf = render(&form, &mut FormState::default(), 5, 2);
+        assert_eq!(buf, Buffer::with_lines(["a [ ]", "     "]));
+    }
+
+    #[test]
+    fn render_styles() {
+        let form = Form::new([Field::input("A").error("e"), Field::checkbox("B")]);
+        let mut state = FormState::default();
+        state.focus_mut().set_len(2);
+        state.focus_mut().focus(Some(0));
+        let buf = render(&form, &mut state, 5, 3);
+        let mut expected = Buffer::with_lines(["A    ", "  e  ", "B [ ]"]);
+        expected.set_style(Rect::new(0, 0, 2, 1), Style::new().bold());
+        expected.set_style(Rect::new(2, 0, 3, 1), Style::new().underlined());
+        expected.set_style(Rect::new(2, 1, 3, 1), Style::new().red());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn sync_replaces_mismatched_values() {
+        let mut state = FormState::new([
+            FieldValue::Checkbox(true),
+            FieldValue::Checkbox(true),
+            FieldValue::Select(5),
+            FieldValue::Checkbox(true),
+        ]);
+        render(&plain_form(fields()), &mut state, 20, 4);
+        assert_eq!(state.text(0), Some(""));
+        assert_eq!(state.checked(1), Some(true));
+        assert_eq!(state.selected_option(2), Some(1));
+        assert_eq!(state.value(3), None);
+    }
+
+    #[test]
+    fn handle_keys() {
+        let mut state = FormState::default();
+        render(&plain_form(fields()), &mut state, 20, 4);
+        assert_eq!(state.focus().focused(), None);
+        assert!(!state.handle_key(KeyCode::Char('a').into()));
+
+        assert!(state.handle_key(KeyCode::Tab.into()));
+        for c in "Bob".chars() {
+            assert!(state.handle_key(KeyCode::Char(c).into()));
+        }
+        assert!(state.handle_key(KeyCode::Backspace.into()));
+        assert!(!state.handle_key(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL)));
+        assert_eq!(state.text(0), Some("Bo"));
+
+        assert!(state.handle_key(KeyCode::Tab.into()));
+        assert!(state.handle_key(KeyCode::Char(' ').into()));
+        assert_eq!(state.checked(1), Some(true));
+        assert!(!state.handle_key(KeyCode::Enter.into()));
+
+        assert!(state.handle_key(KeyCode::Tab.into()));
+        assert!(state.handle_key(KeyCode::Left.into()));
+        assert_eq!(state.selected_option(2), Some(1));
+        assert!(state.handle_key(KeyCode::Right.into()));
+        assert_eq!(state.selected_option(2), Some(0));
+
+        assert!(state.handle_key(KeyCode::BackTab.into()));
+        assert_eq!(state.focus().focused(), Some(1));
+    }
+}
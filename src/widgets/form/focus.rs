@@ -0,0 +1,217 @@
+use crate::backend::event::{KeyCode, KeyEvent, KeyEventKind};
+
+/// The focus of a group of widgets, moved with <kbd>Tab</kbd> and <kbd>Shift+Tab</kbd>
+///
+/// The focus cycles through the indices `0..len` in order, or through a custom traversal order
+/// set with [`FocusState::with_order`], which can also leave out indices that should not be
+/// focused. A [`FormState`](super::FormState) uses a focus state for its fields, but it can be
+/// used for any group of widgets.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{
+///     backend::event::{KeyCode, KeyEvent},
+///     widgets::form::FocusState,
+/// };
+///
+/// // focus the third widget before the first one, and skip the second one
+/// let mut focus = FocusState::new(3).with_order([2, 0]);
+/// assert!(focus.handle_key(KeyEvent::from(KeyCode::Tab)));
+/// assert_eq!(focus.focused(), Some(2));
+/// focus.focus_next();
+/// assert_eq!(focus.focused(), Some(0));
+/// focus.focus_next();
+/// assert_eq!(focus.focused(), Some(2));
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct FocusState {
+    focused: Option<usize>,
+    len: usize,
+    order: Vec<usize>,
+}
+
+impl FocusState {
+    /// Creates a new focus state for the given number of widgets, with no widget focused
+    pub const fn new(len: usize) -> Self {
+        Self {
+            focused: None,
+            len,
+            order: Vec::new(),
+        }
+    }
+
+    /// Sets the traversal order of the focus
+    ///
+    /// Only the given indices can be focused by [`FocusState::focus_next`] and
+    /// [`FocusState::focus_previous`]. Indices that are not less than the number of widgets are
+    /// skipped.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_order<I>(mut self, order: I) -> Self
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        self.order = order.into_iter().collect();
+        self
+    }
+
+    /// The number of widgets
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether there are no widgets to focus
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Sets the number of widgets, removing the focus if the focused widget no longer exists
+    pub fn set_len(&mut self, len: usize) {
+        self.len = len;
+        self.focused = self.focused.filter(|&focused| focused < len);
+    }
+
+    /// The index of the focused widget, if any
+    pub const fn focused(&self) -> Option<usize> {
+        self.focused
+    }
+
+    /// Whether the widget at the given index is focused
+    pub fn is_focused(&self, index: usize) -> bool {
+        self.focused == Some(index)
+    }
+
+    /// Focuses the widget at the given index, or removes the focus with `None`
+    ///
+    /// The index is not required to be part of the traversal order. Indices that are not less
+    /// than the number of widgets remove the focus.
+    pub fn focus(&mut self, index: Option<usize>) {
+        self.focused = index.filter(|&index| index < self.len);
+    }
+
+    /// Focuses the next widget of the traversal order, or the first one after the last one or
+    /// when no widget is focused
+    pub fn focus_next(&mut self) {
+        let order = self.order();
+        let next = match self.position(&order) {
+            Some(position) => order.get(position + 1).or_else(|| order.first()),
+            None => order.first(),
+        };
+        self.focused = next.copied();
+    }
+
+    /// Focuses the previous widget of the traversal order, or the last one before the first one
+    /// or when no widget is focused
+    pub fn focus_previous(&mut self) {
+        let order = self.order();
+        let previous = match self.position(&order) {
+            Some(position) => position
+                .checked_sub(1)
+                .and_then(|position| order.get(position))
+                .or_else(|| order.last()),
+            None => order.last(),
+        };
+        self.focused = previous.copied();
+    }
+
+    /// Moves the focus with <kbd>Tab</kbd> and <kbd>Shift+Tab</kbd> (reported as
+    /// [`KeyCode::BackTab`]), returning whether the key was handled
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        if key.kind == KeyEventKind::Release {
+            return false;
+        }
+        match key.code {
+            KeyCode::Tab => self.focus_next(),
+            KeyCode::BackTab => self.focus_previous(),
+            _ => return false,
+        }
+        true
+    }
+
+    /// The indices that can be focused, in order
+    fn order(&self) -> Vec<usize> {
+        if self.order.is_empty() {
+            (0..self.len).collect()
+        } else {
+            self.order
+                .iter()
+                .copied()
+                .filter(|&index| index < self.len)
+                .collect()
+        }
+    }
+
+    /// The position of the focused widget in the traversal order
+    fn position(&self, order: &[usize]) -> Option<usize> {
+        let focused = self.focused?;
+        order.iter().position(|&index| index == focused)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+    use crate::backend::event::KeyModifiers;
+
+    #[test]
+    fn cycles_in_order() {
+        let mut focus = FocusState::new(3);
+        assert_eq!(focus.focused(), None);
+        focus.focus_next();
+        assert_eq!(focus.focused(), Some(0));
+        focus.focus_next();
+        focus.focus_next();
+        assert_eq!(focus.focused(), Some(2));
+        focus.focus_next();
+        assert_eq!(focus.focused(), Some(0));
+        focus.focus_previous();
+        assert_eq!(focus.focused(), Some(2));
+    }
+
+    #[test]
+    fn custom_order() {
+        let mut focus = FocusState::new(3).with_order([2, 5, 0]);
+        focus.focus_previous();
+        assert_eq!(focus.focused(), Some(0));
+        focus.focus_previous();
+        assert_eq!(focus.focused(), Some(2));
+
+        // a focused widget outside of the order restarts the traversal
+        focus.focus(Some(1));
+        focus.focus_next();
+        assert_eq!(focus.focused(), Some(2));
+    }
+
+    #[test]
+    fn set_len() {
+        let mut focus = FocusState::new(3);
+        focus.focus(Some(2));
+        focus.set_len(2);
+        assert_eq!(focus.focused(), None);
+        focus.focus(Some(5));
+        assert_eq!(focus.focused(), None);
+
+        let mut empty = FocusState::default();
+        empty.focus_next();
+        assert_eq!(empty.focused(), None);
+    }
+
+    #[test]
+    fn handle_key() {
+        let mut focus = FocusState::new(2);
+        assert!(focus.handle_key(KeyCode::Tab.into()));
+        assert_eq!(focus.focused(), Some(0));
+        assert!(focus.handle_key(KeyEvent::new(KeyCode::BackTab, KeyModifiers::SHIFT)));
+        assert_eq!(focus.focused(), Some(1));
+        assert!(!focus.handle_key(KeyCode::Enter.into()));
+
+        let release = KeyEvent {
+            kind: KeyEventKind::Release,
+            ..KeyCode::Tab.into()
+        };
+        assert!(!focus.handle_key(release));
+        assert_eq!(focus.focused(), Some(1));
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{prelude::*, widgets::Block};
+use crate::{prelude::*, style::Gradient, widgets::Block};
 
 /// A widget to display a progress bar.
 ///
@@ -9,7 +9,9 @@ use crate::{prelude::*, widgets::Block};
 /// The associated label is always centered horizontally and vertically. If not set with
 /// [`Gauge::label`], the label is the percentage of the bar filled.
 ///
-/// You might want to have a higher precision bar using [`Gauge::use_unicode`].
+/// You might want to have a higher precision bar using [`Gauge::use_unicode`]. The symbols used to
+/// draw the bar can be changed with [`Gauge::block_set`], and the color of the bar can follow the
+/// progression with [`Gauge::gradient`].
 ///
 /// This can be useful to indicate the progression of a task, like a download.
 ///
@@ -41,6 +43,8 @@ pub struct Gauge<'a> {
     use_unicode: bool,
     style: Style,
     gauge_style: Style,
+    block_set: symbols::block::Set,
+    gradient: Option<Gradient>,
 }
 
 impl<'a> Default for Gauge<'a> {
@@ -52,6 +56,8 @@ impl<'a> Default for Gauge<'a> {
             use_unicode: false,
             style: Style::default(),
             gauge_style: Style::default(),
+            block_set: symbols::block::NINE_LEVELS,
+            gradient: None,
         }
     }
 }
@@ -154,6 +160,53 @@ impl<'a> Gauge<'a> {
         self.use_unicode = unicode;
         self
     }
+
+    /// Sets the symbols used to draw the bar.
+    ///
+    /// The `full` symbol draws the filled part of the bar. The fractional symbols are only used
+    /// for the last cell of the bar when [`Gauge::use_unicode`] is enabled.
+    ///
+    /// Defaults to [`symbols::block::NINE_LEVELS`].
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{prelude::*, widgets::*};
+    ///
+    /// Gauge::default()
+    ///     .block_set(symbols::block::THREE_LEVELS)
+    ///     .use_unicode(true)
+    ///     .percent(42);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn block_set(mut self, block_set: symbols::block::Set) -> Self {
+        self.block_set = block_set;
+        self
+    }
+
+    /// Sets a [`Gradient`] giving the color of the bar for the ratio of the gauge.
+    ///
+    /// The color of the gradient at the ratio (see [`Gradient::color_at`]) replaces the foreground
+    /// color of [`Gauge::gauge_style`] for the filled part of the bar, which makes it possible to
+    /// change the color as the gauge fills up. The direction of the gradient is ignored.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ratatui::{prelude::*, style::Gradient, widgets::*};
+    ///
+    /// let traffic_light = Gradient::new([
+    ///     Color::Rgb(0, 200, 0),
+    ///     Color::Rgb(200, 200, 0),
+    ///     Color::Rgb(200, 0, 0),
+    /// ]);
+    /// Gauge::default().gradient(traffic_light).percent(90);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn gradient(mut self, gradient: Gradient) -> Self {
+        self.gradient = Some(gradient);
+        self
+    }
 }
 
 impl Widget for Gauge<'_> {
@@ -187,6 +240,12 @@ impl Gauge<'_> {
         let label_col = gauge_area.left() + (gauge_area.width - clamped_label_width) / 2;
         let label_row = gauge_area.top() + gauge_area.height / 2;
 
+        let bar_color = self.gradient.as_ref().map_or_else(
+            || self.gauge_style.fg.unwrap_or(Color::Reset),
+            |gradient| gradient.color_at(self.ratio),
+        );
+        let background = self.gauge_style.bg.unwrap_or(Color::Reset);
+
         // the gauge will be filled proportionally to the ratio
         let filled_width = f64::from(gauge_area.width) * self.ratio;
         let end = if self.use_unicode {
@@ -202,36 +261,37 @@ impl Gauge<'_> {
                 // covered by the label. Note that the background and foreground colors are swapped
                 // for the label part, otherwise the gauge will be inverted
                 if x < label_col || x > label_col + clamped_label_width || y != label_row {
-                    cell.set_symbol(symbols::block::FULL)
-                        .set_fg(self.gauge_style.fg.unwrap_or(Color::Reset))
-                        .set_bg(self.gauge_style.bg.unwrap_or(Color::Reset));
+                    cell.set_symbol(self.block_set.full)
+                        .set_fg(bar_color)
+                        .set_bg(background);
                 } else {
-                    cell.set_symbol(" ")
-                        .set_fg(self.gauge_style.bg.unwrap_or(Color::Reset))
-                        .set_bg(self.gauge_style.fg.unwrap_or(Color::Reset));
+                    cell.set_symbol(" ").set_fg(background).set_bg(bar_color);
                 }
             }
             if self.use_unicode && self.ratio < 1.0 {
-                buf.get_mut(end, y)
-                    .set_symbol(get_unicode_block(filled_width % 1.0));
+                let cell = buf.get_mut(end, y);
+                cell.set_symbol(self.unicode_block(filled_width % 1.0));
+                if self.gradient.is_some() {
+                    cell.set_fg(bar_color);
+                }
             }
         }
         // render the label
         buf.set_span(label_col, label_row, label, clamped_label_width);
     }
-}
 
-fn get_unicode_block<'a>(frac: f64) -> &'a str {
-    match (frac * 8.0).round() as u16 {
-        1 => symbols::block::ONE_EIGHTH,
-        2 => symbols::block::ONE_QUARTER,
-        3 => symbols::block::THREE_EIGHTHS,
-        4 => symbols::block::HALF,
-        5 => symbols::block::FIVE_EIGHTHS,
-        6 => symbols::block::THREE_QUARTERS,
-        7 => symbols::block::SEVEN_EIGHTHS,
-        8 => symbols::block::FULL,
-        _ => " ",
+    fn unicode_block(&self, frac: f64) -> &'static str {
+        match (frac * 8.0).round() as u16 {
+            1 => self.block_set.one_eighth,
+            2 => self.block_set.one_quarter,
+            3 => self.block_set.three_eighths,
+            4 => self.block_set.half,
+            5 => self.block_set.five_eighths,
+            6 => self.block_set.three_quarters,
+            7 => self.block_set.seven_eighths,
+            8 => self.block_set.full,
+            _ => self.block_set.empty,
+        }
     }
 }
 
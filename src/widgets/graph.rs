@@ -0,0 +1,402 @@
+use std::collections::VecDeque;
+
+use crate::{
+    prelude::*,
+    style::Gradient,
+    widgets::{Block, StatefulWidgetRef},
+};
+
+/// State of the [`Graph`] widget, which holds the latest values of a time series
+///
+/// The state is a ring buffer with a fixed capacity: when a value is pushed into a full state, the
+/// oldest value is dropped. The capacity is usually the number of values that fit in the widest
+/// graph, i.e. twice its width in cells, but can be larger so that the
+/// [automatic scale](Graph::max) of the graph takes a longer history into account.
+///
+/// # Example
+///
+/// ```rust
+/// # use ratatui::widgets::*;
+/// let mut state = GraphState::new(3);
+/// state.extend([1.0, 2.0, 3.0, 4.0]);
+/// assert_eq!(state.values().collect::<Vec<_>>(), [2.0, 3.0, 4.0]);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GraphState {
+    values: VecDeque<f64>,
+    capacity: usize,
+}
+
+impl GraphState {
+    /// Creates an empty state that holds at most `capacity` values
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            values: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// The maximum number of values held by the state
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Pushes a new value, dropping the oldest value if the state is full
+    pub fn push(&mut self, value: f64) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.values.len() == self.capacity {
+            self.values.pop_front();
+        }
+        self.values.push_back(value);
+    }
+
+    /// The values held by the state, from the oldest to the latest
+    pub fn values(&self) -> impl DoubleEndedIterator<Item = f64> + ExactSizeIterator + '_ {
+        self.values.iter().copied()
+    }
+
+    /// The latest value, if any
+    pub fn last(&self) -> Option<f64> {
+        self.values.back().copied()
+    }
+
+    /// The number of values held by the state
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Whether the state holds no values
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Removes all the values
+    pub fn clear(&mut self) {
+        self.values.clear();
+    }
+}
+
+impl Extend<f64> for GraphState {
+    fn extend<T: IntoIterator<Item = f64>>(&mut self, iter: T) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+/// A widget that plots the latest values of a single metric with Braille patterns, like the
+/// graphs of `btop`
+///
+/// The values are held by a [`GraphState`], which acts as a ring buffer: push a new value into it
+/// on each tick and render the graph with it. Each cell displays two values with a vertical
+/// resolution of four dots, and the latest value is on the right of the graph. Compared to a
+/// [`Sparkline`](crate::widgets::Sparkline), this gives a higher resolution and keeps the history
+/// between frames.
+///
+/// The graph is filled below the values by default, and can be colored with a vertical
+/// [gradient](Graph::gradient). Unless a fixed [maximum](Graph::max) is set, the scale adapts to
+/// the largest value held by the state.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{prelude::*, style::Gradient, widgets::*};
+///
+/// # fn ui(frame: &mut Frame, cpu_usage: f64) {
+/// # let area = Rect::default();
+/// // This should be stored outside of the function in your application state.
+/// let mut state = GraphState::new(200);
+///
+/// state.push(cpu_usage);
+/// let graph = Graph::default()
+///     .block(Block::bordered().title("CPU"))
+///     .max(100.0)
+///     .gradient(Gradient::new([Color::Green, Color::Yellow, Color::Red]));
+/// frame.render_stateful_widget(graph, area, &mut state);
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Graph<'a> {
+    block: Option<Block<'a>>,
+    style: Style,
+    max: Option<f64>,
+    min_max: f64,
+    filled: bool,
+    gradient: Option<Gradient>,
+}
+
+impl Default for Graph<'_> {
+    fn default() -> Self {
+        Self {
+            block: None,
+            style: Style::new(),
+            max: None,
+            min_max: 0.0,
+            filled: true,
+            gradient: None,
+        }
+    }
+}
+
+impl<'a> Graph<'a> {
+    /// Wraps the graph in a [`Block`]
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the style of the graph
+    ///
+    /// The foreground color is used for the dots when no [gradient](Graph::gradient) is set.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the value at the top of the graph
+    ///
+    /// By default, the scale adapts to the largest value held by the state, so a peak keeps the
+    /// scale until it is dropped from the state.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn max(mut self, max: f64) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets the lowest value at the top of the graph when the scale is automatic
+    ///
+    /// This avoids magnifying small variations of a metric that stays low, e.g. a network
+    /// throughput that stays around a few bytes per second.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn min_max(mut self, min_max: f64) -> Self {
+        self.min_max = min_max;
+        self
+    }
+
+    /// Sets whether the area below the values is filled, which is the default
+    ///
+    /// When not filled, only the dot of each value is drawn.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn filled(mut self, filled: bool) -> Self {
+        self.filled = filled;
+        self
+    }
+
+    /// Colors the rows of the graph with the given [`Gradient`], from the bottom to the top
+    ///
+    /// The bottom row is drawn with the first color of the gradient and the top row with the last
+    /// one. The direction of the gradient is ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, style::Gradient, widgets::*};
+    /// let gradient = Gradient::new([Color::Green, Color::Yellow, Color::Red]);
+    /// let graph = Graph::default().gradient(gradient);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn gradient(mut self, gradient: Gradient) -> Self {
+        self.gradient = Some(gradient);
+        self
+    }
+
+    /// The value at the top of the graph for the given state
+    fn scale(&self, state: &GraphState) -> f64 {
+        self.max.unwrap_or_else(|| {
+            state
+                .values()
+                .filter(|value| value.is_finite())
+                .fold(self.min_max, f64::max)
+        })
+    }
+
+    /// The color of the given row, counted from the bottom of a graph of the given height
+    fn row_color(&self, row: u16, height: u16) -> Option<Color> {
+        self.gradient.as_ref().map_or(self.style.fg, |gradient| {
+            Some(gradient.color_at_cell(row.into(), height.into()))
+        })
+    }
+}
+
+impl StatefulWidget for Graph<'_> {
+    type State = GraphState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        self.render_ref(area, buf, state);
+    }
+}
+
+// Note: remove this when StatefulWidgetRef is stabilized and replace with the blanket impl
+impl StatefulWidget for &Graph<'_> {
+    type State = GraphState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        self.render_ref(area, buf, state);
+    }
+}
+
+impl StatefulWidgetRef for Graph<'_> {
+    type State = GraphState;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        self.block.render_ref(area, buf);
+        let area = self.block.inner_if_some(area).intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+        let scale = self.scale(state);
+        if !(scale.is_finite() && scale > 0.0) {
+            return;
+        }
+
+        let width = usize::from(area.width);
+        let dots_height = usize::from(area.height) * 4;
+        let count = state.len().min(width * 2);
+        let mut cells = vec![symbols::braille::BLANK; width * usize::from(area.height)];
+        for (i, value) in state.values().skip(state.len() - count).enumerate() {
+            let x = width * 2 - count + i;
+            let value = if value.is_finite() { value } else { 0.0 };
+            let height = ((value / scale).clamp(0.0, 1.0) * dots_height as f64).round() as usize;
+            let top = dots_height - height;
+            let bottom = if self.filled {
+                dots_height
+            } else {
+                (top + 1).min(dots_height)
+            };
+            for y in top..bottom {
+                cells[y / 4 * width + x / 2] |= symbols::braille::DOTS[y % 4][x % 2];
+            }
+        }
+
+        for (i, &code_point) in cells.iter().enumerate() {
+            if code_point == symbols::braille::BLANK {
+                continue;
+            }
+            let (column, row) = ((i % width) as u16, (i / width) as u16);
+            let symbol = char::from_u32(u32::from(code_point)).unwrap_or(' ');
+            let cell = buf.get_mut(area.x + column, area.y + row);
+            cell.set_char(symbol);
+            if let Some(color) = self.row_color(area.height - 1 - row, area.height) {
+                cell.set_fg(color);
+            }
+        }
+    }
+}
+
+impl Styled for Graph<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn state_drops_oldest_values() {
+        let mut state = GraphState::new(3);
+        assert!(state.is_empty());
+        state.extend([1.0, 2.0, 3.0]);
+        assert_eq!(state.values().collect::<Vec<_>>(), [1.0, 2.0, 3.0]);
+        state.push(4.0);
+        assert_eq!(state.values().collect::<Vec<_>>(), [2.0, 3.0, 4.0]);
+        assert_eq!(state.len(), 3);
+        assert_eq!(state.last(), Some(4.0));
+        state.clear();
+        assert!(state.is_empty());
+    }
+
+    #[test]
+    fn state_without_capacity() {
+        let mut state = GraphState::default();
+        state.push(1.0);
+        assert!(state.is_empty());
+    }
+
+    fn render(graph: &Graph, state: &mut GraphState, width: u16, height: u16) -> Buffer {
+        let mut buf = Buffer::empty(Rect::new(0, 0, width, height));
+        graph.render(buf.area, &mut buf, state);
+        buf
+    }
+
+    #[rstest]
+    #[case::filled(true, ["  ⢀⣿", "⢀⣴⣿⣿"])]
+    #[case::not_filled(false, ["  ⢀⠉", "⢀⠔⠁ "])]
+    fn render_values(#[case] filled: bool, #[case] expected: [&str; 2]) {
+        let mut state = GraphState::new(8);
+        state.extend([0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 8.0, 8.0]);
+        let graph = Graph::default().filled(filled);
+        assert_eq!(
+            render(&graph, &mut state, 4, 2),
+            Buffer::with_lines(expected)
+        );
+    }
+
+    #[test]
+    fn render_latest_values_on_the_right() {
+        let mut state = GraphState::new(10);
+        state.extend([8.0, 8.0, 4.0, 4.0, 4.0, 4.0, 4.0]);
+        let graph = Graph::default();
+        assert_eq!(
+            render(&graph, &mut state, 2, 2),
+            Buffer::with_lines(["  ", "⣿⣿"])
+        );
+    }
+
+    #[test]
+    fn render_fixed_and_minimum_scale() {
+        let mut state = GraphState::new(2);
+        state.extend([1.0, 1.0]);
+        let graph = Graph::default().max(4.0);
+        assert_eq!(render(&graph, &mut state, 1, 1), Buffer::with_lines(["⣀"]));
+        let graph = Graph::default().min_max(2.0);
+        assert_eq!(render(&graph, &mut state, 1, 1), Buffer::with_lines(["⣤"]));
+        let graph = Graph::default();
+        assert_eq!(render(&graph, &mut state, 1, 1), Buffer::with_lines(["⣿"]));
+    }
+
+    #[test]
+    fn render_gradient() {
+        let mut state = GraphState::new(2);
+        state.extend([4.0, 4.0]);
+        let (green, yellow, red) = (
+            Color::Rgb(0, 200, 0),
+            Color::Rgb(200, 200, 0),
+            Color::Rgb(200, 0, 0),
+        );
+        let graph = Graph::default().gradient(Gradient::new([green, yellow, red]));
+        let mut expected = Buffer::with_lines(["⣿", "⣿", "⣿"]);
+        expected.set_style(Rect::new(0, 0, 1, 1), red);
+        expected.set_style(Rect::new(0, 1, 1, 1), yellow);
+        expected.set_style(Rect::new(0, 2, 1, 1), green);
+        assert_eq!(render(&graph, &mut state, 1, 3), expected);
+    }
+
+    #[test]
+    fn render_empty_state() {
+        let mut state = GraphState::new(4);
+        let graph = Graph::default().block(Block::bordered());
+        assert_eq!(
+            render(&graph, &mut state, 4, 3),
+            Buffer::with_lines(["┌──┐", "│  │", "└──┘"])
+        );
+    }
+}
@@ -0,0 +1,512 @@
+use crate::{
+    prelude::*,
+    widgets::{Block, StatefulWidgetRef},
+};
+
+/// Computes the style of a byte, see [`HexView::byte_style`]
+type ByteStyleFn = fn(usize, u8) -> Style;
+
+/// State of the [`HexView`] widget
+///
+/// The state holds the selected byte, which works as a cursor, and the first visible row. The row
+/// offset is updated on render so that the selected byte is visible.
+///
+/// The state also records the number of bytes per row and the number of bytes during the last
+/// render, so that the cursor can be moved by rows and clamped to the data.
+///
+/// # Example
+///
+/// ```rust
+/// # use ratatui::{prelude::*, widgets::*};
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// let data = b"Hello, world!";
+/// let hex_view = HexView::new(data);
+///
+/// // This should be stored outside of the function in your application state.
+/// let mut state = HexViewState::default();
+/// state.select(Some(7));
+///
+/// frame.render_stateful_widget(hex_view, area, &mut state);
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct HexViewState {
+    selected: Option<usize>,
+    offset: usize,
+    /// The number of bytes per row during the last render
+    bytes_per_row: usize,
+    /// The number of bytes during the last render
+    len: usize,
+}
+
+impl HexViewState {
+    /// Creates a new state without selected byte
+    pub const fn new() -> Self {
+        Self {
+            selected: None,
+            offset: 0,
+            bytes_per_row: 0,
+            len: 0,
+        }
+    }
+
+    /// The index of the selected byte, if any
+    pub const fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    /// Selects the byte at the given index, or clears the selection with `None`
+    ///
+    /// The index is clamped to the last byte on the next render.
+    pub fn select(&mut self, index: Option<usize>) {
+        self.selected = index;
+    }
+
+    /// The index of the first visible row
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Selects the next byte, or the first byte if none is selected
+    pub fn select_next(&mut self) {
+        self.move_selection(1, false);
+    }
+
+    /// Selects the previous byte, or the first byte if none is selected
+    pub fn select_previous(&mut self) {
+        self.move_selection(1, true);
+    }
+
+    /// Selects the byte below the selected byte, or the first byte if none is selected
+    pub fn select_next_row(&mut self) {
+        self.move_selection(self.bytes_per_row.max(1), false);
+    }
+
+    /// Selects the byte above the selected byte, or the first byte if none is selected
+    pub fn select_previous_row(&mut self) {
+        self.move_selection(self.bytes_per_row.max(1), true);
+    }
+
+    /// Scrolls up by the given number of rows
+    ///
+    /// The selected byte is kept visible on the next render, so this is mostly useful without
+    /// selection.
+    pub fn scroll_up(&mut self, rows: usize) {
+        self.offset = self.offset.saturating_sub(rows);
+    }
+
+    /// Scrolls down by the given number of rows
+    ///
+    /// The offset is clamped to the last row on the next render.
+    pub fn scroll_down(&mut self, rows: usize) {
+        self.offset = self.offset.saturating_add(rows);
+    }
+
+    fn move_selection(&mut self, distance: usize, backward: bool) {
+        let Some(selected) = self.selected else {
+            self.selected = Some(0);
+            return;
+        };
+        self.selected = Some(if backward {
+            selected.saturating_sub(distance)
+        } else {
+            let next = selected.saturating_add(distance);
+            if self.len > 0 && next >= self.len {
+                selected
+            } else {
+                next
+            }
+        });
+    }
+}
+
+/// A widget that displays bytes as rows of offsets, hexadecimal values and ASCII characters,
+/// like `hexdump -C`
+///
+/// Each row starts with the offset of its first byte, followed by the hexadecimal value of each
+/// byte (with an additional space every 8 bytes), and by the bytes as ASCII characters, where
+/// non-printable bytes are displayed as `.`.
+///
+/// The view scrolls with a [`HexViewState`], which also holds the selected byte. The selected
+/// byte is highlighted in both the hexadecimal and ASCII columns. Each byte can be styled with a
+/// function given to [`HexView::byte_style`], e.g. to highlight zeroes or the bytes of a
+/// structure.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{prelude::*, widgets::*};
+///
+/// # fn ui(frame: &mut Frame, data: &[u8]) {
+/// # let area = Rect::default();
+/// let hex_view = HexView::new(data)
+///     .block(Block::bordered().title("Memory"))
+///     .byte_style(|_, byte| match byte {
+///         0 => Style::new().dark_gray(),
+///         b if b.is_ascii_graphic() => Style::new().cyan(),
+///         _ => Style::new(),
+///     });
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct HexView<'a> {
+    data: &'a [u8],
+    block: Option<Block<'a>>,
+    style: Style,
+    bytes_per_row: usize,
+    offset_style: Style,
+    selected_style: Style,
+    byte_style: Option<ByteStyleFn>,
+    show_ascii: bool,
+}
+
+impl Default for HexView<'_> {
+    fn default() -> Self {
+        Self::new(&[])
+    }
+}
+
+// Implemented manually because the byte style function is compared by address, which the derived
+// implementation warns about
+impl PartialEq for HexView<'_> {
+    #[allow(clippy::fn_to_numeric_cast_any)]
+    fn eq(&self, other: &Self) -> bool {
+        self.data == other.data
+            && self.block == other.block
+            && self.style == other.style
+            && self.bytes_per_row == other.bytes_per_row
+            && self.offset_style == other.offset_style
+            && self.selected_style == other.selected_style
+            && self.byte_style.map(|f| f as usize) == other.byte_style.map(|f| f as usize)
+            && self.show_ascii == other.show_ascii
+    }
+}
+
+impl<'a> HexView<'a> {
+    /// Creates a new view of the given bytes, with 16 bytes per row
+    pub const fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            block: None,
+            style: Style::new(),
+            bytes_per_row: 16,
+            offset_style: Style::new().add_modifier(Modifier::DIM),
+            selected_style: Style::new().add_modifier(Modifier::REVERSED),
+            byte_style: None,
+            show_ascii: true,
+        }
+    }
+
+    /// Wraps the view in a [`Block`]
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the style of the view
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the number of bytes displayed on each row, which defaults to 16
+    ///
+    /// A value of 0 is treated as 1.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn bytes_per_row(mut self, bytes_per_row: usize) -> Self {
+        self.bytes_per_row = if bytes_per_row == 0 { 1 } else { bytes_per_row };
+        self
+    }
+
+    /// Sets the style of the offsets at the start of the rows, which are dimmed by default
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn offset_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.offset_style = style.into();
+        self
+    }
+
+    /// Sets the style of the selected byte, which is reversed by default
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn selected_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.selected_style = style.into();
+        self
+    }
+
+    /// Sets a function that computes the style of each byte from its index and value
+    ///
+    /// The style is used in both the hexadecimal and ASCII columns. The style of the selected
+    /// byte is patched on top of it.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn byte_style(mut self, byte_style: ByteStyleFn) -> Self {
+        self.byte_style = Some(byte_style);
+        self
+    }
+
+    /// Sets whether the ASCII column is displayed, which is the default
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn show_ascii(mut self, show: bool) -> Self {
+        self.show_ascii = show;
+        self
+    }
+
+    /// The number of rows needed to display all the bytes
+    const fn row_count(&self) -> usize {
+        self.data.len().div_ceil(self.bytes_per_row)
+    }
+
+    /// The column of the hexadecimal value of the byte at the given position in a row, relative
+    /// to the start of the hexadecimal column
+    const fn hex_column(position: usize) -> usize {
+        position * 3 + position / 8
+    }
+
+    /// The width of the hexadecimal column
+    const fn hex_width(&self) -> usize {
+        Self::hex_column(self.bytes_per_row - 1) + 2
+    }
+
+    fn render_row(&self, row: usize, area: Rect, buf: &mut Buffer, selected: Option<usize>) {
+        let start = row * self.bytes_per_row;
+        let end = (start + self.bytes_per_row).min(self.data.len());
+        let offset = format!("{start:08x}");
+        let hex_start = offset.len() + 2;
+        let ascii_start = hex_start + self.hex_width() + 2;
+        self.set_str(0, &offset, self.offset_style, area, buf);
+
+        for (index, &byte) in self.data[start..end].iter().enumerate() {
+            let index = start + index;
+            let mut style = self
+                .byte_style
+                .map_or_else(Style::new, |byte_style| byte_style(index, byte));
+            if selected == Some(index) {
+                style = style.patch(self.selected_style);
+            }
+            let position = index - start;
+            let hex = format!("{byte:02x}");
+            self.set_str(
+                hex_start + Self::hex_column(position),
+                &hex,
+                style,
+                area,
+                buf,
+            );
+            if self.show_ascii {
+                let ascii = if byte.is_ascii_graphic() || byte == b' ' {
+                    char::from(byte)
+                } else {
+                    '.'
+                };
+                let ascii = ascii.to_string();
+                self.set_str(ascii_start + position, &ascii, style, area, buf);
+            }
+        }
+    }
+
+    /// Sets a string at the given column of a row, if it fits in the area
+    #[allow(clippy::unused_self)]
+    fn set_str(&self, column: usize, string: &str, style: Style, area: Rect, buf: &mut Buffer) {
+        let Ok(column) = u16::try_from(column) else {
+            return;
+        };
+        if column >= area.width {
+            return;
+        }
+        let width = usize::from(area.width - column);
+        buf.set_stringn(area.x + column, area.y, string, width, style);
+    }
+}
+
+impl StatefulWidget for HexView<'_> {
+    type State = HexViewState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        self.render_ref(area, buf, state);
+    }
+}
+
+// Note: remove this when StatefulWidgetRef is stabilized and replace with the blanket impl
+impl StatefulWidget for &HexView<'_> {
+    type State = HexViewState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        self.render_ref(area, buf, state);
+    }
+}
+
+impl StatefulWidgetRef for HexView<'_> {
+    type State = HexViewState;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        self.block.render_ref(area, buf);
+        let area = self.block.inner_if_some(area).intersection(buf.area);
+        state.bytes_per_row = self.bytes_per_row;
+        state.len = self.data.len();
+        state.selected = state
+            .selected
+            .map(|selected| selected.min(self.data.len().saturating_sub(1)));
+        if area.is_empty() {
+            return;
+        }
+
+        let height = usize::from(area.height);
+        let max_offset = self.row_count().saturating_sub(height);
+        state.offset = state.offset.min(max_offset);
+        if let Some(selected) = state.selected {
+            let row = selected / self.bytes_per_row;
+            state.offset = state.offset.clamp((row + 1).saturating_sub(height), row);
+        }
+
+        let selected = state.selected.filter(|_| !self.data.is_empty());
+        for (y, row) in (area.top()..area.bottom()).zip(state.offset..self.row_count()) {
+            let row_area = Rect::new(area.x, y, area.width, 1);
+            self.render_row(row, row_area, buf, selected);
+        }
+    }
+}
+
+impl Styled for HexView<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    const DATA: &[u8] = b"Hello, world!\n\x00\xff";
+
+    fn render(view: &HexView, state: &mut HexViewState, width: u16, height: u16) -> Buffer {
+        let mut buf = Buffer::empty(Rect::new(0, 0, width, height));
+        view.render(buf.area, &mut buf, state);
+        buf
+    }
+
+    /// A view without the default styles, to test the layout of the rows
+    fn plain_view(data: &[u8]) -> HexView<'_> {
+        HexView::new(data)
+            .offset_style(Style::new())
+            .selected_style(Style::new())
+    }
+
+    #[test]
+    fn render_rows() {
+        let view = plain_view(DATA).bytes_per_row(8);
+        let buf = render(&view, &mut HexViewState::new(), 43, 2);
+        assert_eq!(
+            buf,
+            Buffer::with_lines([
+                "00000000  48 65 6c 6c 6f 2c 20 77  Hello, w",
+                "00000008  6f 72 6c 64 21 0a 00 ff  orld!...",
+            ])
+        );
+    }
+
+    #[test]
+    fn render_default_row_width() {
+        let view = plain_view(DATA);
+        let buf = render(&view, &mut HexViewState::new(), 76, 1);
+        assert_eq!(
+            buf,
+            Buffer::with_lines([
+                "00000000  48 65 6c 6c 6f 2c 20 77  6f 72 6c 64 21 0a 00 ff  Hello, world!...",
+            ])
+        );
+    }
+
+    #[test]
+    fn render_without_ascii() {
+        let view = plain_view(b"AB").bytes_per_row(2).show_ascii(false);
+        let buf = render(&view, &mut HexViewState::new(), 15, 1);
+        assert_eq!(buf, Buffer::with_lines(["00000000  41 42"]));
+    }
+
+    #[test]
+    fn render_selected_and_byte_style() {
+        let view = HexView::new(b"a\x00b")
+            .offset_style(Style::new())
+            .bytes_per_row(3)
+            .byte_style(|_, byte| {
+                if byte == 0 {
+                    Style::new().red()
+                } else {
+                    Style::new()
+                }
+            });
+        let mut state = HexViewState::new();
+        state.select(Some(2));
+        let buf = render(&view, &mut state, 23, 1);
+        let mut expected = Buffer::with_lines(["00000000  61 00 62  a.b"]);
+        expected.set_style(Rect::new(13, 0, 2, 1), Style::new().red());
+        expected.set_style(Rect::new(21, 0, 1, 1), Style::new().red());
+        expected.set_style(Rect::new(16, 0, 2, 1), Style::new().reversed());
+        expected.set_style(Rect::new(22, 0, 1, 1), Style::new().reversed());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_scrolls_to_selected_byte() {
+        let view = plain_view(DATA).bytes_per_row(4).show_ascii(false);
+        let mut state = HexViewState::new();
+        state.select(Some(13));
+        let buf = render(&view, &mut state, 21, 2);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["00000008  6f 72 6c 64", "0000000c  21 0a 00 ff"])
+        );
+        assert_eq!(state.offset(), 2);
+
+        state.select(Some(0));
+        render(&view, &mut state, 21, 2);
+        assert_eq!(state.offset(), 0);
+    }
+
+    #[test]
+    fn state_moves_selection() {
+        let view = HexView::new(DATA).bytes_per_row(4);
+        let mut state = HexViewState::new();
+        state.select_next();
+        assert_eq!(state.selected(), Some(0));
+        render(&view, &mut state, 30, 2);
+        state.select_next_row();
+        assert_eq!(state.selected(), Some(4));
+        state.select_next();
+        assert_eq!(state.selected(), Some(5));
+        state.select_previous_row();
+        assert_eq!(state.selected(), Some(1));
+        state.select_previous_row();
+        assert_eq!(state.selected(), Some(0));
+        state.select(Some(14));
+        state.select_next_row();
+        assert_eq!(state.selected(), Some(14));
+        state.select(Some(100));
+        render(&view, &mut state, 30, 2);
+        assert_eq!(state.selected(), Some(15));
+    }
+
+    #[test]
+    fn state_scrolls() {
+        let view = HexView::new(DATA).bytes_per_row(4);
+        let mut state = HexViewState::new();
+        state.scroll_down(10);
+        render(&view, &mut state, 30, 2);
+        assert_eq!(state.offset(), 2);
+        state.scroll_up(1);
+        assert_eq!(state.offset(), 1);
+    }
+}
@@ -0,0 +1,564 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    fmt::Write,
+    hash::{Hash, Hasher},
+};
+
+use crate::{backend::GraphicsProtocol, buffer::Graphic, layout::Size, prelude::*, widgets::Block};
+
+/// A widget that displays an image.
+///
+/// The image is given as raw RGBA pixels (4 bytes per pixel, row by row) and is scaled to fill the
+/// area it is rendered into. Pixels with an alpha value below 128 are transparent and leave the
+/// underlying cells untouched when rendering with half blocks.
+///
+/// How the image is displayed depends on the [`GraphicsProtocol`]:
+/// - [`GraphicsProtocol::Halfblocks`] (the default) approximates the image with two pixels per
+///   cell, using the `▀` character with the foreground and background colors. This works on any
+///   terminal with true color support and with every backend, including the
+///   [`TestBackend`](crate::backend::TestBackend).
+/// - [`GraphicsProtocol::Sixel`], [`GraphicsProtocol::Kitty`] and [`GraphicsProtocol::Iterm2`]
+///   display the image at the full resolution of the screen. The image is added to the buffer as a
+///   [`Graphic`] (see [`Buffer::set_graphic`]), which the [`Terminal`] writes through the backend
+///   once, when the image appears, and removes when it is no longer rendered. This requires a
+///   backend that implements [`Backend::write_raw`], such as the Crossterm and Termion backends.
+///   The image is not drawn if its area is clipped (see [`Buffer::push_clip`]).
+///
+/// Use [`GraphicsProtocol::detect`] to guess the protocol supported by the terminal.
+///
+/// Sixel images are rendered at a fixed resolution per cell, which should match the size of the
+/// font of the terminal in pixels. It defaults to 8x16 pixels and can be set with
+/// [`Image::font_size`] (see [`Backend::window_size`] to compute it).
+///
+/// [`Terminal`]: crate::terminal::Terminal
+/// [`Backend::write_raw`]: crate::backend::Backend::write_raw
+/// [`Backend::window_size`]: crate::backend::Backend::window_size
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{backend::GraphicsProtocol, prelude::*, widgets::*};
+///
+/// # fn ui(frame: &mut Frame) {
+/// // a 2x2 image with a red, a green, a blue and a white pixel
+/// let pixels = [
+///     255, 0, 0, 255, 0, 255, 0, 255, //
+///     0, 0, 255, 255, 255, 255, 255, 255,
+/// ];
+/// let image = Image::new(2, 2, &pixels)
+///     .protocol(GraphicsProtocol::detect())
+///     .block(Block::bordered().title("Image"));
+/// frame.render_widget(image, frame.size());
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Image<'a> {
+    width: u32,
+    height: u32,
+    pixels: &'a [u8],
+    protocol: GraphicsProtocol,
+    font_size: Size,
+    block: Option<Block<'a>>,
+    style: Style,
+}
+
+impl<'a> Image<'a> {
+    /// Creates a new image from RGBA pixels
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `pixels` is not `width * height * 4`.
+    pub fn new(width: u32, height: u32, pixels: &'a [u8]) -> Self {
+        assert_eq!(
+            pixels.len(),
+            width as usize * height as usize * 4,
+            "the image must contain width * height RGBA pixels"
+        );
+        Self {
+            width,
+            height,
+            pixels,
+            protocol: GraphicsProtocol::default(),
+            font_size: Size::new(8, 16),
+            block: None,
+            style: Style::new(),
+        }
+    }
+
+    /// Sets the protocol used to display the image
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn protocol(mut self, protocol: GraphicsProtocol) -> Self {
+        self.protocol = protocol;
+        self
+    }
+
+    /// Sets the size of a cell in pixels, used to compute the resolution of Sixel images
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn font_size(mut self, font_size: Size) -> Self {
+        self.font_size = font_size;
+        self
+    }
+
+    /// Surrounds the image with a [`Block`]
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the style of the widget
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the RGBA pixel at the given position of the image
+    fn pixel(&self, x: u32, y: u32) -> [u8; 4] {
+        let index = (y as usize * self.width as usize + x as usize) * 4;
+        [
+            self.pixels[index],
+            self.pixels[index + 1],
+            self.pixels[index + 2],
+            self.pixels[index + 3],
+        ]
+    }
+
+    /// Returns the pixel of the image at the given position of an image scaled to `width` and
+    /// `height` (nearest neighbor)
+    fn scaled_pixel(&self, x: u32, y: u32, width: u32, height: u32) -> [u8; 4] {
+        let x = (u64::from(x) * u64::from(self.width) / u64::from(width)) as u32;
+        let y = (u64::from(y) * u64::from(self.height) / u64::from(height)) as u32;
+        self.pixel(x, y)
+    }
+
+    fn render_halfblocks(&self, area: Rect, buf: &mut Buffer) {
+        let width = u32::from(area.width);
+        let height = u32::from(area.height) * 2;
+        for y in 0..area.height {
+            for x in 0..area.width {
+                let top = self.scaled_pixel(u32::from(x), u32::from(y) * 2, width, height);
+                let bottom = self.scaled_pixel(u32::from(x), u32::from(y) * 2 + 1, width, height);
+                let cell = buf.get_mut(area.x + x, area.y + y);
+                match (is_opaque(top), is_opaque(bottom)) {
+                    (true, true) => cell.set_symbol("▀").set_fg(rgb(top)).set_bg(rgb(bottom)),
+                    (true, false) => cell.set_symbol("▀").set_fg(rgb(top)),
+                    (false, true) => cell.set_symbol("▄").set_fg(rgb(bottom)),
+                    (false, false) => cell,
+                };
+            }
+        }
+    }
+
+    fn render_graphic(&self, area: Rect, buf: &mut Buffer) {
+        // sixel and iTerm2 images are drawn in the cells, which erase them when they are redrawn,
+        // while kitty images are placed over the cells and must be deleted
+        let graphic = match self.protocol {
+            GraphicsProtocol::Halfblocks => unreachable!("half blocks are rendered as cells"),
+            GraphicsProtocol::Sixel => Graphic::new(area, self.encode_sixel(area)),
+            GraphicsProtocol::Kitty => {
+                let id = self.kitty_id(area);
+                Graphic::new(area, self.encode_kitty(area, id))
+                    .cleanup(format!("\x1b_Ga=d,d=I,i={id},q=2\x1b\\"))
+            }
+            GraphicsProtocol::Iterm2 => Graphic::new(area, self.encode_iterm2(area)),
+        };
+        buf.set_graphic(graphic);
+    }
+
+    /// Returns a non-zero id identifying the image and its area for the Kitty graphics protocol
+    fn kitty_id(&self, area: Rect) -> u32 {
+        let mut hasher = DefaultHasher::new();
+        (self.width, self.height, self.pixels, area).hash(&mut hasher);
+        (hasher.finish() as u32).max(1)
+    }
+
+    /// Encodes the image with the Sixel format, scaled to the area using the font size
+    fn encode_sixel(&self, area: Rect) -> String {
+        let width = u32::from(area.width) * u32::from(self.font_size.width.max(1));
+        let height = u32::from(area.height) * u32::from(self.font_size.height.max(1));
+        // quantize the colors to a 6x6x6 color cube (None for transparent pixels)
+        let indexes: Vec<Option<u8>> = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| {
+                let pixel = self.scaled_pixel(x, y, width, height);
+                is_opaque(pixel).then(|| {
+                    let [r, g, b, _] = pixel.map(|c| ((u16::from(c) * 6) >> 8) as u8);
+                    r * 36 + g * 6 + b
+                })
+            })
+            .collect();
+
+        let mut sixel = format!("\x1bP9;1q\"1;1;{width};{height}");
+        let mut used = [false; 216];
+        for index in indexes.iter().flatten() {
+            used[*index as usize] = true;
+        }
+        for (index, _) in used.iter().enumerate().filter(|(_, used)| **used) {
+            let level = |value: usize| value * 100 / 5;
+            let (r, g, b) = (index / 36, index / 6 % 6, index % 6);
+            let _ = write!(sixel, "#{index};2;{};{};{}", level(r), level(g), level(b));
+        }
+        for band in 0..height.div_ceil(6) {
+            let mut colors: Vec<u8> = (band * 6..height.min(band * 6 + 6))
+                .flat_map(|y| (0..width).map(move |x| (y * width + x) as usize))
+                .filter_map(|index| indexes[index])
+                .collect();
+            colors.sort_unstable();
+            colors.dedup();
+            for color in colors {
+                let _ = write!(sixel, "#{color}");
+                let mut run: Option<(u8, u32)> = None;
+                for x in 0..width {
+                    let mut bits = 0;
+                    for bit in 0..6 {
+                        let y = band * 6 + bit;
+                        if y < height && indexes[(y * width + x) as usize] == Some(color) {
+                            bits |= 1 << bit;
+                        }
+                    }
+                    run = match run {
+                        Some((previous, count)) if previous == bits => Some((bits, count + 1)),
+                        Some((previous, count)) => {
+                            push_sixel_run(&mut sixel, previous, count);
+                            Some((bits, 1))
+                        }
+                        None => Some((bits, 1)),
+                    };
+                }
+                if let Some((bits, count)) = run {
+                    push_sixel_run(&mut sixel, bits, count);
+                }
+                sixel.push('$');
+            }
+            sixel.push('-');
+        }
+        sixel.push_str("\x1b\\");
+        sixel
+    }
+
+    /// Encodes the image with the Kitty graphics protocol, scaled by the terminal to the area
+    fn encode_kitty(&self, area: Rect, id: u32) -> String {
+        const CHUNK_SIZE: usize = 4096;
+        let data = base64(self.pixels);
+        let mut kitty = String::new();
+        let chunks = data.as_bytes().chunks(CHUNK_SIZE);
+        let last = chunks.len().saturating_sub(1);
+        for (i, chunk) in chunks.enumerate() {
+            let more = u8::from(i < last);
+            // chunks only contain base64 characters
+            let chunk = std::str::from_utf8(chunk).unwrap_or_default();
+            if i == 0 {
+                let _ = write!(
+                    kitty,
+                    "\x1b_Ga=T,f=32,i={id},s={},v={},c={},r={},C=1,q=2,m={more};{chunk}\x1b\\",
+                    self.width, self.height, area.width, area.height
+                );
+            } else {
+                let _ = write!(kitty, "\x1b_Gm={more};{chunk}\x1b\\");
+            }
+        }
+        kitty
+    }
+
+    /// Encodes the image as a PNG file with the iTerm2 inline images protocol, scaled by the
+    /// terminal to the area
+    fn encode_iterm2(&self, area: Rect) -> String {
+        let png = encode_png(self.width, self.height, self.pixels);
+        format!(
+            "\x1b]1337;File=inline=1;size={};width={};height={};preserveAspectRatio=0:{}\x07",
+            png.len(),
+            area.width,
+            area.height,
+            base64(&png)
+        )
+    }
+}
+
+impl Widget for Image<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_ref(area, buf);
+    }
+}
+
+impl WidgetRef for Image<'_> {
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        buf.set_style(area, self.style);
+        self.block.render_ref(area, buf);
+        let inner = self.block.inner_if_some(area);
+        if inner.is_empty() || self.width == 0 || self.height == 0 {
+            return;
+        }
+        match self.protocol {
+            GraphicsProtocol::Halfblocks => self.render_halfblocks(inner, buf),
+            _ => self.render_graphic(inner, buf),
+        }
+    }
+}
+
+impl Styled for Image<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+const fn is_opaque(pixel: [u8; 4]) -> bool {
+    pixel[3] >= 128
+}
+
+const fn rgb(pixel: [u8; 4]) -> Color {
+    Color::Rgb(pixel[0], pixel[1], pixel[2])
+}
+
+/// Appends `count` times the sixel with the given bits, using the repeat introducer for long runs
+fn push_sixel_run(sixel: &mut String, bits: u8, count: u32) {
+    let character = char::from(63 + bits);
+    if count > 3 {
+        let _ = write!(sixel, "!{count}{character}");
+    } else {
+        for _ in 0..count {
+            sixel.push(character);
+        }
+    }
+}
+
+fn base64(data: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut encoded = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let bytes = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let value = u32::from(bytes[0]) << 16 | u32::from(bytes[1]) << 8 | u32::from(bytes[2]);
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(char::from(
+                    ALPHABET[(value >> (18 - 6 * i) & 0x3f) as usize],
+                ));
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+/// Encodes RGBA pixels as an uncompressed PNG file
+fn encode_png(width: u32, height: u32, pixels: &[u8]) -> Vec<u8> {
+    fn chunk(png: &mut Vec<u8>, kind: [u8; 4], data: &[u8]) {
+        png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+        let start = png.len();
+        png.extend_from_slice(&kind);
+        png.extend_from_slice(data);
+        let crc = crc32(&png[start..]);
+        png.extend_from_slice(&crc.to_be_bytes());
+    }
+
+    // each scanline starts with the filter type (0 = none)
+    let row_length = width as usize * 4;
+    let mut raw = Vec::with_capacity((row_length + 1) * height as usize);
+    for row in pixels.chunks(row_length.max(1)) {
+        raw.push(0);
+        raw.extend_from_slice(row);
+    }
+
+    // zlib stream made of uncompressed deflate blocks
+    let mut zlib = vec![0x78, 0x01];
+    let blocks = raw.chunks(usize::from(u16::MAX));
+    let last = blocks.len().saturating_sub(1);
+    for (i, block) in blocks.enumerate() {
+        let length = block.len() as u16;
+        zlib.push(u8::from(i == last));
+        zlib.extend_from_slice(&length.to_le_bytes());
+        zlib.extend_from_slice(&(!length).to_le_bytes());
+        zlib.extend_from_slice(block);
+    }
+    zlib.extend_from_slice(&adler32(&raw).to_be_bytes());
+
+    let mut header = Vec::with_capacity(13);
+    header.extend_from_slice(&width.to_be_bytes());
+    header.extend_from_slice(&height.to_be_bytes());
+    // 8 bits per channel, RGBA, default compression, filter and interlace methods
+    header.extend_from_slice(&[8, 6, 0, 0, 0]);
+
+    let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+    chunk(&mut png, *b"IHDR", &header);
+    chunk(&mut png, *b"IDAT", &zlib);
+    chunk(&mut png, *b"IEND", &[]);
+    png
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for byte in data {
+        crc ^= u32::from(*byte);
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                crc >> 1 ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for byte in data {
+        a = (a + u32::from(*byte)) % 65521;
+        b = (b + a) % 65521;
+    }
+    b << 16 | a
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    const RED: [u8; 4] = [255, 0, 0, 255];
+    const BLUE: [u8; 4] = [0, 0, 255, 255];
+    const CLEAR: [u8; 4] = [0, 0, 0, 0];
+
+    fn pixels(rows: &[&[[u8; 4]]]) -> Vec<u8> {
+        rows.iter()
+            .flat_map(|row| row.iter().flatten())
+            .copied()
+            .collect()
+    }
+
+    #[test]
+    #[should_panic = "the image must contain width * height RGBA pixels"]
+    fn new_panics_with_invalid_length() {
+        let _ = Image::new(2, 2, &[0; 12]);
+    }
+
+    #[test]
+    fn render_halfblocks() {
+        let pixels = pixels(&[&[RED, BLUE], &[BLUE, CLEAR]]);
+        let mut buf = Buffer::with_lines(["xx"]);
+        Image::new(2, 2, &pixels).render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["▀▀"]);
+        expected.get_mut(0, 0).set_fg(Color::Rgb(255, 0, 0));
+        expected.get_mut(0, 0).set_bg(Color::Rgb(0, 0, 255));
+        expected.get_mut(1, 0).set_fg(Color::Rgb(0, 0, 255));
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_halfblocks_scales_image() {
+        let pixels = pixels(&[&[RED]]);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 2));
+        Image::new(1, 1, &pixels).render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["▀▀▀", "▀▀▀"]);
+        expected.set_style(
+            expected.area,
+            Style::new()
+                .fg(Color::Rgb(255, 0, 0))
+                .bg(Color::Rgb(255, 0, 0)),
+        );
+        assert_eq!(buf, expected);
+    }
+
+    #[rstest]
+    #[case::sixel(GraphicsProtocol::Sixel, "\x1bP9;1q", false)]
+    #[case::kitty(GraphicsProtocol::Kitty, "\x1b_Ga=T,f=32,i=", true)]
+    #[case::iterm2(GraphicsProtocol::Iterm2, "\x1b]1337;File=inline=1;", false)]
+    fn render_graphic(
+        #[case] protocol: GraphicsProtocol,
+        #[case] prefix: &str,
+        #[case] cleanup: bool,
+    ) {
+        let pixels = pixels(&[&[RED]]);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 2));
+        Image::new(1, 1, &pixels)
+            .protocol(protocol)
+            .render(buf.area, &mut buf);
+        let [graphic] = buf.graphics() else {
+            panic!("expected one graphic, got {:?}", buf.graphics());
+        };
+        assert_eq!(graphic.area, buf.area);
+        assert!(
+            graphic.sequence.starts_with(prefix),
+            "{:?}",
+            graphic.sequence
+        );
+        assert_eq!(graphic.cleanup.is_some(), cleanup);
+        assert!(buf
+            .content
+            .iter()
+            .all(|cell| cell.skip && cell.symbol() == " "));
+    }
+
+    #[test]
+    fn render_graphic_clipped() {
+        let pixels = pixels(&[&[RED]]);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 2));
+        buf.push_clip(Rect::new(0, 0, 3, 1));
+        Image::new(1, 1, &pixels)
+            .protocol(GraphicsProtocol::Kitty)
+            .render(buf.area, &mut buf);
+        assert_eq!(buf.graphics(), []);
+        assert!(buf.content.iter().all(|cell| !cell.skip));
+    }
+
+    #[test]
+    fn encode_kitty() {
+        let pixels = pixels(&[&[RED]]);
+        let image = Image::new(1, 1, &pixels);
+        let area = Rect::new(0, 0, 3, 2);
+        assert_eq!(
+            image.encode_kitty(area, 7),
+            "\x1b_Ga=T,f=32,i=7,s=1,v=1,c=3,r=2,C=1,q=2,m=0;/wAA/w==\x1b\\"
+        );
+        assert_ne!(image.kitty_id(area), image.kitty_id(Rect::new(1, 0, 3, 2)));
+    }
+
+    #[test]
+    fn encode_sixel() {
+        let pixels = pixels(&[&[RED, BLUE]]);
+        let sixel = Image::new(2, 1, &pixels)
+            .font_size(Size::new(2, 1))
+            .encode_sixel(Rect::new(0, 0, 2, 1));
+        assert_eq!(
+            sixel,
+            "\x1bP9;1q\"1;1;4;1#5;2;0;0;100#180;2;100;0;0#5??@@$#180@@??$-\x1b\\"
+        );
+    }
+
+    #[test]
+    fn base64_padding() {
+        assert_eq!(base64(b""), "");
+        assert_eq!(base64(b"f"), "Zg==");
+        assert_eq!(base64(b"fo"), "Zm8=");
+        assert_eq!(base64(b"foo"), "Zm9v");
+        assert_eq!(base64(b"foobar"), "Zm9vYmFy");
+    }
+
+    #[test]
+    fn checksums() {
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    }
+
+    #[test]
+    fn encode_png() {
+        let png = super::encode_png(1, 1, &RED);
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(&png[png.len() - 12..], b"\0\0\0\0IEND\xaeB`\x82");
+    }
+}
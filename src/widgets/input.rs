@@ -0,0 +1,785 @@
+//! The [`Input`] widget and its [`InputState`].
+use std::ops::Range;
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use crate::{layout::Position, prelude::*, widgets::Block};
+
+/// State of the [`Input`] widget
+///
+/// The state owns the text being edited and tracks the cursor, the selection and the horizontal
+/// scroll offset. All positions are expressed in graphemes (user-perceived characters) rather than
+/// bytes, so that editing operations never split a multi-codepoint character.
+///
+/// The editing methods (e.g. [`insert_char`], [`delete_backward`], [`move_left`]) are meant to be
+/// called from your event handling code. When the widget is rendered, the scroll offset is
+/// adjusted to keep the cursor visible and the screen position of the cursor is recorded so that
+/// it can be passed to [`Frame::set_cursor`].
+///
+/// [`insert_char`]: InputState::insert_char
+/// [`delete_backward`]: InputState::delete_backward
+/// [`move_left`]: InputState::move_left
+/// [`Frame::set_cursor`]: crate::terminal::Frame::set_cursor
+///
+/// # Example
+///
+/// ```rust
+/// # use ratatui::{prelude::*, widgets::*};
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// // This should be stored outside of the function in your application state.
+/// let mut state = InputState::new("hello");
+/// state.insert_str(" world");
+/// state.move_home();
+///
+/// frame.render_stateful_widget(Input::new(), area, &mut state);
+/// if let Some(position) = state.cursor_position() {
+///     frame.set_cursor(position.x, position.y);
+/// }
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InputState {
+    value: String,
+    cursor: usize,
+    selection_anchor: Option<usize>,
+    offset: usize,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    cursor_position: Option<Position>,
+}
+
+impl InputState {
+    /// Creates a new state with the given value and the cursor placed at the end of it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let state = InputState::new("hello");
+    /// assert_eq!(state.cursor(), 5);
+    /// ```
+    pub fn new<T: Into<String>>(value: T) -> Self {
+        let value = value.into();
+        let cursor = value.graphemes(true).count();
+        Self {
+            value,
+            cursor,
+            ..Self::default()
+        }
+    }
+
+    /// Sets the index of the first grapheme to be displayed
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Sets the cursor position (in graphemes)
+    ///
+    /// The cursor is clamped to the length of the value.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_cursor(mut self, cursor: usize) -> Self {
+        self.set_cursor(cursor);
+        self
+    }
+
+    /// The text being edited
+    pub fn value(&self) -> &str {
+        &self.value
+    }
+
+    /// Replaces the text being edited
+    ///
+    /// The cursor is moved to the end of the new value and the selection is cleared.
+    pub fn set_value<T: Into<String>>(&mut self, value: T) {
+        self.value = value.into();
+        self.cursor = self.len();
+        self.selection_anchor = None;
+    }
+
+    /// Number of graphemes in the value
+    pub fn len(&self) -> usize {
+        self.value.graphemes(true).count()
+    }
+
+    /// Returns true if the value is empty
+    pub fn is_empty(&self) -> bool {
+        self.value.is_empty()
+    }
+
+    /// Index of the grapheme before which the cursor is placed
+    pub const fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Moves the cursor to the given grapheme index, clearing the selection
+    ///
+    /// The cursor is clamped to the length of the value.
+    pub fn set_cursor(&mut self, cursor: usize) {
+        self.cursor = cursor.min(self.len());
+        self.selection_anchor = None;
+    }
+
+    /// Index of the first grapheme to be displayed
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Mutable reference to the index of the first grapheme to be displayed
+    pub fn offset_mut(&mut self) -> &mut usize {
+        &mut self.offset
+    }
+
+    /// The screen position of the cursor computed during the last render
+    ///
+    /// Returns `None` if the widget has not been rendered yet or if it was rendered into an empty
+    /// area.
+    pub const fn cursor_position(&self) -> Option<Position> {
+        self.cursor_position
+    }
+
+    /// The selected range of graphemes, if any
+    ///
+    /// Returns `None` if nothing is selected or if the selection is empty.
+    pub fn selection(&self) -> Option<Range<usize>> {
+        let anchor = self.selection_anchor?;
+        let range = anchor.min(self.cursor)..anchor.max(self.cursor);
+        (!range.is_empty()).then_some(range)
+    }
+
+    /// The selected text, if any
+    pub fn selected_text(&self) -> Option<&str> {
+        self.selection()
+            .map(|range| &self.value[self.byte_range(range)])
+    }
+
+    /// Selects the whole value and places the cursor at the end
+    pub fn select_all(&mut self) {
+        self.selection_anchor = Some(0);
+        self.cursor = self.len();
+    }
+
+    /// Clears the selection without changing the value
+    pub fn clear_selection(&mut self) {
+        self.selection_anchor = None;
+    }
+
+    /// Clears the value
+    pub fn clear(&mut self) {
+        self.value.clear();
+        self.cursor = 0;
+        self.offset = 0;
+        self.selection_anchor = None;
+    }
+
+    /// Inserts a character at the cursor, replacing the selection if there is one
+    pub fn insert_char(&mut self, c: char) {
+        self.insert_str(c.encode_utf8(&mut [0; 4]));
+    }
+
+    /// Inserts a string at the cursor, replacing the selection if there is one
+    ///
+    /// Newlines are not supported by this single-line widget and are ignored.
+    pub fn insert_str(&mut self, s: &str) {
+        self.delete_selection();
+        let s: String = s.chars().filter(|c| *c != '\n' && *c != '\r').collect();
+        let index = self.byte_index(self.cursor);
+        self.value.insert_str(index, &s);
+        // the new text may combine with its neighbours (e.g. a combining accent or a zero width
+        // joiner), so place the cursor after the grapheme containing the end of the insertion
+        self.cursor = self.grapheme_index(index + s.len()).min(self.len());
+    }
+
+    /// Deletes the selection or the grapheme before the cursor (i.e. backspace)
+    pub fn delete_backward(&mut self) {
+        if self.delete_selection() || self.cursor == 0 {
+            return;
+        }
+        self.remove(self.cursor - 1..self.cursor);
+        self.cursor -= 1;
+    }
+
+    /// Deletes the selection or the grapheme after the cursor (i.e. delete)
+    pub fn delete_forward(&mut self) {
+        if self.delete_selection() || self.cursor >= self.len() {
+            return;
+        }
+        self.remove(self.cursor..self.cursor + 1);
+    }
+
+    /// Deletes the selection or the word before the cursor
+    pub fn delete_word_backward(&mut self) {
+        if self.delete_selection() {
+            return;
+        }
+        let start = self.previous_word_boundary();
+        self.remove(start..self.cursor);
+        self.cursor = start;
+    }
+
+    /// Moves the cursor one grapheme to the left
+    pub fn move_left(&mut self) {
+        self.move_to(self.cursor.saturating_sub(1), false);
+    }
+
+    /// Moves the cursor one grapheme to the right
+    pub fn move_right(&mut self) {
+        self.move_to(self.cursor + 1, false);
+    }
+
+    /// Moves the cursor to the start of the previous word
+    pub fn move_word_left(&mut self) {
+        self.move_to(self.previous_word_boundary(), false);
+    }
+
+    /// Moves the cursor to the end of the next word
+    pub fn move_word_right(&mut self) {
+        self.move_to(self.next_word_boundary(), false);
+    }
+
+    /// Moves the cursor to the start of the value
+    pub fn move_home(&mut self) {
+        self.move_to(0, false);
+    }
+
+    /// Moves the cursor to the end of the value
+    pub fn move_end(&mut self) {
+        self.move_to(self.len(), false);
+    }
+
+    /// Extends the selection one grapheme to the left
+    pub fn select_left(&mut self) {
+        self.move_to(self.cursor.saturating_sub(1), true);
+    }
+
+    /// Extends the selection one grapheme to the right
+    pub fn select_right(&mut self) {
+        self.move_to(self.cursor + 1, true);
+    }
+
+    /// Extends the selection to the start of the previous word
+    pub fn select_word_left(&mut self) {
+        self.move_to(self.previous_word_boundary(), true);
+    }
+
+    /// Extends the selection to the end of the next word
+    pub fn select_word_right(&mut self) {
+        self.move_to(self.next_word_boundary(), true);
+    }
+
+    /// Extends the selection to the start of the value
+    pub fn select_home(&mut self) {
+        self.move_to(0, true);
+    }
+
+    /// Extends the selection to the end of the value
+    pub fn select_end(&mut self) {
+        self.move_to(self.len(), true);
+    }
+
+    fn move_to(&mut self, cursor: usize, select: bool) {
+        if select {
+            self.selection_anchor.get_or_insert(self.cursor);
+        } else {
+            self.selection_anchor = None;
+        }
+        self.cursor = cursor.min(self.len());
+    }
+
+    /// Deletes the selected text and returns true if there was a selection
+    fn delete_selection(&mut self) -> bool {
+        let selection = self.selection();
+        self.selection_anchor = None;
+        selection.is_some_and(|range| {
+            self.cursor = range.start;
+            self.remove(range);
+            true
+        })
+    }
+
+    fn remove(&mut self, range: Range<usize>) {
+        let range = self.byte_range(range);
+        self.value.replace_range(range, "");
+    }
+
+    /// Converts a grapheme index into a byte index in the value
+    fn byte_index(&self, index: usize) -> usize {
+        self.value
+            .grapheme_indices(true)
+            .nth(index)
+            .map_or(self.value.len(), |(i, _)| i)
+    }
+
+    /// Index of the first grapheme that starts at or after the byte index
+    fn grapheme_index(&self, byte_index: usize) -> usize {
+        self.value
+            .grapheme_indices(true)
+            .take_while(|(i, _)| *i < byte_index)
+            .count()
+    }
+
+    fn byte_range(&self, range: Range<usize>) -> Range<usize> {
+        self.byte_index(range.start)..self.byte_index(range.end)
+    }
+
+    fn previous_word_boundary(&self) -> usize {
+        let graphemes: Vec<&str> = self.value.graphemes(true).take(self.cursor).collect();
+        let mut index = graphemes.len();
+        while index > 0 && is_whitespace(graphemes[index - 1]) {
+            index -= 1;
+        }
+        while index > 0 && !is_whitespace(graphemes[index - 1]) {
+            index -= 1;
+        }
+        index
+    }
+
+    fn next_word_boundary(&self) -> usize {
+        let mut graphemes = self.value.graphemes(true).skip(self.cursor).peekable();
+        let mut index = self.cursor;
+        while graphemes.next_if(|g| is_whitespace(g)).is_some() {
+            index += 1;
+        }
+        while graphemes.next_if(|g| !is_whitespace(g)).is_some() {
+            index += 1;
+        }
+        index
+    }
+}
+
+fn is_whitespace(grapheme: &str) -> bool {
+    grapheme.chars().all(char::is_whitespace)
+}
+
+/// A single-line text input widget
+///
+/// The widget renders the value of an [`InputState`], scrolling horizontally to keep the cursor
+/// visible. The text is edited through the methods of the state, which handle cursor movement,
+/// selection and unicode grapheme clusters.
+///
+/// The widget supports:
+/// - a [`placeholder`] displayed when the value is empty
+/// - masking the value (e.g. for passwords) with [`mask`]
+/// - styling the selection and the cell under the cursor with [`selection_style`] and
+///   [`cursor_style`]
+///
+/// The terminal cursor is not moved by the widget. Use [`InputState::cursor_position`] after
+/// rendering to position it with [`Frame::set_cursor`].
+///
+/// [`placeholder`]: Input::placeholder
+/// [`mask`]: Input::mask
+/// [`selection_style`]: Input::selection_style
+/// [`cursor_style`]: Input::cursor_style
+/// [`Frame::set_cursor`]: crate::terminal::Frame::set_cursor
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{prelude::*, widgets::*};
+///
+/// # fn ui(frame: &mut Frame, state: &mut InputState) {
+/// let input = Input::new()
+///     .block(Block::bordered().title("Password"))
+///     .placeholder("enter your password")
+///     .mask('*')
+///     .selection_style(Style::new().reversed());
+/// frame.render_stateful_widget(input, frame.size(), state);
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Input<'a> {
+    block: Option<Block<'a>>,
+    style: Style,
+    placeholder: Option<Span<'a>>,
+    placeholder_style: Style,
+    mask: Option<char>,
+    selection_style: Style,
+    cursor_style: Style,
+}
+
+impl<'a> Input<'a> {
+    /// Creates a new input widget with default styles and no placeholder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Surrounds the input with a [`Block`]
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the base style of the widget
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the text displayed when the value is empty
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let input = Input::new().placeholder("Search...");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn placeholder<T: Into<Span<'a>>>(mut self, placeholder: T) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Sets the style of the placeholder text
+    ///
+    /// The style is patched over the base style of the widget. Defaults to no additional style.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn placeholder_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.placeholder_style = style.into();
+        self
+    }
+
+    /// Masks every grapheme of the value with the given character
+    ///
+    /// This is useful for password fields. The value stored in the [`InputState`] is not
+    /// modified.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let input = Input::new().mask('•');
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn mask(mut self, mask: char) -> Self {
+        self.mask = Some(mask);
+        self
+    }
+
+    /// Sets the style of the selected text
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn selection_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.selection_style = style.into();
+        self
+    }
+
+    /// Sets the style of the cell under the cursor
+    ///
+    /// This can be used to draw a fake cursor when the terminal cursor is hidden.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn cursor_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.cursor_style = style.into();
+        self
+    }
+}
+
+impl StatefulWidget for Input<'_> {
+    type State = InputState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidgetRef::render_ref(&self, area, buf, state);
+    }
+}
+
+impl StatefulWidget for &Input<'_> {
+    type State = InputState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidgetRef::render_ref(self, area, buf, state);
+    }
+}
+
+impl StatefulWidgetRef for Input<'_> {
+    type State = InputState;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        self.block.render_ref(area, buf);
+        let inner = self.block.inner_if_some(area);
+        state.cursor_position = None;
+        if inner.is_empty() {
+            return;
+        }
+        state.cursor = state.cursor.min(state.len());
+
+        if state.is_empty() {
+            if let Some(placeholder) = &self.placeholder {
+                let style = self
+                    .style
+                    .patch(self.placeholder_style)
+                    .patch(placeholder.style);
+                buf.set_stringn(
+                    inner.x,
+                    inner.y,
+                    &placeholder.content,
+                    inner.width as usize,
+                    style,
+                );
+            }
+        }
+
+        let mask = self.mask.map(|c| c.to_string());
+        let graphemes: Vec<&str> = state
+            .value
+            .graphemes(true)
+            .map(|g| mask.as_deref().unwrap_or(g))
+            .collect();
+        let widths: Vec<u16> = graphemes.iter().map(|g| g.width() as u16).collect();
+
+        // keep the cursor visible, reserving one cell for the cursor itself
+        state.offset = state.offset.min(state.cursor);
+        while state.offset < state.cursor
+            && widths[state.offset..state.cursor].iter().sum::<u16>() >= inner.width
+        {
+            state.offset += 1;
+        }
+
+        let selection = state.selection().unwrap_or_default();
+        let mut x = inner.x;
+        for (i, (grapheme, width)) in graphemes.iter().zip(&widths).enumerate() {
+            if i < state.offset || *width == 0 {
+                continue;
+            }
+            if x + width > inner.right() {
+                break;
+            }
+            let style = if selection.contains(&i) {
+                self.style.patch(self.selection_style)
+            } else {
+                self.style
+            };
+            buf.set_stringn(x, inner.y, grapheme, *width as usize, style);
+            x += width;
+        }
+
+        let cursor_x = inner.x
+            + widths[state.offset..state.cursor]
+                .iter()
+                .sum::<u16>()
+                .min(inner.width - 1);
+        buf.set_style(Rect::new(cursor_x, inner.y, 1, 1), self.cursor_style);
+        state.cursor_position = Some(Position::new(cursor_x, inner.y));
+    }
+}
+
+impl Styled for Input<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn render(input: Input, state: &mut InputState, width: u16) -> Buffer {
+        let mut buf = Buffer::empty(Rect::new(0, 0, width, 1));
+        StatefulWidget::render(input, buf.area, &mut buf, state);
+        buf
+    }
+
+    #[test]
+    fn new_places_cursor_at_end() {
+        let state = InputState::new("héllo");
+        assert_eq!(state.cursor(), 5);
+        assert_eq!(state.len(), 5);
+    }
+
+    #[test]
+    fn insert_and_delete() {
+        let mut state = InputState::default();
+        state.insert_str("helo");
+        state.move_left();
+        state.insert_char('l');
+        assert_eq!(state.value(), "hello");
+        assert_eq!(state.cursor(), 4);
+
+        state.delete_backward();
+        assert_eq!(state.value(), "helo");
+        state.delete_forward();
+        assert_eq!(state.value(), "hel");
+        state.delete_forward();
+        assert_eq!(state.value(), "hel");
+    }
+
+    #[test]
+    fn insert_ignores_newlines() {
+        let mut state = InputState::default();
+        state.insert_str("a\nb\r\n");
+        assert_eq!(state.value(), "ab");
+    }
+
+    #[test]
+    fn grapheme_editing() {
+        let mut state = InputState::new("ae\u{301}o");
+        assert_eq!(state.len(), 3);
+        state.move_left();
+        state.delete_backward();
+        assert_eq!(state.value(), "ao");
+    }
+
+    #[rstest]
+    #[case::combining_accent("ab", 1, "\u{301}", "a\u{301}b", 1)]
+    #[case::zero_width_joiner("👩💻", 1, "\u{200d}", "👩\u{200d}💻", 1)]
+    #[case::joined_emoji("a", 1, "👩\u{200d}💻", "a👩\u{200d}💻", 2)]
+    fn insert_combining(
+        #[case] value: &str,
+        #[case] cursor: usize,
+        #[case] insert: &str,
+        #[case] expected_value: &str,
+        #[case] expected_cursor: usize,
+    ) {
+        let mut state = InputState::new(value).with_cursor(cursor);
+        state.insert_str(insert);
+        assert_eq!(state.value(), expected_value);
+        assert_eq!(state.cursor(), expected_cursor);
+    }
+
+    #[rstest]
+    #[case::word_left(InputState::move_word_left, 6)]
+    #[case::word_right(InputState::move_word_right, 11)]
+    #[case::home(InputState::move_home, 0)]
+    #[case::end(InputState::move_end, 11)]
+    fn movement(#[case] action: fn(&mut InputState), #[case] expected: usize) {
+        let mut state = InputState::new("hello world").with_cursor(8);
+        action(&mut state);
+        assert_eq!(state.cursor(), expected);
+    }
+
+    #[test]
+    fn delete_word_backward() {
+        let mut state = InputState::new("hello big world  ");
+        state.delete_word_backward();
+        assert_eq!(state.value(), "hello big ");
+    }
+
+    #[test]
+    fn selection() {
+        let mut state = InputState::new("hello world");
+        state.select_word_left();
+        assert_eq!(state.selection(), Some(6..11));
+        assert_eq!(state.selected_text(), Some("world"));
+
+        state.insert_str("there");
+        assert_eq!(state.value(), "hello there");
+        assert_eq!(state.selection(), None);
+
+        state.select_all();
+        state.delete_backward();
+        assert!(state.is_empty());
+    }
+
+    #[test]
+    fn moving_clears_selection() {
+        let mut state = InputState::new("hello");
+        state.select_left();
+        state.select_left();
+        assert_eq!(state.selection(), Some(3..5));
+        state.move_right();
+        assert_eq!(state.selection(), None);
+    }
+
+    #[test]
+    fn render_value() {
+        let mut state = InputState::new("hello");
+        let buf = render(Input::new(), &mut state, 8);
+        assert_eq!(buf, Buffer::with_lines(["hello   "]));
+        assert_eq!(state.cursor_position(), Some(Position::new(5, 0)));
+    }
+
+    #[test]
+    fn render_scrolls_to_cursor() {
+        let mut state = InputState::new("hello world");
+        let buf = render(Input::new(), &mut state, 6);
+        assert_eq!(buf, Buffer::with_lines(["world "]));
+        assert_eq!(state.offset(), 6);
+        assert_eq!(state.cursor_position(), Some(Position::new(5, 0)));
+
+        state.move_home();
+        let buf = render(Input::new(), &mut state, 6);
+        assert_eq!(buf, Buffer::with_lines(["hello "]));
+        assert_eq!(state.offset(), 0);
+    }
+
+    #[test]
+    fn render_wide_graphemes() {
+        let mut state = InputState::new("日本語");
+        let buf = render(Input::new(), &mut state, 5);
+        assert_eq!(buf, Buffer::with_lines(["本語 "]));
+        assert_eq!(state.cursor_position(), Some(Position::new(4, 0)));
+    }
+
+    #[test]
+    fn render_placeholder() {
+        let mut state = InputState::default();
+        let input = Input::new()
+            .placeholder("search")
+            .placeholder_style(Style::new().dark_gray());
+        let buf = render(input, &mut state, 8);
+        let mut expected = Buffer::with_lines(["search  "]);
+        expected.set_style(Rect::new(0, 0, 6, 1), Style::new().dark_gray());
+        assert_eq!(buf, expected);
+        assert_eq!(state.cursor_position(), Some(Position::new(0, 0)));
+    }
+
+    #[test]
+    fn render_mask() {
+        let mut state = InputState::new("secret");
+        let buf = render(Input::new().mask('*'), &mut state, 8);
+        assert_eq!(buf, Buffer::with_lines(["******  "]));
+    }
+
+    #[test]
+    fn render_selection_and_cursor() {
+        let mut state = InputState::new("abcd");
+        state.select_left();
+        state.select_left();
+        let input = Input::new()
+            .selection_style(Style::new().reversed())
+            .cursor_style(Style::new().underlined());
+        let buf = render(input, &mut state, 5);
+        let mut expected = Buffer::with_lines(["abcd "]);
+        expected.set_style(Rect::new(2, 0, 2, 1), Style::new().reversed());
+        expected.set_style(Rect::new(2, 0, 1, 1), Style::new().underlined());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_in_block() {
+        let mut state = InputState::new("hi");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 3));
+        Input::new()
+            .block(Block::bordered())
+            .render(buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["┌────┐", "│hi  │", "└────┘"]));
+        assert_eq!(state.cursor_position(), Some(Position::new(3, 1)));
+    }
+
+    #[test]
+    fn render_empty_area() {
+        let mut state = InputState::new("hi");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 0, 0));
+        Input::new().render(buf.area, &mut buf, &mut state);
+        assert_eq!(state.cursor_position(), None);
+    }
+}
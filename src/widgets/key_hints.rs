@@ -0,0 +1,360 @@
+use crate::prelude::*;
+
+/// A key binding displayed by the [`KeyHints`] widget
+///
+/// A hint is made of a key and a description of what the key does. Hints with a higher
+/// [`priority`](KeyHint::priority) are kept when the hints do not fit in the area.
+///
+/// Any `(key, description)` tuple of types that are convertible to [`Span`] can be converted into
+/// a hint.
+///
+/// # Example
+///
+/// ```rust
+/// # use ratatui::widgets::*;
+/// let quit = KeyHint::new("q", "quit").priority(10);
+/// let help = KeyHint::from(("?", "help"));
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct KeyHint<'a> {
+    key: Span<'a>,
+    description: Span<'a>,
+    priority: u16,
+}
+
+impl<'a> KeyHint<'a> {
+    /// Creates a new hint with the given key and description
+    pub fn new<K, D>(key: K, description: D) -> Self
+    where
+        K: Into<Span<'a>>,
+        D: Into<Span<'a>>,
+    {
+        Self {
+            key: key.into(),
+            description: description.into(),
+            priority: 0,
+        }
+    }
+
+    /// Sets the priority of the hint
+    ///
+    /// When the hints do not fit in the area, the hints with the lowest priority are hidden first,
+    /// starting with the last ones. The default priority is 0.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn priority(mut self, priority: u16) -> Self {
+        self.priority = priority;
+        self
+    }
+}
+
+impl<'a, K, D> From<(K, D)> for KeyHint<'a>
+where
+    K: Into<Span<'a>>,
+    D: Into<Span<'a>>,
+{
+    fn from((key, description): (K, D)) -> Self {
+        Self::new(key, description)
+    }
+}
+
+/// A widget that displays a bar of key bindings, e.g. at the bottom of the screen.
+///
+/// Each [`KeyHint`] is rendered as its key followed by its description, and the hints are
+/// separated by a divider (` | ` by default). When the area is more than one line high, the hints
+/// wrap to the next lines. When the hints do not fit in the area, the hints with the lowest
+/// [priority](KeyHint::priority) are hidden until the remaining ones fit.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{prelude::*, widgets::*};
+///
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// let hints = KeyHints::new([
+///     KeyHint::new("q", "quit").priority(10),
+///     KeyHint::new("?", "help").priority(5),
+///     KeyHint::new("↑↓", "move"),
+///     KeyHint::new("enter", "open"),
+/// ])
+/// .key_style(Style::new().bold().yellow());
+/// frame.render_widget(hints, area);
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct KeyHints<'a> {
+    hints: Vec<KeyHint<'a>>,
+    style: Style,
+    key_style: Style,
+    description_style: Style,
+    separator: Span<'a>,
+    key_separator: Span<'a>,
+    alignment: Alignment,
+}
+
+impl<'a> Default for KeyHints<'a> {
+    fn default() -> Self {
+        Self::new(Vec::<KeyHint<'a>>::new())
+    }
+}
+
+impl<'a> KeyHints<'a> {
+    /// Creates a new widget with the given hints
+    ///
+    /// `hints` accepts any iterator of items that are convertible to [`KeyHint`], such as
+    /// `(key, description)` tuples.
+    pub fn new<I>(hints: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<KeyHint<'a>>,
+    {
+        Self {
+            hints: hints.into_iter().map(Into::into).collect(),
+            style: Style::new(),
+            key_style: Style::new().bold(),
+            description_style: Style::new(),
+            separator: Span::raw(" | "),
+            key_separator: Span::raw(" "),
+            alignment: Alignment::Left,
+        }
+    }
+
+    /// Sets the style of the widget
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the keys, which are bold by default
+    ///
+    /// The style of the key of each hint is patched on top of this style.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn key_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.key_style = style.into();
+        self
+    }
+
+    /// Sets the style of the descriptions
+    ///
+    /// The style of the description of each hint is patched on top of this style.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn description_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.description_style = style.into();
+        self
+    }
+
+    /// Sets the separator rendered between two hints, which defaults to ` | `
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn separator<T>(mut self, separator: T) -> Self
+    where
+        T: Into<Span<'a>>,
+    {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Sets the separator rendered between the key and the description of a hint, which defaults
+    /// to a space
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::widgets::*;
+    /// let hints = KeyHints::new([("q", "quit")]).key_separator(": ");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn key_separator<T>(mut self, separator: T) -> Self
+    where
+        T: Into<Span<'a>>,
+    {
+        self.key_separator = separator.into();
+        self
+    }
+
+    /// Sets the alignment of the lines of hints
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Returns the number of lines needed to render all the hints in the given width
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::widgets::*;
+    /// let hints = KeyHints::new([("q", "quit"), ("?", "help")]);
+    /// assert_eq!(hints.line_count(20), 1);
+    /// assert_eq!(hints.line_count(10), 2);
+    /// ```
+    pub fn line_count(&self, width: u16) -> usize {
+        let visible: Vec<usize> = (0..self.hints.len()).collect();
+        self.wrap(&visible, width).len()
+    }
+
+    /// The width of a hint, excluding the separator
+    fn hint_width(&self, hint: &KeyHint) -> usize {
+        hint.key.width() + self.key_separator.width() + hint.description.width()
+    }
+
+    /// Splits the hints at the given indices into lines that fit in the given width
+    fn wrap(&self, visible: &[usize], width: u16) -> Vec<Vec<usize>> {
+        let width = usize::from(width);
+        let mut lines: Vec<Vec<usize>> = Vec::new();
+        let mut line_width = 0;
+        for &index in visible {
+            let hint_width = self.hint_width(&self.hints[index]);
+            match lines.last_mut() {
+                Some(line) if line_width + self.separator.width() + hint_width <= width => {
+                    line.push(index);
+                    line_width += self.separator.width() + hint_width;
+                }
+                _ => {
+                    lines.push(vec![index]);
+                    line_width = hint_width;
+                }
+            }
+        }
+        lines
+    }
+
+    /// Returns the lines of the hints that fit in the area, hiding the hints with the lowest
+    /// priority first
+    fn visible_lines(&self, area: Rect) -> Vec<Vec<usize>> {
+        let mut visible: Vec<usize> = (0..self.hints.len()).collect();
+        loop {
+            let lines = self.wrap(&visible, area.width);
+            if lines.len() <= usize::from(area.height) || visible.len() <= 1 {
+                return lines;
+            }
+            // the last hint with the lowest priority
+            let (position, _) = visible
+                .iter()
+                .enumerate()
+                .rev()
+                .min_by_key(|(_, &index)| self.hints[index].priority)
+                .expect("visible is not empty");
+            visible.remove(position);
+        }
+    }
+
+    fn line(&self, indices: &[usize]) -> Line<'a> {
+        let mut spans = Vec::with_capacity(indices.len() * 4);
+        for (i, &index) in indices.iter().enumerate() {
+            let hint = &self.hints[index];
+            if i > 0 {
+                spans.push(self.separator.clone());
+            }
+            spans.push(hint.key.clone().patch_style(self.key_style));
+            spans.push(self.key_separator.clone());
+            spans.push(hint.description.clone().patch_style(self.description_style));
+        }
+        Line::from(spans).alignment(self.alignment)
+    }
+}
+
+impl Widget for KeyHints<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_ref(area, buf);
+    }
+}
+
+impl WidgetRef for KeyHints<'_> {
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        buf.set_style(area, self.style);
+        let area = area.intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+        for (y, indices) in (area.top()..area.bottom()).zip(self.visible_lines(area)) {
+            self.line(&indices)
+                .render(Rect::new(area.x, y, area.width, 1), buf);
+        }
+    }
+}
+
+impl Styled for KeyHints<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn from_tuple() {
+        assert_eq!(KeyHint::from(("q", "quit")), KeyHint::new("q", "quit"));
+    }
+
+    /// Renders the hints without the default key style
+    fn render(hints: KeyHints, width: u16, height: u16) -> Buffer {
+        let mut buf = Buffer::empty(Rect::new(0, 0, width, height));
+        hints.key_style(Style::new()).render(buf.area, &mut buf);
+        buf
+    }
+
+    #[rstest]
+    #[case::one_line(30, 1, &["a add | d delete | q quit     "])]
+    #[case::wraps(16, 2, &["a add | d delete", "q quit          "])]
+    #[case::hides_last_hints(16, 1, &["a add | d delete"])]
+    fn render_wraps_and_hides_hints(
+        #[case] width: u16,
+        #[case] height: u16,
+        #[case] expected: &[&str],
+    ) {
+        let hints = KeyHints::new([("a", "add"), ("d", "delete"), ("q", "quit")]);
+        let buf = render(hints, width, height);
+        assert_eq!(buf, Buffer::with_lines(expected.iter().copied()));
+    }
+
+    #[test]
+    fn render_hides_lowest_priority_first() {
+        let hints = KeyHints::new([
+            KeyHint::new("a", "add"),
+            KeyHint::new("d", "delete").priority(1),
+            KeyHint::new("q", "quit").priority(2),
+        ]);
+        let buf = render(hints, 17, 1);
+        assert_eq!(buf, Buffer::with_lines(["d delete | q quit"]));
+        let hints = KeyHints::new([
+            KeyHint::new("a", "add").priority(1),
+            KeyHint::new("d", "delete"),
+            KeyHint::new("q", "quit").priority(1),
+        ]);
+        let buf = render(hints, 16, 1);
+        assert_eq!(buf, Buffer::with_lines(["a add | q quit  "]));
+    }
+
+    #[test]
+    fn render_styles() {
+        let hints = KeyHints::new([("q", "quit"), ("?", "help")])
+            .separator(" · ")
+            .key_separator(": ")
+            .description_style(Style::new().dim())
+            .alignment(Alignment::Right);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 20, 1));
+        hints.render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["   q: quit · ?: help"]);
+        expected.set_style(Rect::new(3, 0, 1, 1), Style::new().bold());
+        expected.set_style(Rect::new(6, 0, 4, 1), Style::new().dim());
+        expected.set_style(Rect::new(13, 0, 1, 1), Style::new().bold());
+        expected.set_style(Rect::new(16, 0, 4, 1), Style::new().dim());
+        assert_eq!(buf, expected);
+    }
+}
@@ -1,3 +1,10 @@
+use std::{
+    collections::BTreeSet,
+    hash::{Hash, Hasher},
+    ops::Range,
+    sync::Arc,
+};
+
 use strum::{Display, EnumString};
 use unicode_width::UnicodeWidthStr;
 
@@ -13,12 +20,23 @@ use crate::{
 /// that the selected item is visible. This will modify the [`ListState`] object passed to the
 /// [`Frame::render_stateful_widget`](crate::terminal::Frame::render_stateful_widget) method.
 ///
-/// The state consists of two fields:
+/// The state consists of four fields:
 /// - [`offset`]: the index of the first item to be displayed
+/// - [`row_offset`]: the number of rows of the first item that are scrolled out of view
 /// - [`selected`]: the index of the selected item, which can be `None` if no item is selected
+/// - [`selected_items`]: the indices of the items that are part of a multiple selection
 ///
 /// [`offset`]: ListState::offset()
+/// [`row_offset`]: ListState::row_offset()
 /// [`selected`]: ListState::selected()
+/// [`selected_items`]: ListState::selected_items()
+///
+/// After rendering, the range of the items that are visible can be queried with
+/// [`ListState::first_visible_index`] and [`ListState::last_visible_index`].
+///
+/// The selected item acts as a cursor, which is highlighted with [`List::highlight_style`], while
+/// the items of the multiple selection are styled with [`List::selection_style`]. Use
+/// [`ListState::toggle_selected`] to add or remove the item under the cursor from the selection.
 ///
 /// See the list in the [Examples] directory for a more in depth example of the various
 /// configuration options and for how to handle state.
@@ -43,11 +61,49 @@ use crate::{
 /// frame.render_stateful_widget(list, area, &mut state);
 /// # }
 /// ```
-#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[derive(Debug, Default, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ListState {
     offset: usize,
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "is_zero"))]
+    row_offset: usize,
     selected: Option<usize>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "BTreeSet::is_empty")
+    )]
+    selected_items: BTreeSet<usize>,
+    /// The items that were visible the last time the list was rendered
+    #[cfg_attr(feature = "serde", serde(skip))]
+    visible_items: Range<usize>,
+}
+
+/// List states are equal if they have the same offsets and selection, regardless of the items that
+/// were visible the last time the list was rendered
+impl PartialEq for ListState {
+    fn eq(&self, other: &Self) -> bool {
+        self.offset == other.offset
+            && self.row_offset == other.row_offset
+            && self.selected == other.selected
+            && self.selected_items == other.selected_items
+    }
+}
+
+impl Eq for ListState {}
+
+impl Hash for ListState {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.offset.hash(state);
+        self.row_offset.hash(state);
+        self.selected.hash(state);
+        self.selected_items.hash(state);
+    }
+}
+
+#[cfg(feature = "serde")]
+#[allow(clippy::trivially_copy_pass_by_ref)] // serde requires a reference
+const fn is_zero(value: &usize) -> bool {
+    *value == 0
 }
 
 impl ListState {
@@ -67,6 +123,22 @@ impl ListState {
         self
     }
 
+    /// Sets the number of rows of the first displayed item that are scrolled out of view
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let state = ListState::default().with_offset(1).with_row_offset(2);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_row_offset(mut self, row_offset: usize) -> Self {
+        self.row_offset = row_offset;
+        self
+    }
+
     /// Sets the index of the selected item
     ///
     /// This is a fluent setter method which must be chained or used as it consumes self
@@ -109,6 +181,67 @@ impl ListState {
         &mut self.offset
     }
 
+    /// Number of rows of the first displayed item that are scrolled out of view
+    ///
+    /// This allows items that are taller than the list to be scrolled row by row. The row offset
+    /// is clamped to the height of the first item minus one when rendering, and is reset to `0`
+    /// when the list scrolls to another item to show the selected item.
+    ///
+    /// For a [`ListDirection::BottomToTop`] list, the rows are scrolled out at the bottom of the
+    /// item.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let state = ListState::default();
+    /// assert_eq!(state.row_offset(), 0);
+    /// ```
+    pub const fn row_offset(&self) -> usize {
+        self.row_offset
+    }
+
+    /// Mutable reference to the number of rows of the first displayed item that are scrolled out
+    /// of view
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let mut state = ListState::default();
+    /// *state.row_offset_mut() += 1;
+    /// ```
+    pub fn row_offset_mut(&mut self) -> &mut usize {
+        &mut self.row_offset
+    }
+
+    /// Index of the first item that was visible the last time the list was rendered
+    ///
+    /// The item may only be partially visible. This is `0` before the list is rendered.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let mut buffer = Buffer::empty(Rect::new(0, 0, 6, 2));
+    /// let list = List::new(["Item 0", "Item 1", "Item 2", "Item 3"]);
+    /// let mut state = ListState::default().with_offset(1);
+    /// StatefulWidget::render(list, buffer.area, &mut buffer, &mut state);
+    /// assert_eq!(state.first_visible_index(), 1);
+    /// assert_eq!(state.last_visible_index(), Some(2));
+    /// ```
+    pub const fn first_visible_index(&self) -> usize {
+        self.visible_items.start
+    }
+
+    /// Index of the last item that was visible the last time the list was rendered
+    ///
+    /// The item may only be partially visible. This is `None` if no item was visible or before the
+    /// list is rendered.
+    pub fn last_visible_index(&self) -> Option<usize> {
+        (!self.visible_items.is_empty()).then(|| self.visible_items.end - 1)
+    }
+
     /// Index of the selected item
     ///
     /// Returns `None` if no item is selected
@@ -156,6 +289,118 @@ impl ListState {
             self.offset = 0;
         }
     }
+
+    /// Sets the indices of the items that are part of the multiple selection
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let state = ListState::default().with_selected_items([1, 3]);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_selected_items<T>(mut self, items: T) -> Self
+    where
+        T: IntoIterator<Item = usize>,
+    {
+        self.selected_items = items.into_iter().collect();
+        self
+    }
+
+    /// Indices of the items that are part of the multiple selection
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let state = ListState::default().with_selected_items([3, 1]);
+    /// assert_eq!(state.selected_items().iter().collect::<Vec<_>>(), [&1, &3]);
+    /// ```
+    pub const fn selected_items(&self) -> &BTreeSet<usize> {
+        &self.selected_items
+    }
+
+    /// Mutable reference to the indices of the items that are part of the multiple selection
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let mut state = ListState::default();
+    /// state.selected_items_mut().insert(2);
+    /// ```
+    pub fn selected_items_mut(&mut self) -> &mut BTreeSet<usize> {
+        &mut self.selected_items
+    }
+
+    /// Returns whether the item at `index` is part of the multiple selection
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let state = ListState::default().with_selected_items([1]);
+    /// assert!(state.is_item_selected(1));
+    /// assert!(!state.is_item_selected(2));
+    /// ```
+    pub fn is_item_selected(&self, index: usize) -> bool {
+        self.selected_items.contains(&index)
+    }
+
+    /// Adds the selected item to the multiple selection, or removes it if it is already part of it
+    ///
+    /// Does nothing if no item is selected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let mut state = ListState::default().with_selected(Some(1));
+    /// state.toggle_selected();
+    /// assert!(state.is_item_selected(1));
+    /// state.toggle_selected();
+    /// assert!(!state.is_item_selected(1));
+    /// ```
+    pub fn toggle_selected(&mut self) {
+        if let Some(index) = self.selected {
+            if !self.selected_items.remove(&index) {
+                self.selected_items.insert(index);
+            }
+        }
+    }
+
+    /// Adds all the items of a list of `len` items to the multiple selection
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let list = List::new(["Item 1", "Item 2"]);
+    /// let mut state = ListState::default();
+    /// state.select_all(list.len());
+    /// assert_eq!(state.selected_items().len(), 2);
+    /// ```
+    pub fn select_all(&mut self, len: usize) {
+        self.selected_items = (0..len).collect();
+    }
+
+    /// Removes all the items from the multiple selection
+    ///
+    /// The selected item is not changed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let mut state = ListState::default().with_selected_items([1, 2]);
+    /// state.clear_selection();
+    /// assert!(state.selected_items().is_empty());
+    /// ```
+    pub fn clear_selection(&mut self) {
+        self.selected_items.clear();
+    }
 }
 
 /// A single item in a [`List`]
@@ -432,6 +677,8 @@ pub struct List<'a> {
     direction: ListDirection,
     /// Style used to render selected item
     highlight_style: Style,
+    /// Style used to render the items of the multiple selection
+    selection_style: Style,
     /// Symbol in front of the selected item (Shift all items to the right)
     highlight_symbol: Option<&'a str>,
     /// Whether to repeat the highlight symbol for each line of the selected item
@@ -440,6 +687,64 @@ pub struct List<'a> {
     highlight_spacing: HighlightSpacing,
     /// How many items to try to keep visible before and after the selected item
     scroll_padding: usize,
+    /// Builds the items on demand instead of `items` (see [`List::items_with`])
+    item_provider: Option<ItemProvider<'a>>,
+}
+
+/// A function building the items of a [`List`] on demand
+#[derive(Clone)]
+struct ItemProvider<'a> {
+    len: usize,
+    item: Arc<dyn Fn(usize) -> ListItem<'a> + Send + Sync + 'a>,
+}
+
+impl ItemProvider<'_> {
+    /// The range of items that may be visible when rendering a list of the given height
+    ///
+    /// As every item is at least one row high, only the items around the offset and the selected
+    /// item need to be built.
+    fn window(&self, state: &ListState, height: usize, scroll_padding: usize) -> Range<usize> {
+        let last_index = self.len.saturating_sub(1);
+        let offset = state.offset.min(last_index);
+        let start = state.selected.map_or(offset, |selected| {
+            let selected = selected.min(last_index);
+            offset
+                .min(selected.saturating_sub(scroll_padding))
+                .max((selected + 1).saturating_sub(height))
+        });
+        let end = start
+            .saturating_add(height + scroll_padding + 1)
+            .min(self.len);
+        start..end
+    }
+
+    /// The address of the function, which identifies the provider
+    fn address(&self) -> *const () {
+        Arc::as_ptr(&self.item).cast()
+    }
+}
+
+impl std::fmt::Debug for ItemProvider<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ItemProvider")
+            .field("len", &self.len)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for ItemProvider<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && std::ptr::eq(self.address(), other.address())
+    }
+}
+
+impl Eq for ItemProvider<'_> {}
+
+impl Hash for ItemProvider<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len.hash(state);
+        self.address().hash(state);
+    }
 }
 
 /// Defines the direction in which the list will be rendered.
@@ -523,9 +828,46 @@ impl<'a> List<'a> {
         T::Item: Into<ListItem<'a>>,
     {
         self.items = items.into_iter().map(Into::into).collect();
+        self.item_provider = None;
         self
     }
 
+    /// Creates a new list of `len` items that are built on demand
+    ///
+    /// `item` is called with the index of an item when rendering, and only for the items that can
+    /// be visible in the rendered area. This avoids creating a [`ListItem`] for each element of
+    /// huge lists on every frame.
+    ///
+    /// The visible items are found assuming that every item is at least one row high.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let list = List::items_with(1_000_000, |index| ListItem::new(format!("Item {index}")));
+    /// assert_eq!(list.len(), 1_000_000);
+    /// ```
+    ///
+    /// The function can borrow the data of the application:
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let names = vec!["Alice".to_string(), "Bob".to_string()];
+    /// let list = List::items_with(names.len(), |index| ListItem::new(names[index].as_str()));
+    /// ```
+    pub fn items_with<F>(len: usize, item: F) -> Self
+    where
+        F: Fn(usize) -> ListItem<'a> + Send + Sync + 'a,
+    {
+        Self {
+            item_provider: Some(ItemProvider {
+                len,
+                item: Arc::new(item),
+            }),
+            ..Self::default()
+        }
+    }
+
     /// Wraps the list with a custom [`Block`] widget.
     ///
     /// The `block` parameter holds the specified [`Block`] to be created around the [`List`]
@@ -623,6 +965,32 @@ impl<'a> List<'a> {
         self
     }
 
+    /// Set the style of the items that are part of the multiple selection
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// The items of the multiple selection are given by [`ListState::selected_items`]. This style
+    /// is applied to the entire item and the [highlight style](List::highlight_style) is applied
+    /// on top of it when the item is also the selected item.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let items = ["Item 1", "Item 2"];
+    /// let list = List::new(items)
+    ///     .highlight_style(Style::new().reversed())
+    ///     .selection_style(Style::new().green());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn selection_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.selection_style = style.into();
+        self
+    }
+
     /// Set whether to repeat the highlight symbol and style over selected multi-line items
     ///
     /// This is `false` by default.
@@ -708,12 +1076,62 @@ impl<'a> List<'a> {
 
     /// Returns the number of [`ListItem`]s in the list
     pub fn len(&self) -> usize {
-        self.items.len()
+        self.item_provider
+            .as_ref()
+            .map_or(self.items.len(), |provider| provider.len)
     }
 
     /// Returns true if the list contains no elements.
     pub fn is_empty(&self) -> bool {
-        self.items.is_empty()
+        self.len() == 0
+    }
+
+    /// Renders the items of the provider that can be visible, as a list of these items only
+    fn render_provided_items(
+        &self,
+        provider: &ItemProvider<'a>,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut ListState,
+    ) {
+        let height = self.block.inner_if_some(area).height as usize;
+        if height == 0 {
+            // no item is visible, so none is built
+            let list = List {
+                items: Vec::new(),
+                item_provider: None,
+                ..self.clone()
+            };
+            StatefulWidgetRef::render_ref(&list, area, buf, state);
+            return;
+        }
+        let window = provider.window(state, height, self.scroll_padding);
+        let list = List {
+            items: window.clone().map(|index| (provider.item)(index)).collect(),
+            item_provider: None,
+            ..self.clone()
+        };
+        let start = window.start;
+        let mut window_state = ListState {
+            offset: state.offset.saturating_sub(start),
+            row_offset: if state.offset < start {
+                0
+            } else {
+                state.row_offset
+            },
+            selected: state.selected.map(|selected| selected - start),
+            selected_items: state
+                .selected_items
+                .range(window)
+                .map(|index| index - start)
+                .collect(),
+            visible_items: 0..0,
+        };
+        StatefulWidgetRef::render_ref(&list, area, buf, &mut window_state);
+        state.offset = window_state.offset + start;
+        state.row_offset = window_state.row_offset;
+        state.visible_items =
+            window_state.visible_items.start + start..window_state.visible_items.end + start;
     }
 
     /// Applies scroll padding to the selected index, reducing the padding value to keep the
@@ -762,6 +1180,62 @@ impl<'a> List<'a> {
         )
     }
 
+    fn render_item(
+        &self,
+        item: &ListItem,
+        area: Rect,
+        buf: &mut Buffer,
+        selection_spacing: bool,
+        is_selected: bool,
+        is_in_selection: bool,
+    ) {
+        // Get our set highlighted symbol (if one was set)
+        let highlight_symbol = self.highlight_symbol.unwrap_or("");
+        let blank_symbol = " ".repeat(highlight_symbol.width());
+
+        let item_style = self.style.patch(item.style);
+        buf.set_style(area, item_style);
+
+        let item_area = if selection_spacing {
+            let highlight_symbol_width = highlight_symbol.width() as u16;
+            Rect {
+                x: area.x + highlight_symbol_width,
+                width: area.width.saturating_sub(highlight_symbol_width),
+                ..area
+            }
+        } else {
+            area
+        };
+        item.content.clone().render(item_area, buf);
+
+        for j in 0..item.content.height() {
+            // if the item is selected, we need to display the highlight symbol:
+            // - either for the first line of the item only,
+            // - or for each line of the item if the appropriate option is set
+            let symbol = if is_selected && (j == 0 || self.repeat_highlight_symbol) {
+                highlight_symbol
+            } else {
+                &blank_symbol
+            };
+            if selection_spacing {
+                buf.set_stringn(
+                    area.x,
+                    area.y + j as u16,
+                    symbol,
+                    area.width as usize,
+                    item_style,
+                );
+            }
+        }
+
+        if is_in_selection {
+            buf.set_style(area, self.selection_style);
+        }
+        if is_selected {
+            buf.set_style(area, self.highlight_style);
+        }
+    }
+
     /// Given an offset, calculate which items can fit in a given area
     fn get_items_bounds(
         &self,
@@ -813,8 +1287,8 @@ impl<'a> List<'a> {
             last_visible_index += 1;
 
             // Now we need to hide previous items since we didn't have space
-            // for the selected/offset item
-            while height_from_offset > max_height {
+            // for the selected/offset item (which is kept even if it is taller than the area)
+            while height_from_offset > max_height && first_visible_index < index_to_display {
                 height_from_offset =
                     height_from_offset.saturating_sub(self.items[first_visible_index].height());
 
@@ -832,7 +1306,7 @@ impl<'a> List<'a> {
                 height_from_offset.saturating_add(self.items[first_visible_index].height());
 
             // Don't show an item if it is beyond our viewable height
-            while height_from_offset > max_height {
+            while height_from_offset > max_height && last_visible_index > index_to_display + 1 {
                 last_visible_index -= 1;
 
                 height_from_offset =
@@ -877,91 +1351,107 @@ impl StatefulWidgetRef for List<'_> {
     type State = ListState;
 
     fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        if let Some(provider) = &self.item_provider {
+            self.render_provided_items(provider, area, buf, state);
+            return;
+        }
+
         buf.set_style(area, self.style);
         self.block.render_ref(area, buf);
         let list_area = self.block.inner_if_some(area);
 
         if list_area.is_empty() || self.items.is_empty() {
+            state.visible_items = state.offset..state.offset;
             return;
         }
 
         let list_height = list_area.height as usize;
 
-        let (first_visible_index, last_visible_index) =
-            self.get_items_bounds(state.selected, state.offset, list_height);
+        // The rows of the first item that are scrolled out of view give more room to the next items
+        let offset = state.offset.min(self.items.len() - 1);
+        let mut row_offset = state
+            .row_offset
+            .min(self.items[offset].height().saturating_sub(1));
+        let (mut first_visible_index, mut last_visible_index) =
+            self.get_items_bounds(state.selected, offset, list_height + row_offset);
+        if first_visible_index != offset {
+            // the list scrolled to show the selected item, so the first item is fully shown
+            row_offset = 0;
+            (first_visible_index, last_visible_index) =
+                self.get_items_bounds(state.selected, offset, list_height);
+        }
 
         // Important: this changes the state's offset to be the beginning of the now viewable items
         state.offset = first_visible_index;
+        state.row_offset = row_offset;
+        state.visible_items = first_visible_index..last_visible_index;
 
-        // Get our set highlighted symbol (if one was set)
-        let highlight_symbol = self.highlight_symbol.unwrap_or("");
-        let blank_symbol = " ".repeat(highlight_symbol.width());
-
-        let mut current_height = 0;
         let selection_spacing = self.highlight_spacing.should_add(state.selected.is_some());
+        let mut current_height = 0;
         for (i, item) in self
             .items
             .iter()
             .enumerate()
-            .skip(state.offset)
-            .take(last_visible_index - first_visible_index)
+            .take(last_visible_index)
+            .skip(first_visible_index)
         {
-            let (x, y) = if self.direction == ListDirection::BottomToTop {
-                current_height += item.height() as u16;
-                (list_area.left(), list_area.bottom() - current_height)
+            let hidden_rows = if i == first_visible_index {
+                row_offset
             } else {
-                let pos = (list_area.left(), list_area.top() + current_height);
-                current_height += item.height() as u16;
-                pos
+                0
+            };
+            let height = (item.height() - hidden_rows).min(list_height - current_height);
+            let y = if self.direction == ListDirection::BottomToTop {
+                current_height += height;
+                list_area.bottom() - current_height as u16
+            } else {
+                let y = list_area.top() + current_height as u16;
+                current_height += height;
+                y
             };
-
             let row_area = Rect {
-                x,
                 y,
-                width: list_area.width,
-                height: item.height() as u16,
+                height: height as u16,
+                ..list_area
             };
 
-            let item_style = self.style.patch(item.style);
-            buf.set_style(row_area, item_style);
-
             let is_selected = state.selected.map_or(false, |s| s == i);
-
-            let item_area = if selection_spacing {
-                let highlight_symbol_width = self.highlight_symbol.unwrap_or("").width() as u16;
-                Rect {
-                    x: row_area.x + highlight_symbol_width,
-                    width: row_area.width.saturating_sub(highlight_symbol_width),
-                    ..row_area
-                }
+            let is_in_selection = state.selected_items.contains(&i);
+            if height == item.height() {
+                self.render_item(
+                    item,
+                    row_area,
+                    buf,
+                    selection_spacing,
+                    is_selected,
+                    is_in_selection,
+                );
             } else {
-                row_area
-            };
-            item.content.clone().render(item_area, buf);
-
-            for j in 0..item.content.height() {
-                // if the item is selected, we need to display the highlight symbol:
-                // - either for the first line of the item only,
-                // - or for each line of the item if the appropriate option is set
-                let symbol = if is_selected && (j == 0 || self.repeat_highlight_symbol) {
-                    highlight_symbol
+                // render the whole item aside and only copy the rows that are visible
+                let mut item_buf = Buffer::empty(Rect {
+                    y: 0,
+                    height: item.height() as u16,
+                    ..row_area
+                });
+                self.render_item(
+                    item,
+                    item_buf.area,
+                    &mut item_buf,
+                    selection_spacing,
+                    is_selected,
+                    is_in_selection,
+                );
+                let first_row = if self.direction == ListDirection::BottomToTop {
+                    item.height() - hidden_rows - height
                 } else {
-                    &blank_symbol
+                    hidden_rows
                 };
-                if selection_spacing {
-                    buf.set_stringn(
-                        x,
-                        y + j as u16,
-                        symbol,
-                        list_area.width as usize,
-                        item_style,
-                    );
+                for (row, y) in (first_row as u16..).zip(row_area.rows().map(|row| row.y)) {
+                    for x in row_area.left()..row_area.right() {
+                        *buf.get_mut(x, y) = item_buf.get(x, row).clone();
+                    }
                 }
             }
-
-            if is_selected {
-                buf.set_style(row_area, self.highlight_style);
-            }
         }
     }
 }
@@ -1035,6 +1525,41 @@ mod tests {
         assert_eq!(state.offset, 0);
     }
 
+    #[test]
+    fn test_list_state_eq_ignores_visible_items() {
+        let state = ListState::default().with_selected(Some(1));
+        let mut rendered = state.clone();
+        rendered.visible_items = 0..3;
+        assert_eq!(state, rendered);
+
+        let hash = |state: &ListState| {
+            let mut hasher = std::collections::hash_map::DefaultHasher::new();
+            state.hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash(&state), hash(&rendered));
+        assert_ne!(state, rendered.with_offset(1));
+    }
+
+    #[test]
+    fn test_list_state_multiple_selection() {
+        let mut state = ListState::default();
+        state.toggle_selected();
+        assert!(state.selected_items().is_empty());
+
+        state.select(Some(2));
+        state.toggle_selected();
+        assert!(state.is_item_selected(2));
+        state.toggle_selected();
+        assert!(!state.is_item_selected(2));
+
+        state.select_all(3);
+        assert_eq!(state.selected_items, BTreeSet::from([0, 1, 2]));
+        state.clear_selection();
+        assert!(state.selected_items().is_empty());
+        assert_eq!(state.selected(), Some(2));
+    }
+
     #[test]
     fn test_list_item_new_from_str() {
         let item = ListItem::new("Test item");
@@ -1497,6 +2022,24 @@ mod tests {
         assert_eq!(buffer, expected);
     }
 
+    #[test]
+    fn test_list_selection_style() {
+        let list = List::new(["Item 0", "Item 1", "Item 2"])
+            .highlight_symbol(">>")
+            .highlight_style(Style::new().reversed())
+            .selection_style(Style::new().green());
+        let mut state = ListState::default()
+            .with_selected(Some(1))
+            .with_selected_items([0, 1]);
+        let buffer = render_stateful_widget(list, &mut state, 10, 3);
+        let expected = Buffer::with_lines([
+            "  Item 0  ".green(),
+            ">>Item 1  ".green().reversed(),
+            "  Item 2  ".into(),
+        ]);
+        assert_eq!(buffer, expected);
+    }
+
     #[test]
     fn test_list_highlight_spacing_default_whenselected() {
         // when not selected
@@ -1674,6 +2217,109 @@ mod tests {
         assert_eq!(buffer, expected);
     }
 
+    #[test]
+    fn test_list_row_offset_renders_partial_item() {
+        let list = List::new([
+            Text::from(vec!["A0".into(), "A1".into(), "A2".into()]),
+            Text::from(vec!["B0".into(), "B1".into()]),
+            Text::from("C0"),
+        ]);
+        let mut state = ListState::default().with_row_offset(2);
+        let buffer = render_stateful_widget(list.clone(), &mut state, 2, 4);
+        assert_eq!(buffer, Buffer::with_lines(["A2", "B0", "B1", "C0"]));
+        assert_eq!(state.first_visible_index(), 0);
+        assert_eq!(state.last_visible_index(), Some(2));
+
+        let list = list.direction(ListDirection::BottomToTop);
+        let mut state = ListState::default().with_row_offset(1);
+        let buffer = render_stateful_widget(list, &mut state, 2, 4);
+        assert_eq!(buffer, Buffer::with_lines(["B0", "B1", "A0", "A1"]));
+    }
+
+    #[test]
+    fn test_list_row_offset_scrolls_tall_item() {
+        let list = List::new([
+            Text::from(vec!["A0".into(), "A1".into(), "A2".into(), "A3".into()]),
+            Text::from("B0"),
+        ])
+        .block(Block::bordered());
+        let mut state = ListState::default().with_row_offset(1);
+        let buffer = render_stateful_widget(list.clone(), &mut state, 4, 4);
+        assert_eq!(buffer, Buffer::with_lines(["┌──┐", "│A1│", "│A2│", "└──┘"]));
+        assert_eq!(state.last_visible_index(), Some(0));
+
+        // the row offset is clamped to the height of the item
+        let mut state = ListState::default().with_row_offset(10);
+        let buffer = render_stateful_widget(list, &mut state, 4, 4);
+        assert_eq!(buffer, Buffer::with_lines(["┌──┐", "│A3│", "│B0│", "└──┘"]));
+        assert_eq!(state.row_offset(), 3);
+    }
+
+    #[test]
+    fn test_list_row_offset_reset_when_scrolling_to_selected() {
+        let list = List::new([
+            Text::from(vec!["A0".into(), "A1".into()]),
+            Text::from("B0"),
+            Text::from("C0"),
+            Text::from("D0"),
+        ]);
+        let mut state = ListState::default()
+            .with_row_offset(1)
+            .with_selected(Some(3));
+        let buffer = render_stateful_widget(list, &mut state, 2, 2);
+        assert_eq!(buffer, Buffer::with_lines(["C0", "D0"]));
+        assert_eq!(state.offset(), 2);
+        assert_eq!(state.row_offset(), 0);
+        assert_eq!(state.first_visible_index(), 2);
+        assert_eq!(state.last_visible_index(), Some(3));
+    }
+
+    #[test]
+    fn test_list_items_with_builds_visible_items() {
+        let built = std::sync::Mutex::new(Vec::new());
+        let list = List::items_with(1_000_000, |index| {
+            built.lock().unwrap().push(index);
+            ListItem::new(format!("Item {index}"))
+        })
+        .highlight_symbol(">>");
+        assert_eq!(list.len(), 1_000_000);
+
+        let mut state = ListState::default().with_offset(10);
+        let buffer = render_stateful_widget(list.clone(), &mut state, 7, 2);
+        assert_eq!(buffer, Buffer::with_lines(["Item 10", "Item 11"]));
+        assert_eq!(state.first_visible_index(), 10);
+        assert_eq!(state.last_visible_index(), Some(11));
+        assert!(built
+            .lock()
+            .unwrap()
+            .iter()
+            .all(|index| (10..13).contains(index)));
+
+        let mut state = ListState::default().with_selected(Some(500_000));
+        let buffer = render_stateful_widget(list, &mut state, 13, 2);
+        assert_eq!(
+            buffer,
+            Buffer::with_lines(["  Item 499999", ">>Item 500000"])
+        );
+        assert_eq!(state.offset(), 499_999);
+    }
+
+    #[rstest]
+    #[case::empty_area(List::items_with(5, |_| unreachable!()), Rect::new(0, 0, 10, 0))]
+    #[case::empty_block(
+        List::items_with(5, |_| unreachable!()).block(Block::bordered()),
+        Rect::new(0, 0, 10, 2)
+    )]
+    fn test_list_items_with_empty_inner_area(#[case] list: List<'static>, #[case] area: Rect) {
+        let mut buffer = Buffer::empty(area);
+        let mut state = ListState::default().with_selected(Some(3)).with_offset(1);
+        StatefulWidget::render(list, area, &mut buffer, &mut state);
+        assert_eq!(state.selected(), Some(3));
+        assert_eq!(state.offset(), 1);
+        assert_eq!(state.first_visible_index(), 1);
+        assert_eq!(state.last_visible_index(), None);
+    }
+
     #[rstest]
     #[case(None, [
         "Item 0 with a v",
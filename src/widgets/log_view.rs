@@ -0,0 +1,790 @@
+use std::{
+    collections::VecDeque,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use strum::{Display, EnumString};
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    prelude::*,
+    text::StyledGrapheme,
+    widgets::{Block, StatefulWidgetRef},
+};
+
+/// The severity of a [`LogRecord`], from the most to the least severe
+///
+/// The levels are ordered like the levels of the `log` crate: [`LogLevel::Error`] is the lowest
+/// level and [`LogLevel::Trace`] the highest, so a [level filter](LogViewState::set_level_filter)
+/// shows the records whose level is lower or equal to it.
+#[derive(Debug, Display, EnumString, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum LogLevel {
+    /// An error
+    Error,
+    /// A potential problem
+    Warn,
+    /// Useful information
+    Info,
+    /// Lower priority information, for debugging
+    Debug,
+    /// Very low priority, often extremely verbose, information
+    Trace,
+}
+
+impl LogLevel {
+    /// The label of the level, padded to the width of the longest label
+    const fn label(self) -> &'static str {
+        match self {
+            Self::Error => "ERROR",
+            Self::Warn => "WARN ",
+            Self::Info => "INFO ",
+            Self::Debug => "DEBUG",
+            Self::Trace => "TRACE",
+        }
+    }
+
+    /// The default style of the label of the level
+    const fn style(self) -> Style {
+        match self {
+            Self::Error => Style::new().fg(Color::Red),
+            Self::Warn => Style::new().fg(Color::Yellow),
+            Self::Info => Style::new().fg(Color::Green),
+            Self::Debug => Style::new().fg(Color::Blue),
+            Self::Trace => Style::new().fg(Color::DarkGray),
+        }
+    }
+}
+
+/// A log record displayed by the [`LogView`] widget
+///
+/// # Example
+///
+/// ```rust
+/// # use ratatui::widgets::*;
+/// let record = LogRecord::new(LogLevel::Warn, "disk almost full").target("storage");
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct LogRecord {
+    level: LogLevel,
+    target: String,
+    message: String,
+    timestamp: SystemTime,
+}
+
+impl LogRecord {
+    /// Creates a new record with the given level and message, timestamped now
+    pub fn new<T: Into<String>>(level: LogLevel, message: T) -> Self {
+        Self {
+            level,
+            target: String::new(),
+            message: message.into(),
+            timestamp: SystemTime::now(),
+        }
+    }
+
+    /// Sets the target of the record, usually the module that emitted it
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn target<T: Into<String>>(mut self, target: T) -> Self {
+        self.target = target.into();
+        self
+    }
+
+    /// Sets the time at which the record was emitted
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn timestamp(mut self, timestamp: SystemTime) -> Self {
+        self.timestamp = timestamp;
+        self
+    }
+
+    /// The level of the record
+    pub const fn level(&self) -> LogLevel {
+        self.level
+    }
+
+    /// The message of the record
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+/// State of the [`LogView`] widget
+///
+/// The state holds the latest records, up to a fixed capacity after which the oldest records are
+/// dropped, and the interactive settings of the view: the level filter, the search query whose
+/// matches are highlighted, whether long records wrap, and the scroll position.
+///
+/// In follow mode, which is the default, the view always shows the latest records. Scrolling up
+/// leaves follow mode, and scrolling back to the bottom enters it again.
+///
+/// # Example
+///
+/// ```rust
+/// # use ratatui::widgets::*;
+/// let mut state = LogViewState::new(1000);
+/// state.push(LogRecord::new(LogLevel::Info, "server started"));
+/// state.set_level_filter(LogLevel::Warn);
+/// state.set_search(Some("timeout"));
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct LogViewState {
+    records: VecDeque<LogRecord>,
+    capacity: usize,
+    level_filter: LogLevel,
+    search: Option<String>,
+    wrap: bool,
+    follow: bool,
+    /// The index of the first visible row
+    offset: usize,
+}
+
+impl Default for LogViewState {
+    fn default() -> Self {
+        Self::new(1000)
+    }
+}
+
+impl LogViewState {
+    /// Creates an empty state that holds at most `capacity` records
+    pub const fn new(capacity: usize) -> Self {
+        Self {
+            records: VecDeque::new(),
+            capacity,
+            level_filter: LogLevel::Trace,
+            search: None,
+            wrap: false,
+            follow: true,
+            offset: 0,
+        }
+    }
+
+    /// Adds a record, dropping the oldest record if the state is full
+    pub fn push(&mut self, record: LogRecord) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.records.len() == self.capacity {
+            self.records.pop_front();
+            // keep the same records visible when not following the tail
+            self.offset = self.offset.saturating_sub(1);
+        }
+        self.records.push_back(record);
+    }
+
+    /// The records held by the state, from the oldest to the latest, including the filtered ones
+    pub fn records(&self) -> impl DoubleEndedIterator<Item = &LogRecord> + ExactSizeIterator {
+        self.records.iter()
+    }
+
+    /// The number of records held by the state, including the filtered ones
+    pub fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Whether the state holds no records
+    pub fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Removes all the records
+    pub fn clear(&mut self) {
+        self.records.clear();
+        self.offset = 0;
+    }
+
+    /// The most verbose level displayed
+    pub const fn level_filter(&self) -> LogLevel {
+        self.level_filter
+    }
+
+    /// Only displays the records whose level is lower or equal to the given level, e.g.
+    /// [`LogLevel::Warn`] displays the warnings and errors
+    pub fn set_level_filter(&mut self, level: LogLevel) {
+        self.level_filter = level;
+    }
+
+    /// The search query whose matches are highlighted, if any
+    pub fn search(&self) -> Option<&str> {
+        self.search.as_deref()
+    }
+
+    /// Sets the search query whose matches are highlighted in the messages, or clears it with
+    /// `None`
+    ///
+    /// The search is case sensitive.
+    pub fn set_search<T: Into<String>>(&mut self, search: Option<T>) {
+        self.search = search.map(Into::into).filter(|search| !search.is_empty());
+    }
+
+    /// Whether long records wrap onto several rows instead of being truncated
+    pub const fn wrap(&self) -> bool {
+        self.wrap
+    }
+
+    /// Sets whether long records wrap onto several rows instead of being truncated
+    pub fn set_wrap(&mut self, wrap: bool) {
+        self.wrap = wrap;
+    }
+
+    /// Toggles whether long records wrap onto several rows
+    pub fn toggle_wrap(&mut self) {
+        self.wrap = !self.wrap;
+    }
+
+    /// Whether the view follows the latest records
+    pub const fn follow(&self) -> bool {
+        self.follow
+    }
+
+    /// Sets whether the view follows the latest records
+    pub fn set_follow(&mut self, follow: bool) {
+        self.follow = follow;
+    }
+
+    /// The index of the first visible row
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Scrolls up by the given number of rows, leaving follow mode
+    pub fn scroll_up(&mut self, rows: usize) {
+        self.follow = false;
+        self.offset = self.offset.saturating_sub(rows);
+    }
+
+    /// Scrolls down by the given number of rows
+    ///
+    /// Follow mode is entered again on the next render if the last row becomes visible.
+    pub fn scroll_down(&mut self, rows: usize) {
+        self.offset = self.offset.saturating_add(rows);
+    }
+
+    /// Scrolls to the first record, leaving follow mode
+    pub fn scroll_to_top(&mut self) {
+        self.follow = false;
+        self.offset = 0;
+    }
+
+    /// Scrolls to the latest record and enters follow mode
+    pub fn scroll_to_bottom(&mut self) {
+        self.follow = true;
+    }
+
+    /// The records that pass the level filter
+    fn visible_records(&self) -> impl Iterator<Item = &LogRecord> {
+        self.records
+            .iter()
+            .filter(|record| record.level <= self.level_filter)
+    }
+}
+
+impl Extend<LogRecord> for LogViewState {
+    fn extend<T: IntoIterator<Item = LogRecord>>(&mut self, iter: T) {
+        for record in iter {
+            self.push(record);
+        }
+    }
+}
+
+/// A widget that displays log records, with level filtering, search highlighting and a follow
+/// mode
+///
+/// Each record is displayed on a row with its timestamp (in UTC), its level, its target and its
+/// message. The records are held by a [`LogViewState`], which also holds the interactive settings
+/// of the view, see its documentation for more details.
+///
+/// With the `log` feature, a [`LogViewLogger`] can be installed as the logger of the `log` crate
+/// to feed the state.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{prelude::*, widgets::*};
+///
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// // This should be stored outside of the function in your application state.
+/// let mut state = LogViewState::new(1000);
+///
+/// state.push(LogRecord::new(LogLevel::Error, "connection lost").target("net"));
+/// let log_view = LogView::default()
+///     .block(Block::bordered().title("Logs"))
+///     .highlight_style(Style::new().black().on_yellow());
+/// frame.render_stateful_widget(log_view, area, &mut state);
+/// # }
+/// ```
+///
+/// [`LogViewLogger`]: crate::widgets::LogViewLogger
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct LogView<'a> {
+    block: Option<Block<'a>>,
+    style: Style,
+    highlight_style: Style,
+    show_timestamp: bool,
+    show_target: bool,
+}
+
+impl Default for LogView<'_> {
+    fn default() -> Self {
+        Self {
+            block: None,
+            style: Style::new(),
+            highlight_style: Style::new().add_modifier(Modifier::REVERSED),
+            show_timestamp: true,
+            show_target: true,
+        }
+    }
+}
+
+impl<'a> LogView<'a> {
+    /// Wraps the view in a [`Block`]
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the style of the view
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the matches of the [search query](LogViewState::set_search), which are
+    /// reversed by default
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.highlight_style = style.into();
+        self
+    }
+
+    /// Sets whether the timestamp of the records is displayed, which is the default
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn show_timestamp(mut self, show: bool) -> Self {
+        self.show_timestamp = show;
+        self
+    }
+
+    /// Sets whether the target of the records is displayed, which is the default
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn show_target(mut self, show: bool) -> Self {
+        self.show_target = show;
+        self
+    }
+
+    /// The line displaying a record
+    fn line<'b>(&self, record: &'b LogRecord, search: Option<&str>) -> Line<'b> {
+        let mut spans = Vec::new();
+        if self.show_timestamp {
+            spans.push(Span::styled(
+                format!("{} ", format_time(record.timestamp)),
+                Style::new().add_modifier(Modifier::DIM),
+            ));
+        }
+        spans.push(Span::styled(record.level.label(), record.level.style()));
+        spans.push(Span::raw(" "));
+        if self.show_target && !record.target.is_empty() {
+            spans.push(Span::styled(
+                format!("{} ", record.target),
+                Style::new().add_modifier(Modifier::DIM),
+            ));
+        }
+        let message = record.message.as_str();
+        let mut start = 0;
+        if let Some(search) = search {
+            for (index, matched) in message.match_indices(search) {
+                if index > start {
+                    spans.push(Span::raw(&message[start..index]));
+                }
+                spans.push(Span::styled(matched, self.highlight_style));
+                start = index + matched.len();
+            }
+        }
+        if start < message.len() {
+            spans.push(Span::raw(&message[start..]));
+        }
+        Line::from(spans)
+    }
+}
+
+/// Splits the graphemes of a line into rows of at most `width` columns
+fn wrap_graphemes<'a>(line: &'a Line<'a>, width: u16) -> Vec<Vec<StyledGrapheme<'a>>> {
+    let width = usize::from(width);
+    let mut rows = vec![Vec::new()];
+    let mut row_width = 0;
+    for grapheme in line.styled_graphemes(Style::new()) {
+        let grapheme_width = grapheme.symbol.width();
+        if row_width + grapheme_width > width && row_width > 0 {
+            rows.push(Vec::new());
+            row_width = 0;
+        }
+        row_width += grapheme_width;
+        rows.last_mut().expect("rows is not empty").push(grapheme);
+    }
+    rows
+}
+
+/// Formats the time of day of a timestamp in UTC, as `HH:MM:SS`
+fn format_time(timestamp: SystemTime) -> String {
+    let seconds = timestamp
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let (hours, minutes, seconds) = (seconds / 3600 % 24, seconds / 60 % 60, seconds % 60);
+    format!("{hours:02}:{minutes:02}:{seconds:02}")
+}
+
+impl StatefulWidget for LogView<'_> {
+    type State = LogViewState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        self.render_ref(area, buf, state);
+    }
+}
+
+// Note: remove this when StatefulWidgetRef is stabilized and replace with the blanket impl
+impl StatefulWidget for &LogView<'_> {
+    type State = LogViewState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        self.render_ref(area, buf, state);
+    }
+}
+
+impl StatefulWidgetRef for LogView<'_> {
+    type State = LogViewState;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        self.block.render_ref(area, buf);
+        let area = self.block.inner_if_some(area).intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+
+        let lines: Vec<Line> = state
+            .visible_records()
+            .map(|record| self.line(record, state.search()))
+            .collect();
+        let rows: Vec<Vec<StyledGrapheme>> = if state.wrap {
+            lines
+                .iter()
+                .flat_map(|line| wrap_graphemes(line, area.width))
+                .collect()
+        } else {
+            lines
+                .iter()
+                .map(|line| line.styled_graphemes(Style::new()).collect())
+                .collect()
+        };
+
+        let max_offset = rows.len().saturating_sub(usize::from(area.height));
+        let follow = state.follow || state.offset >= max_offset;
+        let offset = if follow { max_offset } else { state.offset };
+
+        for (y, row) in (area.top()..area.bottom()).zip(rows.iter().skip(offset)) {
+            let mut x = area.left();
+            for grapheme in row {
+                let width = grapheme.symbol.width() as u16;
+                if x + width > area.right() {
+                    break;
+                }
+                buf.get_mut(x, y)
+                    .set_symbol(grapheme.symbol)
+                    .set_style(grapheme.style);
+                x += width.max(1);
+            }
+        }
+        state.follow = follow;
+        state.offset = offset;
+    }
+}
+
+impl Styled for LogView<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(feature = "log")]
+pub use self::logger::LogViewLogger;
+
+#[cfg(feature = "log")]
+mod logger {
+    use std::sync::{Arc, Mutex, PoisonError};
+
+    use super::{LogLevel, LogRecord, LogViewState};
+
+    impl From<log::Level> for LogLevel {
+        fn from(level: log::Level) -> Self {
+            match level {
+                log::Level::Error => Self::Error,
+                log::Level::Warn => Self::Warn,
+                log::Level::Info => Self::Info,
+                log::Level::Debug => Self::Debug,
+                log::Level::Trace => Self::Trace,
+            }
+        }
+    }
+
+    impl From<&log::Record<'_>> for LogRecord {
+        fn from(record: &log::Record<'_>) -> Self {
+            Self::new(record.level().into(), record.args().to_string()).target(record.target())
+        }
+    }
+
+    /// A logger for the `log` crate that collects the records to display in a
+    /// [`LogView`](super::LogView)
+    ///
+    /// The logger buffers the records until they are moved into a [`LogViewState`] with
+    /// [`LogViewLogger::drain_into`], usually before each frame. Clones of the logger share the
+    /// same buffer, so a clone can be installed as the global logger while the application keeps
+    /// the other one.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use ratatui::{prelude::*, widgets::*};
+    ///
+    /// let logger = LogViewLogger::new(log::LevelFilter::Debug);
+    /// logger.clone().init().unwrap();
+    ///
+    /// let mut state = LogViewState::default();
+    /// log::info!("application started");
+    ///
+    /// // before each frame
+    /// logger.drain_into(&mut state);
+    /// ```
+    ///
+    /// This requires the `log` feature.
+    #[derive(Debug, Clone)]
+    pub struct LogViewLogger {
+        level: log::LevelFilter,
+        records: Arc<Mutex<Vec<LogRecord>>>,
+    }
+
+    impl LogViewLogger {
+        /// Creates a logger that collects the records up to the given level
+        pub fn new(level: log::LevelFilter) -> Self {
+            Self {
+                level,
+                records: Arc::default(),
+            }
+        }
+
+        /// Installs this logger as the global logger of the `log` crate and sets the maximum
+        /// level of the `log` crate to the level of the logger
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if a global logger was already installed.
+        pub fn init(self) -> Result<(), log::SetLoggerError> {
+            let level = self.level;
+            log::set_boxed_logger(Box::new(self))?;
+            log::set_max_level(level);
+            Ok(())
+        }
+
+        /// Moves the records collected since the last call into the given state
+        pub fn drain_into(&self, state: &mut LogViewState) {
+            let mut records = self.records.lock().unwrap_or_else(PoisonError::into_inner);
+            state.extend(records.drain(..));
+        }
+    }
+
+    impl log::Log for LogViewLogger {
+        fn enabled(&self, metadata: &log::Metadata) -> bool {
+            metadata.level() <= self.level
+        }
+
+        fn log(&self, record: &log::Record) {
+            if self.enabled(record.metadata()) {
+                self.records
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .push(record.into());
+            }
+        }
+
+        fn flush(&self) {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn record(level: LogLevel, message: &str) -> LogRecord {
+        LogRecord::new(level, message).timestamp(UNIX_EPOCH + Duration::from_secs(3723))
+    }
+
+    fn state() -> LogViewState {
+        let mut state = LogViewState::new(10);
+        state.extend([
+            record(LogLevel::Info, "started"),
+            record(LogLevel::Debug, "config loaded"),
+            record(LogLevel::Error, "connection lost").target("net"),
+        ]);
+        state
+    }
+
+    fn render(view: LogView, state: &mut LogViewState, width: u16, height: u16) -> Buffer {
+        let mut buf = Buffer::empty(Rect::new(0, 0, width, height));
+        view.render(buf.area, &mut buf, state);
+        buf
+    }
+
+    /// A view without timestamps and styles, to test the layout of the records
+    fn plain_view() -> LogView<'static> {
+        LogView::default()
+            .show_timestamp(false)
+            .highlight_style(Style::new())
+    }
+
+    fn unstyled(mut buf: Buffer) -> Buffer {
+        buf.set_style(buf.area, Style::reset());
+        buf
+    }
+
+    #[test]
+    fn state_drops_oldest_records() {
+        let mut state = LogViewState::new(2);
+        state.extend(["a", "b", "c"].map(|message| record(LogLevel::Info, message)));
+        let messages: Vec<_> = state.records().map(LogRecord::message).collect();
+        assert_eq!(messages, ["b", "c"]);
+    }
+
+    #[test]
+    fn level_order() {
+        assert!(LogLevel::Error < LogLevel::Warn);
+        assert!(LogLevel::Debug < LogLevel::Trace);
+        assert_eq!("Warn".parse::<LogLevel>(), Ok(LogLevel::Warn));
+    }
+
+    #[test]
+    fn render_record() {
+        let mut state = LogViewState::new(10);
+        state.push(record(LogLevel::Warn, "disk full").target("fs"));
+        let buf = render(LogView::default(), &mut state, 27, 1);
+        let mut expected = Buffer::with_lines(["01:02:03 WARN  fs disk full"]);
+        expected.set_style(Rect::new(0, 0, 9, 1), Style::new().dim());
+        expected.set_style(Rect::new(9, 0, 5, 1), Style::new().yellow());
+        expected.set_style(Rect::new(15, 0, 3, 1), Style::new().dim());
+        assert_eq!(buf, expected);
+    }
+
+    #[rstest]
+    #[case::all(LogLevel::Trace, ["INFO  started", "DEBUG config loaded", "ERROR net connection lost"])]
+    #[case::errors(LogLevel::Error, ["ERROR net connection lost", "", ""])]
+    fn render_level_filter(#[case] level: LogLevel, #[case] expected: [&str; 3]) {
+        let mut state = state();
+        state.set_level_filter(level);
+        let buf = render(plain_view(), &mut state, 25, 3);
+        assert_eq!(unstyled(buf), unstyled(Buffer::with_lines(expected)));
+    }
+
+    #[test]
+    fn render_follows_tail() {
+        let mut state = state();
+        let buf = render(plain_view(), &mut state, 25, 2);
+        assert_eq!(
+            unstyled(buf),
+            unstyled(Buffer::with_lines([
+                "DEBUG config loaded",
+                "ERROR net connection lost"
+            ]))
+        );
+        assert_eq!(state.offset(), 1);
+
+        state.scroll_up(1);
+        assert!(!state.follow());
+        state.push(record(LogLevel::Info, "stopped"));
+        let buf = render(plain_view(), &mut state, 25, 2);
+        assert_eq!(
+            unstyled(buf),
+            unstyled(Buffer::with_lines([
+                "INFO  started            ",
+                "DEBUG config loaded      "
+            ]))
+        );
+
+        state.scroll_down(2);
+        render(plain_view(), &mut state, 25, 2);
+        assert!(state.follow());
+        assert_eq!(state.offset(), 2);
+    }
+
+    #[test]
+    fn render_wrap() {
+        let mut state = state();
+        state.set_level_filter(LogLevel::Error);
+        state.toggle_wrap();
+        let buf = render(plain_view(), &mut state, 10, 3);
+        assert_eq!(
+            unstyled(buf),
+            unstyled(Buffer::with_lines(["ERROR net ", "connection", " lost"]))
+        );
+    }
+
+    #[test]
+    fn render_search() {
+        let mut state = LogViewState::new(10);
+        state.push(record(LogLevel::Info, "a ab b ab"));
+        state.set_search(Some("ab"));
+        let view = LogView::default()
+            .show_timestamp(false)
+            .highlight_style(Style::new().red());
+        let buf = render(view, &mut state, 15, 1);
+        let mut expected = Buffer::with_lines(["INFO  a ab b ab"]);
+        expected.set_style(Rect::new(0, 0, 5, 1), Style::new().green());
+        expected.set_style(Rect::new(8, 0, 2, 1), Style::new().red());
+        expected.set_style(Rect::new(13, 0, 2, 1), Style::new().red());
+        assert_eq!(buf, expected);
+    }
+
+    #[cfg(feature = "log")]
+    #[test]
+    fn logger() {
+        use log::Log;
+
+        let logger = LogViewLogger::new(log::LevelFilter::Info);
+        let args = format_args!("hello");
+        let info = log::Record::builder()
+            .level(log::Level::Info)
+            .target("app")
+            .args(args)
+            .build();
+        logger.log(&info);
+        let debug = log::Record::builder()
+            .level(log::Level::Debug)
+            .args(args)
+            .build();
+        logger.clone().log(&debug);
+
+        let mut state = LogViewState::default();
+        logger.drain_into(&mut state);
+        let records: Vec<_> = state.records().collect();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].level(), LogLevel::Info);
+        assert_eq!(records[0].message(), "hello");
+        logger.drain_into(&mut state);
+        assert_eq!(state.len(), 1);
+    }
+}
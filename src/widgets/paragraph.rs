@@ -1,19 +1,14 @@
+use std::borrow::Cow;
+
 use unicode_width::UnicodeWidthStr;
 
 use crate::{
+    layout::{Size, SizeHint},
     prelude::*,
-    text::StyledGrapheme,
-    widgets::{reflow::*, Block},
+    text::{measure, StyledGrapheme},
+    widgets::{reflow::*, Block, ScrollbarState},
 };
 
-const fn get_line_offset(line_width: u16, text_area_width: u16, alignment: Alignment) -> u16 {
-    match alignment {
-        Alignment::Center => (text_area_width / 2).saturating_sub(line_width / 2),
-        Alignment::Right => text_area_width.saturating_sub(line_width),
-        Alignment::Left => 0,
-    }
-}
-
 /// A widget to display some text.
 ///
 /// # Example
@@ -34,7 +29,7 @@ const fn get_line_offset(line_width: u16, text_area_width: u16, alignment: Align
 ///     .block(Block::bordered().title("Paragraph"))
 ///     .style(Style::new().white().on_black())
 ///     .alignment(Alignment::Center)
-///     .wrap(Wrap { trim: true });
+///     .wrap(Wrap::new(true));
 /// ```
 #[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
 pub struct Paragraph<'a> {
@@ -43,7 +38,7 @@ pub struct Paragraph<'a> {
     /// Widget style
     style: Style,
     /// How to wrap the text
-    wrap: Option<Wrap>,
+    wrap: Option<Wrap<'a>>,
     /// The text to display
     text: Text<'a>,
     /// Scroll
@@ -66,7 +61,7 @@ pub struct Paragraph<'a> {
 /// );
 ///
 /// // With leading spaces trimmed (window width of 30 chars):
-/// Paragraph::new(bullet_points.clone()).wrap(Wrap { trim: true });
+/// Paragraph::new(bullet_points.clone()).wrap(Wrap::new(true));
 /// // Some indented points:
 /// // - First thing goes here and is
 /// // long so that it wraps
@@ -74,17 +69,177 @@ pub struct Paragraph<'a> {
 /// // is long enough to wrap
 ///
 /// // But without trimming, indentation is preserved:
-/// Paragraph::new(bullet_points).wrap(Wrap { trim: false });
+/// Paragraph::new(bullet_points).wrap(Wrap::new(false));
 /// // Some indented points:
 /// //     - First thing goes here
 /// // and is long so that it wraps
 /// //     - Here is another point
 /// // that is long enough to wrap
 /// ```
-#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
-pub struct Wrap {
+///
+/// To align wrapped lines instead, set an indent for the first and the following wrapped lines
+/// with [`Wrap::initial_indent`] and [`Wrap::subsequent_indent`].
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Wrap<'a> {
     /// Should leading whitespace be trimmed
     pub trim: bool,
+    /// Prepended to the first wrapped line of each line of the text
+    pub initial_indent: Span<'a>,
+    /// Prepended to the other wrapped lines of each line of the text
+    pub subsequent_indent: Span<'a>,
+}
+
+impl<'a> Wrap<'a> {
+    /// Creates a new `Wrap` without indents, which trims the leading whitespace of the wrapped
+    /// lines if `trim` is true
+    pub const fn new(trim: bool) -> Self {
+        const NO_INDENT: Span = Span {
+            content: Cow::Borrowed(""),
+            style: Style::new(),
+            hyperlink: None,
+        };
+        Self {
+            trim,
+            initial_indent: NO_INDENT,
+            subsequent_indent: NO_INDENT,
+        }
+    }
+
+    /// Sets the indent prepended to the first wrapped line of each line of the text.
+    ///
+    /// The `indent` parameter accepts any value that can be converted into a [`Span`], so the
+    /// indent can be styled. The indent reduces the width available to the text of the line. It
+    /// is not added to empty lines, and is ignored if it leaves no room for the text.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let paragraph = Paragraph::new("a bullet point that is long enough to wrap")
+    ///     .wrap(Wrap::new(true).initial_indent("- ").subsequent_indent("  "));
+    /// // - a bullet point
+    /// //   that is long
+    /// //   enough to wrap
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn initial_indent<T: Into<Span<'a>>>(mut self, indent: T) -> Self {
+        self.initial_indent = indent.into();
+        self
+    }
+
+    /// Sets the indent prepended to the wrapped lines following the first one of each line of the
+    /// text.
+    ///
+    /// This is a hanging indent that aligns the continuation of long lines, such as wrapped bullet
+    /// points or log messages. See [`Wrap::initial_indent`] for more details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let paragraph = Paragraph::new("12:00:00 a log message that is long enough to wrap")
+    ///     .wrap(Wrap::new(true).subsequent_indent("         "));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn subsequent_indent<T: Into<Span<'a>>>(mut self, indent: T) -> Self {
+        self.subsequent_indent = indent.into();
+        self
+    }
+
+    /// The line composer wrapping `lines` to `width`, with the indents patched on top of `style`
+    pub(crate) fn word_wrapper<'b, O, I>(
+        &'b self,
+        lines: O,
+        width: u16,
+        style: Style,
+    ) -> WordWrapper<'b, O, I>
+    where
+        O: Iterator<Item = (I, Alignment)>,
+        I: Iterator<Item = StyledGrapheme<'b>>,
+    {
+        let mut line_composer = WordWrapper::new(lines, width, self.trim);
+        line_composer.set_indent(
+            self.initial_indent.styled_graphemes(style).collect(),
+            self.subsequent_indent.styled_graphemes(style).collect(),
+        );
+        line_composer
+    }
+}
+
+/// State of the [`Paragraph`] widget
+///
+/// The state is filled in when a reference to the paragraph is rendered as a [`StatefulWidget`]
+/// and describes how the text fits horizontally in the rendered area. It can be used to drive a
+/// horizontal [`Scrollbar`] without measuring the text again:
+/// - [`line_width`]: the width of the widest line of the text
+/// - [`viewport_width`]: the width of the area the text was rendered in
+/// - [`horizontal_offset`]: the horizontal scroll offset the text was rendered with
+/// - [`overflow`]: how many columns of the widest line do not fit in the area
+///
+/// When the paragraph is wrapped, the lines never overflow the area.
+///
+/// # Example
+///
+/// ```rust
+/// # use ratatui::{prelude::*, widgets::*};
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// let paragraph = Paragraph::new("A very long line of text").scroll((0, 2));
+/// let mut state = ParagraphState::default();
+/// frame.render_stateful_widget(&paragraph, area, &mut state);
+///
+/// let mut scrollbar_state = state.horizontal_scrollbar_state();
+/// let scrollbar = Scrollbar::new(ScrollbarOrientation::HorizontalBottom);
+/// frame.render_stateful_widget(scrollbar, area, &mut scrollbar_state);
+/// # }
+/// ```
+///
+/// [`Scrollbar`]: crate::widgets::Scrollbar
+/// [`line_width`]: ParagraphState::line_width
+/// [`viewport_width`]: ParagraphState::viewport_width
+/// [`horizontal_offset`]: ParagraphState::horizontal_offset
+/// [`overflow`]: ParagraphState::overflow
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParagraphState {
+    line_width: usize,
+    viewport_width: u16,
+    horizontal_offset: u16,
+    overflow: usize,
+}
+
+impl ParagraphState {
+    /// The width of the widest line of the text, see [`Paragraph::line_width`]
+    pub const fn line_width(&self) -> usize {
+        self.line_width
+    }
+
+    /// The width of the area the text was rendered in, excluding the block
+    pub const fn viewport_width(&self) -> u16 {
+        self.viewport_width
+    }
+
+    /// The horizontal scroll offset the text was rendered with, see [`Paragraph::scroll`]
+    pub const fn horizontal_offset(&self) -> u16 {
+        self.horizontal_offset
+    }
+
+    /// The number of columns of the widest line that do not fit in the area
+    ///
+    /// This is also the largest horizontal scroll offset that still shows the end of the widest
+    /// line. It is always 0 when the paragraph is wrapped.
+    pub const fn overflow(&self) -> usize {
+        self.overflow
+    }
+
+    /// A [`ScrollbarState`] matching the horizontal scroll position of the text
+    ///
+    /// [`ScrollbarState`]: crate::widgets::ScrollbarState
+    pub const fn horizontal_scrollbar_state(&self) -> ScrollbarState {
+        ScrollbarState::new(self.overflow)
+            .position(self.horizontal_offset as usize)
+            .viewport_content_length(self.viewport_width as usize)
+    }
 }
 
 type Horizontal = u16;
@@ -162,10 +317,10 @@ impl<'a> Paragraph<'a> {
     ///
     /// ```rust
     /// # use ratatui::{prelude::*, widgets::*};
-    /// let paragraph = Paragraph::new("Hello, world!").wrap(Wrap { trim: true });
+    /// let paragraph = Paragraph::new("Hello, world!").wrap(Wrap::new(true));
     /// ```
     #[must_use = "method moves the value of self and returns the modified value"]
-    pub const fn wrap(mut self, wrap: Wrap) -> Self {
+    pub fn wrap(mut self, wrap: Wrap<'a>) -> Self {
         self.wrap = Some(wrap);
         self
     }
@@ -251,45 +406,29 @@ impl<'a> Paragraph<'a> {
 
     /// Calculates the number of lines needed to fully render.
     ///
-    /// Given a max line width, this method calculates the number of lines that a paragraph will
-    /// need in order to be fully rendered. For paragraphs that do not use wrapping, this count is
-    /// simply the number of lines present in the paragraph.
+    /// Given the width of the area the text is rendered in (excluding the block, if any), this
+    /// method calculates the number of lines that a paragraph will need in order to be fully
+    /// rendered. The lines are wrapped with the same algorithm as when rendering, including the
+    /// trimming of leading whitespace set with [`Wrap::trim`]. For paragraphs that do not use
+    /// wrapping, this count is simply the number of lines present in the paragraph.
     ///
-    /// Note: The design for text wrapping is not stable and might affect this API.
+    /// Returns 0 if `width` is 0.
     ///
     /// # Example
     ///
-    /// ```ignore
+    /// ```rust
     /// # use ratatui::{prelude::*, widgets::*};
-    /// let paragraph = Paragraph::new("Hello World")
-    ///     .wrap(Wrap { trim: false });
+    /// let paragraph = Paragraph::new("Hello World").wrap(Wrap::new(false));
     /// assert_eq!(paragraph.line_count(20), 1);
     /// assert_eq!(paragraph.line_count(10), 2);
     /// ```
-    #[stability::unstable(
-        feature = "rendered-line-info",
-        issue = "https://github.com/ratatui-org/ratatui/issues/293"
-    )]
     pub fn line_count(&self, width: u16) -> usize {
         if width < 1 {
             return 0;
         }
 
-        if let Some(Wrap { trim }) = self.wrap {
-            let styled = self.text.iter().map(|line| {
-                let graphemes = line
-                    .spans
-                    .iter()
-                    .flat_map(|span| span.styled_graphemes(self.style));
-                let alignment = line.alignment.unwrap_or(self.alignment);
-                (graphemes, alignment)
-            });
-            let mut line_composer = WordWrapper::new(styled, width, trim);
-            let mut count = 0;
-            while line_composer.next_line().is_some() {
-                count += 1;
-            }
-            count
+        if let Some(wrap) = &self.wrap {
+            measure::count_rows(self.word_wrapper(width, wrap))
         } else {
             self.text.height()
         }
@@ -297,11 +436,13 @@ impl<'a> Paragraph<'a> {
 
     /// Calculates the shortest line width needed to avoid any word being wrapped or truncated.
     ///
-    /// Note: The design for text wrapping is not stable and might affect this API.
+    /// This is the width of the widest line of the text, measured in columns of the terminal, so
+    /// wide characters count as two columns. Rendering the paragraph in an area this wide
+    /// (excluding the block, if any) renders each line of the text on a single line.
     ///
     /// # Example
     ///
-    /// ```ignore
+    /// ```rust
     /// # use ratatui::{prelude::*, widgets::*};
     /// let paragraph = Paragraph::new("Hello World");
     /// assert_eq!(paragraph.line_width(), 11);
@@ -309,18 +450,35 @@ impl<'a> Paragraph<'a> {
     /// let paragraph = Paragraph::new("Hello World\nhi\nHello World!!!");
     /// assert_eq!(paragraph.line_width(), 14);
     /// ```
-    #[stability::unstable(
-        feature = "rendered-line-info",
-        issue = "https://github.com/ratatui-org/ratatui/issues/293"
-    )]
     pub fn line_width(&self) -> usize {
         self.text.iter().map(Line::width).max().unwrap_or_default()
     }
 }
 
+impl SizeHint for Paragraph<'_> {
+    /// The size of the text and the block of the paragraph
+    ///
+    /// The width is the [line width](Paragraph::line_width) of the text, and the height is the
+    /// [line count](Paragraph::line_count) at that width, both clamped to the available size once
+    /// the borders, titles and padding of the block are added.
+    fn intrinsic_size(&self, available: Size) -> Size {
+        let area = Rect::new(0, 0, available.width, available.height);
+        let inner = self.block.inner_if_some(area);
+        let (extra_width, extra_height) = (area.width - inner.width, area.height - inner.height);
+        let width = u16::try_from(self.line_width())
+            .unwrap_or(u16::MAX)
+            .min(inner.width);
+        let height = u16::try_from(self.line_count(width)).unwrap_or(u16::MAX);
+        Size::new(
+            width.saturating_add(extra_width).min(available.width),
+            height.saturating_add(extra_height).min(available.height),
+        )
+    }
+}
+
 impl Widget for Paragraph<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        self.render_ref(area, buf);
+        WidgetRef::render_ref(&self, area, buf);
     }
 }
 
@@ -333,6 +491,36 @@ impl WidgetRef for Paragraph<'_> {
     }
 }
 
+// Note: this is only implemented for references to keep `Paragraph::render` unambiguous. Remove
+// this when StatefulWidgetRef is stabilized and replace with the blanket impl
+impl StatefulWidget for &Paragraph<'_> {
+    type State = ParagraphState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidgetRef::render_ref(self, area, buf, state);
+    }
+}
+
+impl StatefulWidgetRef for Paragraph<'_> {
+    type State = ParagraphState;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        WidgetRef::render_ref(self, area, buf);
+        let viewport_width = self.block.inner_if_some(area).width;
+        let line_width = self.line_width();
+        *state = ParagraphState {
+            line_width,
+            viewport_width,
+            horizontal_offset: self.scroll.1,
+            overflow: if self.wrap.is_some() {
+                0
+            } else {
+                line_width.saturating_sub(viewport_width as usize)
+            },
+        };
+    }
+}
+
 impl Paragraph<'_> {
     fn render_paragraph(&self, text_area: Rect, buf: &mut Buffer) {
         if text_area.is_empty() {
@@ -340,21 +528,31 @@ impl Paragraph<'_> {
         }
 
         buf.set_style(text_area, self.style);
-        let styled = self.text.iter().map(|line| {
-            let graphemes = line.styled_graphemes(self.text.style);
-            let alignment = line.alignment.unwrap_or(self.alignment);
-            (graphemes, alignment)
-        });
-
-        if let Some(Wrap { trim }) = self.wrap {
-            let line_composer = WordWrapper::new(styled, text_area.width, trim);
+        if let Some(wrap) = &self.wrap {
+            let line_composer = self.word_wrapper(text_area.width, wrap);
             self.render_text(line_composer, text_area, buf);
         } else {
-            let mut line_composer = LineTruncator::new(styled, text_area.width);
+            let mut line_composer = LineTruncator::new(self.styled_lines(), text_area.width);
             line_composer.set_horizontal_offset(self.scroll.1);
             self.render_text(line_composer, text_area, buf);
         }
     }
+
+    /// The line composer wrapping the text to `width` as described by `wrap`
+    fn word_wrapper<'b>(&'b self, width: u16, wrap: &'b Wrap) -> impl LineComposer<'b> {
+        wrap.word_wrapper(self.styled_lines(), width, self.text.style)
+    }
+
+    /// The graphemes and alignment of each line of the text, as passed to the line composers
+    fn styled_lines(
+        &self,
+    ) -> impl Iterator<Item = (impl Iterator<Item = StyledGrapheme<'_>>, Alignment)> {
+        self.text.iter().map(|line| {
+            let graphemes = line.styled_graphemes(self.text.style);
+            let alignment = line.alignment.unwrap_or(self.alignment);
+            (graphemes, alignment)
+        })
+    }
 }
 
 impl<'a> Paragraph<'a> {
@@ -364,11 +562,18 @@ impl<'a> Paragraph<'a> {
             line: current_line,
             width: current_line_width,
             alignment: current_line_alignment,
+            ..
         }) = composer.next_line()
         {
             if y >= self.scroll.0 {
-                let mut x = get_line_offset(current_line_width, area.width, current_line_alignment);
-                for StyledGrapheme { symbol, style } in current_line {
+                let mut x =
+                    measure::line_offset(current_line_width, area.width, current_line_alignment);
+                for StyledGrapheme {
+                    symbol,
+                    style,
+                    hyperlink,
+                } in current_line
+                {
                     let width = symbol.width();
                     if width == 0 {
                         continue;
@@ -378,7 +583,8 @@ impl<'a> Paragraph<'a> {
                     let symbol = if symbol.is_empty() { " " } else { symbol };
                     buf.get_mut(area.left() + x, area.top() + y - self.scroll.0)
                         .set_symbol(symbol)
-                        .set_style(*style);
+                        .set_style(*style)
+                        .set_hyperlink(*hyperlink);
                     x += width as u16;
                 }
             }
@@ -404,9 +610,12 @@ impl<'a> Styled for Paragraph<'a> {
 
 #[cfg(test)]
 mod test {
+    use rstest::rstest;
+
     use super::*;
     use crate::{
         backend::TestBackend,
+        buffer::Cell,
         widgets::{block::Position, Borders},
     };
 
@@ -432,8 +641,8 @@ mod test {
         let line = "foo\0";
         for paragraph in [
             Paragraph::new(line),
-            Paragraph::new(line).wrap(Wrap { trim: false }),
-            Paragraph::new(line).wrap(Wrap { trim: true }),
+            Paragraph::new(line).wrap(Wrap::new(false)),
+            Paragraph::new(line).wrap(Wrap::new(true)),
         ] {
             test_case(&paragraph, &Buffer::with_lines(["foo"]));
             test_case(&paragraph, &Buffer::with_lines(["foo   "]));
@@ -446,8 +655,8 @@ mod test {
     fn test_render_empty_paragraph() {
         for paragraph in [
             Paragraph::new(""),
-            Paragraph::new("").wrap(Wrap { trim: false }),
-            Paragraph::new("").wrap(Wrap { trim: true }),
+            Paragraph::new("").wrap(Wrap::new(false)),
+            Paragraph::new("").wrap(Wrap::new(true)),
         ] {
             test_case(&paragraph, &Buffer::with_lines([" "]));
             test_case(&paragraph, &Buffer::with_lines(["          "]));
@@ -461,8 +670,8 @@ mod test {
         let text = "Hello, world!";
         for paragraph in [
             Paragraph::new(text),
-            Paragraph::new(text).wrap(Wrap { trim: false }),
-            Paragraph::new(text).wrap(Wrap { trim: true }),
+            Paragraph::new(text).wrap(Wrap::new(false)),
+            Paragraph::new(text).wrap(Wrap::new(true)),
         ] {
             test_case(&paragraph, &Buffer::with_lines(["Hello, world!  "]));
             test_case(&paragraph, &Buffer::with_lines(["Hello, world!"]));
@@ -482,8 +691,8 @@ mod test {
         let text = "This is a\nmultiline\nparagraph.";
         for paragraph in [
             Paragraph::new(text),
-            Paragraph::new(text).wrap(Wrap { trim: false }),
-            Paragraph::new(text).wrap(Wrap { trim: true }),
+            Paragraph::new(text).wrap(Wrap::new(false)),
+            Paragraph::new(text).wrap(Wrap::new(true)),
         ] {
             test_case(
                 &paragraph,
@@ -512,8 +721,8 @@ mod test {
         // can truncate this without triggering the typos linter.
         let text = "Hello, worlds!";
         let truncated_paragraph = Paragraph::new(text).block(Block::bordered().title("Title"));
-        let wrapped_paragraph = truncated_paragraph.clone().wrap(Wrap { trim: false });
-        let trimmed_paragraph = truncated_paragraph.clone().wrap(Wrap { trim: true });
+        let wrapped_paragraph = truncated_paragraph.clone().wrap(Wrap::new(false));
+        let trimmed_paragraph = truncated_paragraph.clone().wrap(Wrap::new(true));
 
         for paragraph in [&truncated_paragraph, &wrapped_paragraph, &trimmed_paragraph] {
             #[rustfmt::skip]
@@ -607,6 +816,28 @@ mod test {
         test_case(&paragraph, &expected);
     }
 
+    #[rstest]
+    #[case::truncated(None)]
+    #[case::wrapped(Some(Wrap::new(true)))]
+    fn test_render_hyperlinks(#[case] wrap: Option<Wrap>) {
+        let line = Line::from(vec![
+            Span::raw("see "),
+            Span::raw("docs").hyperlink("https://ratatui.rs"),
+        ]);
+        let mut paragraph = Paragraph::new(line);
+        if let Some(wrap) = wrap {
+            paragraph = paragraph.wrap(wrap);
+        }
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+        paragraph.render(buf.area, &mut buf);
+        let hyperlinks: Vec<_> = buf.content.iter().map(Cell::hyperlink).collect();
+        let link = Some("https://ratatui.rs");
+        assert_eq!(
+            hyperlinks,
+            [None, None, None, None, link, link, link, link, None, None]
+        );
+    }
+
     #[test]
     fn test_render_paragraph_with_block_with_bottom_title_and_border() {
         let block = Block::new()
@@ -623,8 +854,8 @@ mod test {
     #[test]
     fn test_render_paragraph_with_word_wrap() {
         let text = "This is a long line of text that should wrap      and contains a superultramegagigalong word.";
-        let wrapped_paragraph = Paragraph::new(text).wrap(Wrap { trim: false });
-        let trimmed_paragraph = Paragraph::new(text).wrap(Wrap { trim: true });
+        let wrapped_paragraph = Paragraph::new(text).wrap(Wrap::new(false));
+        let trimmed_paragraph = Paragraph::new(text).wrap(Wrap::new(true));
 
         test_case(
             &wrapped_paragraph,
@@ -706,8 +937,8 @@ mod test {
     fn test_render_paragraph_with_left_alignment() {
         let text = "Hello, world!";
         let truncated_paragraph = Paragraph::new(text).alignment(Alignment::Left);
-        let wrapped_paragraph = truncated_paragraph.clone().wrap(Wrap { trim: false });
-        let trimmed_paragraph = truncated_paragraph.clone().wrap(Wrap { trim: true });
+        let wrapped_paragraph = truncated_paragraph.clone().wrap(Wrap::new(false));
+        let trimmed_paragraph = truncated_paragraph.clone().wrap(Wrap::new(true));
 
         for paragraph in [&truncated_paragraph, &wrapped_paragraph, &trimmed_paragraph] {
             test_case(paragraph, &Buffer::with_lines(["Hello, world!  "]));
@@ -729,8 +960,8 @@ mod test {
     fn test_render_paragraph_with_center_alignment() {
         let text = "Hello, world!";
         let truncated_paragraph = Paragraph::new(text).alignment(Alignment::Center);
-        let wrapped_paragraph = truncated_paragraph.clone().wrap(Wrap { trim: false });
-        let trimmed_paragraph = truncated_paragraph.clone().wrap(Wrap { trim: true });
+        let wrapped_paragraph = truncated_paragraph.clone().wrap(Wrap::new(false));
+        let trimmed_paragraph = truncated_paragraph.clone().wrap(Wrap::new(true));
 
         for paragraph in [&truncated_paragraph, &wrapped_paragraph, &trimmed_paragraph] {
             test_case(paragraph, &Buffer::with_lines([" Hello, world! "]));
@@ -754,8 +985,8 @@ mod test {
     fn test_render_paragraph_with_right_alignment() {
         let text = "Hello, world!";
         let truncated_paragraph = Paragraph::new(text).alignment(Alignment::Right);
-        let wrapped_paragraph = truncated_paragraph.clone().wrap(Wrap { trim: false });
-        let trimmed_paragraph = truncated_paragraph.clone().wrap(Wrap { trim: true });
+        let wrapped_paragraph = truncated_paragraph.clone().wrap(Wrap::new(false));
+        let trimmed_paragraph = truncated_paragraph.clone().wrap(Wrap::new(true));
 
         for paragraph in [&truncated_paragraph, &wrapped_paragraph, &trimmed_paragraph] {
             test_case(paragraph, &Buffer::with_lines(["  Hello, world!"]));
@@ -777,8 +1008,8 @@ mod test {
     fn test_render_paragraph_with_scroll_offset() {
         let text = "This is a\ncool\nmultiline\nparagraph.";
         let truncated_paragraph = Paragraph::new(text).scroll((2, 0));
-        let wrapped_paragraph = truncated_paragraph.clone().wrap(Wrap { trim: false });
-        let trimmed_paragraph = truncated_paragraph.clone().wrap(Wrap { trim: true });
+        let wrapped_paragraph = truncated_paragraph.clone().wrap(Wrap::new(false));
+        let trimmed_paragraph = truncated_paragraph.clone().wrap(Wrap::new(true));
 
         for paragraph in [&truncated_paragraph, &wrapped_paragraph, &trimmed_paragraph] {
             test_case(
@@ -805,8 +1036,8 @@ mod test {
 
         for paragraph in [
             Paragraph::new(text),
-            Paragraph::new(text).wrap(Wrap { trim: false }),
-            Paragraph::new(text).wrap(Wrap { trim: true }),
+            Paragraph::new(text).wrap(Wrap::new(false)),
+            Paragraph::new(text).wrap(Wrap::new(true)),
         ] {
             test_case(&paragraph, &Buffer::empty(area));
             test_case(&paragraph.clone().scroll((2, 4)), &Buffer::empty(area));
@@ -820,8 +1051,8 @@ mod test {
 
         for paragraph in [
             Paragraph::new(text),
-            Paragraph::new(text).wrap(Wrap { trim: false }),
-            Paragraph::new(text).wrap(Wrap { trim: true }),
+            Paragraph::new(text).wrap(Wrap::new(false)),
+            Paragraph::new(text).wrap(Wrap::new(true)),
         ] {
             test_case(&paragraph, &Buffer::empty(area));
             test_case(&paragraph.clone().scroll((2, 4)), &Buffer::empty(area));
@@ -847,8 +1078,8 @@ mod test {
 
         for paragraph in [
             Paragraph::new(text.clone()),
-            Paragraph::new(text.clone()).wrap(Wrap { trim: false }),
-            Paragraph::new(text.clone()).wrap(Wrap { trim: true }),
+            Paragraph::new(text.clone()).wrap(Wrap::new(false)),
+            Paragraph::new(text.clone()).wrap(Wrap::new(true)),
         ] {
             test_case(
                 &paragraph.style(Style::default().bg(Color::Green)),
@@ -862,8 +1093,8 @@ mod test {
         let text = "Hello, <world>!";
         for paragraph in [
             Paragraph::new(text),
-            Paragraph::new(text).wrap(Wrap { trim: false }),
-            Paragraph::new(text).wrap(Wrap { trim: true }),
+            Paragraph::new(text).wrap(Wrap::new(false)),
+            Paragraph::new(text).wrap(Wrap::new(true)),
         ] {
             test_case(&paragraph, &Buffer::with_lines(["Hello, <world>!"]));
             test_case(&paragraph, &Buffer::with_lines(["Hello, <world>!     "]));
@@ -882,8 +1113,8 @@ mod test {
     fn test_render_paragraph_with_unicode_characters() {
         let text = "こんにちは, 世界! 😃";
         let truncated_paragraph = Paragraph::new(text);
-        let wrapped_paragraph = Paragraph::new(text).wrap(Wrap { trim: false });
-        let trimmed_paragraph = Paragraph::new(text).wrap(Wrap { trim: true });
+        let wrapped_paragraph = Paragraph::new(text).wrap(Wrap::new(false));
+        let trimmed_paragraph = Paragraph::new(text).wrap(Wrap::new(true));
 
         for paragraph in [&truncated_paragraph, &wrapped_paragraph, &trimmed_paragraph] {
             test_case(paragraph, &Buffer::with_lines(["こんにちは, 世界! 😃"]));
@@ -924,10 +1155,10 @@ mod test {
         let paragraph = Paragraph::new("Hello World");
         assert_eq!(paragraph.line_count(20), 1);
         assert_eq!(paragraph.line_count(10), 1);
-        let paragraph = Paragraph::new("Hello World").wrap(Wrap { trim: false });
+        let paragraph = Paragraph::new("Hello World").wrap(Wrap::new(false));
         assert_eq!(paragraph.line_count(20), 1);
         assert_eq!(paragraph.line_count(10), 2);
-        let paragraph = Paragraph::new("Hello World").wrap(Wrap { trim: true });
+        let paragraph = Paragraph::new("Hello World").wrap(Wrap::new(true));
         assert_eq!(paragraph.line_count(20), 1);
         assert_eq!(paragraph.line_count(10), 2);
 
@@ -935,32 +1166,148 @@ mod test {
         let paragraph = Paragraph::new(text.trim());
         assert_eq!(paragraph.line_count(11), 1);
         assert_eq!(paragraph.line_count(6), 1);
-        let paragraph = paragraph.wrap(Wrap { trim: false });
+        let paragraph = paragraph.wrap(Wrap::new(false));
         assert_eq!(paragraph.line_count(11), 100);
         assert_eq!(paragraph.line_count(6), 200);
-        let paragraph = paragraph.wrap(Wrap { trim: true });
+        let paragraph = paragraph.wrap(Wrap::new(true));
         assert_eq!(paragraph.line_count(11), 100);
         assert_eq!(paragraph.line_count(6), 200);
     }
 
+    #[rstest]
+    fn widgets_paragraph_line_count_matches_render(
+        #[values(1, 2, 3, 5, 8, 13)] width: u16,
+        #[values(false, true)] trim: bool,
+    ) {
+        let text = "The  quick brown fox\n  jumps over 地球 the\nsupercalifragilistic dog";
+        let paragraph = Paragraph::new(text).wrap(Wrap::new(trim));
+        let area = Rect::new(0, 0, width, 100);
+        let mut buf = Buffer::empty(area);
+        WidgetRef::render_ref(&paragraph, area, &mut buf);
+        let rendered = (0..area.height)
+            .rev()
+            .find(|&y| (0..width).any(|x| buf.get(x, y).symbol() != " "))
+            .map_or(0, |y| y as usize + 1);
+        assert_eq!(paragraph.line_count(width), rendered);
+    }
+
+    #[test]
+    fn widgets_paragraph_line_width_wide_characters() {
+        let paragraph = Paragraph::new("地球\nab");
+        assert_eq!(paragraph.line_width(), 4);
+    }
+
+    #[test]
+    fn render_justified() {
+        let text = "the quick brown fox jumps over\nthe lazy dog";
+        let paragraph = Paragraph::new(text)
+            .alignment(Alignment::Justify)
+            .wrap(Wrap::new(true));
+        #[rustfmt::skip]
+        let expected = Buffer::with_lines([
+            "the    quick",
+            "brown    fox",
+            "jumps over  ",
+            "the lazy dog",
+        ]);
+        test_case(&paragraph, &expected);
+        assert_eq!(paragraph.line_count(12), 4);
+    }
+
+    #[test]
+    fn render_justified_without_wrap() {
+        let paragraph = Paragraph::new("a b").alignment(Alignment::Justify);
+        test_case(&paragraph, &Buffer::with_lines(["a b   "]));
+    }
+
+    #[test]
+    fn render_with_indent() {
+        let paragraph = Paragraph::new("a bullet point that wraps\nnext").wrap(
+            Wrap::new(true)
+                .initial_indent("- ".blue())
+                .subsequent_indent("  "),
+        );
+        assert_eq!(paragraph.line_count(12), 4);
+        let mut expected = Buffer::with_lines([
+            "- a bullet  ",
+            "  point that",
+            "  wraps     ",
+            "- next      ",
+        ]);
+        expected.set_style(Rect::new(0, 0, 2, 1), Style::new().blue());
+        expected.set_style(Rect::new(0, 3, 2, 1), Style::new().blue());
+        test_case(&paragraph, &expected);
+    }
+
+    #[test]
+    fn render_with_state() {
+        let paragraph = Paragraph::new("Hello World\nHi")
+            .block(Block::bordered())
+            .scroll((0, 2));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 4));
+        let mut state = ParagraphState::default();
+        StatefulWidget::render(&paragraph, buf.area, &mut buf, &mut state);
+        let expected = Buffer::with_lines(["┌──────┐", "│llo Wo│", "│      │", "└──────┘"]);
+        assert_eq!(buf, expected);
+        assert_eq!(state.line_width(), 11);
+        assert_eq!(state.viewport_width(), 6);
+        assert_eq!(state.horizontal_offset(), 2);
+        assert_eq!(state.overflow(), 5);
+        assert_eq!(
+            state.horizontal_scrollbar_state(),
+            ScrollbarState::new(5)
+                .position(2)
+                .viewport_content_length(6)
+        );
+    }
+
+    #[test]
+    fn render_wrapped_with_state() {
+        let paragraph = Paragraph::new("Hello World").wrap(Wrap::new(true));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 2));
+        let mut state = ParagraphState::default();
+        StatefulWidget::render(&paragraph, buf.area, &mut buf, &mut state);
+        assert_eq!(state.line_width(), 11);
+        assert_eq!(state.overflow(), 0);
+    }
+
     #[test]
     fn widgets_paragraph_line_width() {
         let paragraph = Paragraph::new("Hello World");
         assert_eq!(paragraph.line_width(), 11);
-        let paragraph = Paragraph::new("Hello World").wrap(Wrap { trim: false });
+        let paragraph = Paragraph::new("Hello World").wrap(Wrap::new(false));
         assert_eq!(paragraph.line_width(), 11);
-        let paragraph = Paragraph::new("Hello World").wrap(Wrap { trim: true });
+        let paragraph = Paragraph::new("Hello World").wrap(Wrap::new(true));
         assert_eq!(paragraph.line_width(), 11);
 
         let text = "Hello World ".repeat(100);
         let paragraph = Paragraph::new(text);
         assert_eq!(paragraph.line_width(), 1200);
-        let paragraph = paragraph.wrap(Wrap { trim: false });
+        let paragraph = paragraph.wrap(Wrap::new(false));
         assert_eq!(paragraph.line_width(), 1200);
-        let paragraph = paragraph.wrap(Wrap { trim: true });
+        let paragraph = paragraph.wrap(Wrap::new(true));
         assert_eq!(paragraph.line_width(), 1200);
     }
 
+    #[rstest]
+    #[case::fits(Paragraph::new("Hello\nWorld!"), Size::new(6, 2))]
+    #[case::truncated(Paragraph::new("Hello World ".repeat(3)), Size::new(20, 1))]
+    #[case::wrapped(
+        Paragraph::new("Hello World ".repeat(3)).wrap(Wrap::new(true)),
+        Size::new(20, 2)
+    )]
+    #[case::block(Paragraph::new("Hello").block(Block::bordered()), Size::new(7, 3))]
+    #[case::wrapped_block(
+        Paragraph::new("Hello World").block(Block::bordered()).wrap(Wrap::new(true)),
+        Size::new(13, 3)
+    )]
+    #[case::clamped(Paragraph::new("a\nb\nc\nd\ne\nf").block(Block::bordered()), Size::new(3, 5))]
+    fn intrinsic_size(#[case] paragraph: Paragraph, #[case] expected: Size) {
+        let available = Size::new(20, 5);
+        let size = paragraph.intrinsic_size(available);
+        assert_eq!(size, expected);
+    }
+
     #[test]
     fn left_aligned() {
         let p = Paragraph::new("Hello, world!").left_aligned();
@@ -0,0 +1,221 @@
+use crate::{
+    layout::Flex,
+    prelude::*,
+    widgets::{Block, Clear},
+};
+
+/// A widget that renders its content in a centered box on top of other widgets.
+///
+/// A `Popup` clears the area it occupies (see [`Clear`]), draws an optional [`Block`] and renders
+/// its content inside of it. The size of the popup is given by a [`Constraint`] for each axis, so
+/// it can be a percentage of the area (the default is 50% of the width and height) or a fixed
+/// size. The popup is centered in the area it is rendered into.
+///
+/// The rest of the area can optionally be styled with a [`backdrop`] style, e.g. to dim the
+/// widgets behind a modal dialog.
+///
+/// Any widget that implements [`WidgetRef`] can be used as content. The position of the popup can
+/// be queried with [`Popup::area`], e.g. to place the cursor in a dialog.
+///
+/// [`backdrop`]: Popup::backdrop
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{prelude::*, widgets::*};
+///
+/// # fn ui(frame: &mut Frame) {
+/// let popup = Popup::new(Paragraph::new("Are you sure?"))
+///     .block(Block::bordered().title("Confirm"))
+///     .width(Constraint::Length(30))
+///     .height(Constraint::Length(5))
+///     .backdrop(Style::new().dim());
+/// frame.render_widget(popup, frame.size());
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Popup<'a, W> {
+    content: W,
+    block: Option<Block<'a>>,
+    style: Style,
+    width: Constraint,
+    height: Constraint,
+    backdrop: Option<Style>,
+}
+
+impl<'a, W> Popup<'a, W> {
+    /// Creates a new popup with the given content
+    ///
+    /// The popup has no block and takes 50% of the width and height of the area by default.
+    pub const fn new(content: W) -> Self {
+        Self {
+            content,
+            block: None,
+            style: Style::new(),
+            width: Constraint::Percentage(50),
+            height: Constraint::Percentage(50),
+            backdrop: None,
+        }
+    }
+
+    /// Surrounds the content with a [`Block`]
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the style of the popup area
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the width of the popup
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let popup = Popup::new("content").width(Constraint::Percentage(80));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn width(mut self, width: Constraint) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the popup
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let popup = Popup::new("content").height(Constraint::Length(3));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn height(mut self, height: Constraint) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style applied to the area around the popup
+    ///
+    /// By default the area around the popup is left untouched.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn backdrop<S: Into<Style>>(mut self, style: S) -> Self {
+        self.backdrop = Some(style.into());
+        self
+    }
+
+    /// Returns the area occupied by the popup when rendered into the given area
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let popup = Popup::new("content")
+    ///     .width(Constraint::Length(4))
+    ///     .height(Constraint::Length(2));
+    /// assert_eq!(popup.area(Rect::new(0, 0, 10, 10)), Rect::new(3, 4, 4, 2));
+    /// ```
+    pub fn area(&self, area: Rect) -> Rect {
+        let [area] = Layout::horizontal([self.width])
+            .flex(Flex::Center)
+            .areas(area);
+        let [area] = Layout::vertical([self.height])
+            .flex(Flex::Center)
+            .areas(area);
+        area
+    }
+}
+
+impl<W: WidgetRef> Widget for Popup<'_, W> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_ref(area, buf);
+    }
+}
+
+impl<W: WidgetRef> WidgetRef for Popup<'_, W> {
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        if let Some(backdrop) = self.backdrop {
+            buf.set_style(area, backdrop);
+        }
+        let area = self.area(area);
+        Clear.render(area, buf);
+        buf.set_style(area, self.style);
+        self.block.render_ref(area, buf);
+        let inner = self.block.inner_if_some(area);
+        self.content.render_ref(inner, buf);
+    }
+}
+
+impl<W> Styled for Popup<'_, W> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::percentage(
+        Constraint::Percentage(50),
+        Constraint::Percentage(50),
+        Rect::new(5, 3, 10, 5)
+    )]
+    #[case::length(Constraint::Length(4), Constraint::Length(3), Rect::new(8, 4, 4, 3))]
+    #[case::too_large(
+        Constraint::Length(40),
+        Constraint::Length(20),
+        Rect::new(0, 0, 20, 10)
+    )]
+    fn area(#[case] width: Constraint, #[case] height: Constraint, #[case] expected: Rect) {
+        let popup = Popup::new("").width(width).height(height);
+        assert_eq!(popup.area(Rect::new(0, 0, 20, 10)), expected);
+    }
+
+    #[test]
+    fn render_clears_area_and_renders_content() {
+        let mut buf = Buffer::with_lines(["xxxxxxxx", "xxxxxxxx", "xxxxxxxx", "xxxxxxxx"]);
+        Popup::new("hi")
+            .block(Block::bordered())
+            .width(Constraint::Length(6))
+            .height(Constraint::Length(3))
+            .render(buf.area, &mut buf);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["xxxxxxxx", "x┌────┐x", "x│hi  │x", "x└────┘x"])
+        );
+    }
+
+    #[test]
+    fn render_backdrop() {
+        let mut buf = Buffer::with_lines(["xxxx", "xxxx", "xxxx"]);
+        Popup::new("a")
+            .width(Constraint::Length(2))
+            .height(Constraint::Length(1))
+            .backdrop(Style::new().dim())
+            .style(Style::new().bold())
+            .render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["xxxx", "xa x", "xxxx"]);
+        expected.set_style(expected.area, Style::new().dim());
+        expected.set_style(Rect::new(1, 1, 2, 1), Style::reset().bold());
+        assert_eq!(buf, expected);
+    }
+}
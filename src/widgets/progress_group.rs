@@ -0,0 +1,450 @@
+use strum::{Display, EnumString};
+
+use crate::{prelude::*, widgets::Block};
+
+/// A widget to display a group of labeled progress bars.
+///
+/// A `ProgressGroup` renders one [`ProgressItem`] per line, like the output of `cargo build` or
+/// `docker pull`. Each line is made of the label of the item, a thin bar and the percentage of
+/// completion. Labels and percentages are aligned in columns: the label column is as wide as the
+/// widest label (up to half of the area) and the bars fill the remaining space.
+///
+/// Each item has a [`ProgressState`]. Items that are [`Done`](ProgressState::Done) are displayed
+/// as complete and styled with [`ProgressGroup::done_style`], items that
+/// [`Failed`](ProgressState::Failed) are styled with [`ProgressGroup::failed_style`] and
+/// [`Pending`](ProgressState::Pending) items have no percentage.
+///
+/// Items that do not fit in the area are not rendered.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{prelude::*, widgets::*};
+///
+/// # fn ui(frame: &mut Frame) {
+/// let group = ProgressGroup::new([
+///     ProgressItem::new("serde").state(ProgressState::Done),
+///     ProgressItem::new("ratatui").ratio(0.4),
+///     ProgressItem::new("crossterm"),
+/// ])
+/// .block(Block::bordered().title("Downloads"))
+/// .filled_style(Style::new().cyan())
+/// .done_style(Style::new().green());
+/// frame.render_widget(group, frame.size());
+/// # }
+/// ```
+///
+/// # See also
+///
+/// - [`LineGauge`](crate::widgets::LineGauge) for a single progress bar
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ProgressGroup<'a> {
+    items: Vec<ProgressItem<'a>>,
+    block: Option<Block<'a>>,
+    style: Style,
+    line_set: symbols::line::Set,
+    filled_style: Style,
+    unfilled_style: Style,
+    done_style: Style,
+    failed_style: Style,
+}
+
+/// A single progress bar of a [`ProgressGroup`].
+///
+/// # Example
+///
+/// ```
+/// use ratatui::{prelude::*, widgets::*};
+///
+/// let item = ProgressItem::new("download")
+///     .state(ProgressState::InProgress)
+///     .ratio(0.75)
+///     .style(Style::new().italic());
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ProgressItem<'a> {
+    label: Line<'a>,
+    ratio: f64,
+    state: ProgressState,
+    style: Style,
+}
+
+/// The state of a [`ProgressItem`].
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ProgressState {
+    /// The task has not started, no percentage is displayed
+    #[default]
+    Pending,
+    /// The task is running
+    InProgress,
+    /// The task is complete, the bar is displayed as full
+    Done,
+    /// The task failed
+    Failed,
+}
+
+impl<'a> ProgressItem<'a> {
+    /// Creates a new pending item with the given label.
+    pub fn new<T>(label: T) -> Self
+    where
+        T: Into<Line<'a>>,
+    {
+        Self {
+            label: label.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the progression from a ratio (float).
+    ///
+    /// Setting the ratio of a [`Pending`](ProgressState::Pending) item also marks it as
+    /// [`InProgress`](ProgressState::InProgress).
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `ratio` is **not** between 0 and 1 inclusively.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn ratio(mut self, ratio: f64) -> Self {
+        assert!(
+            (0.0..=1.0).contains(&ratio),
+            "Ratio should be between 0 and 1 inclusively."
+        );
+        self.ratio = ratio;
+        if self.state == ProgressState::Pending {
+            self.state = ProgressState::InProgress;
+        }
+        self
+    }
+
+    /// Sets the progression from a percentage.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if `percent` is **not** between 0 and 100 inclusively.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn percent(self, percent: u16) -> Self {
+        assert!(
+            percent <= 100,
+            "Percentage should be between 0 and 100 inclusively."
+        );
+        self.ratio(f64::from(percent) / 100.0)
+    }
+
+    /// Sets the state of the item.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn state(mut self, state: ProgressState) -> Self {
+        self.state = state;
+        self
+    }
+
+    /// Sets the style of the line of the item.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// The ratio displayed for this item
+    const fn displayed_ratio(&self) -> f64 {
+        match self.state {
+            ProgressState::Pending => 0.0,
+            ProgressState::Done => 1.0,
+            ProgressState::InProgress | ProgressState::Failed => self.ratio,
+        }
+    }
+}
+
+impl<'a, T> From<T> for ProgressItem<'a>
+where
+    T: Into<Line<'a>>,
+{
+    fn from(label: T) -> Self {
+        Self::new(label)
+    }
+}
+
+impl<'a> ProgressGroup<'a> {
+    /// Creates a new group with the given items.
+    ///
+    /// `items` accepts any iterator of values that are convertible to [`ProgressItem`], e.g. an
+    /// array of labels.
+    pub fn new<T>(items: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: Into<ProgressItem<'a>>,
+    {
+        Self {
+            items: items.into_iter().map(Into::into).collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Sets the items of the group.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn items<T>(mut self, items: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: Into<ProgressItem<'a>>,
+    {
+        self.items = items.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Surrounds the `ProgressGroup` with a [`Block`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the widget style.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the characters to use for the bars.
+    ///
+    /// Defaults to [`symbols::line::NORMAL`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn line_set(mut self, set: symbols::line::Set) -> Self {
+        self.line_set = set;
+        self
+    }
+
+    /// Sets the style of the filled part of the bars.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn filled_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.filled_style = style.into();
+        self
+    }
+
+    /// Sets the style of the unfilled part of the bars.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn unfilled_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.unfilled_style = style.into();
+        self
+    }
+
+    /// Sets the style of the items that are [`Done`](ProgressState::Done).
+    ///
+    /// The style is patched over the style of the item line and of the filled part of the bar.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn done_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.done_style = style.into();
+        self
+    }
+
+    /// Sets the style of the items that [`Failed`](ProgressState::Failed).
+    ///
+    /// The style is patched over the style of the item line and of the filled part of the bar.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn failed_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.failed_style = style.into();
+        self
+    }
+
+    /// The style patched over an item depending on its state
+    fn state_style(&self, state: ProgressState) -> Style {
+        match state {
+            ProgressState::Done => self.done_style,
+            ProgressState::Failed => self.failed_style,
+            ProgressState::Pending | ProgressState::InProgress => Style::default(),
+        }
+    }
+
+    fn render_item(&self, item: &ProgressItem, layout: &Layout, area: Rect, buf: &mut Buffer) {
+        let [label_area, bar_area, percent_area] = layout.areas(area);
+        let state_style = self.state_style(item.state);
+        buf.set_style(area, item.style.patch(state_style));
+        item.label.render_ref(label_area, buf);
+
+        let ratio = item.displayed_ratio();
+        let end = bar_area.left() + (f64::from(bar_area.width) * ratio).floor() as u16;
+        for x in bar_area.left()..end {
+            buf.get_mut(x, bar_area.top())
+                .set_symbol(self.line_set.horizontal)
+                .set_style(self.filled_style.patch(state_style));
+        }
+        for x in end..bar_area.right() {
+            buf.get_mut(x, bar_area.top())
+                .set_symbol(self.line_set.horizontal)
+                .set_style(self.unfilled_style);
+        }
+
+        if item.state != ProgressState::Pending {
+            Line::raw(format!("{:.0}%", ratio * 100.0))
+                .right_aligned()
+                .render(percent_area, buf);
+        }
+    }
+}
+
+impl Widget for ProgressGroup<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_ref(area, buf);
+    }
+}
+
+impl WidgetRef for ProgressGroup<'_> {
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        buf.set_style(area, self.style);
+        self.block.render_ref(area, buf);
+        let inner = self.block.inner_if_some(area);
+        if inner.is_empty() {
+            return;
+        }
+
+        let label_width = self
+            .items
+            .iter()
+            .map(|item| item.label.width())
+            .max()
+            .unwrap_or_default()
+            .min(usize::from(inner.width / 2)) as u16;
+        let layout = Layout::horizontal([
+            Constraint::Length(label_width),
+            Constraint::Fill(1),
+            Constraint::Length(4),
+        ])
+        .spacing(1);
+        for (item, y) in self.items.iter().zip(inner.top()..inner.bottom()) {
+            let area = Rect {
+                y,
+                height: 1,
+                ..inner
+            };
+            self.render_item(item, &layout, area, buf);
+        }
+    }
+}
+
+impl Styled for ProgressGroup<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+impl Styled for ProgressItem<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn render(group: ProgressGroup, width: u16, height: u16) -> Buffer {
+        let mut buf = Buffer::empty(Rect::new(0, 0, width, height));
+        group.render(buf.area, &mut buf);
+        buf
+    }
+
+    #[test]
+    fn renders_aligned_columns() {
+        let group = ProgressGroup::new([
+            ProgressItem::new("serde").state(ProgressState::Done),
+            ProgressItem::new("ratatui").ratio(0.5),
+            ProgressItem::new("tui"),
+        ]);
+        assert_eq!(
+            render(group, 24, 3),
+            Buffer::with_lines([
+                "serde   ─────────── 100%",
+                "ratatui ───────────  50%",
+                "tui     ───────────     ",
+            ])
+        );
+    }
+
+    #[test]
+    fn renders_state_styles() {
+        let group = ProgressGroup::new([
+            ProgressItem::new("a").state(ProgressState::Done),
+            ProgressItem::new("b")
+                .ratio(0.5)
+                .state(ProgressState::Failed),
+        ])
+        .filled_style(Color::Blue)
+        .done_style(Color::Green)
+        .failed_style(Color::Red);
+        let mut expected = Buffer::with_lines(["a ───── 100%", "b ─────  50%"]);
+        expected.set_style(Rect::new(0, 0, 12, 1), Color::Green);
+        expected.set_style(Rect::new(0, 1, 12, 1), Color::Red);
+        assert_eq!(render(group, 12, 2), expected);
+    }
+
+    #[test]
+    fn truncates_long_labels() {
+        let group = ProgressGroup::new(["a very long label"]).line_set(symbols::line::THICK);
+        assert_eq!(
+            render(group, 16, 1),
+            Buffer::with_lines(["a very l ━━     "])
+        );
+    }
+
+    #[test]
+    fn skips_items_that_do_not_fit() {
+        let group = ProgressGroup::new([ProgressItem::new("a").percent(100), "b".into()]);
+        assert_eq!(render(group, 8, 1), Buffer::with_lines(["a ─ 100%"]));
+    }
+
+    #[test]
+    fn ratio_starts_pending_item() {
+        let item = ProgressItem::new("a").ratio(0.1);
+        assert_eq!(item.state, ProgressState::InProgress);
+        let item = ProgressItem::new("a")
+            .state(ProgressState::Failed)
+            .ratio(0.1);
+        assert_eq!(item.state, ProgressState::Failed);
+    }
+
+    #[test]
+    #[should_panic = "Ratio should be between 0 and 1 inclusively"]
+    fn invalid_ratio() {
+        let _ = ProgressItem::new("a").ratio(1.5);
+    }
+
+    #[test]
+    fn can_be_stylized() {
+        assert_eq!(
+            ProgressGroup::default().black().on_white().bold().style,
+            Style::default()
+                .fg(Color::Black)
+                .bg(Color::White)
+                .add_modifier(Modifier::BOLD)
+        );
+        assert_eq!(
+            ProgressItem::new("a").italic().style,
+            Style::default().add_modifier(Modifier::ITALIC)
+        );
+    }
+}
@@ -1,5 +1,6 @@
 use std::{collections::VecDeque, vec::IntoIter};
 
+use itertools::Itertools;
 use unicode_segmentation::UnicodeSegmentation;
 use unicode_width::UnicodeWidthStr;
 
@@ -18,12 +19,43 @@ pub trait LineComposer<'a> {
 pub struct WrappedLine<'lend, 'text> {
     /// One line reflowed to the correct width
     pub line: &'lend [StyledGrapheme<'text>],
+    /// The byte offset of each grapheme of `line` in its input line, or `None` for the graphemes
+    /// added by the composer (indents and justification)
+    pub offsets: &'lend [Option<usize>],
     /// The width of the line
     pub width: u16,
     /// Whether the line was aligned left or right
     pub alignment: Alignment,
 }
 
+/// A grapheme of a composed line, with its byte offset in the input line or `None` if it was added
+/// by the composer
+#[derive(Debug, Clone)]
+struct Placed<'a> {
+    grapheme: StyledGrapheme<'a>,
+    offset: Option<usize>,
+}
+
+impl<'a> Placed<'a> {
+    fn width(&self) -> u16 {
+        self.grapheme.symbol.width() as u16
+    }
+}
+
+/// Pairs the graphemes of an input line with their byte offsets in the line
+fn place<'a>(
+    graphemes: impl Iterator<Item = StyledGrapheme<'a>>,
+) -> impl Iterator<Item = Placed<'a>> {
+    graphemes.scan(0, |offset, grapheme| {
+        let placed = Placed {
+            offset: Some(*offset),
+            grapheme,
+        };
+        *offset += placed.grapheme.symbol.len();
+        Some(placed)
+    })
+}
+
 /// A state machine that wraps lines on word boundaries.
 #[derive(Debug, Default, Clone)]
 pub struct WordWrapper<'a, O, I>
@@ -37,11 +69,16 @@ where
     /// The given, unprocessed lines
     input_lines: O,
     max_line_width: u16,
-    wrapped_lines: Option<IntoIter<Vec<StyledGrapheme<'a>>>>,
+    wrapped_lines: Option<IntoIter<Vec<Placed<'a>>>>,
     current_alignment: Alignment,
     current_line: Vec<StyledGrapheme<'a>>,
+    current_offsets: Vec<Option<usize>>,
     /// Removes the leading whitespace from lines
     trim: bool,
+    /// Prepended to the first wrapped line of each line
+    initial_indent: Vec<StyledGrapheme<'a>>,
+    /// Prepended to the other wrapped lines of each line
+    subsequent_indent: Vec<StyledGrapheme<'a>>,
 }
 
 impl<'a, O, I> WordWrapper<'a, O, I>
@@ -56,7 +93,59 @@ where
             wrapped_lines: None,
             current_alignment: Alignment::Left,
             current_line: vec![],
+            current_offsets: vec![],
             trim,
+            initial_indent: vec![],
+            subsequent_indent: vec![],
+        }
+    }
+
+    /// Sets the symbols prepended to the first wrapped line of each line (`initial`) and to the
+    /// following wrapped lines (`subsequent`)
+    ///
+    /// An indent that leaves no room for the text is ignored.
+    pub fn set_indent(
+        &mut self,
+        initial: Vec<StyledGrapheme<'a>>,
+        subsequent: Vec<StyledGrapheme<'a>>,
+    ) {
+        self.initial_indent = initial;
+        self.subsequent_indent = subsequent;
+    }
+
+    /// The indent of the first (`initial`) or following wrapped lines of a line
+    fn indent(&self, initial: bool) -> &[StyledGrapheme<'a>] {
+        let indent = if initial {
+            &self.initial_indent
+        } else {
+            &self.subsequent_indent
+        };
+        let width: usize = indent.iter().map(|grapheme| grapheme.symbol.width()).sum();
+        if width < self.max_line_width as usize {
+            indent
+        } else {
+            &[]
+        }
+    }
+
+    /// The width of the indent of the first (`initial`) or following wrapped lines of a line
+    fn indent_width(&self, initial: bool) -> u16 {
+        self.indent(initial)
+            .iter()
+            .map(|grapheme| grapheme.symbol.width() as u16)
+            .sum()
+    }
+
+    /// Prepends the indents to the wrapped lines of a line, leaving empty lines untouched
+    fn indent_lines(&self, wrapped_lines: &mut [Vec<Placed<'a>>]) {
+        for (index, line) in wrapped_lines.iter_mut().enumerate() {
+            if !line.is_empty() {
+                let indent = self.indent(index == 0).iter().map(|grapheme| Placed {
+                    grapheme: grapheme.clone(),
+                    offset: None,
+                });
+                line.splice(0..0, indent);
+            }
         }
     }
 }
@@ -72,7 +161,7 @@ where
             return None;
         }
 
-        let mut current_line: Option<Vec<StyledGrapheme<'a>>> = None;
+        let mut current_line: Option<Vec<Placed<'a>>> = None;
         let mut line_width: u16 = 0;
 
         // Try to repeatedly retrieve next line
@@ -80,10 +169,7 @@ where
             // Retrieve next preprocessed wrapped line
             if let Some(line_iterator) = &mut self.wrapped_lines {
                 if let Some(line) = line_iterator.next() {
-                    line_width = line
-                        .iter()
-                        .map(|grapheme| grapheme.symbol.width())
-                        .sum::<usize>() as u16;
+                    line_width = line.iter().map(Placed::width).sum();
                     current_line = Some(line);
                 }
             }
@@ -101,26 +187,34 @@ where
                     let (mut unfinished_word, mut word_width) = (vec![], 0);
                     // Saves the whitespaces of the partially unfinished word
                     let (mut unfinished_whitespaces, mut whitespace_width) =
-                        (VecDeque::<StyledGrapheme>::new(), 0);
+                        (VecDeque::<Placed>::new(), 0);
 
                     let mut has_seen_non_whitespace = false;
-                    for StyledGrapheme { symbol, style } in line_symbols {
-                        let symbol_whitespace = symbol == ZWSP
-                            || (symbol.chars().all(&char::is_whitespace) && symbol != NBSP);
+                    // The indents reduce the width available to the wrapped lines
+                    let initial_width = self.max_line_width - self.indent_width(true);
+                    let subsequent_width = self.max_line_width - self.indent_width(false);
+                    for grapheme in place(line_symbols) {
+                        let symbol = grapheme.grapheme.symbol;
+                        let max_line_width = if wrapped_lines.is_empty() {
+                            initial_width
+                        } else {
+                            subsequent_width
+                        };
+                        let symbol_whitespace = is_whitespace(symbol);
                         let symbol_width = symbol.width() as u16;
                         // Ignore characters wider than the total max width
-                        if symbol_width > self.max_line_width {
+                        if symbol_width > max_line_width {
                             continue;
                         }
 
                         // Append finished word to current line
                         if has_seen_non_whitespace && symbol_whitespace
                             // Append if trimmed (whitespaces removed) word would overflow
-                            || word_width + symbol_width > self.max_line_width && current_line.is_empty() && self.trim
+                            || word_width + symbol_width > max_line_width && current_line.is_empty() && self.trim
                             // Append if removed whitespace would overflow -> reset whitespace counting to prevent overflow
-                            || whitespace_width + symbol_width > self.max_line_width && current_line.is_empty() && self.trim
+                            || whitespace_width + symbol_width > max_line_width && current_line.is_empty() && self.trim
                             // Append if complete word would overflow
-                            || word_width + whitespace_width + symbol_width > self.max_line_width && current_line.is_empty() && !self.trim
+                            || word_width + whitespace_width + symbol_width > max_line_width && current_line.is_empty() && !self.trim
                         {
                             if !current_line.is_empty() || !self.trim {
                                 // Also append whitespaces if not trimming or current line is not
@@ -142,11 +236,11 @@ where
 
                         // Append the unfinished wrapped line to wrapped lines if it is as wide as
                         // max line width
-                        if current_line_width >= self.max_line_width
+                        if current_line_width >= max_line_width
                             // or if it would be too long with the current partially processed word added
-                            || current_line_width + whitespace_width + word_width >= self.max_line_width && symbol_width > 0
+                            || current_line_width + whitespace_width + word_width >= max_line_width && symbol_width > 0
                         {
-                            let mut remaining_width = (i32::from(self.max_line_width)
+                            let mut remaining_width = (i32::from(max_line_width)
                                 - i32::from(current_line_width))
                             .max(0) as u16;
                             wrapped_lines.push(std::mem::take(&mut current_line));
@@ -156,7 +250,7 @@ where
                             // whitespace
                             let mut first_whitespace = unfinished_whitespaces.pop_front();
                             while let Some(grapheme) = first_whitespace.as_ref() {
-                                let symbol_width = grapheme.symbol.width() as u16;
+                                let symbol_width = grapheme.width();
                                 whitespace_width -= symbol_width;
 
                                 if symbol_width > remaining_width {
@@ -165,6 +259,36 @@ where
                                 remaining_width -= symbol_width;
                                 first_whitespace = unfinished_whitespaces.pop_front();
                             }
+
+                            // The following wrapped lines can be narrower than the first one, in
+                            // which case the partially processed word is split to fit them
+                            let pending_width = if symbol_whitespace { 0 } else { symbol_width };
+                            if whitespace_width + word_width + pending_width > subsequent_width
+                                && word_width > 0
+                            {
+                                if !self.trim {
+                                    unfinished_word.splice(0..0, unfinished_whitespaces.drain(..));
+                                    word_width += whitespace_width;
+                                }
+                                unfinished_whitespaces.clear();
+                                whitespace_width = 0;
+                                while word_width > 0
+                                    && word_width + pending_width > subsequent_width
+                                {
+                                    let mut chunk_width = 0;
+                                    let split = unfinished_word
+                                        .iter()
+                                        .take_while(|grapheme| {
+                                            chunk_width += grapheme.width();
+                                            chunk_width <= subsequent_width
+                                        })
+                                        .count();
+                                    let rest = unfinished_word.split_off(split);
+                                    wrapped_lines
+                                        .push(std::mem::replace(&mut unfinished_word, rest));
+                                    word_width = unfinished_word.iter().map(Placed::width).sum();
+                                }
+                            }
                             // In case all whitespaces have been exhausted
                             if symbol_whitespace && first_whitespace.is_none() {
                                 // Prevent first whitespace to count towards next word
@@ -175,10 +299,10 @@ where
                         // Append symbol to unfinished, partially processed word
                         if symbol_whitespace {
                             whitespace_width += symbol_width;
-                            unfinished_whitespaces.push_back(StyledGrapheme { symbol, style });
+                            unfinished_whitespaces.push_back(grapheme);
                         } else {
                             word_width += symbol_width;
-                            unfinished_word.push(StyledGrapheme { symbol, style });
+                            unfinished_word.push(grapheme);
                         }
 
                         has_seen_non_whitespace = !symbol_whitespace;
@@ -203,6 +327,19 @@ where
                         // Append empty line if there was nothing to wrap in the first place
                         wrapped_lines.push(vec![]);
                     }
+                    if self.current_alignment == Alignment::Justify {
+                        // the last line of a paragraph is not justified
+                        let last_index = wrapped_lines.len() - 1;
+                        for (index, line) in wrapped_lines[..last_index].iter_mut().enumerate() {
+                            let width = if index == 0 {
+                                initial_width
+                            } else {
+                                subsequent_width
+                            };
+                            justify(line, width);
+                        }
+                    }
+                    self.indent_lines(&mut wrapped_lines);
 
                     self.wrapped_lines = Some(wrapped_lines.into_iter());
                 } else {
@@ -213,9 +350,13 @@ where
         }
 
         if let Some(line) = current_line {
-            self.current_line = line;
+            (self.current_line, self.current_offsets) = line
+                .into_iter()
+                .map(|placed| (placed.grapheme, placed.offset))
+                .unzip();
             Some(WrappedLine {
                 line: &self.current_line,
+                offsets: &self.current_offsets,
                 width: line_width,
                 alignment: self.current_alignment,
             })
@@ -225,6 +366,50 @@ where
     }
 }
 
+/// Whether the symbol separates words
+fn is_whitespace(symbol: &str) -> bool {
+    symbol == ZWSP || (symbol.chars().all(char::is_whitespace) && symbol != NBSP)
+}
+
+/// Stretches the line to `width` by distributing spaces between its words, the leftmost gaps
+/// receiving one more space when the extra space cannot be distributed evenly
+fn justify(line: &mut Vec<Placed<'_>>, width: u16) {
+    let Some(first) = line
+        .iter()
+        .position(|placed| !is_whitespace(placed.grapheme.symbol))
+    else {
+        return;
+    };
+    let last = line
+        .iter()
+        .rposition(|placed| !is_whitespace(placed.grapheme.symbol))
+        .unwrap_or(first);
+    // the index of the first symbol of each word following a gap
+    let gaps = (first..last)
+        .filter(|&index| {
+            is_whitespace(line[index].grapheme.symbol)
+                && !is_whitespace(line[index + 1].grapheme.symbol)
+        })
+        .map(|index| index + 1)
+        .collect_vec();
+    if gaps.is_empty() {
+        return;
+    }
+    let line_width: usize = line.iter().map(|placed| usize::from(placed.width())).sum();
+    let extra_width = usize::from(width).saturating_sub(line_width);
+    for (gap, &index) in gaps.iter().enumerate().rev() {
+        let count = extra_width / gaps.len() + usize::from(gap < extra_width % gaps.len());
+        let space = Placed {
+            grapheme: StyledGrapheme {
+                symbol: " ",
+                ..line[index - 1].grapheme.clone()
+            },
+            offset: None,
+        };
+        line.splice(index..index, std::iter::repeat(space).take(count));
+    }
+}
+
 /// A state machine that truncates overhanging lines.
 #[derive(Debug, Default, Clone)]
 pub struct LineTruncator<'a, O, I>
@@ -239,6 +424,7 @@ where
     input_lines: O,
     max_line_width: u16,
     current_line: Vec<StyledGrapheme<'a>>,
+    current_offsets: Vec<Option<usize>>,
     /// Record the offset to skip render
     horizontal_offset: u16,
 }
@@ -254,6 +440,7 @@ where
             max_line_width,
             horizontal_offset: 0,
             current_line: vec![],
+            current_offsets: vec![],
         }
     }
 
@@ -273,6 +460,7 @@ where
         }
 
         self.current_line.truncate(0);
+        self.current_offsets.truncate(0);
         let mut current_line_width = 0;
 
         let mut lines_exhausted = true;
@@ -282,7 +470,8 @@ where
             lines_exhausted = false;
             current_alignment = *alignment;
 
-            for StyledGrapheme { symbol, style } in current_line {
+            for Placed { grapheme, offset } in place(current_line) {
+                let symbol = grapheme.symbol;
                 // Ignore characters wider that the total max width.
                 if symbol.width() as u16 > self.max_line_width {
                     continue;
@@ -293,7 +482,10 @@ where
                     break;
                 }
 
-                let symbol = if horizontal_offset == 0 || Alignment::Left != *alignment {
+                // justified lines are not stretched when truncated, so they scroll like left
+                // aligned lines
+                let scrolls = matches!(alignment, Alignment::Left | Alignment::Justify);
+                let symbol = if horizontal_offset == 0 || !scrolls {
                     symbol
                 } else {
                     let w = symbol.width();
@@ -307,7 +499,12 @@ where
                     }
                 };
                 current_line_width += symbol.width() as u16;
-                self.current_line.push(StyledGrapheme { symbol, style });
+                // the scrolled part of the symbol is trimmed from its start
+                let trimmed = grapheme.symbol.len() - symbol.len();
+                self.current_offsets
+                    .push(offset.map(|offset| offset + trimmed));
+                self.current_line
+                    .push(StyledGrapheme { symbol, ..grapheme });
             }
         }
 
@@ -316,6 +513,7 @@ where
         } else {
             Some(WrappedLine {
                 line: &self.current_line,
+                offsets: &self.current_offsets,
                 width: current_line_width,
                 alignment: current_alignment,
             })
@@ -344,7 +542,7 @@ mod test {
     use super::*;
     use crate::{
         style::Style,
-        text::{Line, Text},
+        text::{Line, Span, Text},
     };
 
     #[derive(Clone, Copy)]
@@ -380,6 +578,7 @@ mod test {
             line: styled,
             width,
             alignment,
+            ..
         }) = composer.next_line()
         {
             let line = styled
@@ -715,4 +914,108 @@ mod test {
         let (word_wrapper, _, _) = run_composer(Composer::WordWrapper { trim: true }, line, width);
         assert_eq!(word_wrapper, vec!["foo", "bar"]);
     }
+
+    /// Wraps the text with the given indents and returns the lines and their widths
+    fn wrap_with_indent(
+        text: &str,
+        width: u16,
+        initial: &'static str,
+        subsequent: &'static str,
+    ) -> (Vec<String>, Vec<u16>) {
+        let text = Text::from(text);
+        let styled_lines = text.iter().map(|line| {
+            (
+                line.styled_graphemes(Style::default()),
+                line.alignment.unwrap_or(Alignment::Left),
+            )
+        });
+        let (initial, subsequent) = (Span::raw(initial), Span::raw(subsequent));
+        let mut composer = WordWrapper::new(styled_lines, width, true);
+        composer.set_indent(
+            initial.styled_graphemes(Style::default()).collect(),
+            subsequent.styled_graphemes(Style::default()).collect(),
+        );
+        let mut lines = vec![];
+        let mut widths = vec![];
+        while let Some(WrappedLine { line, width, .. }) = composer.next_line() {
+            lines.push(line.iter().map(|grapheme| grapheme.symbol).collect());
+            widths.push(width);
+        }
+        (lines, widths)
+    }
+
+    #[test]
+    fn line_composer_word_wrapper_indent() {
+        let (lines, widths) = wrap_with_indent("a bullet point that wraps\n\nnext", 12, "- ", "  ");
+        assert_eq!(
+            lines,
+            ["- a bullet", "  point that", "  wraps", "", "- next"]
+        );
+        assert_eq!(widths, [10, 12, 7, 0, 6]);
+    }
+
+    #[test]
+    fn line_composer_word_wrapper_hanging_indent() {
+        let (lines, _) = wrap_with_indent("12:00 message that wraps", 12, "", "      ");
+        assert_eq!(
+            lines,
+            ["12:00", "      messag", "      e that", "      wraps"]
+        );
+    }
+
+    #[test]
+    fn line_composer_word_wrapper_indent_too_wide() {
+        let (lines, _) = wrap_with_indent("some text", 4, "----", "----");
+        assert_eq!(lines, ["some", "text"]);
+    }
+
+    #[test]
+    fn line_composer_word_wrapper_justify() {
+        let width = 12;
+        let line = Line::from("the quick brown fox jumps over").alignment(Alignment::Justify);
+        let (word_wrapper, widths, _) =
+            run_composer(Composer::WordWrapper { trim: true }, line, width);
+        assert_eq!(word_wrapper, ["the    quick", "brown    fox", "jumps over"]);
+        assert_eq!(widths, [12, 12, 10]);
+    }
+
+    #[test]
+    fn line_composer_word_wrapper_justify_uneven() {
+        let width = 10;
+        let line = Line::from("a b c d e f").alignment(Alignment::Justify);
+        let (word_wrapper, _, _) = run_composer(Composer::WordWrapper { trim: true }, line, width);
+        assert_eq!(word_wrapper, ["a  b c d e", "f"]);
+    }
+
+    #[test]
+    fn line_composer_word_wrapper_justify_single_word() {
+        let width = 5;
+        let line = Line::from("abcdefgh").alignment(Alignment::Justify);
+        let (word_wrapper, _, _) = run_composer(Composer::WordWrapper { trim: true }, line, width);
+        assert_eq!(word_wrapper, ["abcde", "fgh"]);
+    }
+
+    #[test]
+    fn line_composer_line_truncator_justify() {
+        let width = 12;
+        let line = Line::from("a b").alignment(Alignment::Justify);
+        let (line_truncator, _, _) = run_composer(Composer::LineTruncator, line, width);
+        assert_eq!(line_truncator, ["a b"]);
+    }
+
+    #[test]
+    fn line_composer_line_truncator_justify_horizontal_offset() {
+        let line = Line::from("abcdef").alignment(Alignment::Justify);
+        let styled_lines = [(
+            line.iter()
+                .flat_map(|span| span.styled_graphemes(Style::default())),
+            Alignment::Justify,
+        )];
+        let mut composer = LineTruncator::new(styled_lines.into_iter(), 4);
+        composer.set_horizontal_offset(2);
+        let WrappedLine { line, width, .. } = composer.next_line().unwrap();
+        let symbols = line.iter().map(|g| g.symbol).collect::<String>();
+        assert_eq!(symbols, "cdef");
+        assert_eq!(width, 4);
+    }
 }
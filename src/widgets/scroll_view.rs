@@ -0,0 +1,405 @@
+use strum::{Display, EnumString};
+
+use crate::{
+    layout::{Position, Size},
+    prelude::*,
+    widgets::{Scrollbar, ScrollbarOrientation, ScrollbarState},
+};
+
+/// State of the [`ScrollView`] widget
+///
+/// The state tracks the scroll offset, which is the position of the content that is displayed in
+/// the top left corner of the view. The offset is clamped when the view is rendered so that the
+/// view never scrolls past the end of the content.
+///
+/// # Example
+///
+/// ```rust
+/// # use ratatui::{prelude::*, widgets::*};
+/// let mut state = ScrollViewState::default();
+/// state.scroll_down();
+/// state.scroll_right();
+/// assert_eq!(state.offset(), (1, 1).into());
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct ScrollViewState {
+    offset: Position,
+    /// The size of the visible area during the last render, used for page scrolling
+    page_size: Option<Size>,
+}
+
+impl ScrollViewState {
+    /// Creates a new state with the given offset
+    pub const fn new(offset: Position) -> Self {
+        Self {
+            offset,
+            page_size: None,
+        }
+    }
+
+    /// The position of the content displayed in the top left corner of the view
+    pub const fn offset(&self) -> Position {
+        self.offset
+    }
+
+    /// Sets the position of the content displayed in the top left corner of the view
+    pub fn set_offset(&mut self, offset: Position) {
+        self.offset = offset;
+    }
+
+    /// Scrolls up by one row
+    pub fn scroll_up(&mut self) {
+        self.offset.y = self.offset.y.saturating_sub(1);
+    }
+
+    /// Scrolls down by one row
+    pub fn scroll_down(&mut self) {
+        self.offset.y = self.offset.y.saturating_add(1);
+    }
+
+    /// Scrolls left by one column
+    pub fn scroll_left(&mut self) {
+        self.offset.x = self.offset.x.saturating_sub(1);
+    }
+
+    /// Scrolls right by one column
+    pub fn scroll_right(&mut self) {
+        self.offset.x = self.offset.x.saturating_add(1);
+    }
+
+    /// Scrolls up by the height of the view
+    ///
+    /// The height of the view is only known after the first render, before that this scrolls by
+    /// one row.
+    pub fn scroll_page_up(&mut self) {
+        let page = self.page_size.map_or(1, |size| size.height);
+        self.offset.y = self.offset.y.saturating_sub(page);
+    }
+
+    /// Scrolls down by the height of the view
+    ///
+    /// The height of the view is only known after the first render, before that this scrolls by
+    /// one row.
+    pub fn scroll_page_down(&mut self) {
+        let page = self.page_size.map_or(1, |size| size.height);
+        self.offset.y = self.offset.y.saturating_add(page);
+    }
+
+    /// Scrolls to the top of the content
+    pub fn scroll_to_top(&mut self) {
+        self.offset.y = 0;
+    }
+
+    /// Scrolls to the bottom of the content
+    ///
+    /// The offset is clamped to the last page of the content on the next render.
+    pub fn scroll_to_bottom(&mut self) {
+        self.offset.y = u16::MAX;
+    }
+}
+
+/// Defines when a [`ScrollView`] renders a scrollbar
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ScrollbarVisibility {
+    /// Render the scrollbar only when the content does not fit in the view
+    #[default]
+    Automatic,
+    /// Always render the scrollbar
+    Always,
+    /// Never render the scrollbar
+    Never,
+}
+
+/// A widget that displays a scrollable window over content larger than the visible area
+///
+/// Widgets are rendered into the scroll view with [`ScrollView::render_widget`] using coordinates
+/// relative to the content (the top left corner of the content is `(0, 0)`). The content is stored
+/// in an internal [`Buffer`] of the size given to [`ScrollView::new`]. When the scroll view is
+/// rendered, the part of the content selected by the [`ScrollViewState`] offset is copied to the
+/// visible area.
+///
+/// Scrollbars (without arrows) are rendered on the right and bottom edges of the area when the
+/// content does not fit. This can be changed with [`ScrollView::vertical_scrollbar_visibility`] and
+/// [`ScrollView::horizontal_scrollbar_visibility`].
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{layout::Size, prelude::*, widgets::*};
+///
+/// # fn ui(frame: &mut Frame, state: &mut ScrollViewState) {
+/// let mut scroll_view = ScrollView::new(Size::new(40, 100));
+/// for i in 0..100 {
+///     scroll_view.render_widget(Line::raw(format!("Line {i}")), Rect::new(0, i, 40, 1));
+/// }
+/// frame.render_stateful_widget(scroll_view, frame.size(), state);
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ScrollView {
+    buf: Buffer,
+    vertical_scrollbar_visibility: ScrollbarVisibility,
+    horizontal_scrollbar_visibility: ScrollbarVisibility,
+}
+
+impl ScrollView {
+    /// Creates a new scroll view with content of the given size
+    pub fn new(size: Size) -> Self {
+        Self {
+            buf: Buffer::empty(Rect::new(0, 0, size.width, size.height)),
+            vertical_scrollbar_visibility: ScrollbarVisibility::default(),
+            horizontal_scrollbar_visibility: ScrollbarVisibility::default(),
+        }
+    }
+
+    /// The size of the content
+    pub const fn size(&self) -> Size {
+        Size::new(self.buf.area.width, self.buf.area.height)
+    }
+
+    /// The area of the content, which always starts at `(0, 0)`
+    pub const fn area(&self) -> Rect {
+        self.buf.area
+    }
+
+    /// The buffer holding the content
+    pub const fn buf(&self) -> &Buffer {
+        &self.buf
+    }
+
+    /// Mutable reference to the buffer holding the content
+    ///
+    /// This can be used to render widgets that need direct access to the buffer.
+    pub fn buf_mut(&mut self) -> &mut Buffer {
+        &mut self.buf
+    }
+
+    /// Sets when the vertical scrollbar is rendered
+    ///
+    /// Defaults to [`ScrollbarVisibility::Automatic`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn vertical_scrollbar_visibility(mut self, visibility: ScrollbarVisibility) -> Self {
+        self.vertical_scrollbar_visibility = visibility;
+        self
+    }
+
+    /// Sets when the horizontal scrollbar is rendered
+    ///
+    /// Defaults to [`ScrollbarVisibility::Automatic`].
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn horizontal_scrollbar_visibility(
+        mut self,
+        visibility: ScrollbarVisibility,
+    ) -> Self {
+        self.horizontal_scrollbar_visibility = visibility;
+        self
+    }
+
+    /// Renders a widget into the content of the scroll view
+    ///
+    /// The area is relative to the content and is clipped to the size of the content.
+    pub fn render_widget<W: Widget>(&mut self, widget: W, area: Rect) {
+        widget.render(area.intersection(self.buf.area), &mut self.buf);
+    }
+
+    /// Renders a stateful widget into the content of the scroll view
+    ///
+    /// The area is relative to the content and is clipped to the size of the content.
+    pub fn render_stateful_widget<W: StatefulWidget>(
+        &mut self,
+        widget: W,
+        area: Rect,
+        state: &mut W::State,
+    ) {
+        widget.render(area.intersection(self.buf.area), &mut self.buf, state);
+    }
+
+    /// Computes which scrollbars are visible in the given area
+    fn visible_scrollbars(&self, area: Rect) -> (bool, bool) {
+        let size = self.size();
+        let fixed = |visibility| match visibility {
+            ScrollbarVisibility::Automatic => None,
+            ScrollbarVisibility::Always => Some(true),
+            ScrollbarVisibility::Never => Some(false),
+        };
+        let vertical_fixed = fixed(self.vertical_scrollbar_visibility);
+        let horizontal_fixed = fixed(self.horizontal_scrollbar_visibility);
+        let vertical = vertical_fixed.unwrap_or(size.height > area.height);
+        // a scrollbar takes space from the other axis, which may make the other scrollbar needed
+        let horizontal =
+            horizontal_fixed.unwrap_or(size.width > area.width.saturating_sub(u16::from(vertical)));
+        let vertical = vertical_fixed
+            .unwrap_or(size.height > area.height.saturating_sub(u16::from(horizontal)));
+        (vertical, horizontal)
+    }
+}
+
+impl StatefulWidget for ScrollView {
+    type State = ScrollViewState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        self.render_ref(area, buf, state);
+    }
+}
+
+impl StatefulWidget for &ScrollView {
+    type State = ScrollViewState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        self.render_ref(area, buf, state);
+    }
+}
+
+impl StatefulWidgetRef for ScrollView {
+    type State = ScrollViewState;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let area = area.intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+        let (vertical, horizontal) = self.visible_scrollbars(area);
+        let visible = Rect {
+            width: area.width.saturating_sub(u16::from(vertical)),
+            height: area.height.saturating_sub(u16::from(horizontal)),
+            ..area
+        };
+
+        let size = self.size();
+        let max_offset = Position::new(
+            size.width.saturating_sub(visible.width),
+            size.height.saturating_sub(visible.height),
+        );
+        state.offset.x = state.offset.x.min(max_offset.x);
+        state.offset.y = state.offset.y.min(max_offset.y);
+        state.page_size = Some(visible.as_size());
+
+        let copied = Rect::new(
+            state.offset.x,
+            state.offset.y,
+            visible.width,
+            visible.height,
+        )
+        .intersection(self.buf.area);
+        for (dy, y) in (copied.top()..copied.bottom()).enumerate() {
+            for (dx, x) in (copied.left()..copied.right()).enumerate() {
+                *buf.get_mut(visible.x + dx as u16, visible.y + dy as u16) =
+                    self.buf.get(x, y).clone();
+            }
+        }
+
+        if vertical {
+            let mut scrollbar_state = ScrollbarState::new(max_offset.y as usize + 1)
+                .position(state.offset.y as usize)
+                .viewport_content_length(visible.height as usize);
+            let scrollbar_area = Rect {
+                height: visible.height,
+                ..area
+            };
+            Scrollbar::new(ScrollbarOrientation::VerticalRight)
+                .begin_symbol(None)
+                .end_symbol(None)
+                .render(scrollbar_area, buf, &mut scrollbar_state);
+        }
+        if horizontal {
+            let mut scrollbar_state = ScrollbarState::new(max_offset.x as usize + 1)
+                .position(state.offset.x as usize)
+                .viewport_content_length(visible.width as usize);
+            let scrollbar_area = Rect {
+                width: visible.width,
+                ..area
+            };
+            Scrollbar::new(ScrollbarOrientation::HorizontalBottom)
+                .begin_symbol(None)
+                .end_symbol(None)
+                .render(scrollbar_area, buf, &mut scrollbar_state);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    /// A 6x4 scroll view with one distinct letter per row
+    #[fixture]
+    fn scroll_view() -> ScrollView {
+        let mut scroll_view = ScrollView::new(Size::new(6, 4));
+        for (y, line) in ["aaaaaa", "bbbbbb", "cccccc", "dddddd"]
+            .into_iter()
+            .enumerate()
+        {
+            scroll_view.render_widget(line, Rect::new(0, y as u16, 6, 1));
+        }
+        scroll_view
+    }
+
+    fn render(
+        scroll_view: &ScrollView,
+        state: &mut ScrollViewState,
+        width: u16,
+        height: u16,
+    ) -> Buffer {
+        let mut buf = Buffer::empty(Rect::new(0, 0, width, height));
+        scroll_view.render(buf.area, &mut buf, state);
+        buf
+    }
+
+    #[rstest]
+    fn render_fits(scroll_view: ScrollView) {
+        let mut state = ScrollViewState::default();
+        let buf = render(&scroll_view, &mut state, 7, 4);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["aaaaaa ", "bbbbbb ", "cccccc ", "dddddd "])
+        );
+    }
+
+    #[rstest]
+    fn render_vertical_scrollbar(scroll_view: ScrollView) {
+        let mut state = ScrollViewState::new(Position::new(0, 2));
+        let buf = render(&scroll_view, &mut state, 7, 2);
+        assert_eq!(buf, Buffer::with_lines(["cccccc║", "dddddd█"]));
+    }
+
+    #[rstest]
+    fn render_both_scrollbars(scroll_view: ScrollView) {
+        let mut state = ScrollViewState::new(Position::new(1, 1));
+        let buf = render(&scroll_view, &mut state, 5, 3);
+        assert_eq!(buf, Buffer::with_lines(["bbbb║", "cccc█", "═██═ "]));
+    }
+
+    #[rstest]
+    fn render_clamps_offset(scroll_view: ScrollView) {
+        let mut state = ScrollViewState::default();
+        state.scroll_to_bottom();
+        let scroll_view = scroll_view.vertical_scrollbar_visibility(ScrollbarVisibility::Never);
+        let buf = render(&scroll_view, &mut state, 6, 2);
+        assert_eq!(buf, Buffer::with_lines(["cccccc", "dddddd"]));
+        assert_eq!(state.offset(), Position::new(0, 2));
+
+        state.scroll_page_up();
+        assert_eq!(state.offset(), Position::new(0, 0));
+    }
+
+    #[rstest]
+    fn render_always_scrollbar(scroll_view: ScrollView) {
+        let mut state = ScrollViewState::default();
+        let scroll_view = scroll_view.horizontal_scrollbar_visibility(ScrollbarVisibility::Always);
+        let buf = render(&scroll_view, &mut state, 6, 5);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["aaaaaa", "bbbbbb", "cccccc", "dddddd", "██████"])
+        );
+    }
+
+    #[test]
+    fn render_widget_clips_to_content() {
+        let mut scroll_view = ScrollView::new(Size::new(3, 1));
+        scroll_view.render_widget("hello", Rect::new(1, 0, 5, 3));
+        assert_eq!(scroll_view.buf(), &Buffer::with_lines([" he"]));
+    }
+}
@@ -7,12 +7,15 @@
     clippy::wildcard_imports
 )]
 
-use std::iter;
+use std::{
+    hash::{Hash, Hasher},
+    iter,
+};
 
 use strum::{Display, EnumString};
 use unicode_width::UnicodeWidthStr;
 
-use crate::{prelude::*, symbols::scrollbar::*};
+use crate::{layout::Position, prelude::*, symbols::scrollbar::*};
 
 /// A widget to display a scrollbar
 ///
@@ -29,6 +32,10 @@ use crate::{prelude::*, symbols::scrollbar::*};
 /// └─────────── begin
 /// ```
 ///
+/// The length of the thumb is proportional to the ratio of the viewport to the content, so that a
+/// long document gets a short thumb. Use [`Scrollbar::min_thumb_length`] to keep the thumb easy to
+/// see and to grab with the mouse, and [`Scrollbar::thumb_end_style`] to mark its ends.
+///
 /// # Important
 ///
 /// You must specify the [`ScrollbarState::content_length`] before rendering the `Scrollbar`, or
@@ -77,6 +84,8 @@ pub struct Scrollbar<'a> {
     orientation: ScrollbarOrientation,
     thumb_style: Style,
     thumb_symbol: &'a str,
+    thumb_end_style: Style,
+    min_thumb_length: u16,
     track_style: Style,
     track_symbol: Option<&'a str>,
     begin_symbol: Option<&'a str>,
@@ -94,7 +103,7 @@ pub struct Scrollbar<'a> {
 ///             └───────┘
 ///          HorizontalBottom
 /// ```
-#[derive(Debug, Default, Display, EnumString, Clone, Eq, PartialEq, Hash)]
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
 pub enum ScrollbarOrientation {
     /// Positions the scrollbar on the right, scrolling vertically
     #[default]
@@ -132,7 +141,29 @@ pub enum ScrollbarOrientation {
 ///
 /// If you don't have multi-line content, you can leave the `viewport_content_length` set to the
 /// default and it'll use the track size as a `viewport_content_length`.
-#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+///
+/// The state also records where the track and the thumb of the scrollbar were rendered, see
+/// [`ScrollbarState::track_area`] and [`ScrollbarState::thumb_area`]. This is used by
+/// [`ScrollbarState::hit`] to map a mouse click or drag on the scrollbar to a position, e.g.:
+///
+/// ```rust
+/// use ratatui::{
+///     backend::event::{MouseEvent, MouseEventKind},
+///     layout::Position,
+///     prelude::*,
+///     widgets::ScrollbarState,
+/// };
+///
+/// # fn handle_mouse(event: MouseEvent, state: &mut ScrollbarState, area: Rect) {
+/// if let MouseEventKind::Down(_) | MouseEventKind::Drag(_) = event.kind {
+///     let mouse = Position::new(event.column, event.row);
+///     if let Some(position) = state.hit(mouse, area) {
+///         *state = state.position(position);
+///     }
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ScrollbarState {
     /// The total length of the scrollable content.
@@ -143,6 +174,15 @@ pub struct ScrollbarState {
     ///
     /// FIXME: this should be `Option<usize>`, but it will break serialization to change it.
     viewport_content_length: usize,
+    /// The area of the track excluding the arrow heads when the scrollbar was last rendered.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    track_area: Rect,
+    /// The area of the thumb when the scrollbar was last rendered.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    thumb_area: Rect,
+    /// The orientation of the scrollbar when it was last rendered.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    orientation: ScrollbarOrientation,
 }
 
 /// An enum representing a scrolling direction.
@@ -187,6 +227,8 @@ impl<'a> Scrollbar<'a> {
             orientation,
             thumb_symbol: symbols.thumb,
             thumb_style: Style::new(),
+            thumb_end_style: Style::new(),
+            min_thumb_length: 1,
             track_symbol: Some(symbols.track),
             track_style: Style::new(),
             begin_symbol: Some(symbols.begin),
@@ -258,6 +300,35 @@ impl<'a> Scrollbar<'a> {
         self
     }
 
+    /// Sets the style of the first and last cells of the scrollbar thumb.
+    ///
+    /// The style is applied on top of the [`Scrollbar::thumb_style`], e.g. to make the ends of a
+    /// long thumb stand out from the rest of it. See [`Scrollbar`] for a visual example of what the
+    /// thumb represents.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn thumb_end_style<S: Into<Style>>(mut self, thumb_end_style: S) -> Self {
+        self.thumb_end_style = thumb_end_style.into();
+        self
+    }
+
+    /// Sets the minimum length of the scrollbar thumb, in cells.
+    ///
+    /// The length of the thumb is proportional to the ratio of the viewport to the content, which
+    /// makes it a single cell for large documents. A minimum length keeps the thumb usable in that
+    /// case. The thumb is never longer than the track. Defaults to 1.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn min_thumb_length(mut self, min_thumb_length: u16) -> Self {
+        self.min_thumb_length = min_thumb_length;
+        self
+    }
+
     /// Sets the symbol that represents the track of the scrollbar.
     ///
     /// See [`Scrollbar`] for a visual example of what this represents.
@@ -397,6 +468,26 @@ impl Default for ScrollbarState {
     }
 }
 
+/// Scrollbar states are equal if they have the same content length, position and viewport content
+/// length, regardless of the areas and orientation of the last render
+impl PartialEq for ScrollbarState {
+    fn eq(&self, other: &Self) -> bool {
+        self.content_length == other.content_length
+            && self.position == other.position
+            && self.viewport_content_length == other.viewport_content_length
+    }
+}
+
+impl Eq for ScrollbarState {}
+
+impl Hash for ScrollbarState {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.content_length.hash(state);
+        self.position.hash(state);
+        self.viewport_content_length.hash(state);
+    }
+}
+
 impl ScrollbarState {
     /// Constructs a new [`ScrollbarState`] with the specified content length.
     ///
@@ -408,6 +499,9 @@ impl ScrollbarState {
             content_length,
             position: 0,
             viewport_content_length: 0,
+            track_area: Rect::ZERO,
+            thumb_area: Rect::ZERO,
+            orientation: ScrollbarOrientation::VerticalRight,
         }
     }
 
@@ -477,16 +571,86 @@ impl ScrollbarState {
             }
         }
     }
+
+    /// Returns the area of the track, excluding the arrow heads, where the scrollbar was last
+    /// rendered.
+    ///
+    /// This is empty if the scrollbar was not rendered yet, or if it had nothing to render.
+    #[must_use = "returns the area of the track"]
+    pub const fn track_area(&self) -> Rect {
+        self.track_area
+    }
+
+    /// Returns the area of the thumb where the scrollbar was last rendered.
+    ///
+    /// This is empty if the scrollbar was not rendered yet, or if it had nothing to render.
+    #[must_use = "returns the area of the thumb"]
+    pub const fn thumb_area(&self) -> Rect {
+        self.thumb_area
+    }
+
+    /// Returns the scroll position matching a mouse position on the scrollbar.
+    ///
+    /// `area` is the area the scrollbar was rendered in. The mapping uses the geometry of the last
+    /// render:
+    /// - on the track, the position places the center of the thumb under the mouse, so that
+    ///   dragging the thumb keeps it under the mouse, and clicking the first or last cell of the
+    ///   track scrolls to the start or the end of the content;
+    /// - on the begin or end arrow head, the position is one step before or after the current one.
+    ///
+    /// Returns `None` if the mouse is not on the scrollbar, or if the scrollbar was not rendered.
+    #[must_use = "returns the scroll position to move to"]
+    pub fn hit(&self, position: Position, area: Rect) -> Option<usize> {
+        let (track, thumb) = (self.track_area, self.thumb_area);
+        if !area.contains(position) || track.is_empty() {
+            return None;
+        }
+        // the coordinates along the scrollbar, and whether the mouse is on the line of the bar
+        let (mouse, track_start, track_length, thumb_length, on_bar) =
+            if self.orientation.is_vertical() {
+                let on_bar = position.x == track.x;
+                (position.y, track.y, track.height, thumb.height, on_bar)
+            } else {
+                let on_bar = position.y == track.y;
+                (position.x, track.x, track.width, thumb.width, on_bar)
+            };
+        if !on_bar {
+            return None;
+        }
+        let track_end = track_start + track_length;
+        let max_position = self.content_length.saturating_sub(1);
+        if mouse < track_start {
+            // the begin arrow head is in the cell before the track
+            return (mouse + 1 == track_start).then(|| self.position.saturating_sub(1));
+        }
+        if mouse >= track_end {
+            // the end arrow head is in the cell after the track
+            return (mouse == track_end).then(|| self.position.saturating_add(1).min(max_position));
+        }
+        let travel = track_length.saturating_sub(thumb_length);
+        if travel == 0 {
+            return Some(0);
+        }
+        let offset = (mouse - track_start)
+            .saturating_sub(thumb_length / 2)
+            .min(travel);
+        let position = f64::from(offset) * max_position as f64 / f64::from(travel);
+        Some(position.round() as usize)
+    }
 }
 
 impl<'a> StatefulWidget for Scrollbar<'a> {
     type State = ScrollbarState;
 
     fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        state.track_area = Rect::ZERO;
+        state.thumb_area = Rect::ZERO;
+        state.orientation = self.orientation;
         if state.content_length == 0 || self.track_length_excluding_arrow_heads(area) == 0 {
             return;
         }
 
+        (state.track_area, state.thumb_area) = self.track_and_thumb_areas(area, state);
         let mut bar = self.bar_symbols(area, state);
         let area = self.scollbar_area(area);
         for x in area.left()..area.right() {
@@ -510,7 +674,16 @@ impl Scrollbar<'_> {
 
         let begin = self.begin_symbol.map(|s| Some((s, self.begin_style)));
         let track = Some(self.track_symbol.map(|s| (s, self.track_style)));
-        let thumb = Some(Some((self.thumb_symbol, self.thumb_style)));
+        let thumb_end_style = self.thumb_style.patch(self.thumb_end_style);
+        let thumb = (0..thumb_len).map(move |i| {
+            let is_end = i == 0 || i == thumb_len - 1;
+            let style = if is_end {
+                thumb_end_style
+            } else {
+                self.thumb_style
+            };
+            Some(Some((self.thumb_symbol, style)))
+        });
         let end = self.end_symbol.map(|s| Some((s, self.end_style)));
 
         // `<`
@@ -518,7 +691,7 @@ impl Scrollbar<'_> {
             // `<═══`
             .chain(iter::repeat(track).take(track_start_len))
             // `<═══█████`
-            .chain(iter::repeat(thumb).take(thumb_len))
+            .chain(thumb)
             // `<═══█████═══════`
             .chain(iter::repeat(track).take(track_end_len))
             // `<═══█████═══════>`
@@ -555,15 +728,47 @@ impl Scrollbar<'_> {
         // Make sure that the thumb is at least 1 cell long by ensuring that the start of the thumb
         // is less than the track_len. We use the positions instead of the sizes and use nearest
         // integer instead of floor / ceil to avoid problems caused by rounding errors.
-        let thumb_start = thumb_start.round().clamp(0.0, track_length - 1.0) as usize;
+        let mut thumb_start = thumb_start.round().clamp(0.0, track_length - 1.0) as usize;
         let thumb_end = thumb_end.round().clamp(0.0, track_length) as usize;
 
-        let thumb_length = thumb_end.saturating_sub(thumb_start).max(1);
+        let mut thumb_length = thumb_end.saturating_sub(thumb_start).max(1);
+
+        // Lengthen the thumb to its minimum length, and spread its start over the shorter distance
+        // it can travel along the track so that it still reaches both ends of the track.
+        let min_thumb_length = usize::from(self.min_thumb_length).min(track_length as usize);
+        if thumb_length < min_thumb_length {
+            thumb_length = min_thumb_length;
+            let travel = (track_length as usize - thumb_length) as f64;
+            thumb_start = if max_position == 0.0 {
+                0
+            } else {
+                (start_position * travel / max_position).round() as usize
+            };
+        }
         let track_end_length = (track_length as usize).saturating_sub(thumb_start + thumb_length);
 
         (thumb_start, thumb_length, track_end_length)
     }
 
+    /// Returns the areas of the track, excluding the arrow heads, and of the thumb
+    fn track_and_thumb_areas(&self, area: Rect, state: &ScrollbarState) -> (Rect, Rect) {
+        let (thumb_start, thumb_length, _) = self.part_lengths(area, state);
+        let bar = self.scollbar_area(area);
+        // the begin arrow head takes a single cell, see `bar_symbols`
+        let track_start = u16::from(self.begin_symbol.is_some());
+        let track_length = self.track_length_excluding_arrow_heads(area);
+        let (thumb_start, thumb_length) = (thumb_start as u16, thumb_length as u16);
+        if self.orientation.is_vertical() {
+            let track = Rect::new(bar.x, bar.y + track_start, 1, track_length);
+            let thumb = Rect::new(bar.x, track.y + thumb_start, 1, thumb_length);
+            (track, thumb)
+        } else {
+            let track = Rect::new(bar.x + track_start, bar.y, track_length, 1);
+            let thumb = Rect::new(track.x + thumb_start, bar.y, thumb_length, 1);
+            (track, thumb)
+        }
+    }
+
     fn scollbar_area(&self, area: Rect) -> Rect {
         match self.orientation {
             ScrollbarOrientation::VerticalLeft => area.columns().next(),
@@ -603,6 +808,8 @@ impl Scrollbar<'_> {
     }
 }
 
+// the orientation is taken by reference as it was before it implemented `Copy`
+#[allow(clippy::trivially_copy_pass_by_ref)]
 impl ScrollbarOrientation {
     /// Returns `true` if the scrollbar is vertical.
     #[must_use = "returns the requested kind of the scrollbar"]
@@ -1047,4 +1254,122 @@ mod tests {
         scrollbar_no_arrows.render(buffer.area, &mut buffer, &mut state);
         assert_eq!(buffer, Buffer::with_lines([expected]));
     }
+
+    #[test]
+    fn track_and_thumb_areas_after_render() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 10));
+        let mut state = ScrollbarState::new(10).position(3);
+        assert_eq!(state.track_area(), Rect::ZERO);
+        assert_eq!(state.thumb_area(), Rect::ZERO);
+
+        Scrollbar::new(ScrollbarOrientation::VerticalRight)
+            .begin_symbol(Some("<"))
+            .end_symbol(Some(">"))
+            .render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(state.track_area(), Rect::new(4, 1, 1, 8));
+        assert_eq!(state.thumb_area(), Rect::new(4, 2, 1, 4));
+
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        let mut state = ScrollbarState::new(10).position(9);
+        scrollbar_no_arrows().render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(state.track_area(), Rect::new(0, 0, 10, 1));
+        assert_eq!(state.thumb_area(), Rect::new(5, 0, 5, 1));
+    }
+
+    #[rstest]
+    #[case::begin_arrow((4, 0), Some(2))]
+    #[case::end_arrow((4, 9), Some(4))]
+    #[case::track_start((4, 1), Some(0))]
+    #[case::track_middle((4, 4), Some(2))]
+    #[case::track_end((4, 8), Some(9))]
+    #[case::beside_bar((3, 4), None)]
+    #[case::outside_area((9, 4), None)]
+    fn hit(#[case] position: (u16, u16), #[case] expected: Option<usize>) {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 5, 10));
+        let mut state = ScrollbarState::new(10).position(3);
+        let position = Position::from(position);
+        assert_eq!(state.hit(position, buffer.area), None);
+
+        Scrollbar::new(ScrollbarOrientation::VerticalRight)
+            .begin_symbol(Some("<"))
+            .end_symbol(Some(">"))
+            .render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(state.hit(position, buffer.area), expected);
+    }
+
+    #[test]
+    fn state_eq_ignores_render_caches() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        let state = ScrollbarState::new(10).position(3);
+        let mut rendered = state;
+        Scrollbar::new(ScrollbarOrientation::HorizontalBottom).render(
+            buffer.area,
+            &mut buffer,
+            &mut rendered,
+        );
+        assert_ne!(rendered.track_area(), state.track_area());
+        assert_eq!(rendered, state);
+
+        let hash = |state: &ScrollbarState| {
+            let mut hasher = std::collections::hash_map::DefaultHasher::new();
+            state.hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash(&rendered), hash(&state));
+    }
+
+    #[test]
+    fn hit_horizontal() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 2));
+        let mut state = ScrollbarState::new(10);
+        Scrollbar::new(ScrollbarOrientation::HorizontalBottom)
+            .begin_symbol(None)
+            .end_symbol(None)
+            .render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(state.hit(Position::new(0, 1), buffer.area), Some(0));
+        assert_eq!(state.hit(Position::new(9, 1), buffer.area), Some(9));
+        assert_eq!(state.hit(Position::new(9, 0), buffer.area), None);
+    }
+
+    #[rstest]
+    #[case::position_0("###-------", 0)]
+    #[case::position_50("----###---", 50)]
+    #[case::position_99("-------###", 99)]
+    fn render_scrollbar_min_thumb_length(
+        #[case] expected: &str,
+        #[case] position: usize,
+        scrollbar_no_arrows: Scrollbar,
+    ) {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        let mut state = ScrollbarState::new(100).position(position);
+        scrollbar_no_arrows
+            .min_thumb_length(3)
+            .render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(buffer, Buffer::with_lines([expected]));
+    }
+
+    #[rstest]
+    fn render_scrollbar_min_thumb_length_longer_than_track(scrollbar_no_arrows: Scrollbar) {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 1));
+        let mut state = ScrollbarState::new(100).position(50);
+        scrollbar_no_arrows
+            .min_thumb_length(10)
+            .render(buffer.area, &mut buffer, &mut state);
+        assert_eq!(buffer, Buffer::with_lines(["####"]));
+    }
+
+    #[rstest]
+    fn render_scrollbar_thumb_end_style(scrollbar_no_arrows: Scrollbar) {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        let mut state = ScrollbarState::new(10).position(2);
+        scrollbar_no_arrows
+            .thumb_style(Style::new().bold())
+            .thumb_end_style(Color::Red)
+            .render(buffer.area, &mut buffer, &mut state);
+        let mut expected = Buffer::with_lines(["-#####----"]);
+        expected.set_style(Rect::new(1, 0, 5, 1), Style::new().bold());
+        expected.set_style(Rect::new(1, 0, 1, 1), Color::Red);
+        expected.set_style(Rect::new(5, 0, 1, 1), Color::Red);
+        assert_eq!(buffer, expected);
+    }
 }
@@ -0,0 +1,544 @@
+use crate::{
+    backend::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    prelude::*,
+    widgets::{Block, Clear, List, ListState, StatefulWidgetRef},
+};
+
+/// State of the [`Select`] widget
+///
+/// The state holds the chosen option and, while the popup list is open, the highlighted option
+/// and the text typed to filter the options. The options are matched with the filter
+/// case-insensitively, using their text recorded during the last render.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{backend::event::KeyCode, widgets::*};
+///
+/// let mut state = SelectState::new(Some(1));
+/// state.open();
+/// assert!(state.is_open());
+/// assert_eq!(state.highlighted(), Some(1));
+///
+/// assert!(state.handle_key(KeyCode::Esc.into()));
+/// assert!(!state.is_open());
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct SelectState {
+    selected: Option<usize>,
+    open: bool,
+    highlighted: Option<usize>,
+    filter: String,
+    /// The lowercase text of the options during the last render
+    options: Vec<String>,
+    list_state: ListState,
+}
+
+impl SelectState {
+    /// Creates a new closed state with the given option chosen, or no option with `None`
+    pub fn new(selected: Option<usize>) -> Self {
+        Self {
+            selected,
+            ..Self::default()
+        }
+    }
+
+    /// The index of the chosen option, if any
+    pub const fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    /// Chooses the option at the given index, or no option with `None`
+    pub fn select(&mut self, index: Option<usize>) {
+        self.selected = index;
+    }
+
+    /// Whether the popup list is open
+    pub const fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Opens the popup list, highlighting the chosen option
+    pub fn open(&mut self) {
+        self.open = true;
+        self.highlighted = self.selected;
+    }
+
+    /// Closes the popup list without changing the chosen option, and clears the filter
+    pub fn close(&mut self) {
+        self.open = false;
+        self.highlighted = None;
+        self.filter.clear();
+    }
+
+    /// The index of the highlighted option of the open popup list, if any
+    pub const fn highlighted(&self) -> Option<usize> {
+        self.highlighted
+    }
+
+    /// Highlights the next option matching the filter
+    ///
+    /// The options are known after the first render.
+    pub fn highlight_next(&mut self) {
+        let matches = self.matches();
+        let position = self
+            .highlighted_position(&matches)
+            .map_or(0, |position| position.saturating_add(1));
+        self.highlighted = matches.get(position).or_else(|| matches.last()).copied();
+    }
+
+    /// Highlights the previous option matching the filter
+    ///
+    /// The options are known after the first render.
+    pub fn highlight_previous(&mut self) {
+        let matches = self.matches();
+        let position = self
+            .highlighted_position(&matches)
+            .map_or(0, |position| position.saturating_sub(1));
+        self.highlighted = matches.get(position).copied();
+    }
+
+    /// Chooses the highlighted option and closes the popup list
+    ///
+    /// Returns the index of the chosen option, or `None` if no option is highlighted, in which
+    /// case the popup list stays open.
+    pub fn confirm(&mut self) -> Option<usize> {
+        let highlighted = self.highlighted?;
+        self.selected = Some(highlighted);
+        self.close();
+        Some(highlighted)
+    }
+
+    /// The text typed to filter the options
+    pub fn filter(&self) -> &str {
+        &self.filter
+    }
+
+    /// Sets the text used to filter the options, highlighting the first match if the highlighted
+    /// option no longer matches
+    pub fn set_filter<T: Into<String>>(&mut self, filter: T) {
+        self.filter = filter.into();
+        self.update_highlight();
+    }
+
+    /// Handles a key, returning whether it was used by the widget
+    ///
+    /// When the popup list is closed, <kbd>Enter</kbd>, <kbd>Space</kbd> and <kbd>↓</kbd> open
+    /// it. When it is open:
+    /// - <kbd>↑</kbd> and <kbd>↓</kbd> move the highlight
+    /// - <kbd>Enter</kbd> chooses the highlighted option
+    /// - <kbd>Esc</kbd> closes the list
+    /// - characters are appended to the filter, and <kbd>Backspace</kbd> removes the last one
+    pub fn handle_key(&mut self, key: KeyEvent) -> bool {
+        if key.kind == KeyEventKind::Release {
+            return false;
+        }
+        if !self.open {
+            return match key.code {
+                KeyCode::Enter | KeyCode::Char(' ') | KeyCode::Down => {
+                    self.open();
+                    true
+                }
+                _ => false,
+            };
+        }
+        let shortcut = key
+            .modifiers
+            .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT);
+        match key.code {
+            KeyCode::Up => self.highlight_previous(),
+            KeyCode::Down => self.highlight_next(),
+            KeyCode::Enter => {
+                self.confirm();
+            }
+            KeyCode::Esc => self.close(),
+            KeyCode::Backspace => {
+                self.filter.pop();
+                self.update_highlight();
+            }
+            KeyCode::Char(c) if !shortcut => {
+                self.filter.push(c);
+                self.update_highlight();
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    /// The indices of the options matching the filter
+    fn matches(&self) -> Vec<usize> {
+        let filter = self.filter.to_lowercase();
+        self.options
+            .iter()
+            .enumerate()
+            .filter(|(_, option)| option.contains(&filter))
+            .map(|(index, _)| index)
+            .collect()
+    }
+
+    /// The position of the highlighted option in the matches
+    fn highlighted_position(&self, matches: &[usize]) -> Option<usize> {
+        let highlighted = self.highlighted?;
+        matches.iter().position(|&index| index == highlighted)
+    }
+
+    /// Highlights the first match if the highlighted option does not match the filter
+    fn update_highlight(&mut self) {
+        let matches = self.matches();
+        if self.highlighted_position(&matches).is_none() {
+            self.highlighted = matches.first().copied();
+        }
+    }
+}
+
+/// A widget that displays the chosen option inline, and a popup list of options to choose from
+///
+/// The inline row displays the chosen option, or a placeholder, followed by a symbol at the end of
+/// the row. When the state is open, the filter typed by the user replaces the chosen option, and a
+/// popup list of the options matching the filter is rendered below the widget, or above it when
+/// there is more space there. The popup is as wide as the widget and is constrained to the
+/// [`Buffer`] area, so the widget should be the last one rendered in the frame to stay on top.
+///
+/// The chosen option, the highlighted option and the filter are held by a [`SelectState`], which
+/// can handle the keys with [`SelectState::handle_key`].
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{prelude::*, widgets::*};
+///
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// let select = Select::new(["Rust", "Go", "Zig", "C++"])
+///     .placeholder("Language")
+///     .popup_block(Block::bordered().title("Languages"));
+///
+/// // This should be stored outside of the function in your application state.
+/// let mut state = SelectState::default();
+///
+/// frame.render_stateful_widget(select, area, &mut state);
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Select<'a> {
+    options: Vec<Line<'a>>,
+    block: Option<Block<'a>>,
+    style: Style,
+    placeholder: Line<'a>,
+    symbol: &'a str,
+    popup_block: Block<'a>,
+    popup_style: Style,
+    highlight_style: Style,
+    max_height: u16,
+}
+
+impl Default for Select<'_> {
+    fn default() -> Self {
+        Self::new(Vec::<Line>::new())
+    }
+}
+
+impl<'a> Select<'a> {
+    /// Creates a new select widget with the given options
+    pub fn new<I>(options: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Line<'a>>,
+    {
+        Self {
+            options: options.into_iter().map(Into::into).collect(),
+            block: None,
+            style: Style::new(),
+            placeholder: Line::default(),
+            symbol: "▾",
+            popup_block: Block::bordered(),
+            popup_style: Style::new(),
+            highlight_style: Style::new().add_modifier(Modifier::REVERSED),
+            max_height: 8,
+        }
+    }
+
+    /// Wraps the inline row in a [`Block`]
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the style of the inline row
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the text displayed when no option is chosen
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn placeholder<T: Into<Line<'a>>>(mut self, placeholder: T) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// Sets the symbol displayed at the end of the inline row, which defaults to `▾`
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn symbol(mut self, symbol: &'a str) -> Self {
+        self.symbol = symbol;
+        self
+    }
+
+    /// Sets the block drawn around the popup list, which is bordered by default
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn popup_block(mut self, block: Block<'a>) -> Self {
+        self.popup_block = block;
+        self
+    }
+
+    /// Sets the style of the popup list
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn popup_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.popup_style = style.into();
+        self
+    }
+
+    /// Sets the style of the highlighted option of the popup list, which is reversed by default
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.highlight_style = style.into();
+        self
+    }
+
+    /// Sets the maximum number of options displayed at once in the popup list, which defaults
+    /// to 8
+    ///
+    /// The popup list scrolls to keep the highlighted option visible.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn max_height(mut self, max_height: u16) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    fn render_inline(&self, area: Rect, buf: &mut Buffer, state: &SelectState) {
+        let symbol_width = Line::from(self.symbol).width();
+        let symbol_width = u16::try_from(symbol_width).unwrap_or(u16::MAX);
+        let text_width = area.width.saturating_sub(symbol_width.saturating_add(1));
+        if state.open && !state.filter.is_empty() {
+            buf.set_stringn(
+                area.x,
+                area.y,
+                &state.filter,
+                text_width as usize,
+                Style::new(),
+            );
+        } else if let Some(option) = state.selected.and_then(|index| self.options.get(index)) {
+            buf.set_line(area.x, area.y, option, text_width);
+        } else {
+            buf.set_line(area.x, area.y, &self.placeholder, text_width);
+        }
+        let x = area.right().saturating_sub(symbol_width).max(area.x);
+        buf.set_stringn(x, area.y, self.symbol, area.width as usize, Style::new());
+    }
+
+    /// The area of the popup list, below or above the widget area
+    fn popup_area(&self, area: Rect, bounds: Rect, rows: usize) -> Rect {
+        let probe = Rect::new(0, 0, u16::MAX, u16::MAX);
+        let overhead = probe.height - self.popup_block.inner(probe).height;
+        let rows = u16::try_from(rows.max(1))
+            .unwrap_or(u16::MAX)
+            .min(self.max_height.max(1));
+        let height = rows.saturating_add(overhead);
+        let below = bounds.bottom().saturating_sub(area.bottom());
+        let above = area.top().saturating_sub(bounds.top());
+        let area = if below >= height || below >= above {
+            Rect::new(area.x, area.bottom(), area.width, height.min(below))
+        } else {
+            let height = height.min(above);
+            Rect::new(area.x, area.top() - height, area.width, height)
+        };
+        area.intersection(bounds)
+    }
+}
+
+impl StatefulWidget for Select<'_> {
+    type State = SelectState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        self.render_ref(area, buf, state);
+    }
+}
+
+// Note: remove this when StatefulWidgetRef is stabilized and replace with the blanket impl
+impl StatefulWidget for &Select<'_> {
+    type State = SelectState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        self.render_ref(area, buf, state);
+    }
+}
+
+impl StatefulWidgetRef for Select<'_> {
+    type State = SelectState;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        state.options = self
+            .options
+            .iter()
+            .map(|option| option.to_string().to_lowercase())
+            .collect();
+        state.selected = state.selected.filter(|&index| index < self.options.len());
+        if state.open {
+            state.update_highlight();
+        }
+
+        let area = area.intersection(buf.area);
+        buf.set_style(area, self.style);
+        self.block.render_ref(area, buf);
+        let inner = self.block.inner_if_some(area);
+        if !inner.is_empty() {
+            self.render_inline(inner, buf, state);
+        }
+        if !state.open || area.is_empty() {
+            return;
+        }
+
+        let matches = state.matches();
+        let popup_area = self.popup_area(area, buf.area, matches.len());
+        if popup_area.is_empty() {
+            return;
+        }
+        let position = state.highlighted_position(&matches);
+        let list = List::new(matches.iter().map(|&index| self.options[index].clone()))
+            .block(self.popup_block.clone())
+            .style(self.popup_style)
+            .highlight_style(self.highlight_style);
+        state.list_state.select(position);
+        Clear.render(popup_area, buf);
+        StatefulWidget::render(list, popup_area, buf, &mut state.list_state);
+    }
+}
+
+impl Styled for Select<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+
+    use super::*;
+
+    fn select<'a>() -> Select<'a> {
+        Select::new(["Rust", "Go", "Zig", "Ruby"])
+            .placeholder("Pick")
+            .highlight_style(Style::new())
+    }
+
+    #[test]
+    fn render_closed() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 2));
+        let mut state = SelectState::default();
+        select().render(Rect::new(0, 0, 8, 1), &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["Pick   ▾", "        "]));
+
+        state.select(Some(1));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 2));
+        select().render(Rect::new(0, 0, 8, 1), &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["Go     ▾", "        "]));
+    }
+
+    #[test]
+    fn render_open_below() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 7));
+        let mut state = SelectState::new(Some(2));
+        state.open();
+        select()
+            .max_height(3)
+            .render(Rect::new(0, 0, 8, 1), &mut buf, &mut state);
+        assert_eq!(
+            buf,
+            Buffer::with_lines([
+                "Zig    ▾",
+                "┌──────┐",
+                "│Rust  │",
+                "│Go    │",
+                "│Zig   │",
+                "└──────┘",
+                "        ",
+            ])
+        );
+    }
+
+    #[test]
+    fn render_open_above() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 5));
+        let mut state = SelectState::default();
+        state.open();
+        state.set_filter("ru");
+        select().render(Rect::new(0, 4, 8, 1), &mut buf, &mut state);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["┌──────┐", "│Rust  │", "│Ruby  │", "└──────┘", "ru     ▾",])
+        );
+        assert_eq!(state.highlighted(), Some(0));
+    }
+
+    #[test]
+    fn render_highlight() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 4));
+        let mut state = SelectState::new(Some(1));
+        state.open();
+        Select::new(["a", "b"]).popup_block(Block::new()).render(
+            Rect::new(0, 0, 6, 1),
+            &mut buf,
+            &mut state,
+        );
+        let mut expected = Buffer::with_lines(["b    ▾", "a     ", "b     ", "      "]);
+        expected.set_style(Rect::new(0, 2, 6, 1), Style::new().reversed());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn handle_keys() {
+        let mut state = SelectState::default();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 8));
+        select().render(Rect::new(0, 0, 8, 1), &mut buf, &mut state);
+
+        assert!(!state.handle_key(KeyCode::Up.into()));
+        assert!(state.handle_key(KeyCode::Enter.into()));
+        assert!(state.is_open());
+        assert!(state.handle_key(KeyCode::Down.into()));
+        assert_eq!(state.highlighted(), Some(0));
+        assert!(state.handle_key(KeyCode::Char('z').into()));
+        assert_eq!(state.highlighted(), Some(2));
+        assert!(state.handle_key(KeyCode::Backspace.into()));
+        assert!(state.handle_key(KeyCode::Down.into()));
+        assert!(state.handle_key(KeyCode::Down.into()));
+        assert_eq!(state.highlighted(), Some(3));
+        assert!(state.handle_key(KeyCode::Up.into()));
+        assert!(state.handle_key(KeyCode::Enter.into()));
+        assert_eq!(state.selected(), Some(2));
+        assert!(!state.is_open());
+        assert_eq!(state.filter(), "");
+    }
+
+    #[test]
+    fn confirm_without_matches() {
+        let mut state = SelectState::default();
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 8));
+        select().render(Rect::new(0, 0, 8, 1), &mut buf, &mut state);
+        state.open();
+        state.set_filter("x");
+        assert_eq!(state.confirm(), None);
+        assert!(state.is_open());
+    }
+}
@@ -16,7 +16,10 @@ use crate::{prelude::*, widgets::Block};
 /// - [`Sparkline::block`] wraps the sparkline in a [`Block`]
 /// - [`Sparkline::data`] defines the dataset, you'll almost always want to use it
 /// - [`Sparkline::max`] sets the maximum value of bars
+/// - [`Sparkline::min`] sets the minimum value of bars
 /// - [`Sparkline::direction`] sets the render direction
+/// - [`Sparkline::absent_value_symbol`] and [`Sparkline::absent_value_style`] set how missing
+///   values are displayed
 ///
 /// # Examples
 ///
@@ -36,11 +39,18 @@ pub struct Sparkline<'a> {
     block: Option<Block<'a>>,
     /// Widget style
     style: Style,
-    /// A slice of the data to display
-    data: &'a [u64],
+    /// The data to display
+    data: Vec<SparklineBar>,
     /// The maximum value to take to compute the maximum bar height (if nothing is specified, the
     /// widget uses the max of the dataset)
     max: Option<u64>,
+    /// The value at the bottom of the bars (if nothing is specified, the bars start at zero)
+    min: Option<u64>,
+    /// The symbol used to display absent values on the bottom line (if nothing is specified,
+    /// absent values are left empty)
+    absent_value_symbol: Option<&'a str>,
+    /// The style of absent values
+    absent_value_style: Style,
     /// A set of bar symbols used to represent the give data
     bar_set: symbols::bar::Set,
     // The direction to render the sparkine, either from left to right, or from right to left
@@ -59,13 +69,78 @@ pub enum RenderDirection {
     RightToLeft,
 }
 
+/// A single value of a [`Sparkline`], which may be absent
+///
+/// Absent values are displayed as gaps (or with [`Sparkline::absent_value_symbol`]) instead of
+/// being drawn as zero, which is useful when samples are missing.
+///
+/// `SparklineBar` can be created from `u64`, `Option<u64>` and references to these types, so
+/// [`Sparkline::data`] accepts slices of any of them.
+///
+/// # Example
+///
+/// ```
+/// use ratatui::widgets::{Sparkline, SparklineBar};
+///
+/// let sparkline = Sparkline::default().data(&[Some(1), None, Some(3)]);
+/// assert_eq!(SparklineBar::from(None), SparklineBar::absent());
+/// ```
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct SparklineBar {
+    value: Option<u64>,
+}
+
+impl SparklineBar {
+    /// Creates a bar with the given value
+    pub const fn new(value: u64) -> Self {
+        Self { value: Some(value) }
+    }
+
+    /// Creates a bar for an absent value
+    pub const fn absent() -> Self {
+        Self { value: None }
+    }
+
+    /// Returns the value of the bar, or `None` if it is absent
+    pub const fn value(self) -> Option<u64> {
+        self.value
+    }
+}
+
+impl From<u64> for SparklineBar {
+    fn from(value: u64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<&u64> for SparklineBar {
+    fn from(value: &u64) -> Self {
+        Self::new(*value)
+    }
+}
+
+impl From<Option<u64>> for SparklineBar {
+    fn from(value: Option<u64>) -> Self {
+        Self { value }
+    }
+}
+
+impl From<&Option<u64>> for SparklineBar {
+    fn from(value: &Option<u64>) -> Self {
+        Self { value: *value }
+    }
+}
+
 impl<'a> Default for Sparkline<'a> {
     fn default() -> Self {
         Self {
             block: None,
             style: Style::default(),
-            data: &[],
+            data: Vec::new(),
             max: None,
+            min: None,
+            absent_value_symbol: None,
+            absent_value_style: Style::default(),
             bar_set: symbols::bar::NINE_LEVELS,
             direction: RenderDirection::LeftToRight,
         }
@@ -94,6 +169,9 @@ impl<'a> Sparkline<'a> {
 
     /// Sets the dataset for the sparkline.
     ///
+    /// `data` accepts any iterator of values that are convertible to [`SparklineBar`], e.g. a
+    /// slice of `u64`, or a slice of `Option<u64>` where `None` marks an absent value.
+    ///
     /// # Example
     ///
     /// ```
@@ -105,8 +183,12 @@ impl<'a> Sparkline<'a> {
     /// # }
     /// ```
     #[must_use = "method moves the value of self and returns the modified value"]
-    pub const fn data(mut self, data: &'a [u64]) -> Self {
-        self.data = data;
+    pub fn data<T>(mut self, data: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: Into<SparklineBar>,
+    {
+        self.data = data.into_iter().map(Into::into).collect();
         self
     }
 
@@ -120,6 +202,48 @@ impl<'a> Sparkline<'a> {
         self
     }
 
+    /// Sets the minimum value of bars.
+    ///
+    /// Values lower or equal to the minimum are displayed as empty bars and every bar is scaled
+    /// between the minimum and the [maximum](Sparkline::max). If no min is given, bars start at
+    /// zero. Setting both keeps the scale fixed across frames when the data changes.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn min(mut self, min: u64) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets the symbol displayed on the bottom line for absent values.
+    ///
+    /// Absent values are left empty by default.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let sparkline = Sparkline::default()
+    ///     .data(&[Some(1), None, Some(3)])
+    ///     .absent_value_symbol("·")
+    ///     .absent_value_style(Style::new().dark_gray());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn absent_value_symbol(mut self, symbol: &'a str) -> Self {
+        self.absent_value_symbol = Some(symbol);
+        self
+    }
+
+    /// Sets the style of absent values.
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// The style is patched over the style of the widget.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn absent_value_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.absent_value_style = style.into();
+        self
+    }
+
     /// Sets the characters used to display the bars.
     ///
     /// Can be [`symbols::bar::THREE_LEVELS`], [`symbols::bar::NINE_LEVELS`] (default) or a custom
@@ -174,23 +298,45 @@ impl Sparkline<'_> {
 
         let max = match self.max {
             Some(v) => v,
-            None => *self.data.iter().max().unwrap_or(&1),
+            None => self
+                .data
+                .iter()
+                .filter_map(|bar| bar.value)
+                .max()
+                .unwrap_or(1),
         };
+        let min_value = self.min.unwrap_or(0);
+        let range = max.saturating_sub(min_value);
         let max_index = min(spark_area.width as usize, self.data.len());
         let mut data = self
             .data
             .iter()
             .take(max_index)
-            .map(|e| {
-                if max == 0 {
-                    0
-                } else {
-                    e * u64::from(spark_area.height) * 8 / max
-                }
+            .map(|bar| {
+                bar.value.map(|value| {
+                    (value.min(max).saturating_sub(min_value) * u64::from(spark_area.height) * 8)
+                        .checked_div(range)
+                        .unwrap_or(0)
+                })
             })
-            .collect::<Vec<u64>>();
+            .collect::<Vec<Option<u64>>>();
         for j in (0..spark_area.height).rev() {
             for (i, d) in data.iter_mut().enumerate() {
+                let x = match self.direction {
+                    RenderDirection::LeftToRight => spark_area.left() + i as u16,
+                    RenderDirection::RightToLeft => spark_area.right() - i as u16 - 1,
+                };
+                let Some(d) = d else {
+                    let is_bottom = j == spark_area.height - 1;
+                    let symbol = self
+                        .absent_value_symbol
+                        .filter(|_| is_bottom)
+                        .unwrap_or(self.bar_set.empty);
+                    buf.get_mut(x, spark_area.top() + j)
+                        .set_symbol(symbol)
+                        .set_style(self.style.patch(self.absent_value_style));
+                    continue;
+                };
                 let symbol = match *d {
                     0 => self.bar_set.empty,
                     1 => self.bar_set.one_eighth,
@@ -202,10 +348,6 @@ impl Sparkline<'_> {
                     7 => self.bar_set.seven_eighths,
                     _ => self.bar_set.full,
                 };
-                let x = match self.direction {
-                    RenderDirection::LeftToRight => spark_area.left() + i as u16,
-                    RenderDirection::RightToLeft => spark_area.right() - i as u16 - 1,
-                };
                 buf.get_mut(x, spark_area.top() + j)
                     .set_symbol(symbol)
                     .set_style(self.style);
@@ -262,21 +404,21 @@ mod tests {
 
     #[test]
     fn it_does_not_panic_if_max_is_zero() {
-        let widget = Sparkline::default().data(&[0, 0, 0]);
+        let widget = Sparkline::default().data([0, 0, 0]);
         let buffer = render(widget, 6);
         assert_eq!(buffer, Buffer::with_lines(["   xxx"]));
     }
 
     #[test]
     fn it_does_not_panic_if_max_is_set_to_zero() {
-        let widget = Sparkline::default().data(&[0, 1, 2]).max(0);
+        let widget = Sparkline::default().data([0, 1, 2]).max(0);
         let buffer = render(widget, 6);
         assert_eq!(buffer, Buffer::with_lines(["   xxx"]));
     }
 
     #[test]
     fn it_draws() {
-        let widget = Sparkline::default().data(&[0, 1, 2, 3, 4, 5, 6, 7, 8]);
+        let widget = Sparkline::default().data([0, 1, 2, 3, 4, 5, 6, 7, 8]);
         let buffer = render(widget, 12);
         assert_eq!(buffer, Buffer::with_lines([" ▁▂▃▄▅▆▇█xxx"]));
     }
@@ -284,7 +426,7 @@ mod tests {
     #[test]
     fn it_renders_left_to_right() {
         let widget = Sparkline::default()
-            .data(&[0, 1, 2, 3, 4, 5, 6, 7, 8])
+            .data([0, 1, 2, 3, 4, 5, 6, 7, 8])
             .direction(RenderDirection::LeftToRight);
         let buffer = render(widget, 12);
         assert_eq!(buffer, Buffer::with_lines([" ▁▂▃▄▅▆▇█xxx"]));
@@ -293,12 +435,55 @@ mod tests {
     #[test]
     fn it_renders_right_to_left() {
         let widget = Sparkline::default()
-            .data(&[0, 1, 2, 3, 4, 5, 6, 7, 8])
+            .data([0, 1, 2, 3, 4, 5, 6, 7, 8])
             .direction(RenderDirection::RightToLeft);
         let buffer = render(widget, 12);
         assert_eq!(buffer, Buffer::with_lines(["xxx█▇▆▅▄▃▂▁ "]));
     }
 
+    #[test]
+    fn it_draws_between_min_and_max() {
+        let widget = Sparkline::default()
+            .data([10, 11, 12, 13, 14, 15, 16, 17, 18])
+            .min(10)
+            .max(18);
+        let buffer = render(widget, 12);
+        assert_eq!(buffer, Buffer::with_lines([" ▁▂▃▄▅▆▇█xxx"]));
+    }
+
+    #[test]
+    fn it_clamps_values_outside_min_and_max() {
+        let widget = Sparkline::default().data([0, 4, 12]).min(2).max(10);
+        let buffer = render(widget, 4);
+        assert_eq!(buffer, Buffer::with_lines([" ▂█x"]));
+    }
+
+    #[test]
+    fn it_draws_absent_values_as_gaps() {
+        let widget = Sparkline::default().data([Some(8), None, Some(4), None]);
+        let buffer = render(widget, 5);
+        assert_eq!(buffer, Buffer::with_lines(["█ ▄ x"]));
+    }
+
+    #[test]
+    fn it_draws_absent_value_symbol() {
+        let widget = Sparkline::default()
+            .data([Some(8), None, Some(4)])
+            .absent_value_symbol("·")
+            .absent_value_style(Color::Red);
+        let buffer = render(widget, 4);
+        let mut expected = Buffer::with_lines(["█·▄x"]);
+        expected.set_style(Rect::new(1, 0, 1, 1), Color::Red);
+        assert_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn absent_values_do_not_affect_max() {
+        let widget = Sparkline::default().data([None, Some(2), Some(4)]);
+        let buffer = render(widget, 3);
+        assert_eq!(buffer, Buffer::with_lines([" ▄█"]));
+    }
+
     #[test]
     fn can_be_stylized() {
         assert_eq!(
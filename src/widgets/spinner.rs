@@ -0,0 +1,209 @@
+use crate::{prelude::*, symbols::spinner};
+
+/// A widget that displays an animated loading indicator, optionally followed by a label.
+///
+/// The spinner has no state of its own: the displayed symbol is picked from a tick value, which is
+/// usually the number of frames rendered so far ([`Frame::count`]), so the spinner moves each
+/// time the terminal is drawn. Any other counter, e.g. incremented by a timer, can be used instead
+/// to control the speed of the animation.
+///
+/// The symbols default to [`symbols::spinner::DOTS`](spinner::DOTS). Other built-in sets are
+/// available in the [`symbols::spinner`](spinner) module, and any slice of symbols can be used.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{prelude::*, widgets::*};
+///
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// let spinner = Spinner::new(frame.count())
+///     .symbols(symbols::spinner::LINE)
+///     .label("Loading...")
+///     .symbol_style(Style::new().cyan());
+/// frame.render_widget(spinner, area);
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Spinner<'a> {
+    tick: usize,
+    symbols: &'a [&'a str],
+    label: Option<Line<'a>>,
+    style: Style,
+    symbol_style: Style,
+}
+
+impl Default for Spinner<'_> {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl<'a> Spinner<'a> {
+    /// Creates a new spinner that displays the symbol of the given tick
+    ///
+    /// The tick is usually [`Frame::count`], and the symbols loop when the tick is greater than
+    /// the number of symbols.
+    pub const fn new(tick: usize) -> Self {
+        Self {
+            tick,
+            symbols: spinner::DOTS,
+            label: None,
+            style: Style::new(),
+            symbol_style: Style::new(),
+        }
+    }
+
+    /// Sets the tick that selects the displayed symbol
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn tick(mut self, tick: usize) -> Self {
+        self.tick = tick;
+        self
+    }
+
+    /// Sets the symbols displayed one after the other
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let spinner = Spinner::new(0).symbols(symbols::spinner::BOUNCE);
+    /// let spinner = Spinner::new(0).symbols(&["◐", "◓", "◑", "◒"]);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn symbols(mut self, symbols: &'a [&'a str]) -> Self {
+        self.symbols = symbols;
+        self
+    }
+
+    /// Sets the label displayed after the symbol
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn label<T>(mut self, label: T) -> Self
+    where
+        T: Into<Line<'a>>,
+    {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Sets the style of the whole widget
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the symbol
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn symbol_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.symbol_style = style.into();
+        self
+    }
+
+    /// Returns the symbol displayed for the current tick
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let spinner = Spinner::new(5).symbols(symbols::spinner::LINE);
+    /// assert_eq!(spinner.symbol(), "\\");
+    /// ```
+    pub fn symbol(&self) -> &'a str {
+        if self.symbols.is_empty() {
+            return "";
+        }
+        self.symbols[self.tick % self.symbols.len()]
+    }
+}
+
+impl Widget for Spinner<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        self.render_ref(area, buf);
+    }
+}
+
+impl WidgetRef for Spinner<'_> {
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        buf.set_style(area, self.style);
+        let area = area.intersection(buf.area);
+        if area.is_empty() {
+            return;
+        }
+        let (x, _) = buf.set_stringn(
+            area.x,
+            area.y,
+            self.symbol(),
+            area.width as usize,
+            self.symbol_style,
+        );
+        if let Some(label) = &self.label {
+            let x = x.saturating_add(1);
+            let width = area.right().saturating_sub(x);
+            buf.set_line(x, area.y, label, width);
+        }
+    }
+}
+
+impl Styled for Spinner<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::first(0, "⠋")]
+    #[case::second(1, "⠙")]
+    #[case::last(9, "⠏")]
+    #[case::wraps_around(12, "⠹")]
+    fn symbol(#[case] tick: usize, #[case] expected: &str) {
+        assert_eq!(Spinner::new(tick).symbol(), expected);
+    }
+
+    #[test]
+    fn symbol_without_symbols() {
+        assert_eq!(Spinner::new(3).symbols(&[]).symbol(), "");
+    }
+
+    #[test]
+    fn render() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 12, 1));
+        Spinner::new(2)
+            .symbols(spinner::LINE)
+            .label("Loading")
+            .symbol_style(Style::new().red())
+            .render(buf.area, &mut buf);
+        let mut expected = Buffer::with_lines(["| Loading   "]);
+        expected.set_style(Rect::new(0, 0, 1, 1), Style::new().red());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_truncates_label() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+        Spinner::new(0)
+            .symbols(spinner::BOUNCE)
+            .label("Loading")
+            .render(buf.area, &mut buf);
+        assert_eq!(buf, Buffer::with_lines(["⠁ Loa"]));
+    }
+}
@@ -1,6 +1,7 @@
 mod cell;
 mod highlight_spacing;
 mod row;
+mod sort_direction;
 #[allow(clippy::module_inception)]
 mod table;
 mod table_state;
@@ -8,5 +9,6 @@ mod table_state;
 pub use cell::*;
 pub use highlight_spacing::*;
 pub use row::*;
+pub use sort_direction::*;
 pub use table::*;
 pub use table_state::*;
@@ -9,6 +9,9 @@ use crate::prelude::*;
 ///
 /// You can use [`Text::alignment`] when creating a cell to align its content.
 ///
+/// A cell can span multiple columns of the table using [`Cell::colspan`]. The spanned columns are
+/// merged into a single area, including the spacing between them.
+///
 /// # Examples
 ///
 /// You can create a `Cell` from anything that can be converted to a [`Text`].
@@ -38,10 +41,21 @@ use crate::prelude::*;
 ///
 /// [`Row`]: super::Row
 /// [`Table`]: super::Table
-#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct Cell<'a> {
     content: Text<'a>,
     style: Style,
+    colspan: usize,
+}
+
+impl Default for Cell<'_> {
+    fn default() -> Self {
+        Self {
+            content: Text::default(),
+            style: Style::default(),
+            colspan: 1,
+        }
+    }
 }
 
 impl<'a> Cell<'a> {
@@ -68,6 +82,7 @@ impl<'a> Cell<'a> {
         Self {
             content: content.into(),
             style: Style::default(),
+            colspan: 1,
         }
     }
 
@@ -129,6 +144,34 @@ impl<'a> Cell<'a> {
         self.style = style.into();
         self
     }
+
+    /// Set the number of columns this cell spans
+    ///
+    /// The cell takes the place of `colspan` cells of the [`Row`]: the cells that follow it start
+    /// in the column after the last spanned column. The width of the cell is the combined width of
+    /// the spanned columns and the spacing between them. Values less than 1 are treated as 1.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// // a header grouping the first two columns
+    /// let header = Row::new([Cell::new("Name").colspan(2), Cell::new("Size")]);
+    /// ```
+    ///
+    /// [`Row`]: super::Row
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn colspan(mut self, colspan: usize) -> Self {
+        self.colspan = colspan.max(1);
+        self
+    }
+
+    /// The number of columns this cell spans
+    pub(crate) const fn column_span(&self) -> usize {
+        self.colspan
+    }
 }
 
 impl Cell<'_> {
@@ -143,10 +186,7 @@ where
     T: Into<Text<'a>>,
 {
     fn from(content: T) -> Self {
-        Self {
-            content: content.into(),
-            style: Style::default(),
-        }
+        Self::new(content)
     }
 }
 
@@ -185,6 +225,14 @@ mod tests {
         assert_eq!(cell.style, style);
     }
 
+    #[test]
+    fn colspan() {
+        assert_eq!(Cell::default().colspan, 1);
+        assert_eq!(Cell::from("").colspan, 1);
+        assert_eq!(Cell::new("").colspan(3).colspan, 3);
+        assert_eq!(Cell::new("").colspan(0).colspan, 1);
+    }
+
     #[test]
     fn stylize() {
         assert_eq!(
@@ -1,3 +1,5 @@
+use std::ops::Range;
+
 use super::*;
 use crate::prelude::*;
 
@@ -229,6 +231,20 @@ impl Row<'_> {
             .saturating_add(self.top_margin)
             .saturating_add(self.bottom_margin)
     }
+
+    /// Returns the number of columns spanned by the cells of the row.
+    pub(crate) fn column_count(&self) -> usize {
+        self.cells.iter().map(Cell::column_span).sum()
+    }
+
+    /// Returns the cells of the row together with the range of columns each cell spans.
+    pub(crate) fn cells_with_columns(&self) -> impl Iterator<Item = (Range<usize>, &Cell<'_>)> {
+        self.cells.iter().scan(0, |column, cell| {
+            let start = *column;
+            *column += cell.column_span();
+            Some((start..*column, cell))
+        })
+    }
 }
 
 impl<'a> Styled for Row<'a> {
@@ -272,6 +288,18 @@ mod tests {
         assert_eq!(row.cells, cells);
     }
 
+    #[test]
+    fn cells_with_columns() {
+        let row = Row::new([Cell::from("a").colspan(2), Cell::from("b"), Cell::from("c")]);
+        assert_eq!(row.column_count(), 4);
+        assert_eq!(
+            row.cells_with_columns()
+                .map(|(columns, _)| columns)
+                .collect::<Vec<_>>(),
+            [0..2, 2..3, 3..4]
+        );
+    }
+
     #[test]
     fn cells() {
         let cells = vec![Cell::from("")];
@@ -0,0 +1,75 @@
+use strum::{Display, EnumString};
+
+/// The direction in which a column of a [`Table`] is sorted
+///
+/// The sorted column is marked in the header of the table, see [`Table::sort`] and
+/// [`TableState::sort_by`].
+///
+/// [`Table`]: crate::widgets::Table
+/// [`Table::sort`]: crate::widgets::Table::sort
+/// [`TableState::sort_by`]: crate::widgets::TableState::sort_by
+#[derive(Debug, Display, EnumString, PartialEq, Eq, Clone, Copy, Default, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SortDirection {
+    /// Smallest values first, marked with `▲` by default
+    #[default]
+    Ascending,
+
+    /// Largest values first, marked with `▼` by default
+    Descending,
+}
+
+impl SortDirection {
+    /// Returns the opposite direction
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::widgets::SortDirection;
+    /// assert_eq!(
+    ///     SortDirection::Ascending.reversed(),
+    ///     SortDirection::Descending
+    /// );
+    /// ```
+    #[must_use = "method returns the reversed direction and does not modify self"]
+    pub const fn reversed(self) -> Self {
+        match self {
+            Self::Ascending => Self::Descending,
+            Self::Descending => Self::Ascending,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn reversed() {
+        assert_eq!(
+            SortDirection::Ascending.reversed(),
+            SortDirection::Descending
+        );
+        assert_eq!(
+            SortDirection::Descending.reversed(),
+            SortDirection::Ascending
+        );
+    }
+
+    #[test]
+    fn to_string() {
+        assert_eq!(SortDirection::Ascending.to_string(), "Ascending");
+        assert_eq!(SortDirection::Descending.to_string(), "Descending");
+    }
+
+    #[test]
+    fn from_str() {
+        assert_eq!(
+            SortDirection::from_str("Descending"),
+            Ok(SortDirection::Descending)
+        );
+        assert!(SortDirection::from_str("").is_err());
+    }
+}
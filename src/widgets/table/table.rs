@@ -1,4 +1,11 @@
+use std::{
+    hash::{Hash, Hasher},
+    ops::Range,
+    sync::Arc,
+};
+
 use itertools::Itertools;
+use unicode_width::UnicodeWidthStr;
 
 use super::*;
 use crate::{layout::Flex, prelude::*, widgets::Block};
@@ -210,6 +217,9 @@ pub struct Table<'a> {
     /// Style used to render the selected row
     highlight_style: Style,
 
+    /// Style used to render the selected cell, applied on top of the highlight style of the row
+    cell_highlight_style: Style,
+
     /// Symbol in front of the selected row
     highlight_symbol: Text<'a>,
 
@@ -218,6 +228,71 @@ pub struct Table<'a> {
 
     /// Controls how to distribute extra space among the columns
     flex: Flex,
+
+    /// The column the rows are sorted by, unless set in the state
+    sort: Option<(usize, SortDirection)>,
+
+    /// Symbols marking the sorted column in the header (ascending, descending)
+    sort_symbols: (&'a str, &'a str),
+
+    /// Number of leading columns that stay visible when scrolling horizontally
+    frozen_columns: usize,
+
+    /// Builds the rows on demand instead of `rows` (see [`Table::rows_with`])
+    row_provider: Option<RowProvider<'a>>,
+}
+
+/// A function building the rows of a [`Table`] on demand
+#[derive(Clone)]
+struct RowProvider<'a> {
+    len: usize,
+    row: Arc<dyn Fn(usize) -> Row<'a> + Send + Sync + 'a>,
+}
+
+impl RowProvider<'_> {
+    /// The range of rows that may be visible when rendering rows in an area of the given height
+    ///
+    /// As every row is at least one line high, only the rows around the offset and the selected
+    /// row need to be built.
+    fn window(&self, state: &TableState, height: usize) -> Range<usize> {
+        let last_index = self.len.saturating_sub(1);
+        let offset = state.offset.min(last_index);
+        let start = state.selected.map_or(offset, |selected| {
+            offset
+                .min(selected)
+                .max((selected.min(last_index) + 1).saturating_sub(height))
+        });
+        let end = start.saturating_add(height + 1).min(self.len);
+        start..end
+    }
+
+    /// The address of the function, which identifies the provider
+    fn address(&self) -> *const () {
+        Arc::as_ptr(&self.row).cast()
+    }
+}
+
+impl std::fmt::Debug for RowProvider<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RowProvider")
+            .field("len", &self.len)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for RowProvider<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && std::ptr::eq(self.address(), other.address())
+    }
+}
+
+impl Eq for RowProvider<'_> {}
+
+impl Hash for RowProvider<'_> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len.hash(state);
+        self.address().hash(state);
+    }
 }
 
 impl<'a> Default for Table<'a> {
@@ -231,9 +306,14 @@ impl<'a> Default for Table<'a> {
             block: None,
             style: Style::new(),
             highlight_style: Style::new(),
+            cell_highlight_style: Style::new(),
             highlight_symbol: Text::default(),
             highlight_spacing: HighlightSpacing::default(),
             flex: Flex::Start,
+            sort: None,
+            sort_symbols: ("▲", "▼"),
+            frozen_columns: 0,
+            row_provider: None,
         }
     }
 }
@@ -306,9 +386,50 @@ impl<'a> Table<'a> {
         T: IntoIterator<Item = Row<'a>>,
     {
         self.rows = rows.into_iter().collect();
+        self.row_provider = None;
         self
     }
 
+    /// Creates a new table of `len` rows that are built on demand
+    ///
+    /// `row` is called with the index of a row when rendering, and only for the rows that can be
+    /// visible in the rendered area. This avoids creating a [`Row`] for each element of huge tables
+    /// on every frame.
+    ///
+    /// The visible rows are found assuming that every row is at least one line high. As the rows
+    /// are not known in advance, the column widths must be set with [`Table::widths`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let table = Table::rows_with(100_000, |index| {
+    ///     Row::new([index.to_string(), (index * index).to_string()])
+    /// })
+    /// .widths([Constraint::Length(6), Constraint::Length(11)]);
+    /// ```
+    ///
+    /// The function can borrow the data of the application:
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let names = vec!["Alice".to_string(), "Bob".to_string()];
+    /// let table = Table::rows_with(names.len(), |index| Row::new([names[index].as_str()]))
+    ///     .widths([Constraint::Fill(1)]);
+    /// ```
+    pub fn rows_with<F>(len: usize, row: F) -> Self
+    where
+        F: Fn(usize) -> Row<'a> + Send + Sync + 'a,
+    {
+        Self {
+            row_provider: Some(RowProvider {
+                len,
+                row: Arc::new(row),
+            }),
+            ..Self::default()
+        }
+    }
+
     /// Sets the header row
     ///
     /// The `header` parameter is a [`Row`] which will be displayed at the top of the [`Table`]
@@ -485,6 +606,33 @@ impl<'a> Table<'a> {
         self
     }
 
+    /// Set the style of the selected cell
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    ///
+    /// A cell is selected when both a row and a column are selected in the [`TableState`] (see
+    /// [`TableState::selected_cell`]). This style is applied to the selected cell on top of the
+    /// [`Table::highlight_style`] of its row.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths)
+    ///     .highlight_style(Style::new().bold())
+    ///     .cell_highlight_style(Style::new().reversed());
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn cell_highlight_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.cell_highlight_style = style.into();
+        self
+    }
+
     /// Set the symbol to be displayed in front of the selected row
     ///
     /// This is a fluent setter method which must be chained or used as it consumes self
@@ -562,6 +710,75 @@ impl<'a> Table<'a> {
         self.flex = flex;
         self
     }
+
+    /// Marks a column as sorted in the header
+    ///
+    /// The header cell of the column is marked with a symbol at its right edge, `▲` for
+    /// [`SortDirection::Ascending`] and `▼` for [`SortDirection::Descending`] (see
+    /// [`Table::sort_symbols`]). The rows are not sorted by the table.
+    ///
+    /// When the table is rendered with a [`TableState`] that has a sort column (see
+    /// [`TableState::sort_by`]), the state takes precedence.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths)
+    ///     .header(Row::new(["Name", "Size"]))
+    ///     .sort(1, SortDirection::Descending);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn sort(mut self, column: usize, direction: SortDirection) -> Self {
+        self.sort = Some((column, direction));
+        self
+    }
+
+    /// Set the symbols marking the sorted column in the header
+    ///
+    /// The default symbols are `▲` and `▼`.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2"])];
+    /// # let widths = [Constraint::Length(5), Constraint::Length(5)];
+    /// let table = Table::new(rows, widths).sort_symbols("↑", "↓");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn sort_symbols(mut self, ascending: &'a str, descending: &'a str) -> Self {
+        self.sort_symbols = (ascending, descending);
+        self
+    }
+
+    /// Set the number of leading columns that stay visible when scrolling horizontally
+    ///
+    /// The table is scrolled horizontally with [`TableState::column_offset_mut`], which hides the
+    /// columns that follow the frozen columns. This is useful to keep a column that identifies the
+    /// rows (e.g. a name) visible while scrolling through the other columns.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// # let rows = [Row::new(vec!["Cell1", "Cell2", "Cell3"])];
+    /// # let widths = [Constraint::Length(5); 3];
+    /// let table = Table::new(rows, widths).frozen_columns(1);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn frozen_columns(mut self, count: usize) -> Self {
+        self.frozen_columns = count;
+        self
+    }
 }
 
 impl Widget for Table<'_> {
@@ -597,6 +814,11 @@ impl StatefulWidgetRef for Table<'_> {
     type State = TableState;
 
     fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        if let Some(provider) = &self.row_provider {
+            self.render_provided_rows(provider, area, buf, state);
+            return;
+        }
+
         buf.set_style(area, self.style);
         self.block.render_ref(area, buf);
         let table_area = self.block.inner_if_some(area);
@@ -605,10 +827,24 @@ impl StatefulWidgetRef for Table<'_> {
         }
 
         let selection_width = self.selection_width(state);
-        let columns_widths = self.get_columns_widths(table_area.width, selection_width);
+        // keep at least one column that is not frozen visible
+        state.column_offset = state
+            .column_offset
+            .min(self.column_count().saturating_sub(self.frozen_columns + 1));
+        // keep the selected cell within the table, as the navigation methods of the state do not
+        // know its size
+        state.selected = clamp_index(state.selected, self.rows.len());
+        state.selected_column = clamp_index(state.selected_column, self.column_count());
+        let columns = self.visible_columns(state.column_offset);
+        let columns_widths = self
+            .get_columns_widths(table_area.width, selection_width, state.column_offset)
+            .into_iter()
+            .zip(columns)
+            .map(|((x, width), column)| (column, x, width))
+            .collect_vec();
         let (header_area, rows_area, footer_area) = self.layout(table_area);
 
-        self.render_header(header_area, buf, &columns_widths);
+        self.render_header(header_area, buf, &columns_widths, state.sort.or(self.sort));
 
         self.render_rows(
             rows_area,
@@ -625,6 +861,45 @@ impl StatefulWidgetRef for Table<'_> {
 
 // private methods for rendering
 impl Table<'_> {
+    /// Renders the rows of the provider that can be visible, as a table of these rows only
+    fn render_provided_rows(
+        &self,
+        provider: &RowProvider<'_>,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut TableState,
+    ) {
+        let (_, rows_area, _) = self.layout(self.block.inner_if_some(area));
+        state.selected = clamp_index(state.selected, provider.len);
+        let window = if rows_area.is_empty() {
+            // no row is visible, so none is built
+            state.offset..state.offset
+        } else {
+            provider.window(state, rows_area.height as usize)
+        };
+        let table = Table {
+            rows: window.clone().map(|index| (provider.row)(index)).collect(),
+            row_provider: None,
+            ..self.clone()
+        };
+        let start = window.start;
+        let mut window_state = TableState {
+            offset: state.offset.saturating_sub(start),
+            selected: state
+                .selected
+                .map(|selected| selected.saturating_sub(start)),
+            ..state.clone()
+        };
+        StatefulWidgetRef::render_ref(&table, area, buf, &mut window_state);
+        // the selection was clamped above, and the window does not contain it when no row is
+        // visible
+        *state = TableState {
+            offset: window_state.offset + start,
+            selected: state.selected,
+            ..window_state
+        };
+    }
+
     /// Splits the table area into a header, rows area and a footer
     fn layout(&self, area: Rect) -> (Rect, Rect, Rect) {
         let header_top_margin = self.header.as_ref().map_or(0, |h| h.top_margin);
@@ -647,20 +922,41 @@ impl Table<'_> {
         (header_area, rows_area, footer_area)
     }
 
-    fn render_header(&self, area: Rect, buf: &mut Buffer, column_widths: &[(u16, u16)]) {
+    fn render_header(
+        &self,
+        area: Rect,
+        buf: &mut Buffer,
+        column_widths: &[(usize, u16, u16)],
+        sort: Option<(usize, SortDirection)>,
+    ) {
         if let Some(ref header) = self.header {
             buf.set_style(area, header.style);
-            for ((x, width), cell) in column_widths.iter().zip(header.cells.iter()) {
-                cell.render(Rect::new(area.x + x, area.y, *width, area.height), buf);
+            for (columns, cell, cell_area) in Self::cell_areas(header, area, column_widths) {
+                cell.render(cell_area, buf);
+                if let Some((_, direction)) = sort.filter(|(column, _)| columns.contains(column)) {
+                    let symbol = match direction {
+                        SortDirection::Ascending => self.sort_symbols.0,
+                        SortDirection::Descending => self.sort_symbols.1,
+                    };
+                    let symbol_width = symbol.width() as u16;
+                    if symbol_width <= cell_area.width {
+                        buf.set_string(
+                            cell_area.right() - symbol_width,
+                            cell_area.y,
+                            symbol,
+                            Style::new(),
+                        );
+                    }
+                }
             }
         }
     }
 
-    fn render_footer(&self, area: Rect, buf: &mut Buffer, column_widths: &[(u16, u16)]) {
+    fn render_footer(&self, area: Rect, buf: &mut Buffer, column_widths: &[(usize, u16, u16)]) {
         if let Some(ref footer) = self.footer {
             buf.set_style(area, footer.style);
-            for ((x, width), cell) in column_widths.iter().zip(footer.cells.iter()) {
-                cell.render(Rect::new(area.x + x, area.y, *width, area.height), buf);
+            for (_, cell, cell_area) in Self::cell_areas(footer, area, column_widths) {
+                cell.render(cell_area, buf);
             }
         }
     }
@@ -672,7 +968,7 @@ impl Table<'_> {
         state: &mut TableState,
         selection_width: u16,
         highlight_symbol: &Text<'_>,
-        columns_widths: &[(u16, u16)],
+        columns_widths: &[(usize, u16, u16)],
     ) {
         if self.rows.is_empty() {
             return;
@@ -707,38 +1003,94 @@ impl Table<'_> {
                 buf.set_style(selection_area, row.style);
                 highlight_symbol.clone().render(selection_area, buf);
             };
-            for ((x, width), cell) in columns_widths.iter().zip(row.cells.iter()) {
-                cell.render(
-                    Rect::new(row_area.x + x, row_area.y, *width, row_area.height),
-                    buf,
-                );
+            let mut selected_cell_area = None;
+            for (columns, cell, cell_area) in Self::cell_areas(row, row_area, columns_widths) {
+                cell.render(cell_area, buf);
+                if state
+                    .selected_column
+                    .is_some_and(|column| columns.contains(&column))
+                {
+                    selected_cell_area = Some(cell_area);
+                }
             }
             if is_selected {
                 buf.set_style(row_area, self.highlight_style);
+                if let Some(cell_area) = selected_cell_area {
+                    buf.set_style(cell_area, self.cell_highlight_style);
+                }
             }
             y_offset += row.height_with_margin();
         }
     }
 
-    /// Get all offsets and widths of all user specified columns.
-    ///
-    /// Returns (x, width). When self.widths is empty, it is assumed `.widths()` has not been called
-    /// and a default of equal widths is returned.
-    fn get_columns_widths(&self, max_width: u16, selection_width: u16) -> Vec<(u16, u16)> {
-        let widths = if self.widths.is_empty() {
-            let col_count = self
-                .rows
+    /// The number of columns, given by the widths or by the longest row if no widths are set
+    fn column_count(&self) -> usize {
+        if self.widths.is_empty() {
+            self.rows
                 .iter()
                 .chain(self.header.iter())
                 .chain(self.footer.iter())
-                .map(|r| r.cells.len())
+                .map(Row::column_count)
                 .max()
-                .unwrap_or(0);
+                .unwrap_or(0)
+        } else {
+            self.widths.len()
+        }
+    }
+
+    /// The cells of `row` that are at least partially visible, with the columns they span and the
+    /// area they cover.
+    ///
+    /// A cell spanning multiple columns covers the visible columns it spans, including the spacing
+    /// between them.
+    fn cell_areas<'r>(
+        row: &'r Row<'_>,
+        area: Rect,
+        columns_widths: &'r [(usize, u16, u16)],
+    ) -> impl Iterator<Item = (Range<usize>, &'r Cell<'r>, Rect)> + 'r {
+        row.cells_with_columns().filter_map(move |(columns, cell)| {
+            let (start, end) = columns_widths
+                .iter()
+                .filter(|(column, _, _)| columns.contains(column))
+                .fold(None, |bounds, &(_, x, width)| match bounds {
+                    None => Some((x, x + width)),
+                    Some((start, end)) => Some((u16::min(start, x), u16::max(end, x + width))),
+                })?;
+            let cell_area = Rect::new(area.x + start, area.y, end - start, area.height);
+            Some((columns, cell, cell_area))
+        })
+    }
+
+    /// The indices of the columns that are visible when the columns that follow the frozen columns
+    /// are scrolled by `column_offset`.
+    fn visible_columns(&self, column_offset: usize) -> Vec<usize> {
+        let frozen = self.frozen_columns.min(self.column_count());
+        (0..frozen)
+            .chain(frozen + column_offset..self.column_count())
+            .collect()
+    }
+
+    /// Get all offsets and widths of the visible columns (see [`Table::visible_columns`]).
+    ///
+    /// Returns (x, width). When self.widths is empty, it is assumed `.widths()` has not been called
+    /// and a default of equal widths is returned.
+    fn get_columns_widths(
+        &self,
+        max_width: u16,
+        selection_width: u16,
+        column_offset: usize,
+    ) -> Vec<(u16, u16)> {
+        let col_count = self.column_count();
+        let widths = if self.widths.is_empty() {
             // Divide the space between each column equally
             vec![Constraint::Length(max_width / col_count.max(1) as u16); col_count]
         } else {
             self.widths.clone()
         };
+        let widths = self
+            .visible_columns(column_offset)
+            .into_iter()
+            .map(|column| widths[column]);
         // this will always allocate a selection area
         let [_selection_area, columns_area] =
             Layout::horizontal([Constraint::Length(selection_width), Constraint::Fill(0)])
@@ -800,6 +1152,13 @@ impl Table<'_> {
     }
 }
 
+/// Clamps an index to the last of `len` elements, or returns `None` if there are no elements
+fn clamp_index(index: Option<usize>, len: usize) -> Option<usize> {
+    index
+        .zip(len.checked_sub(1))
+        .map(|(index, last)| index.min(last))
+}
+
 fn ensure_percentages_less_than_100(widths: &[Constraint]) {
     for w in widths {
         if let Constraint::Percentage(p) = w {
@@ -1000,6 +1359,8 @@ mod tests {
 
     #[cfg(test)]
     mod render {
+        use rstest::rstest;
+
         use super::*;
 
         #[test]
@@ -1057,6 +1418,243 @@ mod tests {
             assert_eq!(buf, expected);
         }
 
+        #[test]
+        fn render_with_sort() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 2));
+            let header = Row::new(vec!["Name", "Size"]);
+            let rows = vec![Row::new(vec!["Cell1", "Cell2"])];
+            let table = Table::new(rows, [Constraint::Length(6); 2])
+                .header(header)
+                .sort(1, SortDirection::Descending);
+            Widget::render(table, Rect::new(0, 0, 15, 2), &mut buf);
+            #[rustfmt::skip]
+            let expected = Buffer::with_lines([
+                "Name   Size ▼  ",
+                "Cell1  Cell2   ",
+            ]);
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_state_sort() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 15, 2));
+            let header = Row::new(vec!["Name", "Size"]);
+            let rows = vec![Row::new(vec!["Cell1", "Cell2"])];
+            let table = Table::new(rows, [Constraint::Length(6); 2])
+                .header(header)
+                .sort(1, SortDirection::Descending)
+                .sort_symbols("^", "v");
+            let mut state = TableState::new().with_sort(0, SortDirection::Ascending);
+            StatefulWidget::render(table, Rect::new(0, 0, 15, 2), &mut buf, &mut state);
+            #[rustfmt::skip]
+            let expected = Buffer::with_lines([
+                "Name ^ Size    ",
+                "Cell1  Cell2   ",
+            ]);
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_column_offset() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 17, 2));
+            let header = Row::new(vec!["Name", "A", "B", "C"]);
+            let rows = vec![Row::new(vec!["Cell1", "Cell2", "Cell3", "Cell4"])];
+            let table = Table::new(rows, [Constraint::Length(5); 4])
+                .header(header)
+                .frozen_columns(1);
+            let mut state = TableState::new().with_column_offset(1);
+            StatefulWidget::render(table.clone(), buf.area, &mut buf, &mut state);
+            #[rustfmt::skip]
+            let expected = Buffer::with_lines([
+                "Name  B     C    ",
+                "Cell1 Cell3 Cell4",
+            ]);
+            assert_eq!(buf, expected);
+
+            // the offset is clamped to keep the last column visible
+            let mut buf = Buffer::empty(Rect::new(0, 0, 17, 2));
+            let mut state = TableState::new().with_column_offset(10);
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            #[rustfmt::skip]
+            let expected = Buffer::with_lines([
+                "Name  C          ",
+                "Cell1 Cell4      ",
+            ]);
+            assert_eq!(buf, expected);
+            assert_eq!(state.column_offset(), 2);
+        }
+
+        #[test]
+        fn render_with_selected_cell() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 11, 2));
+            let rows = vec![
+                Row::new(vec!["Cell1", "Cell2"]),
+                Row::new(vec!["Cell3", "Cell4"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(5); 2])
+                .highlight_style(Style::new().red())
+                .cell_highlight_style(Style::new().reversed());
+            let mut state = TableState::new().with_selected_cell(Some((1, 1)));
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            #[rustfmt::skip]
+            let mut expected = Buffer::with_lines([
+                "Cell1 Cell2",
+                "Cell3 Cell4",
+            ]);
+            expected.set_style(Rect::new(0, 1, 11, 1), Style::new().red());
+            expected.set_style(Rect::new(6, 1, 5, 1), Style::new().reversed());
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_clamps_selected_cell() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 11, 2));
+            let rows = vec![
+                Row::new(vec!["Cell1", "Cell2"]),
+                Row::new(vec!["Cell3", "Cell4"]),
+            ];
+            let table = Table::new(rows, [Constraint::Length(5); 2]);
+            let mut state = TableState::new().with_selected_cell(Some((5, 5)));
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            assert_eq!(state.selected_cell(), Some((1, 1)));
+        }
+
+        #[rstest]
+        #[case::one_row(vec![Row::new(["Cell"])], Some(3), Some(0))]
+        #[case::no_rows(vec![], Some(0), None)]
+        #[case::no_selection(vec![Row::new(["Cell"])], None, None)]
+        fn render_clamps_selected_row(
+            #[case] rows: Vec<Row<'static>>,
+            #[case] selected: Option<usize>,
+            #[case] expected: Option<usize>,
+        ) {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 5, 2));
+            let table = Table::new(rows, [Constraint::Length(5)]);
+            let mut state = TableState::new().with_selected(selected);
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            assert_eq!(state.selected(), expected);
+        }
+
+        #[test]
+        fn render_with_colspan() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 17, 3));
+            let header = Row::new([Cell::from("Group").colspan(2), Cell::from("C")]);
+            let rows = vec![Row::new(["Cell1", "Cell2", "Cell3"])];
+            let footer = Row::new([Cell::from("Total"), Cell::from("Summary").colspan(2)]);
+            let table = Table::new(rows, [Constraint::Length(5); 3])
+                .header(header)
+                .footer(footer);
+            StatefulWidget::render(table, buf.area, &mut buf, &mut TableState::new());
+            #[rustfmt::skip]
+            let expected = Buffer::with_lines([
+                "Group       C    ",
+                "Cell1 Cell2 Cell3",
+                "Total Summary    ",
+            ]);
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_colspan_and_selected_cell() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 11, 1));
+            let rows = vec![Row::new([Cell::from("Cell1").colspan(2)])];
+            let table = Table::new(rows, [Constraint::Length(5); 2])
+                .cell_highlight_style(Style::new().reversed());
+            let mut state = TableState::new().with_selected_cell(Some((0, 1)));
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            let mut expected = Buffer::with_lines(["Cell1      "]);
+            expected.set_style(Rect::new(0, 0, 11, 1), Style::new().reversed());
+            assert_eq!(buf, expected);
+        }
+
+        #[test]
+        fn render_with_colspan_partially_scrolled() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 11, 1));
+            let rows = vec![Row::new([
+                Cell::from("Cell1").colspan(2),
+                Cell::from("Cell3"),
+            ])];
+            let table = Table::new(rows, [Constraint::Length(5); 3]);
+            let mut state = TableState::new().with_column_offset(1);
+            StatefulWidget::render(table, buf.area, &mut buf, &mut state);
+            assert_eq!(buf, Buffer::with_lines(["Cell1 Cell3"]));
+        }
+
+        #[test]
+        fn render_with_sorted_spanned_column() {
+            let mut buf = Buffer::empty(Rect::new(0, 0, 11, 1));
+            let header = Row::new([Cell::from("Name").colspan(2)]);
+            let table = Table::new(Vec::<Row>::new(), [Constraint::Length(5); 2])
+                .header(header)
+                .sort(1, SortDirection::Ascending);
+            StatefulWidget::render(table, buf.area, &mut buf, &mut TableState::new());
+            assert_eq!(buf, Buffer::with_lines(["Name      ▲"]));
+        }
+
+        #[test]
+        fn render_rows_with_without_rows_area() {
+            let table = Table::rows_with(10, |_| unreachable!())
+                .widths([Constraint::Length(5)])
+                .header(Row::new(["Index"]));
+            let mut buf = Buffer::empty(Rect::new(0, 0, 5, 1));
+            let mut state = TableState::new().with_selected(Some(3));
+            for _ in 0..3 {
+                StatefulWidget::render(&table, buf.area, &mut buf, &mut state);
+            }
+            assert_eq!(buf, Buffer::with_lines(["Index"]));
+            assert_eq!(state.selected(), Some(3));
+            assert_eq!(state.offset(), 0);
+        }
+
+        #[test]
+        fn render_with_rows_with() {
+            let built = std::sync::Mutex::new(Vec::new());
+            let table = Table::rows_with(100_000, |index| {
+                built.lock().unwrap().push(index);
+                Row::new([index.to_string()])
+            })
+            .widths([Constraint::Length(5)])
+            .header(Row::new(["Index"]))
+            .highlight_symbol(">>");
+
+            let mut buf = Buffer::empty(Rect::new(0, 0, 7, 3));
+            let mut state = TableState::new().with_offset(10);
+            StatefulWidget::render(&table, buf.area, &mut buf, &mut state);
+            #[rustfmt::skip]
+            let expected = Buffer::with_lines([
+                "Index  ",
+                "10     ",
+                "11     ",
+            ]);
+            assert_eq!(buf, expected);
+            assert_eq!(state.offset(), 10);
+            assert!(built
+                .lock()
+                .unwrap()
+                .iter()
+                .all(|index| (10..13).contains(index)));
+
+            let mut buf = Buffer::empty(Rect::new(0, 0, 7, 3));
+            let mut state = TableState::new().with_selected(Some(50_000));
+            StatefulWidget::render(&table, buf.area, &mut buf, &mut state);
+            #[rustfmt::skip]
+            let expected = Buffer::with_lines([
+                "  Index",
+                "  49999",
+                ">>50000",
+            ]);
+            assert_eq!(buf, expected);
+            assert_eq!(state.offset(), 49_999);
+            assert_eq!(state.selected(), Some(50_000));
+
+            // the selection is clamped to the last row
+            let mut buf = Buffer::empty(Rect::new(0, 0, 7, 3));
+            let mut state = TableState::new().with_selected(Some(200_000));
+            StatefulWidget::render(&table, buf.area, &mut buf, &mut state);
+            assert_eq!(state.selected(), Some(99_999));
+            assert_eq!(state.offset(), 99_998);
+        }
+
         #[test]
         fn render_with_footer() {
             let mut buf = Buffer::empty(Rect::new(0, 0, 15, 3));
@@ -1200,15 +1798,15 @@ mod tests {
         fn length_constraint() {
             // without selection, more than needed width
             let table = Table::default().widths([Length(4), Length(4)]);
-            assert_eq!(table.get_columns_widths(20, 0), [(0, 4), (5, 4)]);
+            assert_eq!(table.get_columns_widths(20, 0, 0), [(0, 4), (5, 4)]);
 
             // with selection, more than needed width
             let table = Table::default().widths([Length(4), Length(4)]);
-            assert_eq!(table.get_columns_widths(20, 3), [(3, 4), (8, 4)]);
+            assert_eq!(table.get_columns_widths(20, 3, 0), [(3, 4), (8, 4)]);
 
             // without selection, less than needed width
             let table = Table::default().widths([Length(4), Length(4)]);
-            assert_eq!(table.get_columns_widths(7, 0), [(0, 3), (4, 3)]);
+            assert_eq!(table.get_columns_widths(7, 0, 0), [(0, 3), (4, 3)]);
 
             // with selection, less than needed width
             // <--------7px-------->
@@ -1217,26 +1815,26 @@ mod tests {
             // └────────┘x└────────┘
             // column spacing (i.e. `x`) is always prioritized
             let table = Table::default().widths([Length(4), Length(4)]);
-            assert_eq!(table.get_columns_widths(7, 3), [(3, 2), (6, 1)]);
+            assert_eq!(table.get_columns_widths(7, 3, 0), [(3, 2), (6, 1)]);
         }
 
         #[test]
         fn max_constraint() {
             // without selection, more than needed width
             let table = Table::default().widths([Max(4), Max(4)]);
-            assert_eq!(table.get_columns_widths(20, 0), [(0, 4), (5, 4)]);
+            assert_eq!(table.get_columns_widths(20, 0, 0), [(0, 4), (5, 4)]);
 
             // with selection, more than needed width
             let table = Table::default().widths([Max(4), Max(4)]);
-            assert_eq!(table.get_columns_widths(20, 3), [(3, 4), (8, 4)]);
+            assert_eq!(table.get_columns_widths(20, 3, 0), [(3, 4), (8, 4)]);
 
             // without selection, less than needed width
             let table = Table::default().widths([Max(4), Max(4)]);
-            assert_eq!(table.get_columns_widths(7, 0), [(0, 3), (4, 3)]);
+            assert_eq!(table.get_columns_widths(7, 0, 0), [(0, 3), (4, 3)]);
 
             // with selection, less than needed width
             let table = Table::default().widths([Max(4), Max(4)]);
-            assert_eq!(table.get_columns_widths(7, 3), [(3, 2), (6, 1)]);
+            assert_eq!(table.get_columns_widths(7, 3, 0), [(3, 2), (6, 1)]);
         }
 
         #[test]
@@ -1247,42 +1845,42 @@ mod tests {
 
             // without selection, more than needed width
             let table = Table::default().widths([Min(4), Min(4)]);
-            assert_eq!(table.get_columns_widths(20, 0), [(0, 10), (11, 9)]);
+            assert_eq!(table.get_columns_widths(20, 0, 0), [(0, 10), (11, 9)]);
 
             // with selection, more than needed width
             let table = Table::default().widths([Min(4), Min(4)]);
-            assert_eq!(table.get_columns_widths(20, 3), [(3, 8), (12, 8)]);
+            assert_eq!(table.get_columns_widths(20, 3, 0), [(3, 8), (12, 8)]);
 
             // without selection, less than needed width
             // allocates spacer
             let table = Table::default().widths([Min(4), Min(4)]);
-            assert_eq!(table.get_columns_widths(7, 0), [(0, 3), (4, 3)]);
+            assert_eq!(table.get_columns_widths(7, 0, 0), [(0, 3), (4, 3)]);
 
             // with selection, less than needed width
             // always allocates selection and spacer
             let table = Table::default().widths([Min(4), Min(4)]);
-            assert_eq!(table.get_columns_widths(7, 3), [(3, 2), (6, 1)]);
+            assert_eq!(table.get_columns_widths(7, 3, 0), [(3, 2), (6, 1)]);
         }
 
         #[test]
         fn percentage_constraint() {
             // without selection, more than needed width
             let table = Table::default().widths([Percentage(30), Percentage(30)]);
-            assert_eq!(table.get_columns_widths(20, 0), [(0, 6), (7, 6)]);
+            assert_eq!(table.get_columns_widths(20, 0, 0), [(0, 6), (7, 6)]);
 
             // with selection, more than needed width
             let table = Table::default().widths([Percentage(30), Percentage(30)]);
-            assert_eq!(table.get_columns_widths(20, 3), [(3, 5), (9, 5)]);
+            assert_eq!(table.get_columns_widths(20, 3, 0), [(3, 5), (9, 5)]);
 
             // without selection, less than needed width
             // rounds from positions: [0.0, 0.0, 2.1, 3.1, 5.2, 7.0]
             let table = Table::default().widths([Percentage(30), Percentage(30)]);
-            assert_eq!(table.get_columns_widths(7, 0), [(0, 2), (3, 2)]);
+            assert_eq!(table.get_columns_widths(7, 0, 0), [(0, 2), (3, 2)]);
 
             // with selection, less than needed width
             // rounds from positions: [0.0, 3.0, 5.1, 6.1, 7.0, 7.0]
             let table = Table::default().widths([Percentage(30), Percentage(30)]);
-            assert_eq!(table.get_columns_widths(7, 3), [(3, 1), (5, 1)]);
+            assert_eq!(table.get_columns_widths(7, 3, 0), [(3, 1), (5, 1)]);
         }
 
         #[test]
@@ -1290,22 +1888,22 @@ mod tests {
             // without selection, more than needed width
             // rounds from positions: [0.00, 0.00, 6.67, 7.67, 14.33]
             let table = Table::default().widths([Ratio(1, 3), Ratio(1, 3)]);
-            assert_eq!(table.get_columns_widths(20, 0), [(0, 7), (8, 6)]);
+            assert_eq!(table.get_columns_widths(20, 0, 0), [(0, 7), (8, 6)]);
 
             // with selection, more than needed width
             // rounds from positions: [0.00, 3.00, 10.67, 17.33, 20.00]
             let table = Table::default().widths([Ratio(1, 3), Ratio(1, 3)]);
-            assert_eq!(table.get_columns_widths(20, 3), [(3, 6), (10, 5)]);
+            assert_eq!(table.get_columns_widths(20, 3, 0), [(3, 6), (10, 5)]);
 
             // without selection, less than needed width
             // rounds from positions: [0.00, 2.33, 3.33, 5.66, 7.00]
             let table = Table::default().widths([Ratio(1, 3), Ratio(1, 3)]);
-            assert_eq!(table.get_columns_widths(7, 0), [(0, 2), (3, 3)]);
+            assert_eq!(table.get_columns_widths(7, 0, 0), [(0, 2), (3, 3)]);
 
             // with selection, less than needed width
             // rounds from positions: [0.00, 3.00, 5.33, 6.33, 7.00, 7.00]
             let table = Table::default().widths([Ratio(1, 3), Ratio(1, 3)]);
-            assert_eq!(table.get_columns_widths(7, 3), [(3, 1), (5, 2)]);
+            assert_eq!(table.get_columns_widths(7, 3, 0), [(3, 1), (5, 2)]);
         }
 
         /// When more width is available than requested, the behavior is controlled by flex
@@ -1313,7 +1911,7 @@ mod tests {
         fn underconstrained_flex() {
             let table = Table::default().widths([Min(10), Min(10), Min(1)]);
             assert_eq!(
-                table.get_columns_widths(62, 0),
+                table.get_columns_widths(62, 0, 0),
                 &[(0, 20), (21, 20), (42, 20)]
             );
 
@@ -1321,7 +1919,7 @@ mod tests {
                 .widths([Min(10), Min(10), Min(1)])
                 .flex(Flex::Legacy);
             assert_eq!(
-                table.get_columns_widths(62, 0),
+                table.get_columns_widths(62, 0, 0),
                 &[(0, 10), (11, 10), (22, 40)]
             );
 
@@ -1329,7 +1927,7 @@ mod tests {
                 .widths([Min(10), Min(10), Min(1)])
                 .flex(Flex::SpaceBetween);
             assert_eq!(
-                table.get_columns_widths(62, 0),
+                table.get_columns_widths(62, 0, 0),
                 &[(0, 20), (21, 20), (42, 20)]
             );
         }
@@ -1340,7 +1938,7 @@ mod tests {
         fn underconstrained_segment_size() {
             let table = Table::default().widths([Min(10), Min(10), Min(1)]);
             assert_eq!(
-                table.get_columns_widths(62, 0),
+                table.get_columns_widths(62, 0, 0),
                 &[(0, 20), (21, 20), (42, 20)]
             );
 
@@ -1348,7 +1946,7 @@ mod tests {
                 .widths([Min(10), Min(10), Min(1)])
                 .flex(Flex::Legacy);
             assert_eq!(
-                table.get_columns_widths(62, 0),
+                table.get_columns_widths(62, 0, 0),
                 &[(0, 10), (11, 10), (22, 40)]
             );
         }
@@ -1365,7 +1963,7 @@ mod tests {
                 .footer(Row::new(vec!["h", "i"]))
                 .column_spacing(0);
             assert_eq!(
-                table.get_columns_widths(30, 0),
+                table.get_columns_widths(30, 0, 0),
                 &[(0, 10), (10, 10), (20, 10)]
             );
         }
@@ -1376,7 +1974,7 @@ mod tests {
                 .rows(vec![])
                 .header(Row::new(vec!["f", "g"]))
                 .column_spacing(0);
-            assert_eq!(table.get_columns_widths(10, 0), [(0, 5), (5, 5)]);
+            assert_eq!(table.get_columns_widths(10, 0, 0), [(0, 5), (5, 5)]);
         }
 
         #[test]
@@ -1385,7 +1983,7 @@ mod tests {
                 .rows(vec![])
                 .footer(Row::new(vec!["h", "i"]))
                 .column_spacing(0);
-            assert_eq!(table.get_columns_widths(10, 0), [(0, 5), (5, 5)]);
+            assert_eq!(table.get_columns_widths(10, 0, 0), [(0, 5), (5, 5)]);
         }
 
         #[track_caller]
@@ -1,3 +1,5 @@
+use super::SortDirection;
+
 /// State of a [`Table`] widget
 ///
 /// This state can be used to scroll through the rows and select one of them. When the table is
@@ -5,12 +7,22 @@
 /// shifted to ensure that the selected row is visible. This will modify the [`TableState`] object
 /// passed to the [`Frame::render_stateful_widget`] method.
 ///
-/// The state consists of two fields:
+/// The state consists of five fields:
 /// - [`offset`]: the index of the first row to be displayed
+/// - [`column_offset`]: the number of columns that are scrolled out of view horizontally
 /// - [`selected`]: the index of the selected row, which can be `None` if no row is selected
+/// - [`selected_column`]: the index of the selected column, which turns the selection of a row into
+///   the selection of a single cell (see [`selected_cell`])
+/// - [`sort_column`] and [`sort_direction`]: the column the rows are sorted by, which is marked in
+///   the header of the table
 ///
 /// [`offset`]: TableState::offset()
+/// [`column_offset`]: TableState::column_offset()
 /// [`selected`]: TableState::selected()
+/// [`selected_column`]: TableState::selected_column()
+/// [`selected_cell`]: TableState::selected_cell()
+/// [`sort_column`]: TableState::sort_column()
+/// [`sort_direction`]: TableState::sort_direction()
 ///
 /// See the `table`` example and the `recipe`` and `traceroute`` tabs in the demo2 example in the
 /// [Examples] directory for a more in depth example of the various configuration options and for
@@ -48,7 +60,25 @@
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TableState {
     pub(crate) offset: usize,
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "is_zero"))]
+    pub(crate) column_offset: usize,
     pub(crate) selected: Option<usize>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub(crate) selected_column: Option<usize>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub(crate) sort: Option<(usize, SortDirection)>,
+}
+
+#[cfg(feature = "serde")]
+#[allow(clippy::trivially_copy_pass_by_ref)] // serde requires a reference
+const fn is_zero(value: &usize) -> bool {
+    *value == 0
 }
 
 impl TableState {
@@ -63,7 +93,10 @@ impl TableState {
     pub const fn new() -> Self {
         Self {
             offset: 0,
+            column_offset: 0,
             selected: None,
+            selected_column: None,
+            sort: None,
         }
     }
 
@@ -83,6 +116,22 @@ impl TableState {
         self
     }
 
+    /// Sets the number of columns that are scrolled out of view horizontally
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let state = TableState::new().with_column_offset(1);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_column_offset(mut self, column_offset: usize) -> Self {
+        self.column_offset = column_offset;
+        self
+    }
+
     /// Sets the index of the selected row
     ///
     /// This is a fluent setter method which must be chained or used as it consumes self
@@ -128,6 +177,38 @@ impl TableState {
         &mut self.offset
     }
 
+    /// Number of columns that are scrolled out of view horizontally
+    ///
+    /// The columns that are hidden are the ones following the
+    /// [frozen columns](crate::widgets::Table::frozen_columns), which always stay visible. The
+    /// remaining columns are laid out in the area of the table as if the hidden columns did not
+    /// exist. When rendering, the offset is clamped so that at least one column that is not frozen
+    /// stays visible.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let state = TableState::new();
+    /// assert_eq!(state.column_offset(), 0);
+    /// ```
+    pub const fn column_offset(&self) -> usize {
+        self.column_offset
+    }
+
+    /// Mutable reference to the number of columns that are scrolled out of view horizontally
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let mut state = TableState::default();
+    /// *state.column_offset_mut() += 1; // scroll right
+    /// ```
+    pub fn column_offset_mut(&mut self) -> &mut usize {
+        &mut self.column_offset
+    }
+
     /// Index of the selected row
     ///
     /// Returns `None` if no row is selected
@@ -175,6 +256,261 @@ impl TableState {
             self.offset = 0;
         }
     }
+
+    /// Sets the row and column of the selected cell
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let state = TableState::new().with_selected_cell(Some((1, 2)));
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_selected_cell<T>(mut self, selected: T) -> Self
+    where
+        T: Into<Option<(usize, usize)>>,
+    {
+        self.select_cell(selected.into());
+        self
+    }
+
+    /// Index of the selected column
+    ///
+    /// Returns `None` if no column is selected
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let state = TableState::new();
+    /// assert_eq!(state.selected_column(), None);
+    /// ```
+    pub const fn selected_column(&self) -> Option<usize> {
+        self.selected_column
+    }
+
+    /// Mutable reference to the index of the selected column
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let mut state = TableState::default();
+    /// *state.selected_column_mut() = Some(1);
+    /// ```
+    pub fn selected_column_mut(&mut self) -> &mut Option<usize> {
+        &mut self.selected_column
+    }
+
+    /// Sets the index of the selected column
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let mut state = TableState::default();
+    /// state.select_column(Some(1));
+    /// ```
+    pub fn select_column(&mut self, index: Option<usize>) {
+        self.selected_column = index;
+    }
+
+    /// Row and column of the selected cell
+    ///
+    /// Returns `None` if no row or no column is selected
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let state = TableState::new().with_selected(Some(1));
+    /// assert_eq!(state.selected_cell(), None);
+    /// let state = state.with_selected_cell(Some((1, 2)));
+    /// assert_eq!(state.selected_cell(), Some((1, 2)));
+    /// ```
+    pub const fn selected_cell(&self) -> Option<(usize, usize)> {
+        match (self.selected, self.selected_column) {
+            (Some(row), Some(column)) => Some((row, column)),
+            _ => None,
+        }
+    }
+
+    /// Sets the row and column of the selected cell
+    ///
+    /// Set to `None` to clear the selection of both the row and the column. This will also reset
+    /// the offset to `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let mut state = TableState::default();
+    /// state.select_cell(Some((1, 2)));
+    /// ```
+    pub fn select_cell(&mut self, cell: Option<(usize, usize)>) {
+        self.select(cell.map(|(row, _)| row));
+        self.selected_column = cell.map(|(_, column)| column);
+    }
+
+    /// Selects the next row, or the first row if no row is selected
+    ///
+    /// The selection is clamped to the last row when the table is rendered, and cleared if the
+    /// table has no rows.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let mut state = TableState::default();
+    /// state.select_next();
+    /// assert_eq!(state.selected(), Some(0));
+    /// ```
+    pub fn select_next(&mut self) {
+        self.selected = Some(self.selected.map_or(0, |row| row.saturating_add(1)));
+    }
+
+    /// Selects the previous row, or the first row if no row is selected
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let mut state = TableState::default().with_selected(Some(1));
+    /// state.select_previous();
+    /// assert_eq!(state.selected(), Some(0));
+    /// ```
+    pub fn select_previous(&mut self) {
+        self.selected = Some(self.selected.map_or(0, |row| row.saturating_sub(1)));
+    }
+
+    /// Selects the next column, or the first column if no column is selected
+    ///
+    /// The selection is clamped to the last column when the table is rendered, and cleared if the
+    /// table has no columns.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let mut state = TableState::default();
+    /// state.select_next_column();
+    /// assert_eq!(state.selected_column(), Some(0));
+    /// ```
+    pub fn select_next_column(&mut self) {
+        self.selected_column = Some(
+            self.selected_column
+                .map_or(0, |column| column.saturating_add(1)),
+        );
+    }
+
+    /// Selects the previous column, or the first column if no column is selected
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let mut state = TableState::default().with_selected_cell(Some((0, 1)));
+    /// state.select_previous_column();
+    /// assert_eq!(state.selected_cell(), Some((0, 0)));
+    /// ```
+    pub fn select_previous_column(&mut self) {
+        self.selected_column = Some(
+            self.selected_column
+                .map_or(0, |column| column.saturating_sub(1)),
+        );
+    }
+
+    /// Sets the column the rows are sorted by and the sort direction
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let state = TableState::new().with_sort(1, SortDirection::Descending);
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_sort(mut self, column: usize, direction: SortDirection) -> Self {
+        self.sort = Some((column, direction));
+        self
+    }
+
+    /// Index of the column the rows are sorted by
+    ///
+    /// Returns `None` if the rows are not sorted
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let state = TableState::new().with_sort(1, SortDirection::Ascending);
+    /// assert_eq!(state.sort_column(), Some(1));
+    /// ```
+    pub const fn sort_column(&self) -> Option<usize> {
+        match self.sort {
+            Some((column, _)) => Some(column),
+            None => None,
+        }
+    }
+
+    /// Direction in which the rows are sorted
+    ///
+    /// Returns `None` if the rows are not sorted
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let state = TableState::new().with_sort(1, SortDirection::Descending);
+    /// assert_eq!(state.sort_direction(), Some(SortDirection::Descending));
+    /// ```
+    pub const fn sort_direction(&self) -> Option<SortDirection> {
+        match self.sort {
+            Some((_, direction)) => Some(direction),
+            None => None,
+        }
+    }
+
+    /// Sorts the rows by the given column
+    ///
+    /// If the rows are already sorted by this column, the sort direction is reversed. Otherwise the
+    /// rows are sorted in ascending order. This is meant to be called when the user clicks on a
+    /// column header or presses a key. The rows themselves are not sorted, which is left to the
+    /// application.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let mut state = TableState::new();
+    /// state.sort_by(2);
+    /// assert_eq!(state.sort_direction(), Some(SortDirection::Ascending));
+    /// state.sort_by(2);
+    /// assert_eq!(state.sort_direction(), Some(SortDirection::Descending));
+    /// ```
+    pub fn sort_by(&mut self, column: usize) {
+        self.sort = match self.sort {
+            Some((sorted, direction)) if sorted == column => Some((column, direction.reversed())),
+            _ => Some((column, SortDirection::Ascending)),
+        };
+    }
+
+    /// Clears the sort column
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let mut state = TableState::new().with_sort(0, SortDirection::Ascending);
+    /// state.clear_sort();
+    /// assert_eq!(state.sort_column(), None);
+    /// ```
+    pub fn clear_sort(&mut self) {
+        self.sort = None;
+    }
 }
 
 #[cfg(test)]
@@ -213,6 +549,19 @@ mod tests {
         assert_eq!(state.offset, 1);
     }
 
+    #[test]
+    fn with_column_offset() {
+        let state = TableState::new().with_column_offset(1);
+        assert_eq!(state.column_offset, 1);
+    }
+
+    #[test]
+    fn column_offset_mut() {
+        let mut state = TableState::new();
+        *state.column_offset_mut() = 2;
+        assert_eq!(state.column_offset(), 2);
+    }
+
     #[test]
     fn selected() {
         let state = TableState::new();
@@ -239,4 +588,75 @@ mod tests {
         state.select(None);
         assert_eq!(state.selected, None);
     }
+
+    #[test]
+    fn select_cell() {
+        let mut state = TableState::new().with_offset(2);
+        state.select_cell(Some((1, 2)));
+        assert_eq!(state.selected, Some(1));
+        assert_eq!(state.selected_column, Some(2));
+        assert_eq!(state.selected_cell(), Some((1, 2)));
+
+        state.select_cell(None);
+        assert_eq!(state.selected, None);
+        assert_eq!(state.selected_column, None);
+        assert_eq!(state.offset, 0);
+    }
+
+    #[test]
+    fn selected_cell_requires_row_and_column() {
+        let state = TableState::new().with_selected(Some(1));
+        assert_eq!(state.selected_cell(), None);
+
+        let mut state = TableState::new();
+        state.select_column(Some(1));
+        assert_eq!(state.selected_cell(), None);
+    }
+
+    #[test]
+    fn cell_navigation() {
+        let mut state = TableState::new();
+        state.select_next();
+        state.select_next_column();
+        assert_eq!(state.selected_cell(), Some((0, 0)));
+
+        state.select_next();
+        state.select_next_column();
+        state.select_next_column();
+        assert_eq!(state.selected_cell(), Some((1, 2)));
+
+        state.select_previous();
+        state.select_previous();
+        state.select_previous_column();
+        assert_eq!(state.selected_cell(), Some((0, 1)));
+    }
+
+    #[test]
+    fn with_sort() {
+        let state = TableState::new().with_sort(1, SortDirection::Descending);
+        assert_eq!(state.sort, Some((1, SortDirection::Descending)));
+        assert_eq!(state.sort_column(), Some(1));
+        assert_eq!(state.sort_direction(), Some(SortDirection::Descending));
+    }
+
+    #[test]
+    fn sort_by() {
+        let mut state = TableState::new();
+        state.sort_by(1);
+        assert_eq!(state.sort, Some((1, SortDirection::Ascending)));
+        state.sort_by(1);
+        assert_eq!(state.sort, Some((1, SortDirection::Descending)));
+        state.sort_by(1);
+        assert_eq!(state.sort, Some((1, SortDirection::Ascending)));
+        state.sort_by(0);
+        assert_eq!(state.sort, Some((0, SortDirection::Ascending)));
+    }
+
+    #[test]
+    fn clear_sort() {
+        let mut state = TableState::new().with_sort(1, SortDirection::Ascending);
+        state.clear_sort();
+        assert_eq!(state.sort, None);
+        assert_eq!(state.sort_direction(), None);
+    }
 }
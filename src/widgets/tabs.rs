@@ -1,7 +1,111 @@
-use crate::{prelude::*, widgets::Block};
+use crate::{
+    layout::Position,
+    prelude::*,
+    widgets::{Block, StatefulWidgetRef},
+};
 
 const DEFAULT_HIGHLIGHT_STYLE: Style = Style::new().add_modifier(Modifier::REVERSED);
 
+/// State of the [`Tabs`] widget
+///
+/// The state holds the selected tab and the scroll offset, which is the index of the first visible
+/// tab. When the tabs do not fit in the area, the offset is updated on render so that the selected
+/// tab is visible.
+///
+/// The state also records the areas of the visible tabs and of their close buttons during the last
+/// render, so that mouse clicks can be mapped to tabs with [`TabsState::tab_at`] and
+/// [`TabsState::close_button_at`].
+///
+/// # Example
+///
+/// ```rust
+/// # use ratatui::{prelude::*, widgets::*};
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// let tabs = Tabs::new((0..20).map(|i| format!("Tab {i}"))).overflow_indicators("<", ">");
+///
+/// // This should be stored outside of the function in your application state.
+/// let mut state = TabsState::new(12);
+///
+/// frame.render_stateful_widget(tabs, area, &mut state);
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct TabsState {
+    selected: usize,
+    offset: usize,
+    /// The visible tabs during the last render
+    tab_areas: Vec<TabArea>,
+}
+
+/// The area of a visible tab and of its close button
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+struct TabArea {
+    index: usize,
+    area: Rect,
+    close_button: Option<Rect>,
+}
+
+impl TabsState {
+    /// Creates a new state with the given tab selected
+    pub const fn new(selected: usize) -> Self {
+        Self {
+            selected,
+            offset: 0,
+            tab_areas: Vec::new(),
+        }
+    }
+
+    /// The index of the selected tab
+    pub const fn selected(&self) -> usize {
+        self.selected
+    }
+
+    /// Selects the tab at the given index
+    ///
+    /// The index is clamped to the last tab on the next render.
+    pub fn select(&mut self, index: usize) {
+        self.selected = index;
+    }
+
+    /// Selects the next tab
+    ///
+    /// The index is clamped to the last tab on the next render.
+    pub fn select_next(&mut self) {
+        self.selected = self.selected.saturating_add(1);
+    }
+
+    /// Selects the previous tab
+    pub fn select_previous(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    /// The index of the first visible tab
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the index of the tab at the given position during the last render, e.g. to select
+    /// a tab on a mouse click
+    pub fn tab_at(&self, position: Position) -> Option<usize> {
+        self.tab_areas
+            .iter()
+            .find(|tab| tab.area.contains(position))
+            .map(|tab| tab.index)
+    }
+
+    /// Returns the index of the tab whose close button is at the given position during the last
+    /// render
+    ///
+    /// Close buttons are only rendered when [`Tabs::close_symbol`] is set.
+    pub fn close_button_at(&self, position: Position) -> Option<usize> {
+        self.tab_areas
+            .iter()
+            .find(|tab| tab.close_button.is_some_and(|area| area.contains(position)))
+            .map(|tab| tab.index)
+    }
+}
+
 /// A widget that displays a horizontal set of Tabs with a single tab selected.
 ///
 /// Each tab title is stored as a [`Line`] which can be individually styled. The selected tab is set
@@ -11,6 +115,12 @@ const DEFAULT_HIGHLIGHT_STYLE: Style = Style::new().add_modifier(Modifier::REVER
 ///
 /// The divider defaults to |, and padding defaults to a singular space on each side.
 ///
+/// `Tabs` is also a [`StatefulWidget`] with a [`TabsState`], which holds the selected tab instead
+/// of [`Tabs::select`]. When there are more tabs than fit in the area, the tab bar scrolls to keep
+/// the selected tab visible, and the hidden tabs can be indicated with
+/// [`Tabs::overflow_indicators`]. A close button can be rendered after each title with
+/// [`Tabs::close_symbol`].
+///
 /// # Example
 ///
 /// ```
@@ -51,6 +161,10 @@ pub struct Tabs<'a> {
     padding_left: Line<'a>,
     /// Tab Right Padding
     padding_right: Line<'a>,
+    /// Symbols rendered on the left and right when some tabs are hidden
+    overflow_indicators: Option<(Span<'a>, Span<'a>)>,
+    /// Symbol of the close button rendered after each title
+    close_symbol: Option<Span<'a>>,
 }
 
 impl<'a> Tabs<'a> {
@@ -98,6 +212,8 @@ impl<'a> Tabs<'a> {
             divider: Span::raw(symbols::line::VERTICAL),
             padding_left: Line::from(" "),
             padding_right: Line::from(" "),
+            overflow_indicators: None,
+            close_symbol: None,
         }
     }
 
@@ -235,6 +351,48 @@ impl<'a> Tabs<'a> {
         self.padding_left = padding.into();
         self
     }
+    /// Sets the symbols rendered on the left and right of the tab bar when some tabs are hidden.
+    ///
+    /// When the tabs do not fit in the area, space is reserved for the indicators on both sides
+    /// and the left (resp. right) indicator is rendered when tabs are hidden on the left (resp.
+    /// right). By default no indicators are rendered.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::Tabs};
+    /// let tabs = Tabs::new(vec!["Tab 1", "Tab 2"]).overflow_indicators("<", ">");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn overflow_indicators<T, U>(mut self, left: T, right: U) -> Self
+    where
+        T: Into<Span<'a>>,
+        U: Into<Span<'a>>,
+    {
+        self.overflow_indicators = Some((left.into(), right.into()));
+        self
+    }
+
+    /// Sets the symbol of the close button rendered after the title of each tab.
+    ///
+    /// The position of the close buttons during the last render can be retrieved with
+    /// [`TabsState::close_button_at`] to close a tab on a mouse click. By default no close button
+    /// is rendered.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use ratatui::{prelude::*, widgets::Tabs};
+    /// let tabs = Tabs::new(vec!["Tab 1", "Tab 2"]).close_symbol(" ×");
+    /// ```
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn close_symbol<T>(mut self, symbol: T) -> Self
+    where
+        T: Into<Span<'a>>,
+    {
+        self.close_symbol = Some(symbol.into());
+        self
+    }
 }
 
 impl<'a> Styled for Tabs<'a> {
@@ -251,29 +409,100 @@ impl<'a> Styled for Tabs<'a> {
 
 impl Widget for Tabs<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
-        self.render_ref(area, buf);
+        WidgetRef::render_ref(&self, area, buf);
     }
 }
 
 impl WidgetRef for Tabs<'_> {
     fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        let mut state = TabsState::new(self.selected);
+        self.render_with_state(area, buf, &mut state);
+    }
+}
+
+impl StatefulWidget for Tabs<'_> {
+    type State = TabsState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidgetRef::render_ref(&self, area, buf, state);
+    }
+}
+
+// Note: remove this when StatefulWidgetRef is stabilized and replace with the blanket impl
+impl StatefulWidget for &Tabs<'_> {
+    type State = TabsState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidgetRef::render_ref(self, area, buf, state);
+    }
+}
+
+impl StatefulWidgetRef for Tabs<'_> {
+    type State = TabsState;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        state.selected = state.selected.min(self.titles.len().saturating_sub(1));
+        self.render_with_state(area, buf, state);
+    }
+}
+
+impl Tabs<'_> {
+    fn render_with_state(&self, area: Rect, buf: &mut Buffer, state: &mut TabsState) {
         buf.set_style(area, self.style);
         self.block.render_ref(area, buf);
         let inner = self.block.inner_if_some(area);
-        self.render_tabs(inner, buf);
+        self.render_tabs(inner, buf, state);
     }
-}
 
-impl Tabs<'_> {
-    fn render_tabs(&self, tabs_area: Rect, buf: &mut Buffer) {
+    fn render_tabs(&self, tabs_area: Rect, buf: &mut Buffer, state: &mut TabsState) {
+        state.tab_areas.clear();
+        if tabs_area.is_empty() || self.titles.is_empty() {
+            return;
+        }
+
+        let overflow = self.tabs_width(0..self.titles.len()) > usize::from(tabs_area.width);
+        let indicators = self.overflow_indicators.as_ref().filter(|_| overflow);
+        let (left_width, right_width) = indicators.map_or((0, 0), |(left, right)| {
+            (left.width() as u16, right.width() as u16)
+        });
+        let tabs_area = Rect {
+            x: tabs_area.x + left_width.min(tabs_area.width),
+            width: tabs_area
+                .width
+                .saturating_sub(left_width)
+                .saturating_sub(right_width),
+            ..tabs_area
+        };
+        state.offset = if overflow {
+            self.scroll_offset(state, tabs_area.width)
+        } else {
+            0
+        };
+
+        if let Some((left, right)) = indicators {
+            if state.offset > 0 {
+                buf.set_span(
+                    tabs_area.left() - left_width,
+                    tabs_area.top(),
+                    left,
+                    left_width,
+                );
+            }
+            let hidden_on_right =
+                self.tabs_width(state.offset..self.titles.len()) > usize::from(tabs_area.width);
+            if hidden_on_right {
+                buf.set_span(tabs_area.right(), tabs_area.top(), right, right_width);
+            }
+        }
         if tabs_area.is_empty() {
             return;
         }
 
         let mut x = tabs_area.left();
         let titles_length = self.titles.len();
-        for (i, title) in self.titles.iter().enumerate() {
+        for (i, title) in self.titles.iter().enumerate().skip(state.offset) {
             let last_title = titles_length - 1 == i;
+            let tab_start = x;
             let remaining_width = tabs_area.right().saturating_sub(x);
 
             if remaining_width == 0 {
@@ -285,12 +514,13 @@ impl Tabs<'_> {
             x = pos.0;
             let remaining_width = tabs_area.right().saturating_sub(x);
             if remaining_width == 0 {
+                state.push_tab_area(i, tab_start, x, tabs_area.top(), None);
                 break;
             }
 
             // Title
             let pos = buf.set_line(x, tabs_area.top(), title, remaining_width);
-            if i == self.selected {
+            if i == state.selected {
                 buf.set_style(
                     Rect {
                         x,
@@ -304,12 +534,27 @@ impl Tabs<'_> {
             x = pos.0;
             let remaining_width = tabs_area.right().saturating_sub(x);
             if remaining_width == 0 {
+                state.push_tab_area(i, tab_start, x, tabs_area.top(), None);
+                break;
+            }
+
+            // Close Button
+            let mut close_button = None;
+            if let Some(close_symbol) = &self.close_symbol {
+                let pos = buf.set_span(x, tabs_area.top(), close_symbol, remaining_width);
+                close_button = Some(Rect::new(x, tabs_area.top(), pos.0 - x, 1));
+                x = pos.0;
+            }
+            let remaining_width = tabs_area.right().saturating_sub(x);
+            if remaining_width == 0 {
+                state.push_tab_area(i, tab_start, x, tabs_area.top(), close_button);
                 break;
             }
 
             // Right Padding
             let pos = buf.set_line(x, tabs_area.top(), &self.padding_right, remaining_width);
             x = pos.0;
+            state.push_tab_area(i, tab_start, x, tabs_area.top(), close_button);
             let remaining_width = tabs_area.right().saturating_sub(x);
             if remaining_width == 0 || last_title {
                 break;
@@ -319,6 +564,56 @@ impl Tabs<'_> {
             x = pos.0;
         }
     }
+
+    /// The width of a tab, including its padding and close button
+    fn tab_width(&self, title: &Line) -> usize {
+        self.padding_left.width()
+            + title.width()
+            + self.close_symbol.as_ref().map_or(0, Span::width)
+            + self.padding_right.width()
+    }
+
+    /// The width of a range of tabs, including the dividers between them
+    fn tabs_width(&self, range: std::ops::Range<usize>) -> usize {
+        let tabs = &self.titles[range];
+        let dividers = tabs.len().saturating_sub(1) * self.divider.width();
+        tabs.iter()
+            .map(|title| self.tab_width(title))
+            .sum::<usize>()
+            + dividers
+    }
+
+    /// Returns the offset that keeps the selected tab visible in the given width, scrolling as
+    /// little as possible from the current offset
+    fn scroll_offset(&self, state: &TabsState, width: u16) -> usize {
+        let selected = state.selected;
+        if selected >= self.titles.len() {
+            return state.offset.min(self.titles.len() - 1);
+        }
+        let mut offset = state.offset.min(selected);
+        while offset < selected && self.tabs_width(offset..selected + 1) > usize::from(width) {
+            offset += 1;
+        }
+        offset
+    }
+}
+
+impl TabsState {
+    /// Records the area of a visible tab spanning from `start` to `end`
+    fn push_tab_area(
+        &mut self,
+        index: usize,
+        start: u16,
+        end: u16,
+        y: u16,
+        close_button: Option<Rect>,
+    ) {
+        self.tab_areas.push(TabArea {
+            index,
+            area: Rect::new(start, y, end - start, 1),
+            close_button,
+        });
+    }
 }
 
 impl<'a, Item> FromIterator<Item> for Tabs<'a>
@@ -354,6 +649,8 @@ mod tests {
                 divider: Span::raw(symbols::line::VERTICAL),
                 padding_right: Line::from(" "),
                 padding_left: Line::from(" "),
+                overflow_indicators: None,
+                close_symbol: None,
             }
         );
     }
@@ -381,7 +678,7 @@ mod tests {
     #[track_caller]
     fn test_case(tabs: Tabs, area: Rect, expected: &Buffer) {
         let mut buffer = Buffer::empty(area);
-        tabs.render(area, &mut buffer);
+        Widget::render(tabs, area, &mut buffer);
         assert_eq!(&buffer, expected);
     }
 
@@ -507,4 +804,64 @@ mod tests {
                 .remove_modifier(Modifier::ITALIC)
         );
     }
+
+    #[test]
+    fn render_stateful_scrolls_to_selected_tab() {
+        let tabs = Tabs::new((0..10).map(|i| format!("Tab{i}"))).overflow_indicators("<", ">");
+        let mut state = TabsState::new(5);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 1));
+        StatefulWidget::render(&tabs, buffer.area, &mut buffer, &mut state);
+        let mut expected = Buffer::with_lines(["< Tab4 │ Tab5 │ Tab>"]);
+        expected.set_style(Rect::new(9, 0, 4, 1), DEFAULT_HIGHLIGHT_STYLE);
+        assert_eq!(buffer, expected);
+        assert_eq!(state.offset(), 4);
+
+        // scrolling back to the first tab hides the left indicator
+        state.select(0);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 1));
+        StatefulWidget::render(&tabs, buffer.area, &mut buffer, &mut state);
+        let mut expected = Buffer::with_lines(["  Tab0 │ Tab1 │ Tab>"]);
+        expected.set_style(Rect::new(2, 0, 4, 1), DEFAULT_HIGHLIGHT_STYLE);
+        assert_eq!(buffer, expected);
+        assert_eq!(state.offset(), 0);
+    }
+
+    #[test]
+    fn render_stateful_clamps_selected_tab() {
+        let tabs = Tabs::new(vec!["Tab1", "Tab2", "Tab3"]);
+        let mut state = TabsState::new(10);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 30, 1));
+        StatefulWidget::render(tabs, buffer.area, &mut buffer, &mut state);
+        assert_eq!(state.selected(), 2);
+        assert_eq!(state.offset(), 0);
+    }
+
+    #[test]
+    fn tab_at() {
+        let tabs = Tabs::new((0..10).map(|i| format!("Tab{i}"))).overflow_indicators("<", ">");
+        let mut state = TabsState::new(5);
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 20, 1));
+        StatefulWidget::render(tabs, buffer.area, &mut buffer, &mut state);
+        assert_eq!(state.tab_at(Position::new(0, 0)), None);
+        assert_eq!(state.tab_at(Position::new(2, 0)), Some(4));
+        assert_eq!(state.tab_at(Position::new(7, 0)), None);
+        assert_eq!(state.tab_at(Position::new(9, 0)), Some(5));
+        assert_eq!(state.tab_at(Position::new(17, 0)), Some(6));
+        assert_eq!(state.tab_at(Position::new(19, 0)), None);
+    }
+
+    #[test]
+    fn render_close_symbol() {
+        let tabs = Tabs::new(vec!["A", "B"]).close_symbol("x");
+        let mut state = TabsState::default();
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 10, 1));
+        StatefulWidget::render(tabs, buffer.area, &mut buffer, &mut state);
+        let mut expected = Buffer::with_lines([" Ax │ Bx  "]);
+        expected.set_style(Rect::new(1, 0, 1, 1), DEFAULT_HIGHLIGHT_STYLE);
+        assert_eq!(buffer, expected);
+        assert_eq!(state.close_button_at(Position::new(1, 0)), None);
+        assert_eq!(state.close_button_at(Position::new(2, 0)), Some(0));
+        assert_eq!(state.close_button_at(Position::new(7, 0)), Some(1));
+        assert_eq!(state.tab_at(Position::new(7, 0)), Some(1));
+    }
 }
@@ -0,0 +1,718 @@
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    layout::Position,
+    prelude::*,
+    text::StyledGrapheme,
+    widgets::{
+        reflow::{LineComposer, WordWrapper},
+        Block,
+    },
+};
+
+/// State of the [`TextArea`] widget
+///
+/// The state owns the edited text as a list of lines and tracks the cursor and the vertical scroll
+/// offset. The cursor is expressed as a `(line, column)` pair where the column is counted in
+/// graphemes, so that editing never splits a multi-codepoint character.
+///
+/// The editing methods are meant to be called from your event handling code. When the widget is
+/// rendered, the scroll offset is adjusted so that the cursor stays within the viewport and the
+/// screen position of the cursor is recorded so that it can be passed to [`Frame::set_cursor`].
+///
+/// [`Frame::set_cursor`]: crate::terminal::Frame::set_cursor
+///
+/// # Example
+///
+/// ```rust
+/// # use ratatui::{prelude::*, widgets::*};
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// // This should be stored outside of the function in your application state.
+/// let mut state = TextAreaState::new("Hello\nworld");
+/// state.insert_newline();
+/// state.insert_str("!");
+/// assert_eq!(state.text(), "Hello\nworld\n!");
+///
+/// frame.render_stateful_widget(TextArea::new(), area, &mut state);
+/// if let Some(position) = state.cursor_position() {
+///     frame.set_cursor(position.x, position.y);
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TextAreaState {
+    #[cfg_attr(feature = "serde", serde(deserialize_with = "deserialize_lines"))]
+    lines: Vec<String>,
+    cursor: (usize, usize),
+    offset: usize,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    cursor_position: Option<Position>,
+}
+
+impl Default for TextAreaState {
+    fn default() -> Self {
+        Self {
+            lines: vec![String::new()],
+            cursor: (0, 0),
+            offset: 0,
+            cursor_position: None,
+        }
+    }
+}
+
+/// Deserializes the lines of the state, making sure that there is always at least one line for the
+/// cursor to be on
+#[cfg(feature = "serde")]
+fn deserialize_lines<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let mut lines: Vec<String> = serde::Deserialize::deserialize(deserializer)?;
+    if lines.is_empty() {
+        lines.push(String::new());
+    }
+    Ok(lines)
+}
+
+impl TextAreaState {
+    /// Creates a new state with the given text and the cursor placed at the end of it
+    ///
+    /// The text is split into lines on `\n` (a trailing `\r` is removed from each line).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use ratatui::{prelude::*, widgets::*};
+    /// let state = TextAreaState::new("first\nsecond");
+    /// assert_eq!(state.cursor(), (1, 6));
+    /// ```
+    pub fn new<T: AsRef<str>>(text: T) -> Self {
+        let mut state = Self::default();
+        state.set_text(text);
+        state
+    }
+
+    /// Sets the index of the first visual row to be displayed
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Sets the cursor position as a `(line, column)` pair
+    ///
+    /// The cursor is clamped to the text.
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_cursor(mut self, cursor: (usize, usize)) -> Self {
+        self.set_cursor(cursor);
+        self
+    }
+
+    /// The edited text, with lines joined by `\n`
+    pub fn text(&self) -> String {
+        self.lines.join("\n")
+    }
+
+    /// Replaces the edited text and moves the cursor to the end of it
+    pub fn set_text<T: AsRef<str>>(&mut self, text: T) {
+        self.lines = text
+            .as_ref()
+            .split('\n')
+            .map(|line| line.strip_suffix('\r').unwrap_or(line).to_string())
+            .collect();
+        self.move_bottom();
+    }
+
+    /// The lines of the edited text
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+
+    /// The cursor position as a `(line, column)` pair, where the column is counted in graphemes
+    pub const fn cursor(&self) -> (usize, usize) {
+        self.cursor
+    }
+
+    /// Moves the cursor to the given `(line, column)` pair
+    ///
+    /// The cursor is clamped to the text.
+    pub fn set_cursor(&mut self, (line, column): (usize, usize)) {
+        let line = line.min(self.lines.len() - 1);
+        self.cursor = (line, column.min(self.line_len(line)));
+    }
+
+    /// Index of the first visual row to be displayed
+    ///
+    /// Rows are counted after wrapping, so a long line may span several rows.
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Mutable reference to the index of the first visual row to be displayed
+    pub fn offset_mut(&mut self) -> &mut usize {
+        &mut self.offset
+    }
+
+    /// The screen position of the cursor computed during the last render
+    ///
+    /// Returns `None` if the widget has not been rendered yet or if it was rendered into an empty
+    /// area.
+    pub const fn cursor_position(&self) -> Option<Position> {
+        self.cursor_position
+    }
+
+    /// Inserts a character at the cursor
+    ///
+    /// A `\n` character splits the current line.
+    pub fn insert_char(&mut self, c: char) {
+        if c == '\n' {
+            self.insert_newline();
+        } else {
+            self.insert_str(c.encode_utf8(&mut [0; 4]));
+        }
+    }
+
+    /// Inserts a string at the cursor
+    ///
+    /// The string may contain several lines separated by `\n`.
+    pub fn insert_str(&mut self, s: &str) {
+        for (i, part) in s.split('\n').enumerate() {
+            if i > 0 {
+                self.insert_newline();
+            }
+            let part = part.strip_suffix('\r').unwrap_or(part);
+            let (line, column) = self.cursor;
+            let index = byte_index(&self.lines[line], column);
+            self.lines[line].insert_str(index, part);
+            // the new text may combine with its neighbours (e.g. a combining accent or a zero
+            // width joiner), so place the cursor after the grapheme containing its end
+            let column = grapheme_index(&self.lines[line], index + part.len());
+            self.cursor.1 = column.min(self.line_len(line));
+        }
+    }
+
+    /// Splits the current line at the cursor and moves the cursor to the start of the new line
+    pub fn insert_newline(&mut self) {
+        let (line, column) = self.cursor;
+        let index = byte_index(&self.lines[line], column);
+        let rest = self.lines[line].split_off(index);
+        self.lines.insert(line + 1, rest);
+        self.cursor = (line + 1, 0);
+    }
+
+    /// Deletes the grapheme before the cursor (i.e. backspace)
+    ///
+    /// At the start of a line, the line is joined with the previous one.
+    pub fn delete_backward(&mut self) {
+        let (line, column) = self.cursor;
+        if column > 0 {
+            self.remove(line, column - 1..column);
+            self.cursor.1 -= 1;
+            return;
+        }
+        if line > 0 {
+            let current = self.lines.remove(line);
+            self.cursor = (line - 1, self.line_len(line - 1));
+            self.lines[line - 1].push_str(&current);
+        }
+    }
+
+    /// Deletes the grapheme after the cursor (i.e. delete)
+    ///
+    /// At the end of a line, the next line is joined with the current one.
+    pub fn delete_forward(&mut self) {
+        let (line, column) = self.cursor;
+        if column < self.line_len(line) {
+            self.remove(line, column..column + 1);
+            return;
+        }
+        if line + 1 < self.lines.len() {
+            let next = self.lines.remove(line + 1);
+            self.lines[line].push_str(&next);
+        }
+    }
+
+    /// Deletes the word before the cursor
+    ///
+    /// At the start of a line, the line is joined with the previous one.
+    pub fn delete_word_backward(&mut self) {
+        let (line, column) = self.cursor;
+        if column == 0 {
+            self.delete_backward();
+            return;
+        }
+        let start = previous_word_boundary(&self.lines[line], column);
+        self.remove(line, start..column);
+        self.cursor.1 = start;
+    }
+
+    /// Moves the cursor one grapheme to the left, wrapping to the end of the previous line
+    pub fn move_left(&mut self) {
+        let (line, column) = self.cursor;
+        if column > 0 {
+            self.cursor.1 -= 1;
+            return;
+        }
+        if line > 0 {
+            self.cursor = (line - 1, self.line_len(line - 1));
+        }
+    }
+
+    /// Moves the cursor one grapheme to the right, wrapping to the start of the next line
+    pub fn move_right(&mut self) {
+        let (line, column) = self.cursor;
+        if column < self.line_len(line) {
+            self.cursor.1 += 1;
+            return;
+        }
+        if line + 1 < self.lines.len() {
+            self.cursor = (line + 1, 0);
+        }
+    }
+
+    /// Moves the cursor to the previous line, keeping the column if possible
+    pub fn move_up(&mut self) {
+        let (line, column) = self.cursor;
+        self.set_cursor((line.saturating_sub(1), column));
+    }
+
+    /// Moves the cursor to the next line, keeping the column if possible
+    pub fn move_down(&mut self) {
+        let (line, column) = self.cursor;
+        self.set_cursor((line + 1, column));
+    }
+
+    /// Moves the cursor to the start of the previous word
+    ///
+    /// At the start of a line, the cursor moves to the end of the previous line.
+    pub fn move_word_left(&mut self) {
+        let (line, column) = self.cursor;
+        if column == 0 {
+            self.move_left();
+        } else {
+            self.cursor.1 = previous_word_boundary(&self.lines[line], column);
+        }
+    }
+
+    /// Moves the cursor to the end of the next word
+    ///
+    /// At the end of a line, the cursor moves to the start of the next line.
+    pub fn move_word_right(&mut self) {
+        let (line, column) = self.cursor;
+        if column == self.line_len(line) {
+            self.move_right();
+        } else {
+            self.cursor.1 = next_word_boundary(&self.lines[line], column);
+        }
+    }
+
+    /// Moves the cursor to the start of the current line
+    pub fn move_line_start(&mut self) {
+        self.cursor.1 = 0;
+    }
+
+    /// Moves the cursor to the end of the current line
+    pub fn move_line_end(&mut self) {
+        self.cursor.1 = self.line_len(self.cursor.0);
+    }
+
+    /// Moves the cursor to the start of the text
+    pub fn move_top(&mut self) {
+        self.cursor = (0, 0);
+    }
+
+    /// Moves the cursor to the end of the text
+    pub fn move_bottom(&mut self) {
+        let line = self.lines.len() - 1;
+        self.cursor = (line, self.line_len(line));
+    }
+
+    fn line_len(&self, line: usize) -> usize {
+        self.lines[line].graphemes(true).count()
+    }
+
+    fn remove(&mut self, line: usize, range: std::ops::Range<usize>) {
+        let text = &mut self.lines[line];
+        let range = byte_index(text, range.start)..byte_index(text, range.end);
+        text.replace_range(range, "");
+    }
+}
+
+/// Converts a grapheme index into a byte index in the given string
+fn byte_index(s: &str, index: usize) -> usize {
+    s.grapheme_indices(true)
+        .nth(index)
+        .map_or(s.len(), |(i, _)| i)
+}
+
+/// Index of the first grapheme of `s` that starts at or after the byte index
+fn grapheme_index(s: &str, byte_index: usize) -> usize {
+    s.grapheme_indices(true)
+        .take_while(|(i, _)| *i < byte_index)
+        .count()
+}
+
+fn is_whitespace(grapheme: &str) -> bool {
+    grapheme.chars().all(char::is_whitespace)
+}
+
+fn previous_word_boundary(s: &str, column: usize) -> usize {
+    let graphemes: Vec<&str> = s.graphemes(true).take(column).collect();
+    let mut index = graphemes.len();
+    while index > 0 && is_whitespace(graphemes[index - 1]) {
+        index -= 1;
+    }
+    while index > 0 && !is_whitespace(graphemes[index - 1]) {
+        index -= 1;
+    }
+    index
+}
+
+fn next_word_boundary(s: &str, column: usize) -> usize {
+    let mut graphemes = s.graphemes(true).skip(column).peekable();
+    let mut index = column;
+    while graphemes.next_if(|g| is_whitespace(g)).is_some() {
+        index += 1;
+    }
+    while graphemes.next_if(|g| !is_whitespace(g)).is_some() {
+        index += 1;
+    }
+    index
+}
+
+/// A multi-line text editing widget
+///
+/// The widget renders the text of a [`TextAreaState`], soft wrapping long lines on word
+/// boundaries to the width of the area and scrolling vertically to keep the cursor visible. The
+/// text is edited through the methods of the state.
+///
+/// The terminal cursor is not moved by the widget. Use [`TextAreaState::cursor_position`] after
+/// rendering to position it with [`Frame::set_cursor`], or set a [`cursor_style`] to draw a cursor
+/// in the buffer.
+///
+/// [`cursor_style`]: TextArea::cursor_style
+/// [`Frame::set_cursor`]: crate::terminal::Frame::set_cursor
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{prelude::*, widgets::*};
+///
+/// # fn ui(frame: &mut Frame, state: &mut TextAreaState) {
+/// let text_area = TextArea::new()
+///     .block(Block::bordered().title("Notes"))
+///     .cursor_style(Style::new().reversed());
+/// frame.render_stateful_widget(text_area, frame.size(), state);
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct TextArea<'a> {
+    block: Option<Block<'a>>,
+    style: Style,
+    cursor_style: Style,
+}
+
+impl<'a> TextArea<'a> {
+    /// Creates a new text area with default styles
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Surrounds the text area with a [`Block`]
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the base style of the widget
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the cell under the cursor
+    ///
+    /// This can be used to draw a fake cursor when the terminal cursor is hidden.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn cursor_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.cursor_style = style.into();
+        self
+    }
+}
+
+/// A visual row of the text area after wrapping
+struct Row<'a> {
+    line: usize,
+    /// Each grapheme along with its byte offset in the line
+    graphemes: Vec<(usize, &'a str)>,
+}
+
+/// Wraps each line of the text to the given width.
+///
+/// The reflow module drops whitespace at wrap points, so the byte offset of each grapheme in the
+/// source line is taken from the composer to keep track of the cursor.
+fn wrap_lines(lines: &[String], width: u16) -> Vec<Row<'_>> {
+    let mut rows = vec![];
+    for (i, line) in lines.iter().enumerate() {
+        let graphemes = line
+            .graphemes(true)
+            .map(|g| StyledGrapheme::new(g, Style::default()));
+        let mut composer =
+            WordWrapper::new(std::iter::once((graphemes, Alignment::Left)), width, false);
+        while let Some(wrapped) = composer.next_line() {
+            let graphemes = wrapped
+                .line
+                .iter()
+                .zip(wrapped.offsets)
+                .map(|(g, offset)| (offset.unwrap_or(line.len()), g.symbol))
+                .collect();
+            rows.push(Row { line: i, graphemes });
+        }
+    }
+    rows
+}
+
+impl StatefulWidget for TextArea<'_> {
+    type State = TextAreaState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidgetRef::render_ref(&self, area, buf, state);
+    }
+}
+
+impl StatefulWidget for &TextArea<'_> {
+    type State = TextAreaState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidgetRef::render_ref(self, area, buf, state);
+    }
+}
+
+impl StatefulWidgetRef for TextArea<'_> {
+    type State = TextAreaState;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        self.block.render_ref(area, buf);
+        let inner = self.block.inner_if_some(area);
+        state.cursor_position = None;
+        if inner.is_empty() {
+            return;
+        }
+        state.set_cursor(state.cursor);
+
+        let rows = wrap_lines(&state.lines, inner.width);
+        let (cursor_line, cursor_column) = state.cursor;
+        let cursor_byte = byte_index(&state.lines[cursor_line], cursor_column);
+        // the cursor is on the last row of its line that starts at or before the cursor
+        let cursor_row = rows
+            .iter()
+            .rposition(|row| {
+                row.line == cursor_line
+                    && row
+                        .graphemes
+                        .first()
+                        .map_or(true, |(i, _)| *i <= cursor_byte)
+            })
+            .unwrap_or_default();
+
+        // keep the cursor row within the viewport
+        let height = inner.height as usize;
+        state.offset = state.offset.min(cursor_row);
+        if cursor_row >= state.offset + height {
+            state.offset = cursor_row + 1 - height;
+        }
+
+        for (y, row) in (inner.y..inner.bottom()).zip(rows.iter().skip(state.offset)) {
+            let mut x = inner.x;
+            for (_, symbol) in &row.graphemes {
+                let width = symbol.width() as u16;
+                if width == 0 {
+                    continue;
+                }
+                buf.set_stringn(x, y, symbol, width as usize, self.style);
+                x += width;
+            }
+        }
+
+        let cursor_x = rows[cursor_row]
+            .graphemes
+            .iter()
+            .take_while(|(i, _)| *i < cursor_byte)
+            .map(|(_, symbol)| symbol.width() as u16)
+            .sum::<u16>()
+            .min(inner.width - 1);
+        let position = Position::new(
+            inner.x + cursor_x,
+            inner.y + (cursor_row - state.offset) as u16,
+        );
+        buf.set_style(Rect::new(position.x, position.y, 1, 1), self.cursor_style);
+        state.cursor_position = Some(position);
+    }
+}
+
+impl Styled for TextArea<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn render(state: &mut TextAreaState, width: u16, height: u16) -> Buffer {
+        let mut buf = Buffer::empty(Rect::new(0, 0, width, height));
+        TextArea::new().render(buf.area, &mut buf, state);
+        buf
+    }
+
+    #[test]
+    fn new_splits_lines() {
+        let state = TextAreaState::new("one\r\ntwo\n");
+        assert_eq!(state.lines(), ["one", "two", ""]);
+        assert_eq!(state.cursor(), (2, 0));
+        assert_eq!(state.text(), "one\ntwo\n");
+    }
+
+    #[test]
+    fn insert_multiline() {
+        let mut state = TextAreaState::new("ad").with_cursor((0, 1));
+        state.insert_str("b\nc");
+        assert_eq!(state.lines(), ["ab", "cd"]);
+        assert_eq!(state.cursor(), (1, 1));
+    }
+
+    #[rstest]
+    #[case::combining_accent("ab", 1, "\u{301}", "a\u{301}b", 1)]
+    #[case::zero_width_joiner("👩💻", 1, "\u{200d}", "👩\u{200d}💻", 1)]
+    #[case::multiline_accent("ab", 1, "\u{301}\nc\u{301}", "c\u{301}b", 1)]
+    fn insert_combining(
+        #[case] value: &str,
+        #[case] column: usize,
+        #[case] insert: &str,
+        #[case] expected_line: &str,
+        #[case] expected_column: usize,
+    ) {
+        let mut state = TextAreaState::new(value).with_cursor((0, column));
+        state.insert_str(insert);
+        let (line, _) = state.cursor();
+        assert_eq!(state.lines()[line], expected_line);
+        assert_eq!(state.cursor(), (line, expected_column));
+    }
+
+    #[test]
+    fn delete_joins_lines() {
+        let mut state = TextAreaState::new("ab\ncd").with_cursor((1, 0));
+        state.delete_backward();
+        assert_eq!(state.lines(), ["abcd"]);
+        assert_eq!(state.cursor(), (0, 2));
+
+        state.insert_newline();
+        state.move_left();
+        state.delete_forward();
+        assert_eq!(state.lines(), ["abcd"]);
+        assert_eq!(state.cursor(), (0, 2));
+    }
+
+    #[test]
+    fn delete_word_backward() {
+        let mut state = TextAreaState::new("hello big world");
+        state.delete_word_backward();
+        assert_eq!(state.text(), "hello big ");
+    }
+
+    #[rstest]
+    #[case::left(TextAreaState::move_left, (1, 0))]
+    #[case::left_wraps(|s: &mut TextAreaState| { s.move_left(); s.move_left(); }, (0, 11))]
+    #[case::right(TextAreaState::move_right, (1, 2))]
+    #[case::up(TextAreaState::move_up, (0, 1))]
+    #[case::down_clamps(TextAreaState::move_down, (2, 0))]
+    #[case::word_left(TextAreaState::move_word_left, (1, 0))]
+    #[case::word_right(TextAreaState::move_word_right, (1, 3))]
+    #[case::line_end(TextAreaState::move_line_end, (1, 3))]
+    #[case::top(TextAreaState::move_top, (0, 0))]
+    #[case::bottom(TextAreaState::move_bottom, (2, 0))]
+    fn movement(#[case] action: fn(&mut TextAreaState), #[case] expected: (usize, usize)) {
+        let mut state = TextAreaState::new("hello world\nfoo\n").with_cursor((1, 1));
+        action(&mut state);
+        assert_eq!(state.cursor(), expected);
+    }
+
+    #[test]
+    fn render_wraps_lines() {
+        let mut state = TextAreaState::new("hello world\nfoo").with_cursor((0, 8));
+        let buf = render(&mut state, 7, 3);
+        assert_eq!(buf, Buffer::with_lines(["hello  ", "world  ", "foo    "]));
+        assert_eq!(state.cursor_position(), Some(Position::new(2, 1)));
+    }
+
+    #[test]
+    fn render_scrolls_to_cursor() {
+        let mut state = TextAreaState::new("a\nb\nc\nd");
+        let buf = render(&mut state, 3, 2);
+        assert_eq!(buf, Buffer::with_lines(["c  ", "d  "]));
+        assert_eq!(state.offset(), 2);
+        assert_eq!(state.cursor_position(), Some(Position::new(1, 1)));
+
+        state.move_top();
+        let buf = render(&mut state, 3, 2);
+        assert_eq!(buf, Buffer::with_lines(["a  ", "b  "]));
+        assert_eq!(state.offset(), 0);
+    }
+
+    #[test]
+    fn render_cursor_style() {
+        let mut state = TextAreaState::new("ab").with_cursor((0, 1));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 3, 1));
+        TextArea::new()
+            .cursor_style(Style::new().reversed())
+            .render(buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(["ab "]);
+        expected.set_style(Rect::new(1, 0, 1, 1), Style::new().reversed());
+        assert_eq!(buf, expected);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn deserialize_empty_lines() -> Result<(), serde_json::Error> {
+        let mut state: TextAreaState =
+            serde_json::from_str(r#"{"lines":[],"cursor":[1,2],"offset":0}"#)?;
+        assert_eq!(state.text(), "");
+        state.set_cursor((1, 2));
+        assert_eq!(state.cursor(), (0, 0));
+        Ok(())
+    }
+
+    #[test]
+    fn render_in_block() {
+        let mut state = TextAreaState::new("hi");
+        let mut buf = Buffer::empty(Rect::new(0, 0, 5, 3));
+        TextArea::new()
+            .block(Block::bordered())
+            .render(buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["┌───┐", "│hi │", "└───┘"]));
+        assert_eq!(state.cursor_position(), Some(Position::new(3, 1)));
+    }
+}
@@ -0,0 +1,561 @@
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+use strum::{Display, EnumString};
+
+use crate::{
+    prelude::*,
+    widgets::{Block, BorderType, Paragraph, StatefulWidgetRef, Wrap},
+};
+
+/// The time to live of the toasts pushed with [`ToastState::push`]
+const DEFAULT_TTL: ToastTtl = ToastTtl::Duration(Duration::from_secs(3));
+
+/// The level of a toast, which defines its title and the color of its border
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ToastLevel {
+    /// An informational message. This is the **default**.
+    #[default]
+    Info,
+    /// A message reporting that an operation succeeded
+    Success,
+    /// A message that requires the attention of the user
+    Warning,
+    /// A message reporting that an operation failed
+    Error,
+}
+
+impl ToastLevel {
+    /// The style of the border and title of the toasts of this level
+    pub const fn style(self) -> Style {
+        let color = match self {
+            Self::Info => Color::Blue,
+            Self::Success => Color::Green,
+            Self::Warning => Color::Yellow,
+            Self::Error => Color::Red,
+        };
+        Style::new().fg(color)
+    }
+}
+
+/// How long a toast is displayed before it expires
+///
+/// A duration is measured from the moment the toast is pushed, while a number of frames counts the
+/// renders of the [`Toasts`] widget, which is convenient when the application redraws at a fixed
+/// rate or in tests.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ToastTtl {
+    /// The toast expires after being rendered this number of times
+    Frames(u32),
+    /// The toast expires after this duration
+    Duration(Duration),
+}
+
+impl From<Duration> for ToastTtl {
+    fn from(duration: Duration) -> Self {
+        Self::Duration(duration)
+    }
+}
+
+/// The corner of the area in which the [`Toasts`] are stacked
+#[derive(Debug, Default, Display, EnumString, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ToastPosition {
+    /// Toasts are stacked downwards from the top-left corner
+    TopLeft,
+    /// Toasts are stacked downwards from the top-right corner. This is the **default**.
+    #[default]
+    TopRight,
+    /// Toasts are stacked upwards from the bottom-left corner
+    BottomLeft,
+    /// Toasts are stacked upwards from the bottom-right corner
+    BottomRight,
+}
+
+impl ToastPosition {
+    const fn is_top(self) -> bool {
+        matches!(self, Self::TopLeft | Self::TopRight)
+    }
+
+    const fn is_left(self) -> bool {
+        matches!(self, Self::TopLeft | Self::BottomLeft)
+    }
+}
+
+/// A notification in a [`ToastState`]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+struct Toast {
+    message: Text<'static>,
+    level: ToastLevel,
+    ttl: ToastTtl,
+    created_at: Instant,
+    /// The number of times the toast was rendered
+    frames: u32,
+    /// The number of frames since the toast expired, while it slides out
+    leaving: Option<u16>,
+}
+
+impl Toast {
+    fn is_expired(&self, now: Instant) -> bool {
+        match self.ttl {
+            ToastTtl::Frames(frames) => self.frames > frames,
+            ToastTtl::Duration(duration) => now.duration_since(self.created_at) >= duration,
+        }
+    }
+
+    /// The number of columns of the toast that are visible while it slides in or out
+    fn visible_width(&self, width: u16, animation_frames: u16) -> u16 {
+        if animation_frames == 0 {
+            return width;
+        }
+        let progress = self.leaving.map_or_else(
+            || self.frames.min(u32::from(animation_frames)) as u16,
+            |leaving| animation_frames.saturating_sub(leaving),
+        );
+        (u32::from(width) * u32::from(progress) / u32::from(animation_frames)) as u16
+    }
+}
+
+/// State of the [`Toasts`] widget
+///
+/// The state is a queue of notifications. Each toast is displayed until its time to live expires
+/// and is then removed from the queue when the widget is rendered.
+///
+/// # Example
+///
+/// ```rust
+/// # use std::time::Duration;
+/// # use ratatui::widgets::*;
+/// let mut state = ToastState::default();
+/// state.push("File saved", ToastLevel::Success);
+/// state.push_with_ttl(
+///     "Connection lost",
+///     ToastLevel::Error,
+///     Duration::from_secs(10),
+/// );
+/// assert_eq!(state.len(), 2);
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct ToastState {
+    /// The toasts from the oldest to the newest
+    toasts: VecDeque<Toast>,
+}
+
+impl ToastState {
+    /// Creates a new state without toasts
+    pub const fn new() -> Self {
+        Self {
+            toasts: VecDeque::new(),
+        }
+    }
+
+    /// Pushes a toast that is displayed for 3 seconds
+    pub fn push<T>(&mut self, message: T, level: ToastLevel)
+    where
+        T: Into<Text<'static>>,
+    {
+        self.push_with_ttl(message, level, DEFAULT_TTL);
+    }
+
+    /// Pushes a toast that is displayed for the given number of frames or duration
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use ratatui::widgets::*;
+    /// let mut state = ToastState::default();
+    /// state.push_with_ttl("Copied", ToastLevel::Info, ToastTtl::Frames(30));
+    /// ```
+    pub fn push_with_ttl<T, D>(&mut self, message: T, level: ToastLevel, ttl: D)
+    where
+        T: Into<Text<'static>>,
+        D: Into<ToastTtl>,
+    {
+        self.toasts.push_back(Toast {
+            message: message.into(),
+            level,
+            ttl: ttl.into(),
+            created_at: Instant::now(),
+            frames: 0,
+            leaving: None,
+        });
+    }
+
+    /// The number of toasts in the queue, including the ones that are sliding out
+    pub fn len(&self) -> usize {
+        self.toasts.len()
+    }
+
+    /// Whether there are no toasts in the queue
+    pub fn is_empty(&self) -> bool {
+        self.toasts.is_empty()
+    }
+
+    /// Removes all the toasts
+    pub fn clear(&mut self) {
+        self.toasts.clear();
+    }
+
+    /// Advances the toasts by one frame and removes the ones that finished sliding out
+    fn update(&mut self, now: Instant, animation_frames: u16) {
+        for toast in &mut self.toasts {
+            toast.frames = toast.frames.saturating_add(1);
+            toast.leaving = match toast.leaving {
+                Some(leaving) => Some(leaving.saturating_add(1)),
+                None if toast.is_expired(now) => Some(1),
+                None => None,
+            };
+        }
+        self.toasts.retain(|toast| {
+            toast
+                .leaving
+                .map_or(true, |leaving| leaving < animation_frames)
+        });
+    }
+}
+
+/// A widget that displays stacked notifications that expire automatically.
+///
+/// The notifications, or toasts, are stored in a [`ToastState`] and are stacked from a corner of
+/// the area, the newest being the closest to the corner. Each toast is a bordered box with a title
+/// and a color that depend on its [`ToastLevel`], and its message is wrapped to the width of the
+/// toasts. The toasts that do not fit in the area are replaced by a `+N more` line until older
+/// toasts expire.
+///
+/// Each render of the widget counts as a frame: toasts slide in from the side of the area when
+/// they are pushed and slide out when they expire, over a number of frames that is set with
+/// [`Toasts::animation_frames`].
+///
+/// The widget is usually rendered last, over the whole frame.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{prelude::*, widgets::*};
+///
+/// # fn ui(frame: &mut Frame, toasts: &mut ToastState) {
+/// let widget = Toasts::new().position(ToastPosition::BottomRight).width(30);
+/// frame.render_stateful_widget(widget, frame.size(), toasts);
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Toasts {
+    position: ToastPosition,
+    width: u16,
+    animation_frames: u16,
+    style: Style,
+}
+
+impl Default for Toasts {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Toasts {
+    /// Creates a new widget that stacks toasts of 40 columns from the top-right corner
+    pub const fn new() -> Self {
+        Self {
+            position: ToastPosition::TopRight,
+            width: 40,
+            animation_frames: 4,
+            style: Style::new(),
+        }
+    }
+
+    /// Sets the corner from which the toasts are stacked
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn position(mut self, position: ToastPosition) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Sets the width of the toasts, including their borders
+    ///
+    /// The width is reduced to the width of the area if needed.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn width(mut self, width: u16) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the number of frames during which a toast slides in or out
+    ///
+    /// The default is 4 frames. 0 disables the animation.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn animation_frames(mut self, frames: u16) -> Self {
+        self.animation_frames = frames;
+        self
+    }
+
+    /// Sets the style of the toasts
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Returns the toast rendered into a buffer of its own, and its height
+    fn render_toast(&self, toast: &Toast, width: u16) -> Buffer {
+        let paragraph = Paragraph::new(toast.message.clone()).wrap(Wrap::new(true));
+        let height = (paragraph.line_count(width.saturating_sub(2)) as u16).saturating_add(2);
+        let block = Block::bordered()
+            .border_type(BorderType::Rounded)
+            .border_style(toast.level.style())
+            .title(Line::styled(toast.level.to_string(), toast.level.style()));
+        let mut buf = Buffer::empty(Rect::new(0, 0, width, height));
+        buf.set_style(buf.area, self.style);
+        WidgetRef::render_ref(&paragraph.block(block), buf.area, &mut buf);
+        buf
+    }
+
+    /// Copies the visible columns of a toast to the row `y` of the area
+    fn copy_toast(&self, toast: &Buffer, visible_width: u16, area: Rect, y: u16, buf: &mut Buffer) {
+        let toast_area = toast.area;
+        let (source_x, target_x) = if self.position.is_left() {
+            (toast_area.width - visible_width, area.left())
+        } else {
+            (0, area.right() - visible_width)
+        };
+        for dy in 0..toast_area.height.min(area.bottom().saturating_sub(y)) {
+            for dx in 0..visible_width {
+                *buf.get_mut(target_x + dx, y + dy) = toast.get(source_x + dx, dy).clone();
+            }
+        }
+    }
+
+    /// Renders the `+N more` line in the row `y` of the area
+    fn render_hidden_count(&self, hidden: usize, width: u16, area: Rect, y: u16, buf: &mut Buffer) {
+        let (x, alignment) = if self.position.is_left() {
+            (area.left(), Alignment::Left)
+        } else {
+            (area.right() - width, Alignment::Right)
+        };
+        let line = Line::from(format!("+{hidden} more"))
+            .style(self.style)
+            .dim()
+            .alignment(alignment);
+        line.render(Rect::new(x, y, width, 1), buf);
+    }
+}
+
+impl StatefulWidget for Toasts {
+    type State = ToastState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidgetRef::render_ref(&self, area, buf, state);
+    }
+}
+
+// Note: remove this when StatefulWidgetRef is stabilized and replace with the blanket impl
+impl StatefulWidget for &Toasts {
+    type State = ToastState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidgetRef::render_ref(self, area, buf, state);
+    }
+}
+
+impl StatefulWidgetRef for Toasts {
+    type State = ToastState;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        state.update(Instant::now(), self.animation_frames);
+        let area = area.intersection(buf.area);
+        let width = self.width.min(area.width);
+        if width == 0 || area.is_empty() {
+            return;
+        }
+
+        let mut used_height = 0;
+        for (i, toast) in state.toasts.iter().rev().enumerate() {
+            let toast_buf = self.render_toast(toast, width);
+            let height = toast_buf.area.height;
+            let remaining_height = area.height - used_height;
+            if height > remaining_height {
+                let y = if self.position.is_top() {
+                    area.top() + used_height
+                } else {
+                    area.bottom() - used_height - 1
+                };
+                if remaining_height > 0 {
+                    self.render_hidden_count(state.len() - i, width, area, y, buf);
+                }
+                break;
+            }
+            let y = if self.position.is_top() {
+                area.top() + used_height
+            } else {
+                area.bottom() - used_height - height
+            };
+            let visible_width = toast.visible_width(width, self.animation_frames);
+            self.copy_toast(&toast_buf, visible_width, area, y, buf);
+            used_height += height;
+        }
+    }
+}
+
+impl Styled for Toasts {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn render(toasts: Toasts, state: &mut ToastState, width: u16, height: u16) -> Buffer {
+        let mut buf = Buffer::empty(Rect::new(0, 0, width, height));
+        StatefulWidget::render(toasts, buf.area, &mut buf, state);
+        buf
+    }
+
+    /// Removes the styles of the buffer to compare its content only
+    fn content(mut buf: Buffer) -> Buffer {
+        buf.set_style(buf.area, Style::reset());
+        buf
+    }
+
+    fn unstyled(lines: &[&str]) -> Buffer {
+        let mut buf = Buffer::with_lines(lines.iter().copied());
+        buf.set_style(buf.area, Style::reset());
+        buf
+    }
+
+    #[rstest]
+    #[case::info(ToastLevel::Info, Color::Blue)]
+    #[case::success(ToastLevel::Success, Color::Green)]
+    #[case::warning(ToastLevel::Warning, Color::Yellow)]
+    #[case::error(ToastLevel::Error, Color::Red)]
+    fn level_style(#[case] level: ToastLevel, #[case] color: Color) {
+        assert_eq!(level.style(), Style::new().fg(color));
+    }
+
+    #[test]
+    fn push() {
+        let mut state = ToastState::new();
+        assert!(state.is_empty());
+        state.push("a", ToastLevel::Info);
+        state.push_with_ttl("b", ToastLevel::Error, ToastTtl::Frames(1));
+        assert_eq!(state.len(), 2);
+        state.clear();
+        assert!(state.is_empty());
+    }
+
+    #[test]
+    fn toasts_expire() {
+        let toasts = Toasts::new().animation_frames(0);
+        let mut state = ToastState::new();
+        state.push_with_ttl("a", ToastLevel::Info, ToastTtl::Frames(2));
+        state.push_with_ttl("b", ToastLevel::Info, ToastTtl::Frames(1));
+        render(toasts, &mut state, 10, 10);
+        assert_eq!(state.len(), 2);
+        render(toasts, &mut state, 10, 10);
+        assert_eq!(state.len(), 1);
+        render(toasts, &mut state, 10, 10);
+        assert!(state.is_empty());
+    }
+
+    #[test]
+    fn toasts_expire_after_duration() {
+        let mut state = ToastState::new();
+        state.push_with_ttl("a", ToastLevel::Info, Duration::ZERO);
+        render(Toasts::new().animation_frames(0), &mut state, 10, 10);
+        assert!(state.is_empty());
+    }
+
+    #[rstest]
+    #[case::top_right(
+        ToastPosition::TopRight,
+        &[
+            "    ╭Info╮",
+            "    │new │",
+            "    ╰────╯",
+            "    ╭Info╮",
+            "    │old │",
+            "    ╰────╯",
+            "          ",
+        ],
+    )]
+    #[case::bottom_left(
+        ToastPosition::BottomLeft,
+        &[
+            "          ",
+            "╭Info╮    ",
+            "│old │    ",
+            "╰────╯    ",
+            "╭Info╮    ",
+            "│new │    ",
+            "╰────╯    ",
+        ],
+    )]
+    fn render_position(#[case] position: ToastPosition, #[case] expected: &[&str]) {
+        let toasts = Toasts::new()
+            .position(position)
+            .width(6)
+            .animation_frames(0);
+        let mut state = ToastState::new();
+        state.push("old", ToastLevel::Info);
+        state.push("new", ToastLevel::Info);
+        let buf = render(toasts, &mut state, 10, 7);
+        assert_eq!(content(buf), unstyled(expected));
+    }
+
+    #[test]
+    fn render_style() {
+        let toasts = Toasts::new().width(7).animation_frames(0);
+        let mut state = ToastState::new();
+        state.push("oops", ToastLevel::Error);
+        let buf = render(toasts, &mut state, 7, 3);
+        let mut expected = Buffer::with_lines(["╭Error╮", "│oops │", "╰─────╯"]);
+        expected.set_style(expected.area, Style::new().red());
+        expected.set_style(Rect::new(1, 1, 5, 1), Style::reset());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_overflow() {
+        let toasts = Toasts::new().width(8).animation_frames(0);
+        let mut state = ToastState::new();
+        state.push("1", ToastLevel::Info);
+        state.push("2", ToastLevel::Info);
+        state.push("3", ToastLevel::Info);
+        let buf = render(toasts, &mut state, 8, 4);
+        let expected = unstyled(&["╭Info──╮", "│3     │", "╰──────╯", " +2 more"]);
+        assert_eq!(content(buf), expected);
+    }
+
+    #[test]
+    fn render_animation() {
+        let toasts = Toasts::new().width(6).animation_frames(2);
+        let mut state = ToastState::new();
+        state.push_with_ttl("a", ToastLevel::Info, ToastTtl::Frames(2));
+        let frames: Vec<Buffer> = (0..4)
+            .map(|_| content(render(toasts, &mut state, 6, 3)))
+            .collect();
+        assert_eq!(
+            frames,
+            [
+                unstyled(&["   ╭In", "   │a ", "   ╰──"]),
+                unstyled(&["╭Info╮", "│a   │", "╰────╯"]),
+                unstyled(&["   ╭In", "   │a ", "   ╰──"]),
+                unstyled(&["      ", "      ", "      "]),
+            ]
+        );
+        assert!(state.is_empty());
+    }
+}
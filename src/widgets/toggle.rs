@@ -0,0 +1,792 @@
+use crate::{
+    layout::Position,
+    prelude::*,
+    symbols::toggle,
+    widgets::{Block, StatefulWidgetRef},
+};
+
+/// State of the [`Checkbox`] widget
+///
+/// # Example
+///
+/// ```rust
+/// # use ratatui::widgets::*;
+/// let mut state = CheckboxState::default();
+/// state.toggle();
+/// assert!(state.is_checked());
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct CheckboxState {
+    checked: bool,
+}
+
+impl CheckboxState {
+    /// Creates a new state, checked or not
+    pub const fn new(checked: bool) -> Self {
+        Self { checked }
+    }
+
+    /// Whether the checkbox is checked
+    pub const fn is_checked(&self) -> bool {
+        self.checked
+    }
+
+    /// Checks or unchecks the checkbox
+    pub fn set_checked(&mut self, checked: bool) {
+        self.checked = checked;
+    }
+
+    /// Checks the checkbox if it is unchecked, and unchecks it otherwise
+    pub fn toggle(&mut self) {
+        self.checked = !self.checked;
+    }
+}
+
+/// A widget that displays a checkbox followed by a label
+///
+/// Whether the checkbox is checked is held by a [`CheckboxState`]. The widget does not handle
+/// input: the application toggles the state, e.g. when <kbd>Space</kbd> is pressed.
+///
+/// The symbols default to [`symbols::toggle::CHECKBOX`](toggle::CHECKBOX). A disabled checkbox
+/// is rendered with [`Checkbox::disabled_style`], which is dimmed by default.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{prelude::*, widgets::*};
+///
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// let checkbox = Checkbox::new("Show hidden files").symbols(symbols::toggle::BALLOT);
+///
+/// // This should be stored outside of the function in your application state.
+/// let mut state = CheckboxState::new(true);
+///
+/// frame.render_stateful_widget(checkbox, area, &mut state);
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Checkbox<'a> {
+    label: Line<'a>,
+    block: Option<Block<'a>>,
+    style: Style,
+    symbols: toggle::Set,
+    symbol_style: Style,
+    disabled: bool,
+    disabled_style: Style,
+}
+
+impl Default for Checkbox<'_> {
+    fn default() -> Self {
+        Self::new("")
+    }
+}
+
+impl<'a> Checkbox<'a> {
+    /// Creates a new checkbox with the given label
+    pub fn new<T: Into<Line<'a>>>(label: T) -> Self {
+        Self {
+            label: label.into(),
+            block: None,
+            style: Style::new(),
+            symbols: toggle::CHECKBOX,
+            symbol_style: Style::new(),
+            disabled: false,
+            disabled_style: Style::new().add_modifier(Modifier::DIM),
+        }
+    }
+
+    /// Wraps the checkbox in a [`Block`]
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the style of the checkbox
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the symbols of the checked and unchecked states
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn symbols(mut self, symbols: toggle::Set) -> Self {
+        self.symbols = symbols;
+        self
+    }
+
+    /// Sets the style of the symbol
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn symbol_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.symbol_style = style.into();
+        self
+    }
+
+    /// Sets whether the checkbox is disabled, which renders it with [`Checkbox::disabled_style`]
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Sets the style of a disabled checkbox, which is dimmed by default
+    ///
+    /// The style is patched on top of the other styles.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn disabled_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.disabled_style = style.into();
+        self
+    }
+}
+
+impl StatefulWidget for Checkbox<'_> {
+    type State = CheckboxState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        self.render_ref(area, buf, state);
+    }
+}
+
+// Note: remove this when StatefulWidgetRef is stabilized and replace with the blanket impl
+impl StatefulWidget for &Checkbox<'_> {
+    type State = CheckboxState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        self.render_ref(area, buf, state);
+    }
+}
+
+impl StatefulWidgetRef for Checkbox<'_> {
+    type State = CheckboxState;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        self.block.render_ref(area, buf);
+        let inner = self.block.inner_if_some(area).intersection(buf.area);
+        let symbol = if state.checked {
+            self.symbols.on
+        } else {
+            self.symbols.off
+        };
+        render_toggle(inner, buf, symbol, self.symbol_style, &self.label);
+        if self.disabled {
+            buf.set_style(area.intersection(buf.area), self.disabled_style);
+        }
+    }
+}
+
+impl Styled for Checkbox<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+/// State of the [`Switch`] widget
+///
+/// # Example
+///
+/// ```rust
+/// # use ratatui::widgets::*;
+/// let mut state = SwitchState::new(true);
+/// state.toggle();
+/// assert!(!state.is_on());
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct SwitchState {
+    on: bool,
+}
+
+impl SwitchState {
+    /// Creates a new state, switched on or off
+    pub const fn new(on: bool) -> Self {
+        Self { on }
+    }
+
+    /// Whether the switch is on
+    pub const fn is_on(&self) -> bool {
+        self.on
+    }
+
+    /// Switches the switch on or off
+    pub fn set_on(&mut self, on: bool) {
+        self.on = on;
+    }
+
+    /// Switches the switch off if it is on, and on otherwise
+    pub fn toggle(&mut self) {
+        self.on = !self.on;
+    }
+}
+
+/// A widget that displays an on/off switch followed by a label
+///
+/// Whether the switch is on is held by a [`SwitchState`]. The widget does not handle input: the
+/// application toggles the state, e.g. when <kbd>Space</kbd> is pressed.
+///
+/// The symbols default to [`symbols::toggle::SWITCH`](toggle::SWITCH), and the symbol is styled
+/// with [`Switch::on_style`] or [`Switch::off_style`] depending on the state. A disabled switch is
+/// rendered with [`Switch::disabled_style`], which is dimmed by default.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{prelude::*, widgets::*};
+///
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// let switch = Switch::new("Dark mode").symbols(symbols::toggle::LABELED);
+///
+/// // This should be stored outside of the function in your application state.
+/// let mut state = SwitchState::default();
+///
+/// frame.render_stateful_widget(switch, area, &mut state);
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Switch<'a> {
+    label: Line<'a>,
+    block: Option<Block<'a>>,
+    style: Style,
+    symbols: toggle::Set,
+    on_style: Style,
+    off_style: Style,
+    disabled: bool,
+    disabled_style: Style,
+}
+
+impl Default for Switch<'_> {
+    fn default() -> Self {
+        Self::new("")
+    }
+}
+
+impl<'a> Switch<'a> {
+    /// Creates a new switch with the given label
+    pub fn new<T: Into<Line<'a>>>(label: T) -> Self {
+        Self {
+            label: label.into(),
+            block: None,
+            style: Style::new(),
+            symbols: toggle::SWITCH,
+            on_style: Style::new().fg(Color::Green),
+            off_style: Style::new(),
+            disabled: false,
+            disabled_style: Style::new().add_modifier(Modifier::DIM),
+        }
+    }
+
+    /// Wraps the switch in a [`Block`]
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the style of the switch
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the symbols of the on and off states
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn symbols(mut self, symbols: toggle::Set) -> Self {
+        self.symbols = symbols;
+        self
+    }
+
+    /// Sets the style of the symbol when the switch is on, which is green by default
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn on_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.on_style = style.into();
+        self
+    }
+
+    /// Sets the style of the symbol when the switch is off
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn off_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.off_style = style.into();
+        self
+    }
+
+    /// Sets whether the switch is disabled, which renders it with [`Switch::disabled_style`]
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Sets the style of a disabled switch, which is dimmed by default
+    ///
+    /// The style is patched on top of the other styles.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn disabled_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.disabled_style = style.into();
+        self
+    }
+}
+
+impl StatefulWidget for Switch<'_> {
+    type State = SwitchState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        self.render_ref(area, buf, state);
+    }
+}
+
+// Note: remove this when StatefulWidgetRef is stabilized and replace with the blanket impl
+impl StatefulWidget for &Switch<'_> {
+    type State = SwitchState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        self.render_ref(area, buf, state);
+    }
+}
+
+impl StatefulWidgetRef for Switch<'_> {
+    type State = SwitchState;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        self.block.render_ref(area, buf);
+        let inner = self.block.inner_if_some(area).intersection(buf.area);
+        let (symbol, symbol_style) = if state.on {
+            (self.symbols.on, self.on_style)
+        } else {
+            (self.symbols.off, self.off_style)
+        };
+        render_toggle(inner, buf, symbol, symbol_style, &self.label);
+        if self.disabled {
+            buf.set_style(area.intersection(buf.area), self.disabled_style);
+        }
+    }
+}
+
+impl Styled for Switch<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+/// State of the [`RadioGroup`] widget
+///
+/// The state holds the index of the selected option. The index is clamped to the last option when
+/// the group is rendered.
+///
+/// # Example
+///
+/// ```rust
+/// # use ratatui::widgets::*;
+/// let mut state = RadioGroupState::default();
+/// state.select_next();
+/// assert_eq!(state.selected(), Some(0));
+/// state.select_next();
+/// assert_eq!(state.selected(), Some(1));
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct RadioGroupState {
+    selected: Option<usize>,
+}
+
+impl RadioGroupState {
+    /// Creates a new state with the given option selected, or no option with `None`
+    pub const fn new(selected: Option<usize>) -> Self {
+        Self { selected }
+    }
+
+    /// The index of the selected option, if any
+    pub const fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    /// Selects the option at the given index, or no option with `None`
+    pub fn select(&mut self, index: Option<usize>) {
+        self.selected = index;
+    }
+
+    /// Selects the next option, or the first one when no option is selected
+    ///
+    /// The index is clamped to the last option on the next render.
+    pub fn select_next(&mut self) {
+        self.selected = Some(
+            self.selected
+                .map_or(0, |selected| selected.saturating_add(1)),
+        );
+    }
+
+    /// Selects the previous option, or the first one when no option is selected
+    pub fn select_previous(&mut self) {
+        self.selected = Some(
+            self.selected
+                .map_or(0, |selected| selected.saturating_sub(1)),
+        );
+    }
+}
+
+/// A widget that displays a group of options of which one can be selected
+///
+/// The options are laid out vertically, one per row, or horizontally with
+/// [`RadioGroup::direction`]. Each option is preceded by a symbol showing whether it is selected,
+/// and the selected option is styled with [`RadioGroup::selected_style`].
+///
+/// The selected option is held by a [`RadioGroupState`]. The widget does not handle input: the
+/// application selects the previous or next option, e.g. when the arrow keys are pressed.
+///
+/// The symbols default to [`symbols::toggle::RADIO`](toggle::RADIO). A disabled group is rendered
+/// with [`RadioGroup::disabled_style`], which is dimmed by default.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{prelude::*, widgets::*};
+///
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// let group = RadioGroup::new(["Small", "Medium", "Large"])
+///     .direction(Direction::Horizontal)
+///     .selected_style(Style::new().bold());
+///
+/// // This should be stored outside of the function in your application state.
+/// let mut state = RadioGroupState::new(Some(1));
+///
+/// frame.render_stateful_widget(group, area, &mut state);
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct RadioGroup<'a> {
+    options: Vec<Line<'a>>,
+    block: Option<Block<'a>>,
+    style: Style,
+    symbols: toggle::Set,
+    symbol_style: Style,
+    selected_style: Style,
+    direction: Direction,
+    spacing: u16,
+    disabled: bool,
+    disabled_style: Style,
+}
+
+impl Default for RadioGroup<'_> {
+    fn default() -> Self {
+        Self::new(Vec::<Line>::new())
+    }
+}
+
+impl<'a> RadioGroup<'a> {
+    /// Creates a new group with the given options
+    pub fn new<I>(options: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Line<'a>>,
+    {
+        Self {
+            options: options.into_iter().map(Into::into).collect(),
+            block: None,
+            style: Style::new(),
+            symbols: toggle::RADIO,
+            symbol_style: Style::new(),
+            selected_style: Style::new(),
+            direction: Direction::Vertical,
+            spacing: 2,
+            disabled: false,
+            disabled_style: Style::new().add_modifier(Modifier::DIM),
+        }
+    }
+
+    /// Wraps the group in a [`Block`]
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the style of the group
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the symbols of the selected and unselected options
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn symbols(mut self, symbols: toggle::Set) -> Self {
+        self.symbols = symbols;
+        self
+    }
+
+    /// Sets the style of the symbols
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn symbol_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.symbol_style = style.into();
+        self
+    }
+
+    /// Sets the style of the selected option
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn selected_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.selected_style = style.into();
+        self
+    }
+
+    /// Sets the direction in which the options are laid out, which is vertical by default
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Sets the number of columns between the options laid out horizontally, which defaults to 2
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets whether the group is disabled, which renders it with [`RadioGroup::disabled_style`]
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Sets the style of a disabled group, which is dimmed by default
+    ///
+    /// The style is patched on top of the other styles.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn disabled_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.disabled_style = style.into();
+        self
+    }
+
+    /// The area of the option at the given position, or `None` once the options overflow
+    fn option_area(&self, area: Rect, position: Position, option: &Line) -> Option<Rect> {
+        let area = match self.direction {
+            Direction::Vertical => Rect::new(area.x, position.y, area.width, 1),
+            Direction::Horizontal => {
+                let symbol_width = Line::from(self.symbols.on)
+                    .width()
+                    .max(Line::from(self.symbols.off).width());
+                let width = u16::try_from(symbol_width + 1 + option.width()).unwrap_or(u16::MAX);
+                let width = width.min(area.right().saturating_sub(position.x));
+                Rect::new(position.x, area.y, width, 1)
+            }
+        };
+        Some(area).filter(|area| !area.is_empty())
+    }
+}
+
+impl StatefulWidget for RadioGroup<'_> {
+    type State = RadioGroupState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        self.render_ref(area, buf, state);
+    }
+}
+
+// Note: remove this when StatefulWidgetRef is stabilized and replace with the blanket impl
+impl StatefulWidget for &RadioGroup<'_> {
+    type State = RadioGroupState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        self.render_ref(area, buf, state);
+    }
+}
+
+impl StatefulWidgetRef for RadioGroup<'_> {
+    type State = RadioGroupState;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        self.block.render_ref(area, buf);
+        let inner = self.block.inner_if_some(area).intersection(buf.area);
+        state.selected = state
+            .selected
+            .map(|selected| selected.min(self.options.len().saturating_sub(1)))
+            .filter(|_| !self.options.is_empty());
+
+        let mut position = inner.as_position();
+        for (index, option) in self.options.iter().enumerate() {
+            let Some(option_area) = self
+                .option_area(inner, position, option)
+                .filter(|option_area| inner.intersects(*option_area))
+            else {
+                break;
+            };
+            let symbol = if state.selected == Some(index) {
+                buf.set_style(option_area, self.selected_style);
+                self.symbols.on
+            } else {
+                self.symbols.off
+            };
+            render_toggle(option_area, buf, symbol, self.symbol_style, option);
+            match self.direction {
+                Direction::Vertical => position.y += 1,
+                Direction::Horizontal => {
+                    position.x = option_area.right().saturating_add(self.spacing);
+                }
+            }
+        }
+        if self.disabled {
+            buf.set_style(area.intersection(buf.area), self.disabled_style);
+        }
+    }
+}
+
+impl Styled for RadioGroup<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+/// Renders a symbol followed by a space and a label on the first row of the area
+fn render_toggle(area: Rect, buf: &mut Buffer, symbol: &str, symbol_style: Style, label: &Line) {
+    if area.is_empty() {
+        return;
+    }
+    let (x, _) = buf.set_stringn(area.x, area.y, symbol, area.width as usize, symbol_style);
+    let x = x.saturating_add(1);
+    let width = area.right().saturating_sub(x);
+    buf.set_line(x, area.y, label, width);
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case::unchecked(false, "[ ] Wrap  ")]
+    #[case::checked(true, "[x] Wrap  ")]
+    fn render_checkbox(#[case] checked: bool, #[case] expected: &str) {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+        let mut state = CheckboxState::new(checked);
+        Checkbox::new("Wrap").render(buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines([expected]));
+    }
+
+    #[test]
+    fn render_disabled_checkbox() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 6, 1));
+        Checkbox::new("Wrap")
+            .symbols(toggle::BALLOT)
+            .symbol_style(Style::new().red())
+            .disabled(true)
+            .render(buf.area, &mut buf, &mut CheckboxState::new(true));
+        let mut expected = Buffer::with_lines(["☑ Wrap"]);
+        expected.set_style(Rect::new(0, 0, 1, 1), Style::new().red());
+        expected.set_style(buf.area, Style::new().dim());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn toggle_states() {
+        let mut checkbox = CheckboxState::default();
+        checkbox.toggle();
+        assert!(checkbox.is_checked());
+        checkbox.set_checked(false);
+        assert!(!checkbox.is_checked());
+
+        let mut switch = SwitchState::default();
+        switch.toggle();
+        assert!(switch.is_on());
+        switch.set_on(false);
+        assert!(!switch.is_on());
+    }
+
+    #[rstest]
+    #[case::off(false, "(●─) Sync ", Style::new())]
+    #[case::on(true, "(─●) Sync ", Style::new().green())]
+    fn render_switch(#[case] on: bool, #[case] line: &str, #[case] symbol_style: Style) {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 10, 1));
+        let mut state = SwitchState::new(on);
+        Switch::new("Sync").render(buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines([line]);
+        expected.set_style(Rect::new(0, 0, 4, 1), symbol_style);
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_radio_group_vertical() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 2));
+        let mut state = RadioGroupState::new(Some(5));
+        RadioGroup::new(["One", "Two", "Three"])
+            .selected_style(Style::new().bold())
+            .render(buf.area, &mut buf, &mut state);
+        assert_eq!(state.selected(), Some(2));
+        assert_eq!(buf, Buffer::with_lines(["( ) One ", "( ) Two "]));
+
+        state.select_previous();
+        RadioGroup::new(["One", "Two", "Three"])
+            .selected_style(Style::new().bold())
+            .render(buf.area, &mut buf, &mut state);
+        let mut expected = Buffer::with_lines(["( ) One ", "(•) Two "]);
+        expected.set_style(Rect::new(0, 1, 8, 1), Style::new().bold());
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn render_radio_group_horizontal() {
+        let mut buf = Buffer::empty(Rect::new(0, 0, 16, 1));
+        let mut state = RadioGroupState::new(Some(0));
+        RadioGroup::new(["A", "B", "C"])
+            .direction(Direction::Horizontal)
+            .symbols(toggle::CIRCLE)
+            .render(buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["◉ A  ○ B  ○ C   "]));
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 8, 1));
+        RadioGroup::new(["A", "B", "C"])
+            .direction(Direction::Horizontal)
+            .spacing(1)
+            .render(buf.area, &mut buf, &mut state);
+        assert_eq!(buf, Buffer::with_lines(["(•) A ( "]));
+    }
+
+    #[test]
+    fn radio_group_state() {
+        let mut state = RadioGroupState::default();
+        state.select_previous();
+        assert_eq!(state.selected(), Some(0));
+        state.select_next();
+        assert_eq!(state.selected(), Some(1));
+        state.select(None);
+        assert_eq!(state.selected(), None);
+
+        let mut state = RadioGroupState::new(Some(3));
+        let mut buf = Buffer::empty(Rect::new(0, 0, 4, 1));
+        RadioGroup::default().render(buf.area, &mut buf, &mut state);
+        assert_eq!(state.selected(), None);
+    }
+}
@@ -0,0 +1,692 @@
+use std::collections::BTreeSet;
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::{
+    prelude::*,
+    widgets::{Block, HighlightSpacing},
+};
+
+/// State of the [`Tree`] widget
+///
+/// The state keeps track of which nodes are expanded, which node is selected and the index of the
+/// first visible row. Nodes are identified by their path in the tree: the list of child indices
+/// leading from the root items to the node (e.g. `[1, 0]` is the first child of the second root
+/// item).
+///
+/// The navigation methods ([`select_next`], [`select_previous`], [`select_parent`], ...) operate on
+/// the rows that were visible during the last render, so they match what the user sees on screen.
+///
+/// [`select_next`]: TreeState::select_next
+/// [`select_previous`]: TreeState::select_previous
+/// [`select_parent`]: TreeState::select_parent
+///
+/// # Example
+///
+/// ```rust
+/// # use ratatui::{prelude::*, widgets::*};
+/// # fn ui(frame: &mut Frame) {
+/// # let area = Rect::default();
+/// let items = [
+///     TreeItem::new("src").children([TreeItem::new("lib.rs"), TreeItem::new("main.rs")]),
+///     TreeItem::new("Cargo.toml"),
+/// ];
+///
+/// // This should be stored outside of the function in your application state.
+/// let mut state = TreeState::default();
+/// state.select(Some(vec![0]));
+/// state.expand(vec![0]);
+///
+/// frame.render_stateful_widget(Tree::new(items), area, &mut state);
+///
+/// // in response to key events
+/// state.select_next();
+/// state.toggle_expanded();
+/// # }
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TreeState {
+    offset: usize,
+    selected: Option<Vec<usize>>,
+    expanded: BTreeSet<Vec<usize>>,
+    /// Paths of the rows visible during the last render, in display order
+    #[cfg_attr(feature = "serde", serde(skip))]
+    visible: Vec<Vec<usize>>,
+}
+
+impl TreeState {
+    /// Sets the index of the first row to be displayed
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn with_offset(mut self, offset: usize) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Sets the path of the selected node
+    ///
+    /// This is a fluent setter method which must be chained or used as it consumes self
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn with_selected(mut self, selected: Option<Vec<usize>>) -> Self {
+        self.selected = selected;
+        self
+    }
+
+    /// Index of the first row to be displayed
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Mutable reference to the index of the first row to be displayed
+    pub fn offset_mut(&mut self) -> &mut usize {
+        &mut self.offset
+    }
+
+    /// Path of the selected node
+    ///
+    /// Returns `None` if no node is selected
+    pub fn selected(&self) -> Option<&[usize]> {
+        self.selected.as_deref()
+    }
+
+    /// Selects the node at the given path, or clears the selection with `None`
+    pub fn select(&mut self, path: Option<Vec<usize>>) {
+        self.selected = path;
+        if self.selected.is_none() {
+            self.offset = 0;
+        }
+    }
+
+    /// Returns true if the node at the given path is expanded
+    pub fn is_expanded(&self, path: &[usize]) -> bool {
+        self.expanded.contains(path)
+    }
+
+    /// Expands the node at the given path
+    ///
+    /// Returns true if the node was previously collapsed.
+    pub fn expand(&mut self, path: Vec<usize>) -> bool {
+        self.expanded.insert(path)
+    }
+
+    /// Collapses the node at the given path
+    ///
+    /// Returns true if the node was previously expanded.
+    pub fn collapse(&mut self, path: &[usize]) -> bool {
+        self.expanded.remove(path)
+    }
+
+    /// Collapses every node
+    pub fn collapse_all(&mut self) {
+        self.expanded.clear();
+    }
+
+    /// Toggles the expansion of the selected node
+    ///
+    /// Does nothing if no node is selected.
+    pub fn toggle_expanded(&mut self) {
+        if let Some(path) = self.selected.clone() {
+            if !self.collapse(&path) {
+                self.expand(path);
+            }
+        }
+    }
+
+    /// Selects the next visible row
+    ///
+    /// If nothing is selected, the first row is selected. The selection stays on the last row
+    /// when it is reached.
+    pub fn select_next(&mut self) {
+        let index = self
+            .selected_row()
+            .map_or(0, |i| (i + 1).min(self.visible.len().saturating_sub(1)));
+        self.select_row(index);
+    }
+
+    /// Selects the previous visible row
+    ///
+    /// If nothing is selected, the last row is selected. The selection stays on the first row
+    /// when it is reached.
+    pub fn select_previous(&mut self) {
+        let index = self
+            .selected_row()
+            .map_or(usize::MAX, |i| i.saturating_sub(1));
+        self.select_row(index);
+    }
+
+    /// Selects the first visible row
+    pub fn select_first(&mut self) {
+        self.select_row(0);
+    }
+
+    /// Selects the last visible row
+    pub fn select_last(&mut self) {
+        self.select_row(usize::MAX);
+    }
+
+    /// Selects the parent of the selected node
+    ///
+    /// Does nothing if no node is selected or if the selected node is a root item.
+    pub fn select_parent(&mut self) {
+        if let Some(path) = &mut self.selected {
+            if path.len() > 1 {
+                path.pop();
+            }
+        }
+    }
+
+    /// Collapses the selected node, or selects its parent if it is already collapsed
+    ///
+    /// This matches the behavior of the left arrow key in most tree views.
+    pub fn collapse_or_select_parent(&mut self) {
+        let Some(path) = self.selected.clone() else {
+            return;
+        };
+        if !self.collapse(&path) {
+            self.select_parent();
+        }
+    }
+
+    /// Index of the selected node in the rows visible during the last render
+    fn selected_row(&self) -> Option<usize> {
+        let selected = self.selected.as_ref()?;
+        self.visible.iter().position(|path| path == selected)
+    }
+
+    /// Selects the visible row at the given index, clamped to the number of rows
+    fn select_row(&mut self, index: usize) {
+        if let Some(last) = self.visible.len().checked_sub(1) {
+            self.selected = Some(self.visible[index.min(last)].clone());
+        }
+    }
+}
+
+/// A node of a [`Tree`]
+///
+/// A node has a single line of content, an optional style and any number of children.
+///
+/// # Example
+///
+/// ```rust
+/// # use ratatui::{prelude::*, widgets::*};
+/// let item = TreeItem::new("src")
+///     .style(Style::new().blue())
+///     .children([TreeItem::new("lib.rs"), TreeItem::new("main.rs")]);
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct TreeItem<'a> {
+    content: Line<'a>,
+    style: Style,
+    children: Vec<Self>,
+}
+
+impl<'a> TreeItem<'a> {
+    /// Creates a new leaf node with the given content
+    pub fn new<T: Into<Line<'a>>>(content: T) -> Self {
+        Self {
+            content: content.into(),
+            style: Style::default(),
+            children: vec![],
+        }
+    }
+
+    /// Sets the children of the node
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn children<T>(mut self, children: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: Into<Self>,
+    {
+        self.children = children.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the style of the node
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// The children of the node
+    pub fn child_items(&self) -> &[Self] {
+        &self.children
+    }
+
+    /// Returns true if the node has no children
+    pub fn is_leaf(&self) -> bool {
+        self.children.is_empty()
+    }
+}
+
+impl<'a, T> From<T> for TreeItem<'a>
+where
+    T: Into<Line<'a>>,
+{
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+/// A widget to display hierarchical data
+///
+/// A tree is made of [`TreeItem`]s which can have children. Each node is rendered on its own row,
+/// indented according to its depth. Nodes with children are prefixed by an expand or collapse
+/// symbol, and indentation guides can be drawn to link children to their parent.
+///
+/// [`Tree`] is a [`StatefulWidget`] which uses a [`TreeState`] to track the expanded nodes and the
+/// selection.
+///
+/// # Example
+///
+/// ```rust
+/// use ratatui::{prelude::*, widgets::*};
+///
+/// # fn ui(frame: &mut Frame, state: &mut TreeState) {
+/// let items = [TreeItem::new("fruits").children(["apple", "banana"])];
+/// let tree = Tree::new(items)
+///     .block(Block::bordered().title("Tree"))
+///     .highlight_style(Style::new().reversed())
+///     .highlight_symbol(">> ");
+/// frame.render_stateful_widget(tree, frame.size(), state);
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Tree<'a> {
+    items: Vec<TreeItem<'a>>,
+    block: Option<Block<'a>>,
+    style: Style,
+    highlight_style: Style,
+    highlight_symbol: Option<&'a str>,
+    highlight_spacing: HighlightSpacing,
+    expanded_symbol: &'a str,
+    collapsed_symbol: &'a str,
+    guides: bool,
+}
+
+impl Default for Tree<'_> {
+    fn default() -> Self {
+        Self {
+            items: vec![],
+            block: None,
+            style: Style::default(),
+            highlight_style: Style::default(),
+            highlight_symbol: None,
+            highlight_spacing: HighlightSpacing::default(),
+            expanded_symbol: "▼ ",
+            collapsed_symbol: "▶ ",
+            guides: true,
+        }
+    }
+}
+
+impl<'a> Tree<'a> {
+    /// Creates a new tree from the given root items
+    pub fn new<T>(items: T) -> Self
+    where
+        T: IntoIterator,
+        T::Item: Into<TreeItem<'a>>,
+    {
+        Self {
+            items: items.into_iter().map(Into::into).collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Surrounds the tree with a [`Block`]
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Sets the base style of the widget
+    ///
+    /// `style` accepts any type that is convertible to [`Style`] (e.g. [`Style`], [`Color`], or
+    /// your own type that implements [`Into<Style>`]).
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets the style of the selected row
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub fn highlight_style<S: Into<Style>>(mut self, style: S) -> Self {
+        self.highlight_style = style.into();
+        self
+    }
+
+    /// Sets the symbol displayed in front of the selected row
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn highlight_symbol(mut self, highlight_symbol: &'a str) -> Self {
+        self.highlight_symbol = Some(highlight_symbol);
+        self
+    }
+
+    /// Sets when to allocate spacing for the highlight symbol
+    ///
+    /// See [`HighlightSpacing`] for the available options.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn highlight_spacing(mut self, value: HighlightSpacing) -> Self {
+        self.highlight_spacing = value;
+        self
+    }
+
+    /// Sets the symbols displayed in front of expanded and collapsed nodes
+    ///
+    /// Leaf nodes are padded with spaces of the same width. Defaults to `"▼ "` and `"▶ "`.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn node_symbols(mut self, expanded: &'a str, collapsed: &'a str) -> Self {
+        self.expanded_symbol = expanded;
+        self.collapsed_symbol = collapsed;
+        self
+    }
+
+    /// Sets whether indentation guides linking children to their parent are drawn
+    ///
+    /// When disabled, children are indented with spaces. Defaults to `true`.
+    #[must_use = "method moves the value of self and returns the modified value"]
+    pub const fn guides(mut self, guides: bool) -> Self {
+        self.guides = guides;
+        self
+    }
+
+    /// Flattens the expanded nodes into rows
+    fn visible_rows<'b>(&'b self, state: &TreeState) -> Vec<TreeRow<'b, 'a>> {
+        fn visit<'b, 'a>(
+            items: &'b [TreeItem<'a>],
+            state: &TreeState,
+            path: &mut Vec<usize>,
+            last: &mut Vec<bool>,
+            rows: &mut Vec<TreeRow<'b, 'a>>,
+        ) {
+            for (i, item) in items.iter().enumerate() {
+                path.push(i);
+                last.push(i + 1 == items.len());
+                let expanded = state.is_expanded(path);
+                rows.push(TreeRow {
+                    item,
+                    path: path.clone(),
+                    last: last.clone(),
+                    expanded,
+                });
+                if expanded {
+                    visit(&item.children, state, path, last, rows);
+                }
+                path.pop();
+                last.pop();
+            }
+        }
+        let mut rows = vec![];
+        visit(&self.items, state, &mut vec![], &mut vec![], &mut rows);
+        rows
+    }
+
+    /// Builds the indentation and node symbol displayed in front of a row
+    fn prefix(&self, row: &TreeRow) -> String {
+        let mut prefix = String::new();
+        let depth = row.last.len();
+        for (level, last) in row.last.iter().enumerate().skip(1) {
+            let guide = match (self.guides, level + 1 == depth, *last) {
+                (false, ..) | (true, false, true) => "  ",
+                (true, false, false) => "│ ",
+                (true, true, false) => "├─",
+                (true, true, true) => "└─",
+            };
+            prefix.push_str(guide);
+        }
+        if row.item.is_leaf() {
+            prefix.push_str(&" ".repeat(self.expanded_symbol.width()));
+        } else if row.expanded {
+            prefix.push_str(self.expanded_symbol);
+        } else {
+            prefix.push_str(self.collapsed_symbol);
+        }
+        prefix
+    }
+}
+
+/// A visible row of a [`Tree`]
+struct TreeRow<'b, 'a> {
+    item: &'b TreeItem<'a>,
+    path: Vec<usize>,
+    /// Whether the node and each of its ancestors is the last of its siblings
+    last: Vec<bool>,
+    expanded: bool,
+}
+
+impl StatefulWidget for Tree<'_> {
+    type State = TreeState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidgetRef::render_ref(&self, area, buf, state);
+    }
+}
+
+impl StatefulWidget for &Tree<'_> {
+    type State = TreeState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        StatefulWidgetRef::render_ref(self, area, buf, state);
+    }
+}
+
+impl Widget for Tree<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        WidgetRef::render_ref(&self, area, buf);
+    }
+}
+
+impl WidgetRef for Tree<'_> {
+    fn render_ref(&self, area: Rect, buf: &mut Buffer) {
+        let mut state = TreeState::default();
+        StatefulWidgetRef::render_ref(self, area, buf, &mut state);
+    }
+}
+
+impl StatefulWidgetRef for Tree<'_> {
+    type State = TreeState;
+
+    fn render_ref(&self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+        self.block.render_ref(area, buf);
+        let tree_area = self.block.inner_if_some(area);
+
+        let rows = self.visible_rows(state);
+        state.visible = rows.iter().map(|row| row.path.clone()).collect();
+        if tree_area.is_empty() || rows.is_empty() {
+            return;
+        }
+
+        // keep the selected row within the viewport
+        let height = tree_area.height as usize;
+        state.offset = state.offset.min(rows.len().saturating_sub(1));
+        if let Some(selected) = state.selected_row() {
+            state.offset = state.offset.min(selected);
+            if selected >= state.offset + height {
+                state.offset = selected + 1 - height;
+            }
+        }
+
+        let highlight_symbol = self.highlight_symbol.unwrap_or("");
+        let selection_spacing = self.highlight_spacing.should_add(state.selected.is_some());
+        let selected = state.selected_row();
+        for (i, row) in rows.iter().enumerate().skip(state.offset).take(height) {
+            let y = tree_area.top() + (i - state.offset) as u16;
+            let row_area = Rect::new(tree_area.x, y, tree_area.width, 1);
+            let item_style = self.style.patch(row.item.style);
+            buf.set_style(row_area, item_style);
+
+            let is_selected = selected == Some(i);
+            let mut x = row_area.x;
+            if selection_spacing {
+                let symbol = if is_selected {
+                    highlight_symbol.to_string()
+                } else {
+                    " ".repeat(highlight_symbol.width())
+                };
+                (x, _) = buf.set_stringn(x, y, symbol, row_area.width as usize, item_style);
+            }
+            let max_width = row_area.right().saturating_sub(x) as usize;
+            (x, _) = buf.set_stringn(x, y, self.prefix(row), max_width, item_style);
+            let max_width = row_area.right().saturating_sub(x);
+            buf.set_line(x, y, &row.item.content, max_width);
+
+            if is_selected {
+                buf.set_style(row_area, self.highlight_style);
+            }
+        }
+    }
+}
+
+impl Styled for Tree<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+impl Styled for TreeItem<'_> {
+    type Item = Self;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style<S: Into<Style>>(self, style: S) -> Self::Item {
+        self.style(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    #[fixture]
+    fn items() -> Vec<TreeItem<'static>> {
+        vec![
+            TreeItem::new("a").children([TreeItem::new("b"), TreeItem::new("c").children(["d"])]),
+            TreeItem::new("e"),
+        ]
+    }
+
+    fn render(tree: &Tree, state: &mut TreeState, width: u16, height: u16) -> Buffer {
+        let mut buf = Buffer::empty(Rect::new(0, 0, width, height));
+        StatefulWidget::render(tree, buf.area, &mut buf, state);
+        buf
+    }
+
+    #[rstest]
+    fn render_collapsed(items: Vec<TreeItem<'static>>) {
+        let mut state = TreeState::default();
+        let buf = render(&Tree::new(items), &mut state, 6, 3);
+        assert_eq!(buf, Buffer::with_lines(["▶ a   ", "  e   ", "      "]));
+    }
+
+    #[rstest]
+    fn render_expanded_with_guides(items: Vec<TreeItem<'static>>) {
+        let mut state = TreeState::default();
+        state.expand(vec![0]);
+        state.expand(vec![0, 1]);
+        let buf = render(&Tree::new(items), &mut state, 8, 5);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["▼ a     ", "├─  b   ", "└─▼ c   ", "  └─  d ", "  e     ",])
+        );
+    }
+
+    #[rstest]
+    fn render_without_guides(items: Vec<TreeItem<'static>>) {
+        let mut state = TreeState::default();
+        state.expand(vec![0]);
+        let tree = Tree::new(items).guides(false).node_symbols("- ", "+ ");
+        let buf = render(&tree, &mut state, 6, 4);
+        assert_eq!(
+            buf,
+            Buffer::with_lines(["- a   ", "    b ", "  + c ", "  e   "])
+        );
+    }
+
+    #[rstest]
+    fn render_highlight(items: Vec<TreeItem<'static>>) {
+        let mut state = TreeState::default().with_selected(Some(vec![1]));
+        let tree = Tree::new(items)
+            .highlight_symbol(">")
+            .highlight_style(Style::new().reversed());
+        let buf = render(&tree, &mut state, 5, 2);
+        let mut expected = Buffer::with_lines([" ▶ a ", ">  e "]);
+        expected.set_style(Rect::new(0, 1, 5, 1), Style::new().reversed());
+        assert_eq!(buf, expected);
+    }
+
+    #[rstest]
+    fn render_scrolls_to_selection(items: Vec<TreeItem<'static>>) {
+        let mut state = TreeState::default().with_selected(Some(vec![1]));
+        state.expand(vec![0]);
+        let buf = render(&Tree::new(items).guides(false), &mut state, 5, 2);
+        assert_eq!(buf, Buffer::with_lines(["  ▶ c", "  e  "]));
+        assert_eq!(state.offset(), 2);
+    }
+
+    #[rstest]
+    fn navigation(items: Vec<TreeItem<'static>>) {
+        let tree = Tree::new(items);
+        let mut state = TreeState::default();
+        render(&tree, &mut state, 10, 5);
+
+        state.select_next();
+        assert_eq!(state.selected(), Some([0].as_slice()));
+        state.toggle_expanded();
+        assert!(state.is_expanded(&[0]));
+
+        render(&tree, &mut state, 10, 5);
+        state.select_next();
+        state.select_next();
+        assert_eq!(state.selected(), Some([0, 1].as_slice()));
+        state.select_last();
+        assert_eq!(state.selected(), Some([1].as_slice()));
+        state.select_next();
+        assert_eq!(state.selected(), Some([1].as_slice()));
+        state.select_previous();
+        assert_eq!(state.selected(), Some([0, 1].as_slice()));
+
+        state.collapse_or_select_parent();
+        assert_eq!(state.selected(), Some([0].as_slice()));
+        state.collapse_or_select_parent();
+        assert!(!state.is_expanded(&[0]));
+        state.select_first();
+        assert_eq!(state.selected(), Some([0].as_slice()));
+    }
+
+    #[test]
+    fn select_previous_without_selection_selects_last() {
+        let tree = Tree::new(["a", "b"]);
+        let mut state = TreeState::default();
+        render(&tree, &mut state, 5, 2);
+        state.select_previous();
+        assert_eq!(state.selected(), Some([1].as_slice()));
+    }
+
+    #[test]
+    fn render_empty() {
+        let mut state = TreeState::default();
+        let buf = render(&Tree::default(), &mut state, 3, 1);
+        assert_eq!(buf, Buffer::with_lines(["   "]));
+        state.select_next();
+        assert_eq!(state.selected(), None);
+    }
+}
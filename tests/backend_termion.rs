@@ -16,6 +16,7 @@ fn backend_termion_should_only_write_diffs() -> Result<(), Box<dyn std::error::E
             backend,
             TerminalOptions {
                 viewport: Viewport::Fixed(area),
+                ..Default::default()
             },
         )?;
         terminal.draw(|f| {
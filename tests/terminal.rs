@@ -1,10 +1,16 @@
-use std::error::Error;
+use std::{
+    error::Error,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::Duration,
+};
 
 use ratatui::{
     backend::{Backend, TestBackend},
     layout::Rect,
+    terminal::Metrics,
+    text::Text,
     widgets::{Paragraph, Widget},
-    Terminal, TerminalOptions, Viewport,
+    Frame, Terminal, TerminalOptions, Viewport,
 };
 
 #[test]
@@ -73,6 +79,22 @@ fn terminal_draw_increments_frame_count() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[test]
+fn terminal_draw_resets_hit_areas() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(10, 10);
+    let mut terminal = Terminal::new(backend)?;
+    terminal.draw(|f| {
+        f.register_hit_area("full", f.size());
+        f.register_hit_area("corner", Rect::new(0, 0, 2, 2));
+    })?;
+    assert_eq!(terminal.hit_test((1, 1)), Some("corner"));
+    assert_eq!(terminal.hit_test((5, 5)), Some("full"));
+    terminal.draw(|_| {})?;
+    assert!(terminal.hit_areas().is_empty());
+    assert_eq!(terminal.hit_test((1, 1)), None);
+    Ok(())
+}
+
 #[test]
 fn terminal_insert_before_moves_viewport() -> Result<(), Box<dyn Error>> {
     // When we have a terminal with 5 lines, and a single line viewport, if we insert a
@@ -84,6 +106,7 @@ fn terminal_insert_before_moves_viewport() -> Result<(), Box<dyn Error>> {
         backend,
         TerminalOptions {
             viewport: Viewport::Inline(1),
+            ..Default::default()
         },
     )?;
 
@@ -126,6 +149,7 @@ fn terminal_insert_before_scrolls_on_large_input() -> Result<(), Box<dyn Error>>
         backend,
         TerminalOptions {
             viewport: Viewport::Inline(1),
+            ..Default::default()
         },
     )?;
 
@@ -169,6 +193,7 @@ fn terminal_insert_before_scrolls_on_many_inserts() -> Result<(), Box<dyn Error>
         backend,
         TerminalOptions {
             viewport: Viewport::Inline(1),
+            ..Default::default()
         },
     )?;
 
@@ -207,3 +232,381 @@ fn terminal_insert_before_scrolls_on_many_inserts() -> Result<(), Box<dyn Error>
 
     Ok(())
 }
+
+#[cfg(feature = "crossterm")]
+#[test]
+fn terminal_draw_wraps_frame_in_synchronized_update() -> Result<(), Box<dyn Error>> {
+    use ratatui::backend::CrosstermBackend;
+
+    let mut bytes = Vec::new();
+    {
+        let area = Rect::new(0, 0, 3, 1);
+        let options = TerminalOptions::default()
+            .viewport(Viewport::Fixed(area))
+            .synchronized_output(true);
+        let mut terminal = Terminal::with_options(CrosstermBackend::new(&mut bytes), options)?;
+        terminal.draw(|f| {
+            f.render_widget(Paragraph::new("abc"), area);
+        })?;
+    }
+    let output = String::from_utf8(bytes)?;
+    let begin = output
+        .find("\x1b[?2026h")
+        .expect("begin synchronized update");
+    let end = output.find("\x1b[?2026l").expect("end synchronized update");
+    let content = output.find("abc").expect("frame content");
+    assert!(begin < content && content < end, "{output:?}");
+    Ok(())
+}
+
+#[cfg(feature = "crossterm")]
+#[test]
+fn terminal_draw_ends_synchronized_update_on_error() -> Result<(), Box<dyn Error>> {
+    use std::io::{self, Write};
+
+    use ratatui::backend::CrosstermBackend;
+
+    /// A writer which only accepts escape sequences
+    struct EscapeSequenceWriter(Vec<u8>);
+
+    impl Write for EscapeSequenceWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            if !buf.starts_with(b"\x1b") {
+                return Err(io::Error::other("write failed"));
+            }
+            self.0.write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    let mut writer = EscapeSequenceWriter(Vec::new());
+    {
+        let area = Rect::new(0, 0, 3, 1);
+        let options = TerminalOptions::default()
+            .viewport(Viewport::Fixed(area))
+            .synchronized_output(true);
+        let mut terminal = Terminal::with_options(CrosstermBackend::new(&mut writer), options)?;
+        let result = terminal.draw(|f| {
+            f.render_widget(Paragraph::new("abc"), area);
+        });
+        assert!(result.is_err());
+    }
+    let output = String::from_utf8(writer.0)?;
+    let begin = output
+        .find("\x1b[?2026h")
+        .expect("begin synchronized update");
+    let end = output.find("\x1b[?2026l").expect("end synchronized update");
+    assert!(begin < end, "{output:?}");
+    Ok(())
+}
+
+#[cfg(feature = "crossterm")]
+#[test]
+fn terminal_draw_emits_hyperlinks() -> Result<(), Box<dyn Error>> {
+    use ratatui::backend::CrosstermBackend;
+
+    let mut bytes = Vec::new();
+    {
+        let area = Rect::new(0, 0, 5, 1);
+        let options = TerminalOptions::default().viewport(Viewport::Fixed(area));
+        let mut terminal = Terminal::with_options(CrosstermBackend::new(&mut bytes), options)?;
+        terminal.draw(|f| {
+            f.render_widget(
+                ratatui::text::Span::raw("ab").hyperlink("https://ratatui.rs"),
+                area,
+            );
+        })?;
+    }
+    let output = String::from_utf8(bytes)?;
+    assert!(
+        output.contains("\x1b]8;;https://ratatui.rs\x1b\\ab\x1b]8;;\x1b\\"),
+        "{output:?}"
+    );
+    Ok(())
+}
+
+#[cfg(feature = "crossterm")]
+#[test]
+fn terminal_draw_writes_graphics_once() -> Result<(), Box<dyn Error>> {
+    use ratatui::{backend::CrosstermBackend, buffer::Graphic};
+
+    let mut bytes = Vec::new();
+    {
+        let area = Rect::new(0, 0, 5, 1);
+        let options = TerminalOptions::default().viewport(Viewport::Fixed(area));
+        let mut terminal = Terminal::with_options(CrosstermBackend::new(&mut bytes), options)?;
+        let graphic = Graphic::new(Rect::new(1, 0, 2, 1), "<image>").cleanup("<delete>");
+        for _ in 0..2 {
+            terminal.draw(|f| f.buffer_mut().set_graphic(graphic.clone()))?;
+        }
+        terminal.draw(|f| f.render_widget(Paragraph::new("abcde"), area))?;
+    }
+    let output = String::from_utf8(bytes)?;
+    assert_eq!(output.matches("<image>").count(), 1, "{output:?}");
+    assert_eq!(output.matches("<delete>").count(), 1, "{output:?}");
+    let image = output.find("<image>").unwrap();
+    let delete = output.find("<delete>").unwrap();
+    let content = output.find("abcde").unwrap();
+    assert!(image < delete && delete < content, "{output:?}");
+    Ok(())
+}
+
+#[test]
+fn terminal_draw_with_clip() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(10, 2);
+    let mut terminal = Terminal::new(backend)?;
+    terminal.draw(|f| {
+        f.render_widget(Paragraph::new("..........\n.........."), f.size());
+        f.with_clip(Rect::new(2, 0, 5, 1), |f| {
+            f.render_widget(Paragraph::new("Hello World\nHello World"), f.size());
+        });
+    })?;
+    terminal
+        .backend()
+        .assert_buffer_lines(["..llo W...", ".........."]);
+    Ok(())
+}
+
+#[test]
+fn terminal_inline_viewport_grows_to_fit_content() -> Result<(), Box<dyn Error>> {
+    let mut backend = TestBackend::new(10, 5);
+    backend.set_cursor(0, 1)?;
+    let options = TerminalOptions::default()
+        .viewport(Viewport::Inline(1))
+        .inline_max_height(3);
+    let mut terminal = Terminal::with_options(backend, options)?;
+
+    terminal.draw(|f| {
+        assert_eq!(f.size(), Rect::new(0, 1, 10, 3));
+        f.render_widget(Paragraph::new("a\nb"), f.size());
+    })?;
+    terminal.backend().assert_buffer_lines([
+        "          ",
+        "a         ",
+        "b         ",
+        "          ",
+        "          ",
+    ]);
+
+    // the viewport does not grow past the maximum height, and scrolls the terminal when it
+    // reaches the bottom
+    terminal.insert_before(2, |buf| Paragraph::new("log").render(buf.area, buf))?;
+    terminal.draw(|f| f.render_widget(Paragraph::new("a\nb\nc\nd"), f.size()))?;
+    terminal.backend().assert_buffer_lines([
+        "log       ",
+        "          ",
+        "a         ",
+        "b         ",
+        "c         ",
+    ]);
+
+    // the viewport does not shrink
+    terminal.draw(|f| {
+        assert_eq!(f.size(), Rect::new(0, 2, 10, 3));
+        f.render_widget(Paragraph::new("e"), f.size());
+    })?;
+    terminal.backend().assert_buffer_lines([
+        "log       ",
+        "          ",
+        "e         ",
+        "          ",
+        "          ",
+    ]);
+    Ok(())
+}
+
+#[test]
+fn terminal_insert_lines_before_wraps_text() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(10, 5);
+    let options = TerminalOptions::default().viewport(Viewport::Inline(1));
+    let mut terminal = Terminal::with_options(backend, options)?;
+
+    terminal.insert_lines_before("first line\nsecond line wraps")?;
+    terminal.insert_lines_before(Text::from("end").right_aligned())?;
+    terminal.draw(|f| f.render_widget(Paragraph::new("viewport"), f.size()))?;
+
+    terminal.backend().assert_buffer_lines([
+        "first line",
+        "second    ",
+        "line wraps",
+        "       end",
+        "viewport  ",
+    ]);
+    Ok(())
+}
+
+#[test]
+fn terminal_insert_lines_before_many_lines() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(80, 4);
+    let options = TerminalOptions::default().viewport(Viewport::Inline(1));
+    let mut terminal = Terminal::with_options(backend, options)?;
+
+    // 1000 rows of 80 cells do not fit in a single buffer
+    let lines: Vec<String> = (0..1000).map(|i| format!("line {i}")).collect();
+    terminal.insert_lines_before(lines.join("\n"))?;
+    terminal.draw(|f| f.render_widget(Paragraph::new("viewport"), f.size()))?;
+
+    terminal.backend().assert_buffer_lines([
+        format!("{:80}", "line 997"),
+        format!("{:80}", "line 998"),
+        format!("{:80}", "line 999"),
+        format!("{:80}", "viewport"),
+    ]);
+    Ok(())
+}
+
+#[test]
+fn terminal_insert_lines_before_long_line() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(80, 4);
+    let options = TerminalOptions::default().viewport(Viewport::Inline(1));
+    let mut terminal = Terminal::with_options(backend, options)?;
+
+    // a single line wrapping to 1000 rows
+    let line: String = (0..1000).map(|i| format!("{i:<80}")).collect();
+    terminal.insert_lines_before(line)?;
+    terminal.draw(|f| f.render_widget(Paragraph::new("viewport"), f.size()))?;
+
+    terminal.backend().assert_buffer_lines([
+        format!("{:<80}", 997),
+        format!("{:<80}", 998),
+        format!("{:<80}", 999),
+        format!("{:80}", "viewport"),
+    ]);
+    Ok(())
+}
+
+#[test]
+fn terminal_draw_additional_viewport() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(10, 3);
+    let options = TerminalOptions::default().viewport(Viewport::Fixed(Rect::new(0, 0, 10, 2)));
+    let mut terminal = Terminal::with_options(backend, options)?;
+    let status_bar = terminal.add_viewport(Rect::new(0, 2, 10, 1));
+    assert_eq!(terminal.viewport_area(status_bar), Rect::new(0, 2, 10, 1));
+
+    terminal.draw(|f| {
+        f.render_widget(Paragraph::new("main"), f.size());
+        f.set_cursor(1, 1);
+    })?;
+    terminal.draw_viewport(status_bar, |f| {
+        assert_eq!(f.size(), Rect::new(0, 2, 10, 1));
+        f.render_widget(Paragraph::new("status"), f.size());
+        f.register_hit_area("status", f.size());
+    })?;
+    // the cursor is moved back to where the main viewport put it
+    assert_eq!(terminal.get_cursor()?, (1, 1));
+
+    terminal.draw(|f| f.render_widget(Paragraph::new("updated"), f.size()))?;
+    terminal
+        .backend()
+        .assert_buffer_lines(["updated   ", "          ", "status    "]);
+    assert_eq!(terminal.hit_test((3, 2)), Some("status"));
+    assert_eq!(terminal.hit_test((3, 0)), None);
+
+    terminal.draw_viewport(status_bar, |f| {
+        f.render_widget(Paragraph::new("done"), f.size());
+    })?;
+    terminal
+        .backend()
+        .assert_buffer_lines(["updated   ", "          ", "done      "]);
+    Ok(())
+}
+
+#[test]
+fn terminal_suspend_redraws_everything() -> Result<(), Box<dyn Error>> {
+    let backend = TestBackend::new(10, 2);
+    let mut terminal = Terminal::new(backend)?;
+    terminal.draw(|f| f.render_widget(Paragraph::new("hello"), f.size()))?;
+
+    let result = terminal.suspend(|| 42)?;
+    assert_eq!(result, 42);
+    terminal
+        .backend()
+        .assert_buffer_lines(["          ", "          "]);
+
+    terminal.draw(|f| f.render_widget(Paragraph::new("hello"), f.size()))?;
+    terminal
+        .backend()
+        .assert_buffer_lines(["hello     ", "          "]);
+    Ok(())
+}
+
+#[test]
+fn terminal_draw_records_metrics() -> Result<(), Box<dyn Error>> {
+    let mut terminal = Terminal::new(TestBackend::new(10, 2))?;
+    terminal.draw(|f| f.render_widget(Paragraph::new("hello"), f.size()))?;
+    assert_eq!(terminal.metrics(), None);
+
+    let options = TerminalOptions::default().metrics(true);
+    let mut terminal = Terminal::with_options(TestBackend::new(10, 2), options)?;
+    terminal.draw(|f| f.render_widget(Paragraph::new("hello"), f.size()))?;
+    terminal.draw(|f| f.render_widget(Paragraph::new("help"), f.size()))?;
+
+    let metrics = terminal.metrics().expect("metrics are enabled");
+    assert_eq!(metrics.frames, 2);
+    assert_eq!(metrics.last_frame.cells_updated, 2);
+    assert_eq!(metrics.total.cells_updated, 7);
+    assert_eq!(metrics.last_frame.allocations, None);
+    assert!(metrics.total.total_time() >= metrics.last_frame.total_time());
+
+    terminal.reset_metrics();
+    assert_eq!(terminal.metrics(), Some(&Metrics::default()));
+    Ok(())
+}
+
+#[test]
+fn terminal_draw_counts_allocations() -> Result<(), Box<dyn Error>> {
+    static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+    // pretend that rendering each frame makes 3 allocations
+    let render = |f: &mut Frame| {
+        ALLOCATIONS.fetch_add(3, Ordering::Relaxed);
+        f.render_widget(Paragraph::new("hello"), f.size());
+    };
+    let options = TerminalOptions::default()
+        .metrics(true)
+        .allocation_counter(&ALLOCATIONS);
+    let mut terminal = Terminal::with_options(TestBackend::new(10, 2), options)?;
+    terminal.draw(render)?;
+    terminal.draw(render)?;
+
+    let metrics = terminal.metrics().expect("metrics are enabled");
+    assert_eq!(metrics.last_frame.allocations, Some(3));
+    assert_eq!(metrics.total.allocations, Some(6));
+    Ok(())
+}
+
+#[test]
+fn terminal_draw_throttled_draws_when_requested() -> Result<(), Box<dyn Error>> {
+    let mut terminal = Terminal::new(TestBackend::new(10, 1))?;
+    let render = |f: &mut Frame| f.render_widget(Paragraph::new("hello"), f.size());
+    assert!(terminal.needs_redraw());
+    assert!(terminal.draw_throttled(0, render)?.is_some());
+    assert!(!terminal.needs_redraw());
+    assert!(terminal.draw_throttled(0, render)?.is_none());
+
+    terminal.request_redraw();
+    assert!(terminal.draw_throttled(0, render)?.is_some());
+
+    terminal.backend_mut().resize(8, 1);
+    assert!(terminal.draw_throttled(0, render)?.is_some());
+    terminal.backend().assert_buffer_lines(["hello   "]);
+    Ok(())
+}
+
+#[test]
+fn terminal_draw_throttled_limits_frame_rate() -> Result<(), Box<dyn Error>> {
+    let mut terminal = Terminal::new(TestBackend::new(10, 1))?;
+    let render = |f: &mut Frame| f.render_widget(Paragraph::new("hello"), f.size());
+    assert_eq!(terminal.time_until_next_frame(1), Duration::ZERO);
+    assert!(terminal.draw_throttled(1, render)?.is_some());
+
+    terminal.request_redraw();
+    assert!(terminal.draw_throttled(1, render)?.is_none());
+    assert!(terminal.needs_redraw());
+    assert!(terminal.time_until_next_frame(1) > Duration::ZERO);
+    assert_eq!(terminal.time_until_next_frame(0), Duration::ZERO);
+    Ok(())
+}
@@ -4,12 +4,12 @@ use ratatui::{
     buffer::Buffer,
     style::Style,
     widgets::{
-        calendar::{CalendarEventStore, Monthly},
+        calendar::{CalendarEventStore, Monthly, Quarterly, Yearly},
         Widget,
     },
     Terminal,
 };
-use time::{Date, Month};
+use time::{Date, Month, Weekday};
 
 #[track_caller]
 fn test_render<W: Widget>(widget: W, width: u16, height: u16, expected: &Buffer) {
@@ -111,3 +111,99 @@ fn show_combo() {
     ]);
     test_render(c, 21, 7, &expected);
 }
+
+#[test]
+fn show_week_numbers() {
+    let c = Monthly::new(
+        Date::from_calendar_date(2023, Month::January, 1).unwrap(),
+        CalendarEventStore::default(),
+    )
+    .show_weekdays_header(Style::default())
+    .show_week_numbers(Style::default());
+    let expected = Buffer::with_lines([
+        "    Su Mo Tu We Th Fr Sa",
+        " 1   1  2  3  4  5  6  7",
+        " 2   8  9 10 11 12 13 14",
+        " 3  15 16 17 18 19 20 21",
+        " 4  22 23 24 25 26 27 28",
+        " 5  29 30 31",
+    ]);
+    test_render(c, 24, 6, &expected);
+}
+
+#[test]
+fn quarterly() {
+    let monthly = Monthly::new(
+        Date::from_calendar_date(2023, Month::May, 15).unwrap(),
+        CalendarEventStore::default(),
+    )
+    .show_month_header(Style::default());
+    let expected = Buffer::with_lines([
+        "     April 2023             May 2023              June 2023      ",
+        "                    1      1  2  3  4  5  6               1  2  3",
+        "  2  3  4  5  6  7  8   7  8  9 10 11 12 13   4  5  6  7  8  9 10",
+        "  9 10 11 12 13 14 15  14 15 16 17 18 19 20  11 12 13 14 15 16 17",
+        " 16 17 18 19 20 21 22  21 22 23 24 25 26 27  18 19 20 21 22 23 24",
+        " 23 24 25 26 27 28 29  28 29 30 31           25 26 27 28 29 30",
+        " 30",
+    ]);
+    test_render(Quarterly::new(monthly), 65, 7, &expected);
+}
+
+#[test]
+fn yearly() {
+    let monthly = Monthly::new(
+        Date::from_calendar_date(2024, Month::July, 4).unwrap(),
+        CalendarEventStore::default(),
+    )
+    .show_month_header(Style::default());
+    let expected = Buffer::with_lines([
+        "    January 2024          February 2024    ",
+        "     1  2  3  4  5  6               1  2  3",
+        "  7  8  9 10 11 12 13   4  5  6  7  8  9 10",
+        " 14 15 16 17 18 19 20  11 12 13 14 15 16 17",
+        " 21 22 23 24 25 26 27  18 19 20 21 22 23 24",
+        " 28 29 30 31           25 26 27 28 29",
+        "",
+        "",
+        "     March 2024            April 2024",
+        "                 1  2      1  2  3  4  5  6",
+    ]);
+    test_render(Yearly::new(monthly).columns(2), 43, 10, &expected);
+}
+
+#[test]
+fn first_weekday_and_names() {
+    let c = Monthly::new(
+        Date::from_calendar_date(2023, Month::January, 1).unwrap(),
+        CalendarEventStore::default(),
+    )
+    .first_weekday(Weekday::Monday)
+    .weekday_names(|weekday| {
+        match weekday {
+            Weekday::Monday => "Lundi",
+            Weekday::Tuesday => "Mardi",
+            Weekday::Wednesday => "Mercredi",
+            Weekday::Thursday => "Jeudi",
+            Weekday::Friday => "Vendredi",
+            Weekday::Saturday => "Samedi",
+            Weekday::Sunday => "Dimanche",
+        }
+        .to_string()
+    })
+    .month_names(|month| format!("Mois {}", u8::from(month)))
+    .show_month_header(Style::default())
+    .show_weekdays_header(Style::default())
+    .show_week_numbers(Style::default());
+    let expected = Buffer::with_lines([
+        "      Mois 1 2023       ",
+        "    Lu Ma Me Je Ve Sa Di",
+        "52                     1",
+        " 1   2  3  4  5  6  7  8",
+        " 2   9 10 11 12 13 14 15",
+        " 3  16 17 18 19 20 21 22",
+        " 4  23 24 25 26 27 28 29",
+        " 5  30 31",
+    ]);
+    test_render(c, 24, 8, &expected);
+}
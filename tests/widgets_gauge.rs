@@ -2,7 +2,7 @@ use ratatui::{
     backend::TestBackend,
     buffer::Buffer,
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style, Stylize},
+    style::{Color, Gradient, Modifier, Style, Stylize},
     symbols,
     text::Span,
     widgets::{Block, Gauge, LineGauge},
@@ -173,6 +173,58 @@ fn widgets_gauge_supports_large_labels() {
     terminal.backend().assert_buffer_lines(["4333333333"]);
 }
 
+#[test]
+fn widgets_gauge_renders_block_set() {
+    let backend = TestBackend::new(10, 1);
+    let mut terminal = Terminal::new(backend).unwrap();
+
+    terminal
+        .draw(|f| {
+            let gauge = Gauge::default()
+                .block_set(symbols::block::Set {
+                    full: "#",
+                    ..symbols::block::THREE_LEVELS
+                })
+                .use_unicode(true)
+                .ratio(0.45)
+                .label("");
+            f.render_widget(gauge, f.size());
+        })
+        .unwrap();
+    terminal.backend().assert_buffer_lines(["####▌     "]);
+}
+
+#[test]
+fn widgets_gauge_renders_gradient() {
+    let gradient = Gradient::new([Color::Rgb(0, 0, 0), Color::Rgb(200, 0, 0)]);
+    let backend = TestBackend::new(10, 2);
+    let mut terminal = Terminal::new(backend).unwrap();
+
+    terminal
+        .draw(|f| {
+            let [low, high] = Layout::vertical([Constraint::Length(1); 2]).areas(f.size());
+            let gauge = Gauge::default()
+                .gauge_style(Style::new().blue())
+                .gradient(gradient.clone())
+                .percent(40)
+                .label("");
+            f.render_widget(gauge, low);
+            let gauge = Gauge::default()
+                .gauge_style(Style::new().blue())
+                .gradient(gradient)
+                .use_unicode(true)
+                .percent(35)
+                .label("");
+            f.render_widget(gauge, high);
+        })
+        .unwrap();
+    let mut expected = Buffer::with_lines(["████      ", "███▌      "]);
+    expected.set_style(Rect::new(0, 0, 10, 2), Style::new().blue());
+    expected.set_style(Rect::new(0, 0, 4, 1), Color::Rgb(80, 0, 0));
+    expected.set_style(Rect::new(0, 1, 4, 1), Color::Rgb(70, 0, 0));
+    terminal.backend().assert_buffer(&expected);
+}
+
 #[test]
 fn widgets_line_gauge_renders() {
     let backend = TestBackend::new(20, 4);
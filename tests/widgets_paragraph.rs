@@ -29,7 +29,7 @@ fn widgets_paragraph_renders_double_width_graphemes() {
     let text = vec![Line::from(s)];
     let paragraph = Paragraph::new(text)
         .block(Block::bordered())
-        .wrap(Wrap { trim: true });
+        .wrap(Wrap::new(true));
 
     test_case(
         paragraph,
@@ -60,7 +60,7 @@ fn widgets_paragraph_renders_mixed_width_graphemes() {
             let text = vec![Line::from(s)];
             let paragraph = Paragraph::new(text)
                 .block(Block::bordered())
-                .wrap(Wrap { trim: true });
+                .wrap(Wrap::new(true));
             f.render_widget(paragraph, size);
         })
         .unwrap();
@@ -141,7 +141,7 @@ fn widgets_paragraph_can_wrap_its_content() {
     let text = vec![Line::from(SAMPLE_STRING)];
     let paragraph = Paragraph::new(text)
         .block(Block::bordered())
-        .wrap(Wrap { trim: true });
+        .wrap(Wrap::new(true));
 
     test_case(
         paragraph.clone().alignment(Alignment::Left),
@@ -200,7 +200,7 @@ fn widgets_paragraph_works_with_padding() {
     });
     let paragraph = Paragraph::new(vec![Line::from(SAMPLE_STRING)])
         .block(block.clone())
-        .wrap(Wrap { trim: true });
+        .wrap(Wrap::new(true));
 
     test_case(
         paragraph.clone().alignment(Alignment::Left),
@@ -242,7 +242,7 @@ fn widgets_paragraph_works_with_padding() {
         Line::from(SAMPLE_STRING),
     ])
     .block(block)
-    .wrap(Wrap { trim: true });
+    .wrap(Wrap::new(true));
 
     test_case(
         paragraph.alignment(Alignment::Right),
@@ -276,7 +276,7 @@ fn widgets_paragraph_can_align_spans() {
     ];
     let paragraph = Paragraph::new(text)
         .block(Block::bordered())
-        .wrap(Wrap { trim: true });
+        .wrap(Wrap::new(true));
 
     test_case(
         paragraph.clone().alignment(Alignment::Left),